@@ -613,6 +613,10 @@ export class PromptDjMidi extends LitElement {
       this.dispatchEvent(new CustomEvent('error', { detail: (e as any).message }));
     }
   }
+  /** Raw MIDI messages from the app's native input */
+  public receiveMidiMessages(messages: number[][]) {
+    messages.forEach((data) => this.midiDispatcher.handleMessage(data));
+  }
   public getMidiInputs(): string[] { return this.midiInputIds; }
   public getActiveMidiInputId(): string | null { return this.activeMidiInputId; }
   public setActiveMidiInputId(id: string) {
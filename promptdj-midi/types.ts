@@ -16,4 +16,11 @@ export interface ControlChange {
   value: number;
 }
 
+export interface NoteMessage {
+  channel: number;
+  note: number;
+  /** 0 for note off */
+  velocity: number;
+}
+
 export type PlaybackState = 'stopped' | 'playing' | 'loading' | 'paused';
@@ -2,9 +2,9 @@
  * @license
  * SPDX-License-Identifier: Apache-2.0
 */
-import type { ControlChange } from '../types';
+import type { ControlChange, NoteMessage } from '../types';
 
-/** Simple class for dispatching MIDI CC messages as events. */
+/** Simple class for dispatching MIDI CC and note messages as events. */
 export class MidiDispatcher extends EventTarget {
   private access: MIDIAccess | null = null;
   private denied: boolean = false;
@@ -62,24 +62,39 @@ export class MidiDispatcher extends EventTarget {
           console.error('MIDI message has no data');
           return;
         }
-
-        const statusByte = data[0];
-        const channel = statusByte & 0x0f;
-        const messageType = statusByte & 0xf0;
-
-        const isControlChange = messageType === 0xb0;
-        if (!isControlChange) return;
-
-        const detail: ControlChange = { cc: data[1], value: data[2], channel };
-        this.dispatchEvent(
-          new CustomEvent<ControlChange>('cc-message', { detail }),
-        );
+        this.handleMessage(data);
       };
     }
 
     return inputIds;
   }
 
+  /**
+   * Dispatch one raw MIDI message. Also fed by the app's native MIDI input
+   * (`pm-dj-midi` window messages), which works without Web MIDI access.
+   */
+  handleMessage(data: ArrayLike<number>) {
+    if (data.length < 3) return;
+
+    const statusByte = data[0];
+    const channel = statusByte & 0x0f;
+    const messageType = statusByte & 0xf0;
+
+    if (messageType === 0xb0) {
+      const detail: ControlChange = { cc: data[1], value: data[2], channel };
+      this.dispatchEvent(
+        new CustomEvent<ControlChange>('cc-message', { detail }),
+      );
+    } else if (messageType === 0x90 || messageType === 0x80) {
+      // Note on with velocity 0 is a note off
+      const velocity = messageType === 0x90 ? data[2] : 0;
+      const detail: NoteMessage = { note: data[1], velocity, channel };
+      this.dispatchEvent(
+        new CustomEvent<NoteMessage>('note-message', { detail }),
+      );
+    }
+  }
+
   getDeviceName(id: string): string | null {
     if (!this.access) {
       return null;
@@ -1,5 +1,11 @@
 use lazy_static::lazy_static;
-use std::time::Duration;
+use std::collections::HashMap;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::config::Config;
 
 lazy_static! {
     pub static ref UREQ_AGENT: ureq::Agent = {
@@ -8,4 +14,115 @@ lazy_static! {
             .build();
         config.into()
     };
+    // Agents with a per-provider response timeout, keyed by that timeout in seconds
+    static ref TIMED_AGENTS: Mutex<HashMap<u64, ureq::Agent>> = Mutex::new(HashMap::new());
+    // Last connectivity result per host, so a burst of requests only probes once
+    static ref LAST_CHECK: Mutex<Vec<(&'static str, Instant, bool)>> = Mutex::new(Vec::new());
+}
+
+/// Timeout for providers missing from `Config::request_timeout_secs`
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 60;
+
+/// Configured request timeout for `provider`
+pub fn request_timeout(config: &Config, provider: &str) -> Duration {
+    let secs = config
+        .request_timeout_secs
+        .get(provider)
+        .copied()
+        .unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS);
+    Duration::from_secs(secs.max(1))
+}
+
+/// HTTP agent for `provider`'s requests. The timeout bounds the wait for the response
+/// to start, so long streamed answers aren't cut off; a timed-out request fails with
+/// ureq's "timeout: ..." error (see `overlay::utils::is_timeout_error`).
+pub fn agent_for(config: &Config, provider: &str) -> ureq::Agent {
+    let timeout = request_timeout(config, provider);
+    let mut agents = TIMED_AGENTS.lock().unwrap();
+    agents
+        .entry(timeout.as_secs())
+        .or_insert_with(|| {
+            ureq::Agent::config_builder()
+                .timeout_connect(Some(Duration::from_secs(10)))
+                .timeout_recv_response(Some(timeout))
+                .build()
+                .into()
+        })
+        .clone()
+}
+
+/// Error returned instead of dispatching a request while offline
+pub const NO_INTERNET: &str = "NO_INTERNET";
+
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(1500);
+/// How long a connectivity result is trusted (failures are re-probed sooner)
+const ONLINE_TTL: Duration = Duration::from_secs(10);
+const OFFLINE_TTL: Duration = Duration::from_secs(2);
+/// Interval between checks while waiting for the connection to come back
+const RECHECK_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Host a cloud provider's requests go to (`None` for local providers)
+fn provider_host(provider: &str) -> Option<&'static str> {
+    match provider {
+        "ollama" => None,
+        "groq" => Some("api.groq.com"),
+        "google" => Some("generativelanguage.googleapis.com"),
+        "google-gtx" => Some("translate.googleapis.com"),
+        "cerebras" => Some("api.cerebras.ai"),
+        "openrouter" => Some("openrouter.ai"),
+        _ => Some("www.google.com"),
+    }
+}
+
+/// DNS lookup plus a TCP connect to port 443; no request is sent
+fn probe(host: &str) -> bool {
+    let Ok(addrs) = (host, 443).to_socket_addrs() else {
+        return false;
+    };
+    addrs
+        .into_iter()
+        .any(|addr| TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT).is_ok())
+}
+
+/// Whether `provider` is reachable. Local providers always are.
+pub fn is_online(provider: &str) -> bool {
+    let Some(host) = provider_host(provider) else {
+        return true;
+    };
+    if let Ok(checks) = LAST_CHECK.lock() {
+        if let Some((_, at, online)) = checks.iter().find(|(h, _, _)| *h == host) {
+            let ttl = if *online { ONLINE_TTL } else { OFFLINE_TTL };
+            if at.elapsed() < ttl {
+                return *online;
+            }
+        }
+    }
+
+    let online = probe(host);
+    if let Ok(mut checks) = LAST_CHECK.lock() {
+        checks.retain(|(h, _, _)| *h != host);
+        checks.push((host, Instant::now(), online));
+    }
+    online
+}
+
+/// Re-check every few seconds until `provider` is reachable, `cancel` is set or
+/// `max_wait` runs out. Returns whether the connection came back.
+pub fn wait_until_online(provider: &str, cancel: &AtomicBool, max_wait: Duration) -> bool {
+    let started = Instant::now();
+    loop {
+        if is_online(provider) {
+            return true;
+        }
+        if cancel.load(Ordering::Relaxed) || started.elapsed() >= max_wait {
+            return false;
+        }
+        let wake = Instant::now() + RECHECK_INTERVAL;
+        while Instant::now() < wake {
+            if cancel.load(Ordering::Relaxed) {
+                return false;
+            }
+            std::thread::sleep(Duration::from_millis(200));
+        }
+    }
 }
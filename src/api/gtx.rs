@@ -0,0 +1,39 @@
+use crate::api::client::UREQ_AGENT;
+
+/// Unofficial Google Translate (GTX) fallback
+pub fn translate_with_google_gtx(text: &str, target_lang: &str) -> Option<String> {
+    let target_code = isolang::Language::from_name(target_lang)
+        .and_then(|lang| lang.to_639_1())
+        .map(|code| code.to_string())
+        .unwrap_or_else(|| "en".to_string());
+
+    let encoded_text = urlencoding::encode(text);
+    let url = format!(
+        "https://translate.googleapis.com/translate_a/single?client=gtx&sl=auto&tl={}&dt=t&q={}",
+        target_code, encoded_text
+    );
+
+    match UREQ_AGENT
+        .get(&url)
+        .header("User-Agent", "Mozilla/5.0")
+        .call()
+    {
+        Ok(resp) => {
+            if let Ok(json) = resp.into_body().read_json::<serde_json::Value>() {
+                if let Some(sentences) = json.get(0).and_then(|v| v.as_array()) {
+                    let mut full_text = String::new();
+                    for sentence_node in sentences {
+                        if let Some(segment) = sentence_node.get(0).and_then(|s| s.as_str()) {
+                            full_text.push_str(segment);
+                        }
+                    }
+                    if !full_text.is_empty() {
+                        return Some(full_text);
+                    }
+                }
+            }
+        }
+        Err(_) => {}
+    }
+    None
+}
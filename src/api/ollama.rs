@@ -5,9 +5,12 @@ use anyhow::Result;
 use image::{ImageBuffer, Rgba};
 use base64::{Engine as _, engine::general_purpose};
 use std::io::{Cursor, BufRead, BufReader};
+use std::sync::atomic::{AtomicBool, Ordering};
 use serde::Deserialize;
-use super::client::UREQ_AGENT;
-use crate::gui::locale::LocaleText;
+use super::client::agent_for;
+use super::usage::record_model_usage;
+use crate::config::Config;
+use crate::locale::LocaleText;
 
 /// Ollama streaming chunk response
 #[derive(Deserialize, Debug)]
@@ -18,6 +21,15 @@ pub struct OllamaStreamChunk {
     pub thinking: Option<String>,
     #[serde(default)]
     pub done: bool,
+    /// Tokens generated (final line only)
+    #[serde(default)]
+    pub eval_count: Option<u64>,
+    /// Generation time in nanoseconds (final line only)
+    #[serde(default)]
+    pub eval_duration: Option<u64>,
+    /// Set instead of a response when the server fails mid-stream
+    #[serde(default)]
+    pub error: Option<String>,
 }
 
 /// Ollama non-streaming response
@@ -25,12 +37,19 @@ pub struct OllamaStreamChunk {
 pub struct OllamaGenerateResponse {
     #[serde(default)]
     pub response: String,
+    #[serde(default)]
+    pub eval_count: Option<u64>,
+    #[serde(default)]
+    pub eval_duration: Option<u64>,
 }
 
 /// Ollama model info from /api/tags
 #[derive(Deserialize, Debug, Clone)]
 pub struct OllamaModel {
     pub name: String,
+    /// Size on disk in bytes
+    #[serde(default)]
+    pub size: u64,
 }
 
 /// Response from /api/tags
@@ -63,10 +82,10 @@ struct OllamaModelDetails {
 }
 
 /// Fetch available models from Ollama
-pub fn fetch_ollama_models(base_url: &str) -> Result<Vec<OllamaModel>> {
+pub fn fetch_ollama_models(config: &Config, base_url: &str) -> Result<Vec<OllamaModel>> {
     let url = format!("{}/api/tags", base_url.trim_end_matches('/'));
     
-    let resp = UREQ_AGENT.get(&url)
+    let resp = agent_for(config, "ollama").get(&url)
         
                 .call()
         .map_err(|e| anyhow::anyhow!("Failed to connect to Ollama: {}", e))?;
@@ -78,14 +97,14 @@ pub fn fetch_ollama_models(base_url: &str) -> Result<Vec<OllamaModel>> {
 }
 
 /// Check if a model has vision capability by querying /api/show
-fn check_model_has_vision(base_url: &str, model_name: &str) -> bool {
+fn check_model_has_vision(config: &Config, base_url: &str, model_name: &str) -> bool {
     let url = format!("{}/api/show", base_url.trim_end_matches('/'));
     
     let payload = serde_json::json!({
         "name": model_name
     });
     
-    let resp = match UREQ_AGENT.post(&url)
+    let resp = match agent_for(config, "ollama").post(&url)
         
                 .send_json(&payload) {
             Ok(r) => r,
@@ -118,12 +137,12 @@ fn check_model_has_vision(base_url: &str, model_name: &str) -> bool {
 }
 
 /// Fetch models with their capabilities (vision/text)
-pub fn fetch_ollama_models_with_caps(base_url: &str) -> Result<Vec<OllamaModelWithCaps>> {
-    let models = fetch_ollama_models(base_url)?;
+pub fn fetch_ollama_models_with_caps(config: &Config, base_url: &str) -> Result<Vec<OllamaModelWithCaps>> {
+    let models = fetch_ollama_models(config, base_url)?;
     
     let mut result = Vec::new();
     for model in models {
-        let has_vision = check_model_has_vision(base_url, &model.name);
+        let has_vision = check_model_has_vision(config, base_url, &model.name);
         result.push(OllamaModelWithCaps {
             name: model.name,
             has_vision,
@@ -136,17 +155,18 @@ pub fn fetch_ollama_models_with_caps(base_url: &str) -> Result<Vec<OllamaModelWi
 
 /// Generate text with Ollama (text-only, no image)
 pub fn ollama_generate_text<F>(
-    base_url: &str,
+    config: &Config,
     model: &str,
     prompt: &str,
     streaming_enabled: bool,
     ui_language: &str,
+    stop_signal: &AtomicBool,
     mut on_chunk: F,
 ) -> Result<String>
 where
     F: FnMut(&str),
 {
-    let url = format!("{}/api/generate", base_url.trim_end_matches('/'));
+    let url = format!("{}/api/generate", config.ollama_base_url.trim_end_matches('/'));
     
     let payload = serde_json::json!({
         "model": model,
@@ -154,80 +174,37 @@ where
         "stream": streaming_enabled
     });
     
-    let resp = UREQ_AGENT.post(&url)
+    let resp = agent_for(config, "ollama").post(&url)
         
                 .send_json(&payload)
         .map_err(|e| anyhow::anyhow!("Ollama API Error: {}", e))?;
     
-    let mut full_content = String::new();
-    
     if streaming_enabled {
-        let reader = BufReader::new(resp.into_body().into_reader());
-        let mut thinking_shown = false;
-        let mut content_started = false;
-        let locale = LocaleText::get(ui_language);
-        
-        for line in reader.lines() {
-            let line = line?;
-            if line.is_empty() { continue; }
-            
-            match serde_json::from_str::<OllamaStreamChunk>(&line) {
-                Ok(chunk) => {
-                    // Handle thinking tokens (qwen3 and similar models)
-                    if let Some(thinking) = &chunk.thinking {
-                        if !thinking.is_empty() && !thinking_shown && !content_started {
-                            on_chunk(locale.model_thinking);
-                            thinking_shown = true;
-                        }
-                    }
-                    
-                    // Handle response content
-                    if !chunk.response.is_empty() {
-                        if !content_started && thinking_shown {
-                            // Wipe thinking message on first content
-                            content_started = true;
-                            full_content.push_str(&chunk.response);
-                            let wipe_content = format!("{}{}", crate::api::WIPE_SIGNAL, full_content);
-                            on_chunk(&wipe_content);
-                        } else {
-                            content_started = true;
-                            full_content.push_str(&chunk.response);
-                            on_chunk(&chunk.response);
-                        }
-                    }
-                    
-                    if chunk.done {
-                        break;
-                    }
-                }
-                Err(_) => continue,
-            }
-        }
-    } else {
-        let ollama_resp: OllamaGenerateResponse = resp.into_body().read_json()
-            .map_err(|e| anyhow::anyhow!("Failed to parse Ollama response: {}", e))?;
-        
-        full_content = ollama_resp.response;
-        on_chunk(&full_content);
+        return read_stream(resp, model, ui_language, stop_signal, on_chunk);
     }
-    
-    Ok(full_content)
+
+    let ollama_resp: OllamaGenerateResponse = resp.into_body().read_json()
+        .map_err(|e| anyhow::anyhow!("Failed to parse Ollama response: {}", e))?;
+    record_usage(model, ollama_resp.eval_count, ollama_resp.eval_duration);
+    on_chunk(&ollama_resp.response);
+    Ok(ollama_resp.response)
 }
 
 /// Generate with Ollama vision model (image + text)
 pub fn ollama_generate_vision<F>(
-    base_url: &str,
+    config: &Config,
     model: &str,
     prompt: &str,
     image: ImageBuffer<Rgba<u8>, Vec<u8>>,
     streaming_enabled: bool,
     ui_language: &str,
+    stop_signal: &AtomicBool,
     mut on_chunk: F,
 ) -> Result<String>
 where
     F: FnMut(&str),
 {
-    let url = format!("{}/api/generate", base_url.trim_end_matches('/'));
+    let url = format!("{}/api/generate", config.ollama_base_url.trim_end_matches('/'));
     
     // Encode image as base64 PNG
     let mut image_data = Vec::new();
@@ -241,61 +218,98 @@ where
         "stream": streaming_enabled
     });
     
-    let resp = UREQ_AGENT.post(&url)
+    let resp = agent_for(config, "ollama").post(&url)
         
                 .send_json(&payload)
         .map_err(|e| anyhow::anyhow!("Ollama Vision API Error: {}", e))?;
     
-    let mut full_content = String::new();
-    
     if streaming_enabled {
-        let reader = BufReader::new(resp.into_body().into_reader());
-        let mut thinking_shown = false;
-        let mut content_started = false;
-        let locale = LocaleText::get(ui_language);
-        
-        for line in reader.lines() {
-            let line = line?;
-            if line.is_empty() { continue; }
-            
-            match serde_json::from_str::<OllamaStreamChunk>(&line) {
-                Ok(chunk) => {
-                    // Handle thinking tokens
-                    if let Some(thinking) = &chunk.thinking {
-                        if !thinking.is_empty() && !thinking_shown && !content_started {
-                            on_chunk(locale.model_thinking);
-                            thinking_shown = true;
-                        }
-                    }
-                    
-                    // Handle response content
-                    if !chunk.response.is_empty() {
-                        if !content_started && thinking_shown {
-                            content_started = true;
-                            full_content.push_str(&chunk.response);
-                            let wipe_content = format!("{}{}", crate::api::WIPE_SIGNAL, full_content);
-                            on_chunk(&wipe_content);
-                        } else {
-                            content_started = true;
-                            full_content.push_str(&chunk.response);
-                            on_chunk(&chunk.response);
-                        }
-                    }
-                    
-                    if chunk.done {
-                        break;
-                    }
-                }
-                Err(_) => continue,
+        return read_stream(resp, model, ui_language, stop_signal, on_chunk);
+    }
+
+    let ollama_resp: OllamaGenerateResponse = resp.into_body().read_json()
+        .map_err(|e| anyhow::anyhow!("Failed to parse Ollama response: {}", e))?;
+    record_usage(model, ollama_resp.eval_count, ollama_resp.eval_duration);
+    on_chunk(&ollama_resp.response);
+    Ok(ollama_resp.response)
+}
+
+/// Consume a `stream: true` NDJSON response, passing each token to `on_chunk`.
+/// Thinking models first show the "thinking" notice, which the first content token
+/// wipes (`WIPE_SIGNAL`). Stops early when `stop_signal` is set.
+fn read_stream<F>(
+    resp: ureq::http::Response<ureq::Body>,
+    model: &str,
+    ui_language: &str,
+    stop_signal: &AtomicBool,
+    mut on_chunk: F,
+) -> Result<String>
+where
+    F: FnMut(&str),
+{
+    let reader = BufReader::new(resp.into_body().into_reader());
+    let locale = LocaleText::get(ui_language);
+    let mut full_content = String::new();
+    let mut thinking_shown = false;
+    let mut content_started = false;
+
+    for line in reader.lines() {
+        if stop_signal.load(Ordering::Relaxed) {
+            // Dropping the reader closes the connection, which makes Ollama stop generating
+            break;
+        }
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+        let Ok(chunk) = serde_json::from_str::<OllamaStreamChunk>(&line) else {
+            continue;
+        };
+
+        if let Some(error) = chunk.error {
+            return Err(anyhow::anyhow!("Ollama API Error: {}", error));
+        }
+
+        // Thinking tokens (qwen3 and similar models)
+        if let Some(thinking) = &chunk.thinking {
+            if !thinking.is_empty() && !thinking_shown && !content_started {
+                on_chunk(locale.model_thinking);
+                thinking_shown = true;
             }
         }
-    } else {
-        let ollama_resp: OllamaGenerateResponse = resp.into_body().read_json()
-            .map_err(|e| anyhow::anyhow!("Failed to parse Ollama response: {}", e))?;
-        
-        full_content = ollama_resp.response;
-        on_chunk(&full_content);
+
+        if !chunk.response.is_empty() {
+            full_content.push_str(&chunk.response);
+            if !content_started && thinking_shown {
+                // Replace the thinking notice with the content so far
+                on_chunk(&format!("{}{}", crate::api::WIPE_SIGNAL, full_content));
+            } else {
+                on_chunk(&chunk.response);
+            }
+            content_started = true;
+        }
+
+        if chunk.done {
+            record_usage(model, chunk.eval_count, chunk.eval_duration);
+            break;
+        }
     }
-    
+
     Ok(full_content)
 }
+
+/// Show the last generation's token count (and speed) in the usage statistics
+fn record_usage(model: &str, eval_count: Option<u64>, eval_duration: Option<u64>) {
+    let Some(tokens) = eval_count else {
+        return;
+    };
+    let usage_str = match eval_duration {
+        Some(nanos) if nanos > 0 => format!(
+            "{} tokens ({:.1} tok/s)",
+            tokens,
+            tokens as f64 / (nanos as f64 / 1e9)
+        ),
+        _ => format!("{} tokens", tokens),
+    };
+    record_model_usage(model.to_string(), usage_str);
+}
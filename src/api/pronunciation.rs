@@ -0,0 +1,140 @@
+//! Pronunciation scoring
+//!
+//! The transcription of a recording is lined up word by word against the phrase
+//! that was meant to be said. A word the transcriber heard differently was most
+//! likely mispronounced, and how many of its letters changed gives its score.
+
+/// One word of the alignment
+pub struct WordScore {
+    /// Word of the target phrase, as written there; `None` for an extra word
+    pub target: Option<String>,
+    /// What was heard in its place; `None` if the word was left out
+    pub heard: Option<String>,
+    /// 0-100: 100 when heard exactly, 0 when left out (or extra)
+    pub score: u8,
+}
+
+/// Line `heard` up against `target` and score every word
+pub fn score_words(target: &str, heard: &str) -> Vec<WordScore> {
+    let target_words = split_words(target);
+    let heard_words = split_words(heard);
+    let (n, m) = (target_words.len(), heard_words.len());
+    let target_keys: Vec<Vec<char>> = target_words.iter().map(|w| normalize(w)).collect();
+    let heard_keys: Vec<Vec<char>> = heard_words.iter().map(|w| normalize(w)).collect();
+
+    // Edit distance over words: a substitution costs what the two words differ by,
+    // leaving a word out or adding one costs a whole word. Unrelated words cost a
+    // bit more than that, so a skipped word doesn't shift every word after it.
+    let mut cost = vec![vec![0.0f32; m + 1]; n + 1];
+    for (i, row) in cost.iter_mut().enumerate() {
+        row[0] = i as f32;
+    }
+    for j in 0..=m {
+        cost[0][j] = j as f32;
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            let substitute =
+                cost[i - 1][j - 1] + substitution_cost(&target_keys[i - 1], &heard_keys[j - 1]);
+            let missed = cost[i - 1][j] + 1.0;
+            let extra = cost[i][j - 1] + 1.0;
+            cost[i][j] = substitute.min(missed).min(extra);
+        }
+    }
+
+    // Walk back from the end to recover the alignment
+    let mut words = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 {
+            let (target_key, heard_key) = (&target_keys[i - 1], &heard_keys[j - 1]);
+            let sim = similarity(target_key, heard_key);
+            let substitute = cost[i - 1][j - 1] + substitution_cost(target_key, heard_key);
+            if (cost[i][j] - substitute).abs() < 1e-4 {
+                words.push(WordScore {
+                    target: Some(target_words[i - 1].to_string()),
+                    heard: Some(heard_words[j - 1].to_string()),
+                    score: (sim * 100.0).round() as u8,
+                });
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+        if i > 0 && (j == 0 || (cost[i][j] - (cost[i - 1][j] + 1.0)).abs() < 1e-4) {
+            words.push(WordScore {
+                target: Some(target_words[i - 1].to_string()),
+                heard: None,
+                score: 0,
+            });
+            i -= 1;
+        } else {
+            words.push(WordScore {
+                target: None,
+                heard: Some(heard_words[j - 1].to_string()),
+                score: 0,
+            });
+            j -= 1;
+        }
+    }
+    words.reverse();
+    words
+}
+
+/// Score of the whole phrase: the average over its words (extra words don't count)
+pub fn overall_score(words: &[WordScore]) -> u8 {
+    let scores: Vec<u32> = words
+        .iter()
+        .filter(|w| w.target.is_some())
+        .map(|w| w.score as u32)
+        .collect();
+    if scores.is_empty() {
+        return 0;
+    }
+    (scores.iter().sum::<u32>() as f32 / scores.len() as f32).round() as u8
+}
+
+/// Words of a phrase. Scripts written without spaces (Chinese, Japanese) have each
+/// character scored on its own.
+fn split_words(text: &str) -> Vec<&str> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let unspaced = words.len() == 1 && !text.chars().any(|c| c.is_ascii_alphabetic());
+    if !unspaced {
+        return words;
+    }
+    text.trim()
+        .char_indices()
+        .map(|(i, c)| &text.trim()[i..i + c.len_utf8()])
+        .filter(|s| s.chars().any(|c| c.is_alphanumeric()))
+        .collect()
+}
+
+/// Lowercase letters and digits of a word, for comparing
+fn normalize(word: &str) -> Vec<char> {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn substitution_cost(a: &[char], b: &[char]) -> f32 {
+    (1.0 - similarity(a, b)) * 1.2
+}
+
+/// 1.0 for the same word, down to 0.0 when no letter lines up
+fn similarity(a: &[char], b: &[char]) -> f32 {
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = previous[j] + (ca != cb) as usize;
+            current[j + 1] = substitute.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    1.0 - previous[b.len()] as f32 / longest as f32
+}
@@ -0,0 +1,205 @@
+//! Structured (JSON) answers
+//!
+//! Providers with a native JSON mode get it switched on in the request: Gemini takes
+//! the schema itself (`responseSchema`), OpenAI-style APIs get `json_object` mode.
+//! Every JSON request also carries the schema in the prompt, and the answer is parsed
+//! leniently and checked here, since no mode guarantees the schema on its own.
+
+use serde_json::{Map, Value};
+
+use crate::config::ResponseFormat;
+
+/// Prompt suffix asking for JSON (matching `schema` if there is one)
+pub fn json_instruction(schema: Option<&Value>) -> String {
+    let mut instruction =
+        "Answer with JSON only: no explanations, no markdown and no code fences.".to_string();
+    if let Some(schema) = schema {
+        instruction.push_str("\nThe JSON must match this JSON Schema:\n");
+        instruction.push_str(&serde_json::to_string_pretty(schema).unwrap_or_default());
+    }
+    instruction
+}
+
+/// Switch on `provider`'s native JSON mode in a request payload if `format` asks for
+/// JSON. Gemini requests that use tools (search grounding) can't have it and rely on
+/// the prompt instead.
+pub fn request_native_json(payload: &mut Value, provider: &str, format: &ResponseFormat) {
+    if !format.is_json() {
+        return;
+    }
+    let schema = format.schema();
+    let schema = schema.as_ref();
+    match provider {
+        "google" => {
+            if payload.get("tools").is_some() {
+                return;
+            }
+            payload["generationConfig"]["responseMimeType"] = "application/json".into();
+            if let Some(schema) = schema {
+                payload["generationConfig"]["responseSchema"] = gemini_schema(schema);
+            }
+        }
+        // json_object mode only produces objects, so a top-level array is left to the prompt
+        "groq" | "cerebras" | "openrouter" => {
+            let wants_object = schema
+                .and_then(|s| s.get("type"))
+                .is_none_or(|t| t == "object");
+            if wants_object {
+                payload["response_format"] = serde_json::json!({ "type": "json_object" });
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The part of a JSON Schema Gemini understands (its OpenAPI subset): upper-case
+/// types, `nullable` instead of a "null" type, and no other keywords
+fn gemini_schema(schema: &Value) -> Value {
+    let mut out = Map::new();
+    match schema.get("type") {
+        Some(Value::String(t)) => {
+            out.insert("type".into(), t.to_uppercase().into());
+        }
+        Some(Value::Array(types)) => {
+            let (nulls, others): (Vec<&str>, Vec<&str>) = types
+                .iter()
+                .filter_map(|t| t.as_str())
+                .partition(|t| *t == "null");
+            if let Some(t) = others.first() {
+                out.insert("type".into(), t.to_uppercase().into());
+            }
+            if !nulls.is_empty() {
+                out.insert("nullable".into(), true.into());
+            }
+        }
+        _ => {}
+    }
+    for key in ["description", "format", "nullable", "required"] {
+        if let Some(value) = schema.get(key) {
+            out.insert(key.into(), value.clone());
+        }
+    }
+    if let Some(values) = schema.get("enum").and_then(|e| e.as_array()) {
+        let values: Vec<Value> = values
+            .iter()
+            .map(|v| match v {
+                Value::String(_) => v.clone(),
+                other => other.to_string().into(),
+            })
+            .collect();
+        out.insert("enum".into(), values.into());
+    }
+    if let Some(properties) = schema.get("properties").and_then(|p| p.as_object()) {
+        let properties: Map<String, Value> = properties
+            .iter()
+            .map(|(name, sub)| (name.clone(), gemini_schema(sub)))
+            .collect();
+        out.insert("properties".into(), properties.into());
+    }
+    if let Some(items) = schema.get("items") {
+        out.insert("items".into(), gemini_schema(items));
+    }
+    Value::Object(out)
+}
+
+/// Read JSON out of a model's answer: the whole text, else the inside of a code
+/// fence, else everything from the first `{`/`[` to the last `}`/`]`
+pub fn parse_reply(text: &str) -> Option<Value> {
+    let text = text.trim();
+    if let Ok(value) = serde_json::from_str(text) {
+        return Some(value);
+    }
+
+    if let Some(start) = text.find("```") {
+        let fenced = &text[start + 3..];
+        let fenced = fenced.strip_prefix("json").unwrap_or(fenced);
+        let fenced = fenced.split("```").next().unwrap_or(fenced);
+        if let Ok(value) = serde_json::from_str(fenced.trim()) {
+            return Some(value);
+        }
+    }
+
+    let start = text.find(['{', '['])?;
+    let end = text.rfind(['}', ']'])?;
+    if end <= start {
+        return None;
+    }
+    serde_json::from_str(&text[start..=end]).ok()
+}
+
+/// Check `value` against the common JSON Schema keywords (`type`, `enum`,
+/// `required`, `properties`, `items`). The error names the first place that differs.
+pub fn validate(value: &Value, schema: &Value) -> Result<(), String> {
+    check(value, schema, "$")
+}
+
+fn check(value: &Value, schema: &Value, path: &str) -> Result<(), String> {
+    let types: Vec<&str> = match schema.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(types)) => types.iter().filter_map(|t| t.as_str()).collect(),
+        _ => Vec::new(),
+    };
+    if !types.is_empty() && !types.iter().any(|t| type_matches(value, t)) {
+        return Err(format!(
+            "{}: expected {}, got {}",
+            path,
+            types.join(" or "),
+            type_name(value)
+        ));
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(|e| e.as_array()) {
+        if !allowed.contains(value) {
+            return Err(format!("{}: {} is not an allowed value", path, value));
+        }
+    }
+
+    if let Value::Object(fields) = value {
+        let required = schema.get("required").and_then(|r| r.as_array());
+        for name in required.into_iter().flatten().filter_map(|n| n.as_str()) {
+            if !fields.contains_key(name) {
+                return Err(format!("{}: missing \"{}\"", path, name));
+            }
+        }
+        if let Some(properties) = schema.get("properties").and_then(|p| p.as_object()) {
+            for (name, sub) in properties {
+                if let Some(field) = fields.get(name) {
+                    check(field, sub, &format!("{}.{}", path, name))?;
+                }
+            }
+        }
+    }
+
+    if let (Value::Array(items), Some(item_schema)) = (value, schema.get("items")) {
+        for (i, item) in items.iter().enumerate() {
+            check(item, item_schema, &format!("{}[{}]", path, i))?;
+        }
+    }
+    Ok(())
+}
+
+fn type_matches(value: &Value, expected: &str) -> bool {
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
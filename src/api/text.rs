@@ -1,84 +1,56 @@
-use super::client::UREQ_AGENT;
+use super::client::agent_for;
+use super::structured::request_native_json;
 use super::types::{ChatCompletionResponse, StreamChunk};
+use super::usage::record_model_usage;
 use super::vision::translate_image_streaming as vision_translate_image_streaming;
-use crate::gui::locale::LocaleText;
-use crate::overlay::result::RefineContext;
-use crate::overlay::utils::get_context_quote;
-use crate::APP;
+use crate::config::{Config, ResponseFormat};
+use crate::locale::LocaleText;
 use anyhow::Result;
 use std::io::{BufRead, BufReader};
+use std::sync::atomic::{AtomicBool, Ordering};
 
+/// Run `instruction` on `text` with `model` of `provider`, using the API keys and
+/// provider settings of `config`
 pub fn translate_text_streaming<F>(
-    groq_api_key: &str,
-    gemini_api_key: &str,
+    config: &Config,
     text: String,
     instruction: String,
     model: String,
     provider: String,
     streaming_enabled: bool,
     use_json_format: bool,
+    response_format: &ResponseFormat,
     search_label: Option<String>,
     ui_language: &str,
+    stop_signal: &AtomicBool,
     mut on_chunk: F,
 ) -> Result<String>
 where
     F: FnMut(&str),
 {
-    let openrouter_api_key = crate::APP
-        .lock()
-        .ok()
-        .and_then(|app| {
-            let config = app.config.clone();
-            if config.openrouter_api_key.is_empty() {
-                None
-            } else {
-                Some(config.openrouter_api_key.clone())
-            }
-        })
-        .unwrap_or_default();
-
-    let cerebras_api_key = crate::APP
-        .lock()
-        .ok()
-        .and_then(|app| {
-            let config = app.config.clone();
-            if config.cerebras_api_key.is_empty() {
-                None
-            } else {
-                Some(config.cerebras_api_key.clone())
-            }
-        })
-        .unwrap_or_default();
+    let groq_api_key = config.api_key.as_str();
+    let gemini_api_key = config.gemini_api_key.as_str();
+    let openrouter_api_key = config.openrouter_api_key.as_str();
+    let cerebras_api_key = config.cerebras_api_key.as_str();
 
     let mut full_content = String::new();
     let prompt = format!("{}\n\n{}", instruction, text);
 
     if provider == "ollama" {
         // --- OLLAMA LOCAL API ---
-        let (ollama_base_url, ollama_text_model) = crate::APP
-            .lock()
-            .ok()
-            .map(|app| {
-                let config = app.config.clone();
-                (
-                    config.ollama_base_url.clone(),
-                    config.ollama_text_model.clone(),
-                )
-            })
-            .unwrap_or_else(|| ("http://localhost:11434".to_string(), model.clone()));
-
-        let actual_model = if ollama_text_model.is_empty() {
+        let actual_model = if config.ollama_text_model.is_empty() {
             model.clone()
         } else {
-            ollama_text_model
+            config.ollama_text_model.clone()
         };
 
         return super::ollama::ollama_generate_text(
-            &ollama_base_url,
+            config,
             &actual_model,
             &prompt,
             streaming_enabled,
             ui_language,
+            stop_signal,
             on_chunk,
         );
     } else if provider == "google-gtx" {
@@ -107,7 +79,7 @@ where
             })
             .collect::<String>();
 
-        match crate::api::realtime_audio::translate_with_google_gtx(&text, &target_lang) {
+        match super::gtx::translate_with_google_gtx(&text, &target_lang) {
             Some(translated) => {
                 on_chunk(&translated);
                 return Ok(translated);
@@ -165,8 +137,9 @@ where
                 { "google_search": {} }
             ]);
         }
+        request_native_json(&mut payload, "google", response_format);
 
-        let resp = UREQ_AGENT
+        let resp = agent_for(config, "google")
             .post(&url)
             .header("x-goog-api-key", gemini_api_key)
             .send_json(payload)
@@ -186,6 +159,9 @@ where
             let locale = LocaleText::get(ui_language);
 
             for line in reader.lines() {
+                if stop_signal.load(Ordering::Relaxed) {
+                    break;
+                }
                 let line = line.map_err(|e| anyhow::anyhow!("Failed to read line: {}", e))?;
                 if line.starts_with("data: ") {
                     let json_str = &line["data: ".len()..];
@@ -277,15 +253,16 @@ where
             return Err(anyhow::anyhow!("NO_API_KEY:cerebras"));
         }
 
-        let payload = serde_json::json!({
+        let mut payload = serde_json::json!({
             "model": model,
             "messages": [
                 { "role": "user", "content": prompt }
             ],
             "stream": streaming_enabled
         });
+        request_native_json(&mut payload, "cerebras", response_format);
 
-        let resp = UREQ_AGENT
+        let resp = agent_for(config, "cerebras")
             .post("https://api.cerebras.ai/v1/chat/completions")
             .header("Authorization", &format!("Bearer {}", cerebras_api_key))
             .header("Content-Type", "application/json")
@@ -326,9 +303,7 @@ where
 
         if remaining != "?" || limit != "?" {
             let usage_str = format!("{} / {}", remaining, limit);
-            if let Ok(mut app) = APP.lock() {
-                app.model_usage_stats.insert(model.clone(), usage_str);
-            }
+            record_model_usage(model.clone(), usage_str);
         }
 
         if streaming_enabled {
@@ -341,6 +316,9 @@ where
             let is_reasoning_model = model.contains("gpt-oss") || model.contains("zai-glm");
 
             for line in reader.lines() {
+                if stop_signal.load(Ordering::Relaxed) {
+                    break;
+                }
                 let line = line?;
                 if line.starts_with("data: ") {
                     let data = &line[6..];
@@ -412,15 +390,16 @@ where
             return Err(anyhow::anyhow!("NO_API_KEY:openrouter"));
         }
 
-        let payload = serde_json::json!({
+        let mut payload = serde_json::json!({
             "model": model,
             "messages": [
                 { "role": "user", "content": prompt }
             ],
             "stream": streaming_enabled
         });
+        request_native_json(&mut payload, "openrouter", response_format);
 
-        let resp = UREQ_AGENT
+        let resp = agent_for(config, "openrouter")
             .post("https://openrouter.ai/api/v1/chat/completions")
             .header("Authorization", &format!("Bearer {}", openrouter_api_key))
             .header("Content-Type", "application/json")
@@ -441,6 +420,9 @@ where
             let locale = LocaleText::get(ui_language);
 
             for line in reader.lines() {
+                if stop_signal.load(Ordering::Relaxed) {
+                    break;
+                }
                 let line = line?;
                 if line.starts_with("data: ") {
                     let data = &line[6..];
@@ -545,7 +527,7 @@ where
             };
             on_chunk(&search_msg);
 
-            let resp = UREQ_AGENT
+            let resp = agent_for(config, "groq")
                 .post("https://api.groq.com/openai/v1/chat/completions")
                 .header("Authorization", &format!("Bearer {}", groq_api_key))
                 .send_json(payload)
@@ -570,9 +552,7 @@ where
                     .unwrap_or("?");
                 let usage_str = format!("{} / {}", remaining, limit);
 
-                if let Ok(mut app) = APP.lock() {
-                    app.model_usage_stats.insert(model.clone(), usage_str);
-                }
+                record_model_usage(model.clone(), usage_str);
             }
 
             let json: serde_json::Value = resp
@@ -747,7 +727,7 @@ where
             }
         } else {
             // --- STANDARD GROQ API ---
-            let payload = if streaming_enabled {
+            let mut payload = if streaming_enabled {
                 serde_json::json!({
                     "model": model,
                     "messages": [
@@ -770,8 +750,9 @@ where
 
                 payload_obj
             };
+            request_native_json(&mut payload, "groq", response_format);
 
-            let resp = UREQ_AGENT
+            let resp = agent_for(config, "groq")
                 .post("https://api.groq.com/openai/v1/chat/completions")
                 .header("Authorization", &format!("Bearer {}", groq_api_key))
                 .send_json(payload)
@@ -796,15 +777,16 @@ where
                     .unwrap_or("?");
                 let usage_str = format!("{} / {}", remaining, limit);
 
-                if let Ok(mut app) = APP.lock() {
-                    app.model_usage_stats.insert(model.clone(), usage_str);
-                }
+                record_model_usage(model.clone(), usage_str);
             }
 
             if streaming_enabled {
                 let reader = BufReader::new(resp.into_body().into_reader());
 
                 for line in reader.lines() {
+                    if stop_signal.load(Ordering::Relaxed) {
+                        break;
+                    }
                     let line = line?;
                     if line.starts_with("data: ") {
                         let data = &line[6..];
@@ -860,9 +842,57 @@ where
     Ok(full_content)
 }
 
+/// One-shot, non-streaming translation for automation entry points (HTTP control API, CLI).
+/// `model_id` is a `model_config` ID; the provider is taken from the model.
+/// `source_language` may be `"auto"` to let the model detect it.
+pub fn translate_text_blocking(
+    config: &Config,
+    text: &str,
+    source_language: &str,
+    target_language: &str,
+    model_id: &str,
+) -> Result<String> {
+    let model = crate::model_config::get_model_by_id(model_id)
+        .ok_or_else(|| anyhow::anyhow!("Unknown model: {}", model_id))?;
+    // Keep "Translate to X." first: the GTX provider parses the target from it
+    let instruction = if source_language.eq_ignore_ascii_case("auto") {
+        format!(
+            "Translate to {}. Output ONLY the translation.",
+            target_language
+        )
+    } else {
+        format!(
+            "Translate to {}. The source text is in {}. Output ONLY the translation.",
+            target_language, source_language
+        )
+    };
+
+    translate_text_streaming(
+        config,
+        text.to_string(),
+        instruction,
+        model.full_name,
+        model.provider,
+        false,
+        false,
+        &ResponseFormat::Text,
+        None,
+        &config.ui_language,
+        &AtomicBool::new(false),
+        |_| {},
+    )
+}
+
+/// What a result was made from, for refining it with a follow-up prompt
+#[derive(Clone)]
+pub enum RefineContext {
+    None,
+    Image(Vec<u8>), // PNG Bytes
+    Audio(Vec<u8>), // WAV Bytes
+}
+
 pub fn refine_text_streaming<F>(
-    groq_api_key: &str,
-    gemini_api_key: &str,
+    config: &Config,
     context: RefineContext,
     previous_text: String,
     user_prompt: String,
@@ -870,36 +900,16 @@ pub fn refine_text_streaming<F>(
     original_provider: &str,
     streaming_enabled: bool,
     ui_language: &str,
+    stop_signal: &AtomicBool,
     mut on_chunk: F,
 ) -> Result<String>
 where
     F: FnMut(&str),
 {
-    let openrouter_api_key = crate::APP
-        .lock()
-        .ok()
-        .and_then(|app| {
-            let config = app.config.clone();
-            if config.openrouter_api_key.is_empty() {
-                None
-            } else {
-                Some(config.openrouter_api_key.clone())
-            }
-        })
-        .unwrap_or_default();
-
-    let cerebras_api_key = crate::APP
-        .lock()
-        .ok()
-        .and_then(|app| {
-            let config = app.config.clone();
-            if config.cerebras_api_key.is_empty() {
-                None
-            } else {
-                Some(config.cerebras_api_key.clone())
-            }
-        })
-        .unwrap_or_default();
+    let groq_api_key = config.api_key.as_str();
+    let gemini_api_key = config.gemini_api_key.as_str();
+    let openrouter_api_key = config.openrouter_api_key.as_str();
+    let cerebras_api_key = config.cerebras_api_key.as_str();
 
     let final_prompt = format!(
         "Content:\n{}\n\nInstruction:\n{}\n\nOutput ONLY the result.",
@@ -982,7 +992,7 @@ where
                 ]);
             }
 
-            let resp = UREQ_AGENT
+            let resp = agent_for(config, "google")
                 .post(&url)
                 .header("x-goog-api-key", gemini_api_key)
                 .send_json(payload)
@@ -995,6 +1005,9 @@ where
                 let locale = LocaleText::get(ui_language);
 
                 for line in reader.lines() {
+                    if stop_signal.load(Ordering::Relaxed) {
+                        break;
+                    }
                     let line = line?;
                     if line.starts_with("data: ") {
                         let json_str = &line["data: ".len()..];
@@ -1085,7 +1098,7 @@ where
                 "stream": streaming_enabled
             });
 
-            let resp = UREQ_AGENT
+            let resp = agent_for(config, "cerebras")
                 .post("https://api.cerebras.ai/v1/chat/completions")
                 .header("Authorization", &format!("Bearer {}", cerebras_api_key))
                 .header("Content-Type", "application/json")
@@ -1119,9 +1132,7 @@ where
 
             if remaining != "?" || limit != "?" {
                 let usage_str = format!("{} / {}", remaining, limit);
-                if let Ok(mut app) = APP.lock() {
-                    app.model_usage_stats.insert(p_model.clone(), usage_str);
-                }
+                record_model_usage(p_model.clone(), usage_str);
             }
 
             if streaming_enabled {
@@ -1133,6 +1144,9 @@ where
                 let is_reasoning_model = p_model.contains("gpt-oss") || p_model.contains("zai-glm");
 
                 for line in reader.lines() {
+                    if stop_signal.load(Ordering::Relaxed) {
+                        break;
+                    }
                     let line = line?;
                     if line.starts_with("data: ") {
                         let data = &line[6..];
@@ -1204,7 +1218,7 @@ where
                 "stream": streaming_enabled
             });
 
-            let resp = UREQ_AGENT
+            let resp = agent_for(config, "openrouter")
                 .post("https://openrouter.ai/api/v1/chat/completions")
                 .header("Authorization", &format!("Bearer {}", openrouter_api_key))
                 .header("Content-Type", "application/json")
@@ -1218,6 +1232,9 @@ where
                 let locale = LocaleText::get(ui_language);
 
                 for line in reader.lines() {
+                    if stop_signal.load(Ordering::Relaxed) {
+                        break;
+                    }
                     let line = line?;
                     if line.starts_with("data: ") {
                         let data = &line[6..];
@@ -1306,7 +1323,7 @@ where
                     context_quote, locale.search_doing, locale.search_searching
                 ));
 
-                let resp = UREQ_AGENT
+                let resp = agent_for(config, "groq")
                     .post("https://api.groq.com/openai/v1/chat/completions")
                     .header("Authorization", &format!("Bearer {}", groq_api_key))
                     .send_json(payload)
@@ -1323,9 +1340,7 @@ where
                         .and_then(|v| v.to_str().ok())
                         .unwrap_or("?");
                     let usage_str = format!("{} / {}", remaining, limit);
-                    if let Ok(mut app) = APP.lock() {
-                        app.model_usage_stats.insert(p_model.clone(), usage_str);
-                    }
+                    record_model_usage(p_model.clone(), usage_str);
                 }
 
                 let json: serde_json::Value = resp.into_body().read_json()?;
@@ -1450,7 +1465,7 @@ where
                     "stream": streaming_enabled
                 });
 
-                let resp = UREQ_AGENT
+                let resp = agent_for(config, "groq")
                     .post("https://api.groq.com/openai/v1/chat/completions")
                     .header("Authorization", &format!("Bearer {}", groq_api_key))
                     .send_json(payload)
@@ -1467,14 +1482,15 @@ where
                         .and_then(|v| v.to_str().ok())
                         .unwrap_or("?");
                     let usage_str = format!("{} / {}", remaining, limit);
-                    if let Ok(mut app) = APP.lock() {
-                        app.model_usage_stats.insert(p_model.clone(), usage_str);
-                    }
+                    record_model_usage(p_model.clone(), usage_str);
                 }
 
                 if streaming_enabled {
                     let reader = BufReader::new(resp.into_body().into_reader());
                     for line in reader.lines() {
+                        if stop_signal.load(Ordering::Relaxed) {
+                            break;
+                        }
                         let line = line?;
                         if line.starts_with("data: ") {
                             let data = &line[6..];
@@ -1512,8 +1528,7 @@ where
                 }
                 let img = image::load_from_memory(&img_bytes)?.to_rgba8();
                 vision_translate_image_streaming(
-                    groq_api_key,
-                    gemini_api_key,
+                    config,
                     final_prompt,
                     target_id_or_name,
                     target_provider,
@@ -1521,6 +1536,8 @@ where
                     Some(img_bytes.clone()),
                     streaming_enabled,
                     false,
+                    &ResponseFormat::Text,
+                    stop_signal,
                     on_chunk,
                 )
             } else {
@@ -1529,8 +1546,7 @@ where
                 }
                 let img = image::load_from_memory(&img_bytes)?.to_rgba8();
                 vision_translate_image_streaming(
-                    groq_api_key,
-                    gemini_api_key,
+                    config,
                     final_prompt,
                     target_id_or_name,
                     target_provider,
@@ -1538,6 +1554,8 @@ where
                     Some(img_bytes.clone()),
                     streaming_enabled,
                     false,
+                    &ResponseFormat::Text,
+                    stop_signal,
                     on_chunk,
                 )
             }
@@ -1549,3 +1567,15 @@ where
         RefineContext::None => exec_text_only(target_id_or_name, target_provider),
     }
 }
+
+/// The last words of a prompt, quoted, for the "searching..." notes shown while a
+/// compound model works
+pub fn get_context_quote(text: &str) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let len = words.len();
+    if len > 50 {
+        format!("\"... {}\"", words[len - 50..].join(" "))
+    } else {
+        format!("\"... {}\"", words.join(" "))
+    }
+}
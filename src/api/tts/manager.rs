@@ -1,5 +1,6 @@
-use super::types::{AudioEvent, QueuedRequest, TtsRequest};
+use super::types::{AudioEvent, DocumentReading, QueuedRequest, TtsRequest, TtsSettings};
 use super::utils;
+use crate::config::Config;
 use std::collections::VecDeque;
 use std::sync::mpsc;
 use std::sync::{
@@ -19,8 +20,9 @@ pub struct TtsManager {
     /// Signal for Socket Workers
     pub work_signal: Condvar,
 
-    /// Queue for Player: (Input Channel, Window Handle, Request ID, Generation ID, IsRealtime)
-    pub playback_queue: Mutex<VecDeque<(mpsc::Receiver<AudioEvent>, isize, u64, u64, bool)>>,
+    /// Queue for Player: (Input Channel, Window Handle, Request ID, Generation ID, IsRealtime,
+    /// Playback Speed)
+    pub playback_queue: Mutex<VecDeque<(mpsc::Receiver<AudioEvent>, isize, u64, u64, bool, u32)>>,
     /// Signal for Player
     pub playback_signal: Condvar,
 
@@ -30,6 +32,16 @@ pub struct TtsManager {
     /// Flag to indicate if audio is currently playing (set by player thread)
     pub is_playing: AtomicBool,
 
+    /// Playback paused: the player outputs silence and keeps what it has buffered,
+    /// and socket workers take no new requests
+    pub paused: AtomicBool,
+
+    /// Request the player is playing now (0 = none)
+    playing_request: AtomicU64,
+
+    /// Long text being read aloud sentence by sentence
+    document: Mutex<Option<DocumentReading>>,
+
     /// Flag to shutdown the manager
     pub shutdown: AtomicBool,
 }
@@ -44,6 +56,9 @@ impl TtsManager {
             playback_signal: Condvar::new(),
             interrupt_generation: AtomicU64::new(0),
             is_playing: AtomicBool::new(false),
+            paused: AtomicBool::new(false),
+            playing_request: AtomicU64::new(0),
+            document: Mutex::new(None),
             shutdown: AtomicBool::new(false),
         }
     }
@@ -53,23 +68,42 @@ impl TtsManager {
         self._is_ready.load(Ordering::SeqCst)
     }
 
-    /// Request TTS for the given text. Appends to queue (sequential playback).
-    /// Returns the request ID.
-    pub fn speak(&self, text: &str, hwnd: isize) -> u64 {
-        self.speak_internal(text, hwnd, false)
+    /// Request TTS for the given text with `config`'s TTS settings. Appends to queue
+    /// (sequential playback). Returns the request ID.
+    pub fn speak(&self, text: &str, hwnd: isize, config: &Config) -> u64 {
+        self.speak_internal(text, hwnd, false, TtsSettings::from_config(config))
     }
 
-    /// Request TTS for realtime translation. Uses REALTIME_TTS_SPEED and auto-catchup.
+    /// Request TTS for realtime translation. Paced by `TtsHooks::realtime_speed`.
     /// Returns the request ID.
-    pub fn speak_realtime(&self, text: &str, hwnd: isize) -> u64 {
-        self.speak_internal(text, hwnd, true)
+    pub fn speak_realtime(&self, text: &str, hwnd: isize, config: &Config) -> u64 {
+        self.speak_internal(text, hwnd, true, TtsSettings::from_config(config))
     }
 
     /// Internal speak implementation
-    fn speak_internal(&self, text: &str, hwnd: isize, is_realtime: bool) -> u64 {
-        let id = REQUEST_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
+    fn speak_internal(
+        &self,
+        text: &str,
+        hwnd: isize,
+        is_realtime: bool,
+        settings: TtsSettings,
+    ) -> u64 {
         let current_gen = self.interrupt_generation.load(Ordering::SeqCst);
+        self.enqueue(text, hwnd, current_gen, is_realtime, settings)
+    }
 
+    /// Queue `text` for the socket workers and the player under `generation`.
+    /// Returns the request ID.
+    fn enqueue(
+        &self,
+        text: &str,
+        hwnd: isize,
+        generation: u64,
+        is_realtime: bool,
+        settings: TtsSettings,
+    ) -> u64 {
+        let playback_speed = settings.playback_speed;
+        let id = REQUEST_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
         let (tx, rx) = mpsc::channel();
 
         // Add to queues
@@ -82,8 +116,9 @@ impl TtsManager {
                         text: text.to_string(),
                         hwnd,
                         is_realtime,
+                        settings,
                     },
-                    generation: current_gen,
+                    generation,
                 },
                 tx,
             ));
@@ -92,7 +127,7 @@ impl TtsManager {
 
         {
             let mut pq = self.playback_queue.lock().unwrap();
-            pq.push_back((rx, hwnd, id, current_gen, is_realtime));
+            pq.push_back((rx, hwnd, id, generation, is_realtime, playback_speed));
         }
         self.playback_signal.notify_one();
 
@@ -101,64 +136,184 @@ impl TtsManager {
 
     /// Request TTS for the given text, interrupting any current speech.
     /// Clears the queue and stops current playback immediately.
-    pub fn speak_interrupt(&self, text: &str, hwnd: isize) -> u64 {
-        // Increment generation to invalidate all currently running/queued work
-        let new_gen = self.interrupt_generation.fetch_add(1, Ordering::SeqCst) + 1;
-        let id = REQUEST_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
+    pub fn speak_interrupt(&self, text: &str, hwnd: isize, config: &Config) -> u64 {
+        *self.document.lock().unwrap() = None;
+        let new_gen = self.interrupt_and_clear();
+        self.resume();
 
-        // Clear all queues
-        {
-            let mut wq = self.work_queue.lock().unwrap();
-            wq.clear();
+        // Push new request (this also wakes the player to check generation/queue)
+        self.enqueue(text, hwnd, new_gen, false, TtsSettings::from_config(config))
+    }
+
+    /// Read a long text aloud one sentence at a time, interrupting any current
+    /// speech. Sentences are fetched ahead by the socket workers and played back to
+    /// back; `next_sentence`, `previous_sentence` and `toggle_pause` control the
+    /// reading. A text of a single sentence is simply queued like `speak`.
+    /// Returns the request ID the window holds while the document is read.
+    pub fn speak_document(&self, text: &str, hwnd: isize, config: &Config) -> u64 {
+        let sentences = utils::split_sentences(text);
+        if sentences.len() <= 1 {
+            return self.speak(text, hwnd, config);
         }
-        {
-            let mut pq = self.playback_queue.lock().unwrap();
-            pq.clear(); // Drops receivers, causing senders to error and workers to reset
+
+        self.stop();
+        let request_id = REQUEST_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
+        *self.document.lock().unwrap() = Some(DocumentReading {
+            hwnd,
+            settings: TtsSettings::from_config(config),
+            request_id,
+            sentence_ids: vec![0; sentences.len()],
+            sentences,
+            current: 0,
+        });
+        self.read_document_from(0);
+        request_id
+    }
+
+    /// Drop whatever is playing or queued and queue the document from sentence `index`
+    fn read_document_from(&self, index: usize) {
+        let generation = self.interrupt_and_clear();
+        let mut document = self.document.lock().unwrap();
+        let Some(doc) = document.as_mut() else {
+            return;
+        };
+        doc.current = index;
+        for i in index..doc.sentences.len() {
+            doc.sentence_ids[i] = self.enqueue(
+                &doc.sentences[i],
+                doc.hwnd,
+                generation,
+                false,
+                doc.settings.clone(),
+            );
         }
+    }
 
-        // Push new request
-        let (tx, rx) = mpsc::channel();
+    /// Skip to the next sentence of the document being read
+    pub fn next_sentence(&self) {
+        let target = self
+            .document
+            .lock()
+            .unwrap()
+            .as_ref()
+            .filter(|doc| doc.current + 1 < doc.sentences.len())
+            .map(|doc| doc.current + 1);
+        if let Some(target) = target {
+            self.read_document_from(target);
+        }
+    }
 
-        {
-            let mut wq = self.work_queue.lock().unwrap();
-            wq.push_back((
-                QueuedRequest {
-                    req: TtsRequest {
-                        _id: id,
-                        text: text.to_string(),
-                        hwnd,
-                        is_realtime: false,
-                    },
-                    generation: new_gen,
-                },
-                tx,
-            ));
+    /// Go back to the previous sentence of the document being read (or restart the
+    /// first one)
+    pub fn previous_sentence(&self) {
+        let target = self
+            .document
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|doc| doc.current.saturating_sub(1));
+        if let Some(target) = target {
+            self.read_document_from(target);
         }
-        self.work_signal.notify_one();
+    }
 
-        {
-            let mut pq = self.playback_queue.lock().unwrap();
-            pq.push_back((rx, hwnd, id, new_gen, false));
+    /// Pause playback where it is. Queued requests stay queued; nothing is dropped.
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+    }
+
+    /// Continue paused playback
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+        // Workers idle while paused
+        self.work_signal.notify_all();
+    }
+
+    /// Pause or resume playback. Returns whether it is paused now.
+    pub fn toggle_pause(&self) -> bool {
+        if self.is_paused() {
+            self.resume();
+            false
+        } else {
+            self.pause();
+            true
         }
-        // Force notify player to wake up and check generation/queue
-        self.playback_signal.notify_one();
+    }
 
-        id
+    /// Whether playback is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
     }
 
-    /// Stop the current speech or cancel pending request
-    pub fn stop(&self) {
-        self.interrupt_generation.fetch_add(1, Ordering::SeqCst);
+    /// Whether a document is being read, for the window `hwnd` (or any window if 0)
+    pub fn is_reading_document(&self, hwnd: isize) -> bool {
+        self.document
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|doc| hwnd == 0 || doc.hwnd == hwnd)
+    }
 
-        // Clear queues
+    /// Player: `request_id` started playing, which may make it the current sentence
+    pub fn on_request_started(&self, request_id: u64) {
+        self.playing_request.store(request_id, Ordering::SeqCst);
+        if let Some(doc) = self.document.lock().unwrap().as_mut() {
+            if let Some(i) = doc.sentence_ids.iter().position(|&id| id == request_id) {
+                doc.current = i;
+            }
+        }
+    }
+
+    /// Player: `request_id` finished playing. Returns true if the next sentence of
+    /// the document follows it; after the last sentence the reading is over.
+    pub fn on_request_finished(&self, request_id: u64) -> bool {
+        let mut document = self.document.lock().unwrap();
+        let Some(doc) = document.as_ref() else {
+            return false;
+        };
+        match doc.sentence_ids.iter().position(|&id| id == request_id) {
+            Some(i) if i + 1 < doc.sentence_ids.len() => true,
+            Some(_) => {
+                *document = None;
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Player: done with `request_id`, finished or interrupted
+    pub fn on_playback_ended(&self, request_id: u64) {
+        let _ = self.playing_request.compare_exchange(
+            request_id,
+            0,
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+        );
+    }
+
+    /// Invalidate all running and queued work and empty both queues. Returns the
+    /// new generation.
+    fn interrupt_and_clear(&self) -> u64 {
+        // Increment generation to invalidate all currently running/queued work
+        let new_gen = self.interrupt_generation.fetch_add(1, Ordering::SeqCst) + 1;
         {
             let mut wq = self.work_queue.lock().unwrap();
             wq.clear();
         }
         {
             let mut pq = self.playback_queue.lock().unwrap();
-            pq.clear();
+            pq.clear(); // Drops receivers, causing senders to error and workers to reset
         }
+        new_gen
+    }
+
+    /// Stop the current speech and flush everything queued. The socket workers and
+    /// the audio device stay up for the next request.
+    pub fn stop(&self) {
+        *self.document.lock().unwrap() = None;
+        self.interrupt_and_clear();
+        self.playing_request.store(0, Ordering::SeqCst);
+        self.resume();
 
         // Wake up player to realize it should stop
         self.playback_signal.notify_all();
@@ -170,9 +325,28 @@ impl TtsManager {
         self.stop();
     }
 
-    /// Check if this request ID is currently active
-    pub fn is_speaking(&self, _request_id: u64) -> bool {
-        false
+    /// Check if this request ID is currently active: playing (or paused), waiting
+    /// to play, or a document being read
+    pub fn is_speaking(&self, request_id: u64) -> bool {
+        if request_id == 0 {
+            return false;
+        }
+        if self.playing_request.load(Ordering::SeqCst) == request_id {
+            return true;
+        }
+        let reading = self
+            .document
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|doc| doc.request_id == request_id);
+        reading
+            || self
+                .playback_queue
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|(_, _, id, _, _, _)| *id == request_id)
     }
 
     /// Check if there's any pending TTS audio (in work queue, playback queue, or currently playing)
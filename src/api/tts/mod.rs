@@ -17,19 +17,75 @@ pub mod wsola;
 // Re-export public API for backward compatibility
 pub use instance::TTS_MANAGER;
 pub use manager::TtsManager;
+pub use types::TtsSettings;
+
+use std::sync::OnceLock;
+
+/// Slowest playback speed in percent (100 = 1.0x). Speeds are time-stretched, so
+/// the pitch stays the same.
+pub const MIN_PLAYBACK_SPEED: u32 = 25;
+/// Fastest playback speed in percent
+pub const MAX_PLAYBACK_SPEED: u32 = 300;
+
+/// How speech reports back to the app that requested it. Without hooks, speech
+/// plays without notifying anyone and realtime speech plays at 1.0x.
+#[derive(Clone, Copy)]
+pub struct TtsHooks {
+    /// The first audio of the request made for window `hwnd` arrived
+    pub loading_done: fn(isize),
+    /// Speech for window `hwnd` ended, finished or interrupted
+    pub speech_ended: fn(isize),
+    /// Playback speed in percent for realtime speech, checked per audio chunk
+    pub realtime_speed: fn() -> u32,
+    /// A realtime segment finished after this many unstretched samples at this
+    /// sample rate
+    pub realtime_segment_played: fn(usize, u32),
+}
+
+impl Default for TtsHooks {
+    fn default() -> Self {
+        Self {
+            loading_done: |_| {},
+            speech_ended: |_| {},
+            realtime_speed: || 100,
+            realtime_segment_played: |_, _| {},
+        }
+    }
+}
+
+static HOOKS: OnceLock<TtsHooks> = OnceLock::new();
+
+/// Install the app's hooks. Only the first call has an effect.
+pub fn set_hooks(hooks: TtsHooks) {
+    let _ = HOOKS.set(hooks);
+}
+
+pub(crate) fn hooks() -> TtsHooks {
+    HOOKS.get().copied().unwrap_or_default()
+}
+
+/// Initialize the TTS system - call this at app startup. `output_device` is the ID
+/// of the audio device to play on (empty = system default).
+pub fn init_tts(output_device: &str) {
+    init_tts_tracked(output_device, |_| ());
+}
+
+/// `init_tts`, with each TTS thread holding `track_worker(name)` while it runs
+pub fn init_tts_tracked<G: 'static>(output_device: &str, track_worker: fn(&'static str) -> G) {
+    let output_device = (!output_device.is_empty()).then(|| output_device.to_string());
 
-/// Initialize the TTS system - call this at app startup
-pub fn init_tts() {
     // Spawn 1 Player Thread
     let manager = TTS_MANAGER.clone();
     std::thread::spawn(move || {
-        player::run_player_thread(manager);
+        let _worker = track_worker("TTS player");
+        player::run_player_thread(manager, output_device);
     });
 
     // Spawn 2 Socket Worker Threads (Parallel Fetching)
     for _ in 0..2 {
         let manager = TTS_MANAGER.clone();
         std::thread::spawn(move || {
+            let _worker = track_worker("TTS socket");
             worker::run_socket_worker(manager);
         });
     }
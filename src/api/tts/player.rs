@@ -13,12 +13,13 @@ use super::types::*;
 use super::utils::{clear_tts_loading_state, clear_tts_state};
 use super::wsola::WsolaStretcher;
 
-/// Main Player thread - consumes audio streams sequentially
-pub fn run_player_thread(manager: Arc<TtsManager>) {
+/// Main Player thread - consumes audio streams sequentially. Plays on the device
+/// `output_device` (system default if None).
+pub fn run_player_thread(manager: Arc<TtsManager>, output_device: Option<String>) {
     // Create ONE persistent audio player
     // This avoids the overhead of opening the audio device for every request
     // We pass manager to AudioPlayer so it can check interrupts
-    let audio_player = AudioPlayer::new(PLAYBACK_SAMPLE_RATE, manager.clone());
+    let audio_player = AudioPlayer::new(PLAYBACK_SAMPLE_RATE, manager.clone(), output_device);
 
     loop {
         if manager.shutdown.load(Ordering::SeqCst) {
@@ -37,11 +38,14 @@ pub fn run_player_thread(manager: Arc<TtsManager>) {
             pq.pop_front()
         };
 
-        if let Some((rx, hwnd, _req_id, generation, is_realtime)) = playback_job {
+        if let Some((rx, hwnd, req_id, generation, is_realtime, playback_speed)) = playback_job {
             let mut loading_cleared = false;
+            // Unstretched samples received, for pacing realtime speech
+            let mut job_samples = 0usize;
 
             // Mark that we're now playing audio
             manager.is_playing.store(true, Ordering::SeqCst);
+            manager.on_request_started(req_id);
 
             // Loop reading chunks from this channel
             loop {
@@ -58,28 +62,32 @@ pub fn run_player_thread(manager: Arc<TtsManager>) {
                             loading_cleared = true;
                             clear_tts_loading_state(hwnd);
                         }
-                        audio_player.play(&data, is_realtime);
+                        job_samples += data.len() / 2;
+                        audio_player.play(&data, is_realtime, playback_speed);
                     }
-                    Ok(AudioEvent::End) => {
+                    // End, or the sender disconnected
+                    Ok(AudioEvent::End) | Err(_) => {
                         // Check if we were interrupted or finished normally
                         if generation < manager.interrupt_generation.load(Ordering::SeqCst) {
                             audio_player.stop(); // Immediate cut-off
                         } else {
+                            // Play what the time-stretcher still holds
+                            audio_player.finish();
+                            if manager.on_request_finished(req_id) {
+                                // The document's next sentence follows straight on, no gap
+                                break;
+                            }
                             audio_player.drain(); // Normal finish
+                            if is_realtime {
+                                (super::hooks().realtime_segment_played)(
+                                    job_samples,
+                                    SOURCE_SAMPLE_RATE,
+                                );
+                            }
                         }
                         clear_tts_state(hwnd);
                         break; // Job done
                     }
-                    Err(_) => {
-                        // Sender disconnected
-                        if generation < manager.interrupt_generation.load(Ordering::SeqCst) {
-                            audio_player.stop();
-                        } else {
-                            audio_player.drain();
-                        }
-                        clear_tts_state(hwnd);
-                        break;
-                    }
                 }
 
                 if manager.shutdown.load(Ordering::SeqCst) {
@@ -96,6 +104,7 @@ pub fn run_player_thread(manager: Arc<TtsManager>) {
             }
 
             // Mark that we're done playing this job
+            manager.on_playback_ended(req_id);
             manager.is_playing.store(false, Ordering::SeqCst);
         }
     }
@@ -115,31 +124,17 @@ struct AudioPlayer {
 }
 
 impl AudioPlayer {
-    fn new(sample_rate: u32, manager: Arc<TtsManager>) -> Self {
+    fn new(sample_rate: u32, manager: Arc<TtsManager>, target_device_id: Option<String>) -> Self {
         let shared_buffer: Arc<Mutex<VecDeque<i16>>> = Arc::new(Mutex::new(VecDeque::new()));
         let buffer_clone = shared_buffer.clone();
         let shutdown = Arc::new(AtomicBool::new(false));
         let shutdown_clone = shutdown.clone();
 
-        // Read config for device ID
-        let target_device_id = {
-            if let Ok(app) = crate::APP.lock() {
-                let id = app.config.tts_output_device.clone();
-                if id.is_empty() {
-                    None
-                } else {
-                    Some(id)
-                }
-            } else {
-                None
-            }
-        };
-
         // Spawn a dedicated thread for WASAPI playback
         let thread = std::thread::spawn(move || {
             // Initialize COM for this thread
             if wasapi::initialize_mta().is_err() {
-                eprintln!("TTS: Failed to initialize COM");
+                log::error!("TTS: Failed to initialize COM");
                 return;
             }
 
@@ -153,7 +148,7 @@ impl AudioPlayer {
             );
 
             if let Err(e) = result {
-                eprintln!(
+                log::error!(
                     "TTS: WASAPI with exclusion failed ({}), falling back to cpal",
                     e
                 );
@@ -190,7 +185,7 @@ impl AudioPlayer {
                     manager,
                 )
             } {
-                eprintln!(
+                log::error!(
                     "TTS: WASAPI exclusion FAILED with error: {:?}. Call ended.",
                     e
                 );
@@ -252,6 +247,14 @@ impl AudioPlayer {
         let _frames_written = 0;
 
         let mut last_gen = manager.interrupt_generation.load(Ordering::SeqCst);
+        // Plays nothing while paused, keeping the buffered audio for resuming
+        let next_sample = |deck: &mut VecDeque<i16>| {
+            if manager.paused.load(Ordering::Relaxed) {
+                None
+            } else {
+                deck.pop_front()
+            }
+        };
 
         while !shutdown.load(Ordering::Relaxed) {
             let current_gen = manager.interrupt_generation.load(Ordering::SeqCst);
@@ -277,7 +280,7 @@ impl AudioPlayer {
                     );
 
                     for i in 0..available as usize {
-                        if let Some(sample) = deck.pop_front() {
+                        if let Some(sample) = next_sample(&mut deck) {
                             let s = (sample as f32) / 32768.0;
                             for c in 0..channels {
                                 out_slice[i * channels + c] = s;
@@ -296,7 +299,7 @@ impl AudioPlayer {
                         (available as usize) * channels,
                     );
                     for i in 0..available as usize {
-                        if let Some(sample) = deck.pop_front() {
+                        if let Some(sample) = next_sample(&mut deck) {
                             for c in 0..channels {
                                 out_slice[i * channels + c] = sample;
                             }
@@ -318,60 +321,15 @@ impl AudioPlayer {
         Ok(())
     }
 
-    fn play(&self, audio_data: &[u8], is_realtime: bool) {
-        // Get effective speed
+    fn play(&self, audio_data: &[u8], is_realtime: bool, playback_speed: u32) {
+        // Get effective speed: realtime speech is paced by the app, result windows
+        // read at the speed set in TTS settings
         let effective_speed = if is_realtime {
-            use crate::overlay::realtime_webview::state::{
-                COMMITTED_TRANSLATION_QUEUE, CURRENT_TTS_SPEED, REALTIME_HWND,
-                REALTIME_TTS_AUTO_SPEED, REALTIME_TTS_SPEED, WM_UPDATE_TTS_SPEED,
-            };
-
-            let base_speed = REALTIME_TTS_SPEED.load(Ordering::Relaxed);
-            let auto_enabled = REALTIME_TTS_AUTO_SPEED.load(Ordering::Relaxed);
-
-            // Auto-catchup: boost speed if queue is building up
-            let queue_len = COMMITTED_TRANSLATION_QUEUE
-                .lock()
-                .map(|q| q.len())
-                .unwrap_or(0);
-
-            let speed = if auto_enabled && queue_len > 0 {
-                // +15% per queued item, up to +60%
-                let boost = (queue_len as u32 * 15).min(60);
-                (base_speed + boost).min(200)
-            } else {
-                base_speed
-            };
-
-            // Update current speed for UI if it changed
-            let old_speed = CURRENT_TTS_SPEED.swap(speed, Ordering::Relaxed);
-            if old_speed != speed {
-                unsafe {
-                    use crate::overlay::realtime_webview::state::TRANSLATION_HWND;
-                    use windows::Win32::Foundation::{LPARAM, WPARAM};
-                    use windows::Win32::UI::WindowsAndMessaging::PostMessageW;
-                    if !std::ptr::addr_of!(REALTIME_HWND).read().is_invalid() {
-                        let _ = PostMessageW(
-                            Some(REALTIME_HWND),
-                            WM_UPDATE_TTS_SPEED,
-                            WPARAM(speed as usize),
-                            LPARAM(0),
-                        );
-                    }
-                    if !std::ptr::addr_of!(TRANSLATION_HWND).read().is_invalid() {
-                        let _ = PostMessageW(
-                            Some(TRANSLATION_HWND),
-                            WM_UPDATE_TTS_SPEED,
-                            WPARAM(speed as usize),
-                            LPARAM(0),
-                        );
-                    }
-                }
-            }
-            speed
+            (super::hooks().realtime_speed)()
         } else {
-            100 // Normal speed for non-realtime TTS
-        };
+            playback_speed
+        }
+        .clamp(super::MIN_PLAYBACK_SPEED, super::MAX_PLAYBACK_SPEED);
 
         let speed_ratio = effective_speed as f64 / 100.0;
 
@@ -386,7 +344,7 @@ impl AudioPlayer {
         }
 
         // Apply WSOLA time-stretching
-        let stretched_samples = if (speed_ratio - 1.0).abs() < 0.05 {
+        let stretched_samples = if (speed_ratio - 1.0).abs() < 0.01 {
             input_samples
         } else {
             if let Ok(mut wsola) = self.wsola.lock() {
@@ -400,8 +358,23 @@ impl AudioPlayer {
             }
         };
 
+        self.push(&stretched_samples);
+    }
+
+    /// Flush the end of a stretched stream
+    fn finish(&self) {
+        let rest = match self.wsola.lock() {
+            Ok(mut wsola) => wsola.flush(),
+            Err(_) => return,
+        };
+        if !rest.is_empty() {
+            self.push(&rest);
+        }
+    }
+
+    fn push(&self, samples: &[i16]) {
         // Upsample from 24kHz to 48kHz (duplicate each sample)
-        let output_samples: Vec<i16> = stretched_samples.iter().flat_map(|&s| [s, s]).collect();
+        let output_samples: Vec<i16> = samples.iter().flat_map(|&s| [s, s]).collect();
 
         // Add to shared buffer
         if let Ok(mut buf) = self.shared_buffer.lock() {
@@ -425,6 +398,9 @@ impl AudioPlayer {
         if let Ok(mut buf) = self.shared_buffer.lock() {
             buf.clear();
         }
+        if let Ok(mut wsola) = self.wsola.lock() {
+            wsola.reset();
+        }
     }
 }
 
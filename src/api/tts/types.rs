@@ -1,6 +1,14 @@
+use std::time::Duration;
+
+use crate::config::{Config, EdgeTtsSettings, TtsLanguageCondition, TtsMethod};
+
 /// Model for TTS (same native audio model, configured for output only)
 pub const TTS_MODEL: &str = "gemini-2.5-flash-native-audio-preview-12-2025";
 
+/// A Gemini Live socket quiet for this long ends the turn (Gemini stopped sending
+/// audio). Separate from the HTTP request timeouts, which are far longer.
+pub const TTS_IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// Output audio sample rate from Gemini (24kHz)
 pub const SOURCE_SAMPLE_RATE: u32 = 24000;
 
@@ -20,11 +28,53 @@ pub struct QueuedRequest {
     pub generation: u64,
 }
 
+/// The TTS settings a request is spoken with, taken from the config when the
+/// request is made
+#[derive(Clone)]
+pub struct TtsSettings {
+    pub method: TtsMethod,
+    pub gemini_api_key: String,
+    pub voice: String,
+    pub speed: String,
+    /// Playback speed in percent for non-realtime speech
+    pub playback_speed: u32,
+    pub language_conditions: Vec<TtsLanguageCondition>,
+    pub edge: EdgeTtsSettings,
+}
+
+impl TtsSettings {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            method: config.tts_method.clone(),
+            gemini_api_key: config.gemini_api_key.clone(),
+            voice: config.tts_voice.clone(),
+            speed: config.tts_speed.clone(),
+            playback_speed: config.tts_playback_speed,
+            language_conditions: config.tts_language_conditions.clone(),
+            edge: config.edge_tts_settings.clone(),
+        }
+    }
+}
+
+/// A long text read aloud as a queue of sentences
+pub struct DocumentReading {
+    pub hwnd: isize,
+    pub settings: TtsSettings,
+    /// ID the window holds while the document is read
+    pub request_id: u64,
+    pub sentences: Vec<String>,
+    /// Request ID of each queued sentence (0 = not queued)
+    pub sentence_ids: Vec<u64>,
+    /// Sentence playing now
+    pub current: usize,
+}
+
 /// TTS request with unique ID for cancellation
 #[derive(Clone)]
 pub struct TtsRequest {
     pub _id: u64,
     pub text: String,
     pub hwnd: isize,       // Window handle to update state when audio starts
-    pub is_realtime: bool, // True if this is from realtime translation (uses REALTIME_TTS_SPEED)
+    pub is_realtime: bool, // True if this is from realtime translation (paced by TtsHooks::realtime_speed)
+    pub settings: TtsSettings,
 }
@@ -1,39 +1,89 @@
-use windows::Win32::Foundation::HWND;
-use windows::Win32::Graphics::Gdi::InvalidateRect;
 use windows::Win32::Media::Audio::*;
 use windows::Win32::System::Com::*;
 
-use crate::overlay::result::state::WINDOW_STATES;
-
-/// Clear the TTS loading state for a window and trigger repaint
+/// Tell the window that requested speech that its audio started arriving
 pub fn clear_tts_loading_state(hwnd: isize) {
-    {
-        let mut states = WINDOW_STATES.lock().unwrap();
-        if let Some(state) = states.get_mut(&hwnd) {
-            state.tts_loading = false;
-        }
-    }
+    (super::hooks().loading_done)(hwnd);
+}
 
-    // Trigger repaint to update button appearance
-    unsafe {
-        let _ = InvalidateRect(Some(HWND(hwnd as *mut std::ffi::c_void)), None, false);
+/// Tell the window that requested speech that it ended
+pub fn clear_tts_state(hwnd: isize) {
+    // A window reading a document keeps its state until the last sentence is done
+    if super::TTS_MANAGER.is_reading_document(hwnd) {
+        return;
     }
+    (super::hooks().speech_ended)(hwnd);
 }
 
-/// Clear TTS state completely when speech ends
-pub fn clear_tts_state(hwnd: isize) {
-    {
-        let mut states = WINDOW_STATES.lock().unwrap();
-        if let Some(state) = states.get_mut(&hwnd) {
-            state.tts_loading = false;
-            state.tts_request_id = 0;
+/// Sentences shorter than this are read together with the next one
+const MIN_SENTENCE_CHARS: usize = 24;
+/// Longer sentences are split at a comma or space
+const MAX_SENTENCE_CHARS: usize = 400;
+
+/// Split a text into the sentences a document is read in: one request each, so no
+/// request gets too long and playback can skip between them. Lines always end a
+/// sentence, and very short pieces (headings, list markers) join the next one.
+pub fn split_sentences(text: &str) -> Vec<String> {
+    let mut pieces = Vec::new();
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let mut current = String::new();
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            current.push(c);
+            let ends_sentence = match c {
+                '。' | '！' | '？' => true,
+                '.' | '!' | '?' | ';' => chars.peek().is_none_or(|next| next.is_whitespace()),
+                _ => false,
+            };
+            if ends_sentence {
+                pieces.push(std::mem::take(&mut current));
+            }
         }
+        pieces.push(current);
     }
 
-    // Trigger repaint to update button appearance
-    unsafe {
-        let _ = InvalidateRect(Some(HWND(hwnd as *mut std::ffi::c_void)), None, false);
+    let mut sentences: Vec<String> = Vec::new();
+    let mut pending = String::new();
+    for piece in pieces.iter().map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        if !pending.is_empty() {
+            pending.push(' ');
+        }
+        pending.push_str(piece);
+        if pending.chars().count() >= MIN_SENTENCE_CHARS {
+            sentences.extend(split_long(&pending));
+            pending.clear();
+        }
+    }
+    if !pending.is_empty() {
+        sentences.extend(split_long(&pending));
+    }
+    sentences
+}
+
+/// Cut a sentence longer than `MAX_SENTENCE_CHARS` at the last comma (else space)
+/// before the limit
+fn split_long(sentence: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut rest = sentence;
+    while rest.chars().count() > MAX_SENTENCE_CHARS {
+        let limit = rest
+            .char_indices()
+            .nth(MAX_SENTENCE_CHARS)
+            .map_or(rest.len(), |(i, _)| i);
+        let head = &rest[..limit];
+        let cut = head
+            .rfind([',', '，', '、'])
+            .map(|i| i + head[i..].chars().next().map_or(1, char::len_utf8))
+            .or_else(|| head.rfind(' '))
+            .filter(|&i| i > 0)
+            .unwrap_or(limit);
+        parts.push(rest[..cut].trim().to_string());
+        rest = rest[cut..].trim_start();
+    }
+    if !rest.is_empty() {
+        parts.push(rest.to_string());
     }
+    parts
 }
 
 /// Detect language of text and get matching TTS instruction from config conditions
@@ -5,7 +5,7 @@ use std::net::TcpStream;
 use std::time::Duration;
 use tungstenite::WebSocket;
 
-use super::types::TTS_MODEL;
+use super::types::{TTS_IDLE_TIMEOUT, TTS_MODEL};
 
 /// Create TLS WebSocket connection to Gemini Live API for TTS
 pub fn connect_tts_websocket(api_key: &str) -> Result<WebSocket<TlsStream<TcpStream>>> {
@@ -27,8 +27,8 @@ pub fn connect_tts_websocket(api_key: &str) -> Result<WebSocket<TlsStream<TcpStr
         .ok_or_else(|| anyhow::anyhow!("Failed to resolve hostname: {}", host))?;
 
     let tcp_stream = TcpStream::connect_timeout(&addr, Duration::from_secs(10))?;
-    tcp_stream.set_read_timeout(Some(Duration::from_secs(30)))?;
-    tcp_stream.set_write_timeout(Some(Duration::from_secs(30)))?;
+    tcp_stream.set_read_timeout(Some(TTS_IDLE_TIMEOUT))?;
+    tcp_stream.set_write_timeout(Some(TTS_IDLE_TIMEOUT))?;
     tcp_stream.set_nodelay(true)?;
 
     let connector = native_tls::TlsConnector::new()?;
@@ -11,8 +11,7 @@ use super::websocket::{
     connect_tts_websocket, is_turn_complete, parse_audio_data, send_tts_setup, send_tts_text,
 };
 use crate::api::client::UREQ_AGENT;
-
-use crate::APP;
+use crate::config::TtsMethod;
 use isolang::Language;
 
 /// Socket Worker thread - fetches audio data and pipes it to the player
@@ -28,7 +27,10 @@ pub fn run_socket_worker(manager: Arc<TtsManager>) {
         // Wait for a request
         let (request, tx) = {
             let mut queue = manager.work_queue.lock().unwrap();
-            while queue.is_empty() && !manager.shutdown.load(Ordering::SeqCst) {
+            // Paused: leave the queue alone until playback resumes
+            while (queue.is_empty() || manager.paused.load(Ordering::SeqCst))
+                && !manager.shutdown.load(Ordering::SeqCst)
+            {
                 let result = manager.work_signal.wait(queue).unwrap();
                 queue = result;
             }
@@ -45,40 +47,22 @@ pub fn run_socket_worker(manager: Arc<TtsManager>) {
         }
 
         // Check TTS Method - route to alternative handlers if not Gemini
-        let tts_method = {
-            match APP.lock() {
-                Ok(app) => app.config.tts_method.clone(),
-                Err(_) => {
-                    let _ = tx.send(AudioEvent::End);
-                    continue;
-                }
-            }
-        };
+        let settings = request.req.settings.clone();
 
-        if tts_method == crate::config::TtsMethod::GoogleTranslate {
+        if settings.method == TtsMethod::GoogleTranslate {
             handle_google_tts(manager.clone(), request, tx);
             continue;
         }
 
-        if tts_method == crate::config::TtsMethod::EdgeTTS {
+        if settings.method == TtsMethod::EdgeTTS {
             handle_edge_tts(manager.clone(), request, tx);
             continue;
         }
 
-        // Get API key
-        let api_key = {
-            match APP.lock() {
-                Ok(app) => app.config.gemini_api_key.clone(),
-                Err(_) => {
-                    let _ = tx.send(AudioEvent::End);
-                    std::thread::sleep(Duration::from_secs(1));
-                    continue;
-                }
-            }
-        };
+        let api_key = settings.gemini_api_key;
 
         if api_key.trim().is_empty() {
-            eprintln!("TTS: No Gemini API key configured");
+            log::error!("TTS: No Gemini API key configured");
             let _ = tx.send(AudioEvent::End);
             clear_tts_loading_state(request.req.hwnd);
             clear_tts_state(request.req.hwnd);
@@ -91,7 +75,7 @@ pub fn run_socket_worker(manager: Arc<TtsManager>) {
         let mut socket = match socket_result {
             Ok(s) => s,
             Err(e) => {
-                eprintln!("TTS: Failed to connect: {}", e);
+                log::error!("TTS: Failed to connect: {}", e);
                 let _ = tx.send(AudioEvent::End);
                 clear_tts_loading_state(request.req.hwnd);
                 clear_tts_state(request.req.hwnd);
@@ -100,20 +84,15 @@ pub fn run_socket_worker(manager: Arc<TtsManager>) {
             }
         };
 
-        // Read config for setup
-        let (current_voice, current_speed, language_instruction) = {
-            let app = APP.lock().unwrap();
-            let voice = app.config.tts_voice.clone();
-            let conditions = app.config.tts_language_conditions.clone();
-
-            let instruction = get_language_instruction_for_text(&request.req.text, &conditions);
-
-            if request.req.is_realtime {
-                (voice, "Normal".to_string(), instruction)
-            } else {
-                (voice, app.config.tts_speed.clone(), instruction)
-            }
+        // Settings for setup
+        let current_voice = settings.voice;
+        let current_speed = if request.req.is_realtime {
+            "Normal".to_string()
+        } else {
+            settings.speed
         };
+        let language_instruction =
+            get_language_instruction_for_text(&request.req.text, &settings.language_conditions);
 
         // Send setup
         if let Err(e) = send_tts_setup(
@@ -122,7 +101,7 @@ pub fn run_socket_worker(manager: Arc<TtsManager>) {
             &current_speed,
             language_instruction.as_deref(),
         ) {
-            eprintln!("TTS: Failed to send setup: {}", e);
+            log::error!("TTS: Failed to send setup: {}", e);
             let _ = socket.close(None);
             let _ = tx.send(AudioEvent::End);
             std::thread::sleep(Duration::from_secs(2));
@@ -149,7 +128,7 @@ pub fn run_socket_worker(manager: Arc<TtsManager>) {
                         break;
                     }
                     if msg.contains("error") || msg.contains("Error") {
-                        eprintln!("TTS: Setup error: {}", msg);
+                        log::error!("TTS: Setup error: {}", msg);
                         break;
                     }
                 }
@@ -191,7 +170,7 @@ pub fn run_socket_worker(manager: Arc<TtsManager>) {
 
         // Send request text
         if let Err(e) = send_tts_text(&mut socket, &request.req.text) {
-            eprintln!("TTS: Failed to send text: {}", e);
+            log::error!("TTS: Failed to send text: {}", e);
             let _ = tx.send(AudioEvent::End);
             let _ = socket.close(None);
             continue;
@@ -240,7 +219,7 @@ pub fn run_socket_worker(manager: Arc<TtsManager>) {
                     std::thread::sleep(Duration::from_millis(5));
                 }
                 Err(e) => {
-                    eprintln!("TTS: Read error: {}", e);
+                    log::error!("TTS: Read error: {}", e);
                     let _ = tx.send(AudioEvent::End);
                     break;
                 }
@@ -368,8 +347,8 @@ fn handle_google_tts(
         let _ = tx.send(AudioEvent::Data(chunk.to_vec()));
     }
 
+    // The player resets the window's speaker button once this has played
     let _ = tx.send(AudioEvent::End);
-    clear_tts_state(request.req.hwnd);
 }
 
 fn handle_edge_tts(
@@ -383,8 +362,7 @@ fn handle_edge_tts(
 
     // Get Settings
     let (voice_name, pitch, rate) = {
-        let app = APP.lock().unwrap();
-        let settings = &app.config.edge_tts_settings;
+        let settings = &request.req.settings.edge;
 
         let lang_detect = whatlang::detect(&text);
 
@@ -615,8 +593,8 @@ fn handle_edge_tts(
         let _ = tx.send(AudioEvent::Data(chunk.to_vec()));
     }
 
+    // The player resets the window's speaker button once this has played
     let _ = tx.send(AudioEvent::End);
-    clear_tts_state(request.req.hwnd);
 }
 
 fn resample_audio(samples: &[i16], from_rate: u32, to_rate: u32) -> Vec<i16> {
@@ -15,8 +15,12 @@ pub struct WsolaStretcher {
     output_overlap: Vec<f32>,
     /// Search range for alignment (SOLA)
     search_range: usize,
-    /// Previous speed ratio (to detect changes)
-    last_speed: f64,
+    /// Start of the last frame taken from the input buffer
+    last_frame_pos: usize,
+    /// Where the speed ratio puts the next frame in the input buffer. Frames are
+    /// searched around it (not one hop past the last frame), so the alignment
+    /// can't drift the tempo, and may start before the last frame at slow speeds.
+    next_ideal_pos: f64,
 }
 
 impl WsolaStretcher {
@@ -43,65 +47,92 @@ impl WsolaStretcher {
             input_buffer: Vec::new(),
             output_overlap: Vec::new(),
             search_range: hop_size / 2, // Search +/- 50% of hop size
-            last_speed: 1.0,
+            last_frame_pos: 0,
+            next_ideal_pos: 0.0,
         }
     }
     
-    /// Find best offset using cross-correlation
-    fn find_best_offset(&self, input_pos: usize, target_hop: usize) -> usize {
+    /// Find the best start for the next frame using cross-correlation
+    fn find_best_position(&self, target_pos: usize) -> usize {
         // Strategy: We want to overlap the END of the previous frame (which is in output buffer)
         // with the BEGINNING of the new frame.
-        // We look for self-similarity in the input signal around the analysis hop.
-        
-        // Search range: [target_hop - search_range, target_hop + search_range]
-        let start = target_hop.saturating_sub(self.search_range);
-        let end = (target_hop + self.search_range).min(self.input_buffer.len().saturating_sub(self.frame_size + input_pos).saturating_sub(1));
-        
-        if start >= end {
-            return target_hop;
-        }
-
-        let mut best_offset = target_hop;
-        let mut max_corr = -1.0;
-        
-        // Use a subset of samples for correlation to save CPU
-        let compare_len = self.search_range;
-        
-        let ref_pos = input_pos + self.hop_size;
+        // The natural continuation of the previous frame (one hop into it) is the
+        // reference; the candidate around the target that looks most like it wins.
+        let compare_len = self.hop_size;
+        let ref_pos = self.last_frame_pos + self.hop_size;
         if ref_pos + compare_len > self.input_buffer.len() {
-             return target_hop;
+            return target_pos;
         }
-        
         let ref_segment = &self.input_buffer[ref_pos..ref_pos + compare_len];
 
-        for k in start..end {
-            let candidate_pos = input_pos + k;
-             if candidate_pos + compare_len > self.input_buffer.len() {
-                continue;
-            }
-            
-            let candidate = &self.input_buffer[candidate_pos..candidate_pos + compare_len];
-            
-            // Cross-correlation
+        // Search range: [target - search_range, target + search_range]
+        let start = target_pos.saturating_sub(self.search_range);
+        let end = (target_pos + self.search_range)
+            .min(self.input_buffer.len().saturating_sub(self.frame_size));
+
+        let mut best_pos = target_pos;
+        let mut max_corr = f32::MIN;
+        for pos in start..end {
+            let candidate = &self.input_buffer[pos..pos + compare_len];
+
+            // Cross-correlation, normalized by the candidate's energy so loud
+            // candidates don't win over well-aligned ones
             let mut corr = 0.0;
+            let mut energy = 0.0;
             for i in 0..compare_len {
                 corr += ref_segment[i] * candidate[i];
+                energy += candidate[i] * candidate[i];
             }
-            
+            let corr = corr / (energy + 1.0).sqrt();
+
             if corr > max_corr {
                 max_corr = corr;
-                best_offset = k;
+                best_pos = pos;
             }
         }
-        
-        best_offset
+
+        best_pos
+    }
+
+    /// Drop buffered input and output, e.g. when playback is cut off, so none of
+    /// it bleeds into the next audio
+    pub fn reset(&mut self) {
+        self.input_buffer.clear();
+        self.output_overlap.clear();
+        self.last_frame_pos = 0;
+        self.next_ideal_pos = 0.0;
+    }
+
+    /// The audio still buffered at the end of a stream, unstretched: the tail of the
+    /// last frame faded into the rest of the input. Leaves the stretcher empty.
+    pub fn flush(&mut self) -> Vec<i16> {
+        let tail_start = self.last_frame_pos + self.hop_size;
+        let rest: Vec<f32> = if self.output_overlap.is_empty() {
+            // No frame was taken yet: everything is still plain input
+            self.input_buffer.clone()
+        } else {
+            // The overlap fades the last frame out; fading its input back in
+            // (Hann halves sum to 1) restores it, then the input carries on
+            let mut rest: Vec<f32> = self.output_overlap.iter().take(self.hop_size).copied().collect();
+            for (i, s) in rest.iter_mut().enumerate() {
+                if let Some(x) = self.input_buffer.get(tail_start + i) {
+                    *s += x * self.window[i];
+                }
+            }
+            rest.extend(self.input_buffer.iter().skip(tail_start + self.hop_size));
+            rest
+        };
+        self.reset();
+        rest.iter()
+            .map(|&s| s.clamp(-32768.0, 32767.0) as i16)
+            .collect()
     }
 
     /// Time-stretch the input samples.
     /// speed_ratio > 1.0 = faster (compress time), < 1.0 = slower (expand time)
     pub fn stretch(&mut self, input: &[i16], speed_ratio: f64) -> Vec<i16> {
         // Bypass for normal speed
-        if (speed_ratio - 1.0).abs() < 0.05 || input.is_empty() {
+        if (speed_ratio - 1.0).abs() < 0.01 || input.is_empty() {
             // Flush any remaining overlap buffer
             if !self.output_overlap.is_empty() {
                 let result: Vec<i16> = self.output_overlap.drain(..)
@@ -115,12 +146,8 @@ impl WsolaStretcher {
             return input.to_vec();
         }
         
-        // Clear buffers if speed changed significantly (avoid artifacts)
-        if (speed_ratio - self.last_speed).abs() > 0.15 {
-            self.input_buffer.clear();
-            self.output_overlap.clear();
-        }
-        self.last_speed = speed_ratio;
+        // A speed change just changes the hop from here on; the buffered input and
+        // overlap carry over so the audio doesn't skip or click
         
         // Add input samples to buffer (convert to f32)
         self.input_buffer.extend(input.iter().map(|&s| s as f32));
@@ -131,14 +158,14 @@ impl WsolaStretcher {
         }
         
         // Ideal analysis hop
-        let target_analysis_hop = (self.hop_size as f64 * speed_ratio).round() as usize;
+        let ideal_hop = self.hop_size as f64 * speed_ratio;
         
         // Synthesis hop stays constant at 50% of frame size
         let synthesis_hop = self.hop_size;
         
         // Output buffer
         // We guess size based on target ratio, but it will vary slightly due to SOLA
-        let estimated_frames = self.input_buffer.len() / target_analysis_hop.max(1);
+        let estimated_frames = self.input_buffer.len() / (ideal_hop as usize).max(1);
         let mut output = vec![0.0f32; estimated_frames * synthesis_hop + self.frame_size];
         
         // Initialize output with overlap from previous call
@@ -148,33 +175,30 @@ impl WsolaStretcher {
             }
         }
         
-        let mut input_pos = 0usize;
         let mut output_pos = 0usize;
         
         loop {
-            // Ensure we have enough input for:
-            // 1. Comparison (at current pos + hop_size)
-            // 2. Next frame (at current pos + target_hop + search_range)
-            if input_pos + self.frame_size + self.search_range + target_analysis_hop > self.input_buffer.len() {
+            // Ensure we have enough input for the whole search range around the target
+            let target_pos = self.next_ideal_pos.round() as usize;
+            if target_pos + self.search_range + self.frame_size > self.input_buffer.len() {
                 break;
             }
             if output_pos + self.frame_size > output.len() {
                 output.resize(output_pos + self.frame_size * 2, 0.0);
             }
             
-            // Find best alignment offset (SOLA)
-            let actual_analysis_hop = self.find_best_offset(input_pos, target_analysis_hop);
-            
-            // Advance input by the OPTIMIZED hop
-            input_pos += actual_analysis_hop;
+            // Find best alignment (WSOLA)
+            let frame_pos = self.find_best_position(target_pos);
             
             // Apply window and overlap-add
             for i in 0..self.frame_size {
-                let in_sample = self.input_buffer[input_pos + i];
+                let in_sample = self.input_buffer[frame_pos + i];
                 let w = self.window[i];
                 output[output_pos + i] += in_sample * w;
             }
             
+            self.last_frame_pos = frame_pos;
+            self.next_ideal_pos += ideal_hop;
             output_pos += synthesis_hop;
         }
         
@@ -187,11 +211,15 @@ impl WsolaStretcher {
             self.output_overlap.extend_from_slice(&output[complete_len..]);
         }
         
-        // Remove consumed input
-        let consumed = input_pos.min(self.input_buffer.len());
+        // Remove input no later frame can reach: before the last frame and before
+        // the earliest position the next search may pick
+        let earliest_next = (self.next_ideal_pos as usize).saturating_sub(self.search_range);
+        let consumed = self.last_frame_pos.min(earliest_next).min(self.input_buffer.len());
         
         if consumed > 0 {
             self.input_buffer.drain(0..consumed);
+            self.last_frame_pos -= consumed;
+            self.next_ideal_pos -= consumed as f64;
         }
         
         // Return the complete portion as i16
@@ -0,0 +1,20 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+lazy_static! {
+    // API usage limits per model (Key: Model Full Name, Value: "Remaining / Total")
+    static ref MODEL_USAGE: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
+}
+
+/// Remember the usage reported by a model's last response
+pub fn record_model_usage(model: String, usage: String) {
+    if let Ok(mut stats) = MODEL_USAGE.lock() {
+        stats.insert(model, usage);
+    }
+}
+
+/// Snapshot of the recorded usage, for the usage statistics view
+pub fn model_usage_stats() -> HashMap<String, String> {
+    MODEL_USAGE.lock().map(|s| s.clone()).unwrap_or_default()
+}
@@ -1,15 +1,17 @@
-use super::client::UREQ_AGENT;
+use super::client::{agent_for, UREQ_AGENT};
+use super::structured::request_native_json;
 use super::types::{ChatCompletionResponse, StreamChunk};
-use crate::gui::locale::LocaleText;
-use crate::APP;
+use super::usage::record_model_usage;
+use crate::config::{Config, ResponseFormat};
+use crate::locale::LocaleText;
 use anyhow::Result;
 use base64::{engine::general_purpose, Engine as _};
 use image::{ImageBuffer, Rgba};
 use std::io::{BufRead, BufReader, Cursor};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 pub fn translate_image_streaming<F>(
-    groq_api_key: &str,
-    gemini_api_key: &str,
+    config: &Config,
     prompt: String,
     model: String,
     provider: String,
@@ -17,31 +19,33 @@ pub fn translate_image_streaming<F>(
     original_bytes: Option<Vec<u8>>, // Zero-Copy support
     streaming_enabled: bool,
     use_json_format: bool,
+    response_format: &ResponseFormat,
+    stop_signal: &AtomicBool,
     mut on_chunk: F,
 ) -> Result<String>
 where
     F: FnMut(&str),
 {
-    let openrouter_api_key = crate::APP
-        .lock()
-        .ok()
-        .and_then(|app| {
-            let config = app.config.clone();
-            if config.openrouter_api_key.is_empty() {
-                None
-            } else {
-                Some(config.openrouter_api_key.clone())
-            }
-        })
-        .unwrap_or_default();
+    let groq_api_key = config.api_key.as_str();
+    let gemini_api_key = config.gemini_api_key.as_str();
+    let openrouter_api_key = config.openrouter_api_key.as_str();
 
     let b64_image: String;
     let mut image_data = Vec::new();
     let mut mime_type = "image/png".to_string();
 
-    // Check for "Zero-Copy" path (Google provider + Original Bytes available)
-    if provider == "google" && original_bytes.is_some() {
-        println!("DEBUG: Zero-Copy optimization active for Google provider");
+    // "Zero-Copy" path: send the original bytes (possibly already compressed by the
+    // block's upload settings) when no resize is needed. Google handles large images;
+    // other cloud providers get them up to max_dim. Ollama and the QR scanner need PNG.
+    let max_dim = 2048;
+    let fits = image.width() <= max_dim && image.height() <= max_dim;
+    let zero_copy = match provider.as_str() {
+        "google" => true,
+        "ollama" | "qrserver" => false,
+        _ => fits,
+    };
+    if zero_copy && original_bytes.is_some() {
+        log::debug!("Zero-Copy optimization active for {}", provider);
         // Use original bytes directly (e.g. JPEG) - no resize, no conversion
         let bytes = original_bytes.as_ref().unwrap();
         b64_image = general_purpose::STANDARD.encode(bytes);
@@ -56,16 +60,15 @@ where
         {
             mime_type = "image/webp".to_string();
         }
-        println!("DEBUG: Detected MIME type: {}", mime_type);
+        log::debug!("Detected MIME type: {}", mime_type);
     } else {
         // Standard Processing Path (Resize + Convert to PNG)
         let mut final_image = image;
-        let max_dim = 2048;
 
         // Resize if too large (Skip for Google as they handle large images well if we fall back to this path)
         if provider != "google" && (final_image.width() > max_dim || final_image.height() > max_dim)
         {
-            println!("DEBUG: Image exceeds {}px, resizing...", max_dim);
+            log::debug!("Image exceeds {}px, resizing...", max_dim);
             let (n_w, n_h) = if final_image.width() > final_image.height() {
                 let ratio = max_dim as f32 / final_image.width() as f32;
                 (max_dim, (final_image.height() as f32 * ratio) as u32)
@@ -79,8 +82,8 @@ where
                 n_h,
                 image::imageops::FilterType::Lanczos3,
             );
-            println!(
-                "DEBUG: Resized to: {}x{}",
+            log::debug!(
+                "Resized to: {}x{}",
                 final_image.width(),
                 final_image.height()
             );
@@ -90,46 +93,33 @@ where
         b64_image = general_purpose::STANDARD.encode(&image_data);
         mime_type = "image/png".to_string();
     }
+    log::info!(
+        "Image payload: {} KB ({}, base64)",
+        b64_image.len() / 1024,
+        mime_type
+    );
 
     let mut full_content = String::new();
 
     if provider == "ollama" {
         // Ollama Local API
-        let (ollama_base_url, ollama_vision_model, ui_language) = crate::APP
-            .lock()
-            .ok()
-            .map(|app| {
-                let config = app.config.clone();
-                (
-                    config.ollama_base_url.clone(),
-                    config.ollama_vision_model.clone(),
-                    config.ui_language.clone(),
-                )
-            })
-            .unwrap_or_else(|| {
-                (
-                    "http://localhost:11434".to_string(),
-                    model.clone(),
-                    "en".to_string(),
-                )
-            });
-
-        let actual_model = if ollama_vision_model.is_empty() {
+        let actual_model = if config.ollama_vision_model.is_empty() {
             model.clone()
         } else {
-            ollama_vision_model
+            config.ollama_vision_model.clone()
         };
 
         // Reload image from PNG data
         let ollama_image = image::load_from_memory(&image_data)?.to_rgba8();
 
         return super::ollama::ollama_generate_vision(
-            &ollama_base_url,
+            config,
             &actual_model,
             &prompt,
             ollama_image,
             streaming_enabled,
-            &ui_language,
+            &config.ui_language,
+            stop_signal,
             on_chunk,
         );
     } else if provider == "qrserver" {
@@ -259,8 +249,9 @@ where
                 { "google_search": {} }
             ]);
         }
+        request_native_json(&mut payload, "google", response_format);
 
-        let resp = UREQ_AGENT
+        let resp = agent_for(config, "google")
             .post(&url)
             .header("x-goog-api-key", gemini_api_key)
             .send_json(payload)
@@ -278,15 +269,12 @@ where
             let mut thinking_shown = false;
             let mut content_started = false;
 
-            // Get UI language from config for thinking indicator
-            let ui_language = crate::APP
-                .lock()
-                .ok()
-                .map(|app| app.config.ui_language.clone())
-                .unwrap_or_else(|| "en".to_string());
-            let locale = LocaleText::get(&ui_language);
+            let locale = LocaleText::get(&config.ui_language);
 
             for line in reader.lines() {
+                if stop_signal.load(Ordering::Relaxed) {
+                    break;
+                }
                 let line = line.map_err(|e| anyhow::anyhow!("Failed to read line: {}", e))?;
                 if line.starts_with("data: ") {
                     let json_str = &line["data: ".len()..];
@@ -377,21 +365,22 @@ where
             return Err(anyhow::anyhow!("NO_API_KEY:openrouter"));
         }
 
-        let payload = serde_json::json!({
+        let mut payload = serde_json::json!({
             "model": model,
             "messages": [
                 {
                     "role": "user",
                     "content": [
                         { "type": "text", "text": prompt },
-                        { "type": "image_url", "image_url": { "url": format!("data:image/png;base64,{}", b64_image) } }
+                        { "type": "image_url", "image_url": { "url": format!("data:{};base64,{}", mime_type, b64_image) } }
                     ]
                 }
             ],
             "stream": streaming_enabled
         });
+        request_native_json(&mut payload, "openrouter", response_format);
 
-        let resp = UREQ_AGENT
+        let resp = agent_for(config, "openrouter")
             .post("https://openrouter.ai/api/v1/chat/completions")
             .header("Authorization", &format!("Bearer {}", openrouter_api_key))
             .header("Content-Type", "application/json")
@@ -410,15 +399,12 @@ where
             let mut thinking_shown = false;
             let mut content_started = false;
 
-            // Get UI language from config for thinking indicator
-            let ui_language = crate::APP
-                .lock()
-                .ok()
-                .map(|app| app.config.ui_language.clone())
-                .unwrap_or_else(|| "en".to_string());
-            let locale = LocaleText::get(&ui_language);
+            let locale = LocaleText::get(&config.ui_language);
 
             for line in reader.lines() {
+                if stop_signal.load(Ordering::Relaxed) {
+                    break;
+                }
                 let line = line?;
                 if line.starts_with("data: ") {
                     let data = &line[6..];
@@ -483,7 +469,7 @@ where
             return Err(anyhow::anyhow!("NO_API_KEY:groq"));
         }
 
-        let payload = if streaming_enabled {
+        let mut payload = if streaming_enabled {
             serde_json::json!({
                 "model": model,
                 "messages": [
@@ -491,7 +477,7 @@ where
                         "role": "user",
                         "content": [
                             { "type": "text", "text": prompt },
-                            { "type": "image_url", "image_url": { "url": format!("data:image/png;base64,{}", b64_image) } }
+                            { "type": "image_url", "image_url": { "url": format!("data:{};base64,{}", mime_type, b64_image) } }
                         ]
                     }
                 ],
@@ -507,7 +493,7 @@ where
                         "role": "user",
                         "content": [
                             { "type": "text", "text": prompt },
-                            { "type": "image_url", "image_url": { "url": format!("data:image/png;base64,{}", b64_image) } }
+                            { "type": "image_url", "image_url": { "url": format!("data:{};base64,{}", mime_type, b64_image) } }
                         ]
                     }
                 ],
@@ -518,8 +504,9 @@ where
 
             payload_obj
         };
+        request_native_json(&mut payload, "groq", response_format);
 
-        let resp = UREQ_AGENT.post("https://api.groq.com/openai/v1/chat/completions")
+        let resp = agent_for(config, "groq").post("https://api.groq.com/openai/v1/chat/completions")
             .header("Authorization", &format!("Bearer {}", groq_api_key))
             .send_json(payload)
             .map_err(|e| {
@@ -545,14 +532,15 @@ where
                 .unwrap_or("?");
             let usage_str = format!("{} / {}", remaining, limit);
 
-            if let Ok(mut app) = APP.lock() {
-                app.model_usage_stats.insert(model.clone(), usage_str);
-            }
+            record_model_usage(model.clone(), usage_str);
         }
 
         if streaming_enabled {
             let reader = BufReader::new(resp.into_body().into_reader());
             for line in reader.lines() {
+                if stop_signal.load(Ordering::Relaxed) {
+                    break;
+                }
                 let line = line?;
 
                 if line.starts_with("data: ") {
@@ -607,3 +595,35 @@ where
 
     Ok(full_content)
 }
+
+/// One-shot, non-streaming image translation (read the text in the image and
+/// translate it), the image counterpart of `text::translate_text_blocking`.
+/// `image_bytes` is an encoded image (PNG, JPEG...); `model_id` a vision `model_config` ID.
+pub fn translate_image_blocking(
+    config: &Config,
+    image_bytes: &[u8],
+    target_language: &str,
+    model_id: &str,
+) -> Result<String> {
+    let model = crate::model_config::get_model_by_id(model_id)
+        .ok_or_else(|| anyhow::anyhow!("Unknown model: {}", model_id))?;
+    let image = image::load_from_memory(image_bytes)?.to_rgba8();
+    let prompt = format!(
+        "Extract the text from this image and translate it to {}. Output ONLY the translation.",
+        target_language
+    );
+
+    translate_image_streaming(
+        config,
+        prompt,
+        model.full_name,
+        model.provider,
+        image,
+        Some(image_bytes.to_vec()),
+        false,
+        false,
+        &ResponseFormat::Text,
+        &AtomicBool::new(false),
+        |_| {},
+    )
+}
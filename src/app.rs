@@ -0,0 +1,1192 @@
+//! The tray app: settings window, hotkeys, overlays. `src/main.rs` runs it.
+
+use crate::{
+    api, cli, config, crash_handler, gui, history, http_api, logging, overlay, redact, updater,
+};
+use config::{load_config, AppHotkey, Config, ThemeMode, UI_SCALE_RANGE};
+use gui::locale::LocaleText;
+use history::HistoryManager;
+use lazy_static::lazy_static;
+use std::sync::{Arc, Mutex};
+use tray_icon::menu::{CheckMenuItem, Menu, MenuItem};
+use windows::core::*;
+use windows::Win32::Foundation::*;
+use windows::Win32::Graphics::Gdi::*;
+use windows::Win32::System::Com::CoInitialize;
+use windows::Win32::System::LibraryLoader::*;
+use windows::Win32::System::Threading::*;
+use windows::Win32::UI::Input::KeyboardAndMouse::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+// Window dimensions - Increased to accommodate two-column sidebar and longer text labels
+pub const WINDOW_WIDTH: f32 = 1230.0;
+pub const WINDOW_HEIGHT: f32 = 620.0;
+// Width when the sidebar uses the single-column "compact" layout
+pub const COMPACT_WINDOW_WIDTH: f32 = 800.0;
+
+/// Default (and minimum) window width for the given sidebar layout
+pub fn window_width_for_layout(sidebar_layout: &str) -> f32 {
+    if sidebar_layout == "compact" {
+        COMPACT_WINDOW_WIDTH
+    } else {
+        WINDOW_WIDTH
+    }
+}
+
+// Modifier Constants for Hook
+const MOD_ALT: u32 = 0x0001;
+const MOD_CONTROL: u32 = 0x0002;
+const MOD_SHIFT: u32 = 0x0004;
+const MOD_WIN: u32 = 0x0008;
+
+/// Added to a hotkey id for its Shift variant, which runs the preset with its
+/// secondary target language
+const SECONDARY_LANGUAGE_ID_OFFSET: i32 = 500;
+
+// Wrappers for thread-safe types now imported from win_types
+use crate::win_types::{SendHandle, SendHhook, SendHwnd};
+
+// Global event for inter-process restore signaling (manual-reset event)
+lazy_static! {
+    pub static ref RESTORE_EVENT: Option<SendHandle> = unsafe {
+        CreateEventW(None, true, false, w!("Global\\ScreenGoatedToolboxRestoreEvent")).ok().map(SendHandle)
+    };
+    // Global handle for the listener window (for the mouse hook to post messages to)
+    pub static ref LISTENER_HWND: Mutex<SendHwnd> = Mutex::new(SendHwnd::default());
+    // (code, modifiers) of hotkeys RegisterHotKey refused, usually because another app owns them
+    static ref UNAVAILABLE_HOTKEYS: Mutex<Vec<(u32, u32)>> = Mutex::new(Vec::new());
+    // Global handle for the mouse hook
+    pub static ref MOUSE_HOOK: Mutex<SendHhook> = Mutex::new(SendHhook::default());
+}
+
+// 1. Define a wrapper for the GDI Handle to ensure we clean it up
+pub struct GdiCapture {
+    pub hbitmap: HBITMAP,
+    pub width: i32,
+    pub height: i32,
+}
+
+// Make it safe to send between threads (Handles are process-global in Windows GDI)
+unsafe impl Send for GdiCapture {}
+unsafe impl Sync for GdiCapture {}
+
+impl Drop for GdiCapture {
+    fn drop(&mut self) {
+        unsafe {
+            if !self.hbitmap.is_invalid() {
+                let _ = DeleteObject(self.hbitmap.into());
+            }
+        }
+    }
+}
+
+pub struct AppState {
+    pub config: Config,
+    pub screenshot_handle: Option<GdiCapture>,
+    pub hotkeys_updated: bool,
+    pub registered_hotkey_ids: Vec<i32>, // Track IDs of currently registered hotkeys
+    pub history: Arc<HistoryManager>,    // NEW
+    pub last_active_window: Option<SendHwnd>, // NEW: Store window handle for auto-paste focus restoration
+    /// Preset of the last preset hotkey, for the "repeat last preset" hotkey
+    pub last_preset_index: Option<usize>,
+}
+
+lazy_static! {
+    pub static ref APP: Arc<Mutex<AppState>> = Arc::new(Mutex::new({
+        let config = load_config();
+        let history = Arc::new(HistoryManager::new(config.max_history_items));
+        AppState {
+            config,
+            screenshot_handle: None,
+            hotkeys_updated: false,
+            registered_hotkey_ids: Vec::new(),
+            history,
+            last_active_window: None, // NEW
+            last_preset_index: None,
+        }
+    }));
+}
+
+/// Enable dark mode for Win32 native menus (context menus, tray menus)
+/// This uses the undocumented SetPreferredAppMode API from uxtheme.dll
+fn enable_dark_mode_for_app() {
+    use windows::core::w;
+    use windows::Win32::System::LibraryLoader::{GetProcAddress, LoadLibraryW};
+
+    // PreferredAppMode enum values
+    const ALLOW_DARK: u32 = 1; // AllowDark mode
+
+    unsafe {
+        // Load uxtheme.dll
+        if let Ok(uxtheme) = LoadLibraryW(w!("uxtheme.dll")) {
+            // SetPreferredAppMode is at ordinal 135 (undocumented)
+            // MAKEINTRESOURCEA(135) is just the number 135 cast to PCSTR
+            let ordinal = 135u16;
+            let ordinal_ptr = ordinal as usize as *const u8;
+            let proc_name = windows::core::PCSTR::from_raw(ordinal_ptr);
+
+            if let Some(set_preferred_app_mode) = GetProcAddress(uxtheme, proc_name) {
+                // Cast to function pointer: fn(u32) -> u32
+                let func: extern "system" fn(u32) -> u32 =
+                    std::mem::transmute(set_preferred_app_mode);
+                func(ALLOW_DARK);
+            }
+        }
+    }
+}
+
+pub fn run() -> eframe::Result<()> {
+    // --- CLI MODE ---
+    // `translate ...` runs headless and exits before any GUI/tray/overlay initialization
+    if let Some(exit_code) = cli::run_from_args() {
+        std::process::exit(exit_code);
+    }
+
+    // --- INIT COM ---
+    // Essential for Tray Icon and Shell interactions, especially in Admin/Task Scheduler context.
+    unsafe {
+        let _ = CoInitialize(None);
+    }
+
+    // --- DPI AWARENESS ---
+    // Per-Monitor V2 before any window exists, so the screen capture, the selection
+    // rect and all overlays use physical pixels on mixed-DPI setups (eframe would
+    // only set it once its own window is created). Fails harmlessly if already set.
+    unsafe {
+        let _ = windows::Win32::UI::HiDpi::SetProcessDpiAwarenessContext(
+            windows::Win32::UI::HiDpi::DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2,
+        );
+    }
+
+    // --- ENABLE DARK MODE FOR NATIVE MENUS ---
+    // Uses undocumented Windows API to make context menus respect system dark theme
+    enable_dark_mode_for_app();
+
+    // --- APPLY PENDING UPDATE ---
+    if let Ok(exe_path) = std::env::current_exe() {
+        if let Some(exe_dir) = exe_path.parent() {
+            let staging_path = exe_dir.join("update_pending.exe");
+            let backup_path = exe_dir.join(updater::backup_name());
+
+            // If there's a pending update, apply it
+            if staging_path.exists() {
+                // Backup current exe under its version, for "Roll back to previous version"
+                let _ = std::fs::copy(&exe_path, &backup_path);
+                // Replace with staged exe
+                if std::fs::rename(&staging_path, &exe_path).is_ok() {
+                    // Success - cleanup temp file
+                    let _ = std::fs::remove_file("temp_download");
+                }
+            }
+
+            // --- CLEANUP OLD EXE FILES ---
+            // Older ScreenGoatedToolbox_v*.exe downloads and .exe.old backups: the two
+            // newest versions stay for "Roll back to previous version"
+            updater::prune_previous_builds(&exe_path);
+        }
+    }
+
+    // --- CRASH HANDLER ---
+    // Localized message box + crash.log in the app data dir
+    crash_handler::install();
+    // Settings mirrored outside the config follow every save
+    config::on_config_saved(overlay::utils::set_clipboard_retention);
+    if let Ok(app) = APP.lock() {
+        crash_handler::set_ui_language(&app.config.ui_language);
+        // --- LOGGING ---
+        // Leveled logs go to sgt.log in the app data dir
+        logging::init(&app.config.log_level);
+        redact::set_secrets(&app.config);
+        overlay::utils::set_clipboard_retention(&app.config);
+    }
+    log::info!("Starting v{}", env!("CARGO_PKG_VERSION"));
+
+    // Ensure the named event exists (for first instance, for second instance to signal)
+    let _ = RESTORE_EVENT.as_ref();
+
+    // Keep the handle alive for the duration of the program
+    let _single_instance_mutex = unsafe {
+        let instance = CreateMutexW(
+            None,
+            true,
+            w!("Global\\ScreenGoatedToolboxSingleInstanceMutex"),
+        );
+        if let Ok(handle) = instance {
+            let already_exists = GetLastError() == ERROR_ALREADY_EXISTS;
+            // Owning the mutex right away means the other instance is gone: it released
+            // it, or died holding it (abandoned). Otherwise it's still running.
+            let wait = WaitForSingleObject(handle, 0);
+            let owned = wait == WAIT_OBJECT_0 || wait == WAIT_ABANDONED;
+            if already_exists && !owned {
+                match existing_instance_state(handle) {
+                    InstanceState::Responding => {
+                        // Another instance is running - signal it to restore
+                        if let Some(event) = RESTORE_EVENT.as_ref() {
+                            let _ = SetEvent(event.0);
+                        }
+                        let _ = CloseHandle(handle);
+                        return Ok(());
+                    }
+                    InstanceState::NotResponding => {
+                        // Hung, not dead: a second copy would fight it over the
+                        // hotkeys and tray
+                        let locale = LocaleText::get(&APP.lock().unwrap().config.ui_language);
+                        MessageBoxW(
+                            None,
+                            &HSTRING::from(locale.instance_not_responding_msg),
+                            &HSTRING::from(locale.instance_not_responding_title),
+                            MB_OK | MB_ICONWARNING | MB_TOPMOST | MB_SETFOREGROUND,
+                        );
+                        let _ = CloseHandle(handle);
+                        return Ok(());
+                    }
+                    // Its process is gone, so the mutex is ours
+                    InstanceState::Exited => {}
+                }
+            }
+            Some(handle)
+        } else {
+            None
+        }
+    };
+
+    std::thread::spawn(|| {
+        run_hotkey_listener();
+    });
+
+    // Initialize TTS for instant speech synthesis
+    api::tts::set_hooks(api::tts::TtsHooks {
+        loading_done: overlay::result::state::tts_loading_done,
+        speech_ended: overlay::result::state::tts_speech_ended,
+        realtime_speed: overlay::realtime_webview::tts_pacing::playback_speed,
+        realtime_segment_played: overlay::realtime_webview::tts_pacing::segment_played,
+    });
+    let tts_output_device = APP.lock().unwrap().config.tts_output_device.clone();
+    api::tts::init_tts_tracked(&tts_output_device, overlay::diagnostics::track_worker);
+
+    // --- CLEAR WEBVIEW DATA IF SCHEDULED (before any WebViews are created) ---
+    {
+        let mut config = APP.lock().unwrap();
+        if config.config.clear_webview_on_startup {
+            // Clear WebView data - should succeed since no WebViews exist yet
+            overlay::clear_webview_permissions();
+            // Reset the flag
+            config.config.clear_webview_on_startup = false;
+            // Save immediately
+            config::save_config(&config.config);
+        }
+    }
+
+    // --- HTTP CONTROL API (opt-in) ---
+    if let Ok(app) = APP.lock() {
+        http_api::sync_with_config(&app.config);
+        overlay::html_components::font_manager::sync_with_config(&app.config);
+    }
+
+    // Create the hidden WebViews in the background, one at a time while the CPU is idle
+    // (with warmups disabled each overlay creates its WebView on first use instead)
+    let (disable_warmups, lazy_webviews) = {
+        let app = APP.lock().unwrap();
+        (
+            app.config.disable_warmups,
+            app.config.webview_lifecycle == config::WebViewLifecycle::Lazy,
+        )
+    };
+    // Fonts come first: they're served to every WebView and aren't a WebView themselves
+    let mut warmups = overlay::warmup::WarmupManager::new()
+        .with_start_delay(std::time::Duration::from_millis(500))
+        .register(
+            "fonts",
+            0,
+            &[],
+            overlay::html_components::font_manager::warmup_fonts,
+        );
+    if !disable_warmups {
+        warmups = warmups
+            .register(
+                "tray_popup",
+                1,
+                &["fonts"],
+                overlay::tray_popup::warmup_tray_popup,
+            )
+            .register("preset_wheel", 2, &["fonts"], overlay::preset_wheel::warmup)
+            .register("text_input", 3, &["fonts"], overlay::text_input::warmup)
+            .register(
+                "auto_copy_badge",
+                4,
+                &["fonts"],
+                overlay::auto_copy_badge::warmup,
+            )
+            .register(
+                "markdown_view",
+                5,
+                &["fonts"],
+                overlay::result::markdown_view::warmup,
+            )
+            .register(
+                "recording",
+                8,
+                &["fonts"],
+                overlay::recording::warmup_recording_overlay,
+            );
+        // Lazy mode builds these on first show and drops them on hide
+        if !lazy_webviews {
+            warmups = warmups
+                .register("prompt_dj", 6, &["fonts"], overlay::prompt_dj::warmup)
+                .register(
+                    "realtime",
+                    7,
+                    &["prompt_dj"],
+                    overlay::realtime_webview::warmup,
+                );
+        }
+    }
+    warmups.spawn();
+
+    // 1. Load config early to get theme setting and language for tray i18n
+    let initial_config = APP.lock().unwrap().config.clone();
+
+    // --- TRAY MENU SETUP (with i18n) ---
+    let tray_locale = LocaleText::get(&initial_config.ui_language);
+    let tray_menu = Menu::new();
+
+    // Favorite bubble toggle - check if any presets are favorited
+    let has_favorites = initial_config.presets.iter().any(|p| p.is_favorite);
+    let favorite_bubble_text = if has_favorites {
+        tray_locale.tray_favorite_bubble
+    } else {
+        tray_locale.tray_favorite_bubble_disabled
+    };
+    let tray_favorite_bubble_item = CheckMenuItem::with_id(
+        "1003",
+        favorite_bubble_text,
+        has_favorites, // enabled only if has favorites
+        initial_config.show_favorite_bubble && has_favorites,
+        None,
+    );
+
+    let tray_settings_item = MenuItem::with_id("1002", tray_locale.tray_settings, true, None);
+    let tray_quit_item = MenuItem::with_id("1001", tray_locale.tray_quit, true, None);
+    let _ = tray_menu.append(&tray_favorite_bubble_item);
+    let _ = tray_menu.append(&tray_settings_item);
+    let _ = tray_menu.append(&tray_quit_item);
+
+    // --- WINDOW SETUP ---
+    // The builder size is in native points (before zoom), so apply the UI scale here too
+    let ui_scale = initial_config
+        .ui_scale
+        .clamp(*UI_SCALE_RANGE.start(), *UI_SCALE_RANGE.end());
+    let window_width = window_width_for_layout(&initial_config.sidebar_layout);
+    let mut viewport_builder = eframe::egui::ViewportBuilder::default()
+        .with_inner_size([window_width * ui_scale, WINDOW_HEIGHT * ui_scale])
+        .with_min_inner_size([window_width * ui_scale, WINDOW_HEIGHT * ui_scale / 2.0])
+        .with_resizable(true)
+        .with_visible(false) // Start invisible
+        .with_transparent(false)
+        .with_decorations(true); // FIX: Start WITH decorations, opaque window
+
+    // 2. Detect System Theme
+    let system_dark = gui::utils::is_system_in_dark_mode();
+
+    // 3. Resolve Initial Theme
+    let effective_dark = match initial_config.theme_mode {
+        ThemeMode::Dark => true,
+        ThemeMode::Light => false,
+        ThemeMode::System => system_dark,
+    };
+
+    // 4. Use Effective Theme for initial icon
+    let icon_data = crate::icon_gen::get_window_icon(effective_dark);
+    viewport_builder = viewport_builder.with_icon(std::sync::Arc::new(icon_data));
+
+    // Screen reader (NVDA/Narrator) support comes from eframe's `accesskit` feature
+    let options = eframe::NativeOptions {
+        viewport: viewport_builder,
+        ..Default::default()
+    };
+
+    eframe::run_native(
+        "Screen Goated Toolbox (SGT by nganlinh4)",
+        options,
+        Box::new(move |cc| {
+            gui::configure_fonts(&cc.egui_ctx);
+
+            // Apply the saved UI scale (also scales the splash screen). Ctrl +/- zoom is
+            // disabled so the settings slider stays the single source of truth.
+            cc.egui_ctx.set_zoom_factor(ui_scale);
+            cc.egui_ctx.options_mut(|o| o.zoom_with_keyboard = false);
+
+            // Store global context for background threads
+            *gui::GUI_CONTEXT.lock().unwrap() = Some(cc.egui_ctx.clone());
+
+            // 5. Set Initial Visuals Explicitly
+            if effective_dark {
+                cc.egui_ctx.set_visuals(eframe::egui::Visuals::dark());
+            } else {
+                cc.egui_ctx.set_visuals(eframe::egui::Visuals::light());
+            }
+
+            // 6. Set Native Icon
+            gui::utils::update_window_icon_native(effective_dark);
+
+            Ok(Box::new(gui::SettingsApp::new(
+                initial_config,
+                APP.clone(),
+                tray_menu,
+                tray_settings_item,
+                tray_quit_item,
+                tray_favorite_bubble_item,
+                cc.egui_ctx.clone(),
+            )))
+        }),
+    )
+}
+
+/// Run a preset through the same path as its hotkey (used by the HTTP control API).
+/// Posts a WM_HOTKEY for the preset's first hotkey slot to the listener window.
+pub fn trigger_preset(preset_idx: usize) -> bool {
+    let hwnd = LISTENER_HWND.lock().map(|h| *h).unwrap_or_default();
+    if hwnd.is_invalid() {
+        return false;
+    }
+    // ID encoding: 1000 * preset_idx + hotkey_idx + 1 (see register_all_hotkeys)
+    let id = preset_idx * 1000 + 1;
+    unsafe { PostMessageW(Some(hwnd.0), WM_HOTKEY, WPARAM(id), LPARAM(0)).is_ok() }
+}
+
+/// Whether the last registration of `hotkey` failed (taken by another app or Windows)
+pub fn is_hotkey_unavailable(hotkey: &config::Hotkey) -> bool {
+    UNAVAILABLE_HOTKEYS
+        .lock()
+        .map(|failed| failed.contains(&(hotkey.code, hotkey.modifiers)))
+        .unwrap_or(false)
+}
+
+fn register_all_hotkeys(hwnd: HWND) {
+    let mut app = APP.lock().unwrap();
+    let presets = &app.config.presets;
+
+    let mut registered_ids = Vec::new();
+    let mut failed: Vec<(u32, u32, String)> = Vec::new();
+    for (p_idx, preset) in presets.iter().enumerate() {
+        for (h_idx, hotkey) in preset.hotkeys.iter().enumerate() {
+            // ID encoding: 1000 * preset_idx + hotkey_idx + 1
+
+            // Skip Mouse Buttons for RegisterHotKey (handled via hook)
+            if [0x04, 0x05, 0x06].contains(&hotkey.code) {
+                continue;
+            }
+
+            let id = (p_idx as i32 * 1000) + (h_idx as i32) + 1;
+            let result = unsafe {
+                RegisterHotKey(
+                    Some(hwnd),
+                    id,
+                    HOT_KEY_MODIFIERS(hotkey.modifiers),
+                    hotkey.code,
+                )
+            };
+            match result {
+                Ok(()) => registered_ids.push(id),
+                Err(e) => {
+                    log::warn!(
+                        "Hotkey {} of preset '{}' could not be registered: {}",
+                        hotkey.name,
+                        preset.name,
+                        e
+                    );
+                    failed.push((hotkey.code, hotkey.modifiers, hotkey.name.clone()));
+                }
+            }
+
+            // Same hotkey plus Shift: run with the secondary language
+            if !preset.secondary_language.trim().is_empty() && hotkey.modifiers & MOD_SHIFT == 0 {
+                let shift_id = id + SECONDARY_LANGUAGE_ID_OFFSET;
+                unsafe {
+                    let _ = RegisterHotKey(
+                        Some(hwnd),
+                        shift_id,
+                        HOT_KEY_MODIFIERS(hotkey.modifiers | MOD_SHIFT),
+                        hotkey.code,
+                    );
+                }
+                registered_ids.push(shift_id);
+            }
+        }
+    }
+
+    // App-wide hotkeys (open settings, dismiss overlays, toggle bubble)
+    for which in AppHotkey::ALL {
+        let Some(hotkey) = app.config.app_hotkey(which) else {
+            continue;
+        };
+        if [0x04, 0x05, 0x06].contains(&hotkey.code) {
+            continue;
+        }
+        let result = unsafe {
+            RegisterHotKey(
+                Some(hwnd),
+                which.id(),
+                HOT_KEY_MODIFIERS(hotkey.modifiers),
+                hotkey.code,
+            )
+        };
+        match result {
+            Ok(()) => registered_ids.push(which.id()),
+            Err(e) => {
+                log::warn!(
+                    "Hotkey {} ({}) could not be registered: {}",
+                    hotkey.name,
+                    which.name(),
+                    e
+                );
+                failed.push((hotkey.code, hotkey.modifiers, hotkey.name.clone()));
+            }
+        }
+    }
+    app.registered_hotkey_ids = registered_ids;
+    let ui_language = app.config.ui_language.clone();
+    drop(app);
+
+    // Tell the user once about hotkeys that newly stopped working; the settings
+    // window marks them for as long as they stay unavailable
+    let newly_failed: Vec<String> = {
+        let mut unavailable = UNAVAILABLE_HOTKEYS.lock().unwrap();
+        let newly_failed = failed
+            .iter()
+            .filter(|(code, mods, _)| !unavailable.contains(&(*code, *mods)))
+            .map(|(_, _, name)| name.clone())
+            .collect();
+        *unavailable = failed
+            .iter()
+            .map(|(code, mods, _)| (*code, *mods))
+            .collect();
+        newly_failed
+    };
+    if !newly_failed.is_empty() {
+        let locale = LocaleText::get(&ui_language);
+        overlay::auto_copy_badge::show_notification(
+            &locale
+                .hotkeys_unavailable_notification
+                .replace("{}", &newly_failed.join(", ")),
+        );
+    }
+}
+
+/// Display name of the preset for the hotkey toast, or None if the toast is turned off
+fn hotkey_feedback_name(preset_idx: usize) -> Option<String> {
+    let feedback = APP.lock().ok()?.config.hotkey_feedback;
+    if !feedback {
+        return None;
+    }
+    preset_display_name(preset_idx)
+}
+
+/// Name of the preset as shown in the settings: localized for built-in presets
+fn preset_display_name(preset_idx: usize) -> Option<String> {
+    let app = APP.lock().ok()?;
+    let preset = app.config.presets.get(preset_idx)?;
+    Some(if preset.id.starts_with("preset_") {
+        gui::settings_ui::get_localized_preset_name(&preset.id, &app.config.ui_language)
+    } else {
+        preset.name.clone()
+    })
+}
+
+/// Run the preset of the last preset hotkey again. Image presets open a new
+/// selection; text selection presets take the current selection, or the clipboard.
+fn repeat_last_preset() {
+    let last = APP.lock().ok().and_then(|app| {
+        let idx = app.last_preset_index?;
+        let preset = app.config.presets.get(idx)?;
+        Some((
+            idx,
+            preset.preset_type.clone(),
+            preset.text_input_mode.clone(),
+            app.config.hotkey_feedback,
+        ))
+    });
+    let Some((preset_idx, preset_type, text_mode, feedback)) = last else {
+        let ui_language = APP
+            .lock()
+            .map(|app| app.config.ui_language.clone())
+            .unwrap_or_default();
+        let text = LocaleText::get(&ui_language);
+        overlay::auto_copy_badge::show_notification(text.repeat_last_none);
+        return;
+    };
+
+    // Image presets go without: the toast would end up in the screenshot
+    let name = preset_display_name(preset_idx).unwrap_or_default();
+    if preset_type == "text" && text_mode == "select" {
+        overlay::auto_copy_badge::show_notification(&format!("▶ {}", name));
+        if let Ok(mut app) = APP.lock() {
+            app.last_active_window =
+                overlay::utils::get_target_window_for_paste().map(crate::win_types::SendHwnd);
+        }
+        std::thread::spawn(move || {
+            if !overlay::text_selection::process_selection_or_clipboard(preset_idx) {
+                overlay::show_text_selection_tag(preset_idx);
+            }
+        });
+        return;
+    }
+    // The hotkey path shows the toast itself when hotkey feedback is on
+    if preset_type != "image" && !feedback {
+        overlay::auto_copy_badge::show_notification(&format!("▶ {}", name));
+    }
+    trigger_preset(preset_idx);
+}
+
+/// Run an app-wide hotkey action
+fn run_app_hotkey(which: AppHotkey) {
+    match which {
+        AppHotkey::OpenSettings => gui::signal_restore_window(),
+        AppHotkey::DismissOverlays => overlay::dismiss_all_overlays(),
+        AppHotkey::ToggleFavoriteBubble => {
+            overlay::favorite_bubble::toggle_favorite_bubble();
+        }
+        AppHotkey::RepeatLastPreset => repeat_last_preset(),
+        AppHotkey::TranslateClipboard => overlay::quick_translate::translate_clipboard(),
+        AppHotkey::TranslateSelection => overlay::quick_translate::translate_selection(),
+        AppHotkey::UndoReplace => {
+            std::thread::spawn(overlay::text_selection::undo_last_replacement);
+        }
+    }
+}
+
+fn unregister_all_hotkeys(hwnd: HWND) {
+    let app = APP.lock().unwrap();
+    for &id in &app.registered_hotkey_ids {
+        unsafe {
+            let _ = UnregisterHotKey(Some(hwnd), id);
+        }
+    }
+}
+
+// Low-Level Mouse Hook Procedure
+unsafe extern "system" fn mouse_hook_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    if code >= 0 {
+        let msg = wparam.0 as u32;
+        let vk_code = match msg {
+            WM_MBUTTONDOWN => Some(0x04), // VK_MBUTTON
+            WM_XBUTTONDOWN => {
+                let info = *(lparam.0 as *const MSLLHOOKSTRUCT);
+                let xbutton = (info.mouseData >> 16) & 0xFFFF;
+                if xbutton == 1 {
+                    Some(0x05)
+                }
+                // VK_XBUTTON1
+                else if xbutton == 2 {
+                    Some(0x06)
+                }
+                // VK_XBUTTON2
+                else {
+                    None
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(vk) = vk_code {
+            // Check modifiers using GetAsyncKeyState for real-time state
+            let mut mods = 0;
+            if (GetAsyncKeyState(VK_MENU.0 as i32) as u16 & 0x8000) != 0 {
+                mods |= MOD_ALT;
+            }
+            if (GetAsyncKeyState(VK_CONTROL.0 as i32) as u16 & 0x8000) != 0 {
+                mods |= MOD_CONTROL;
+            }
+            if (GetAsyncKeyState(VK_SHIFT.0 as i32) as u16 & 0x8000) != 0 {
+                mods |= MOD_SHIFT;
+            }
+            if (GetAsyncKeyState(VK_LWIN.0 as i32) as u16 & 0x8000) != 0
+                || (GetAsyncKeyState(VK_RWIN.0 as i32) as u16 & 0x8000) != 0
+            {
+                mods |= MOD_WIN;
+            }
+
+            // Check config for a match
+            let mut found_id = None;
+            if let Ok(app) = APP.lock() {
+                for (p_idx, preset) in app.config.presets.iter().enumerate() {
+                    for (h_idx, hotkey) in preset.hotkeys.iter().enumerate() {
+                        if hotkey.code != vk {
+                            continue;
+                        }
+                        // Synthesize ID same as register_all_hotkeys
+                        let id = (p_idx as i32 * 1000) + (h_idx as i32) + 1;
+                        if hotkey.modifiers == mods {
+                            found_id = Some(id);
+                            break;
+                        }
+                        if !preset.secondary_language.trim().is_empty()
+                            && hotkey.modifiers & MOD_SHIFT == 0
+                            && hotkey.modifiers | MOD_SHIFT == mods
+                        {
+                            found_id = Some(id + SECONDARY_LANGUAGE_ID_OFFSET);
+                            break;
+                        }
+                    }
+                    if found_id.is_some() {
+                        break;
+                    }
+                }
+                if found_id.is_none() {
+                    found_id = AppHotkey::ALL
+                        .into_iter()
+                        .find(|&which| {
+                            app.config
+                                .app_hotkey(which)
+                                .is_some_and(|h| h.code == vk && h.modifiers == mods)
+                        })
+                        .map(AppHotkey::id);
+                }
+            }
+
+            if let Some(id) = found_id {
+                if let Ok(hwnd_target) = LISTENER_HWND.lock() {
+                    if !hwnd_target.0.is_invalid() {
+                        // Post WM_HOTKEY to the listener window logic
+                        let _ = PostMessageW(
+                            Some(hwnd_target.0),
+                            WM_HOTKEY,
+                            WPARAM(id as usize),
+                            LPARAM(0),
+                        );
+                        return LRESULT(1); // Consume/Block input
+                    }
+                }
+            }
+        }
+    }
+    CallNextHookEx(None, code, wparam, lparam)
+}
+
+const WM_RELOAD_HOTKEYS: u32 = WM_USER + 101;
+
+/// What the instance holding the single-instance mutex is doing
+enum InstanceState {
+    /// Its hotkey listener answers messages
+    Responding,
+    /// Still running but not answering
+    NotResponding,
+    /// Its process has exited; the mutex is now owned by us
+    Exited,
+}
+
+/// Check the instance holding `mutex`. Polls briefly for its hotkey listener window
+/// in case that instance is still starting up.
+unsafe fn existing_instance_state(mutex: HANDLE) -> InstanceState {
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(3);
+    let hwnd = loop {
+        if let Ok(hwnd) = FindWindowW(w!("HotkeyListenerClass"), w!("Listener")) {
+            break Some(hwnd);
+        }
+        if std::time::Instant::now() >= deadline {
+            break None;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    };
+
+    if let Some(hwnd) = hwnd {
+        let mut result = 0usize;
+        let responded = SendMessageTimeoutW(
+            hwnd,
+            WM_NULL,
+            WPARAM(0),
+            LPARAM(0),
+            SMTO_ABORTIFHUNG,
+            1000,
+            Some(&mut result),
+        );
+        if responded.0 != 0 {
+            return InstanceState::Responding;
+        }
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+        if let Ok(process) = OpenProcess(PROCESS_SYNCHRONIZE, false, pid) {
+            let exited = WaitForSingleObject(process, 0) == WAIT_OBJECT_0;
+            let _ = CloseHandle(process);
+            if !exited {
+                return InstanceState::NotResponding;
+            }
+        }
+    }
+
+    // No live window to ask: the instance is gone only if its mutex can be taken
+    let wait = WaitForSingleObject(mutex, 1000);
+    if wait == WAIT_OBJECT_0 || wait == WAIT_ABANDONED {
+        InstanceState::Exited
+    } else {
+        InstanceState::NotResponding
+    }
+}
+
+fn run_hotkey_listener() {
+    let _worker = overlay::diagnostics::track_worker("Hotkey listener");
+    unsafe {
+        // Error handling: GetModuleHandleW should not fail, but handle it
+        let instance = match GetModuleHandleW(None) {
+            Ok(h) => h,
+            Err(_) => {
+                log::error!("Failed to get module handle for hotkey listener");
+                return;
+            }
+        };
+
+        let class_name = w!("HotkeyListenerClass");
+
+        let wc = WNDCLASSW {
+            lpfnWndProc: Some(hotkey_proc),
+            hInstance: instance.into(),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+
+        // RegisterClassW can fail if class already exists, which is okay
+        let _ = RegisterClassW(&wc);
+
+        let hwnd = CreateWindowExW(
+            WINDOW_EX_STYLE::default(),
+            class_name,
+            w!("Listener"),
+            WS_OVERLAPPEDWINDOW,
+            0,
+            0,
+            0,
+            0,
+            None,
+            None,
+            Some(instance.into()),
+            None,
+        )
+        .unwrap_or_default();
+
+        // Error handling: hwnd is invalid if creation failed
+        if hwnd.is_invalid() {
+            log::error!("Failed to create hotkey listener window");
+            return;
+        }
+
+        // Store HWND for the hook
+        if let Ok(mut guard) = LISTENER_HWND.lock() {
+            *guard = SendHwnd(hwnd);
+        }
+
+        // Install Mouse Hook
+        if let Ok(hhook) =
+            SetWindowsHookExW(WH_MOUSE_LL, Some(mouse_hook_proc), Some(instance.into()), 0)
+        {
+            if let Ok(mut hook_guard) = MOUSE_HOOK.lock() {
+                *hook_guard = SendHhook(hhook);
+            }
+        } else {
+            log::warn!("Failed to install low-level mouse hook");
+        }
+
+        register_all_hotkeys(hwnd);
+
+        let mut msg = MSG::default();
+        loop {
+            if GetMessageW(&mut msg, None, 0, 0).as_bool() {
+                if msg.message == WM_RELOAD_HOTKEYS {
+                    unregister_all_hotkeys(hwnd);
+                    register_all_hotkeys(hwnd);
+
+                    if let Ok(mut app) = APP.lock() {
+                        app.hotkeys_updated = false;
+                    }
+                } else {
+                    let _ = TranslateMessage(&msg);
+                    DispatchMessageW(&msg);
+                }
+            }
+        }
+    }
+}
+
+unsafe extern "system" fn hotkey_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    match msg {
+        WM_HOTKEY => {
+            let id = wparam.0 as i32;
+            if let Some(which) = AppHotkey::from_id(id) {
+                run_app_hotkey(which);
+                return LRESULT(0);
+            }
+            if id > 0 {
+                // CRITICAL: If preset wheel is active, dismiss it and return early
+                // This allows pressing the hotkey again to dismiss the wheel
+                if overlay::preset_wheel::is_wheel_active() {
+                    overlay::preset_wheel::dismiss_wheel();
+                    return LRESULT(0);
+                }
+
+                let preset_idx = ((id - 1) / 1000) as usize;
+                if let Ok(mut app) = APP.lock() {
+                    app.last_preset_index = Some(preset_idx);
+                }
+
+                // The Shift variant of the hotkey runs with the preset's secondary language
+                let secondary_language_run = if (id - 1) % 1000 >= SECONDARY_LANGUAGE_ID_OFFSET {
+                    APP.lock()
+                        .ok()
+                        .and_then(|app| app.config.presets.get(preset_idx).map(|p| p.id.clone()))
+                } else {
+                    None
+                };
+                overlay::process::set_secondary_language_run(secondary_language_run);
+
+                // Determine context and fetch hotkey name
+                let (preset_type, text_mode, is_audio_stopping, hotkey_name) = {
+                    if let Ok(app) = APP.lock() {
+                        if preset_idx < app.config.presets.len() {
+                            let p = &app.config.presets[preset_idx];
+                            let p_type = p.preset_type.clone();
+                            let t_mode = p.text_input_mode.clone();
+                            let stopping =
+                                p_type == "audio" && overlay::is_recording_overlay_active();
+
+                            // Find the specific hotkey name that triggered this
+                            let hk_idx = ((id - 1) % 1000 % SECONDARY_LANGUAGE_ID_OFFSET) as usize;
+                            let hk_name = if hk_idx < p.hotkeys.len() {
+                                p.hotkeys[hk_idx].name.clone()
+                            } else {
+                                String::new()
+                            };
+
+                            (p_type, t_mode, stopping, hk_name)
+                        } else {
+                            (
+                                "image".to_string(),
+                                "select".to_string(),
+                                false,
+                                String::new(),
+                            )
+                        }
+                    } else {
+                        (
+                            "image".to_string(),
+                            "select".to_string(),
+                            false,
+                            String::new(),
+                        )
+                    }
+                };
+
+                // Instant confirmation, before any capture or WebView work. Not for image
+                // presets: the toast would end up in the screenshot.
+                if preset_type != "image" && !is_audio_stopping {
+                    if let Some(name) = hotkey_feedback_name(preset_idx) {
+                        overlay::auto_copy_badge::show_notification(&format!("▶ {}", name));
+                    }
+                }
+
+                // FIX: Only capture target window if we are NOT stopping an audio recording.
+                if !is_audio_stopping {
+                    let target_window = crate::overlay::utils::get_target_window_for_paste();
+
+                    if let Ok(mut app) = APP.lock() {
+                        app.last_active_window = target_window.map(crate::win_types::SendHwnd);
+                    }
+                }
+
+                if preset_type == "audio" {
+                    // Check for realtime mode
+                    let is_realtime = {
+                        if let Ok(app) = APP.lock() {
+                            if preset_idx < app.config.presets.len() {
+                                app.config.presets[preset_idx].audio_processing_mode == "realtime"
+                            } else {
+                                false
+                            }
+                        } else {
+                            false
+                        }
+                    };
+
+                    if is_realtime {
+                        // Realtime mode - toggle realtime overlay
+                        // Check if minimal or webview is active
+                        let is_minimal_active = overlay::realtime_egui::MINIMAL_ACTIVE
+                            .load(std::sync::atomic::Ordering::SeqCst);
+                        let is_webview_active = overlay::is_realtime_overlay_active();
+
+                        if is_webview_active {
+                            // WebView active - stop it (toggle off)
+                            overlay::stop_realtime_overlay();
+                        } else if is_minimal_active {
+                            // Minimal egui active - do NOT allow hotkey to close (user must use window X)
+                            // This prevents buggy behavior
+                        } else {
+                            // Nothing active - Start
+                            std::thread::spawn(move || {
+                                overlay::show_realtime_overlay(preset_idx);
+                            });
+                        }
+                    } else {
+                        // Record-then-process mode
+                        if overlay::is_recording_overlay_active() {
+                            overlay::stop_recording_and_submit();
+                        } else {
+                            std::thread::spawn(move || {
+                                overlay::show_recording_overlay(preset_idx);
+                            });
+                        }
+                    }
+                } else if preset_type == "text" {
+                    // NEW TEXT LOGIC
+                    if text_mode == "select" {
+                        // Toggle Logic for Selection
+                        if overlay::text_selection::is_active() {
+                            overlay::text_selection::cancel_selection();
+                        } else {
+                            // NEW: Try instant processing if text is already selected
+                            std::thread::spawn(move || {
+                                // First, try to process any already-selected text
+                                if !overlay::text_selection::try_instant_process(preset_idx) {
+                                    // No pre-selected text - fall back to showing selection tag
+                                    overlay::show_text_selection_tag(preset_idx);
+                                }
+                            });
+                        }
+                    } else {
+                        // Type Mode - Toggle Logic for Input Window
+                        if overlay::text_input::is_active() {
+                            overlay::text_input::cancel_input();
+                        } else {
+                            if let Ok(app) = APP.lock() {
+                                let config = app.config.clone();
+                                let preset = config.presets[preset_idx].clone();
+                                let screen_w = GetSystemMetrics(SM_CXSCREEN);
+                                let screen_h = GetSystemMetrics(SM_CYSCREEN);
+                                let center_rect = RECT {
+                                    left: (screen_w - 700) / 2,
+                                    top: (screen_h - 300) / 2,
+                                    right: (screen_w + 700) / 2,
+                                    bottom: (screen_h + 300) / 2,
+                                };
+
+                                // Get localized preset name for display
+                                let localized_name = gui::settings_ui::get_localized_preset_name(
+                                    &preset.id,
+                                    &config.ui_language,
+                                );
+
+                                let hotkey_name_clone = hotkey_name.clone();
+                                std::thread::spawn(move || {
+                                    overlay::process::start_text_processing(
+                                        String::new(),
+                                        center_rect,
+                                        config,
+                                        preset,
+                                        localized_name,
+                                        hotkey_name_clone,
+                                    );
+                                });
+                            }
+                        }
+                    }
+                } else {
+                    // Image Mode
+                    if overlay::is_selection_overlay_active_and_dismiss() {
+                        return LRESULT(0);
+                    }
+
+                    let app_clone = APP.clone();
+                    let p_idx = preset_idx;
+
+                    std::thread::spawn(move || match capture_screen_fast() {
+                        Ok(capture) => {
+                            if let Ok(mut app) = app_clone.lock() {
+                                app.screenshot_handle = Some(capture);
+                            } else {
+                                return;
+                            }
+                            overlay::show_selection_overlay(p_idx);
+                        }
+                        Err(e) => {
+                            log::error!("Capture Error: {}", e);
+                        }
+                    });
+                }
+            }
+            LRESULT(0)
+        }
+
+        _ => DefWindowProcW(hwnd, msg, wparam, lparam),
+    }
+}
+
+fn capture_screen_fast() -> anyhow::Result<GdiCapture> {
+    unsafe {
+        let x = GetSystemMetrics(SM_XVIRTUALSCREEN);
+        let y = GetSystemMetrics(SM_YVIRTUALSCREEN);
+        let width = GetSystemMetrics(SM_CXVIRTUALSCREEN);
+        let height = GetSystemMetrics(SM_CYVIRTUALSCREEN);
+
+        // Validate dimensions
+        if width <= 0 || height <= 0 {
+            return Err(anyhow::anyhow!(
+                "GDI Error: Invalid screen dimensions ({} x {})",
+                width,
+                height
+            ));
+        }
+
+        let hdc_screen = GetDC(None);
+        if hdc_screen.is_invalid() {
+            return Err(anyhow::anyhow!(
+                "GDI Error: Failed to get screen device context"
+            ));
+        }
+
+        let hdc_mem = CreateCompatibleDC(Some(hdc_screen));
+        if hdc_mem.is_invalid() {
+            let _ = ReleaseDC(None, hdc_screen);
+            return Err(anyhow::anyhow!(
+                "GDI Error: Failed to create compatible device context"
+            ));
+        }
+
+        let hbitmap = CreateCompatibleBitmap(hdc_screen, width, height);
+
+        if hbitmap.is_invalid() {
+            let _ = DeleteDC(hdc_mem);
+            let _ = ReleaseDC(None, hdc_screen);
+            return Err(anyhow::anyhow!(
+                "GDI Error: Failed to create compatible bitmap."
+            ));
+        }
+
+        SelectObject(hdc_mem, hbitmap.into());
+
+        // This is the only "heavy" part, but it's purely GPU/GDI memory move. Very fast.
+        BitBlt(
+            hdc_mem,
+            0,
+            0,
+            width,
+            height,
+            Some(hdc_screen),
+            x,
+            y,
+            SRCCOPY,
+        )?;
+
+        // Cleanup DCs, but KEEP the HBITMAP
+        let _ = DeleteDC(hdc_mem);
+        ReleaseDC(None, hdc_screen);
+
+        Ok(GdiCapture {
+            hbitmap,
+            width,
+            height,
+        })
+    }
+}
@@ -1,5 +1,5 @@
-use super::client::UREQ_AGENT;
-use crate::config::Preset;
+use super::client::agent_for;
+use crate::config::{Config, Preset};
 use crate::model_config::{get_model_by_id, model_is_non_llm};
 use crate::APP;
 use anyhow::Result;
@@ -14,7 +14,7 @@ use windows::Win32::Foundation::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
 
 pub fn transcribe_audio_gemini<F>(
-    gemini_api_key: &str,
+    config: &Config,
     prompt: String,
     model: String,
     wav_data: Vec<u8>,
@@ -23,6 +23,7 @@ pub fn transcribe_audio_gemini<F>(
 where
     F: FnMut(&str),
 {
+    let gemini_api_key = config.gemini_api_key.as_str();
     if gemini_api_key.trim().is_empty() {
         return Err(anyhow::anyhow!("NO_API_KEY:google"));
     }
@@ -56,7 +57,7 @@ where
         ]);
     }
 
-    let resp = UREQ_AGENT
+    let resp = agent_for(config, "google")
         .post(&url)
         .header("x-goog-api-key", gemini_api_key)
         .send_json(payload)
@@ -110,10 +111,11 @@ where
 }
 
 fn upload_audio_to_whisper(
-    api_key: &str,
+    config: &Config,
     model: &str,
     audio_data: Vec<u8>,
 ) -> anyhow::Result<String> {
+    let api_key = config.api_key.as_str();
     // Create multipart form data
     let boundary = format!(
         "----SGTBoundary{}",
@@ -144,7 +146,7 @@ fn upload_audio_to_whisper(
     body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
 
     // Make API request
-    let response = UREQ_AGENT
+    let response = agent_for(config, "groq")
         .post("https://api.groq.com/openai/v1/audio/transcriptions")
         .header("Authorization", &format!("Bearer {}", api_key))
         .header(
@@ -173,9 +175,7 @@ fn upload_audio_to_whisper(
             .and_then(|v| v.to_str().ok())
             .unwrap_or("?");
         let usage_str = format!("{} / {}", remaining, limit);
-        if let Ok(mut app) = APP.lock() {
-            app.model_usage_stats.insert(model.to_string(), usage_str);
-        }
+        crate::api::usage::record_model_usage(model.to_string(), usage_str);
     }
     // ---------------------------
 
@@ -195,6 +195,23 @@ fn upload_audio_to_whisper(
 
 /// Shared logic to process audio data based on a preset's configuration
 /// Returns the transcription/processing result text
+/// The preset and its audio block's provider/model, for the history entry
+fn history_source(preset: &Preset) -> crate::history::HistorySource {
+    let model = preset
+        .blocks
+        .iter()
+        .find(|b| b.block_type == "audio")
+        .and_then(|b| get_model_by_id(&b.effective_model_id()));
+    crate::history::HistorySource {
+        preset_id: preset.id.clone(),
+        provider: model
+            .as_ref()
+            .map(|m| m.provider.clone())
+            .unwrap_or_default(),
+        model: model.map(|m| m.full_name).unwrap_or_default(),
+    }
+}
+
 fn execute_audio_processing_logic(preset: &Preset, wav_data: Vec<u8>) -> anyhow::Result<String> {
     // Find the first block that is specifically an "audio" processing block
     // OR allow input_adapter if no audio block exists (for raw audio overlay)
@@ -209,7 +226,7 @@ fn execute_audio_processing_logic(preset: &Preset, wav_data: Vec<u8>) -> anyhow:
                 Some(b) => (b.clone(), true),
                 None => {
                     let debug_types: Vec<_> = preset.blocks.iter().map(|b| &b.block_type).collect();
-                    eprintln!(
+                    log::error!(
                     "DEBUG [Audio]: No 'audio' blocks found in preset. Block types present: {:?}",
                     debug_types
                 );
@@ -224,29 +241,24 @@ fn execute_audio_processing_logic(preset: &Preset, wav_data: Vec<u8>) -> anyhow:
         return Ok(String::new());
     }
 
-    let model_config = get_model_by_id(&audio_block.model);
+    let audio_model_id = audio_block.effective_model_id();
+    let model_config = get_model_by_id(&audio_model_id);
     let model_config = match model_config {
         Some(c) => c,
         None => {
             return Err(anyhow::anyhow!(
                 "Model config not found for audio model: {}",
-                audio_block.model
+                audio_model_id
             ));
         }
     };
     let model_name = model_config.full_name.clone();
     let provider = model_config.provider.clone();
 
-    let (groq_api_key, gemini_api_key) = {
-        let app = crate::APP.lock().unwrap();
-        (
-            app.config.api_key.clone(),
-            app.config.gemini_api_key.clone(),
-        )
-    };
+    let config = crate::APP.lock().unwrap().config.clone();
 
     // Use block's prompt and language settings
-    let mut final_prompt = if model_is_non_llm(&audio_block.model) {
+    let mut final_prompt = if model_is_non_llm(&audio_model_id) {
         String::new()
     } else {
         audio_block.prompt.clone()
@@ -265,16 +277,16 @@ fn execute_audio_processing_logic(preset: &Preset, wav_data: Vec<u8>) -> anyhow:
     final_prompt = final_prompt.replace("{language}", &audio_block.selected_language);
 
     if provider == "groq" {
-        if groq_api_key.trim().is_empty() {
+        if config.api_key.trim().is_empty() {
             Err(anyhow::anyhow!("NO_API_KEY:groq"))
         } else {
-            upload_audio_to_whisper(&groq_api_key, &model_name, wav_data)
+            upload_audio_to_whisper(&config, &model_name, wav_data)
         }
     } else if provider == "google" {
-        if gemini_api_key.trim().is_empty() {
+        if config.gemini_api_key.trim().is_empty() {
             Err(anyhow::anyhow!("NO_API_KEY:google"))
         } else {
-            transcribe_audio_gemini(&gemini_api_key, final_prompt, model_name, wav_data, |_| {})
+            transcribe_audio_gemini(&config, final_prompt, model_name, wav_data, |_| {})
         }
     } else {
         Err(anyhow::anyhow!("Unsupported audio provider: {}", provider))
@@ -303,7 +315,7 @@ pub fn record_audio_and_transcribe(
             match host.default_output_device() {
                 Some(d) => d,
                 None => {
-                    eprintln!("Error: No default output device found for loopback.");
+                    log::error!("No default output device found for loopback.");
                     unsafe {
                         let _ = PostMessageW(Some(overlay_hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
                     }
@@ -314,7 +326,7 @@ pub fn record_audio_and_transcribe(
         #[cfg(not(target_os = "windows"))]
         {
             // Strict failure if not Windows (device loopback primarily supported on Windows via WASAPI)
-            eprintln!("Error: Device capture not supported on this OS or no device found.");
+            log::error!("Device capture not supported on this OS or no device found.");
             unsafe {
                 let _ = PostMessageW(Some(overlay_hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
             }
@@ -324,7 +336,7 @@ pub fn record_audio_and_transcribe(
         match host.default_input_device() {
             Some(d) => d,
             None => {
-                eprintln!("Error: No input device available.");
+                log::error!("No input device available.");
                 unsafe {
                     let _ = PostMessageW(Some(overlay_hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
                 }
@@ -339,7 +351,7 @@ pub fn record_audio_and_transcribe(
             Err(_) => match device.default_input_config() {
                 Ok(c) => c,
                 Err(e) => {
-                    eprintln!("Failed to get audio config: {}", e);
+                    log::error!("Failed to get audio config: {}", e);
                     unsafe {
                         let _ = PostMessageW(Some(overlay_hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
                     }
@@ -351,7 +363,7 @@ pub fn record_audio_and_transcribe(
         match device.default_input_config() {
             Ok(c) => c,
             Err(e) => {
-                eprintln!("Failed to get audio config: {}", e);
+                log::error!("Failed to get audio config: {}", e);
                 unsafe {
                     let _ = PostMessageW(Some(overlay_hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
                 }
@@ -372,7 +384,7 @@ pub fn record_audio_and_transcribe(
 
     let (tx, rx) = mpsc::channel::<Vec<f32>>();
 
-    let err_fn = |err| eprintln!("Audio stream error: {}", err);
+    let err_fn = |err| log::error!("Audio stream error: {}", err);
 
     // Threshold for "meaningful audio" - above this RMS means mic is truly receiving sound
     const WARMUP_RMS_THRESHOLD: f32 = 0.001;
@@ -426,7 +438,7 @@ pub fn record_audio_and_transcribe(
             None,
         ),
         _ => {
-            eprintln!(
+            log::error!(
                 "Unsupported audio sample format: {:?}",
                 config.sample_format()
             );
@@ -435,7 +447,7 @@ pub fn record_audio_and_transcribe(
     };
 
     if let Err(e) = stream_res {
-        eprintln!("Failed to build stream: {}", e);
+        log::error!("Failed to build stream: {}", e);
         unsafe {
             let _ = PostMessageW(Some(overlay_hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
         }
@@ -444,7 +456,7 @@ pub fn record_audio_and_transcribe(
     let stream = stream_res.unwrap();
 
     if let Err(e) = stream.play() {
-        eprintln!("Failed to play stream: {}", e);
+        log::error!("Failed to play stream: {}", e);
         unsafe {
             let _ = PostMessageW(Some(overlay_hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
         }
@@ -528,7 +540,7 @@ pub fn record_audio_and_transcribe(
         .collect();
 
     if samples.is_empty() {
-        println!("Warning: Recorded audio buffer is empty.");
+        log::warn!("Recorded audio buffer is empty.");
         unsafe {
             let _ = PostMessageW(Some(overlay_hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
         }
@@ -609,8 +621,11 @@ pub fn record_audio_and_transcribe(
             // SAVE HISTORY
             {
                 let app = crate::APP.lock().unwrap();
-                app.history
-                    .save_audio(wav_data_for_history, transcription_text.clone());
+                app.history.save_audio(
+                    wav_data_for_history,
+                    transcription_text.clone(),
+                    history_source(&working_preset),
+                );
             }
 
             // Use working_preset (already resolved by wheel for MASTER presets)
@@ -668,7 +683,7 @@ pub fn record_audio_and_transcribe(
             );
         }
         Err(e) => {
-            eprintln!("Transcription error: {}", e);
+            log::error!("Transcription error: {}", e);
             // Close overlay on error
             unsafe {
                 if IsWindow(Some(overlay_hwnd)).as_bool() {
@@ -690,8 +705,11 @@ pub fn process_audio_file_request(preset: Preset, wav_data: Vec<u8>) {
             // Save history
             {
                 let app = crate::APP.lock().unwrap();
-                app.history
-                    .save_audio(wav_data.clone(), result_text.clone());
+                app.history.save_audio(
+                    wav_data.clone(),
+                    result_text.clone(),
+                    history_source(&preset),
+                );
             }
 
             // Calculate centered position for result
@@ -749,7 +767,7 @@ pub fn process_audio_file_request(preset: Preset, wav_data: Vec<u8>) {
             );
         }
         Err(e) => {
-            eprintln!("Audio file processing error: {}", e);
+            log::error!("Audio file processing error: {}", e);
         }
     }
 }
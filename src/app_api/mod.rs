@@ -0,0 +1,11 @@
+//! The library's API backends plus the parts only the app uses: recording and
+//! transcribing audio, realtime audio translation and API key checks.
+
+pub use screen_goated_toolbox::api::*;
+
+pub mod audio;
+pub mod realtime_audio;
+pub mod validate;
+
+pub use audio::record_audio_and_transcribe;
+// realtime_audio types/functions are used directly where needed via crate::api::realtime_audio::
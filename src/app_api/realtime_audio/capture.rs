@@ -8,7 +8,7 @@ use std::sync::{
 };
 use std::time::Duration;
 
-use super::REALTIME_RMS;
+use super::vad;
 
 /// Start per-app audio capture using WASAPI process loopback (Windows 10 1903+)
 ///
@@ -26,7 +26,7 @@ pub fn start_per_app_capture(
     std::thread::spawn(move || {
         // Initialize COM for this thread (required for WASAPI)
         if wasapi::initialize_mta().is_err() {
-            eprintln!("Per-app capture: Failed to initialize MTA");
+            log::error!("Per-app capture: Failed to initialize MTA");
             return;
         }
 
@@ -35,9 +35,10 @@ pub fn start_per_app_capture(
         let audio_client = match AudioClient::new_application_loopback_client(process_id, true) {
             Ok(client) => client,
             Err(e) => {
-                eprintln!(
+                log::error!(
                     "Per-app capture: Failed to create loopback client for PID {}: {:?}",
-                    process_id, e
+                    process_id,
+                    e
                 );
                 return;
             }
@@ -66,11 +67,11 @@ pub fn start_per_app_capture(
         let mut audio_client = audio_client;
         if let Err(e) = audio_client.initialize_client(&desired_format, &Direction::Capture, &mode)
         {
-            eprintln!(
+            log::error!(
                 "Per-app capture: Failed to initialize audio client: {:?}",
                 e
             );
-            eprintln!("Hint: Per-app capture requires Windows 10 version 1903 or later");
+            log::warn!("Per-app capture requires Windows 10 version 1903 or later");
             return;
         }
 
@@ -78,7 +79,7 @@ pub fn start_per_app_capture(
         let capture_client = match audio_client.get_audiocaptureclient() {
             Ok(client) => client,
             Err(e) => {
-                eprintln!("Per-app capture: Failed to get capture client: {:?}", e);
+                log::error!("Per-app capture: Failed to get capture client: {:?}", e);
                 return;
             }
         };
@@ -87,14 +88,14 @@ pub fn start_per_app_capture(
         let event_handle = match audio_client.set_get_eventhandle() {
             Ok(handle) => handle,
             Err(e) => {
-                eprintln!("Per-app capture: Failed to get event handle: {:?}", e);
+                log::error!("Per-app capture: Failed to get event handle: {:?}", e);
                 return;
             }
         };
 
         // Start the audio stream
         if let Err(e) = audio_client.start_stream() {
-            eprintln!("Per-app capture: Failed to start stream: {:?}", e);
+            log::error!("Per-app capture: Failed to start stream: {:?}", e);
             return;
         }
 
@@ -143,14 +144,14 @@ pub fn start_per_app_capture(
                                 let sum_sq: f64 =
                                     samples.iter().map(|&s| (s as f64 / 32768.0).powi(2)).sum();
                                 let rms = (sum_sq / samples.len() as f64).sqrt() as f32;
-                                REALTIME_RMS.store(rms.to_bits(), Ordering::Relaxed);
+                                vad::record_rms(rms);
                             }
                         }
                     }
                 }
                 Err(e) => {
                     // Check for specific errors that indicate process ended or connection lost
-                    eprintln!("Per-app capture: Read error: {:?}", e);
+                    log::error!("Per-app capture: Read error: {:?}", e);
                     // Small delay before retrying
                     std::thread::sleep(Duration::from_millis(10));
                 }
@@ -192,7 +193,7 @@ pub fn start_device_loopback_capture(
     let resample_ratio = target_rate as f64 / sample_rate as f64;
 
     let stop_signal_audio = stop_signal.clone();
-    let err_fn = |err| eprintln!("Audio stream error: {}", err);
+    let err_fn = |err| log::error!("Audio stream error: {}", err);
 
     let stream = match config.sample_format() {
         cpal::SampleFormat::F32 => device.build_input_stream(
@@ -241,7 +242,7 @@ pub fn start_device_loopback_capture(
                         .map(|&s| (s as f64 / 32768.0).powi(2))
                         .sum();
                     let rms = (sum_sq / resampled.len() as f64).sqrt() as f32;
-                    REALTIME_RMS.store(rms.to_bits(), Ordering::Relaxed);
+                    vad::record_rms(rms);
                 }
             },
             err_fn,
@@ -292,7 +293,7 @@ pub fn start_device_loopback_capture(
                         .map(|&s| (s as f64 / 32768.0).powi(2))
                         .sum();
                     let rms = (sum_sq / resampled.len() as f64).sqrt() as f32;
-                    REALTIME_RMS.store(rms.to_bits(), Ordering::Relaxed);
+                    vad::record_rms(rms);
                 }
             },
             err_fn,
@@ -323,7 +324,7 @@ pub fn start_mic_capture(
     let target_rate = 16000u32;
     let resample_ratio = target_rate as f64 / sample_rate as f64;
     let stop_signal_audio = stop_signal.clone();
-    let err_fn = |err| eprintln!("Audio stream error: {}", err);
+    let err_fn = |err| log::error!("Audio stream error: {}", err);
 
     let stream = match config.sample_format() {
         cpal::SampleFormat::F32 => device.build_input_stream(
@@ -369,7 +370,7 @@ pub fn start_mic_capture(
                         .map(|&s| (s as f64 / 32768.0).powi(2))
                         .sum();
                     let rms = (sum_sq / resampled.len() as f64).sqrt() as f32;
-                    REALTIME_RMS.store(rms.to_bits(), Ordering::Relaxed);
+                    vad::record_rms(rms);
                 }
             },
             err_fn,
@@ -8,22 +8,24 @@
 
 mod capture;
 pub mod model_loader;
+pub mod notes;
 pub mod parakeet;
+mod speakers;
 mod state;
 mod transcription;
 mod translation;
 mod utils;
-mod websocket;
+pub mod vad;
+pub(crate) mod websocket;
 
 use windows::Win32::UI::WindowsAndMessaging::WM_APP;
 
 // Re-export public items
 pub use state::{RealtimeState, SharedRealtimeState};
 pub use transcription::start_realtime_transcription;
-pub use translation::translate_with_google_gtx;
 
-/// Interval for triggering translation (milliseconds)
-pub const TRANSLATION_INTERVAL_MS: u64 = 1500;
+/// Allowed values for `Config::realtime_translation_interval_ms`
+pub const TRANSLATION_INTERVAL_RANGE_MS: std::ops::RangeInclusive<u64> = 500..=5000;
 
 /// Model for realtime audio transcription
 pub const REALTIME_MODEL: &str = "gemini-2.5-flash-native-audio-preview-12-2025";
@@ -94,5 +96,5 @@ pub fn cancel_download_and_revert_to_gemini() {
         }
     }
 
-    println!("Parakeet download cancelled, reverting to Gemini Live");
+    log::info!("Parakeet download cancelled, reverting to Gemini Live");
 }
@@ -19,7 +19,7 @@ pub fn download_file(
 
     use std::io::Write;
 
-    println!("Downloading file from: {}", url);
+    log::info!("Downloading file from: {}", url);
     let response = ureq::get(url)
         .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
         .call()
@@ -121,7 +121,7 @@ pub fn download_parakeet_model(
     use windows::Win32::Foundation::{LPARAM, WPARAM};
     use windows::Win32::UI::WindowsAndMessaging::PostMessageW;
 
-    println!("Parakeet model not found, starting download. Modal should appear now...");
+    log::info!("Parakeet model not found, starting download. Modal should appear now...");
 
     // Small delay to ensure WebView is ready to receive the message
     std::thread::sleep(std::time::Duration::from_millis(100));
@@ -0,0 +1,203 @@
+//! Session notes autosave
+//!
+//! While a live session runs, finished segments are appended to a markdown file named
+//! after the session's start time, so a crash or a forgotten copy doesn't lose it.
+//! With translation the file gets every committed (source, translation) pair; without,
+//! the transcript sentence by sentence. Whatever is still pending is written on stop.
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::state::SharedRealtimeState;
+
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+const STOP_POLL: Duration = Duration::from_millis(100);
+const SENTENCE_DELIMITERS: [char; 6] = ['.', '!', '?', '。', '！', '？'];
+
+/// Folder used when `Config::realtime_autosave_dir` is empty
+pub fn default_notes_dir() -> PathBuf {
+    dirs::document_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("Screen Goated Toolbox")
+        .join("Live notes")
+}
+
+fn notes_dir(configured: &str) -> PathBuf {
+    let configured = configured.trim();
+    if configured.is_empty() {
+        default_notes_dir()
+    } else {
+        PathBuf::from(configured)
+    }
+}
+
+fn timestamp() -> String {
+    chrono::Local::now().format("%H:%M:%S").to_string()
+}
+
+struct NotesWriter {
+    file: File,
+    /// Segments of `committed_segments` already written
+    segments_written: usize,
+    /// Length of the last written translation, which later text can still extend
+    last_translation_len: usize,
+    /// Byte offset in `full_transcript` written so far (transcription-only sessions)
+    transcript_written: usize,
+}
+
+impl NotesWriter {
+    fn create(
+        dir: PathBuf,
+        audio_source: &str,
+        target_language: Option<&str>,
+    ) -> std::io::Result<Self> {
+        std::fs::create_dir_all(&dir)?;
+        let now = chrono::Local::now();
+        let path = dir.join(format!("{}.md", now.format("%Y-%m-%d %H-%M-%S")));
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+
+        let mut header = format!(
+            "# Live session {}\n\n- Audio source: {}\n",
+            now.format("%Y-%m-%d %H:%M"),
+            audio_source
+        );
+        if let Some(language) = target_language {
+            header.push_str(&format!("- Target language: {}\n", language));
+        }
+        header.push('\n');
+        file.write_all(header.as_bytes())?;
+        log::info!("Saving live session notes to {}", path.display());
+
+        Ok(Self {
+            file,
+            segments_written: 0,
+            last_translation_len: 0,
+            transcript_written: 0,
+        })
+    }
+
+    /// Markdown for segments committed since the last call
+    fn new_segments(&mut self, segments: &[(String, String)]) -> String {
+        let mut out = String::new();
+        // The state was cleared mid-session
+        if segments.len() < self.segments_written {
+            self.segments_written = 0;
+            self.last_translation_len = 0;
+        }
+
+        if let Some((_, last)) = self.segments_written.checked_sub(1).map(|i| &segments[i]) {
+            if let Some(extra) = last.get(self.last_translation_len..) {
+                if !extra.trim().is_empty() {
+                    out.push_str(&format!("> {}\n\n", extra.trim()));
+                }
+            }
+            self.last_translation_len = last.len();
+        }
+
+        let stamp = timestamp();
+        for (source, translation) in &segments[self.segments_written..] {
+            if !source.trim().is_empty() {
+                out.push_str(&format!("**{}** {}\n", stamp, source.trim()));
+            }
+            out.push_str(&format!("> {}\n\n", translation.trim()));
+            self.last_translation_len = translation.len();
+        }
+        self.segments_written = segments.len();
+        out
+    }
+
+    /// Markdown for transcript sentences finished since the last call; `all` also
+    /// takes the unfinished tail
+    fn new_sentences(&mut self, transcript: &str, all: bool) -> String {
+        if transcript.len() < self.transcript_written {
+            self.transcript_written = 0;
+        }
+        let rest = transcript.get(self.transcript_written..).unwrap_or("");
+        let end = if all {
+            rest.len()
+        } else {
+            match rest.rfind(SENTENCE_DELIMITERS) {
+                Some(i) => i + rest[i..].chars().next().map_or(1, char::len_utf8),
+                None => return String::new(),
+            }
+        };
+        self.transcript_written += end;
+
+        let text = rest[..end].trim();
+        if text.is_empty() {
+            String::new()
+        } else {
+            format!("**{}** {}\n\n", timestamp(), text)
+        }
+    }
+
+    fn flush(&mut self, state: &SharedRealtimeState, has_translation: bool, last: bool) {
+        let text = {
+            let Ok(s) = state.lock() else { return };
+            if has_translation {
+                let mut text = self.new_segments(&s.committed_segments);
+                if last {
+                    let pending_source =
+                        s.full_transcript.get(s.last_committed_pos..).unwrap_or("");
+                    if !pending_source.trim().is_empty() {
+                        text.push_str(&format!("**{}** {}\n", timestamp(), pending_source.trim()));
+                    }
+                    if !s.uncommitted_translation.trim().is_empty() {
+                        text.push_str(&format!("> {}\n\n", s.uncommitted_translation.trim()));
+                    }
+                }
+                text
+            } else {
+                self.new_sentences(&s.full_transcript, last)
+            }
+        };
+
+        if !text.is_empty() {
+            if let Err(e) = self.file.write_all(text.as_bytes()) {
+                log::warn!("Failed to write live session notes: {}", e);
+            }
+        }
+    }
+}
+
+/// Append the session to a notes file until `stop_signal` is set. Blocks; run it on
+/// its own thread.
+pub fn run_notes_writer(
+    stop_signal: Arc<AtomicBool>,
+    state: SharedRealtimeState,
+    audio_source: String,
+    has_translation: bool,
+) {
+    let (dir, target_language) = {
+        let app = crate::APP.lock().unwrap();
+        (
+            notes_dir(&app.config.realtime_autosave_dir),
+            app.config.realtime_target_language.clone(),
+        )
+    };
+    let target_language = has_translation.then_some(target_language.as_str());
+
+    let mut writer = match NotesWriter::create(dir, &audio_source, target_language) {
+        Ok(writer) => writer,
+        Err(e) => {
+            log::warn!("Failed to create live session notes: {}", e);
+            return;
+        }
+    };
+
+    let mut since_flush = Duration::ZERO;
+    while !stop_signal.load(Ordering::Relaxed) {
+        std::thread::sleep(STOP_POLL);
+        since_flush += STOP_POLL;
+        if since_flush >= FLUSH_INTERVAL {
+            since_flush = Duration::ZERO;
+            writer.flush(&state, has_translation, false);
+        }
+    }
+    writer.flush(&state, has_translation, true);
+}
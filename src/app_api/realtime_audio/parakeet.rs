@@ -11,7 +11,7 @@ use windows::Win32::Foundation::LPARAM;
 use windows::Win32::Foundation::WPARAM;
 use windows::Win32::UI::WindowsAndMessaging::PostMessageW;
 
-use super::{REALTIME_RMS, WM_REALTIME_UPDATE, WM_VOLUME_UPDATE};
+use super::{WM_REALTIME_UPDATE, WM_VOLUME_UPDATE};
 use crate::overlay::realtime_webview::AUDIO_SOURCE_CHANGE;
 
 /// 160ms chunk at 16kHz = 2560 samples (recommended by parakeet-rs)
@@ -32,7 +32,7 @@ pub fn run_parakeet_transcription(
                 let err_msg = e.to_string();
                 if err_msg.contains("cancelled") || stop_signal.load(Ordering::Relaxed) {
                     // Download was cancelled by user - this is not an error, just exit gracefully
-                    println!("Parakeet download was cancelled by user");
+                    log::info!("Parakeet download was cancelled by user");
                     return Ok(());
                 }
                 // Real error - propagate it
@@ -46,7 +46,7 @@ pub fn run_parakeet_transcription(
 
     // 2. Load Model
     let model_dir = super::model_loader::get_parakeet_model_dir();
-    // println!("Loading Parakeet model from: {:?}", model_dir);
+    log::debug!("Loading Parakeet model from: {:?}", model_dir);
 
     // Configure DirectML for GPU acceleration (falls back to CPU if unavailable)
     let config = ExecutionConfig::new().with_execution_provider(ExecutionProvider::DirectML);
@@ -54,7 +54,7 @@ pub fn run_parakeet_transcription(
     let mut parakeet = ParakeetEOU::from_pretrained(&model_dir, Some(config))
         .map_err(|e| anyhow::anyhow!("Failed to load Parakeet model: {:?}", e))?;
 
-    // println!("Parakeet model loaded successfully!");
+    log::debug!("Parakeet model loaded successfully!");
 
     // Set transcription method to Parakeet for timeout-based segmentation
     if let Ok(mut s) = state.lock() {
@@ -98,7 +98,7 @@ pub fn run_parakeet_transcription(
     } else if audio_source == "device" && tts_enabled && selected_pid == 0 {
         // Edge case: TTS enabled (Isolation mode) but no app selected yet.
         // We MUST NOT fall back to full loopback because that would record the TTS and echo.
-        // println!("Parakeet: TTS enabled but no app selected - pausing capture to avoid echo.");
+        log::debug!("Parakeet: TTS enabled but no app selected - pausing capture to avoid echo.");
         None
     } else {
         Some(super::capture::start_device_loopback_capture(
@@ -106,10 +106,11 @@ pub fn run_parakeet_transcription(
             stop_signal.clone(),
         )?)
     };
-    // println!("Parakeet: Audio capture started, entering processing loop...");
+    log::debug!("Parakeet: Audio capture started, entering processing loop...");
 
     // Buffer for accumulating samples to reach chunk size
     let mut sample_accumulator: Vec<f32> = Vec::with_capacity(CHUNK_SIZE * 2);
+    let mut speakers = super::speakers::SpeakerTracker::new();
 
     // 4. Processing Loop
     while !stop_signal.load(Ordering::Relaxed) {
@@ -120,22 +121,19 @@ pub fn run_parakeet_transcription(
         }
 
         // Read from buffer and convert i16 to f32
-        let new_samples: Vec<f32> = {
+        let raw_samples: Vec<i16> = {
             let mut buf = audio_buffer.lock().unwrap();
-            if !buf.is_empty() {
-                // Convert i16 to f32 normalized (-1.0 to 1.0)
-                let samples: Vec<f32> = buf.drain(..).map(|s| s as f32 / 32768.0).collect();
-                samples
-            } else {
-                Vec::new()
-            }
+            std::mem::take(&mut *buf)
         };
+        super::transcription::track_speaker(&mut speakers, &raw_samples, &state);
+        // Convert i16 to f32 normalized (-1.0 to 1.0)
+        let new_samples: Vec<f32> = raw_samples.iter().map(|&s| s as f32 / 32768.0).collect();
 
         if !new_samples.is_empty() {
             // Calculate RMS for volume visualization
             let sum_sq: f64 = new_samples.iter().map(|&s| (s as f64).powi(2)).sum();
             let rms = (sum_sq / new_samples.len() as f64).sqrt() as f32;
-            REALTIME_RMS.store(rms.to_bits(), Ordering::Relaxed);
+            super::vad::record_rms(rms);
 
             unsafe {
                 if !overlay_hwnd.is_invalid() {
@@ -197,7 +195,7 @@ pub fn run_parakeet_transcription(
                     }
                 }
                 Err(e) => {
-                    eprintln!("Parakeet transcription error: {:?}", e);
+                    log::error!("Parakeet transcription error: {:?}", e);
                 }
             }
         }
@@ -206,7 +204,7 @@ pub fn run_parakeet_transcription(
     }
 
     // Flush: send silence chunks to get any remaining text
-    // println!("Flushing Parakeet decoder...");
+    log::debug!("Flushing Parakeet decoder...");
     let silence = vec![0.0f32; CHUNK_SIZE];
     for _ in 0..3 {
         if let Ok(text) = parakeet.transcribe(&silence, false) {
@@ -0,0 +1,134 @@
+//! Speaker turn detection for transcription models that don't diarize.
+//!
+//! A turn starts after a pause. Its first half-second of speech is reduced to two
+//! features, loudness (dB) and zero-crossing rate (a rough proxy for pitch and
+//! brightness), and matched to the closest known speaker profile, or registers a new
+//! one. Crude, but enough to tell two people on a call apart most of the time.
+
+use std::time::Duration;
+
+const SAMPLE_RATE: f32 = 16000.0;
+/// RMS (0..1) above which a chunk counts as speech
+const SPEECH_RMS: f32 = 0.015;
+/// Silence that ends a turn
+const TURN_PAUSE: Duration = Duration::from_millis(700);
+/// Speech collected before a turn is attributed to a speaker
+const PROFILE_SPEECH: Duration = Duration::from_millis(500);
+/// Feature distance under which a turn belongs to an existing speaker
+const MATCH_DISTANCE: f32 = 1.0;
+const MAX_SPEAKERS: usize = 4;
+
+fn rms(samples: &[i16]) -> f32 {
+    let sum_sq: f64 = samples.iter().map(|&s| (s as f64 / 32768.0).powi(2)).sum();
+    (sum_sq / samples.len().max(1) as f64).sqrt() as f32
+}
+
+#[derive(Clone, Copy, Default)]
+struct Features {
+    /// Loudness in units of 6 dB
+    loudness: f32,
+    /// Zero-crossing rate in units of 0.04
+    brightness: f32,
+}
+
+impl Features {
+    fn of(samples: &[i16], rms: f32) -> Self {
+        let crossings = samples
+            .windows(2)
+            .filter(|w| (w[0] >= 0) != (w[1] >= 0))
+            .count();
+        let zcr = crossings as f32 / samples.len().max(1) as f32;
+        Self {
+            loudness: 20.0 * rms.max(1e-6).log10() / 6.0,
+            brightness: zcr / 0.04,
+        }
+    }
+
+    fn distance(&self, other: &Features) -> f32 {
+        ((self.loudness - other.loudness).powi(2) + (self.brightness - other.brightness).powi(2))
+            .sqrt()
+    }
+
+    fn blend(&mut self, other: &Features, weight: f32) {
+        self.loudness += (other.loudness - self.loudness) * weight;
+        self.brightness += (other.brightness - self.brightness) * weight;
+    }
+}
+
+pub struct SpeakerTracker {
+    profiles: Vec<Features>,
+    current: Option<usize>,
+    silence: Duration,
+    /// Speech of the turn being attributed; `None` once it has been
+    turn: Option<(Features, Duration)>,
+}
+
+impl SpeakerTracker {
+    pub fn new() -> Self {
+        Self {
+            profiles: Vec::new(),
+            current: None,
+            silence: Duration::ZERO,
+            turn: Some((Features::default(), Duration::ZERO)),
+        }
+    }
+
+    /// Feed captured 16 kHz mono audio. Returns the speaker (0-based) when a turn by a
+    /// different speaker than the last one has been identified.
+    pub fn feed(&mut self, samples: &[i16]) -> Option<usize> {
+        if samples.is_empty() {
+            return None;
+        }
+        let length = Duration::from_secs_f32(samples.len() as f32 / SAMPLE_RATE);
+        let rms = rms(samples);
+
+        if rms < SPEECH_RMS {
+            self.silence += length;
+            if self.silence >= TURN_PAUSE && self.turn.is_none() {
+                self.turn = Some((Features::default(), Duration::ZERO));
+            }
+            return None;
+        }
+        self.silence = Duration::ZERO;
+
+        let features = Features::of(samples, rms);
+        let (sum, speech) = self.turn.as_mut()?;
+        // Running mean over the turn's speech so far
+        let weight = length.as_secs_f32() / (*speech + length).as_secs_f32();
+        sum.blend(&features, weight);
+        *speech += length;
+        if *speech < PROFILE_SPEECH {
+            return None;
+        }
+
+        let turn = *sum;
+        self.turn = None;
+        let speaker = self.identify(&turn);
+        if self.current == Some(speaker) {
+            return None;
+        }
+        self.current = Some(speaker);
+        Some(speaker)
+    }
+
+    fn identify(&mut self, turn: &Features) -> usize {
+        let nearest = self
+            .profiles
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (i, p.distance(turn)))
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        match nearest {
+            Some((i, distance))
+                if distance < MATCH_DISTANCE || self.profiles.len() >= MAX_SPEAKERS =>
+            {
+                self.profiles[i].blend(turn, 0.2);
+                i
+            }
+            _ => {
+                self.profiles.push(*turn);
+                self.profiles.len() - 1
+            }
+        }
+    }
+}
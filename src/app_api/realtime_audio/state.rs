@@ -61,6 +61,8 @@ pub struct RealtimeState {
     /// Translation history for conversation context: (source_text, translation)
     /// Keeps last 3 entries to maintain consistent style/atmosphere
     pub translation_history: Vec<(String, String)>,
+    /// Every committed (source, translation) pair of the session, for bilingual copy
+    pub committed_segments: Vec<(String, String)>,
 
     /// When the user last spoke (Audio input)
     pub last_transcript_append_time: Instant,
@@ -81,6 +83,11 @@ pub struct RealtimeState {
     pub transcription_method: TranscriptionMethod,
     /// When the current uncommitted segment started (for Parakeet timeout)
     pub parakeet_segment_start_time: Instant,
+
+    /// Speaker turns: (byte offset in full_transcript, 0-based speaker)
+    pub speaker_turns: Vec<(usize, usize)>,
+    /// Speaker whose turn starts with the next transcript text
+    pending_speaker: Option<usize>,
 }
 
 impl RealtimeState {
@@ -94,6 +101,7 @@ impl RealtimeState {
             uncommitted_translation: String::new(),
             display_translation: String::new(),
             translation_history: Vec::new(),
+            committed_segments: Vec::new(),
             last_transcript_append_time: Instant::now(),
             last_translation_update_time: Instant::now(),
             is_downloading: false,
@@ -103,6 +111,8 @@ impl RealtimeState {
             // Parakeet-specific: default to GeminiLive (existing behavior)
             transcription_method: TranscriptionMethod::GeminiLive,
             parakeet_segment_start_time: Instant::now(),
+            speaker_turns: Vec::new(),
+            pending_speaker: None,
         }
     }
 
@@ -161,11 +171,70 @@ impl RealtimeState {
             }
         }
 
+        if let Some(speaker) = self.pending_speaker.take() {
+            let leading_ws = text_to_append.len() - text_to_append.trim_start().len();
+            self.speaker_turns
+                .push((self.full_transcript.len() + leading_ws, speaker));
+        }
+
         self.full_transcript.push_str(&text_to_append);
         self.last_transcript_append_time = Instant::now();
         self.update_display_transcript();
     }
 
+    /// A new speaker's turn began; labelled at the next transcript text
+    pub fn mark_speaker_turn(&mut self, speaker: usize) {
+        self.pending_speaker = Some(speaker);
+    }
+
+    /// Transcript for the overlay split into (committed, current) text, with a
+    /// `label` line ("Speaker {}:") before each speaker turn when given
+    pub fn transcript_display_parts(&self, label: Option<&str>) -> (String, String) {
+        let full = &self.full_transcript;
+        let pos = self.last_committed_pos.min(full.len());
+        let (old, new, new_starts_turn) = match label {
+            Some(label) if !self.speaker_turns.is_empty() => (
+                self.labelled(0, pos, label),
+                self.labelled(pos, full.len(), label),
+                self.speaker_turns
+                    .iter()
+                    .any(|&(at, _)| at >= pos && full[pos..at].trim().is_empty()),
+            ),
+            _ => (full[..pos].to_string(), full[pos..].to_string(), false),
+        };
+
+        let old = old.trim_end();
+        let new = new.trim_start();
+        if !old.is_empty() && !new.is_empty() {
+            let separator = if new_starts_turn { '\n' } else { ' ' };
+            (old.to_string(), format!("{}{}", separator, new))
+        } else {
+            (old.to_string(), new.to_string())
+        }
+    }
+
+    /// `full_transcript[start..end]` with speaker labels inserted at turn starts
+    fn labelled(&self, start: usize, end: usize, label: &str) -> String {
+        let mut out = String::new();
+        let mut cursor = start;
+        for &(at, speaker) in &self.speaker_turns {
+            if at < start || at >= end || !self.full_transcript.is_char_boundary(at) {
+                continue;
+            }
+            out.push_str(&self.full_transcript[cursor..at]);
+            // Each turn on its own line
+            if !out.trim().is_empty() {
+                out.truncate(out.trim_end().len());
+                out.push('\n');
+            }
+            out.push_str(&label.replace("{}", &(speaker + 1).to_string()));
+            out.push(' ');
+            cursor = at;
+        }
+        out.push_str(&self.full_transcript[cursor..end]);
+        out
+    }
+
     // ============================================
     // PARAKEET-SPECIFIC METHODS
     // ============================================
@@ -320,13 +389,19 @@ impl RealtimeState {
 
         if !trans_segment.is_empty() {
             // Get source segment for history (may be empty if transcription already committed)
-            let source_segment = if self.last_committed_pos < self.full_transcript.len() {
+            let source_text = if self.last_committed_pos < self.full_transcript.len() {
                 self.full_transcript[self.last_committed_pos..]
                     .trim()
                     .to_string()
             } else {
+                String::new()
+            };
+            self.record_segment(source_text.clone(), trans_segment.clone());
+            let source_segment = if source_text.is_empty() {
                 // Transcription already committed - use a placeholder for history
                 "[continued]".to_string()
+            } else {
+                source_text
             };
 
             // Add to history (for translation context continuity)
@@ -497,6 +572,7 @@ impl RealtimeState {
 
             if !source_segment.is_empty() && !trans_segment.is_empty() {
                 // Add to History
+                self.record_segment(source_segment.clone(), trans_segment.clone());
                 self.add_to_history(source_segment, trans_segment.clone());
 
                 // Add to Committed String
@@ -547,6 +623,51 @@ impl RealtimeState {
         }
     }
 
+    /// Keep a committed pair for the bilingual transcript. A translation without new
+    /// source text finishes the previous pair.
+    fn record_segment(&mut self, source: String, translation: String) {
+        if source.is_empty() {
+            if let Some((_, last)) = self.committed_segments.last_mut() {
+                last.push(' ');
+                last.push_str(&translation);
+                return;
+            }
+        }
+        self.committed_segments.push((source, translation));
+    }
+
+    /// The session as bilingual notes: each committed sentence followed by its
+    /// translation, blocks separated by a blank line, then anything still pending
+    pub fn bilingual_text(&self) -> String {
+        let mut blocks: Vec<String> = self
+            .committed_segments
+            .iter()
+            .map(|(source, translation)| {
+                if source.is_empty() {
+                    translation.clone()
+                } else {
+                    format!("{}\n{}", source, translation)
+                }
+            })
+            .collect();
+
+        let pending_source = self
+            .full_transcript
+            .get(self.last_committed_pos..)
+            .unwrap_or("")
+            .trim();
+        let pending_translation = self.uncommitted_translation.trim();
+        let pending: Vec<&str> = [pending_source, pending_translation]
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect();
+        if !pending.is_empty() {
+            blocks.push(pending.join("\n"));
+        }
+
+        blocks.join("\n\n")
+    }
+
     /// Get translation history as messages for API request
     pub fn get_history_messages(&self, target_language: &str) -> Vec<serde_json::Value> {
         let mut messages = Vec::new();
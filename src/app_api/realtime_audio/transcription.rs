@@ -14,6 +14,7 @@ use crate::overlay::realtime_webview::SELECTED_APP_PID;
 use crate::APP;
 
 use super::capture::{start_device_loopback_capture, start_mic_capture, start_per_app_capture};
+use super::speakers::SpeakerTracker;
 use super::state::SharedRealtimeState;
 use super::translation::run_translation_loop;
 use super::utils::update_overlay_text;
@@ -42,6 +43,8 @@ pub fn start_realtime_transcription(
     let overlay_send = crate::win_types::SendHwnd(overlay_hwnd);
     let translation_send = translation_hwnd.map(crate::win_types::SendHwnd);
 
+    super::vad::set_threshold(APP.lock().unwrap().config.realtime_vad_threshold);
+
     // Spawn translation thread if needed (Independent of transcription model)
     let has_translation = translation_hwnd.is_some() && preset.blocks.len() > 1;
     if has_translation {
@@ -51,11 +54,23 @@ pub fn start_realtime_transcription(
         let t_preset = preset.clone();
 
         std::thread::spawn(move || {
+            let _worker = crate::overlay::diagnostics::track_worker("Live translation");
             run_translation_loop(t_preset, t_stop, t_send, t_state);
         });
     }
 
+    if APP.lock().unwrap().config.realtime_autosave {
+        let n_state = state.clone();
+        let n_stop = stop_signal.clone();
+        let n_source = preset.audio_source.clone();
+        std::thread::spawn(move || {
+            let _worker = crate::overlay::diagnostics::track_worker("Live notes");
+            super::notes::run_notes_writer(n_stop, n_state, n_source, has_translation);
+        });
+    }
+
     std::thread::spawn(move || {
+        let _worker = crate::overlay::diagnostics::track_worker("Live transcription");
         transcription_thread_entry(preset, stop_signal, overlay_send, translation_send, state);
     });
 }
@@ -95,7 +110,7 @@ fn transcription_thread_entry(
         }
 
         let result = if trans_model == "parakeet" {
-            // println!(">>> Starting Parakeet transcription");
+            log::debug!("Starting Parakeet transcription");
             super::parakeet::run_parakeet_transcription(
                 current_preset.clone(),
                 stop_signal.clone(),
@@ -103,7 +118,7 @@ fn transcription_thread_entry(
                 state.clone(),
             )
         } else {
-            // println!(">>> Starting Gemini Live transcription");
+            log::debug!("Starting Gemini Live transcription");
             run_realtime_transcription(
                 current_preset.clone(),
                 stop_signal.clone(),
@@ -121,7 +136,7 @@ fn transcription_thread_entry(
 
             if !is_user_initiated {
                 let err_msg = format!(" [Error: {}]", e);
-                eprintln!("Realtime transcription error: {}", e);
+                log::error!("Realtime transcription error: {}", e);
 
                 // Append error to state so it's visible in the window
                 if let Ok(mut s) = state.lock() {
@@ -147,7 +162,7 @@ fn transcription_thread_entry(
         if restart_source {
             if let Ok(new_source) = NEW_AUDIO_SOURCE.lock() {
                 if !new_source.is_empty() {
-                    // println!("Changing audio source to: {}", new_source);
+                    log::debug!("Changing audio source to: {}", new_source);
                     let mut app = APP.lock().unwrap();
                     app.config.realtime_audio_source = new_source.clone();
                     // Save config? Optional, but UI should sync.
@@ -158,7 +173,7 @@ fn transcription_thread_entry(
         if restart_model {
             if let Ok(new_model) = NEW_TRANSCRIPTION_MODEL.lock() {
                 if !new_model.is_empty() {
-                    // println!("Changing transcription model to: {}", new_model);
+                    log::debug!("Changing transcription model to: {}", new_model);
                     let mut app = APP.lock().unwrap();
                     app.config.realtime_transcription_model = new_model.clone();
                 }
@@ -199,11 +214,11 @@ fn run_realtime_transcription(
         return Err(anyhow::anyhow!("NO_API_KEY:google"));
     }
 
-    // println!("Gemini: Connecting to WebSocket...");
+    log::debug!("Gemini: Connecting to WebSocket...");
     let mut socket = connect_websocket(&gemini_api_key)?;
-    // println!("Gemini: Connected! Sending setup...");
+    log::debug!("Gemini: Connected! Sending setup...");
     send_setup_message(&mut socket)?;
-    // println!("Gemini: Setup sent, waiting for acknowledgment...");
+    log::debug!("Gemini: Setup sent, waiting for acknowledgment...");
 
     // Set transcription method to GeminiLive (uses delimiter-based segmentation)
     if let Ok(mut s) = state.lock() {
@@ -267,7 +282,7 @@ fn run_realtime_transcription(
         if TRANSCRIPTION_MODEL_CHANGE.load(Ordering::SeqCst)
             || AUDIO_SOURCE_CHANGE.load(Ordering::SeqCst)
         {
-            // println!("Gemini: Model/source change detected during setup, aborting...");
+            log::debug!("Gemini: Model/source change detected during setup, aborting...");
             return Ok(()); // Return cleanly to allow the outer loop to handle the change
         }
     }
@@ -323,6 +338,23 @@ fn run_realtime_transcription(
     Ok(())
 }
 
+/// Label a new speaker turn when speaker labels are on
+pub(super) fn track_speaker(
+    speakers: &mut SpeakerTracker,
+    samples: &[i16],
+    state: &SharedRealtimeState,
+) {
+    use crate::overlay::realtime_webview::REALTIME_SPEAKER_LABELS;
+    if !REALTIME_SPEAKER_LABELS.load(Ordering::Relaxed) {
+        return;
+    }
+    if let Some(speaker) = speakers.feed(samples) {
+        if let Ok(mut s) = state.lock() {
+            s.mark_speaker_turn(speaker);
+        }
+    }
+}
+
 fn run_main_loop(
     mut socket: tungstenite::WebSocket<native_tls::TlsStream<std::net::TcpStream>>,
     audio_buffer: Arc<Mutex<Vec<i16>>>,
@@ -344,6 +376,8 @@ fn run_main_loop(
 
     let mut last_transcription_time = Instant::now();
     let mut consecutive_empty_reads: u32 = 0;
+    // Gemini Live doesn't diarize, so speaker turns come from the audio itself
+    let mut speakers = SpeakerTracker::new();
     const NO_RESULT_THRESHOLD_SECS: u64 = 8;
     const EMPTY_READ_CHECK_COUNT: u32 = 50;
 
@@ -393,6 +427,7 @@ fn run_main_loop(
                 let mut buf = audio_buffer.lock().unwrap();
                 std::mem::take(&mut *buf)
             };
+            track_speaker(&mut speakers, &real_audio, &state);
 
             match audio_mode {
                 AudioMode::Normal => {
@@ -1,23 +1,32 @@
 //! Translation loop for realtime audio
 
-use isolang;
 use std::io::BufRead;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc,
 };
 use std::time::{Duration, Instant};
-use urlencoding;
 use windows::Win32::Foundation::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
 
 use crate::api::client::UREQ_AGENT;
+use crate::api::gtx::translate_with_google_gtx;
+use crate::api::usage::record_model_usage;
 use crate::config::Preset;
 use crate::APP;
 
 use super::state::SharedRealtimeState;
 use super::utils::{refresh_transcription_window, update_translation_text};
-use super::{TRANSLATION_INTERVAL_MS, WM_MODEL_SWITCH};
+use super::{TRANSLATION_INTERVAL_RANGE_MS, WM_MODEL_SWITCH};
+
+/// In sentence mode, a pause this long also ends the chunk
+const SENTENCE_PAUSE: Duration = Duration::from_millis(600);
+/// In sentence mode, translate anyway after this many intervals without a boundary
+const SENTENCE_MAX_INTERVALS: u32 = 4;
+
+fn ends_sentence(text: &str) -> bool {
+    text.trim_end().ends_with(['.', '!', '?', '。', '！', '？'])
+}
 
 /// Translation loop using Cerebras' gpt-oss-120b model
 pub fn run_translation_loop(
@@ -27,7 +36,6 @@ pub fn run_translation_loop(
     state: SharedRealtimeState,
 ) {
     let translation_hwnd = translation_hwnd_send.0;
-    let interval = Duration::from_millis(TRANSLATION_INTERVAL_MS);
     let mut last_run = Instant::now();
 
     let translation_block = match preset.blocks.get(1) {
@@ -66,6 +74,19 @@ pub fn run_translation_loop(
             break;
         }
 
+        // Read every cycle so slider changes apply to the running session
+        let (interval, on_sentence) = {
+            let app = APP.lock().unwrap();
+            let ms = app.config.realtime_translation_interval_ms.clamp(
+                *TRANSLATION_INTERVAL_RANGE_MS.start(),
+                *TRANSLATION_INTERVAL_RANGE_MS.end(),
+            );
+            (
+                Duration::from_millis(ms),
+                app.config.realtime_translate_on_sentence,
+            )
+        };
+
         // Check for language change
         if crate::overlay::realtime_webview::LANGUAGE_CHANGE.load(Ordering::SeqCst) {
             if let Ok(new_lang) = crate::overlay::realtime_webview::NEW_TARGET_LANGUAGE.lock() {
@@ -108,6 +129,13 @@ pub fn run_translation_loop(
                 continue;
             }
 
+            // Nobody is speaking: don't spend requests on noise. Poll often so the
+            // first chunk after speech resumes is translated right away.
+            if super::vad::is_silent() {
+                std::thread::sleep(Duration::from_millis(100));
+                continue;
+            }
+
             let (chunk, has_finished, is_unchanged) = {
                 let s = state.lock().unwrap();
                 if s.is_transcript_unchanged() {
@@ -126,6 +154,18 @@ pub fn run_translation_loop(
                 continue;
             }
 
+            // Sentence mode: hold mid-sentence text back so it isn't retranslated on
+            // every tick, unless the speaker paused or it has waited too long
+            if on_sentence {
+                let at_boundary = chunk.as_deref().is_some_and(ends_sentence)
+                    || super::vad::time_since_speech() >= SENTENCE_PAUSE
+                    || last_run.elapsed() >= interval * SENTENCE_MAX_INTERVALS;
+                if !at_boundary {
+                    std::thread::sleep(Duration::from_millis(100));
+                    continue;
+                }
+            }
+
             if let Some(chunk) = chunk {
                 {
                     let mut s = state.lock().unwrap();
@@ -210,12 +250,10 @@ pub fn run_translation_loop(
                                             .get("x-ratelimit-limit-tokens")
                                             .and_then(|v| v.to_str().ok())
                                             .unwrap_or("?");
-                                        if let Ok(mut app) = APP.lock() {
-                                            app.model_usage_stats.insert(
-                                                "gpt-oss-120b".to_string(),
-                                                format!("{} / {}", remaining, limit),
-                                            );
-                                        }
+                                        record_model_usage(
+                                            "gpt-oss-120b".to_string(),
+                                            format!("{} / {}", remaining, limit),
+                                        );
                                     }
                                 }
                                 let reader =
@@ -395,12 +433,10 @@ fn handle_fallback_translation(
                             .get("x-ratelimit-limit-tokens")
                             .and_then(|v| v.to_str().ok())
                             .unwrap_or("?");
-                        if let Ok(mut app) = APP.lock() {
-                            app.model_usage_stats.insert(
-                                "gpt-oss-120b".to_string(),
-                                format!("{} / {}", remaining, limit),
-                            );
-                        }
+                        record_model_usage(
+                            "gpt-oss-120b".to_string(),
+                            format!("{} / {}", remaining, limit),
+                        );
                     }
                 }
                 let reader = std::io::BufReader::new(resp.into_body().into_reader());
@@ -444,41 +480,3 @@ fn handle_fallback_translation(
         }
     }
 }
-
-/// Unofficial Google Translate (GTX) fallback
-pub fn translate_with_google_gtx(text: &str, target_lang: &str) -> Option<String> {
-    let target_code = isolang::Language::from_name(target_lang)
-        .and_then(|lang| lang.to_639_1())
-        .map(|code| code.to_string())
-        .unwrap_or_else(|| "en".to_string());
-
-    let encoded_text = urlencoding::encode(text);
-    let url = format!(
-        "https://translate.googleapis.com/translate_a/single?client=gtx&sl=auto&tl={}&dt=t&q={}",
-        target_code, encoded_text
-    );
-
-    match UREQ_AGENT
-        .get(&url)
-        .header("User-Agent", "Mozilla/5.0")
-        .call()
-    {
-        Ok(resp) => {
-            if let Ok(json) = resp.into_body().read_json::<serde_json::Value>() {
-                if let Some(sentences) = json.get(0).and_then(|v| v.as_array()) {
-                    let mut full_text = String::new();
-                    for sentence_node in sentences {
-                        if let Some(segment) = sentence_node.get(0).and_then(|s| s.as_str()) {
-                            full_text.push_str(segment);
-                        }
-                    }
-                    if !full_text.is_empty() {
-                        return Some(full_text);
-                    }
-                }
-            }
-        }
-        Err(_) => {}
-    }
-    None
-}
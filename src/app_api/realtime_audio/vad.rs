@@ -0,0 +1,54 @@
+//! Voice activity detection from the capture RMS
+//!
+//! Every capture path reports its chunk RMS through `record_rms`. Once it has stayed
+//! under the configured threshold for `SILENCE_HOLD`, the translation loop stops
+//! triggering and the volume visualizer dims; the first loud chunk ends the silence.
+
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use super::REALTIME_RMS;
+
+/// How long the RMS must stay under the threshold before it counts as silence
+pub const SILENCE_HOLD: Duration = Duration::from_millis(2000);
+
+lazy_static::lazy_static! {
+    static ref EPOCH: Instant = Instant::now();
+}
+
+/// Threshold as f32 bits; 0 disables detection
+static THRESHOLD: AtomicU32 = AtomicU32::new(0);
+/// Milliseconds since `EPOCH` of the last chunk above the threshold
+static LAST_SPEECH_MS: AtomicU64 = AtomicU64::new(0);
+
+fn now_ms() -> u64 {
+    EPOCH.elapsed().as_millis() as u64
+}
+
+/// Set the speech threshold (RMS, 0..1) and start the session as "speaking"
+pub fn set_threshold(threshold: f32) {
+    THRESHOLD.store(threshold.max(0.0).to_bits(), Ordering::Relaxed);
+    LAST_SPEECH_MS.store(now_ms(), Ordering::Relaxed);
+}
+
+/// Publish a chunk's RMS for the visualizer and update the speech timestamp
+pub fn record_rms(rms: f32) {
+    REALTIME_RMS.store(rms.to_bits(), Ordering::Relaxed);
+    let threshold = f32::from_bits(THRESHOLD.load(Ordering::Relaxed));
+    if rms >= threshold {
+        LAST_SPEECH_MS.store(now_ms(), Ordering::Relaxed);
+    }
+}
+
+/// Time since the last chunk above the threshold
+pub fn time_since_speech() -> Duration {
+    Duration::from_millis(now_ms().saturating_sub(LAST_SPEECH_MS.load(Ordering::Relaxed)))
+}
+
+/// True while nobody has spoken for `SILENCE_HOLD` (always false when disabled)
+pub fn is_silent() -> bool {
+    if f32::from_bits(THRESHOLD.load(Ordering::Relaxed)) <= 0.0 {
+        return false;
+    }
+    time_since_speech() >= SILENCE_HOLD
+}
@@ -31,9 +31,11 @@ pub fn connect_websocket(
 
     // Connect TCP with a long timeout for initial handshake
     let tcp_stream = TcpStream::connect_timeout(&addr, Duration::from_secs(10))?;
-    // Use blocking mode with long timeout during setup
-    tcp_stream.set_read_timeout(Some(Duration::from_secs(30)))?;
-    tcp_stream.set_write_timeout(Some(Duration::from_secs(30)))?;
+    // Use blocking mode with the provider's timeout during setup
+    let setup_timeout =
+        crate::api::client::request_timeout(&crate::APP.lock().unwrap().config, "google");
+    tcp_stream.set_read_timeout(Some(setup_timeout))?;
+    tcp_stream.set_write_timeout(Some(setup_timeout))?;
     tcp_stream.set_nodelay(true)?;
 
     // Wrap with TLS
@@ -0,0 +1,129 @@
+//! API key checks for the settings screen
+//!
+//! Each provider gets the cheapest authenticated request it has (listing models or
+//! key info), so a test costs no tokens. The last result per provider is kept in
+//! memory together with the key it was made for.
+
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::client::agent_for;
+
+/// Outcome of the last test of a provider's key
+#[derive(Clone)]
+pub struct KeyCheck {
+    /// The key that was tested; the result is stale once the field changes
+    pub key: String,
+    pub result: Result<(), String>,
+    pub checked_at: chrono::DateTime<chrono::Local>,
+}
+
+lazy_static! {
+    static ref LAST_CHECKS: Mutex<HashMap<&'static str, KeyCheck>> = Mutex::new(HashMap::new());
+    static ref RUNNING: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+}
+
+/// Last result for `provider`, if it was made for `key`
+pub fn last_check(provider: &str, key: &str) -> Option<KeyCheck> {
+    LAST_CHECKS
+        .lock()
+        .unwrap()
+        .get(provider)
+        .filter(|c| c.key == key)
+        .cloned()
+}
+
+pub fn is_checking(provider: &str) -> bool {
+    RUNNING.lock().unwrap().iter().any(|p| *p == provider)
+}
+
+fn describe_error(e: ureq::Error) -> String {
+    match e {
+        ureq::Error::StatusCode(401) | ureq::Error::StatusCode(403) => {
+            "The key was rejected (invalid or expired)".to_string()
+        }
+        ureq::Error::StatusCode(400) => "The key is malformed".to_string(),
+        ureq::Error::StatusCode(code) => format!("The server answered HTTP {}", code),
+        e => e.to_string(),
+    }
+}
+
+/// `agent_for` with the app's configured timeouts
+fn agent(provider: &str) -> ureq::Agent {
+    agent_for(&crate::APP.lock().unwrap().config, provider)
+}
+
+fn bearer_get(provider: &str, url: &str, key: &str) -> Result<(), String> {
+    agent(provider)
+        .get(url)
+        .header("Authorization", &format!("Bearer {}", key))
+        .call()
+        .map(|_| ())
+        .map_err(describe_error)
+}
+
+/// One authenticated request against `provider` ("groq", "cerebras", "google",
+/// "openrouter"). For Gemini this also opens and closes a Live session socket, which
+/// confirms the key works for live translate too.
+pub fn validate_key(provider: &str, key: &str) -> Result<(), String> {
+    let key = key.trim();
+    if key.is_empty() {
+        return Err("No key entered".to_string());
+    }
+    if !super::client::is_online(provider) {
+        return Err("No internet connection".to_string());
+    }
+
+    match provider {
+        "groq" => bearer_get(provider, "https://api.groq.com/openai/v1/models", key),
+        "cerebras" => bearer_get(provider, "https://api.cerebras.ai/v1/models", key),
+        // The model list is public there; the key endpoint needs a valid key
+        "openrouter" => bearer_get(provider, "https://openrouter.ai/api/v1/key", key),
+        "google" => {
+            agent(provider)
+                .get("https://generativelanguage.googleapis.com/v1beta/models?pageSize=1")
+                .header("x-goog-api-key", key)
+                .call()
+                .map_err(describe_error)?;
+            let mut socket = super::realtime_audio::websocket::connect_websocket(key)
+                .map_err(|e| format!("Live API: {}", e))?;
+            let _ = socket.close(None);
+            Ok(())
+        }
+        _ => Err(format!("Unknown provider {}", provider)),
+    }
+}
+
+/// Test `key` on a worker thread, then call `on_done` (e.g. to repaint). Does nothing
+/// while a test for `provider` is already running.
+pub fn validate_key_in_background(
+    provider: &'static str,
+    key: String,
+    on_done: impl FnOnce() + Send + 'static,
+) {
+    {
+        let mut running = RUNNING.lock().unwrap();
+        if running.contains(&provider) {
+            return;
+        }
+        running.push(provider);
+    }
+
+    std::thread::spawn(move || {
+        let result = validate_key(provider, &key);
+        if let Err(e) = &result {
+            log::warn!("{} key test failed: {}", provider, e);
+        }
+        LAST_CHECKS.lock().unwrap().insert(
+            provider,
+            KeyCheck {
+                key,
+                result,
+                checked_at: chrono::Local::now(),
+            },
+        );
+        RUNNING.lock().unwrap().retain(|p| *p != provider);
+        on_done();
+    });
+}
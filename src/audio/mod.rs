@@ -0,0 +1,3 @@
+//! System audio helpers shared by overlays.
+
+pub mod sessions;
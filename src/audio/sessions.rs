@@ -0,0 +1,120 @@
+//! WASAPI audio sessions of the default playback device: list them per process and
+//! set their volume.
+
+use windows::core::{Interface, Result, PWSTR};
+use windows::Win32::Foundation::CloseHandle;
+use windows::Win32::Media::Audio::{
+    eMultimedia, eRender, IAudioSessionControl2, IAudioSessionManager2, IMMDeviceEnumerator,
+    ISimpleAudioVolume, MMDeviceEnumerator,
+};
+use windows::Win32::System::Com::{
+    CoCreateInstance, CoInitializeEx, CLSCTX_ALL, COINIT_APARTMENTTHREADED,
+};
+use windows::Win32::System::Threading::{
+    OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
+};
+
+/// One process's audio on the default playback device
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct AudioSession {
+    /// 0 for the system sounds session
+    pub pid: u32,
+    /// Executable name without extension (empty for system sounds)
+    pub name: String,
+    /// 0.0 - 1.0
+    pub volume: f32,
+}
+
+/// Call `f` with every session of the default playback device
+unsafe fn for_each_session(
+    mut f: impl FnMut(u32, &IAudioSessionControl2, &ISimpleAudioVolume),
+) -> Result<()> {
+    // May already be initialized on this thread; that's fine
+    let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+
+    let device_enumerator: IMMDeviceEnumerator =
+        CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)?;
+    let device = device_enumerator.GetDefaultAudioEndpoint(eRender, eMultimedia)?;
+    let session_manager: IAudioSessionManager2 = device.Activate(CLSCTX_ALL, None)?;
+    let session_enumerator = session_manager.GetSessionEnumerator()?;
+    let count = session_enumerator.GetCount()?;
+
+    for i in 0..count {
+        let Ok(session_control) = session_enumerator.GetSession(i) else {
+            continue;
+        };
+        let (Ok(control2), Ok(simple_volume)) = (
+            session_control.cast::<IAudioSessionControl2>(),
+            session_control.cast::<ISimpleAudioVolume>(),
+        ) else {
+            continue;
+        };
+        if let Ok(pid) = control2.GetProcessId() {
+            f(pid, &control2, &simple_volume);
+        }
+    }
+    Ok(())
+}
+
+fn process_name(pid: u32) -> Option<String> {
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let mut buffer = [0u16; 1024];
+        let mut size = buffer.len() as u32;
+        let result = QueryFullProcessImageNameW(
+            handle,
+            PROCESS_NAME_WIN32,
+            PWSTR(buffer.as_mut_ptr()),
+            &mut size,
+        );
+        let _ = CloseHandle(handle);
+        result.ok()?;
+
+        let path = String::from_utf16_lossy(&buffer[..size as usize]);
+        std::path::Path::new(&path)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+    }
+}
+
+/// Sessions of the default playback device, one entry per process (a process with
+/// several sessions reports the first one's volume)
+pub fn list_sessions() -> Result<Vec<AudioSession>> {
+    let mut sessions: Vec<AudioSession> = Vec::new();
+    unsafe {
+        for_each_session(|pid, _, simple_volume| {
+            if sessions.iter().any(|s| s.pid == pid) {
+                return;
+            }
+            let name = if pid == 0 {
+                String::new()
+            } else {
+                match process_name(pid) {
+                    Some(name) => name,
+                    // Exited (or inaccessible) process
+                    None => return,
+                }
+            };
+            let volume = simple_volume.GetMasterVolume().unwrap_or(1.0);
+            sessions.push(AudioSession { pid, name, volume });
+        })?;
+    }
+    Ok(sessions)
+}
+
+/// Set the volume (0.0 - 1.0) of every session whose process id passes `filter`
+pub fn set_volume_where(filter: impl Fn(u32) -> bool, volume: f32) -> Result<()> {
+    let volume = volume.clamp(0.0, 1.0);
+    unsafe {
+        for_each_session(|pid, _, simple_volume| {
+            if filter(pid) {
+                let _ = simple_volume.SetMasterVolume(volume, std::ptr::null());
+            }
+        })
+    }
+}
+
+/// Set the volume (0.0 - 1.0) of one process's sessions
+pub fn set_volume(pid: u32, volume: f32) -> Result<()> {
+    set_volume_where(|session_pid| session_pid == pid, volume)
+}
@@ -0,0 +1,246 @@
+//! Whole-setup backup bundles (`.sgtbackup`).
+//!
+//! A bundle is a zip archive holding:
+//! - `manifest.json`: format marker, bundle version, app version, whether API keys were redacted
+//! - `config.json`: the full `Config`
+//! - `history.json` + `history_media/*`: the result history
+
+use std::io::{Read, Write};
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::config::{config_file_json, config_from_json, get_config_path, Config};
+use crate::history::{history_media_dir, HistoryItem};
+
+/// File extension of backup bundles (without the dot)
+pub const BACKUP_EXTENSION: &str = "sgtbackup";
+
+const MANIFEST_FORMAT: &str = "screen-goated-toolbox-backup";
+const BUNDLE_VERSION: u32 = 1;
+
+const MANIFEST_ENTRY: &str = "manifest.json";
+const CONFIG_ENTRY: &str = "config.json";
+const HISTORY_ENTRY: &str = "history.json";
+const MEDIA_PREFIX: &str = "history_media/";
+
+#[derive(Serialize, Deserialize)]
+struct Manifest {
+    format: String,
+    bundle_version: u32,
+    app_version: String,
+    created: String,
+    api_keys_redacted: bool,
+}
+
+/// A validated bundle, ready to be applied
+pub struct BackupBundle {
+    pub config: Config,
+    pub api_keys_redacted: bool,
+    pub history: Vec<HistoryItem>,
+    pub history_media: Vec<(String, Vec<u8>)>,
+}
+
+impl BackupBundle {
+    /// True if applying the bundle would replace API keys the user currently has
+    pub fn overwrites_api_keys(&self, current: &Config) -> bool {
+        !self.api_keys_redacted
+            && api_keys(current)
+                .iter()
+                .zip(api_keys(&self.config).iter())
+                .any(|(cur, new)| !cur.is_empty() && cur != new)
+    }
+}
+
+fn api_keys(config: &Config) -> [&String; 5] {
+    [
+        &config.api_key,
+        &config.gemini_api_key,
+        &config.openrouter_api_key,
+        &config.cerebras_api_key,
+        &config.http_api_token,
+    ]
+}
+
+fn api_keys_mut(config: &mut Config) -> [&mut String; 5] {
+    [
+        &mut config.api_key,
+        &mut config.gemini_api_key,
+        &mut config.openrouter_api_key,
+        &mut config.cerebras_api_key,
+        &mut config.http_api_token,
+    ]
+}
+
+/// Write `config` and the current history to a bundle at `path`
+pub fn export_backup(
+    path: &Path,
+    config: &Config,
+    history: &[HistoryItem],
+    redact_api_keys: bool,
+) -> Result<()> {
+    let mut config = config.clone();
+    if redact_api_keys {
+        for key in api_keys_mut(&mut config) {
+            key.clear();
+        }
+    }
+
+    let manifest = Manifest {
+        format: MANIFEST_FORMAT.to_string(),
+        bundle_version: BUNDLE_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created: chrono::Local::now().to_rfc3339(),
+        api_keys_redacted: redact_api_keys,
+    };
+
+    let file = std::fs::File::create(path).context("Failed to create backup file")?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+
+    zip.start_file(MANIFEST_ENTRY, options)?;
+    zip.write_all(serde_json::to_string_pretty(&manifest)?.as_bytes())?;
+
+    zip.start_file(CONFIG_ENTRY, options)?;
+    zip.write_all(serde_json::to_string_pretty(&config)?.as_bytes())?;
+
+    zip.start_file(HISTORY_ENTRY, options)?;
+    zip.write_all(serde_json::to_string_pretty(history)?.as_bytes())?;
+
+    let media_dir = history_media_dir();
+    for item in history.iter().filter(|i| !i.media_path.is_empty()) {
+        // Media files may have been removed by hand; skip what's missing
+        if let Ok(data) = std::fs::read(media_dir.join(&item.media_path)) {
+            zip.start_file(format!("{}{}", MEDIA_PREFIX, item.media_path), options)?;
+            zip.write_all(&data)?;
+        }
+    }
+
+    zip.finish()?;
+    Ok(())
+}
+
+/// Read and validate a bundle without applying anything
+pub fn read_backup(path: &Path) -> Result<BackupBundle> {
+    let file = std::fs::File::open(path).context("Failed to open backup file")?;
+    let mut archive = zip::ZipArchive::new(file).context("Not a valid backup file")?;
+
+    let manifest: Manifest = serde_json::from_str(&read_entry(&mut archive, MANIFEST_ENTRY)?)
+        .context("Invalid backup manifest")?;
+    if manifest.format != MANIFEST_FORMAT {
+        return Err(anyhow!("Not a Screen Goated Toolbox backup"));
+    }
+    if manifest.bundle_version > BUNDLE_VERSION {
+        return Err(anyhow!(
+            "Backup was made by a newer version ({}); please update first",
+            manifest.app_version
+        ));
+    }
+
+    let config = config_from_json(&read_entry(&mut archive, CONFIG_ENTRY)?)
+        .ok_or_else(|| anyhow!("Backup contains an invalid config"))?;
+
+    let history: Vec<HistoryItem> = match read_entry(&mut archive, HISTORY_ENTRY) {
+        Ok(data) => serde_json::from_str(&data).context("Backup contains an invalid history")?,
+        Err(_) => Vec::new(),
+    };
+
+    let mut history_media = Vec::new();
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        // Only plain file names: never let a bundle write outside the media folder
+        let Some(name) = entry
+            .name()
+            .strip_prefix(MEDIA_PREFIX)
+            .filter(|n| !n.is_empty() && !n.contains(['/', '\\']) && *n != "..")
+            .map(|n| n.to_string())
+        else {
+            continue;
+        };
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data)?;
+        history_media.push((name, data));
+    }
+
+    Ok(BackupBundle {
+        config,
+        api_keys_redacted: manifest.api_keys_redacted,
+        history,
+        history_media,
+    })
+}
+
+/// Apply a bundle: the current config is saved next to the config file first
+/// (`*.pre-import.bak`), then the history is replaced and the new config returned.
+/// API keys are taken from the bundle only when `use_bundle_api_keys` is set.
+pub fn apply_backup(
+    bundle: BackupBundle,
+    current: &Config,
+    use_bundle_api_keys: bool,
+) -> Result<Config> {
+    let config_path = get_config_path();
+    let mut backup_name = config_path.file_name().unwrap_or_default().to_os_string();
+    backup_name.push(".pre-import.bak");
+    write_pre_import_backup(&config_path.with_file_name(backup_name), current)?;
+
+    let mut config = bundle.config;
+    if bundle.api_keys_redacted || !use_bundle_api_keys {
+        for (key, current_key) in api_keys_mut(&mut config).into_iter().zip(api_keys(current)) {
+            *key = current_key.clone();
+        }
+    }
+
+    if let Ok(app) = crate::APP.lock() {
+        app.history.restore(bundle.history, bundle.history_media);
+    }
+
+    Ok(config)
+}
+
+/// Keep `current` at `path` the way the config file stores it (keys encrypted
+/// when the config says so), never as plain JSON
+fn write_pre_import_backup(path: &Path, current: &Config) -> Result<()> {
+    let data =
+        config_file_json(current).ok_or_else(|| anyhow!("Failed to serialize the config"))?;
+    std::fs::write(path, data).context("Failed to back up the current config")
+}
+
+fn read_entry(archive: &mut zip::ZipArchive<std::fs::File>, name: &str) -> Result<String> {
+    let mut entry = archive
+        .by_name(name)
+        .map_err(|_| anyhow!("Backup is missing {}", name))?;
+    let mut data = String::new();
+    entry.read_to_string(&mut data)?;
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pre_import_backup_has_no_raw_keys() {
+        let dir = std::env::temp_dir().join(format!("sgt-backup-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config_v3.json.pre-import.bak");
+        let current = Config {
+            api_key: "gsk_raw_test_key".to_string(),
+            gemini_api_key: "AIza_raw_test_key".to_string(),
+            encrypt_api_keys: true,
+            ..Default::default()
+        };
+
+        write_pre_import_backup(&path, &current).unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(!written.contains("gsk_raw_test_key"));
+        assert!(!written.contains("AIza_raw_test_key"));
+        // Still the same keys once read back on this PC
+        let restored = config_from_json(&written).unwrap();
+        assert_eq!(restored.api_key, "gsk_raw_test_key");
+        assert_eq!(restored.gemini_api_key, "AIza_raw_test_key");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
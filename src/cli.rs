@@ -0,0 +1,187 @@
+//! Headless command-line mode for scripting / CI.
+//!
+//! `screen-goated-toolbox.exe translate [--from auto] [--to vi] [--provider gemini] [--model id] "text"`
+//!
+//! Prints the translation to stdout and exits without creating any window, tray icon
+//! or overlay. Text is read from stdin when it's omitted or given as `-`.
+
+use std::io::{Read, Write};
+
+use crate::model_config::{get_all_models, ModelType};
+use screen_goated_toolbox::DEFAULT_TEXT_MODEL;
+
+const EXIT_OK: i32 = 0;
+const EXIT_API_ERROR: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+
+const USAGE: &str = "Usage: screen-goated-toolbox translate [--from <lang|auto>] [--to <lang>] \
+[--provider <groq|gemini|cerebras|openrouter|gtx>] [--model <model id>] [text|-]";
+
+struct TranslateArgs {
+    from: String,
+    to: Option<String>,
+    provider: Option<String>,
+    model: Option<String>,
+    text: Option<String>,
+    /// `-h`/`--help` was given: print the usage and exit
+    help: bool,
+}
+
+/// Run a CLI subcommand if one was given.
+/// Returns the process exit code, or `None` when the GUI should start as usual.
+pub fn run_from_args() -> Option<i32> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(|s| s.as_str()) {
+        Some("translate") => {
+            attach_parent_console();
+            Some(run_translate(&args[1..]))
+        }
+        _ => None,
+    }
+}
+
+/// Release builds use the windows subsystem, so stdout/stderr go nowhere unless we
+/// borrow the console of the shell that launched us. Redirected handles (pipes in CI)
+/// are inherited either way.
+fn attach_parent_console() {
+    use windows::Win32::System::Console::{AttachConsole, ATTACH_PARENT_PROCESS};
+    unsafe {
+        let _ = AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
+fn run_translate(args: &[String]) -> i32 {
+    let parsed = match parse_translate_args(args) {
+        Ok(p) => p,
+        Err(msg) => {
+            eprintln!("{}\n{}", msg, USAGE);
+            return EXIT_USAGE;
+        }
+    };
+    if parsed.help {
+        println!("{}", USAGE);
+        return EXIT_OK;
+    }
+
+    let text = match parsed.text.as_deref() {
+        Some(t) if t != "-" => t.to_string(),
+        _ => {
+            let mut buf = String::new();
+            if let Err(e) = std::io::stdin().read_to_string(&mut buf) {
+                eprintln!("Failed to read stdin: {}", e);
+                return EXIT_USAGE;
+            }
+            buf
+        }
+    };
+    if text.trim().is_empty() {
+        eprintln!("Nothing to translate.\n{}", USAGE);
+        return EXIT_USAGE;
+    }
+
+    let model_id = match resolve_model_id(parsed.provider.as_deref(), parsed.model) {
+        Ok(id) => id,
+        Err(msg) => {
+            eprintln!("{}", msg);
+            return EXIT_USAGE;
+        }
+    };
+
+    // Only the config is loaded (for API keys); no app state, tray, overlays or warmups
+    let config = crate::config::load_config();
+    let target = parsed
+        .to
+        .unwrap_or_else(|| config.realtime_target_language.clone());
+
+    match crate::api::text::translate_text_blocking(
+        &config,
+        text.trim(),
+        &language_name(&parsed.from),
+        &language_name(&target),
+        &model_id,
+    ) {
+        Ok(result) => {
+            let mut stdout = std::io::stdout();
+            let _ = writeln!(stdout, "{}", result.trim());
+            let _ = stdout.flush();
+            EXIT_OK
+        }
+        Err(e) => {
+            eprintln!("Translation failed: {}", e);
+            EXIT_API_ERROR
+        }
+    }
+}
+
+fn parse_translate_args(args: &[String]) -> Result<TranslateArgs, String> {
+    let mut parsed = TranslateArgs {
+        from: "auto".to_string(),
+        to: None,
+        provider: None,
+        model: None,
+        text: None,
+        help: false,
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = |name: &str| {
+            iter.next()
+                .cloned()
+                .ok_or_else(|| format!("Missing value for {}", name))
+        };
+        match arg.as_str() {
+            "--from" => parsed.from = value("--from")?,
+            "--to" => parsed.to = Some(value("--to")?),
+            "--provider" => parsed.provider = Some(value("--provider")?),
+            "--model" => parsed.model = Some(value("--model")?),
+            "-h" | "--help" => {
+                parsed.help = true;
+                return Ok(parsed);
+            }
+            flag if flag.starts_with("--") => return Err(format!("Unknown option: {}", flag)),
+            text => {
+                if parsed.text.is_some() {
+                    return Err("Only one text argument is allowed (quote it)".to_string());
+                }
+                parsed.text = Some(text.to_string());
+            }
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Pick the model: an explicit `--model` wins, otherwise the first text model of `--provider`
+fn resolve_model_id(provider: Option<&str>, model: Option<String>) -> Result<String, String> {
+    if let Some(model) = model {
+        return Ok(model);
+    }
+    let Some(provider) = provider else {
+        return Ok(DEFAULT_TEXT_MODEL.to_string());
+    };
+
+    let provider_key = match provider.to_lowercase().as_str() {
+        "gemini" | "google" => "google",
+        "gtx" | "google-gtx" => "google-gtx",
+        other => other,
+    }
+    .to_string();
+
+    get_all_models()
+        .iter()
+        .find(|m| m.enabled && m.model_type == ModelType::Text && m.provider == provider_key)
+        .map(|m| m.id.clone())
+        .ok_or_else(|| format!("No text model available for provider '{}'", provider))
+}
+
+/// Accept ISO 639-1 codes (`vi`) as well as English names (`Vietnamese`)
+fn language_name(lang: &str) -> String {
+    if lang.eq_ignore_ascii_case("auto") {
+        return "auto".to_string();
+    }
+    isolang::Language::from_639_1(&lang.to_lowercase())
+        .map(|l| l.to_name().to_string())
+        .unwrap_or_else(|| lang.to_string())
+}
@@ -1,13 +1,23 @@
 //! Main Config struct definition.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use crate::config::preset::{get_default_presets, Preset};
 use crate::config::types::{
-    default_tts_language_conditions, get_system_ui_language, EdgeTtsSettings, ThemeMode,
-    TtsLanguageCondition, TtsMethod, DEFAULT_HISTORY_LIMIT,
+    default_tts_language_conditions, get_system_ui_language, AppHotkey, EdgeTtsSettings, Hotkey,
+    ThemeMode, TtsLanguageCondition, TtsMethod, UpdateChannel, WebViewLifecycle,
+    DEFAULT_HISTORY_LIMIT,
 };
 
+/// Current on-disk config layout version.
+/// Bump it and add a step to `io::migrate` whenever fields are renamed or reshaped.
+pub const CONFIG_VERSION: u32 = 2;
+
+/// Allowed `ui_scale` values: the settings slider's range, and what a hand-edited
+/// config is clamped to
+pub const UI_SCALE_RANGE: std::ops::RangeInclusive<f32> = 0.75..=2.0;
+
 // ============================================================================
 // SERDE DEFAULT FUNCTIONS
 // ============================================================================
@@ -16,6 +26,10 @@ fn default_true() -> bool {
     true
 }
 
+fn default_quick_translate_language() -> String {
+    "Vietnamese".to_string()
+}
+
 fn default_history_limit() -> usize {
     DEFAULT_HISTORY_LIMIT
 }
@@ -24,6 +38,14 @@ fn default_graphics_mode() -> String {
     "standard".to_string()
 }
 
+fn default_sidebar_layout() -> String {
+    "columns".to_string()
+}
+
+fn default_ui_scale() -> f32 {
+    1.0
+}
+
 fn default_tts_voice() -> String {
     "Aoede".to_string()
 }
@@ -32,6 +54,10 @@ fn default_tts_speed() -> String {
     "Fast".to_string()
 }
 
+fn default_tts_playback_speed() -> u32 {
+    100
+}
+
 fn default_tts_method() -> TtsMethod {
     TtsMethod::GeminiLive
 }
@@ -60,16 +86,82 @@ fn default_realtime_target_language() -> String {
     "Vietnamese".to_string()
 }
 
+fn default_realtime_translation_interval_ms() -> u64 {
+    1500
+}
+
+fn default_realtime_caption_hotkey() -> Hotkey {
+    // Ctrl + Alt + C
+    Hotkey::new(0x43, "Ctrl + Alt + C", 0x0002 | 0x0001)
+}
+
+fn default_realtime_vad_threshold() -> f32 {
+    0.01
+}
+
+fn default_http_api_port() -> u16 {
+    47821
+}
+
+fn default_log_level() -> String {
+    "error".to_string()
+}
+
+fn default_update_check_interval_hours() -> u32 {
+    24
+}
+
+fn default_selection_dim_opacity() -> u8 {
+    120
+}
+
+fn default_selection_border_color() -> [u8; 3] {
+    [255, 255, 255]
+}
+
+fn default_update_quiet_start() -> u8 {
+    22
+}
+
+fn default_update_quiet_end() -> u8 {
+    8
+}
+
 fn default_ollama_base_url() -> String {
     "http://localhost:11434".to_string()
 }
 
+fn default_request_timeout_secs() -> HashMap<String, u64> {
+    // Cloud providers fail fast; a local model on a slow machine may take minutes
+    let mut timeouts: HashMap<String, u64> = ["groq", "google", "cerebras", "openrouter"]
+        .into_iter()
+        .map(|provider| (provider.to_string(), 60))
+        .collect();
+    timeouts.insert("ollama".to_string(), 300);
+    timeouts
+}
+
+fn default_wheel_radius() -> u32 {
+    80
+}
+
+fn default_wheel_item_size() -> u32 {
+    12
+}
+
 // ============================================================================
 // CONFIG STRUCT
 // ============================================================================
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Config {
+    // -------------------------------------------------------------------------
+    // Schema
+    // -------------------------------------------------------------------------
+    /// On-disk layout version (missing in files written before versioning = 0)
+    #[serde(default)]
+    pub config_version: u32,
+
     // -------------------------------------------------------------------------
     // API Keys
     // -------------------------------------------------------------------------
@@ -87,6 +179,35 @@ pub struct Config {
     #[serde(default)]
     pub cerebras_api_key: String,
 
+    /// Store the API keys DPAPI-encrypted in the config file. Encrypted keys only
+    /// decrypt for the same Windows user, so turn off to sync the file across machines.
+    #[serde(default = "default_true")]
+    pub encrypt_api_keys: bool,
+    /// Check the OCR'd text of image presets for personal data and ask before it is
+    /// sent on to the next blocks
+    #[serde(default)]
+    pub privacy_scan_enabled: bool,
+    /// Regexes for the check, one per entry
+    #[serde(default = "crate::privacy::default_patterns")]
+    pub privacy_patterns: Vec<String>,
+    /// Empty the clipboard on exit if it still holds the text the app copied last
+    #[serde(default)]
+    pub clear_clipboard_on_exit: bool,
+    /// Empty the clipboard this many seconds after the app copies text, unless
+    /// something else was copied since (0 = keep)
+    #[serde(default)]
+    pub clipboard_clear_after_secs: u32,
+
+    // -------------------------------------------------------------------------
+    // Global Prompt
+    // -------------------------------------------------------------------------
+    /// Put before the prompt of every LLM block (presets can opt out)
+    #[serde(default)]
+    pub global_system_prefix: String,
+    /// Put after the prompt of every LLM block
+    #[serde(default)]
+    pub global_system_suffix: String,
+
     // -------------------------------------------------------------------------
     // Presets
     // -------------------------------------------------------------------------
@@ -96,6 +217,45 @@ pub struct Config {
     /// Index of the currently active preset
     pub active_preset_idx: usize,
 
+    // -------------------------------------------------------------------------
+    // App Hotkeys (registered alongside the preset hotkeys)
+    // -------------------------------------------------------------------------
+    #[serde(default)]
+    pub open_settings_hotkey: Option<Hotkey>,
+
+    /// Closes result windows and selections, stops live translate
+    #[serde(default)]
+    pub dismiss_overlays_hotkey: Option<Hotkey>,
+
+    #[serde(default)]
+    pub toggle_bubble_hotkey: Option<Hotkey>,
+
+    #[serde(default)]
+    pub repeat_last_hotkey: Option<Hotkey>,
+
+    #[serde(default)]
+    pub translate_clipboard_hotkey: Option<Hotkey>,
+
+    #[serde(default)]
+    pub translate_selection_hotkey: Option<Hotkey>,
+
+    #[serde(default)]
+    pub undo_replace_hotkey: Option<Hotkey>,
+
+    /// Flash a toast with the preset name as soon as its hotkey is pressed
+    #[serde(default)]
+    pub hotkey_feedback: bool,
+
+    // -------------------------------------------------------------------------
+    // Quick Translate (tray and hotkey actions that need no preset)
+    // -------------------------------------------------------------------------
+    #[serde(default = "default_quick_translate_language")]
+    pub quick_translate_language: String,
+
+    /// Provider the quick translation runs on; None follows the global provider settings
+    #[serde(default)]
+    pub quick_translate_provider: Option<String>,
+
     // -------------------------------------------------------------------------
     // UI Settings
     // -------------------------------------------------------------------------
@@ -114,6 +274,27 @@ pub struct Config {
     #[serde(default = "default_graphics_mode")]
     pub graphics_mode: String,
 
+    /// Sidebar layout: "columns" (three-column grid) or "compact" (single list)
+    #[serde(default = "default_sidebar_layout")]
+    pub sidebar_layout: String,
+
+    /// UI scale multiplier on top of the system DPI (1.0 = 100%)
+    #[serde(default = "default_ui_scale")]
+    pub ui_scale: f32,
+
+    /// Font for the realtime, result and PromptDJ windows: a family name,
+    /// "system", or empty for the bundled Google Sans Flex
+    #[serde(default)]
+    pub overlay_font_family: String,
+
+    /// Darkening of the screen around a region selection (0 = none, 255 = black)
+    #[serde(default = "default_selection_dim_opacity")]
+    pub selection_dim_opacity: u8,
+
+    /// Border of the region being selected, RGB
+    #[serde(default = "default_selection_border_color")]
+    pub selection_border_color: [u8; 3],
+
     // -------------------------------------------------------------------------
     // Startup Behavior
     // -------------------------------------------------------------------------
@@ -125,6 +306,37 @@ pub struct Config {
     #[serde(default)]
     pub run_as_admin_on_startup: bool,
 
+    /// Create overlay WebViews on first use instead of warming them up at startup
+    #[serde(default)]
+    pub disable_warmups: bool,
+
+    /// Whether the Prompt DJ and Live Translate WebViews stay alive while hidden.
+    /// Result windows aren't affected: each creates its WebView when opened and
+    /// drops it when closed, and the shared warmup WebView they are built from
+    /// must stay alive for them to open at all.
+    #[serde(default)]
+    pub webview_lifecycle: WebViewLifecycle,
+
+    /// Releases offered by the updater (Beta includes GitHub pre-releases)
+    #[serde(default)]
+    pub update_channel: UpdateChannel,
+
+    /// Hours between background update checks while the app runs (0 = only at startup)
+    #[serde(default = "default_update_check_interval_hours")]
+    pub update_check_interval_hours: u32,
+
+    /// No background checks or update popups between these hours (local time)
+    #[serde(default)]
+    pub update_quiet_hours_enabled: bool,
+    #[serde(default = "default_update_quiet_start")]
+    pub update_quiet_start: u8,
+    #[serde(default = "default_update_quiet_end")]
+    pub update_quiet_end: u8,
+
+    /// Unix time of the last automatic update check
+    #[serde(default)]
+    pub last_update_check: i64,
+
     // -------------------------------------------------------------------------
     // API Provider Toggles
     // -------------------------------------------------------------------------
@@ -163,6 +375,13 @@ pub struct Config {
     #[serde(default)]
     pub ollama_text_model: String,
 
+    // -------------------------------------------------------------------------
+    // Request Timeouts
+    // -------------------------------------------------------------------------
+    /// Seconds to wait for a provider to start responding, keyed by provider id
+    #[serde(default = "default_request_timeout_secs")]
+    pub request_timeout_secs: HashMap<String, u64>,
+
     // -------------------------------------------------------------------------
     // Realtime Audio Settings
     // -------------------------------------------------------------------------
@@ -194,6 +413,38 @@ pub struct Config {
     #[serde(default = "default_realtime_target_language")]
     pub realtime_target_language: String,
 
+    /// Label speaker turns in the realtime transcription
+    #[serde(default)]
+    pub realtime_speaker_labels: bool,
+
+    /// Audio level (RMS, 0..1) under which live translation pauses; 0 disables
+    #[serde(default = "default_realtime_vad_threshold")]
+    pub realtime_vad_threshold: f32,
+
+    /// How often live translation runs (milliseconds)
+    #[serde(default = "default_realtime_translation_interval_ms")]
+    pub realtime_translation_interval_ms: u64,
+
+    /// Translate when a sentence ends or the speaker pauses instead of on every tick
+    #[serde(default)]
+    pub realtime_translate_on_sentence: bool,
+
+    /// Show live text as a caption bar docked to the bottom of the screen
+    #[serde(default)]
+    pub realtime_caption_mode: bool,
+
+    /// Toggles the caption bar while a live session runs
+    #[serde(default = "default_realtime_caption_hotkey")]
+    pub realtime_caption_hotkey: Hotkey,
+
+    /// Append finished live segments to a markdown notes file during the session
+    #[serde(default)]
+    pub realtime_autosave: bool,
+
+    /// Folder for live session notes; empty uses Documents/Screen Goated Toolbox/Live notes
+    #[serde(default)]
+    pub realtime_autosave_dir: String,
+
     // -------------------------------------------------------------------------
     // TTS Settings
     // -------------------------------------------------------------------------
@@ -209,6 +460,11 @@ pub struct Config {
     #[serde(default = "default_tts_speed")]
     pub tts_speed: String,
 
+    /// Playback speed of read-aloud results in percent (100 = 1.0x), and the starting
+    /// speed of realtime TTS
+    #[serde(default = "default_tts_playback_speed")]
+    pub tts_playback_speed: u32,
+
     /// TTS output device ID
     #[serde(default)]
     pub tts_output_device: String,
@@ -236,12 +492,77 @@ pub struct Config {
     #[serde(default)]
     pub favorites_keep_open: bool,
 
+    /// Show one bubble per favorite preset instead of a single bubble with a panel
+    #[serde(default)]
+    pub favorite_bubbles_separate: bool,
+
+    /// Positions of the per-preset bubbles (physical pixels), keyed by preset id
+    #[serde(default)]
+    pub favorite_bubble_positions: HashMap<String, (i32, i32)>,
+
+    // -------------------------------------------------------------------------
+    // Preset Wheel
+    // -------------------------------------------------------------------------
+    /// Reach of the wheel's hover magnification (px); item spacing follows it
+    #[serde(default = "default_wheel_radius")]
+    pub wheel_radius: u32,
+
+    /// Font size of the wheel's preset items (px)
+    #[serde(default = "default_wheel_item_size")]
+    pub wheel_item_size: u32,
+
+    /// Releasing the keys held when the wheel opened picks the hovered preset
+    #[serde(default)]
+    pub wheel_release_to_select: bool,
+
+    // -------------------------------------------------------------------------
+    // Screen Selection
+    // -------------------------------------------------------------------------
+    /// Keep the box after the drag so it can be nudged with the arrow keys;
+    /// Enter confirms it
+    #[serde(default)]
+    pub selection_adjust_before_confirm: bool,
+
+    // -------------------------------------------------------------------------
+    // Prompt DJ
+    // -------------------------------------------------------------------------
+    /// Last Prompt DJ window rect (x, y, width, height; physical pixels)
+    #[serde(default)]
+    pub prompt_dj_window_rect: Option<(i32, i32, i32, i32)>,
+
+    // -------------------------------------------------------------------------
+    // HTTP Control API
+    // -------------------------------------------------------------------------
+    /// Enable the local (127.0.0.1) HTTP control API
+    #[serde(default)]
+    pub http_api_enabled: bool,
+
+    /// Port of the HTTP control API
+    #[serde(default = "default_http_api_port")]
+    pub http_api_port: u16,
+
+    /// Token required in the `X-SGT-Token` header
+    #[serde(default)]
+    pub http_api_token: String,
+
+    // -------------------------------------------------------------------------
+    // Diagnostics
+    // -------------------------------------------------------------------------
+    /// Verbosity of `sgt.log`: "off", "error", "info" or "debug"
+    #[serde(default = "default_log_level")]
+    pub log_level: String,
+
     // -------------------------------------------------------------------------
     // Maintenance Flags
     // -------------------------------------------------------------------------
     /// Clear WebView data on next startup (for MIDI permission reset)
     #[serde(default)]
     pub clear_webview_on_startup: bool,
+
+    /// App version that last ran with this config, to show "What's new" once after an
+    /// update. Missing in configs from before it was tracked, which counts as an update.
+    #[serde(default)]
+    pub last_seen_version: String,
 }
 
 // ============================================================================
@@ -251,25 +572,60 @@ pub struct Config {
 impl Default for Config {
     fn default() -> Self {
         Self {
+            // Schema
+            config_version: CONFIG_VERSION,
+
             // API Keys
             api_key: String::new(),
             gemini_api_key: String::new(),
             openrouter_api_key: String::new(),
             cerebras_api_key: String::new(),
+            encrypt_api_keys: true,
+            privacy_scan_enabled: false,
+            privacy_patterns: crate::privacy::default_patterns(),
+            clear_clipboard_on_exit: false,
+            clipboard_clear_after_secs: 0,
+            global_system_prefix: String::new(),
+            global_system_suffix: String::new(),
 
             // Presets - use the centralized ordered list
             presets: get_default_presets(),
             active_preset_idx: 0,
 
+            // App Hotkeys
+            open_settings_hotkey: None,
+            dismiss_overlays_hotkey: None,
+            toggle_bubble_hotkey: None,
+            repeat_last_hotkey: None,
+            translate_clipboard_hotkey: None,
+            translate_selection_hotkey: None,
+            undo_replace_hotkey: None,
+            hotkey_feedback: false,
+            quick_translate_language: default_quick_translate_language(),
+            quick_translate_provider: None,
+
             // UI Settings
             theme_mode: ThemeMode::System,
             ui_language: get_system_ui_language(),
             max_history_items: DEFAULT_HISTORY_LIMIT,
             graphics_mode: "standard".to_string(),
+            sidebar_layout: "columns".to_string(),
+            ui_scale: 1.0,
+            overlay_font_family: String::new(),
+            selection_dim_opacity: default_selection_dim_opacity(),
+            selection_border_color: default_selection_border_color(),
 
             // Startup
             start_in_tray: false,
             run_as_admin_on_startup: false,
+            disable_warmups: false,
+            webview_lifecycle: WebViewLifecycle::default(),
+            update_channel: UpdateChannel::Stable,
+            update_check_interval_hours: default_update_check_interval_hours(),
+            update_quiet_hours_enabled: false,
+            update_quiet_start: default_update_quiet_start(),
+            update_quiet_end: default_update_quiet_end(),
+            last_update_check: 0,
 
             // API Providers
             use_groq: true,
@@ -282,6 +638,7 @@ impl Default for Config {
             ollama_base_url: "http://localhost:11434".to_string(),
             ollama_vision_model: String::new(),
             ollama_text_model: String::new(),
+            request_timeout_secs: default_request_timeout_secs(),
 
             // Realtime Audio
             realtime_translation_model: "cerebras-oss".to_string(),
@@ -291,11 +648,20 @@ impl Default for Config {
             realtime_translation_size: (500, 180),
             realtime_audio_source: "device".to_string(),
             realtime_target_language: "Vietnamese".to_string(),
+            realtime_speaker_labels: false,
+            realtime_vad_threshold: default_realtime_vad_threshold(),
+            realtime_translation_interval_ms: default_realtime_translation_interval_ms(),
+            realtime_translate_on_sentence: false,
+            realtime_caption_mode: false,
+            realtime_caption_hotkey: default_realtime_caption_hotkey(),
+            realtime_autosave: false,
+            realtime_autosave_dir: String::new(),
 
             // TTS
             tts_method: TtsMethod::GeminiLive,
             tts_voice: "Aoede".to_string(),
             tts_speed: "Fast".to_string(),
+            tts_playback_speed: default_tts_playback_speed(),
             tts_output_device: String::new(),
             tts_language_conditions: default_tts_language_conditions(),
             edge_tts_settings: EdgeTtsSettings::default(),
@@ -304,9 +670,55 @@ impl Default for Config {
             show_favorite_bubble: false,
             favorite_bubble_position: None,
             favorites_keep_open: false,
+            favorite_bubbles_separate: false,
+            favorite_bubble_positions: HashMap::new(),
+
+            // Preset Wheel
+            wheel_radius: 80,
+            wheel_item_size: 12,
+            wheel_release_to_select: false,
+            selection_adjust_before_confirm: false,
+            prompt_dj_window_rect: None,
+
+            // HTTP Control API
+            http_api_enabled: false,
+            http_api_port: 47821,
+            http_api_token: String::new(),
+
+            // Diagnostics
+            log_level: "error".to_string(),
 
             // Maintenance
             clear_webview_on_startup: false,
+            // A fresh install has nothing new to show
+            last_seen_version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+}
+
+impl Config {
+    /// Binding of an app-wide hotkey, if one is set
+    pub fn app_hotkey(&self, which: AppHotkey) -> Option<&Hotkey> {
+        match which {
+            AppHotkey::OpenSettings => self.open_settings_hotkey.as_ref(),
+            AppHotkey::DismissOverlays => self.dismiss_overlays_hotkey.as_ref(),
+            AppHotkey::ToggleFavoriteBubble => self.toggle_bubble_hotkey.as_ref(),
+            AppHotkey::RepeatLastPreset => self.repeat_last_hotkey.as_ref(),
+            AppHotkey::TranslateClipboard => self.translate_clipboard_hotkey.as_ref(),
+            AppHotkey::TranslateSelection => self.translate_selection_hotkey.as_ref(),
+            AppHotkey::UndoReplace => self.undo_replace_hotkey.as_ref(),
+        }
+    }
+
+    pub fn app_hotkey_mut(&mut self, which: AppHotkey) -> &mut Option<Hotkey> {
+        match which {
+            AppHotkey::OpenSettings => &mut self.open_settings_hotkey,
+            AppHotkey::DismissOverlays => &mut self.dismiss_overlays_hotkey,
+            AppHotkey::ToggleFavoriteBubble => &mut self.toggle_bubble_hotkey,
+            AppHotkey::RepeatLastPreset => &mut self.repeat_last_hotkey,
+            AppHotkey::TranslateClipboard => &mut self.translate_clipboard_hotkey,
+            AppHotkey::TranslateSelection => &mut self.translate_selection_hotkey,
+            AppHotkey::UndoReplace => &mut self.undo_replace_hotkey,
         }
     }
 }
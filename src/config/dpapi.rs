@@ -0,0 +1,182 @@
+//! API key encryption at rest with Windows DPAPI.
+//!
+//! Encrypted values are stored as `dpapi:<base64>` in place of the plaintext key, so
+//! old plaintext configs still load and are encrypted on their next save. DPAPI ties
+//! the data to the current Windows user: a file copied to another machine loads with
+//! empty keys, and saving keeps the stored ones until new keys are entered.
+
+use base64::{engine::general_purpose, Engine as _};
+use std::sync::Mutex;
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::{LocalFree, HLOCAL};
+use windows::Win32::Security::Cryptography::{
+    CryptProtectData, CryptUnprotectData, CRYPTPROTECT_UI_FORBIDDEN, CRYPT_INTEGER_BLOB,
+};
+
+const PREFIX: &str = "dpapi:";
+
+/// Config fields holding API keys
+const KEY_FIELDS: [&str; 4] = [
+    "api_key",
+    "gemini_api_key",
+    "openrouter_api_key",
+    "cerebras_api_key",
+];
+
+/// Stored values of key fields that couldn't be decrypted, by field. They load as
+/// empty keys and go back into the file on save while the field stays empty.
+static UNREADABLE_KEYS: Mutex<Vec<(&str, String)>> = Mutex::new(Vec::new());
+
+/// Copy a DPAPI-allocated blob out and free it
+unsafe fn take_blob(blob: CRYPT_INTEGER_BLOB) -> Vec<u8> {
+    let bytes = std::slice::from_raw_parts(blob.pbData, blob.cbData as usize).to_vec();
+    let _ = LocalFree(Some(HLOCAL(blob.pbData as *mut _)));
+    bytes
+}
+
+fn protect(plain: &str) -> Option<String> {
+    let mut input = plain.as_bytes().to_vec();
+    let input_blob = CRYPT_INTEGER_BLOB {
+        cbData: input.len() as u32,
+        pbData: input.as_mut_ptr(),
+    };
+    let mut output_blob = CRYPT_INTEGER_BLOB::default();
+    unsafe {
+        CryptProtectData(
+            &input_blob,
+            PCWSTR::null(),
+            None,
+            None,
+            None,
+            CRYPTPROTECT_UI_FORBIDDEN,
+            &mut output_blob,
+        )
+        .ok()?;
+        let encrypted = take_blob(output_blob);
+        Some(format!(
+            "{}{}",
+            PREFIX,
+            general_purpose::STANDARD.encode(encrypted)
+        ))
+    }
+}
+
+fn unprotect(stored: &str) -> Option<String> {
+    let mut input = general_purpose::STANDARD.decode(stored).ok()?;
+    let input_blob = CRYPT_INTEGER_BLOB {
+        cbData: input.len() as u32,
+        pbData: input.as_mut_ptr(),
+    };
+    let mut output_blob = CRYPT_INTEGER_BLOB::default();
+    unsafe {
+        CryptUnprotectData(
+            &input_blob,
+            None,
+            None,
+            None,
+            None,
+            CRYPTPROTECT_UI_FORBIDDEN,
+            &mut output_blob,
+        )
+        .ok()?;
+        String::from_utf8(take_blob(output_blob)).ok()
+    }
+}
+
+/// Replace the key fields of a raw config JSON with their encrypted form.
+/// A key that fails to encrypt is kept as plaintext rather than lost.
+pub fn encrypt_keys(json: &mut serde_json::Value) {
+    let Some(obj) = json.as_object_mut() else {
+        return;
+    };
+    for field in KEY_FIELDS {
+        let Some(serde_json::Value::String(key)) = obj.get_mut(field) else {
+            continue;
+        };
+        if key.is_empty() || key.starts_with(PREFIX) {
+            continue;
+        }
+        match protect(key) {
+            Some(encrypted) => *key = encrypted,
+            None => log::warn!("Failed to encrypt {}, saving it unencrypted", field),
+        }
+    }
+}
+
+/// Decrypt the key fields of the config file's JSON in place, remembering the ones
+/// that can't be decrypted for `keep_unreadable_keys`.
+/// Returns whether any key was stored as plaintext.
+pub fn decrypt_keys(json: &mut serde_json::Value) -> bool {
+    let (has_plaintext, unreadable) = decrypt_fields(json);
+    *UNREADABLE_KEYS.lock().unwrap() = unreadable;
+    has_plaintext
+}
+
+/// Decrypt the key fields of a config that isn't the config file (e.g. from a backup
+/// bundle). Keys that can't be decrypted are left empty and nothing is remembered.
+pub fn decrypt_foreign_keys(json: &mut serde_json::Value) {
+    decrypt_fields(json);
+}
+
+/// Decrypt the key fields in place. Returns whether any key was stored as plaintext,
+/// and the fields that couldn't be decrypted (now empty) with their stored value.
+fn decrypt_fields(json: &mut serde_json::Value) -> (bool, Vec<(&'static str, String)>) {
+    let mut has_plaintext = false;
+    let mut unreadable = Vec::new();
+    let Some(obj) = json.as_object_mut() else {
+        return (has_plaintext, unreadable);
+    };
+    for field in KEY_FIELDS {
+        let Some(serde_json::Value::String(key)) = obj.get_mut(field) else {
+            continue;
+        };
+        match key.strip_prefix(PREFIX) {
+            Some(encrypted) => match unprotect(encrypted) {
+                Some(plain) => *key = plain,
+                None => {
+                    log::warn!(
+                        "Could not decrypt {} (config from another user or PC?), keeping it as is",
+                        field
+                    );
+                    unreadable.push((field, std::mem::take(key)));
+                }
+            },
+            None => has_plaintext |= !key.is_empty(),
+        }
+    }
+    (has_plaintext, unreadable)
+}
+
+/// Put back the stored value of key fields that couldn't be decrypted and are still
+/// empty, so saving doesn't wipe a key that another Windows user can still read.
+/// A field that got a new key is forgotten.
+pub fn keep_unreadable_keys(json: &mut serde_json::Value) {
+    let Some(obj) = json.as_object_mut() else {
+        return;
+    };
+    UNREADABLE_KEYS
+        .lock()
+        .unwrap()
+        .retain(|(field, stored)| match obj.get_mut(*field) {
+            Some(serde_json::Value::String(key)) if key.is_empty() => {
+                *key = stored.clone();
+                true
+            }
+            _ => false,
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn foreign_reads_leave_unreadable_keys_alone() {
+        let mut json =
+            serde_json::json!({ "api_key": "dpapi:not base64!", "gemini_api_key": "plain" });
+        decrypt_foreign_keys(&mut json);
+        assert_eq!(json["api_key"], "");
+        assert_eq!(json["gemini_api_key"], "plain");
+        assert!(UNREADABLE_KEYS.lock().unwrap().is_empty());
+    }
+}
@@ -1,8 +1,10 @@
 //! Config I/O operations: load, save, and language utilities.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
-use crate::config::config::Config;
+use crate::config::config::{Config, CONFIG_VERSION};
+use crate::config::dpapi;
 use crate::config::preset::{get_default_presets, Preset, ProcessingBlock};
 
 // ============================================================================
@@ -24,26 +26,161 @@ pub fn get_config_path() -> PathBuf {
 
 /// Load config from disk, merging with defaults as needed
 pub fn load_config() -> Config {
-    let path = get_config_path();
-
-    if !path.exists() {
+    let Some((mut config, migrated)) = read_config_or_backup(&get_config_path()) else {
         return Config::default();
+    };
+
+    // Apply migrations and merge new defaults
+    migrate_config(&mut config);
+
+    if migrated {
+        // Persist the new layout (and encrypted keys) so the migration only runs once
+        save_config(&config);
+    }
+
+    config
+}
+
+/// A crash mid-write (or a bad manual edit) can leave the primary file unreadable:
+/// fall back to the last known-good copy before giving up
+fn read_config_or_backup(path: &Path) -> Option<(Config, bool)> {
+    if let Some(loaded) = read_config_file(path) {
+        return Some(loaded);
+    }
+    let loaded = read_config_file(&backup_path(path))?;
+    if path.exists() {
+        log::warn!("Config file unreadable, restored from backup");
     }
+    Some(loaded)
+}
 
-    let data = match std::fs::read_to_string(&path) {
-        Ok(d) => d,
-        Err(_) => return Config::default(),
+/// Read, schema-migrate and parse one config file.
+/// Returns the config and whether it should be written back (a schema migration was
+/// applied, or plaintext API keys are waiting to be encrypted).
+fn read_config_file(path: &Path) -> Option<(Config, bool)> {
+    let data = std::fs::read_to_string(path).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&data).ok()?;
+
+    // Upgrade older on-disk layouts before they hit the typed struct
+    let from_version = schema_version(&json);
+    let needs_migration = from_version < CONFIG_VERSION;
+    let mut json = if needs_migration {
+        // Keep the untouched original around in case a migration goes wrong
+        let _ = std::fs::write(
+            sibling_path(path, &format!(".v{}.bak", from_version)),
+            &data,
+        );
+        migrate(json, from_version)
+    } else {
+        json
     };
 
-    let mut config: Config = match serde_json::from_str(&data) {
-        Ok(c) => c,
-        Err(_) => return Config::default(),
+    let has_plaintext_keys = dpapi::decrypt_keys(&mut json);
+    let config: Config = serde_json::from_value(json).ok()?;
+    let needs_save = needs_migration || (has_plaintext_keys && config.encrypt_api_keys);
+    Some((config, needs_save))
+}
+
+/// Parse a config coming from somewhere other than the config file (e.g. a backup
+/// bundle), applying the same schema migration and preset merge as `load_config`
+pub fn config_from_json(data: &str) -> Option<Config> {
+    let json: serde_json::Value = serde_json::from_str(data).ok()?;
+    let from_version = schema_version(&json);
+    let mut json = if from_version < CONFIG_VERSION {
+        migrate(json, from_version)
+    } else {
+        json
     };
+    // Bundles carry plaintext keys, but a copied config file may not
+    dpapi::decrypt_foreign_keys(&mut json);
 
-    // Apply migrations and merge new defaults
+    let mut config = serde_json::from_value(json).ok()?;
     migrate_config(&mut config);
+    Some(config)
+}
 
-    config
+/// `config_version` of a raw config JSON (files from before versioning = 0)
+fn schema_version(json: &serde_json::Value) -> u32 {
+    json.get("config_version")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as u32
+}
+
+/// `config_v3.json` + `suffix` -> `config_v3.json{suffix}` in the same directory
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// Last known-good copy of the config, refreshed on every save
+fn backup_path(path: &Path) -> PathBuf {
+    sibling_path(path, ".bak")
+}
+
+// ============================================================================
+// SCHEMA MIGRATION
+// ============================================================================
+
+/// Upgrade a raw config JSON written by an older version to the current layout.
+/// Each step handles exactly one version bump, so old files walk through all of them.
+pub fn migrate(mut config_json: serde_json::Value, from_version: u32) -> serde_json::Value {
+    let Some(obj) = config_json.as_object_mut() else {
+        return config_json;
+    };
+
+    // -------------------------------------------------------------------------
+    // v0 -> v1: window sizes must be [width, height] pairs
+    // -------------------------------------------------------------------------
+    // Hand-edited or very old configs can hold `{ "width", "height" }` objects or
+    // junk here, which made the whole file fail to parse and reset to defaults.
+    if from_version < 1 {
+        for key in ["realtime_transcription_size", "realtime_translation_size"] {
+            let Some(value) = obj.get(key) else {
+                continue;
+            };
+            let pair = match value {
+                serde_json::Value::Array(items) if items.len() == 2 => {
+                    items[0].as_i64().zip(items[1].as_i64())
+                }
+                serde_json::Value::Object(size) => size
+                    .get("width")
+                    .and_then(|w| w.as_i64())
+                    .zip(size.get("height").and_then(|h| h.as_i64())),
+                _ => None,
+            };
+            match pair {
+                Some((w, h)) => {
+                    obj.insert(key.to_string(), serde_json::json!([w, h]));
+                }
+                // Unusable: drop it so the serde default kicks in
+                None => {
+                    obj.remove(key);
+                }
+            }
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // v1 -> v2: the built-in Arena preset starts in arena mode
+    // -------------------------------------------------------------------------
+    // Configs saved before `arena_mode` existed hold the Arena preset without it,
+    // so it ran as a plain preset. Turn it on once; later the toggle is the user's.
+    if from_version < 2 {
+        if let Some(presets) = obj.get_mut("presets").and_then(|p| p.as_array_mut()) {
+            for preset in presets.iter_mut().filter_map(|p| p.as_object_mut()) {
+                if preset.get("id").and_then(|id| id.as_str()) == Some("preset_translate_arena") {
+                    preset.insert("arena_mode".to_string(), serde_json::json!(true));
+                }
+            }
+        }
+    }
+
+    obj.insert(
+        "config_version".to_string(),
+        serde_json::json!(CONFIG_VERSION),
+    );
+    config_json
 }
 
 /// Apply config migrations and merge new default presets
@@ -94,6 +231,7 @@ fn migrate_config(config: &mut Config) {
             // Sync audio-specific settings
             if preset.preset_type == "audio" {
                 preset.auto_stop_recording = default_preset.auto_stop_recording;
+                preset.pronunciation_practice = default_preset.pronunciation_practice;
             }
         }
     }
@@ -115,14 +253,73 @@ fn migrate_config(config: &mut Config) {
 // CONFIG SAVING
 // ============================================================================
 
-/// Save config to disk
+static ON_SAVED: OnceLock<fn(&Config)> = OnceLock::new();
+
+/// Run `hook` on every config passed to `save_config`, so settings mirrored
+/// elsewhere follow it. Only the first hook registered is kept.
+pub fn on_config_saved(hook: fn(&Config)) {
+    let _ = ON_SAVED.set(hook);
+}
+
+/// Save config to disk.
+///
+/// Written to a temp file in the same directory and renamed over the target, so a
+/// crash mid-write never leaves a truncated config behind. The previous file is kept
+/// as `.bak` for `load_config` to fall back on.
 pub fn save_config(config: &Config) {
+    // Keys may have just been entered or changed
+    crate::redact::set_secrets(config);
+    if let Some(hook) = ON_SAVED.get() {
+        hook(config);
+    }
     let path = get_config_path();
-    if let Ok(data) = serde_json::to_string_pretty(config) {
-        let _ = std::fs::write(path, data);
+    let Some(data) = config_file_json(config) else {
+        return;
+    };
+    if let Err(e) = write_config_file(&path, &data) {
+        log::error!("Failed to save config: {}", e);
     }
 }
 
+/// `config` the way it's written to disk: API keys encrypted when
+/// `encrypt_api_keys` is on. Anything that writes a config file goes through this.
+pub fn config_file_json(config: &Config) -> Option<String> {
+    let mut json = serde_json::to_value(config).ok()?;
+    if config.encrypt_api_keys {
+        dpapi::encrypt_keys(&mut json);
+    }
+    dpapi::keep_unreadable_keys(&mut json);
+    serde_json::to_string_pretty(&json).ok()
+}
+
+/// Replace the config file at `path` with `data`, keeping the previous file as the
+/// backup when it was still valid
+fn write_config_file(path: &Path, data: &str) -> std::io::Result<()> {
+    let tmp_path = sibling_path(path, ".tmp");
+    let written = std::fs::File::create(&tmp_path).and_then(|mut file| {
+        use std::io::Write;
+        file.write_all(data.as_bytes())?;
+        // Make sure the bytes are on disk before the rename makes them "the" config
+        file.sync_all()
+    });
+    if let Err(e) = written {
+        let _ = std::fs::remove_file(&tmp_path);
+        return Err(e);
+    }
+
+    // Only a file that is still valid JSON is worth keeping as the fallback
+    let previous_is_valid = std::fs::read_to_string(path)
+        .ok()
+        .is_some_and(|d| serde_json::from_str::<serde_json::Value>(&d).is_ok());
+    if previous_is_valid {
+        let _ = std::fs::copy(path, backup_path(path));
+    }
+
+    std::fs::rename(&tmp_path, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&tmp_path);
+    })
+}
+
 // ============================================================================
 // LANGUAGE UTILITIES
 // ============================================================================
@@ -149,3 +346,120 @@ lazy_static::lazy_static! {
 pub fn get_all_languages() -> &'static Vec<String> {
     &ALL_LANGUAGES
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh directory per test, so tests can run in parallel
+    fn test_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("sgt-config-test-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn config_json(ui_language: &str) -> String {
+        let config = Config {
+            ui_language: ui_language.to_string(),
+            ..Default::default()
+        };
+        serde_json::to_string_pretty(&config).unwrap()
+    }
+
+    #[test]
+    fn truncated_config_falls_back_to_backup() {
+        let dir = test_dir("truncated");
+        let path = dir.join("config_v3.json");
+        let valid = config_json("ko");
+        std::fs::write(&path, &valid[..valid.len() / 2]).unwrap();
+        std::fs::write(backup_path(&path), &valid).unwrap();
+
+        let (config, _) = read_config_or_backup(&path).expect("backup should load");
+        assert_eq!(config.ui_language, "ko");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn unreadable_config_without_backup_is_none() {
+        let dir = test_dir("no-backup");
+        let path = dir.join("config_v3.json");
+        std::fs::write(&path, "{\"ui_language\": \"k").unwrap();
+
+        assert!(read_config_or_backup(&path).is_none());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn save_replaces_through_temp_file_and_backs_up_valid_config() {
+        let dir = test_dir("save");
+        let path = dir.join("config_v3.json");
+
+        // First save: nothing to back up
+        write_config_file(&path, &config_json("vi")).unwrap();
+        assert!(!backup_path(&path).exists());
+        assert!(!sibling_path(&path, ".tmp").exists());
+
+        // The valid previous file becomes the backup
+        write_config_file(&path, &config_json("ko")).unwrap();
+        let (backup, _) = read_config_file(&backup_path(&path)).unwrap();
+        assert_eq!(backup.ui_language, "vi");
+        let (current, _) = read_config_file(&path).unwrap();
+        assert_eq!(current.ui_language, "ko");
+        assert!(!sibling_path(&path, ".tmp").exists());
+
+        // A corrupt previous file must not replace the good backup
+        std::fs::write(&path, "{ truncated").unwrap();
+        write_config_file(&path, &config_json("en")).unwrap();
+        let (backup, _) = read_config_file(&backup_path(&path)).unwrap();
+        assert_eq!(backup.ui_language, "vi");
+        let (current, _) = read_config_file(&path).unwrap();
+        assert_eq!(current.ui_language, "en");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn migrate_reshapes_sizes_and_turns_on_arena_mode_once() {
+        let old = serde_json::json!({
+            "realtime_transcription_size": { "width": 640, "height": 200 },
+            "realtime_translation_size": "junk",
+            "presets": [
+                { "id": "preset_translate_arena", "arena_mode": false },
+                { "id": "preset_translate", "arena_mode": false }
+            ]
+        });
+
+        let migrated = migrate(old, 0);
+        assert_eq!(
+            migrated["config_version"],
+            serde_json::json!(CONFIG_VERSION)
+        );
+        assert_eq!(
+            migrated["realtime_transcription_size"],
+            serde_json::json!([640, 200])
+        );
+        assert!(migrated.get("realtime_translation_size").is_none());
+        assert_eq!(
+            migrated["presets"][0]["arena_mode"],
+            serde_json::json!(true)
+        );
+        assert_eq!(
+            migrated["presets"][1]["arena_mode"],
+            serde_json::json!(false)
+        );
+
+        // Already on v2: a user who turned arena mode off keeps it off
+        let current = serde_json::json!({
+            "presets": [{ "id": "preset_translate_arena", "arena_mode": false }]
+        });
+        let migrated = migrate(current, 2);
+        assert_eq!(
+            migrated["presets"][0]["arena_mode"],
+            serde_json::json!(false)
+        );
+    }
+}
@@ -7,6 +7,7 @@
 //! - `preset`: Preset and ProcessingBlock with builder patterns
 //! - `types`: Core types (enums, TTS settings, hotkeys)
 //! - `io`: Load/save operations
+//! - `dpapi`: API key encryption at rest
 //!
 //! ## Usage
 //! ```rust
@@ -29,6 +30,7 @@
 //! ```
 
 mod config;
+mod dpapi;
 mod io;
 pub mod preset;
 pub mod types;
@@ -38,23 +40,29 @@ pub mod types;
 // ============================================================================
 
 // Config struct
-pub use config::Config;
+pub use config::{Config, UI_SCALE_RANGE};
 
 // Preset and ProcessingBlock
-pub use preset::{Preset, ProcessingBlock};
+pub use preset::{ParallelBranch, Preset, ProcessingBlock, ResponseFormat};
 
 // I/O functions
-pub use io::{get_all_languages, load_config, save_config};
+pub use io::{
+    config_file_json, config_from_json, get_all_languages, get_config_path, load_config,
+    on_config_saved, save_config,
+};
 
 // ============================================================================
 // RE-EXPORTS - Types (only what's actually used externally)
 // ============================================================================
 
 // Core enums
-pub use types::ThemeMode;
+pub use types::{
+    AutoCopyMode, AutoCopyTarget, ImageUploadFormat, ImageUploadSettings, PostCommandMode,
+    ThemeMode, UpdateChannel, WebViewLifecycle,
+};
 
 // Hotkey
-pub use types::Hotkey;
+pub use types::{reserved_shortcut, AppHotkey, Hotkey};
 
 // TTS types
 pub use types::{EdgeTtsSettings, EdgeTtsVoiceConfig, TtsLanguageCondition, TtsMethod};
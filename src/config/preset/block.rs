@@ -5,8 +5,10 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Mutex;
 
-use crate::config::types::BlockType;
+use crate::config::types::{BlockType, ImageUploadSettings};
+use crate::model_config::{get_all_models_with_ollama, get_model_by_id, ModelType};
 
 // ============================================================================
 // PROCESSING BLOCK
@@ -18,7 +20,7 @@ pub struct ProcessingBlock {
     #[serde(default = "generate_block_id")]
     pub id: String,
 
-    /// Type of block: "input_adapter", "image", "text", "audio"
+    /// Type of block: "input_adapter", "image", "text", "audio", "parallel"
     #[serde(default)]
     pub block_type: String,
 
@@ -26,6 +28,15 @@ pub struct ProcessingBlock {
     #[serde(default)]
     pub model: String,
 
+    /// Restrict this block to one provider (e.g. "groq", "google").
+    /// `None` follows the global provider settings, including cross-provider fallback.
+    #[serde(default)]
+    pub provider_override: Option<String>,
+
+    /// Pin this block to an exact model: no fallback to other models on errors
+    #[serde(default)]
+    pub model_override: Option<String>,
+
     /// Prompt template (supports {language1}, {language2}, etc.)
     #[serde(default)]
     pub prompt: String,
@@ -57,6 +68,117 @@ pub struct ProcessingBlock {
     /// Auto-speak result using TTS
     #[serde(default)]
     pub auto_speak: bool,
+
+    /// Regex replacements `(pattern, replacement)` applied in order to the block's
+    /// finished output, before it is shown, copied or passed on
+    #[serde(default)]
+    pub output_regex: Vec<(String, String)>,
+
+    /// Image blocks: format and size the capture is re-encoded to before upload
+    #[serde(default)]
+    pub image_upload: ImageUploadSettings,
+
+    /// Run only when the block's input (the previous block's output) passes this;
+    /// otherwise the input is passed on unchanged
+    #[serde(default)]
+    pub condition: BlockCondition,
+
+    /// Parallel blocks: the models the input is sent to at once (`model` is unused)
+    #[serde(default)]
+    pub branches: Vec<ParallelBranch>,
+
+    /// Parallel blocks: index of the branch whose output goes on; `None` passes on
+    /// all outputs, one section per model
+    #[serde(default)]
+    pub parallel_pick: Option<usize>,
+
+    /// Plain text, or JSON checked against a schema and shown as a table
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// One model of a parallel block
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ParallelBranch {
+    pub model: String,
+    /// Same meaning as `ProcessingBlock::provider_override`, for this branch only
+    #[serde(default)]
+    pub provider_override: Option<String>,
+}
+
+impl ParallelBranch {
+    pub fn new(model: &str) -> Self {
+        Self {
+            model: model.to_string(),
+            provider_override: None,
+        }
+    }
+}
+
+/// When a block runs, judged on the text it receives
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum BlockCondition {
+    #[default]
+    Always,
+    NotEmpty,
+    Contains(String),
+    NotContains(String),
+    /// Regex; an invalid pattern never matches
+    Matches(String),
+    /// Detected language is not this one (English name, e.g. "English")
+    NotLanguage(String),
+}
+
+impl BlockCondition {
+    pub fn is_met(&self, input: &str) -> bool {
+        match self {
+            BlockCondition::Always => true,
+            BlockCondition::NotEmpty => !input.trim().is_empty(),
+            BlockCondition::Contains(needle) => input.contains(needle.as_str()),
+            BlockCondition::NotContains(needle) => !input.contains(needle.as_str()),
+            BlockCondition::Matches(pattern) => {
+                compile_output_regex(pattern).is_ok_and(|re| re.is_match(input))
+            }
+            BlockCondition::NotLanguage(language) => whatlang::detect_lang(input)
+                .is_none_or(|detected| !detected.eng_name().eq_ignore_ascii_case(language.trim())),
+        }
+    }
+}
+
+/// What a block asks the model to answer with
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(tag = "kind", content = "schema", rename_all = "snake_case")]
+pub enum ResponseFormat {
+    #[default]
+    Text,
+    /// JSON matching this JSON Schema (empty: any JSON). Uses the provider's native
+    /// JSON mode where there is one, otherwise the prompt asks for it.
+    Json(String),
+}
+
+impl ResponseFormat {
+    pub fn is_json(&self) -> bool {
+        matches!(self, ResponseFormat::Json(_))
+    }
+
+    /// The schema of a JSON format, `None` for text or an empty schema.
+    /// An unparsable schema is logged and treated as empty.
+    pub fn schema(&self) -> Option<serde_json::Value> {
+        let ResponseFormat::Json(schema) = self else {
+            return None;
+        };
+        if schema.trim().is_empty() {
+            return None;
+        }
+        match serde_json::from_str(schema) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                log::warn!("Ignoring invalid JSON schema of a block: {}", e);
+                None
+            }
+        }
+    }
 }
 
 fn generate_block_id() -> String {
@@ -83,6 +205,8 @@ impl Default for ProcessingBlock {
             id: generate_block_id(),
             block_type: "text".to_string(),
             model: "text_accurate_kimi".to_string(),
+            provider_override: None,
+            model_override: None,
             prompt: "Translate to {language1}. Output ONLY the translation.".to_string(),
             selected_language: "Vietnamese".to_string(),
             language_vars: HashMap::new(),
@@ -91,6 +215,12 @@ impl Default for ProcessingBlock {
             show_overlay: true,
             auto_copy: false,
             auto_speak: false,
+            output_regex: Vec::new(),
+            image_upload: ImageUploadSettings::default(),
+            condition: BlockCondition::Always,
+            branches: Vec::new(),
+            parallel_pick: None,
+            response_format: ResponseFormat::Text,
         }
     }
 }
@@ -164,6 +294,34 @@ impl BlockBuilder {
         }
     }
 
+    /// Create a parallel block that sends its input to every model in `branches`
+    /// at once and passes on all their outputs
+    pub fn parallel(branches: &[&str]) -> Self {
+        Self {
+            block: ProcessingBlock {
+                block_type: "parallel".to_string(),
+                branches: branches.iter().map(|m| ParallelBranch::new(m)).collect(),
+                streaming_enabled: false,
+                render_mode: "markdown".to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Pass on only the output of branch `index` of a parallel block
+    pub fn pick_branch(mut self, index: usize) -> Self {
+        self.block.parallel_pick = Some(index);
+        self
+    }
+
+    /// Ask for JSON matching `schema` and show it as a table
+    pub fn json_output(mut self, schema: &str) -> Self {
+        self.block.response_format = ResponseFormat::Json(schema.to_string());
+        self.block.streaming_enabled = false;
+        self.block.render_mode = "markdown".to_string();
+        self
+    }
+
     /// Set the prompt template
     pub fn prompt(mut self, prompt: &str) -> Self {
         self.block.prompt = prompt.to_string();
@@ -240,8 +398,167 @@ impl ProcessingBlock {
         self.block_type == "audio"
     }
 
+    /// Check if this is a parallel (multi-model) block
+    pub fn is_parallel(&self) -> bool {
+        self.block_type == "parallel"
+    }
+
     /// Get the block type as enum
     pub fn block_type_enum(&self) -> BlockType {
         BlockType::from_str(&self.block_type)
     }
+
+    /// Model this block should actually run with, after applying the overrides:
+    /// a pinned `model_override` wins, then `model` if it belongs to `provider_override`,
+    /// then the first enabled model of that provider with the same model type.
+    pub fn effective_model_id(&self) -> String {
+        if let Some(pinned) = self.model_override.as_deref().filter(|m| !m.is_empty()) {
+            if get_model_by_id(pinned).is_some() {
+                return pinned.to_string();
+            }
+        }
+
+        let Some(provider) = self.provider_override.as_deref() else {
+            return self.model.clone();
+        };
+        let current = get_model_by_id(&self.model);
+        if current.as_ref().is_some_and(|m| m.provider == provider) {
+            return self.model.clone();
+        }
+
+        let model_type = current
+            .map(|m| m.model_type)
+            .unwrap_or(match self.block_type.as_str() {
+                "image" => ModelType::Vision,
+                "audio" => ModelType::Audio,
+                _ => ModelType::Text,
+            });
+        get_all_models_with_ollama()
+            .into_iter()
+            .find(|m| m.enabled && m.provider == provider && m.model_type == model_type)
+            .map(|m| m.id)
+            .unwrap_or_else(|| self.model.clone())
+    }
+
+    /// True if errors must not fall back to a different model
+    pub fn is_model_pinned(&self) -> bool {
+        self.model_override
+            .as_deref()
+            .is_some_and(|m| !m.is_empty())
+    }
+
+    /// Run `output_regex` over the block's final text. Invalid or empty patterns are skipped.
+    pub fn apply_output_regex(&self, text: &str) -> String {
+        let mut result = text.to_string();
+        for (pattern, replacement) in &self.output_regex {
+            if let Ok(re) = compile_output_regex(pattern) {
+                result = re.replace_all(&result, replacement.as_str()).into_owned();
+            }
+        }
+        result
+    }
+}
+
+// ============================================================================
+// OUTPUT REGEX
+// ============================================================================
+
+/// Compiled patterns kept at most; the cache starts over past this, so patterns
+/// typed and abandoned in the editor don't pile up
+const OUTPUT_REGEX_CACHE_LIMIT: usize = 256;
+
+lazy_static::lazy_static! {
+    // Patterns are compiled once and reused for every result (and every editor repaint)
+    static ref OUTPUT_REGEX_CACHE: Mutex<HashMap<String, Result<regex::Regex, String>>> =
+        Mutex::new(HashMap::new());
+}
+
+fn compile_output_regex(pattern: &str) -> Result<regex::Regex, String> {
+    if pattern.is_empty() {
+        return Err(String::new());
+    }
+    let mut cache = OUTPUT_REGEX_CACHE.lock().unwrap();
+    if let Some(compiled) = cache.get(pattern) {
+        return compiled.clone();
+    }
+    if cache.len() >= OUTPUT_REGEX_CACHE_LIMIT {
+        cache.clear();
+    }
+    let compiled = regex::Regex::new(pattern).map_err(|e| e.to_string());
+    cache.insert(pattern.to_string(), compiled.clone());
+    compiled
+}
+
+/// Why `pattern` can't be used as an output regex, or None if it compiles.
+/// Empty patterns are not an error (the rule is just unused).
+pub fn output_regex_error(pattern: &str) -> Option<String> {
+    if pattern.is_empty() {
+        return None;
+    }
+    compile_output_regex(pattern).err()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn always_is_met_even_without_input() {
+        assert!(BlockCondition::Always.is_met(""));
+        assert!(BlockCondition::Always.is_met("text"));
+    }
+
+    #[test]
+    fn not_empty_ignores_whitespace() {
+        assert!(BlockCondition::NotEmpty.is_met("text"));
+        assert!(!BlockCondition::NotEmpty.is_met(""));
+        assert!(!BlockCondition::NotEmpty.is_met("  \n\t"));
+    }
+
+    #[test]
+    fn contains_and_not_contains_are_opposites() {
+        let contains = BlockCondition::Contains("TODO".to_string());
+        let not_contains = BlockCondition::NotContains("TODO".to_string());
+        assert!(contains.is_met("fix the TODO list"));
+        assert!(!not_contains.is_met("fix the TODO list"));
+        // Case-sensitive
+        assert!(!contains.is_met("fix the todo list"));
+        assert!(not_contains.is_met("fix the todo list"));
+    }
+
+    #[test]
+    fn matches_uses_regex() {
+        let condition = BlockCondition::Matches(r"^\d{3}-\d{4}$".to_string());
+        assert!(condition.is_met("555-1234"));
+        assert!(!condition.is_met("call 555-1234"));
+    }
+
+    #[test]
+    fn invalid_or_empty_regex_never_matches() {
+        assert!(!BlockCondition::Matches("(unclosed".to_string()).is_met("(unclosed"));
+        assert!(!BlockCondition::Matches(String::new()).is_met("anything"));
+    }
+
+    #[test]
+    fn not_language_compares_detected_language() {
+        let condition = BlockCondition::NotLanguage("English".to_string());
+        let english = "The quick brown fox jumps over the lazy dog while the farmer watches";
+        let french = "Le renard brun rapide saute par-dessus le chien paresseux pendant que le fermier regarde";
+        assert!(!condition.is_met(english));
+        assert!(condition.is_met(french));
+        // Name is matched case-insensitively, surrounding spaces ignored
+        assert!(!BlockCondition::NotLanguage(" english ".to_string()).is_met(english));
+        // Nothing detectable counts as "not that language"
+        assert!(condition.is_met(""));
+    }
+
+    #[test]
+    fn regex_cache_stays_bounded() {
+        for i in 0..OUTPUT_REGEX_CACHE_LIMIT * 2 {
+            assert!(compile_output_regex(&format!("cache-test-{}", i)).is_ok());
+        }
+        assert!(OUTPUT_REGEX_CACHE.lock().unwrap().len() <= OUTPUT_REGEX_CACHE_LIMIT);
+        // Still compiles (and caches) after starting over
+        assert!(compile_output_regex("cache-test-0").is_ok());
+    }
 }
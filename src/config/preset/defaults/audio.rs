@@ -28,6 +28,7 @@ pub fn create_audio_presets() -> Vec<Preset> {
         PresetBuilder::new("preset_fix_pronunciation", "Fix pronunciation")
             .audio_mic()
             .auto_stop()
+            .pronunciation_practice()
             .blocks(vec![
                 BlockBuilder::audio("whisper-accurate")
                     .language("Vietnamese")
@@ -113,6 +114,17 @@ pub fn create_audio_presets() -> Vec<Preset> {
             ])
             .build(),
 
+        // Continuous writing - Live transcript typed into the focused app
+        PresetBuilder::new("preset_continuous_writing_online", "Continuous writing (online)")
+            .audio_mic()
+            .realtime()
+            .dictation()
+            .blocks(vec![
+                BlockBuilder::audio("whisper-accurate")
+                    .build(),
+            ])
+            .build(),
+
         // =====================================================================
         // DEVICE AUDIO PRESETS
         // =====================================================================
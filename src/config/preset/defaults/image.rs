@@ -139,9 +139,9 @@ pub fn create_image_presets() -> Vec<Preset> {
             .image()
             .blocks(vec![
                 BlockBuilder::image("maverick")
-                    .prompt("Extract all data from any tables, forms, or structured content in this image. Format the output as a markdown table. Output ONLY the table, no explanations.")
+                    .prompt("Extract all data from any tables, forms, or structured content in this image. Put the column names in \"columns\" and every row's cells, in the same order, in \"rows\".")
                     .language("Vietnamese")
-                    .markdown()
+                    .json_output(r#"{"type": "object", "properties": {"columns": {"type": "array", "items": {"type": "string"}}, "rows": {"type": "array", "items": {"type": "array", "items": {"type": "string"}}}}, "required": ["columns", "rows"]}"#)
                     .auto_copy()
                     .build(),
             ])
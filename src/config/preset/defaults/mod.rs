@@ -88,6 +88,7 @@ pub fn get_default_presets() -> Vec<Preset> {
         find(&audio, "preset_quick_ai_question"),
         find(&audio, "preset_voice_search"),
         find(&audio, "preset_quick_record"),
+        find(&audio, "preset_continuous_writing_online"),
         find(&masters, "preset_audio_mic_master"),
         // Device audio section
         find(&audio, "preset_study_language"),
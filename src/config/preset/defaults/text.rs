@@ -32,33 +32,35 @@ pub fn create_text_presets() -> Vec<Preset> {
             ])
             .build(),
 
-        // Dịch (Arena) - Compare 3 translation models in parallel
+        // Dịch (Arena) - Google Translate vs Cerebras side by side, plus Gemini
         PresetBuilder::new("preset_translate_arena", "Dịch (Arena)")
             .text_select()
+            .arena()
             .blocks(vec![
                 // Node 0: Input adapter (text selection)
                 BlockBuilder::input_adapter()
                     .build(),
-                // Node 1: Google Translate (GTX) - fast, non-LLM
+                // Node 1: Google Translate (GTX) - fast, free (left side of the arena)
                 BlockBuilder::text("google-gtx")
                     .prompt("Translate to {language1}. Output ONLY the translation.")
                     .language("Vietnamese")
                     .streaming(false)
                     .build(),
-                // Node 2: Groq Kimi - accurate LLM
+                // Node 2: Cerebras Qwen3 - accurate LLM (right side of the arena)
                 BlockBuilder::text("cerebras_qwen3")
                     .prompt("Translate the following text to {language1}. Output ONLY the translation.")
                     .language("Vietnamese")
                     .auto_copy()
                     .build(),
-                // Node 3: Gemini Flash Lite - Google's fast LLM
+                // Node 3: Gemini Flash Lite - Google's fast LLM, in its own window
                 BlockBuilder::text("text_gemini_flash_lite")
                     .prompt("Translate the following text to {language1}. Output ONLY the translation.")
                     .language("Vietnamese")
                     .streaming(false)
                     .build(),
             ])
-            // All 3 translation nodes branch from input (0 -> 1, 0 -> 2, 0 -> 3)
+            // All 3 translation nodes branch from input (0 -> 1, 0 -> 2, 0 -> 3);
+            // the first two share the arena window
             .connections(vec![(0, 1), (0, 2), (0, 3)])
             .build(),
 
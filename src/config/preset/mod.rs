@@ -11,7 +11,10 @@ mod block;
 pub mod defaults;
 mod preset;
 
-pub use block::{BlockBuilder, ProcessingBlock};
+pub use block::{
+    output_regex_error, BlockBuilder, BlockCondition, ParallelBranch, ProcessingBlock,
+    ResponseFormat,
+};
 pub use preset::{Preset, PresetBuilder};
 
 // Re-export default preset functions for convenience
@@ -9,7 +9,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::block::ProcessingBlock;
-use crate::config::types::Hotkey;
+use crate::config::types::{AutoCopyMode, AutoCopyTarget, Hotkey, PostCommandMode};
 
 // ============================================================================
 // PRESET STRUCT
@@ -59,6 +59,10 @@ pub struct Preset {
     #[serde(default = "default_realtime_window_mode")]
     pub realtime_window_mode: String,
 
+    /// Realtime: type the transcript into the focused app as it comes in
+    #[serde(default)]
+    pub realtime_dictation: bool,
+
     /// Video capture method
     #[serde(default)]
     pub video_capture_method: String,
@@ -74,6 +78,53 @@ pub struct Preset {
     #[serde(default = "default_true")]
     pub auto_paste_newline: bool,
 
+    /// Type the result out as keystrokes instead of pasting it, for apps that mangle
+    /// pasted text
+    #[serde(default)]
+    pub auto_paste_typing: bool,
+
+    /// Which block outputs are copied to the clipboard
+    #[serde(default)]
+    pub auto_copy_target: AutoCopyTarget,
+
+    /// Replace the clipboard, or append to the text already on it
+    #[serde(default)]
+    pub auto_copy_mode: AutoCopyMode,
+
+    /// Arena: the first two branches run concurrently and share one side-by-side
+    /// result window, with a button to pick (copy) the better answer
+    #[serde(default)]
+    pub arena_mode: bool,
+
+    /// Close result windows after this many seconds without the mouse over them.
+    /// None keeps them open until closed.
+    #[serde(default)]
+    pub result_auto_dismiss_secs: Option<u32>,
+
+    /// External command run on the final result: it gets the text on stdin and its
+    /// output replaces the result or is appended to it. Empty runs nothing.
+    #[serde(default)]
+    pub post_command: String,
+
+    #[serde(default)]
+    pub post_command_mode: PostCommandMode,
+
+    // -------------------------------------------------------------------------
+    // Image Capture Options
+    // -------------------------------------------------------------------------
+    /// Start the screen selection with the last confirmed region already drawn
+    #[serde(default)]
+    pub remember_selection: bool,
+
+    /// Last confirmed selection (left, top, right, bottom) in virtual-screen
+    /// coordinates, kept while `remember_selection` is on
+    #[serde(default)]
+    pub last_selection: Option<(i32, i32, i32, i32)>,
+
+    /// After selecting, black out regions of the capture before it is sent
+    #[serde(default)]
+    pub redact_before_send: bool,
+
     // -------------------------------------------------------------------------
     // Audio Recording Options
     // -------------------------------------------------------------------------
@@ -85,6 +136,11 @@ pub struct Preset {
     #[serde(default)]
     pub auto_stop_recording: bool,
 
+    /// Score the recording word by word against a target phrase (taken from the
+    /// clipboard when recording starts) instead of only transcribing it
+    #[serde(default)]
+    pub pronunciation_practice: bool,
+
     // -------------------------------------------------------------------------
     // Text Input Options
     // -------------------------------------------------------------------------
@@ -99,6 +155,15 @@ pub struct Preset {
     #[serde(default)]
     pub hotkeys: Vec<Hotkey>,
 
+    /// Target language used instead of the blocks' own when the hotkey is pressed
+    /// with Shift held. Empty disables it. Live (realtime) presets ignore it.
+    #[serde(default)]
+    pub secondary_language: String,
+
+    /// Don't wrap this preset's prompts in the global prefix/suffix
+    #[serde(default)]
+    pub skip_global_prompt: bool,
+
     // -------------------------------------------------------------------------
     // Special Flags
     // -------------------------------------------------------------------------
@@ -168,13 +233,27 @@ impl Default for Preset {
             audio_source: "mic".to_string(),
             audio_processing_mode: "record_then_process".to_string(),
             realtime_window_mode: "standard".to_string(),
+            realtime_dictation: false,
             video_capture_method: "region".to_string(),
             auto_paste: false,
             auto_paste_newline: false,
+            auto_paste_typing: false,
+            auto_copy_target: AutoCopyTarget::default(),
+            auto_copy_mode: AutoCopyMode::default(),
+            arena_mode: false,
+            result_auto_dismiss_secs: None,
+            post_command: String::new(),
+            post_command_mode: PostCommandMode::default(),
+            remember_selection: false,
+            last_selection: None,
+            redact_before_send: false,
             hide_recording_ui: false,
             auto_stop_recording: false,
+            pronunciation_practice: false,
             continuous_input: false,
             hotkeys: vec![],
+            secondary_language: String::new(),
+            skip_global_prompt: false,
             is_upcoming: false,
             is_master: false,
             show_controller_ui: false,
@@ -289,6 +368,12 @@ impl PresetBuilder {
         self
     }
 
+    /// Compare the first two branches side by side in one window
+    pub fn arena(mut self) -> Self {
+        self.preset.arena_mode = true;
+        self
+    }
+
     // -------------------------------------------------------------------------
     // Audio Options
     // -------------------------------------------------------------------------
@@ -299,12 +384,24 @@ impl PresetBuilder {
         self
     }
 
+    /// Score pronunciation against a target phrase from the clipboard
+    pub fn pronunciation_practice(mut self) -> Self {
+        self.preset.pronunciation_practice = true;
+        self
+    }
+
     /// Enable realtime audio processing
     pub fn realtime(mut self) -> Self {
         self.preset.audio_processing_mode = "realtime".to_string();
         self
     }
 
+    /// Type the realtime transcript into the focused app
+    pub fn dictation(mut self) -> Self {
+        self.preset.realtime_dictation = true;
+        self
+    }
+
     /// Set realtime window mode to minimal
     #[allow(dead_code)]
     pub fn minimal_mode(mut self) -> Self {
@@ -374,4 +471,42 @@ impl Preset {
     pub fn input_block_mut(&mut self) -> Option<&mut ProcessingBlock> {
         self.blocks.first_mut()
     }
+
+    /// Whether the output of `block` is auto-copied.
+    /// `is_final`: the block has no downstream blocks.
+    pub fn copies_block(&self, block: &ProcessingBlock, is_final: bool) -> bool {
+        match self.auto_copy_target {
+            AutoCopyTarget::PerBlock => block.auto_copy,
+            AutoCopyTarget::FinalBlock => is_final && !block.is_input_adapter(),
+            AutoCopyTarget::EachBlock => !block.is_input_adapter(),
+            AutoCopyTarget::None => false,
+        }
+    }
+
+    /// Point every block that translates into a target language at
+    /// `secondary_language`. Returns the language, or `None` if none is set.
+    pub fn use_secondary_language(&mut self) -> Option<String> {
+        let language = self.secondary_language.trim().to_string();
+        if language.is_empty() {
+            return None;
+        }
+        for block in &mut self.blocks {
+            if block.prompt.contains("{language") {
+                block.selected_language = language.clone();
+                if let Some(var) = block.language_vars.get_mut("language1") {
+                    *var = language.clone();
+                }
+            }
+        }
+        Some(language)
+    }
+
+    /// True if any result of this preset gets auto-copied
+    pub fn has_auto_copy(&self) -> bool {
+        match self.auto_copy_target {
+            AutoCopyTarget::PerBlock => self.blocks.iter().any(|b| b.auto_copy),
+            AutoCopyTarget::None => false,
+            _ => true,
+        }
+    }
 }
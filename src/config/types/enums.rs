@@ -20,6 +20,34 @@ pub enum ThemeMode {
     Light,
 }
 
+// ============================================================================
+// UPDATE CHANNEL
+// ============================================================================
+
+/// Which GitHub releases the updater offers
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub enum UpdateChannel {
+    #[default]
+    Stable,
+    /// Pre-releases too
+    Beta,
+}
+
+// ============================================================================
+// WEBVIEW LIFECYCLE
+// ============================================================================
+
+/// What the Prompt DJ and Live Translate overlays do with their WebView while hidden
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WebViewLifecycle {
+    /// Kept alive, so the overlay opens instantly
+    #[default]
+    Persistent,
+    /// Destroyed on hide and rebuilt on the next show
+    Lazy,
+}
+
 // ============================================================================
 // BLOCK TYPE - Used by ProcessingBlock for type checking
 // ============================================================================
@@ -45,6 +73,86 @@ impl BlockType {
     }
 }
 
+// ============================================================================
+// AUTO-COPY - Which results a preset copies, and how
+// ============================================================================
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoCopyTarget {
+    #[default]
+    PerBlock, // Blocks with auto-copy switched on in the node editor
+    FinalBlock, // Output of the last block(s) of the chain
+    EachBlock,  // Output of every processing block, as it finishes
+    None,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoCopyMode {
+    #[default]
+    Replace,
+    Append, // Add to the text already on the clipboard
+}
+
+// ============================================================================
+// POST COMMAND - What a preset's external command does with the result
+// ============================================================================
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PostCommandMode {
+    #[default]
+    Replace, // The command's output becomes the result
+    Append, // The output is added below the result
+}
+
+// ============================================================================
+// IMAGE UPLOAD - How an image block's capture is encoded for the API
+// ============================================================================
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageUploadFormat {
+    #[default]
+    Png,
+    Jpeg, // Lossy, at ImageUploadSettings::quality
+    Webp, // Lossless
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct ImageUploadSettings {
+    #[serde(default)]
+    pub format: ImageUploadFormat,
+    /// JPEG quality, 1-100
+    #[serde(default = "default_image_quality")]
+    pub quality: u8,
+    /// Downscale so the longest side is at most this many px; 0 keeps the capture size
+    #[serde(default)]
+    pub max_dimension: u32,
+}
+
+fn default_image_quality() -> u8 {
+    85
+}
+
+impl Default for ImageUploadSettings {
+    fn default() -> Self {
+        Self {
+            format: ImageUploadFormat::Png,
+            quality: default_image_quality(),
+            max_dimension: 0,
+        }
+    }
+}
+
+impl ImageUploadSettings {
+    /// The capture is sent as captured (PNG, full size)
+    pub fn is_passthrough(&self) -> bool {
+        self.format == ImageUploadFormat::Png && self.max_dimension == 0
+    }
+}
+
 // ============================================================================
 // UTILITY FUNCTIONS
 // ============================================================================
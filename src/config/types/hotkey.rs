@@ -1,4 +1,4 @@
-//! Hotkey configuration type.
+//! Hotkey configuration types.
 
 use serde::{Deserialize, Serialize};
 
@@ -22,3 +22,90 @@ impl Hotkey {
         }
     }
 }
+
+const MOD_ALT: u32 = 0x0001;
+const MOD_CONTROL: u32 = 0x0002;
+const MOD_SHIFT: u32 = 0x0004;
+const MOD_WIN: u32 = 0x0008;
+
+/// Shortcuts Windows keeps for itself: (virtual key, modifiers, what it does)
+const RESERVED_SHORTCUTS: [(u32, u32, &str); 16] = [
+    (0x4C, MOD_WIN, "Lock screen"),
+    (0x44, MOD_WIN, "Show desktop"),
+    (0x45, MOD_WIN, "File Explorer"),
+    (0x52, MOD_WIN, "Run"),
+    (0x49, MOD_WIN, "Settings"),
+    (0x53, MOD_WIN, "Search"),
+    (0x58, MOD_WIN, "Quick Link menu"),
+    (0x56, MOD_WIN, "Clipboard history"),
+    (0x41, MOD_WIN, "Quick Settings"),
+    (0x09, MOD_WIN, "Task View"),
+    (0x53, MOD_WIN | MOD_SHIFT, "Snipping Tool"),
+    (0x09, MOD_ALT, "Switch windows"),
+    (0x73, MOD_ALT, "Close window"),
+    (0x2E, MOD_CONTROL | MOD_ALT, "Security screen"),
+    (0x1B, MOD_CONTROL | MOD_SHIFT, "Task Manager"),
+    (0x1B, MOD_CONTROL, "Start menu"),
+];
+
+/// What Windows uses `code` + `modifiers` for, if it is one of its own shortcuts
+pub fn reserved_shortcut(code: u32, modifiers: u32) -> Option<&'static str> {
+    RESERVED_SHORTCUTS
+        .iter()
+        .find(|(c, m, _)| *c == code && *m == modifiers)
+        .map(|(_, _, what)| *what)
+}
+
+/// First WM_HOTKEY id of the app-wide hotkeys. Preset hotkeys use
+/// `1000 * preset_idx + hotkey_idx + 1`, which stays below this for up to 48 presets.
+const APP_HOTKEY_ID_BASE: i32 = 0xBF00;
+
+/// App-wide actions that can be bound to a global hotkey, next to the preset hotkeys
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AppHotkey {
+    OpenSettings,
+    DismissOverlays,
+    ToggleFavoriteBubble,
+    /// Run the most recently used preset again
+    RepeatLastPreset,
+    /// Translate the clipboard's text with the quick translate settings
+    TranslateClipboard,
+    /// Translate the selected text with the quick translate settings
+    TranslateSelection,
+    /// Put back the selection the last auto-pasted result replaced
+    UndoReplace,
+}
+
+impl AppHotkey {
+    pub const ALL: [AppHotkey; 7] = [
+        AppHotkey::OpenSettings,
+        AppHotkey::DismissOverlays,
+        AppHotkey::ToggleFavoriteBubble,
+        AppHotkey::RepeatLastPreset,
+        AppHotkey::TranslateClipboard,
+        AppHotkey::TranslateSelection,
+        AppHotkey::UndoReplace,
+    ];
+
+    /// Id passed to `RegisterHotKey` and received in WM_HOTKEY
+    pub fn id(self) -> i32 {
+        APP_HOTKEY_ID_BASE + self as i32
+    }
+
+    pub fn from_id(id: i32) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.id() == id)
+    }
+
+    /// English name, for conflict messages
+    pub fn name(self) -> &'static str {
+        match self {
+            AppHotkey::OpenSettings => "Open settings",
+            AppHotkey::DismissOverlays => "Dismiss all overlays",
+            AppHotkey::ToggleFavoriteBubble => "Toggle favorite bubble",
+            AppHotkey::RepeatLastPreset => "Repeat last preset",
+            AppHotkey::TranslateClipboard => "Translate clipboard",
+            AppHotkey::TranslateSelection => "Translate selection",
+            AppHotkey::UndoReplace => "Undo last replace",
+        }
+    }
+}
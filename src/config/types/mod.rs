@@ -1,8 +1,9 @@
 //! Configuration types module.
 //!
 //! This module organizes all configuration-related types into logical groups:
-//! - `enums`: Core enums (ThemeMode, BlockType)
-//! - `hotkey`: Hotkey binding type
+//! - `enums`: Core enums (ThemeMode, UpdateChannel, BlockType, AutoCopyTarget, AutoCopyMode,
+//!   PostCommandMode, ImageUploadSettings)
+//! - `hotkey`: Hotkey binding type and the app-wide hotkey actions
 //! - `tts`: TTS-related types (TtsMethod, EdgeTtsSettings, etc.)
 
 mod enums;
@@ -10,9 +11,13 @@ mod hotkey;
 mod tts;
 
 // Re-export all types for easy access
-pub use enums::{get_system_ui_language, BlockType, ThemeMode, DEFAULT_HISTORY_LIMIT};
+pub use enums::{
+    get_system_ui_language, AutoCopyMode, AutoCopyTarget, BlockType, ImageUploadFormat,
+    ImageUploadSettings, PostCommandMode, ThemeMode, UpdateChannel, WebViewLifecycle,
+    DEFAULT_HISTORY_LIMIT,
+};
 
-pub use hotkey::Hotkey;
+pub use hotkey::{reserved_shortcut, AppHotkey, Hotkey};
 
 pub use tts::{
     default_tts_language_conditions, EdgeTtsSettings, EdgeTtsVoiceConfig, TtsLanguageCondition,
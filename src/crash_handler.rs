@@ -0,0 +1,137 @@
+//! Panic hook: shows a localized crash message box and appends the crash to `crash.log`.
+
+use std::io::Write;
+use std::panic;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use windows::core::PCWSTR;
+use windows::Win32::UI::WindowsAndMessaging::{MessageBoxW, MB_ICONERROR, MB_OK};
+
+use crate::gui::locale::LocaleText;
+
+/// Rotate `crash.log` to `crash.log.old` once it grows past this size
+const MAX_CRASH_LOG_BYTES: u64 = 512 * 1024;
+
+lazy_static::lazy_static! {
+    // Cached UI language, so the hook never has to touch the (possibly locked) APP state
+    static ref CRASH_UI_LANGUAGE: Mutex<String> = Mutex::new("en".to_string());
+}
+
+// Set while the hook runs, so a panic inside the hook doesn't recurse
+static IN_PANIC_HOOK: AtomicBool = AtomicBool::new(false);
+
+/// Update the language used by the crash message box
+pub fn set_ui_language(lang: &str) {
+    if let Ok(mut cached) = CRASH_UI_LANGUAGE.lock() {
+        *cached = lang.to_string();
+    }
+}
+
+/// Path of the rolling crash log in the app data dir
+pub fn crash_log_path() -> PathBuf {
+    let config_dir = dirs::config_dir()
+        .unwrap_or_default()
+        .join("screen-goated-toolbox");
+    let _ = std::fs::create_dir_all(&config_dir);
+    config_dir.join("crash.log")
+}
+
+/// Install the panic hook. Call as early as possible in `main()`.
+pub fn install() {
+    panic::set_hook(Box::new(|panic_info| {
+        // Re-entrant panic (e.g. while writing the log): bail out, the first one reports
+        if IN_PANIC_HOOK.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        // 1. Format the error message
+        let location = if let Some(location) = panic_info.location() {
+            format!("File: {}\nLine: {}", location.file(), location.line())
+        } else {
+            "Unknown location".to_string()
+        };
+
+        let payload = if let Some(s) = panic_info.payload().downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = panic_info.payload().downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "Unknown panic payload".to_string()
+        };
+        // A panic inside a request can carry the key in its message
+        let payload = crate::redact::redact(&payload).into_owned();
+
+        // 2. Append to crash.log
+        let log_path = append_crash_log(&payload, &location);
+
+        // 3. Show a Windows Message Box so the user knows it crashed
+        // try_lock: the panicking thread may be the one holding the lock
+        let lang = CRASH_UI_LANGUAGE
+            .try_lock()
+            .map(|l| l.clone())
+            .unwrap_or_else(|_| "en".to_string());
+        let text = LocaleText::get(&lang);
+
+        let mut error_msg = format!(
+            "{}\n\n{} {}\n\n{}\n{}",
+            text.crash_detected,
+            text.crash_error_label,
+            payload,
+            text.crash_location_label,
+            location
+        );
+        if let Some(path) = log_path {
+            error_msg.push_str(&format!("\n\n{}\n{}", text.crash_log_saved, path.display()));
+        }
+
+        let wide_msg: Vec<u16> = error_msg.encode_utf16().chain(std::iter::once(0)).collect();
+        let wide_title: Vec<u16> = text
+            .crash_report_title
+            .encode_utf16()
+            .chain(std::iter::once(0))
+            .collect();
+
+        unsafe {
+            MessageBoxW(
+                None,
+                PCWSTR(wide_msg.as_ptr()),
+                PCWSTR(wide_title.as_ptr()),
+                MB_ICONERROR | MB_OK,
+            );
+        }
+
+        IN_PANIC_HOOK.store(false, Ordering::SeqCst);
+    }));
+}
+
+/// Append one crash entry; returns the log path if the write succeeded
+fn append_crash_log(payload: &str, location: &str) -> Option<PathBuf> {
+    let path = crash_log_path();
+
+    // Keep the log small: start over (keeping one old copy) when it gets too big
+    if std::fs::metadata(&path).is_ok_and(|m| m.len() > MAX_CRASH_LOG_BYTES) {
+        let _ = std::fs::rename(&path, path.with_extension("log.old"));
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .ok()?;
+
+    let thread = std::thread::current();
+    writeln!(
+        file,
+        "[{}] v{} thread '{}'\nError: {}\n{}\n",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+        env!("CARGO_PKG_VERSION"),
+        thread.name().unwrap_or("<unnamed>"),
+        payload,
+        location
+    )
+    .ok()?;
+
+    Some(path)
+}
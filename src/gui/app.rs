@@ -6,7 +6,7 @@ mod types;
 mod utils;
 
 pub use types::SettingsApp;
-pub use utils::signal_restore_window;
+pub use utils::{flush_pending_config_save, signal_restore_window};
 
 use eframe::egui;
 
@@ -29,9 +29,15 @@ impl eframe::App for SettingsApp {
         // Startup Logic
         self.update_startup(ctx);
 
+        // Periodic Update Check
+        self.update_scheduled_update_check(ctx);
+
         // Bubble Sync
         self.update_bubble_sync();
 
+        // UI Scale & Sidebar Layout
+        self.update_window_layout(ctx);
+
         // Splash
         self.update_splash(ctx);
 
@@ -44,6 +50,9 @@ impl eframe::App for SettingsApp {
         // Event Handling
         self.handle_events(ctx);
 
+        // Debounced Config Save (flushes before the close request hides the window)
+        self.update_config_save(ctx);
+
         // Close Request
         self.handle_close_request(ctx);
 
@@ -54,9 +63,15 @@ impl eframe::App for SettingsApp {
         // Footer & Tips Modal
         self.render_footer_and_tips_modal(ctx);
 
+        // What's New (once after an update)
+        self.render_whats_new_modal(ctx);
+
         // Main Layout
         self.render_main_layout(ctx);
 
+        // Footer contents (after main layout so Tab order ends at the footer)
+        self.render_footer_content(ctx);
+
         // Fade In Overlay (Last)
         self.render_fade_overlay(ctx);
 
@@ -73,6 +88,8 @@ impl eframe::App for SettingsApp {
     }
 
     fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        utils::flush_pending_config_save();
+        crate::overlay::utils::clear_clipboard_on_exit();
         self.tray_icon = None;
     }
 }
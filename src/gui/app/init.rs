@@ -1,5 +1,5 @@
 use super::types::{SettingsApp, UserEvent, RESTORE_SIGNAL};
-use crate::config::{Config, ThemeMode};
+use crate::config::{Config, ThemeMode, UI_SCALE_RANGE};
 use crate::gui::settings_ui::ViewMode;
 use crate::gui::utils::get_monitor_names;
 use crate::updater::{UpdateStatus, Updater};
@@ -136,7 +136,11 @@ impl SettingsApp {
         std::thread::spawn(move || {
             while let Ok(event) = MenuEvent::receiver().recv() {
                 match event.id.0.as_str() {
-                    "1001" => std::process::exit(0),
+                    "1001" => {
+                        super::utils::flush_pending_config_save();
+                        crate::overlay::utils::clear_clipboard_on_exit();
+                        std::process::exit(0);
+                    }
                     "1002" => {
                         unsafe {
                             let class_name = w!("eframe");
@@ -178,6 +182,24 @@ impl SettingsApp {
 
         let cached_monitors = get_monitor_names();
         let (up_tx, up_rx) = channel();
+        let updater = Updater::new(up_tx);
+
+        // First run of a new version (e.g. after a self-update): show its release notes once
+        let current_version = env!("CARGO_PKG_VERSION");
+        if config.last_seen_version != current_version {
+            let previous =
+                std::mem::replace(&mut config.last_seen_version, current_version.to_string());
+            let is_upgrade = previous.is_empty()
+                || self_update::version::bump_is_greater(&previous, current_version)
+                    .unwrap_or(false);
+            if is_upgrade {
+                updater.fetch_release_notes(current_version);
+            }
+            if let Ok(mut state) = app_state.lock() {
+                state.config.last_seen_version = current_version.to_string();
+            }
+            crate::config::save_config(&config);
+        }
 
         // --- Init Audio Device Cache ---
         let cached_audio_devices = Arc::new(Mutex::new(Vec::new()));
@@ -209,6 +231,7 @@ impl SettingsApp {
 
         let start_in_tray = config.start_in_tray;
         let initial_ui_language = config.ui_language.clone(); // Extract before move
+        crate::gui::icons::set_ui_language(&initial_ui_language);
         let rng_seed = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap()
@@ -220,6 +243,11 @@ impl SettingsApp {
         // Capture bubble state before config is moved
         let initial_bubble_enabled = config.show_favorite_bubble;
         let initial_has_favorites = config.presets.iter().any(|p| p.is_favorite);
+        let initial_bubbles_separate = config.favorite_bubbles_separate;
+        let initial_ui_scale = config
+            .ui_scale
+            .clamp(*UI_SCALE_RANGE.start(), *UI_SCALE_RANGE.end());
+        let initial_sidebar_layout = config.sidebar_layout.clone();
 
         Self {
             config,
@@ -241,7 +269,9 @@ impl SettingsApp {
             show_openrouter_api_key: false,
             show_cerebras_api_key: false,
             view_mode,
+            sidebar_focused_preset: None,
             recording_hotkey_for_preset: None,
+            recording_app_hotkey: None,
             hotkey_conflict_msg: None,
             splash: if start_in_tray {
                 None
@@ -254,9 +284,10 @@ impl SettingsApp {
             cached_audio_devices,
             snarl: None,
             last_edited_preset_idx: None,
-            updater: Some(Updater::new(up_tx)),
+            updater: Some(updater),
             update_rx: up_rx,
             update_status: UpdateStatus::Idle,
+            background_update_check: false,
 
             // --- NEW FIELD INIT ---
             current_admin_state,
@@ -274,16 +305,32 @@ impl SettingsApp {
             rng_seed,
             // ---------------
 
+            // --- FOOTER STATE INIT ---
+            footer_rect: egui::Rect::NOTHING,
+
             // --- USAGE MODAL INIT ---
             show_usage_modal: false,
             drop_overlay_fade: 0.0,
             // --- TTS SETTINGS MODAL INIT ---
             show_tts_modal: false,
             // -----------------------
+            // --- WHAT'S NEW MODAL INIT ---
+            whats_new: None,
+            // -----------------------------
 
             // --- FAVORITE BUBBLE STATE INIT ---
             last_bubble_enabled: initial_bubble_enabled,
             last_has_favorites: initial_has_favorites,
+            last_bubbles_separate: initial_bubbles_separate,
+            // ----------------------------------
+
+            // --- WINDOW LAYOUT STATE INIT ---
+            last_ui_scale: initial_ui_scale,
+            last_sidebar_layout: initial_sidebar_layout,
+            // --------------------------------
+
+            // --- DEBOUNCED CONFIG SAVE INIT ---
+            config_save_due: None,
             // ----------------------------------
         }
     }
@@ -1,12 +1,12 @@
 use super::types::{
-    SettingsApp, UserEvent, MOD_ALT, MOD_CONTROL, MOD_SHIFT, MOD_WIN, RESTORE_SIGNAL,
+    HotkeyOwner, SettingsApp, UserEvent, MOD_ALT, MOD_CONTROL, MOD_SHIFT, MOD_WIN, RESTORE_SIGNAL,
 };
-use crate::config::{Hotkey, ThemeMode};
+use crate::config::{Hotkey, ThemeMode, UI_SCALE_RANGE};
 use crate::gui::app::utils::simple_rand;
 use crate::gui::key_mapping::{egui_key_to_vk, egui_pointer_to_vk};
 use crate::gui::locale::LocaleText;
 use crate::icon_gen;
-use crate::{WINDOW_HEIGHT, WINDOW_WIDTH};
+use crate::{window_width_for_layout, WINDOW_HEIGHT};
 use eframe::egui;
 use std::sync::atomic::Ordering;
 use tray_icon::{MouseButton, TrayIconBuilder, TrayIconEvent};
@@ -19,17 +19,101 @@ use windows::Win32::UI::WindowsAndMessaging::GetCursorPos;
 impl SettingsApp {
     pub(crate) fn check_updater(&mut self) {
         while let Ok(status) = self.update_rx.try_recv() {
+            if let crate::updater::UpdateStatus::ReleaseNotes { version, body } = status {
+                self.whats_new = Some((version, body));
+                continue;
+            }
             // Show popup notification when update is available
             if let crate::updater::UpdateStatus::UpdateAvailable { ref version, .. } = status {
-                // Show blue-themed update notification with longer duration
-                let ui_lang = self.config.ui_language.clone();
-                let locale = crate::gui::locale::LocaleText::get(&ui_lang);
-                let notification_text =
-                    format!("{} v{}", locale.update_available_notification, version);
-                crate::overlay::auto_copy_badge::show_update_notification(&notification_text);
+                // Scheduled checks and quiet hours only mark the tray and the update section
+                let quiet = self.background_update_check || self.in_update_quiet_hours();
+                if !quiet {
+                    // Show blue-themed update notification with longer duration
+                    let ui_lang = self.config.ui_language.clone();
+                    let locale = crate::gui::locale::LocaleText::get(&ui_lang);
+                    let notification_text =
+                        format!("{} v{}", locale.update_available_notification, version);
+                    crate::overlay::auto_copy_badge::show_update_notification(&notification_text);
+                }
+            }
+            if !matches!(status, crate::updater::UpdateStatus::Checking) {
+                self.background_update_check = false;
             }
             self.update_status = status;
+            if let Some(tray) = &self.tray_icon {
+                let _ = tray.set_tooltip(Some(self.tray_tooltip()));
+            }
+        }
+    }
+
+    /// Tray tooltip, naming the new version while an update is available
+    fn tray_tooltip(&self) -> String {
+        let base = "Screen Goated Toolbox (nganlinh4)";
+        match &self.update_status {
+            crate::updater::UpdateStatus::UpdateAvailable { version, .. } => {
+                let locale = LocaleText::get(&self.config.ui_language);
+                format!(
+                    "{}\n{} v{}",
+                    base, locale.update_available_notification, version
+                )
+            }
+            _ => base.to_string(),
+        }
+    }
+
+    fn in_update_quiet_hours(&self) -> bool {
+        use chrono::Timelike;
+        self.config.update_quiet_hours_enabled
+            && crate::updater::is_quiet_hour(
+                self.config.update_quiet_start,
+                self.config.update_quiet_end,
+                chrono::Local::now().hour(),
+            )
+    }
+
+    /// Start an update check and remember when, so the schedule survives restarts
+    fn start_update_check(&mut self, background: bool) {
+        let Some(updater) = &self.updater else {
+            return;
+        };
+        updater.check_for_updates(self.config.update_channel);
+        self.background_update_check = background;
+        self.config.last_update_check = chrono::Utc::now().timestamp();
+        self.save_and_sync();
+    }
+
+    /// Periodic update check for sessions that stay open for days. Waits out quiet
+    /// hours, and never runs while a check or download is already going.
+    pub(crate) fn update_scheduled_update_check(&mut self, ctx: &egui::Context) {
+        use crate::updater::UpdateStatus;
+
+        let interval = self.config.update_check_interval_hours;
+        if interval == 0 || self.startup_stage < 3 || self.updater.is_none() {
+            return;
+        }
+        if matches!(
+            self.update_status,
+            UpdateStatus::Checking
+                | UpdateStatus::Downloading
+                | UpdateStatus::UpdatedAndRestartRequired
+                | UpdateStatus::RollbackStaged(_)
+        ) || self.background_update_check
+        {
+            return;
+        }
+
+        let due = self.config.last_update_check + interval as i64 * 3600;
+        let wait = due - chrono::Utc::now().timestamp();
+        if wait > 0 {
+            ctx.request_repaint_after(std::time::Duration::from_secs(wait as u64));
+            return;
+        }
+        if self.in_update_quiet_hours() {
+            // Look again in a while; the window may have ended
+            ctx.request_repaint_after(std::time::Duration::from_secs(15 * 60));
+            return;
         }
+        self.start_update_check(true);
     }
 
     pub(crate) fn update_theme_and_tray(&mut self, ctx: &egui::Context) {
@@ -78,6 +162,8 @@ impl SettingsApp {
             let new_locale = LocaleText::get(&self.config.ui_language);
             self.tray_settings_item.set_text(new_locale.tray_settings);
             self.tray_quit_item.set_text(new_locale.tray_quit);
+            crate::crash_handler::set_ui_language(&self.config.ui_language);
+            crate::gui::icons::set_ui_language(&self.config.ui_language);
         }
 
         // --- LAZY TRAY ICON CREATION ---
@@ -96,7 +182,7 @@ impl SettingsApp {
 
                 if let Ok(tray) = TrayIconBuilder::new()
                     // NO with_menu() - we handle menu manually to avoid blocking
-                    .with_tooltip("Screen Goated Toolbox (nganlinh4)")
+                    .with_tooltip(self.tray_tooltip())
                     .with_icon(icon)
                     .build()
                 {
@@ -122,8 +208,9 @@ impl SettingsApp {
                 let work_left = mi.rcWork.left as f32;
                 let work_top = mi.rcWork.top as f32;
 
+                let window_width = window_width_for_layout(&self.config.sidebar_layout);
                 let pixels_per_point = ctx.pixels_per_point();
-                let win_w_physical = WINDOW_WIDTH * pixels_per_point;
+                let win_w_physical = window_width * pixels_per_point;
                 let win_h_physical = WINDOW_HEIGHT * pixels_per_point;
 
                 let center_x_physical = work_left + (work_w - win_w_physical) / 2.0;
@@ -136,7 +223,7 @@ impl SettingsApp {
                     x_logical, y_logical,
                 )));
                 ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(
-                    WINDOW_WIDTH,
+                    window_width,
                     WINDOW_HEIGHT,
                 )));
 
@@ -152,7 +239,7 @@ impl SettingsApp {
                 splash.reset_timer(ctx);
             }
             ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(
-                WINDOW_WIDTH,
+                window_width_for_layout(&self.config.sidebar_layout),
                 WINDOW_HEIGHT,
             )));
 
@@ -164,9 +251,7 @@ impl SettingsApp {
             self.startup_stage = 3;
 
             // Trigger auto-update check at startup
-            if let Some(updater) = &self.updater {
-                updater.check_for_updates();
-            }
+            self.start_update_check(false);
 
             // Start favorite bubble if enabled and has favorites
             let has_favorites = self.config.presets.iter().any(|p| p.is_favorite);
@@ -200,6 +285,60 @@ impl SettingsApp {
                 crate::overlay::favorite_bubble::hide_favorite_bubble();
             }
         }
+
+        // Switching between one bubble and per-preset bubbles: swap them while visible
+        if self.config.favorite_bubbles_separate != self.last_bubbles_separate {
+            self.last_bubbles_separate = self.config.favorite_bubbles_separate;
+            if current_bubble_enabled && current_has_favorites {
+                crate::overlay::favorite_bubble::hide_favorite_bubble();
+                crate::overlay::favorite_bubble::show_favorite_bubble();
+            }
+        }
+    }
+
+    pub(crate) fn update_window_layout(&mut self, ctx: &egui::Context) {
+        // --- SIDEBAR LAYOUT SYNC (Change-Detection Only) ---
+        if self.config.sidebar_layout != self.last_sidebar_layout {
+            self.last_sidebar_layout = self.config.sidebar_layout.clone();
+            let width = window_width_for_layout(&self.config.sidebar_layout);
+            let height = ctx
+                .input(|i| i.viewport().inner_rect.map(|r| r.height()))
+                .unwrap_or(WINDOW_HEIGHT);
+            ctx.send_viewport_cmd(egui::ViewportCommand::MinInnerSize(egui::vec2(
+                width,
+                WINDOW_HEIGHT / 2.0,
+            )));
+            ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(width, height)));
+        }
+
+        // --- UI SCALE SYNC (Change-Detection Only) ---
+        let scale = self
+            .config
+            .ui_scale
+            .clamp(*UI_SCALE_RANGE.start(), *UI_SCALE_RANGE.end());
+        if scale == self.last_ui_scale {
+            return;
+        }
+        // Wait for the slider to be released, otherwise the slider moves under the cursor
+        if ctx.input(|i| i.pointer.any_down()) {
+            return;
+        }
+
+        let old_scale = self.last_ui_scale;
+        self.last_ui_scale = scale;
+        ctx.set_zoom_factor(scale);
+
+        // Window sizes are in points, so grow/shrink them with the zoom to avoid clipping.
+        // These commands are applied with the old zoom factor, hence the ratio.
+        let ratio = scale / old_scale;
+        let width = window_width_for_layout(&self.config.sidebar_layout);
+        let current_size = ctx
+            .input(|i| i.viewport().inner_rect.map(|r| r.size()))
+            .unwrap_or(egui::vec2(width, WINDOW_HEIGHT));
+        ctx.send_viewport_cmd(egui::ViewportCommand::MinInnerSize(
+            egui::vec2(width, WINDOW_HEIGHT / 2.0) * ratio,
+        ));
+        ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(current_size * ratio));
     }
 
     pub(crate) fn update_splash(&mut self, ctx: &egui::Context) {
@@ -279,7 +418,12 @@ impl SettingsApp {
     }
 
     pub(crate) fn update_hotkey_recording(&mut self, ctx: &egui::Context) {
-        if let Some(preset_idx) = self.recording_hotkey_for_preset {
+        let owner = match (self.recording_hotkey_for_preset, self.recording_app_hotkey) {
+            (Some(preset_idx), _) => Some(HotkeyOwner::Preset(preset_idx)),
+            (None, Some(which)) => Some(HotkeyOwner::App(which)),
+            (None, None) => None,
+        };
+        if let Some(owner) = owner {
             let mut key_recorded: Option<(u32, u32, String)> = None;
             let mut cancel = false;
 
@@ -346,9 +490,10 @@ impl SettingsApp {
 
             if cancel {
                 self.recording_hotkey_for_preset = None;
+                self.recording_app_hotkey = None;
                 self.hotkey_conflict_msg = None;
             } else if let Some((vk, mods, key_name)) = key_recorded {
-                if let Some(msg) = self.check_hotkey_conflict(vk, mods, preset_idx) {
+                if let Some(msg) = self.check_hotkey_conflict(vk, mods, owner) {
                     self.hotkey_conflict_msg = Some(msg);
                 } else {
                     let mut name_parts = Vec::new();
@@ -372,17 +517,26 @@ impl SettingsApp {
                         name: name_parts.join(" + "),
                     };
 
-                    if let Some(preset) = self.config.presets.get_mut(preset_idx) {
-                        if !preset
-                            .hotkeys
-                            .iter()
-                            .any(|h| h.code == vk && h.modifiers == mods)
-                        {
-                            preset.hotkeys.push(new_hotkey);
+                    match owner {
+                        HotkeyOwner::Preset(preset_idx) => {
+                            if let Some(preset) = self.config.presets.get_mut(preset_idx) {
+                                if !preset
+                                    .hotkeys
+                                    .iter()
+                                    .any(|h| h.code == vk && h.modifiers == mods)
+                                {
+                                    preset.hotkeys.push(new_hotkey);
+                                    self.save_and_sync();
+                                }
+                            }
+                        }
+                        HotkeyOwner::App(which) => {
+                            *self.config.app_hotkey_mut(which) = Some(new_hotkey);
                             self.save_and_sync();
                         }
                     }
                     self.recording_hotkey_for_preset = None;
+                    self.recording_app_hotkey = None;
                     self.hotkey_conflict_msg = None;
                 }
             }
@@ -2,12 +2,15 @@ use super::types::SettingsApp;
 use crate::gui::locale::LocaleText;
 use crate::gui::settings_ui::node_graph::{blocks_to_snarl, snarl_to_graph};
 use crate::gui::settings_ui::{
-    render_footer, render_global_settings, render_history_panel, render_preset_editor,
-    render_sidebar, ViewMode,
+    handle_sidebar_keyboard_nav, render_footer, render_global_settings, render_history_panel,
+    render_preset_editor, render_sidebar, ViewMode,
 };
 use eframe::egui;
 use egui::text::{LayoutJob, TextFormat};
 
+/// Height of the footer row (tip line + admin/version labels)
+const FOOTER_CONTENT_HEIGHT: f32 = 18.0;
+
 impl SettingsApp {
     pub(crate) fn render_footer_and_tips_modal(&mut self, ctx: &egui::Context) {
         let text = LocaleText::get(&self.config.ui_language);
@@ -18,14 +21,10 @@ impl SettingsApp {
             egui::Color32::from_gray(240)
         };
 
-        // Determine current tip text for footer
-        let current_tip = text
-            .tips_list
-            .get(self.current_tip_idx)
-            .unwrap_or(&"")
-            .to_string();
-
-        egui::TopBottomPanel::bottom("footer_panel")
+        // Panels must be laid out before the central panel, so only reserve the strip here.
+        // The footer widgets are added in `render_footer_content` after the main layout,
+        // which keeps keyboard/screen-reader focus order: sidebar -> content -> footer.
+        self.footer_rect = egui::TopBottomPanel::bottom("footer_panel")
             .resizable(false)
             .show_separator_line(false)
             .frame(
@@ -34,14 +33,13 @@ impl SettingsApp {
                     .fill(footer_bg),
             )
             .show(ctx, |ui| {
-                render_footer(
-                    ui,
-                    &text,
-                    current_tip.clone(),
-                    self.tip_fade_state,
-                    &mut self.show_tips_modal,
+                let (rect, _) = ui.allocate_exact_size(
+                    egui::vec2(ui.available_width(), FOOTER_CONTENT_HEIGHT),
+                    egui::Sense::hover(),
                 );
-            });
+                rect
+            })
+            .inner;
 
         // [TIPS POPUP]
         let tips_popup_id = egui::Id::new("tips_popup_modal");
@@ -129,8 +127,95 @@ impl SettingsApp {
         }
     }
 
+    /// "What's new" panel shown once after the app was updated
+    pub(crate) fn render_whats_new_modal(&mut self, ctx: &egui::Context) {
+        let Some((version, body)) = &self.whats_new else {
+            return;
+        };
+        let text = LocaleText::get(&self.config.ui_language);
+        let title = text.whats_new_title.replace("{}", version);
+        let mut close = false;
+
+        egui::Window::new(&title)
+            .collapsible(false)
+            .resizable(false)
+            .title_bar(false)
+            .default_width(480.0)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(&title).strong().size(14.0));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if crate::gui::icons::icon_button(ui, crate::gui::icons::Icon::Close)
+                            .clicked()
+                        {
+                            close = true;
+                        }
+                    });
+                });
+                ui.separator();
+                ui.add_space(4.0);
+
+                egui::ScrollArea::vertical()
+                    .max_height(360.0)
+                    .show(ui, |ui| {
+                        // Release notes are markdown; headings and bullets read fine as-is
+                        ui.label(body.replace("\r\n", "\n"));
+                    });
+                ui.add_space(8.0);
+                if ui.button(text.whats_new_close).clicked() {
+                    close = true;
+                }
+            });
+
+        if close || ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            self.whats_new = None;
+        }
+    }
+
+    /// Fill the footer strip reserved by `render_footer_and_tips_modal`
+    pub(crate) fn render_footer_content(&mut self, ctx: &egui::Context) {
+        let text = LocaleText::get(&self.config.ui_language);
+
+        // Determine current tip text for footer
+        let current_tip = text
+            .tips_list
+            .get(self.current_tip_idx)
+            .unwrap_or(&"")
+            .to_string();
+
+        let mut ui = egui::Ui::new(
+            ctx.clone(),
+            egui::Id::new("footer_content"),
+            egui::UiBuilder::new()
+                .layer_id(egui::LayerId::background())
+                .max_rect(self.footer_rect),
+        );
+        render_footer(
+            &mut ui,
+            &text,
+            current_tip,
+            self.tip_fade_state,
+            &mut self.show_tips_modal,
+        );
+    }
+
     pub(crate) fn render_main_layout(&mut self, ctx: &egui::Context) {
         let text = LocaleText::get(&self.config.ui_language);
+
+        // Arrow-key preset navigation (not while a hotkey is being recorded)
+        if self.recording_hotkey_for_preset.is_none()
+            && self.recording_app_hotkey.is_none()
+            && !self.show_tips_modal
+        {
+            handle_sidebar_keyboard_nav(
+                ctx,
+                &self.config,
+                &mut self.view_mode,
+                &mut self.sidebar_focused_preset,
+            );
+        }
+
         egui::CentralPanel::default().show(ctx, |ui| {
             let available_width = ui.available_width();
             let left_width = available_width * 0.35;
@@ -142,7 +227,13 @@ impl SettingsApp {
                     egui::vec2(left_width, ui.available_height()),
                     egui::Layout::top_down(egui::Align::Min),
                     |ui| {
-                        if render_sidebar(ui, &mut self.config, &mut self.view_mode, &text) {
+                        if render_sidebar(
+                            ui,
+                            &mut self.config,
+                            &mut self.view_mode,
+                            &mut self.sidebar_focused_preset,
+                            &text,
+                        ) {
                             self.save_and_sync();
                         }
                     },
@@ -157,10 +248,7 @@ impl SettingsApp {
                     |ui| {
                         match self.view_mode {
                             ViewMode::Global => {
-                                let usage_stats = {
-                                    let app = self.app_state_ref.lock().unwrap();
-                                    app.model_usage_stats.clone()
-                                };
+                                let usage_stats = crate::api::usage::model_usage_stats();
                                 if render_global_settings(
                                     ui,
                                     &mut self.config,
@@ -178,7 +266,12 @@ impl SettingsApp {
                                     &mut self.show_usage_modal,
                                     &mut self.show_tts_modal,
                                     &self.cached_audio_devices,
+                                    &mut self.recording_app_hotkey,
+                                    &self.hotkey_conflict_msg,
                                 ) {
+                                    // Presets may have been replaced (reset / backup import):
+                                    // rebuild the node graph on the next preset visit
+                                    self.last_edited_preset_idx = None;
                                     self.save_and_sync();
                                 }
                             }
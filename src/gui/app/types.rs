@@ -1,4 +1,4 @@
-use crate::config::Config;
+use crate::config::{AppHotkey, Config};
 use crate::gui::settings_ui::node_graph::ChainNode;
 use crate::gui::settings_ui::ViewMode;
 use crate::updater::{UpdateStatus, Updater};
@@ -21,6 +21,13 @@ lazy_static::lazy_static! {
     pub static ref RESTORE_SIGNAL: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
 }
 
+/// What a hotkey being recorded (or checked for conflicts) belongs to
+#[derive(Clone, Copy, PartialEq)]
+pub enum HotkeyOwner {
+    Preset(usize),
+    App(AppHotkey),
+}
+
 pub enum UserEvent {
     Tray(TrayIconEvent),
     Menu(MenuEvent),
@@ -48,7 +55,9 @@ pub struct SettingsApp {
     pub(crate) show_cerebras_api_key: bool,
 
     pub(crate) view_mode: ViewMode,
+    pub(crate) sidebar_focused_preset: Option<usize>, // Keyboard focus in the preset sidebar
     pub(crate) recording_hotkey_for_preset: Option<usize>,
+    pub(crate) recording_app_hotkey: Option<AppHotkey>,
     pub(crate) hotkey_conflict_msg: Option<String>,
     pub(crate) splash: Option<crate::gui::splash::SplashScreen>,
     pub(crate) fade_in_start: Option<f64>,
@@ -62,6 +71,7 @@ pub struct SettingsApp {
     pub(crate) updater: Option<Updater>,
     pub(crate) update_rx: Receiver<UpdateStatus>,
     pub(crate) update_status: UpdateStatus,
+    pub(crate) background_update_check: bool, // The running check is a scheduled one: no popup
 
     // --- NEW FIELDS ---
     pub(crate) current_admin_state: bool, // Track runtime admin status
@@ -83,6 +93,8 @@ pub struct SettingsApp {
     pub(crate) last_edited_preset_idx: Option<usize>,
     // ------------------------
 
+    // --- FOOTER STATE ---
+    pub(crate) footer_rect: eframe::egui::Rect, // Strip reserved for the footer this frame
     // --- USAGE MODAL STATE ---
     pub(crate) show_usage_modal: bool,
     // --- DROP OVERLAY STATE ---
@@ -90,9 +102,23 @@ pub struct SettingsApp {
     // --- TTS SETTINGS MODAL STATE ---
     pub(crate) show_tts_modal: bool,
     // --------------------
+    // --- WHAT'S NEW MODAL STATE ---
+    pub(crate) whats_new: Option<(String, String)>, // (version, release notes)
+    // ------------------------------
 
     // --- FAVORITE BUBBLE STATE TRACKING ---
     pub(crate) last_bubble_enabled: bool,
     pub(crate) last_has_favorites: bool,
+    pub(crate) last_bubbles_separate: bool,
     // --------------------------------------
+
+    // --- WINDOW LAYOUT STATE TRACKING ---
+    pub(crate) last_ui_scale: f32,
+    pub(crate) last_sidebar_layout: String,
+    // ------------------------------------
+
+    // --- DEBOUNCED CONFIG SAVE ---
+    // When the pending disk write fires (None = nothing pending)
+    pub(crate) config_save_due: Option<std::time::Instant>,
+    // -----------------------------
 }
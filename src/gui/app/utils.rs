@@ -1,11 +1,28 @@
-use super::types::{SettingsApp, RESTORE_SIGNAL};
+use super::types::{HotkeyOwner, SettingsApp, RESTORE_SIGNAL};
 use crate::config::save_config;
 use eframe::egui;
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 use windows::core::*;
 use windows::Win32::Foundation::CloseHandle;
 use windows::Win32::System::Threading::*;
 
+/// Coalesce config writes from rapid UI edits (e.g. slider drags) into one per window
+const CONFIG_SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Set while a debounced config write is pending
+static CONFIG_SAVE_PENDING: AtomicBool = AtomicBool::new(false);
+
+/// Write a pending debounced config save right away.
+/// Call before `std::process::exit` so the last edits aren't lost.
+pub fn flush_pending_config_save() {
+    if CONFIG_SAVE_PENDING.swap(false, Ordering::SeqCst) {
+        if let Ok(app) = crate::APP.lock() {
+            save_config(&app.config);
+        }
+    }
+}
+
 // Simple Linear Congruential Generator for randomness without external crate
 pub fn simple_rand(seed: u32) -> u32 {
     seed.wrapping_mul(1103515245).wrapping_add(12345)
@@ -36,11 +53,27 @@ impl SettingsApp {
         state.hotkeys_updated = true;
         state.config = self.config.clone();
         drop(state);
-        save_config(&self.config);
+
+        // The disk write is debounced; in-memory state and hotkeys update right away
+        CONFIG_SAVE_PENDING.store(true, Ordering::SeqCst);
+        self.config_save_due
+            .get_or_insert_with(|| Instant::now() + CONFIG_SAVE_DEBOUNCE);
 
         // Sync PromptDJ settings if window is active
         crate::overlay::prompt_dj::update_settings();
 
+        // Start/stop the HTTP control API if its settings changed
+        crate::http_api::sync_with_config(&self.config);
+
+        // Swap the overlay font if it changed
+        crate::overlay::html_components::font_manager::sync_with_config(&self.config);
+
+        // Rebuild the preset wheel page if its size settings changed
+        crate::overlay::preset_wheel::sync_with_config(&self.config);
+
+        // Apply log verbosity changes immediately
+        crate::logging::set_level(&self.config.log_level);
+
         unsafe {
             let class = w!("HotkeyListenerClass");
             let title = w!("Listener");
@@ -57,6 +90,25 @@ impl SettingsApp {
         }
     }
 
+    /// Write the debounced config once it's due, or immediately when the window
+    /// loses focus or is being closed/hidden
+    pub(crate) fn update_config_save(&mut self, ctx: &egui::Context) {
+        let Some(due) = self.config_save_due else {
+            return;
+        };
+
+        let leaving =
+            ctx.input(|i| i.viewport().close_requested() || i.viewport().focused == Some(false));
+        let now = Instant::now();
+        if leaving || now >= due {
+            self.config_save_due = None;
+            flush_pending_config_save();
+        } else {
+            // Make sure a frame runs when the save is due, even if the UI goes idle
+            ctx.request_repaint_after(due - now);
+        }
+    }
+
     pub(crate) fn restore_window(&self, ctx: &egui::Context) {
         ctx.send_viewport_cmd(egui::ViewportCommand::Visible(true));
         ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
@@ -74,10 +126,13 @@ impl SettingsApp {
         &self,
         vk: u32,
         mods: u32,
-        current_preset_idx: usize,
+        owner: HotkeyOwner,
     ) -> Option<String> {
+        if let Some(what) = crate::config::reserved_shortcut(vk, mods) {
+            return Some(format!("Reserved by Windows ({})", what));
+        }
         for (idx, preset) in self.config.presets.iter().enumerate() {
-            if idx == current_preset_idx {
+            if owner == HotkeyOwner::Preset(idx) {
                 continue;
             }
             for hk in &preset.hotkeys {
@@ -89,6 +144,16 @@ impl SettingsApp {
                 }
             }
         }
+        for which in crate::config::AppHotkey::ALL {
+            if owner == HotkeyOwner::App(which) {
+                continue;
+            }
+            if let Some(hk) = self.config.app_hotkey(which) {
+                if hk.code == vk && hk.modifiers == mods {
+                    return Some(format!("Conflict with '{}' ({})", hk.name, which.name()));
+                }
+            }
+        }
         None
     }
 }
@@ -2,8 +2,10 @@
 // High-fidelity programmatic vector icons for egui.
 // No assets, no fonts, pure math.
 
+use crate::gui::locale::LocaleText;
 use eframe::egui;
 use std::f32::consts::PI;
+use std::sync::Mutex;
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum Icon {
@@ -38,6 +40,47 @@ pub enum Icon {
     History,         // New: History icon (clock)
 }
 
+impl Icon {
+    /// Spoken name for screen readers (icon buttons have no visible text), in the UI language
+    pub fn accessible_name(self, text: &LocaleText) -> &'static str {
+        match self {
+            Icon::Settings => text.icon_settings,
+            Icon::EyeOpen => text.icon_hide_value,
+            Icon::EyeClosed => text.icon_show_value,
+            Icon::Microphone => text.icon_microphone,
+            Icon::Image => text.icon_image,
+            Icon::Text => text.icon_text,
+            Icon::Delete | Icon::DeleteLarge => text.icon_delete,
+            Icon::Folder => text.icon_open_media,
+            Icon::Copy | Icon::CopySmall => text.icon_copy,
+            Icon::Close => text.icon_close,
+            Icon::TextSelect => text.icon_text_selection,
+            Icon::Speaker => text.icon_speak,
+            Icon::SpeakerDisabled => text.icon_speech_disabled,
+            Icon::CopyDisabled => text.icon_auto_copy_disabled,
+            Icon::Lightbulb => text.icon_tips,
+            Icon::Realtime => text.icon_realtime,
+            Icon::Star => text.icon_add_favorite,
+            Icon::StarFilled => text.icon_remove_favorite,
+            Icon::Sun => text.icon_theme_light,
+            Icon::Moon => text.icon_theme_dark,
+            Icon::Device => text.icon_theme_system,
+            Icon::DragHandle => text.icon_drag_to_reorder,
+            Icon::History => text.icon_history,
+        }
+    }
+}
+
+/// Language of the spoken icon names, kept in step with `Config::ui_language`
+static UI_LANGUAGE: Mutex<String> = Mutex::new(String::new());
+
+/// Update the language icon buttons are announced in
+pub fn set_ui_language(lang: &str) {
+    if let Ok(mut current) = UI_LANGUAGE.lock() {
+        *current = lang.to_string();
+    }
+}
+
 /// Main entry point: Draw a clickable icon button (default size 24.0)
 pub fn icon_button(ui: &mut egui::Ui, icon: Icon) -> egui::Response {
     icon_button_sized(ui, icon, 24.0)
@@ -64,6 +107,18 @@ pub fn icon_button_sized(ui: &mut egui::Ui, icon: Icon, size_val: f32) -> egui::
     // 3. Paint
     paint_internal(ui.painter(), rect, icon, color);
 
+    // 4. Accessibility: expose a name so screen readers don't announce "button"
+    let enabled = ui.is_enabled();
+    response.widget_info(|| {
+        let lang = UI_LANGUAGE.lock().map(|l| l.clone()).unwrap_or_default();
+        let text = LocaleText::get(&lang);
+        egui::WidgetInfo::labeled(
+            egui::WidgetType::Button,
+            enabled,
+            icon.accessible_name(&text),
+        )
+    });
+
     response
 }
 
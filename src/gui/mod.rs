@@ -1,13 +1,14 @@
 mod app;
 pub mod icons;
 mod key_mapping;
-pub mod locale;
 pub mod settings_ui;
 pub mod splash;
 pub mod utils;
 
+pub use app::flush_pending_config_save;
 pub use app::signal_restore_window;
 pub use app::SettingsApp;
+pub use screen_goated_toolbox::locale;
 pub use utils::configure_fonts;
 
 lazy_static::lazy_static! {
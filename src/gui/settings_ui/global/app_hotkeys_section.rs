@@ -0,0 +1,102 @@
+use crate::config::{AppHotkey, Config};
+use crate::gui::locale::LocaleText;
+use eframe::egui;
+
+fn label(which: AppHotkey, text: &LocaleText) -> &'static str {
+    match which {
+        AppHotkey::OpenSettings => text.app_hotkey_open_settings,
+        AppHotkey::DismissOverlays => text.app_hotkey_dismiss_overlays,
+        AppHotkey::ToggleFavoriteBubble => text.app_hotkey_toggle_bubble,
+        AppHotkey::RepeatLastPreset => text.app_hotkey_repeat_last,
+        AppHotkey::TranslateClipboard => text.app_hotkey_translate_clipboard,
+        AppHotkey::TranslateSelection => text.app_hotkey_translate_selection,
+        AppHotkey::UndoReplace => text.app_hotkey_undo_replace,
+    }
+}
+
+/// One row per app-wide hotkey: its binding (click to clear) or a button to record one.
+/// Recording itself happens in `SettingsApp::update_hotkey_recording`.
+pub fn render_app_hotkeys_section_content(
+    ui: &mut egui::Ui,
+    config: &mut Config,
+    recording: &mut Option<AppHotkey>,
+    conflict_msg: &Option<String>,
+    text: &LocaleText,
+) -> bool {
+    let mut changed = false;
+    let is_dark = ui.visuals().dark_mode;
+    let hotkey_bg = if is_dark {
+        egui::Color32::from_rgb(90, 70, 130)
+    } else {
+        egui::Color32::from_rgb(170, 150, 200)
+    };
+
+    egui::Grid::new("app_hotkeys_grid")
+        .num_columns(2)
+        .spacing([12.0, 6.0])
+        .show(ui, |ui| {
+            for which in AppHotkey::ALL {
+                ui.label(label(which, text));
+                ui.horizontal(|ui| {
+                    if *recording == Some(which) {
+                        let color = if is_dark {
+                            egui::Color32::from_rgb(255, 200, 60)
+                        } else {
+                            egui::Color32::from_rgb(200, 130, 0)
+                        };
+                        ui.colored_label(color, text.press_keys);
+                        if ui.button(text.cancel_label).clicked() {
+                            *recording = None;
+                        }
+                    } else if let Some(hotkey) = config.app_hotkey(which) {
+                        let unavailable = crate::is_hotkey_unavailable(hotkey);
+                        let (label, fill) = if unavailable {
+                            (
+                                format!("⚠ {} ×", hotkey.name),
+                                egui::Color32::from_rgb(170, 90, 40),
+                            )
+                        } else {
+                            (format!("{} ×", hotkey.name), hotkey_bg)
+                        };
+                        let mut response = ui
+                            .add(
+                                egui::Button::new(
+                                    egui::RichText::new(label)
+                                        .color(egui::Color32::WHITE)
+                                        .small(),
+                                )
+                                .fill(fill)
+                                .corner_radius(10.0),
+                            )
+                            .on_hover_cursor(egui::CursorIcon::PointingHand);
+                        if unavailable {
+                            response = response.on_hover_text(text.hotkey_unavailable_tooltip);
+                        }
+                        if response.clicked() {
+                            *config.app_hotkey_mut(which) = None;
+                            changed = true;
+                        }
+                    } else if ui.button(text.add_hotkey_button).clicked() {
+                        *recording = Some(which);
+                    }
+                });
+                ui.end_row();
+            }
+        });
+
+    if recording.is_some() {
+        if let Some(msg) = conflict_msg {
+            ui.colored_label(egui::Color32::RED, msg);
+        }
+    }
+
+    ui.add_space(4.0);
+    if ui
+        .checkbox(&mut config.hotkey_feedback, text.hotkey_feedback_checkbox)
+        .on_hover_text(text.hotkey_feedback_tooltip)
+        .changed()
+    {
+        changed = true;
+    }
+    changed
+}
@@ -0,0 +1,101 @@
+use crate::backup::{apply_backup, export_backup, read_backup, BACKUP_EXTENSION};
+use crate::config::Config;
+use crate::gui::locale::LocaleText;
+use crate::gui::settings_ui::node_graph::request_node_graph_view_reset;
+use crate::gui::utils::pick_file_path;
+use eframe::egui;
+
+/// Result of the last export/import: (message, is_error)
+type BackupStatus = Option<(String, bool)>;
+
+pub fn render_backup_section_content(
+    ui: &mut egui::Ui,
+    config: &mut Config,
+    text: &LocaleText,
+) -> bool {
+    let mut changed = false;
+
+    let include_keys_id = egui::Id::new("backup_include_api_keys");
+    let status_id = egui::Id::new("backup_status");
+    let mut include_api_keys = ui
+        .ctx()
+        .data(|d| d.get_temp::<bool>(include_keys_id))
+        .unwrap_or(false);
+    let mut status: BackupStatus = ui.ctx().data(|d| d.get_temp(status_id)).flatten();
+
+    ui.horizontal(|ui| {
+        if ui.button(text.backup_export_btn).clicked() {
+            let default_name = format!(
+                "sgt-{}.{}",
+                chrono::Local::now().format("%Y%m%d"),
+                BACKUP_EXTENSION
+            );
+            if let Some(path) =
+                pick_file_path(true, text.backup_file_type, BACKUP_EXTENSION, &default_name)
+            {
+                let history = crate::APP
+                    .lock()
+                    .map(|app| app.history.items.lock().unwrap().clone())
+                    .unwrap_or_default();
+                status = Some(
+                    match export_backup(&path, config, &history, !include_api_keys) {
+                        Ok(()) => (text.backup_export_done.to_string(), false),
+                        Err(e) => (format!("{} {}", text.backup_failed, e), true),
+                    },
+                );
+            }
+        }
+
+        if ui.button(text.backup_import_btn).clicked() {
+            if let Some(path) = pick_file_path(false, text.backup_file_type, BACKUP_EXTENSION, "") {
+                let result = read_backup(&path).and_then(|bundle| {
+                    let use_bundle_keys =
+                        !bundle.overwrites_api_keys(config) || confirm_overwrite_api_keys(text);
+                    apply_backup(bundle, config, use_bundle_keys)
+                });
+                status = Some(match result {
+                    Ok(imported) => {
+                        *config = imported;
+                        request_node_graph_view_reset(ui.ctx());
+                        changed = true;
+                        (text.backup_import_done.to_string(), false)
+                    }
+                    Err(e) => (format!("{} {}", text.backup_failed, e), true),
+                });
+            }
+        }
+
+        ui.checkbox(&mut include_api_keys, text.backup_include_api_keys);
+    });
+
+    if let Some((message, is_error)) = &status {
+        let color = if *is_error {
+            egui::Color32::from_rgb(200, 100, 50)
+        } else {
+            egui::Color32::from_rgb(34, 139, 34)
+        };
+        ui.label(egui::RichText::new(message).size(11.0).color(color));
+    }
+
+    ui.ctx().data_mut(|d| {
+        d.insert_temp(include_keys_id, include_api_keys);
+        d.insert_temp(status_id, status);
+    });
+
+    changed
+}
+
+/// Native Yes/No prompt: replace the API keys currently configured?
+fn confirm_overwrite_api_keys(text: &LocaleText) -> bool {
+    use windows::core::HSTRING;
+    use windows::Win32::UI::WindowsAndMessaging::{MessageBoxW, IDYES, MB_ICONQUESTION, MB_YESNO};
+
+    unsafe {
+        MessageBoxW(
+            None,
+            &HSTRING::from(text.backup_overwrite_keys_msg),
+            &HSTRING::from(text.backup_import_btn),
+            MB_YESNO | MB_ICONQUESTION,
+        ) == IDYES
+    }
+}
@@ -0,0 +1,78 @@
+use crate::config::Config;
+use crate::gui::locale::LocaleText;
+use crate::overlay::html_components::font_manager::{
+    font_covers_language, script_for_language, FONT_CHOICES, SYSTEM_FONT,
+};
+use eframe::egui;
+
+/// A target language (live translate, or any preset block) the chosen font can't show
+fn uncovered_language(config: &Config) -> Option<String> {
+    let family = if config.overlay_font_family.is_empty() {
+        FONT_CHOICES[0].family
+    } else {
+        config.overlay_font_family.as_str()
+    };
+    std::iter::once(config.realtime_target_language.as_str())
+        .chain(
+            config
+                .presets
+                .iter()
+                .flat_map(|p| p.blocks.iter())
+                .map(|b| b.selected_language.as_str()),
+        )
+        .filter(|lang| script_for_language(lang).is_some())
+        .find(|lang| !font_covers_language(family, lang))
+        .map(str::to_string)
+}
+
+/// Font combo for the overlay windows, with a warning when it lacks a needed script
+pub fn render_overlay_font_picker(
+    ui: &mut egui::Ui,
+    config: &mut Config,
+    text: &LocaleText,
+) -> bool {
+    let mut changed = false;
+    let label_for = |family: &str| -> String {
+        match FONT_CHOICES.iter().find(|c| c.family == family) {
+            Some(c) if c.family == SYSTEM_FONT => text.overlay_font_system.to_string(),
+            Some(c) => c.label.to_string(),
+            None if family.is_empty() => FONT_CHOICES[0].label.to_string(),
+            None => family.to_string(),
+        }
+    };
+
+    ui.horizontal(|ui| {
+        let font_label = ui.label(text.overlay_font_label);
+        egui::ComboBox::from_id_salt("overlay_font_combo")
+            .selected_text(label_for(&config.overlay_font_family))
+            .show_ui(ui, |ui| {
+                for (i, choice) in FONT_CHOICES.iter().enumerate() {
+                    // The bundled font (first entry) is stored as an empty string
+                    let value = if i == 0 { "" } else { choice.family };
+                    if ui
+                        .selectable_label(
+                            config.overlay_font_family == value,
+                            label_for(choice.family),
+                        )
+                        .clicked()
+                        && config.overlay_font_family != value
+                    {
+                        config.overlay_font_family = value.to_string();
+                        changed = true;
+                    }
+                }
+            })
+            .response
+            .labelled_by(font_label.id);
+    });
+
+    if let Some(language) = uncovered_language(config) {
+        ui.label(
+            egui::RichText::new(text.overlay_font_script_warning.replace("{}", &language))
+                .small()
+                .color(egui::Color32::from_rgb(230, 160, 60)),
+        );
+    }
+
+    changed
+}
@@ -0,0 +1,63 @@
+use crate::config::Config;
+use crate::gui::locale::LocaleText;
+use crate::http_api::{generate_token, TOKEN_HEADER};
+use eframe::egui;
+
+pub fn render_http_api_section_content(
+    ui: &mut egui::Ui,
+    config: &mut Config,
+    text: &LocaleText,
+) -> bool {
+    let mut changed = false;
+
+    if ui
+        .checkbox(&mut config.http_api_enabled, text.http_api_enable)
+        .clicked()
+    {
+        // First enable: make sure there is a token to hand out
+        if config.http_api_enabled && config.http_api_token.is_empty() {
+            config.http_api_token = generate_token();
+        }
+        changed = true;
+    }
+
+    if !config.http_api_enabled {
+        return changed;
+    }
+
+    ui.indent("http_api_indent", |ui| {
+        ui.horizontal(|ui| {
+            let port_label = ui.label(text.http_api_port_label);
+            if ui
+                .add(egui::DragValue::new(&mut config.http_api_port).range(1024..=65535))
+                .labelled_by(port_label.id)
+                .changed()
+            {
+                changed = true;
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label(text.http_api_token_label);
+            ui.label(egui::RichText::new(&config.http_api_token).monospace());
+            if ui.button(text.http_api_copy_btn).clicked() {
+                ui.ctx().copy_text(config.http_api_token.clone());
+            }
+            if ui.button(text.http_api_regenerate_btn).clicked() {
+                config.http_api_token = generate_token();
+                changed = true;
+            }
+        });
+
+        ui.label(
+            egui::RichText::new(format!(
+                "{}\nGET /presets · POST /preset/{{id}}/run · POST /translate ({}: …)",
+                text.http_api_hint, TOKEN_HEADER
+            ))
+            .size(11.0)
+            .color(egui::Color32::GRAY),
+        );
+    });
+
+    changed
+}
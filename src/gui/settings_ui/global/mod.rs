@@ -1,5 +1,5 @@
 use super::node_graph::request_node_graph_view_reset;
-use crate::config::Config;
+use crate::config::{AppHotkey, Config, WebViewLifecycle, UI_SCALE_RANGE};
 use crate::gui::icons::{icon_button, Icon};
 use crate::gui::locale::LocaleText;
 use crate::updater::{UpdateStatus, Updater};
@@ -7,16 +7,82 @@ use auto_launch::AutoLaunch;
 use eframe::egui;
 use std::collections::HashMap;
 
+mod app_hotkeys_section;
+mod backup_section;
+mod font_section;
+mod http_api_section;
+mod ollama_section;
+mod quick_translate_section;
+mod selection_overlay_section;
 mod tts_settings;
 mod update_section;
+mod usage_charts;
 mod usage_stats;
 
+use app_hotkeys_section::render_app_hotkeys_section_content;
+use backup_section::render_backup_section_content;
+use font_section::render_overlay_font_picker;
+use http_api_section::render_http_api_section_content;
+use ollama_section::render_ollama_model_picker;
+use quick_translate_section::render_quick_translate_section_content;
+use selection_overlay_section::render_selection_overlay_settings;
 use tts_settings::render_tts_settings_modal;
 use update_section::render_update_section_content;
 use usage_stats::render_usage_modal;
 
 const API_KEY_FIELD_WIDTH: f32 = 400.0;
 
+/// Providers with an editable request timeout (`Config::request_timeout_secs`)
+const TIMEOUT_PROVIDERS: [(&str, &str); 5] = [
+    ("groq", "Groq"),
+    ("cerebras", "Cerebras"),
+    ("google", "Google Gemini"),
+    ("openrouter", "OpenRouter"),
+    ("ollama", "Ollama"),
+];
+
+/// "Test" button for a provider's key, followed by the last result for that key
+fn render_key_test(ui: &mut egui::Ui, provider: &'static str, key: &str, text: &LocaleText) {
+    use crate::api::validate;
+
+    if validate::is_checking(provider) {
+        ui.spinner();
+        return;
+    }
+    if ui
+        .add_enabled(
+            !key.trim().is_empty(),
+            egui::Button::new(text.api_key_test_button),
+        )
+        .on_hover_text(text.api_key_test_tooltip)
+        .clicked()
+    {
+        let ctx = ui.ctx().clone();
+        validate::validate_key_in_background(provider, key.to_string(), move || {
+            ctx.request_repaint()
+        });
+    }
+
+    if let Some(check) = validate::last_check(provider, key) {
+        let time = check.checked_at.format("%H:%M").to_string();
+        match check.result {
+            Ok(()) => {
+                ui.label(egui::RichText::new("✔").color(egui::Color32::from_rgb(60, 180, 90)))
+                    .on_hover_text(text.api_key_valid.replace("{}", &time));
+            }
+            Err(e) => {
+                ui.label(egui::RichText::new("✖").color(egui::Color32::from_rgb(220, 80, 80)))
+                    .on_hover_text(text.api_key_invalid.replace("{}", &time));
+                ui.label(
+                    egui::RichText::new(e)
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(220, 80, 80)),
+                );
+            }
+        }
+    }
+}
+
 pub fn render_global_settings(
     ui: &mut egui::Ui,
     config: &mut Config,
@@ -34,6 +100,8 @@ pub fn render_global_settings(
     show_usage_modal: &mut bool,
     show_tts_modal: &mut bool,
     _cached_audio_devices: &std::sync::Arc<std::sync::Mutex<Vec<(String, String)>>>,
+    recording_app_hotkey: &mut Option<AppHotkey>,
+    hotkey_conflict_msg: &Option<String>,
 ) -> bool {
     let mut changed = false;
 
@@ -99,8 +167,9 @@ pub fn render_global_settings(
 
             // Groq API Key (only show if enabled)
             if config.use_groq {
+                let mut key_label_id = egui::Id::NULL;
                 ui.horizontal(|ui| {
-                    ui.label(text.groq_label);
+                    key_label_id = ui.label(text.groq_label).id;
                     if ui.link(text.get_key_link).clicked() {
                         let _ = open::that("https://console.groq.com/keys");
                     }
@@ -113,6 +182,7 @@ pub fn render_global_settings(
                                 .password(!*show_api_key)
                                 .desired_width(API_KEY_FIELD_WIDTH),
                         )
+                        .labelled_by(key_label_id)
                         .changed()
                     {
                         changed = true;
@@ -125,13 +195,15 @@ pub fn render_global_settings(
                     if icon_button(ui, eye_icon).clicked() {
                         *show_api_key = !*show_api_key;
                     }
+                    render_key_test(ui, "groq", &config.api_key, text);
                 });
             }
 
             // Cerebras API Key (only show if enabled)
             if config.use_cerebras {
+                let mut key_label_id = egui::Id::NULL;
                 ui.horizontal(|ui| {
-                    ui.label(text.cerebras_api_key_label);
+                    key_label_id = ui.label(text.cerebras_api_key_label).id;
                     if ui.link(text.cerebras_get_key_link).clicked() {
                         let _ = open::that("https://cloud.cerebras.ai/");
                     }
@@ -144,6 +216,7 @@ pub fn render_global_settings(
                                 .password(!*show_cerebras_api_key)
                                 .desired_width(API_KEY_FIELD_WIDTH),
                         )
+                        .labelled_by(key_label_id)
                         .changed()
                     {
                         changed = true;
@@ -156,13 +229,15 @@ pub fn render_global_settings(
                     if icon_button(ui, eye_icon).clicked() {
                         *show_cerebras_api_key = !*show_cerebras_api_key;
                     }
+                    render_key_test(ui, "cerebras", &config.cerebras_api_key, text);
                 });
             }
 
             // Gemini API Key (only show if enabled)
             if config.use_gemini {
+                let mut key_label_id = egui::Id::NULL;
                 ui.horizontal(|ui| {
-                    ui.label(text.gemini_api_key_label);
+                    key_label_id = ui.label(text.gemini_api_key_label).id;
                     if ui.link(text.gemini_get_key_link).clicked() {
                         let _ = open::that("https://aistudio.google.com/app/apikey");
                     }
@@ -175,6 +250,7 @@ pub fn render_global_settings(
                                 .password(!*show_gemini_api_key)
                                 .desired_width(API_KEY_FIELD_WIDTH),
                         )
+                        .labelled_by(key_label_id)
                         .changed()
                     {
                         changed = true;
@@ -187,13 +263,15 @@ pub fn render_global_settings(
                     if icon_button(ui, eye_icon).clicked() {
                         *show_gemini_api_key = !*show_gemini_api_key;
                     }
+                    render_key_test(ui, "google", &config.gemini_api_key, text);
                 });
             }
 
             // OpenRouter API Key (only show if enabled)
             if config.use_openrouter {
+                let mut key_label_id = egui::Id::NULL;
                 ui.horizontal(|ui| {
-                    ui.label(text.openrouter_api_key_label);
+                    key_label_id = ui.label(text.openrouter_api_key_label).id;
                     if ui.link(text.openrouter_get_key_link).clicked() {
                         let _ = open::that("https://openrouter.ai/settings/keys");
                     }
@@ -206,6 +284,7 @@ pub fn render_global_settings(
                                 .password(!*show_openrouter_api_key)
                                 .desired_width(API_KEY_FIELD_WIDTH),
                         )
+                        .labelled_by(key_label_id)
                         .changed()
                     {
                         changed = true;
@@ -218,13 +297,15 @@ pub fn render_global_settings(
                     if icon_button(ui, eye_icon).clicked() {
                         *show_openrouter_api_key = !*show_openrouter_api_key;
                     }
+                    render_key_test(ui, "openrouter", &config.openrouter_api_key, text);
                 });
             }
 
             // Ollama (Local AI) - only show URL field if enabled
             if config.use_ollama {
+                let mut url_label_id = egui::Id::NULL;
                 ui.horizontal(|ui| {
-                    ui.label("Ollama URL:");
+                    url_label_id = ui.label("Ollama URL:").id;
                     if ui.link(text.ollama_url_guide).clicked() {
                         let _ = open::that("https://docs.ollama.com/api/introduction#base-url");
                     }
@@ -236,6 +317,7 @@ pub fn render_global_settings(
                                 .id(egui::Id::new("settings_api_key_ollama_url"))
                                 .desired_width(API_KEY_FIELD_WIDTH),
                         )
+                        .labelled_by(url_label_id)
                         .changed()
                     {
                         changed = true;
@@ -248,7 +330,111 @@ pub fn render_global_settings(
                         ui.label(egui::RichText::new(&status).size(11.0));
                     }
                 });
+                if render_ollama_model_picker(ui, config, text) {
+                    changed = true;
+                }
+            }
+
+            ui.add_space(6.0);
+            egui::CollapsingHeader::new(text.request_timeouts_label)
+                .id_salt("request_timeouts")
+                .show(ui, |ui| {
+                    ui.label(egui::RichText::new(text.request_timeouts_hint).size(11.0));
+                    egui::Grid::new("request_timeouts_grid")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            for (provider, name) in TIMEOUT_PROVIDERS {
+                                let label = ui.label(name);
+                                let mut secs =
+                                    crate::api::client::request_timeout(config, provider).as_secs();
+                                if ui
+                                    .add(
+                                        egui::DragValue::new(&mut secs).range(1..=900).suffix(" s"),
+                                    )
+                                    .labelled_by(label.id)
+                                    .changed()
+                                {
+                                    config
+                                        .request_timeout_secs
+                                        .insert(provider.to_string(), secs);
+                                    changed = true;
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+
+            ui.add_space(6.0);
+            if ui
+                .checkbox(&mut config.encrypt_api_keys, text.encrypt_api_keys_checkbox)
+                .on_hover_text(text.encrypt_api_keys_tooltip)
+                .changed()
+            {
+                changed = true;
+            }
+            if ui
+                .checkbox(&mut config.privacy_scan_enabled, text.privacy_scan_label)
+                .on_hover_text(text.privacy_scan_tooltip)
+                .changed()
+            {
+                changed = true;
+            }
+            if config.privacy_scan_enabled {
+                ui.horizontal(|ui| {
+                    ui.label(text.privacy_patterns_label);
+                    if ui.small_button(text.privacy_reset_patterns_btn).clicked() {
+                        config.privacy_patterns = crate::privacy::default_patterns();
+                        changed = true;
+                    }
+                });
+                // Edited as one line per pattern; blank lines are ignored by the check
+                let mut patterns = config.privacy_patterns.join("\n");
+                if ui
+                    .add(
+                        egui::TextEdit::multiline(&mut patterns)
+                            .code_editor()
+                            .desired_rows(3)
+                            .desired_width(f32::INFINITY),
+                    )
+                    .changed()
+                {
+                    config.privacy_patterns = patterns.split('\n').map(str::to_string).collect();
+                    changed = true;
+                }
+            }
+
+            // Clipboard retention (applied by overlay::utils on every text copy)
+            if ui
+                .checkbox(
+                    &mut config.clear_clipboard_on_exit,
+                    text.clear_clipboard_on_exit_label,
+                )
+                .on_hover_text(text.clear_clipboard_on_exit_tooltip)
+                .changed()
+            {
+                changed = true;
             }
+            ui.horizontal(|ui| {
+                let clear_label = ui
+                    .label(text.clipboard_clear_after_label)
+                    .on_hover_text(text.clipboard_clear_after_tooltip);
+                if ui
+                    .add(
+                        egui::Slider::new(&mut config.clipboard_clear_after_secs, 0..=300)
+                            .custom_formatter(|v, _| {
+                                if v == 0.0 {
+                                    text.clipboard_keep_label.to_string()
+                                } else {
+                                    format!("{} s", v)
+                                }
+                            }),
+                    )
+                    .labelled_by(clear_label.id)
+                    .changed()
+                {
+                    changed = true;
+                }
+            });
         });
 
     ui.add_space(10.0);
@@ -315,6 +501,8 @@ pub fn render_global_settings(
         config.use_openrouter,
         config.use_ollama,
         config.use_cerebras,
+        &config.presets,
+        &config.ui_language,
     );
 
     // === TTS SETTINGS MODAL ===
@@ -337,7 +525,9 @@ pub fn render_global_settings(
                     .size(14.0),
             );
             ui.add_space(6.0);
-            render_update_section_content(ui, updater, update_status, text);
+            if render_update_section_content(ui, config, updater, update_status, text) {
+                changed = true;
+            }
         });
 
     ui.add_space(10.0);
@@ -449,11 +639,263 @@ pub fn render_global_settings(
                 }
             }
 
+            if ui
+                .checkbox(&mut config.disable_warmups, text.disable_warmups_label)
+                .on_hover_text(text.disable_warmups_tooltip)
+                .clicked()
+            {
+                changed = true;
+            }
+
             ui.add_space(8.0);
 
+            // UI Scale (applied by the app once the slider is released)
+            ui.horizontal(|ui| {
+                let scale_label = ui.label(text.ui_scale_label);
+                if ui
+                    .add(
+                        egui::Slider::new(&mut config.ui_scale, UI_SCALE_RANGE)
+                            .step_by(0.05)
+                            .custom_formatter(|v, _| format!("{:.0}%", v * 100.0)),
+                    )
+                    .labelled_by(scale_label.id)
+                    .changed()
+                {
+                    changed = true;
+                }
+            });
+
+            ui.add_space(4.0);
+
+            // Overlay font (realtime, result and PromptDJ windows)
+            changed |= render_overlay_font_picker(ui, config, text);
+
+            ui.add_space(4.0);
+
+            // Region selection overlay: dimming and border color
+            changed |= render_selection_overlay_settings(ui, config, text);
+
+            ui.add_space(4.0);
+
+            // Sidebar Layout (the window width follows the layout)
+            ui.horizontal(|ui| {
+                let layout_label = ui.label(text.sidebar_layout_label);
+                let current_label = if config.sidebar_layout == "compact" {
+                    text.sidebar_layout_compact
+                } else {
+                    text.sidebar_layout_columns
+                };
+                egui::ComboBox::from_id_salt("sidebar_layout_combo")
+                    .selected_text(current_label)
+                    .show_ui(ui, |ui| {
+                        if ui
+                            .selectable_label(
+                                config.sidebar_layout != "compact",
+                                text.sidebar_layout_columns,
+                            )
+                            .clicked()
+                        {
+                            config.sidebar_layout = "columns".to_string();
+                            changed = true;
+                        }
+                        if ui
+                            .selectable_label(
+                                config.sidebar_layout == "compact",
+                                text.sidebar_layout_compact,
+                            )
+                            .clicked()
+                        {
+                            config.sidebar_layout = "compact".to_string();
+                            changed = true;
+                        }
+                    })
+                    .response
+                    .labelled_by(layout_label.id);
+            });
+
+            ui.add_space(4.0);
+
+            // Preset Wheel (the wheel page is rebuilt when the sizes change)
+            ui.horizontal(|ui| {
+                ui.label(text.wheel_settings_label);
+                let size_label = ui.label(text.wheel_item_size_label);
+                if ui
+                    .add(egui::Slider::new(&mut config.wheel_item_size, 10..=28).suffix("px"))
+                    .labelled_by(size_label.id)
+                    .changed()
+                {
+                    changed = true;
+                }
+                let radius_label = ui.label(text.wheel_radius_label);
+                if ui
+                    .add(egui::Slider::new(&mut config.wheel_radius, 40..=200).suffix("px"))
+                    .labelled_by(radius_label.id)
+                    .changed()
+                {
+                    changed = true;
+                }
+            });
+            if ui
+                .checkbox(
+                    &mut config.wheel_release_to_select,
+                    text.wheel_release_to_select_label,
+                )
+                .on_hover_text(text.wheel_release_to_select_tooltip)
+                .changed()
+            {
+                changed = true;
+            }
+            if ui
+                .checkbox(
+                    &mut config.selection_adjust_before_confirm,
+                    text.selection_adjust_label,
+                )
+                .on_hover_text(text.selection_adjust_tooltip)
+                .changed()
+            {
+                changed = true;
+            }
+
+            // Favorite bubble style (applied by the app's bubble sync)
+            if ui
+                .checkbox(
+                    &mut config.favorite_bubbles_separate,
+                    text.favorite_bubbles_separate_label,
+                )
+                .on_hover_text(text.favorite_bubbles_separate_tooltip)
+                .changed()
+            {
+                changed = true;
+            }
+
+            ui.add_space(4.0);
+
+            // Log Level (sgt.log in the app data dir)
+            ui.horizontal(|ui| {
+                let log_label = ui.label(text.log_level_label);
+                let levels = [
+                    ("off", text.log_level_off),
+                    ("error", text.log_level_error),
+                    ("info", text.log_level_info),
+                    ("debug", text.log_level_debug),
+                ];
+                let current_label = levels
+                    .iter()
+                    .find(|(value, _)| *value == config.log_level)
+                    .map(|(_, label)| *label)
+                    .unwrap_or(text.log_level_error);
+                egui::ComboBox::from_id_salt("log_level_combo")
+                    .selected_text(current_label)
+                    .show_ui(ui, |ui| {
+                        for (value, label) in levels {
+                            if ui
+                                .selectable_label(config.log_level == value, label)
+                                .clicked()
+                            {
+                                config.log_level = value.to_string();
+                                changed = true;
+                            }
+                        }
+                    })
+                    .response
+                    .labelled_by(log_label.id);
+
+                if ui.button(text.open_log_folder_btn).clicked() {
+                    if let Some(dir) = crate::logging::log_path().parent() {
+                        let _ = open::that(dir);
+                    }
+                }
+            });
+
+            ui.add_space(4.0);
+
+            // WebView lifecycle (read by the overlays on hide) + WebView memory on demand
+            ui.horizontal(|ui| {
+                let lifecycle_label = ui
+                    .label(text.webview_lifecycle_label)
+                    .on_hover_text(text.webview_lifecycle_tooltip);
+                let modes = [
+                    (
+                        WebViewLifecycle::Persistent,
+                        text.webview_lifecycle_persistent,
+                    ),
+                    (WebViewLifecycle::Lazy, text.webview_lifecycle_lazy),
+                ];
+                let current_label = modes
+                    .iter()
+                    .find(|(value, _)| *value == config.webview_lifecycle)
+                    .map(|(_, label)| *label)
+                    .unwrap_or(text.webview_lifecycle_persistent);
+                egui::ComboBox::from_id_salt("webview_lifecycle_combo")
+                    .selected_text(current_label)
+                    .show_ui(ui, |ui| {
+                        for (value, label) in modes {
+                            if ui
+                                .selectable_label(config.webview_lifecycle == value, label)
+                                .clicked()
+                            {
+                                config.webview_lifecycle = value;
+                                changed = true;
+                            }
+                        }
+                    })
+                    .response
+                    .labelled_by(lifecycle_label.id);
+
+                let memory_id = egui::Id::new("webview_memory_measurement");
+                if ui.button(text.webview_memory_btn).clicked() {
+                    if let Some(memory) = crate::overlay::webview_lifecycle::measure_memory() {
+                        ui.data_mut(|d| {
+                            d.insert_temp(memory_id, (memory.working_set_bytes, memory.processes))
+                        });
+                    }
+                }
+                if let Some((bytes, processes)) = ui.data(|d| d.get_temp::<(u64, usize)>(memory_id))
+                {
+                    let megabytes = (bytes / (1024 * 1024)).to_string();
+                    ui.label(
+                        text.webview_memory_value
+                            .replacen("{}", &megabytes, 1)
+                            .replacen("{}", &processes.to_string(), 1),
+                    );
+                }
+            });
+
+            // Diagnostics: overlay windows, workers, hooks and memory (collected on demand)
+            egui::CollapsingHeader::new(text.diagnostics_header)
+                .id_salt("diagnostics_section")
+                .show(ui, |ui| {
+                    let report_id = egui::Id::new("diagnostics_report");
+                    let mut report: Option<String> = ui.data(|d| d.get_temp(report_id));
+                    ui.horizontal(|ui| {
+                        if ui.button(text.diagnostics_refresh_btn).clicked() || report.is_none() {
+                            let fresh = crate::overlay::diagnostics::snapshot().to_report();
+                            ui.data_mut(|d| d.insert_temp(report_id, fresh.clone()));
+                            report = Some(fresh);
+                        }
+                        if ui.button(text.diagnostics_copy_btn).clicked() {
+                            if let Some(report) = &report {
+                                ui.ctx().copy_text(report.clone());
+                            }
+                        }
+                        if ui
+                            .button(text.diagnostics_dpi_test_btn)
+                            .on_hover_text(text.diagnostics_dpi_test_tooltip)
+                            .clicked()
+                        {
+                            crate::overlay::dpi_calibration::show_dpi_calibration();
+                        }
+                    });
+                    if let Some(report) = &report {
+                        ui.label(egui::RichText::new(report).monospace().size(11.0));
+                    }
+                });
+
+            ui.add_space(4.0);
+
             // Graphics Mode + Reset button on same row
             ui.horizontal(|ui| {
-                ui.label(text.graphics_mode_label);
+                let graphics_label = ui.label(text.graphics_mode_label);
 
                 let current_label = match config.ui_language.as_str() {
                     "vi" => {
@@ -502,7 +944,9 @@ pub fn render_global_settings(
                             config.graphics_mode = "minimal".to_string();
                             changed = true;
                         }
-                    });
+                    })
+                    .response
+                    .labelled_by(graphics_label.id);
 
                 // Big gap to simulate right alignment
                 ui.add_space(80.0);
@@ -564,5 +1008,132 @@ pub fn render_global_settings(
             });
         });
 
+    ui.add_space(10.0);
+
+    // === GLOBAL PROMPT CARD ===
+    egui::Frame::new()
+        .fill(card_bg)
+        .stroke(card_stroke)
+        .inner_margin(12.0)
+        .corner_radius(10.0)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(text.global_prompt_header)
+                    .strong()
+                    .size(14.0),
+            );
+            ui.label(
+                egui::RichText::new(text.global_prompt_hint)
+                    .size(11.0)
+                    .weak(),
+            );
+            ui.add_space(6.0);
+            for (label, value) in [
+                (
+                    text.global_prompt_prefix_label,
+                    &mut config.global_system_prefix,
+                ),
+                (
+                    text.global_prompt_suffix_label,
+                    &mut config.global_system_suffix,
+                ),
+            ] {
+                let label = ui.label(label);
+                if ui
+                    .add(
+                        egui::TextEdit::multiline(value)
+                            .desired_rows(2)
+                            .desired_width(f32::INFINITY),
+                    )
+                    .labelled_by(label.id)
+                    .changed()
+                {
+                    changed = true;
+                }
+            }
+        });
+
+    ui.add_space(10.0);
+
+    // === APP HOTKEYS CARD ===
+    egui::Frame::new()
+        .fill(card_bg)
+        .stroke(card_stroke)
+        .inner_margin(12.0)
+        .corner_radius(10.0)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(text.app_hotkeys_header)
+                    .strong()
+                    .size(14.0),
+            );
+            ui.add_space(6.0);
+            if render_app_hotkeys_section_content(
+                ui,
+                config,
+                recording_app_hotkey,
+                hotkey_conflict_msg,
+                text,
+            ) {
+                changed = true;
+            }
+        });
+
+    ui.add_space(10.0);
+
+    // === QUICK TRANSLATE CARD ===
+    egui::Frame::new()
+        .fill(card_bg)
+        .stroke(card_stroke)
+        .inner_margin(12.0)
+        .corner_radius(10.0)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(text.quick_translate_header)
+                    .strong()
+                    .size(14.0),
+            );
+            ui.add_space(6.0);
+            if render_quick_translate_section_content(ui, config, text) {
+                changed = true;
+            }
+        });
+
+    ui.add_space(10.0);
+
+    // === BACKUP CARD ===
+    egui::Frame::new()
+        .fill(card_bg)
+        .stroke(card_stroke)
+        .inner_margin(12.0)
+        .corner_radius(10.0)
+        .show(ui, |ui| {
+            ui.label(egui::RichText::new(text.backup_header).strong().size(14.0));
+            ui.add_space(6.0);
+            if render_backup_section_content(ui, config, text) {
+                changed = true;
+            }
+        });
+
+    ui.add_space(10.0);
+
+    // === HTTP CONTROL API CARD ===
+    egui::Frame::new()
+        .fill(card_bg)
+        .stroke(card_stroke)
+        .inner_margin(12.0)
+        .corner_radius(10.0)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(text.http_api_header)
+                    .strong()
+                    .size(14.0),
+            );
+            ui.add_space(6.0);
+            if render_http_api_section_content(ui, config, text) {
+                changed = true;
+            }
+        });
+
     changed
 }
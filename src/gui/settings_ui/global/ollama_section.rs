@@ -0,0 +1,156 @@
+use crate::config::Config;
+use crate::gui::locale::LocaleText;
+use eframe::egui;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Pulled models as (name, size in bytes), from the last `/api/tags` call
+type PulledModels = Vec<(String, u64)>;
+
+static FETCHING: AtomicBool = AtomicBool::new(false);
+
+fn models_id() -> egui::Id {
+    egui::Id::new("ollama_models")
+}
+
+/// Shown next to the URL field
+fn status_id() -> egui::Id {
+    egui::Id::new("ollama_status")
+}
+
+/// List the server's models on a worker thread; the result lands in egui temp data
+fn refresh_models(ctx: &egui::Context, config: &Config, text: &LocaleText) {
+    if FETCHING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    ctx.data_mut(|d| d.insert_temp(status_id(), text.ollama_status_loading.to_string()));
+
+    let ctx = ctx.clone();
+    let config = config.clone();
+    let ok_format = text.ollama_status_ok;
+    let failed = text.ollama_status_failed;
+    std::thread::spawn(move || {
+        let result = crate::api::ollama::fetch_ollama_models(&config, &config.ollama_base_url);
+        let (models, status): (PulledModels, String) = match result {
+            Ok(models) => {
+                let models: PulledModels = models.into_iter().map(|m| (m.name, m.size)).collect();
+                let status = ok_format.replace("{}", &models.len().to_string());
+                (models, status)
+            }
+            Err(e) => {
+                log::warn!("Ollama model list failed: {}", e);
+                (Vec::new(), failed.to_string())
+            }
+        };
+        ctx.data_mut(|d| {
+            d.insert_temp(models_id(), models);
+            d.insert_temp(status_id(), status);
+        });
+        FETCHING.store(false, Ordering::SeqCst);
+        ctx.request_repaint();
+    });
+}
+
+fn model_label(name: &str, size: u64) -> String {
+    let gb = size as f64 / (1024.0 * 1024.0 * 1024.0);
+    if gb >= 1.0 {
+        format!("{} ({:.1} GB)", name, gb)
+    } else {
+        format!("{} ({} MB)", name, size / (1024 * 1024))
+    }
+}
+
+/// Combo box over the pulled models; an empty selection means "use the preset's model"
+fn model_combo(
+    ui: &mut egui::Ui,
+    id: &str,
+    selected: &mut String,
+    models: &PulledModels,
+    text: &LocaleText,
+) -> bool {
+    let mut changed = false;
+    let selected_text = if selected.is_empty() {
+        text.ollama_model_default.to_string()
+    } else {
+        models
+            .iter()
+            .find(|(name, _)| name == selected)
+            .map(|(name, size)| model_label(name, *size))
+            .unwrap_or_else(|| selected.clone())
+    };
+    egui::ComboBox::from_id_salt(id)
+        .selected_text(selected_text)
+        .width(260.0)
+        .show_ui(ui, |ui| {
+            if ui
+                .selectable_label(selected.is_empty(), text.ollama_model_default)
+                .clicked()
+                && !selected.is_empty()
+            {
+                selected.clear();
+                changed = true;
+            }
+            for (name, size) in models {
+                if ui
+                    .selectable_label(selected == name, model_label(name, *size))
+                    .clicked()
+                    && selected != name
+                {
+                    *selected = name.clone();
+                    changed = true;
+                }
+            }
+        });
+    changed
+}
+
+/// Text and vision model pickers for the Ollama server at `config.ollama_base_url`
+pub fn render_ollama_model_picker(
+    ui: &mut egui::Ui,
+    config: &mut Config,
+    text: &LocaleText,
+) -> bool {
+    let mut changed = false;
+
+    let models: Option<PulledModels> = ui.ctx().data(|d| d.get_temp(models_id()));
+    if models.is_none() && !FETCHING.load(Ordering::SeqCst) {
+        // First time the section is shown this session
+        refresh_models(ui.ctx(), config, text);
+    }
+    let models = models.unwrap_or_default();
+
+    egui::Grid::new("ollama_model_grid")
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label(text.ollama_text_model_label);
+            ui.horizontal(|ui| {
+                changed |= model_combo(
+                    ui,
+                    "ollama_text_model",
+                    &mut config.ollama_text_model,
+                    &models,
+                    text,
+                );
+                let reload = ui
+                    .add_enabled(!FETCHING.load(Ordering::SeqCst), egui::Button::new("🔄"))
+                    .on_hover_text(text.ollama_refresh_tooltip);
+                if reload.clicked() {
+                    refresh_models(ui.ctx(), config, text);
+                    // Model ids change with the server's models
+                    crate::model_config::trigger_ollama_model_scan(config);
+                }
+            });
+            ui.end_row();
+
+            ui.label(text.ollama_vision_model_label);
+            changed |= model_combo(
+                ui,
+                "ollama_vision_model",
+                &mut config.ollama_vision_model,
+                &models,
+                text,
+            );
+            ui.end_row();
+        });
+
+    changed
+}
@@ -0,0 +1,104 @@
+use crate::config::Config;
+use crate::gui::locale::LocaleText;
+use crate::model_config::{get_all_models_with_ollama, ModelType};
+use eframe::egui;
+
+/// Whether the provider is switched on in the API settings
+fn provider_enabled(config: &Config, provider: &str) -> bool {
+    match provider {
+        "groq" => config.use_groq,
+        "google" => config.use_gemini,
+        "openrouter" => config.use_openrouter,
+        "cerebras" => config.use_cerebras,
+        "ollama" => config.use_ollama,
+        _ => true,
+    }
+}
+
+/// Target language and provider of the "Translate clipboard" / "Translate selection"
+/// actions
+pub fn render_quick_translate_section_content(
+    ui: &mut egui::Ui,
+    config: &mut Config,
+    text: &LocaleText,
+) -> bool {
+    let mut changed = false;
+
+    ui.label(
+        egui::RichText::new(text.quick_translate_hint)
+            .size(11.0)
+            .weak(),
+    );
+    ui.add_space(4.0);
+
+    // Providers with at least one usable text model
+    let mut providers: Vec<String> = Vec::new();
+    for m in get_all_models_with_ollama() {
+        if m.enabled
+            && m.model_type == ModelType::Text
+            && provider_enabled(config, &m.provider)
+            && !providers.contains(&m.provider)
+        {
+            providers.push(m.provider);
+        }
+    }
+
+    egui::Grid::new("quick_translate_grid")
+        .num_columns(2)
+        .spacing([12.0, 6.0])
+        .show(ui, |ui| {
+            let language_label = ui.label(text.quick_translate_language_label);
+            egui::ComboBox::from_id_salt("quick_translate_language")
+                .selected_text(config.quick_translate_language.as_str())
+                .height(250.0)
+                .show_ui(ui, |ui| {
+                    for lang in crate::config::get_all_languages() {
+                        if ui
+                            .selectable_label(config.quick_translate_language == *lang, lang)
+                            .clicked()
+                            && config.quick_translate_language != *lang
+                        {
+                            config.quick_translate_language = lang.clone();
+                            changed = true;
+                        }
+                    }
+                })
+                .response
+                .labelled_by(language_label.id);
+            ui.end_row();
+
+            let provider_label = ui.label(text.quick_translate_provider_label);
+            let selected = config
+                .quick_translate_provider
+                .as_deref()
+                .unwrap_or(text.quick_translate_provider_auto);
+            egui::ComboBox::from_id_salt("quick_translate_provider")
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    if ui
+                        .selectable_label(
+                            config.quick_translate_provider.is_none(),
+                            text.quick_translate_provider_auto,
+                        )
+                        .clicked()
+                        && config.quick_translate_provider.is_some()
+                    {
+                        config.quick_translate_provider = None;
+                        changed = true;
+                    }
+                    for provider in &providers {
+                        let is_selected =
+                            config.quick_translate_provider.as_deref() == Some(provider.as_str());
+                        if ui.selectable_label(is_selected, provider).clicked() && !is_selected {
+                            config.quick_translate_provider = Some(provider.clone());
+                            changed = true;
+                        }
+                    }
+                })
+                .response
+                .labelled_by(provider_label.id);
+            ui.end_row();
+        });
+
+    changed
+}
@@ -0,0 +1,76 @@
+use crate::config::Config;
+use crate::gui::locale::LocaleText;
+use eframe::egui;
+
+/// Dimming and border color of the region selection overlay, with a preview of a
+/// selection over bright content
+pub fn render_selection_overlay_settings(
+    ui: &mut egui::Ui,
+    config: &mut Config,
+    text: &LocaleText,
+) -> bool {
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        let dim_label = ui.label(text.selection_dim_label);
+        if ui
+            .add(
+                egui::Slider::new(&mut config.selection_dim_opacity, 0..=230)
+                    .custom_formatter(|v, _| format!("{:.0}%", v / 255.0 * 100.0)),
+            )
+            .labelled_by(dim_label.id)
+            .changed()
+        {
+            changed = true;
+        }
+    });
+
+    ui.horizontal(|ui| {
+        let color_label = ui.label(text.selection_border_color_label);
+        if ui
+            .color_edit_button_srgb(&mut config.selection_border_color)
+            .labelled_by(color_label.id)
+            .changed()
+        {
+            changed = true;
+        }
+    });
+
+    draw_preview(ui, config);
+    changed
+}
+
+/// A light page with a few lines of "text", dimmed like the screen, and a selection
+fn draw_preview(ui: &mut egui::Ui, config: &Config) {
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(240.0, 90.0), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+
+    painter.rect_filled(rect, 4.0, egui::Color32::from_rgb(245, 245, 240));
+    for i in 0..5 {
+        let y = rect.top() + 14.0 + i as f32 * 14.0;
+        let width = if i % 2 == 0 { 200.0 } else { 160.0 };
+        painter.rect_filled(
+            egui::Rect::from_min_size(egui::pos2(rect.left() + 16.0, y), egui::vec2(width, 5.0)),
+            2.0,
+            egui::Color32::from_rgb(120, 120, 130),
+        );
+    }
+
+    painter.rect_filled(
+        rect,
+        4.0,
+        egui::Color32::from_black_alpha(config.selection_dim_opacity),
+    );
+
+    let selection = egui::Rect::from_min_max(
+        rect.min + egui::vec2(60.0, 20.0),
+        rect.min + egui::vec2(180.0, 66.0),
+    );
+    let [r, g, b] = config.selection_border_color;
+    painter.rect_stroke(
+        selection,
+        6.0,
+        egui::Stroke::new(2.0, egui::Color32::from_rgb(r, g, b)),
+        egui::StrokeKind::Middle,
+    );
+}
@@ -73,6 +73,20 @@ pub fn render_tts_settings_modal(
                     changed = true;
                 }
             });
+            ui.add_space(6.0);
+
+            // Playback speed (time-stretched, applies to every method)
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(text.tts_playback_speed_label).strong())
+                    .on_hover_text(text.tts_playback_speed_tooltip);
+                let speed_range = crate::api::tts::MIN_PLAYBACK_SPEED..=crate::api::tts::MAX_PLAYBACK_SPEED;
+                let slider = egui::Slider::new(&mut config.tts_playback_speed, speed_range)
+                    .step_by(5.0)
+                    .custom_formatter(|v, _| format!("{:.2}x", v / 100.0));
+                if ui.add(slider).on_hover_text(text.tts_playback_speed_tooltip).changed() {
+                    changed = true;
+                }
+            });
             ui.add_space(10.0);
             ui.separator();
             ui.add_space(10.0);
@@ -224,10 +238,10 @@ pub fn render_tts_settings_modal(
                                     LAST_PREVIEW_IDX.store(idx, Ordering::Relaxed);
                                     
                                     let preview_text = text.tts_preview_texts[idx].replace("{}", name);
-                                    crate::api::tts::TTS_MANAGER.speak_interrupt(&preview_text, 0);
+                                    crate::api::tts::TTS_MANAGER.speak_interrupt(&preview_text, 0, config);
                                 } else {
                                     let preview_text = format!("Hello, I am {}. This is a voice preview.", name);
-                                    crate::api::tts::TTS_MANAGER.speak_interrupt(&preview_text, 0);
+                                    crate::api::tts::TTS_MANAGER.speak_interrupt(&preview_text, 0, config);
                                 }
                             }
                             ui.label(egui::RichText::new(name).strong());
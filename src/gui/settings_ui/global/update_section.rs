@@ -1,17 +1,126 @@
+use crate::config::{Config, UpdateChannel};
 use crate::gui::locale::LocaleText;
 use crate::updater::{UpdateStatus, Updater};
 use eframe::egui;
 
 #[allow(unexpected_cfgs)]
 
+/// Stable / Beta choice, with a warning while on Beta. Returns true if it changed.
+fn render_channel_selector(ui: &mut egui::Ui, config: &mut Config, text: &LocaleText) -> bool {
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label(text.update_channel_label);
+        for (channel, label) in [
+            (UpdateChannel::Stable, text.update_channel_stable),
+            (UpdateChannel::Beta, text.update_channel_beta),
+        ] {
+            if ui
+                .radio_value(&mut config.update_channel, channel, label)
+                .changed()
+            {
+                changed = true;
+            }
+        }
+    });
+    if config.update_channel == UpdateChannel::Beta {
+        ui.label(
+            egui::RichText::new(text.update_channel_beta_warning)
+                .small()
+                .color(egui::Color32::from_rgb(230, 160, 60)),
+        );
+    }
+    changed
+}
+
+/// Background check interval and quiet hours. Returns true if anything changed.
+fn render_schedule_settings(ui: &mut egui::Ui, config: &mut Config, text: &LocaleText) -> bool {
+    let mut changed = false;
+    let interval_label = |hours: u32| match hours {
+        0 => text.update_interval_startup_only.to_string(),
+        168 => text.update_interval_weekly.to_string(),
+        h => text.update_interval_hours.replace("{}", &h.to_string()),
+    };
+
+    ui.horizontal(|ui| {
+        ui.label(text.update_interval_label);
+        egui::ComboBox::from_id_salt("update_check_interval")
+            .selected_text(interval_label(config.update_check_interval_hours))
+            .show_ui(ui, |ui| {
+                for hours in [0, 6, 12, 24, 168] {
+                    if ui
+                        .selectable_value(
+                            &mut config.update_check_interval_hours,
+                            hours,
+                            interval_label(hours),
+                        )
+                        .changed()
+                    {
+                        changed = true;
+                    }
+                }
+            });
+    });
+
+    ui.horizontal(|ui| {
+        if ui
+            .checkbox(
+                &mut config.update_quiet_hours_enabled,
+                text.update_quiet_hours_label,
+            )
+            .on_hover_text(text.update_quiet_hours_tooltip)
+            .changed()
+        {
+            changed = true;
+        }
+        ui.add_enabled_ui(config.update_quiet_hours_enabled, |ui| {
+            for (i, hour) in [&mut config.update_quiet_start, &mut config.update_quiet_end]
+                .into_iter()
+                .enumerate()
+            {
+                if i == 1 {
+                    ui.label("–");
+                }
+                if ui
+                    .add(egui::DragValue::new(hour).range(0..=23).suffix(":00"))
+                    .changed()
+                {
+                    changed = true;
+                }
+            }
+        });
+    });
+
+    if config.last_update_check > 0 {
+        if let Some(checked) = chrono::DateTime::from_timestamp(config.last_update_check, 0) {
+            let checked = checked.with_timezone(&chrono::Local);
+            ui.label(
+                egui::RichText::new(format!(
+                    "{} {}",
+                    text.update_last_checked,
+                    checked.format("%Y-%m-%d %H:%M")
+                ))
+                .small()
+                .weak(),
+            );
+        }
+    }
+    changed
+}
+
 pub fn render_update_section_content(
     ui: &mut egui::Ui,
+    config: &mut Config,
     updater: &Option<Updater>,
     status: &UpdateStatus,
     text: &LocaleText,
-) {
+) -> bool {
+    let mut changed = render_channel_selector(ui, config, text);
+    changed |= render_schedule_settings(ui, config, text);
+    ui.add_space(4.0);
+
     match status {
-        UpdateStatus::Idle => {
+        // ReleaseNotes never becomes the update status, see `check_updater`
+        UpdateStatus::Idle | UpdateStatus::ReleaseNotes { .. } => {
             ui.horizontal(|ui| {
                 let mut ver_string = format!(
                     "{} v{}",
@@ -24,7 +133,7 @@ pub fn render_update_section_content(
                 ui.label(ver_string);
                 if ui.button(text.check_for_updates_btn).clicked() {
                     if let Some(u) = updater {
-                        u.check_for_updates();
+                        u.check_for_updates(config.update_channel);
                     }
                 }
             });
@@ -43,7 +152,7 @@ pub fn render_update_section_content(
                 );
                 if ui.button(text.check_again_btn).clicked() {
                     if let Some(u) = updater {
-                        u.check_for_updates();
+                        u.check_for_updates(config.update_channel);
                     }
                 }
             });
@@ -62,7 +171,7 @@ pub fn render_update_section_content(
                 .clicked()
             {
                 if let Some(u) = updater {
-                    u.perform_update();
+                    u.perform_update(config.update_channel);
                 }
             }
         }
@@ -77,7 +186,7 @@ pub fn render_update_section_content(
             ui.label(egui::RichText::new(text.app_folder_writable_hint).size(11.0));
             if ui.button(text.retry_btn).clicked() {
                 if let Some(u) = updater {
-                    u.check_for_updates();
+                    u.check_for_updates(config.update_channel);
                 }
             }
         }
@@ -102,46 +211,102 @@ pub fn render_update_section_content(
                                 })
                                 .max_by_key(|e| e.metadata().ok().and_then(|m| m.modified().ok()))
                             {
-                                let path = newest_exe.path();
-                                println!("Attempting to spawn with delay: {:?}", path);
-
-                                // Create a temporary batch file to handle the delayed restart reliably
-                                // This avoids complex escaping issues with cmd /C inline commands
-                                let kill_mutex_cmd = format!("timeout /t 2 /nobreak > NUL");
-                                let start_cmd =
-                                    format!("start \"\" \"{}\"", path.to_string_lossy());
-                                let self_del_cmd = "(goto) 2>nul & del \"%~f0\"";
-
-                                let batch_content = format!(
-                                    "@echo off\r\n{}\r\n{}\r\n{}",
-                                    kill_mutex_cmd, start_cmd, self_del_cmd
-                                );
-
-                                let temp_dir = std::env::temp_dir();
-                                let bat_path = temp_dir
-                                    .join(format!("sgt_restart_{}.bat", std::process::id()));
-
-                                println!("Writing batch file to: {:?}", bat_path);
-                                if let Ok(_) = std::fs::write(&bat_path, batch_content) {
-                                    // Spawn the batch file hidden via cmd /C
-                                    let status = std::process::Command::new("cmd")
-                                        .args(["/C", &bat_path.to_string_lossy()])
-                                        .spawn();
-
-                                    match status {
-                                        Ok(_) => std::process::exit(0),
-                                        Err(e) => {
-                                            eprintln!("Failed to spawn batch file: {}", e);
-                                        }
-                                    }
-                                } else {
-                                    eprintln!("Failed to write batch file");
-                                }
+                                restart_into(&newest_exe.path());
                             }
                         }
                     }
                 }
             }
         }
+        UpdateStatus::RollbackStaged(previous) => {
+            ui.label(
+                egui::RichText::new(text.rollback_staged.replace("{}", previous))
+                    .color(egui::Color32::from_rgb(230, 160, 60)),
+            );
+            if ui.button(text.restart_app_btn).clicked() {
+                // The staged build is swapped in by this exe's startup
+                if let Ok(exe_path) = std::env::current_exe() {
+                    restart_into(&exe_path);
+                }
+            }
+        }
+    }
+
+    let can_roll_back = matches!(
+        status,
+        UpdateStatus::Idle
+            | UpdateStatus::ReleaseNotes { .. }
+            | UpdateStatus::UpToDate(_)
+            | UpdateStatus::UpdateAvailable { .. }
+            | UpdateStatus::Error(_)
+    );
+    if can_roll_back {
+        if let Some(previous) = previous_build_name(ui.ctx()) {
+            ui.add_space(5.0);
+            if ui
+                .button(text.rollback_btn)
+                .on_hover_text(text.rollback_tooltip.replace("{}", &previous))
+                .clicked()
+            {
+                if let Some(u) = updater {
+                    u.roll_back();
+                }
+            }
+        }
+    }
+    changed
+}
+
+/// File name of the build a rollback would restore, looked up once per session
+fn previous_build_name(ctx: &egui::Context) -> Option<String> {
+    ctx.data_mut(|d| {
+        d.get_temp_mut_or_insert_with(egui::Id::new("previous_build_name"), || {
+            let exe_path = std::env::current_exe().ok()?;
+            crate::updater::previous_builds(&exe_path)
+                .first()
+                .and_then(|p| p.file_name())
+                .map(|n| n.to_string_lossy().to_string())
+        })
+        .clone()
+    })
+}
+
+/// Exit and start `path` a moment later, once this process is gone
+fn restart_into(path: &std::path::Path) {
+    log::info!("Attempting to spawn with delay: {:?}", path);
+
+    // Create a temporary batch file to handle the delayed restart reliably
+    // This avoids complex escaping issues with cmd /C inline commands
+    let kill_mutex_cmd = format!("timeout /t 2 /nobreak > NUL");
+    let start_cmd = format!("start \"\" \"{}\"", path.to_string_lossy());
+    let self_del_cmd = "(goto) 2>nul & del \"%~f0\"";
+
+    let batch_content = format!(
+        "@echo off\r\n{}\r\n{}\r\n{}",
+        kill_mutex_cmd, start_cmd, self_del_cmd
+    );
+
+    let temp_dir = std::env::temp_dir();
+    let bat_path = temp_dir.join(format!("sgt_restart_{}.bat", std::process::id()));
+
+    log::info!("Writing batch file to: {:?}", bat_path);
+    if let Ok(_) = std::fs::write(&bat_path, batch_content) {
+        // Spawn the batch file hidden via cmd /C
+        let status = std::process::Command::new("cmd")
+            .args(["/C", &bat_path.to_string_lossy()])
+            .spawn();
+
+        match status {
+            Ok(_) => {
+                crate::gui::flush_pending_config_save();
+                crate::overlay::utils::clear_clipboard_on_exit();
+                std::process::exit(0)
+            }
+            Err(e) => {
+                log::error!("Failed to spawn batch file: {}", e);
+            }
+        }
+    } else {
+        log::error!("Failed to write batch file");
     }
 }
@@ -0,0 +1,209 @@
+use crate::config::Preset;
+use crate::gui::locale::LocaleText;
+use crate::gui::settings_ui::get_localized_preset_name;
+use crate::gui::utils::{export_in_background, pick_file_path, render_export_status};
+use crate::usage_history::{last_days, to_csv, to_json};
+use eframe::egui;
+use egui_plot::{Bar, BarChart, Legend, Line, Plot, PlotPoints};
+use std::collections::BTreeMap;
+
+const CHART_DAYS: i64 = 30;
+const CHART_HEIGHT: f32 = 130.0;
+
+fn provider_color(provider: &str) -> egui::Color32 {
+    match provider {
+        "groq" => egui::Color32::from_rgb(240, 130, 60),
+        "google" | "gemini-live" => egui::Color32::from_rgb(70, 130, 230),
+        "cerebras" => egui::Color32::from_rgb(220, 80, 80),
+        "openrouter" => egui::Color32::from_rgb(140, 100, 220),
+        "ollama" => egui::Color32::from_rgb(90, 170, 110),
+        _ => egui::Color32::GRAY,
+    }
+}
+
+/// Requests per day and estimated tokens over the last 30 days, with a provider
+/// filter, the busiest presets and a CSV/JSON export of the numbers shown
+pub fn render_usage_charts(
+    ui: &mut egui::Ui,
+    text: &LocaleText,
+    presets: &[Preset],
+    ui_language: &str,
+) {
+    egui::CollapsingHeader::new(
+        egui::RichText::new(format!("📈 {}", text.usage_charts_title))
+            .strong()
+            .size(13.0),
+    )
+    .default_open(true)
+    .show(ui, |ui| {
+        let filter_id = egui::Id::new("usage_charts_provider");
+        let mut filter: Option<String> = ui.ctx().data(|d| d.get_temp(filter_id)).flatten();
+
+        let days = last_days(CHART_DAYS);
+        let providers: Vec<String> = days
+            .iter()
+            .flat_map(|(_, day)| day.providers.keys().cloned())
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .filter(|p| filter.as_ref().is_none_or(|f| f == p))
+            .collect();
+
+        ui.horizontal(|ui| {
+            let filter_label = ui.label(text.usage_charts_provider_label);
+            egui::ComboBox::from_id_salt("usage_charts_provider_combo")
+                .selected_text(filter.as_deref().unwrap_or(text.usage_charts_all_providers))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut filter, None, text.usage_charts_all_providers);
+                    let all: std::collections::BTreeSet<&String> = days
+                        .iter()
+                        .flat_map(|(_, day)| day.providers.keys())
+                        .collect();
+                    for provider in all {
+                        ui.selectable_value(&mut filter, Some(provider.clone()), provider);
+                    }
+                })
+                .response
+                .labelled_by(filter_label.id);
+
+            let status_id = egui::Id::new("usage_export_status");
+            let formats = [
+                (text.export_csv_btn, "CSV", "csv"),
+                (text.export_json_btn, "JSON", "json"),
+            ];
+            for (label, filter_name, ext) in formats {
+                if !ui.button(label).clicked() {
+                    continue;
+                }
+                let default_name = format!(
+                    "sgt-usage-{}.{}",
+                    chrono::Local::now().format("%Y%m%d"),
+                    ext
+                );
+                if let Some(path) = pick_file_path(true, filter_name, ext, &default_name) {
+                    let mut shown = days.clone();
+                    for (_, day) in &mut shown {
+                        day.providers.retain(|p, _| providers.contains(p));
+                    }
+                    export_in_background(ui.ctx(), status_id, path, text, move |path| {
+                        let data = if ext == "csv" {
+                            to_csv(&shown)
+                        } else {
+                            to_json(&shown)?
+                        };
+                        std::fs::write(path, data)?;
+                        Ok(())
+                    });
+                }
+            }
+        });
+        render_export_status(ui, egui::Id::new("usage_export_status"));
+
+        // x axis: day index, 0 = oldest
+        let date_labels: Vec<String> = days
+            .iter()
+            .map(|(date, _)| date.format("%m-%d").to_string())
+            .collect();
+        let format_day = move |mark: egui_plot::GridMark, _: &std::ops::RangeInclusive<f64>| {
+            let i = mark.value.round();
+            if (mark.value - i).abs() < 1e-6 && i >= 0.0 {
+                date_labels.get(i as usize).cloned().unwrap_or_default()
+            } else {
+                String::new()
+            }
+        };
+
+        ui.label(egui::RichText::new(text.usage_charts_requests_label).size(11.0));
+        let mut charts: Vec<BarChart> = Vec::new();
+        for provider in &providers {
+            let bars = days
+                .iter()
+                .enumerate()
+                .map(|(i, (_, day))| {
+                    let requests = day.providers.get(provider).map_or(0, |u| u.requests);
+                    Bar::new(i as f64, requests as f64).width(0.7)
+                })
+                .collect();
+            let below: Vec<&BarChart> = charts.iter().collect();
+            let chart = BarChart::new(provider.clone(), bars)
+                .color(provider_color(provider))
+                .stack_on(&below);
+            charts.push(chart);
+        }
+        Plot::new("usage_requests_plot")
+            .height(CHART_HEIGHT)
+            .legend(Legend::default())
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .include_y(0.0)
+            .x_axis_formatter(format_day.clone())
+            .show(ui, |plot_ui| {
+                for chart in charts {
+                    plot_ui.bar_chart(chart);
+                }
+            });
+
+        ui.label(egui::RichText::new(text.usage_charts_tokens_label).size(11.0));
+        Plot::new("usage_tokens_plot")
+            .height(CHART_HEIGHT)
+            .legend(Legend::default())
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .include_y(0.0)
+            .x_axis_formatter(format_day)
+            .show(ui, |plot_ui| {
+                for provider in &providers {
+                    let points: PlotPoints = days
+                        .iter()
+                        .enumerate()
+                        .map(|(i, (_, day))| {
+                            let tokens = day.providers.get(provider).map_or(0, |u| u.est_tokens);
+                            [i as f64, tokens as f64]
+                        })
+                        .collect();
+                    plot_ui
+                        .line(Line::new(provider.clone(), points).color(provider_color(provider)));
+                }
+            });
+
+        // Busiest presets over the same period
+        let mut by_preset: BTreeMap<&str, u64> = BTreeMap::new();
+        for (_, day) in &days {
+            for (id, count) in &day.presets {
+                *by_preset.entry(id.as_str()).or_default() += count;
+            }
+        }
+        let mut by_preset: Vec<(&str, u64)> = by_preset.into_iter().collect();
+        by_preset.sort_by(|a, b| b.1.cmp(&a.1));
+        if !by_preset.is_empty() {
+            egui::Grid::new("usage_presets_grid")
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label(
+                        egui::RichText::new(text.usage_charts_preset_column)
+                            .strong()
+                            .size(11.0),
+                    );
+                    ui.label(
+                        egui::RichText::new(text.usage_charts_requests_column)
+                            .strong()
+                            .size(11.0),
+                    );
+                    ui.end_row();
+                    for (id, count) in by_preset.into_iter().take(8) {
+                        let name = match presets.iter().find(|p| p.id == id) {
+                            Some(p) if !p.id.starts_with("preset_") => p.name.clone(),
+                            _ => get_localized_preset_name(id, ui_language),
+                        };
+                        ui.label(name);
+                        ui.label(count.to_string());
+                        ui.end_row();
+                    }
+                });
+        }
+
+        ui.ctx().data_mut(|d| d.insert_temp(filter_id, filter));
+    });
+    ui.add_space(6.0);
+}
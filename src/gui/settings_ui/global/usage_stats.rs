@@ -14,6 +14,8 @@ pub fn render_usage_modal(
     use_openrouter: bool,
     use_ollama: bool,
     use_cerebras: bool,
+    presets: &[crate::config::Preset],
+    ui_language: &str,
 ) {
     if !*show_modal {
         return;
@@ -52,6 +54,7 @@ pub fn render_usage_modal(
                 .auto_shrink([false, false])
                 .show(ui, |ui| {
                 ui.set_width(ui.available_width());
+                super::usage_charts::render_usage_charts(ui, text, presets, ui_language);
                 if use_groq {
                     egui::CollapsingHeader::new(egui::RichText::new("⚡ Groq").strong().size(13.0))
                         .default_open(true)
@@ -204,7 +207,18 @@ pub fn render_usage_modal(
                             if shown_models.contains(&model.full_name) { continue; }
                             shown_models.insert(model.full_name.clone());
                             
-                            ui.label(&model.full_name);
+                            // Last generation's token count, once the model has been used
+                            match usage_stats.get(&model.full_name) {
+                                Some(last_run) => {
+                                    ui.horizontal(|ui| {
+                                        ui.label(&model.full_name);
+                                        ui.label(egui::RichText::new(last_run).weak().size(11.0));
+                                    });
+                                }
+                                None => {
+                                    ui.label(&model.full_name);
+                                }
+                            }
                         }
                     });
                 }
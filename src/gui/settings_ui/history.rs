@@ -1,8 +1,11 @@
 use crate::config::Config;
 use crate::gui::icons::{draw_icon_static, icon_button, Icon};
 use crate::gui::locale::LocaleText;
-use crate::history::{HistoryItem, HistoryManager, HistoryType};
+use crate::gui::settings_ui::get_localized_preset_name;
+use crate::gui::utils::{export_in_background, pick_file_path, render_export_status};
+use crate::history::{export_history, ExportFormat, HistoryItem, HistoryManager, HistoryType};
 use eframe::egui;
+use std::collections::HashMap;
 
 pub fn render_history_panel(
     ui: &mut egui::Ui,
@@ -28,6 +31,20 @@ pub fn render_history_panel(
     // Set max width for entire panel (outside frame so it properly constrains the card)
     ui.set_max_width(510.0);
 
+    let export_status_id = egui::Id::new("history_export_status");
+    let preset_names: HashMap<String, String> = config
+        .presets
+        .iter()
+        .map(|p| {
+            let name = if p.id.starts_with("preset_") {
+                get_localized_preset_name(&p.id, &config.ui_language)
+            } else {
+                p.name.clone()
+            };
+            (p.id.clone(), name)
+        })
+        .collect();
+
     // === HEADER CARD ===
     ui.add_space(5.0);
     egui::Frame::new()
@@ -98,6 +115,29 @@ pub fn render_history_panel(
                     let _ = open::that(config_dir);
                 }
 
+                // Export all entries (not just the search results)
+                let formats = [
+                    (text.export_csv_btn, "CSV", "csv", ExportFormat::Csv),
+                    (text.export_json_btn, "JSON", "json", ExportFormat::Json),
+                ];
+                for (label, filter_name, ext, format) in formats {
+                    if !ui.small_button(label).clicked() {
+                        continue;
+                    }
+                    let default_name = format!(
+                        "sgt-history-{}.{}",
+                        chrono::Local::now().format("%Y%m%d"),
+                        ext
+                    );
+                    if let Some(path) = pick_file_path(true, filter_name, ext, &default_name) {
+                        let items = history_manager.items.lock().unwrap().clone();
+                        let preset_names = preset_names.clone();
+                        export_in_background(ui.ctx(), export_status_id, path, text, move |path| {
+                            export_history(&items, &preset_names, path, format)
+                        });
+                    }
+                }
+
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                     // Clear All button - styled
                     let clear_bg = if is_dark {
@@ -121,6 +161,7 @@ pub fn render_history_panel(
                     }
                 });
             });
+            render_export_status(ui, export_status_id);
         });
 
     ui.add_space(8.0);
@@ -129,7 +170,13 @@ pub fn render_history_panel(
     let q = search_query.to_lowercase();
     let filtered: Vec<&HistoryItem> = items
         .iter()
-        .filter(|i| q.is_empty() || i.text.to_lowercase().contains(&q) || i.timestamp.contains(&q))
+        .filter(|i| {
+            q.is_empty()
+                || i.text.to_lowercase().contains(&q)
+                || i.timestamp.contains(&q)
+                || i.model.to_lowercase().contains(&q)
+                || i.provider.contains(&q)
+        })
         .collect();
 
     if filtered.is_empty() {
@@ -190,6 +237,18 @@ pub fn render_history_panel(
                                 draw_icon_static(ui, icon, Some(14.0));
                                 ui.label(egui::RichText::new(&item.timestamp).size(10.0).weak());
 
+                                // Provenance; entries saved before it was recorded have none
+                                let source = if item.model.is_empty() {
+                                    text.history_unknown_source.to_string()
+                                } else {
+                                    format!("{} · {}", item.provider, item.model)
+                                };
+                                let source_label =
+                                    ui.label(egui::RichText::new(source).size(10.0).weak());
+                                if let Some(name) = preset_names.get(&item.preset_id) {
+                                    source_label.on_hover_text(name);
+                                }
+
                                 ui.with_layout(
                                     egui::Layout::right_to_left(egui::Align::Center),
                                     |ui| {
@@ -11,7 +11,7 @@ pub use global::render_global_settings;
 pub use history::render_history_panel;
 pub use preset::render_preset_editor;
 pub use sidebar::get_localized_preset_name;
-pub use sidebar::render_sidebar;
+pub use sidebar::{handle_sidebar_keyboard_nav, render_sidebar};
 
 #[derive(PartialEq, Clone, Copy)]
 pub enum ViewMode {
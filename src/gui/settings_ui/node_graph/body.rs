@@ -1,6 +1,10 @@
 use super::node::ChainNode;
-use super::utils::{insert_next_language_tag, model_supports_search, show_language_vars};
+use super::utils::{
+    insert_next_language_tag, model_supports_search, show_language_vars, show_prompt_variables,
+};
 use super::viewer::ChainViewer;
+use crate::config::preset::{output_regex_error, BlockCondition, ParallelBranch, ResponseFormat};
+use crate::config::{ImageUploadFormat, ImageUploadSettings};
 use crate::gui::icons::{icon_button, Icon};
 use crate::model_config::{
     get_all_models_with_ollama, get_model_by_id, is_ollama_scan_in_progress, model_is_non_llm,
@@ -204,6 +208,8 @@ pub fn show_body(
                     }
                     ChainNode::Special {
                         model,
+                        provider_override,
+                        model_override,
                         prompt,
                         language_vars,
                         show_overlay,
@@ -211,6 +217,9 @@ pub fn show_body(
                         render_mode,
                         auto_copy,
                         auto_speak,
+                        output_regex,
+                        image_upload,
+                        response_format,
                         ..
                     } => {
                         // Special nodes use different model types based on preset type
@@ -245,7 +254,7 @@ pub fn show_body(
                                 egui::Popup::toggle_id(ui.ctx(), button_response.id);
                                 // Trigger background scan when popup opens
                                 if viewer.use_ollama {
-                                    trigger_ollama_model_scan();
+                                    trigger_ollama_model_scan(&crate::APP.lock().unwrap().config);
                                 }
                             }
                             let popup_layer_id = button_response.id;
@@ -267,6 +276,9 @@ pub fn show_body(
                                     if m.enabled
                                         && m.model_type == target_model_type
                                         && viewer.is_provider_enabled(&m.provider)
+                                        && provider_override
+                                            .as_ref()
+                                            .is_none_or(|p| *p == m.provider)
                                     {
                                         let name = match viewer.ui_language.as_str() {
                                             "vi" => &m.name_vi,
@@ -301,6 +313,9 @@ pub fn show_body(
 
                                         if ui.selectable_label(is_selected, label).clicked() {
                                             *model = m.id.clone();
+                                            if model_override.is_some() {
+                                                *model_override = Some(m.id.clone());
+                                            }
                                             viewer.changed = true;
                                             egui::Popup::toggle_id(ui.ctx(), popup_layer_id);
                                         }
@@ -309,6 +324,15 @@ pub fn show_body(
                             });
                         });
 
+                        show_model_overrides(
+                            ui,
+                            viewer,
+                            model,
+                            provider_override,
+                            model_override,
+                            &target_model_type,
+                        );
+
                         // Only show prompt UI for LLM models (not QR scanner, GTX, Whisper, etc.)
                         if !model_is_non_llm(model) {
                             // Row 2: Prompt Label + Add Tag Button
@@ -369,6 +393,20 @@ pub fn show_body(
                                 &mut viewer.changed,
                                 &mut viewer.language_search,
                             );
+                            show_prompt_variables(
+                                ui,
+                                &viewer.ui_language,
+                                prompt,
+                                &mut viewer.changed,
+                            );
+                        }
+
+                        show_output_regex(ui, viewer, output_regex);
+                        if !model_is_non_llm(model) {
+                            show_response_format(ui, viewer, response_format);
+                        }
+                        if viewer.preset_type == "image" && !model_is_non_llm(model) {
+                            show_image_upload(ui, viewer, image_upload);
                         }
 
                         // Bottom Row: Settings
@@ -509,7 +547,10 @@ pub fn show_body(
                         });
                     }
                     ChainNode::Process {
+                        block_type,
                         model,
+                        provider_override,
+                        model_override,
                         prompt,
                         language_vars,
                         show_overlay,
@@ -517,98 +558,137 @@ pub fn show_body(
                         render_mode,
                         auto_copy,
                         auto_speak,
+                        output_regex,
+                        condition,
+                        branches,
+                        parallel_pick,
+                        response_format,
                         ..
                     } => {
                         // Process nodes always use Text models (text-to-text transformation)
                         let target_model_type = ModelType::Text;
 
-                        // Row 1: Model
-                        let model_label = match viewer.ui_language.as_str() {
-                            "vi" => "Mô hình:",
-                            "ko" => "모델:",
-                            _ => "Model:",
-                        };
-                        ui.horizontal(|ui| {
-                            ui.label(model_label);
-                            let model_def = get_model_by_id(model);
-                            let display_name = model_def
-                                .as_ref()
-                                .map(|m| match viewer.ui_language.as_str() {
-                                    "vi" => m.name_vi.as_str(),
-                                    "ko" => m.name_ko.as_str(),
-                                    _ => m.name_en.as_str(),
-                                })
-                                .unwrap_or(model.as_str());
+                        // Row 1: Model (parallel nodes: one row per branch instead)
+                        if block_type == "parallel" {
+                            show_parallel_branches(ui, viewer, branches, parallel_pick);
+                        } else {
+                            let model_label = match viewer.ui_language.as_str() {
+                                "vi" => "Mô hình:",
+                                "ko" => "모델:",
+                                _ => "Model:",
+                            };
+                            ui.horizontal(|ui| {
+                                ui.label(model_label);
+                                let model_def = get_model_by_id(model);
+                                let display_name = model_def
+                                    .as_ref()
+                                    .map(|m| match viewer.ui_language.as_str() {
+                                        "vi" => m.name_vi.as_str(),
+                                        "ko" => m.name_ko.as_str(),
+                                        _ => m.name_en.as_str(),
+                                    })
+                                    .unwrap_or(model.as_str());
 
-                            let button_response = ui.button(display_name);
-                            if button_response.clicked() {
-                                egui::Popup::toggle_id(ui.ctx(), button_response.id);
-                                if viewer.use_ollama {
-                                    trigger_ollama_model_scan();
+                                let button_response = ui.button(display_name);
+                                if button_response.clicked() {
+                                    egui::Popup::toggle_id(ui.ctx(), button_response.id);
+                                    if viewer.use_ollama {
+                                        trigger_ollama_model_scan(
+                                            &crate::APP.lock().unwrap().config,
+                                        );
+                                    }
                                 }
-                            }
-                            let popup_layer_id = button_response.id;
-                            egui::Popup::from_toggle_button_response(&button_response).show(|ui| {
-                                ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
+                                let popup_layer_id = button_response.id;
+                                egui::Popup::from_toggle_button_response(&button_response).show(
+                                    |ui| {
+                                        ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
 
-                                if viewer.use_ollama && is_ollama_scan_in_progress() {
-                                    let loading_text = match viewer.ui_language.as_str() {
-                                        "vi" => "⏳ Đang quét các model local...",
-                                        "ko" => "⏳ 로컬 모델 스캔 중...",
-                                        _ => "⏳ Scanning local models...",
-                                    };
-                                    ui.label(egui::RichText::new(loading_text).weak().italics());
-                                    ui.separator();
-                                }
+                                        if viewer.use_ollama && is_ollama_scan_in_progress() {
+                                            let loading_text = match viewer.ui_language.as_str() {
+                                                "vi" => "⏳ Đang quét các model local...",
+                                                "ko" => "⏳ 로컬 모델 스캔 중...",
+                                                _ => "⏳ Scanning local models...",
+                                            };
+                                            ui.label(
+                                                egui::RichText::new(loading_text).weak().italics(),
+                                            );
+                                            ui.separator();
+                                        }
 
-                                for m in get_all_models_with_ollama() {
-                                    if m.enabled
-                                        && m.model_type == target_model_type
-                                        && viewer.is_provider_enabled(&m.provider)
-                                    {
-                                        let name = match viewer.ui_language.as_str() {
-                                            "vi" => &m.name_vi,
-                                            "ko" => &m.name_ko,
-                                            _ => &m.name_en,
-                                        };
-                                        let quota = match viewer.ui_language.as_str() {
-                                            "vi" => &m.quota_limit_vi,
-                                            "ko" => &m.quota_limit_ko,
-                                            _ => &m.quota_limit_en,
-                                        };
-                                        let provider_icon = match m.provider.as_str() {
-                                            "google" => "✨ ",
-                                            "google-gtx" => "🌍 ",
-                                            "groq" => "⚡ ",
-                                            "cerebras" => "🔥 ",
-                                            "openrouter" => "🌐 ",
-                                            "ollama" => "🏠 ",
-                                            "qrserver" => "🔳 ",
-                                            _ => "⚙️ ",
-                                        };
-                                        let search_suffix = if model_supports_search(&m.id) {
-                                            " 🔍"
-                                        } else {
-                                            ""
-                                        };
-                                        let label = format!(
-                                            "{}{} - {} - {}{}",
-                                            provider_icon, name, m.full_name, quota, search_suffix
-                                        );
-                                        let is_selected = *model == m.id;
+                                        for m in get_all_models_with_ollama() {
+                                            if m.enabled
+                                                && m.model_type == target_model_type
+                                                && viewer.is_provider_enabled(&m.provider)
+                                                && provider_override
+                                                    .as_ref()
+                                                    .is_none_or(|p| *p == m.provider)
+                                            {
+                                                let name = match viewer.ui_language.as_str() {
+                                                    "vi" => &m.name_vi,
+                                                    "ko" => &m.name_ko,
+                                                    _ => &m.name_en,
+                                                };
+                                                let quota = match viewer.ui_language.as_str() {
+                                                    "vi" => &m.quota_limit_vi,
+                                                    "ko" => &m.quota_limit_ko,
+                                                    _ => &m.quota_limit_en,
+                                                };
+                                                let provider_icon = match m.provider.as_str() {
+                                                    "google" => "✨ ",
+                                                    "google-gtx" => "🌍 ",
+                                                    "groq" => "⚡ ",
+                                                    "cerebras" => "🔥 ",
+                                                    "openrouter" => "🌐 ",
+                                                    "ollama" => "🏠 ",
+                                                    "qrserver" => "🔳 ",
+                                                    _ => "⚙️ ",
+                                                };
+                                                let search_suffix = if model_supports_search(&m.id)
+                                                {
+                                                    " 🔍"
+                                                } else {
+                                                    ""
+                                                };
+                                                let label = format!(
+                                                    "{}{} - {} - {}{}",
+                                                    provider_icon,
+                                                    name,
+                                                    m.full_name,
+                                                    quota,
+                                                    search_suffix
+                                                );
+                                                let is_selected = *model == m.id;
 
-                                        if ui.selectable_label(is_selected, label).clicked() {
-                                            *model = m.id.clone();
-                                            viewer.changed = true;
-                                            egui::Popup::toggle_id(ui.ctx(), popup_layer_id);
+                                                if ui.selectable_label(is_selected, label).clicked()
+                                                {
+                                                    *model = m.id.clone();
+                                                    if model_override.is_some() {
+                                                        *model_override = Some(m.id.clone());
+                                                    }
+                                                    viewer.changed = true;
+                                                    egui::Popup::toggle_id(
+                                                        ui.ctx(),
+                                                        popup_layer_id,
+                                                    );
+                                                }
+                                            }
                                         }
-                                    }
-                                }
+                                    },
+                                );
                             });
-                        });
+
+                            show_model_overrides(
+                                ui,
+                                viewer,
+                                model,
+                                provider_override,
+                                model_override,
+                                &target_model_type,
+                            );
+                        }
 
                         // Only show prompt UI for LLM models (not GTX, etc.)
-                        if !model_is_non_llm(model) {
+                        if block_type == "parallel" || !model_is_non_llm(model) {
                             // Row 2: Prompt Label + Add Tag Button
                             ui.horizontal(|ui| {
                                 let prompt_label = match viewer.ui_language.as_str() {
@@ -667,8 +747,20 @@ pub fn show_body(
                                 &mut viewer.changed,
                                 &mut viewer.language_search,
                             );
+                            show_prompt_variables(
+                                ui,
+                                &viewer.ui_language,
+                                prompt,
+                                &mut viewer.changed,
+                            );
                         }
 
+                        show_output_regex(ui, viewer, output_regex);
+                        if block_type != "parallel" && !model_is_non_llm(model) {
+                            show_response_format(ui, viewer, response_format);
+                        }
+                        show_condition(ui, viewer, condition);
+
                         // Bottom Row: Settings
                         ui.horizontal(|ui| {
                             let icon = if *show_overlay {
@@ -816,3 +908,608 @@ pub fn show_body(
         }
     }
 }
+
+/// Row under the model picker: lock the node to one provider and/or pin the exact model.
+/// Unset overrides follow the global provider settings (including cross-provider fallback).
+fn show_model_overrides(
+    ui: &mut egui::Ui,
+    viewer: &mut ChainViewer,
+    model: &mut String,
+    provider_override: &mut Option<String>,
+    model_override: &mut Option<String>,
+    target_model_type: &ModelType,
+) {
+    let (provider_label, auto_label, pin_label, pin_tooltip) = match viewer.ui_language.as_str() {
+        "vi" => (
+            "Nhà cung cấp:",
+            "Tự động (chung)",
+            "Ghim mô hình",
+            "Luôn dùng đúng mô hình này, không chuyển sang mô hình khác khi lỗi",
+        ),
+        "ko" => (
+            "제공자:",
+            "자동 (전역)",
+            "모델 고정",
+            "오류가 나도 다른 모델로 전환하지 않고 항상 이 모델을 사용합니다",
+        ),
+        _ => (
+            "Provider:",
+            "Auto (global)",
+            "Pin model",
+            "Always use exactly this model, never fall back to another one on errors",
+        ),
+    };
+
+    // Providers with at least one usable model of this node's type
+    let models = get_all_models_with_ollama();
+    let mut providers: Vec<&str> = Vec::new();
+    for m in models.iter().filter(|m| {
+        m.enabled && m.model_type == *target_model_type && viewer.is_provider_enabled(&m.provider)
+    }) {
+        if !providers.contains(&m.provider.as_str()) {
+            providers.push(&m.provider);
+        }
+    }
+
+    ui.horizontal(|ui| {
+        ui.label(provider_label);
+        let selected_text = provider_override.as_deref().unwrap_or(auto_label);
+        egui::ComboBox::from_id_salt(ui.id().with("provider_override"))
+            .selected_text(selected_text)
+            .show_ui(ui, |ui| {
+                if ui
+                    .selectable_label(provider_override.is_none(), auto_label)
+                    .clicked()
+                {
+                    *provider_override = None;
+                    viewer.changed = true;
+                }
+                for provider in &providers {
+                    let is_selected = provider_override.as_deref() == Some(*provider);
+                    if ui.selectable_label(is_selected, *provider).clicked() {
+                        *provider_override = Some(provider.to_string());
+                        // Keep the shown model in line with the locked provider
+                        let model_matches =
+                            get_model_by_id(model).is_some_and(|m| m.provider == *provider);
+                        if !model_matches {
+                            if let Some(first) = models.iter().find(|m| {
+                                m.enabled
+                                    && m.model_type == *target_model_type
+                                    && m.provider == *provider
+                            }) {
+                                *model = first.id.clone();
+                                if model_override.is_some() {
+                                    *model_override = Some(first.id.clone());
+                                }
+                            }
+                        }
+                        viewer.changed = true;
+                    }
+                }
+            });
+
+        let mut pinned = model_override.is_some();
+        if ui
+            .checkbox(&mut pinned, pin_label)
+            .on_hover_text(pin_tooltip)
+            .changed()
+        {
+            *model_override = pinned.then(|| model.clone());
+            viewer.changed = true;
+        }
+    });
+}
+
+/// Model rows of a parallel node, each with its own provider lock, and the choice
+/// of which output goes on to the next node
+fn show_parallel_branches(
+    ui: &mut egui::Ui,
+    viewer: &mut ChainViewer,
+    branches: &mut Vec<ParallelBranch>,
+    parallel_pick: &mut Option<usize>,
+) {
+    let (models_label, auto_label, add_label, pass_label, all_label, model_n) =
+        match viewer.ui_language.as_str() {
+            "vi" => (
+                "Mô hình (chạy cùng lúc):",
+                "Tự động",
+                "+ Mô hình",
+                "Chuyển tiếp:",
+                "Tất cả kết quả",
+                "Mô hình",
+            ),
+            "ko" => (
+                "모델 (동시에 실행):",
+                "자동",
+                "+ 모델",
+                "다음으로 전달:",
+                "모든 결과",
+                "모델",
+            ),
+            _ => (
+                "Models (run at once):",
+                "Auto",
+                "+ Model",
+                "Pass on:",
+                "All outputs",
+                "Model",
+            ),
+        };
+
+    let models = get_all_models_with_ollama();
+    let mut providers: Vec<&str> = Vec::new();
+    for m in models.iter().filter(|m| {
+        m.enabled && m.model_type == ModelType::Text && viewer.is_provider_enabled(&m.provider)
+    }) {
+        if !providers.contains(&m.provider.as_str()) {
+            providers.push(&m.provider);
+        }
+    }
+    let model_name = |id: &str| -> String {
+        get_model_by_id(id)
+            .map(|m| match viewer.ui_language.as_str() {
+                "vi" => m.name_vi,
+                "ko" => m.name_ko,
+                _ => m.name_en,
+            })
+            .unwrap_or_else(|| id.to_string())
+    };
+
+    ui.label(models_label);
+    let mut remove = None;
+    let count = branches.len();
+    for (i, branch) in branches.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            ui.label(format!("{}.", i + 1));
+            egui::ComboBox::from_id_salt(ui.id().with(("parallel_model", i)))
+                .selected_text(model_name(&branch.model))
+                .show_ui(ui, |ui| {
+                    for m in models.iter().filter(|m| {
+                        m.enabled
+                            && m.model_type == ModelType::Text
+                            && viewer.is_provider_enabled(&m.provider)
+                            && branch
+                                .provider_override
+                                .as_ref()
+                                .is_none_or(|p| *p == m.provider)
+                    }) {
+                        let label = format!("{} ({})", model_name(&m.id), m.provider);
+                        if ui.selectable_label(branch.model == m.id, label).clicked() {
+                            branch.model = m.id.clone();
+                            viewer.changed = true;
+                        }
+                    }
+                });
+            egui::ComboBox::from_id_salt(ui.id().with(("parallel_provider", i)))
+                .selected_text(branch.provider_override.as_deref().unwrap_or(auto_label))
+                .show_ui(ui, |ui| {
+                    if ui
+                        .selectable_label(branch.provider_override.is_none(), auto_label)
+                        .clicked()
+                    {
+                        branch.provider_override = None;
+                        viewer.changed = true;
+                    }
+                    for provider in &providers {
+                        let is_selected = branch.provider_override.as_deref() == Some(*provider);
+                        if ui.selectable_label(is_selected, *provider).clicked() {
+                            branch.provider_override = Some(provider.to_string());
+                            viewer.changed = true;
+                        }
+                    }
+                });
+            if count > 1 && icon_button(ui, Icon::Close).clicked() {
+                remove = Some(i);
+            }
+        });
+    }
+    if let Some(i) = remove {
+        branches.remove(i);
+        // Keep the passed-on branch pointing at the same model
+        *parallel_pick = match *parallel_pick {
+            Some(p) if p == i => None,
+            Some(p) if p > i => Some(p - 1),
+            other => other,
+        };
+        viewer.changed = true;
+    }
+    if ui.small_button(add_label).clicked() {
+        let model = branches
+            .last()
+            .map(|b| b.model.clone())
+            .unwrap_or_else(|| "text_accurate_kimi".to_string());
+        branches.push(ParallelBranch::new(&model));
+        viewer.changed = true;
+    }
+
+    ui.horizontal(|ui| {
+        ui.label(pass_label);
+        let pick_label = |pick: Option<usize>| match pick {
+            Some(i) => format!("{} {}", model_n, i + 1),
+            None => all_label.to_string(),
+        };
+        egui::ComboBox::from_id_salt(ui.id().with("parallel_pick"))
+            .selected_text(pick_label(*parallel_pick))
+            .show_ui(ui, |ui| {
+                for pick in std::iter::once(None).chain((0..branches.len()).map(Some)) {
+                    if ui
+                        .selectable_label(*parallel_pick == pick, pick_label(pick))
+                        .clicked()
+                    {
+                        *parallel_pick = pick;
+                        viewer.changed = true;
+                    }
+                }
+            });
+    });
+}
+
+/// Collapsible list of regex replacements applied to the node's final output.
+/// Invalid patterns are flagged under their row (they are skipped when running).
+fn show_output_regex(
+    ui: &mut egui::Ui,
+    viewer: &mut ChainViewer,
+    output_regex: &mut Vec<(String, String)>,
+) {
+    let (header, pattern_hint, replacement_hint, add_label, invalid_label) =
+        match viewer.ui_language.as_str() {
+            "vi" => (
+                "Thay thế kết quả (regex)",
+                "Mẫu",
+                "Thay bằng",
+                "+ Quy tắc",
+                "Regex không hợp lệ:",
+            ),
+            "ko" => (
+                "출력 치환 (정규식)",
+                "패턴",
+                "바꿀 내용",
+                "+ 규칙",
+                "잘못된 정규식:",
+            ),
+            _ => (
+                "Output replace (regex)",
+                "Pattern",
+                "Replacement",
+                "+ Rule",
+                "Invalid regex:",
+            ),
+        };
+
+    let title = if output_regex.is_empty() {
+        header.to_string()
+    } else {
+        format!("{} ({})", header, output_regex.len())
+    };
+    egui::CollapsingHeader::new(title)
+        .id_salt(ui.id().with("output_regex"))
+        .show(ui, |ui| {
+            let mut remove = None;
+            for (i, (pattern, replacement)) in output_regex.iter_mut().enumerate() {
+                ui.horizontal(|ui| {
+                    if ui
+                        .add(
+                            egui::TextEdit::singleline(pattern)
+                                .hint_text(pattern_hint)
+                                .desired_width(110.0),
+                        )
+                        .changed()
+                    {
+                        viewer.changed = true;
+                    }
+                    ui.label("→");
+                    if ui
+                        .add(
+                            egui::TextEdit::singleline(replacement)
+                                .hint_text(replacement_hint)
+                                .desired_width(90.0),
+                        )
+                        .changed()
+                    {
+                        viewer.changed = true;
+                    }
+                    if icon_button(ui, Icon::Close).clicked() {
+                        remove = Some(i);
+                    }
+                });
+                if let Some(error) = output_regex_error(pattern) {
+                    ui.label(
+                        egui::RichText::new(format!("{} {}", invalid_label, error))
+                            .size(11.0)
+                            .color(egui::Color32::from_rgb(200, 100, 50)),
+                    );
+                }
+            }
+            if let Some(i) = remove {
+                output_regex.remove(i);
+                viewer.changed = true;
+            }
+            if ui.small_button(add_label).clicked() {
+                output_regex.push((String::new(), String::new()));
+                viewer.changed = true;
+            }
+        });
+}
+
+fn show_response_format(
+    ui: &mut egui::Ui,
+    viewer: &mut ChainViewer,
+    response_format: &mut ResponseFormat,
+) {
+    let (header, json_label, hint, schema_hint, invalid_label) = match viewer.ui_language.as_str() {
+        "vi" => (
+            "Định dạng đầu ra",
+            "JSON có cấu trúc (hiện dạng bảng)",
+            "JSON Schema mà kết quả phải khớp. Để trống để nhận JSON bất kỳ.",
+            "{\"type\": \"array\", \"items\": {...}}",
+            "Schema không hợp lệ:",
+        ),
+        "ko" => (
+            "출력 형식",
+            "구조화된 JSON (표로 표시)",
+            "결과가 따라야 할 JSON Schema입니다. 비워 두면 모든 JSON을 허용합니다.",
+            "{\"type\": \"array\", \"items\": {...}}",
+            "잘못된 스키마:",
+        ),
+        _ => (
+            "Output format",
+            "Structured JSON (shown as a table)",
+            "JSON Schema the answer must match. Leave empty to accept any JSON.",
+            "{\"type\": \"array\", \"items\": {...}}",
+            "Invalid schema:",
+        ),
+    };
+
+    let title = if response_format.is_json() {
+        format!("{}: JSON", header)
+    } else {
+        header.to_string()
+    };
+    egui::CollapsingHeader::new(title)
+        .id_salt(ui.id().with("response_format"))
+        .show(ui, |ui| {
+            let mut is_json = response_format.is_json();
+            if ui.checkbox(&mut is_json, json_label).changed() {
+                *response_format = if is_json {
+                    ResponseFormat::Json(String::new())
+                } else {
+                    ResponseFormat::Text
+                };
+                viewer.changed = true;
+            }
+            if let ResponseFormat::Json(schema) = response_format {
+                ui.label(egui::RichText::new(hint).size(11.0).weak());
+                if ui
+                    .add(
+                        egui::TextEdit::multiline(schema)
+                            .code_editor()
+                            .hint_text(schema_hint)
+                            .desired_rows(4)
+                            .desired_width(220.0),
+                    )
+                    .changed()
+                {
+                    viewer.changed = true;
+                }
+                if !schema.trim().is_empty() {
+                    if let Err(error) = serde_json::from_str::<serde_json::Value>(schema) {
+                        ui.label(
+                            egui::RichText::new(format!("{} {}", invalid_label, error))
+                                .size(11.0)
+                                .color(egui::Color32::from_rgb(200, 100, 50)),
+                        );
+                    }
+                }
+            }
+        });
+}
+
+fn show_condition(ui: &mut egui::Ui, viewer: &mut ChainViewer, condition: &mut BlockCondition) {
+    let (header, kinds, hint, invalid_label) = match viewer.ui_language.as_str() {
+        "vi" => (
+            "Điều kiện chạy",
+            [
+                "Luôn chạy",
+                "Đầu vào không trống",
+                "Chứa",
+                "Không chứa",
+                "Khớp regex",
+                "Không phải ngôn ngữ",
+            ],
+            "Khi không đạt, khối này bị bỏ qua và đầu vào được chuyển tiếp nguyên vẹn.",
+            "Regex không hợp lệ:",
+        ),
+        "ko" => (
+            "실행 조건",
+            [
+                "항상 실행",
+                "입력이 비어 있지 않음",
+                "포함",
+                "포함하지 않음",
+                "정규식 일치",
+                "해당 언어가 아님",
+            ],
+            "조건을 만족하지 않으면 이 블록은 건너뛰고 입력을 그대로 전달합니다.",
+            "잘못된 정규식:",
+        ),
+        _ => (
+            "Run condition",
+            [
+                "Always run",
+                "Input is not empty",
+                "Contains",
+                "Doesn't contain",
+                "Matches regex",
+                "Not in language",
+            ],
+            "When not met, this block is skipped and its input is passed on unchanged.",
+            "Invalid regex:",
+        ),
+    };
+
+    let selected = match condition {
+        BlockCondition::Always => 0,
+        BlockCondition::NotEmpty => 1,
+        BlockCondition::Contains(_) => 2,
+        BlockCondition::NotContains(_) => 3,
+        BlockCondition::Matches(_) => 4,
+        BlockCondition::NotLanguage(_) => 5,
+    };
+    let title = if selected == 0 {
+        header.to_string()
+    } else {
+        format!("{}: {}", header, kinds[selected])
+    };
+    egui::CollapsingHeader::new(title)
+        .id_salt(ui.id().with("block_condition"))
+        .show(ui, |ui| {
+            ui.label(egui::RichText::new(hint).size(11.0).weak());
+            egui::ComboBox::from_id_salt(ui.id().with("block_condition_kind"))
+                .selected_text(kinds[selected])
+                .show_ui(ui, |ui| {
+                    for (i, kind) in kinds.iter().enumerate() {
+                        if ui.selectable_label(i == selected, *kind).clicked() && i != selected {
+                            // Keep the typed value when switching between kinds that have one
+                            let value = match condition {
+                                BlockCondition::Contains(v)
+                                | BlockCondition::NotContains(v)
+                                | BlockCondition::Matches(v)
+                                | BlockCondition::NotLanguage(v) => std::mem::take(v),
+                                _ => String::new(),
+                            };
+                            *condition = match i {
+                                1 => BlockCondition::NotEmpty,
+                                2 => BlockCondition::Contains(value),
+                                3 => BlockCondition::NotContains(value),
+                                4 => BlockCondition::Matches(value),
+                                5 => BlockCondition::NotLanguage(if value.is_empty() {
+                                    "English".to_string()
+                                } else {
+                                    value
+                                }),
+                                _ => BlockCondition::Always,
+                            };
+                            viewer.changed = true;
+                        }
+                    }
+                });
+
+            let is_regex = matches!(condition, BlockCondition::Matches(_));
+            if let BlockCondition::Contains(value)
+            | BlockCondition::NotContains(value)
+            | BlockCondition::Matches(value)
+            | BlockCondition::NotLanguage(value) = condition
+            {
+                if ui
+                    .add(egui::TextEdit::singleline(value).desired_width(152.0))
+                    .changed()
+                {
+                    viewer.changed = true;
+                }
+                if is_regex {
+                    if let Some(error) = output_regex_error(value) {
+                        ui.label(
+                            egui::RichText::new(format!("{} {}", invalid_label, error))
+                                .size(11.0)
+                                .color(egui::Color32::from_rgb(200, 100, 50)),
+                        );
+                    }
+                }
+            }
+        });
+}
+
+fn show_image_upload(
+    ui: &mut egui::Ui,
+    viewer: &mut ChainViewer,
+    settings: &mut ImageUploadSettings,
+) {
+    let (header, format_label, quality_label, max_size_label, original_label, hint) =
+        match viewer.ui_language.as_str() {
+            "vi" => (
+                "Nén ảnh trước khi gửi",
+                "Định dạng:",
+                "Chất lượng:",
+                "Cạnh dài tối đa:",
+                "Gốc",
+                "PNG gốc giữ chi tiết tốt nhất cho OCR. JPEG và ảnh nhỏ hơn tải lên nhanh hơn, hợp với tóm tắt.",
+            ),
+            "ko" => (
+                "전송 전 이미지 압축",
+                "형식:",
+                "품질:",
+                "최대 긴 변:",
+                "원본",
+                "원본 PNG는 OCR에 가장 정확합니다. JPEG와 작은 크기는 업로드가 빨라 요약에 적합합니다.",
+            ),
+            _ => (
+                "Image compression",
+                "Format:",
+                "Quality:",
+                "Max side:",
+                "Original",
+                "Full-size PNG keeps the most detail for OCR. JPEG and smaller sizes upload faster, fine for summaries.",
+            ),
+        };
+
+    let title = if settings.is_passthrough() {
+        header.to_string()
+    } else {
+        format!("{} ✓", header)
+    };
+    egui::CollapsingHeader::new(title)
+        .id_salt(ui.id().with("image_upload"))
+        .show(ui, |ui| {
+            ui.label(egui::RichText::new(hint).size(11.0).weak());
+            ui.horizontal(|ui| {
+                ui.label(format_label);
+                for (format, name) in [
+                    (ImageUploadFormat::Png, "PNG"),
+                    (ImageUploadFormat::Jpeg, "JPEG"),
+                    (ImageUploadFormat::Webp, "WebP"),
+                ] {
+                    if ui
+                        .selectable_label(settings.format == format, name)
+                        .clicked()
+                    {
+                        settings.format = format;
+                        viewer.changed = true;
+                    }
+                }
+            });
+            if settings.format == ImageUploadFormat::Jpeg {
+                ui.horizontal(|ui| {
+                    ui.label(quality_label);
+                    if ui
+                        .add(egui::Slider::new(&mut settings.quality, 30..=100))
+                        .changed()
+                    {
+                        viewer.changed = true;
+                    }
+                });
+            }
+            ui.horizontal(|ui| {
+                ui.label(max_size_label);
+                let size_name = |dim: u32| {
+                    if dim == 0 {
+                        original_label.to_string()
+                    } else {
+                        format!("{}px", dim)
+                    }
+                };
+                egui::ComboBox::from_id_salt(ui.id().with("image_max_dimension"))
+                    .selected_text(size_name(settings.max_dimension))
+                    .show_ui(ui, |ui| {
+                        for dim in [0, 2048, 1536, 1024, 768] {
+                            if ui
+                                .selectable_label(settings.max_dimension == dim, size_name(dim))
+                                .clicked()
+                            {
+                                settings.max_dimension = dim;
+                                viewer.changed = true;
+                            }
+                        }
+                    });
+            });
+        });
+}
@@ -1,4 +1,5 @@
-use crate::config::ProcessingBlock;
+use crate::config::preset::BlockCondition;
+use crate::config::{ImageUploadSettings, ParallelBranch, ProcessingBlock, ResponseFormat};
 use std::collections::HashMap;
 
 /// Node type for the processing chain
@@ -18,6 +19,9 @@ pub enum ChainNode {
         id: String,
         block_type: String,
         model: String,
+        provider_override: Option<String>,
+        model_override: Option<String>,
+        output_regex: Vec<(String, String)>,
         prompt: String,
         language_vars: HashMap<String, String>,
         show_overlay: bool,
@@ -25,12 +29,17 @@ pub enum ChainNode {
         render_mode: String,
         auto_copy: bool,
         auto_speak: bool,
+        image_upload: ImageUploadSettings,
+        response_format: ResponseFormat,
     },
     /// Processing node (transforms text)
     Process {
         id: String,
         block_type: String,
         model: String,
+        provider_override: Option<String>,
+        model_override: Option<String>,
+        output_regex: Vec<(String, String)>,
         prompt: String,
         language_vars: HashMap<String, String>,
         show_overlay: bool,
@@ -38,6 +47,12 @@ pub enum ChainNode {
         render_mode: String,
         auto_copy: bool,
         auto_speak: bool,
+        image_upload: ImageUploadSettings,
+        response_format: ResponseFormat,
+        condition: BlockCondition,
+        /// Only for block_type "parallel"
+        branches: Vec<ParallelBranch>,
+        parallel_pick: Option<usize>,
     },
 }
 
@@ -53,6 +68,9 @@ impl Default for ChainNode {
             ),
             block_type: "text".to_string(),
             model: "text_accurate_kimi".to_string(),
+            provider_override: None,
+            model_override: None,
+            output_regex: Vec::new(),
             prompt: "Translate to {language1}. Output ONLY the translation.".to_string(),
             language_vars: HashMap::new(),
             show_overlay: true,
@@ -60,6 +78,11 @@ impl Default for ChainNode {
             render_mode: "stream".to_string(),
             auto_copy: false,
             auto_speak: false,
+            image_upload: ImageUploadSettings::default(),
+            response_format: ResponseFormat::Text,
+            condition: BlockCondition::Always,
+            branches: Vec::new(),
+            parallel_pick: None,
         }
     }
 }
@@ -73,6 +96,29 @@ impl ChainNode {
         matches!(self, ChainNode::Special { .. })
     }
 
+    /// A processing node that sends its input to two models at once
+    pub fn new_parallel() -> Self {
+        let mut node = ChainNode::default();
+        if let ChainNode::Process {
+            block_type,
+            model,
+            streaming_enabled,
+            render_mode,
+            branches,
+            ..
+        } = &mut node
+        {
+            *block_type = "parallel".to_string();
+            *branches = vec![
+                ParallelBranch::new(model),
+                ParallelBranch::new("text_gemini_flash_lite"),
+            ];
+            *streaming_enabled = false;
+            *render_mode = "markdown".to_string();
+        }
+        node
+    }
+
     /// Convert to ProcessingBlock for execution
     pub fn to_block(&self) -> ProcessingBlock {
         match self {
@@ -88,6 +134,9 @@ impl ChainNode {
                     id: id.clone(),
                     block_type: "input_adapter".to_string(), // Always adapter for Input Node
                     model: String::new(),
+                    provider_override: None,
+                    model_override: None,
+                    output_regex: Vec::new(),
                     prompt: String::new(),
                     selected_language: String::new(),
                     language_vars: HashMap::new(),
@@ -96,12 +145,20 @@ impl ChainNode {
                     render_mode: render_mode.clone(),
                     auto_copy: *auto_copy,
                     auto_speak: *auto_speak,
+                    image_upload: ImageUploadSettings::default(),
+                    condition: BlockCondition::Always,
+                    branches: Vec::new(),
+                    parallel_pick: None,
+                    response_format: ResponseFormat::Text,
                 }
             }
             ChainNode::Special {
                 id,
                 block_type,
                 model,
+                provider_override,
+                model_override,
+                output_regex,
                 prompt,
                 language_vars,
                 show_overlay,
@@ -109,11 +166,16 @@ impl ChainNode {
                 render_mode,
                 auto_copy,
                 auto_speak,
+                image_upload,
+                response_format,
             }
             | ChainNode::Process {
                 id,
                 block_type,
                 model,
+                provider_override,
+                model_override,
+                output_regex,
                 prompt,
                 language_vars,
                 show_overlay,
@@ -121,10 +183,16 @@ impl ChainNode {
                 render_mode,
                 auto_copy,
                 auto_speak,
+                image_upload,
+                response_format,
+                ..
             } => ProcessingBlock {
                 id: id.clone(),
                 block_type: block_type.clone(),
                 model: model.clone(),
+                provider_override: provider_override.clone(),
+                model_override: model_override.clone(),
+                output_regex: output_regex.clone(),
                 prompt: prompt.clone(),
                 selected_language: language_vars.get("language1").cloned().unwrap_or_default(),
                 language_vars: language_vars.clone(),
@@ -133,6 +201,20 @@ impl ChainNode {
                 render_mode: render_mode.clone(),
                 auto_copy: *auto_copy,
                 auto_speak: *auto_speak,
+                image_upload: *image_upload,
+                response_format: response_format.clone(),
+                condition: match self {
+                    ChainNode::Process { condition, .. } => condition.clone(),
+                    _ => BlockCondition::Always,
+                },
+                branches: match self {
+                    ChainNode::Process { branches, .. } => branches.clone(),
+                    _ => Vec::new(),
+                },
+                parallel_pick: match self {
+                    ChainNode::Process { parallel_pick, .. } => *parallel_pick,
+                    _ => None,
+                },
             },
         }
     }
@@ -172,6 +254,9 @@ impl ChainNode {
                 id: block.id.clone(),
                 block_type: block.block_type.clone(),
                 model: block.model.clone(),
+                provider_override: block.provider_override.clone(),
+                model_override: block.model_override.clone(),
+                output_regex: block.output_regex.clone(),
                 prompt: block.prompt.clone(),
                 language_vars,
                 show_overlay: block.show_overlay,
@@ -179,11 +264,16 @@ impl ChainNode {
                 render_mode: block.render_mode.clone(),
                 auto_copy: block.auto_copy,
                 auto_speak: block.auto_speak,
+                image_upload: block.image_upload,
+                response_format: block.response_format.clone(),
             },
             _ => ChainNode::Process {
                 id: block.id.clone(),
                 block_type: block.block_type.clone(),
                 model: block.model.clone(),
+                provider_override: block.provider_override.clone(),
+                model_override: block.model_override.clone(),
+                output_regex: block.output_regex.clone(),
                 prompt: block.prompt.clone(),
                 language_vars,
                 show_overlay: block.show_overlay,
@@ -191,6 +281,11 @@ impl ChainNode {
                 render_mode: block.render_mode.clone(),
                 auto_copy: block.auto_copy,
                 auto_speak: block.auto_speak,
+                image_upload: block.image_upload,
+                response_format: block.response_format.clone(),
+                condition: block.condition.clone(),
+                branches: block.branches.clone(),
+                parallel_pick: block.parallel_pick,
             },
         }
     }
@@ -1,5 +1,6 @@
 use crate::config::get_all_languages;
 use crate::model_config::model_supports_search_by_id;
+use crate::overlay::process::prompt_vars::{unknown_variables, PROMPT_VARIABLES};
 use eframe::egui;
 use std::collections::HashMap;
 
@@ -116,6 +117,54 @@ pub fn show_language_vars(
     }
 }
 
+/// Insert buttons for the run-time prompt variables, plus a warning for
+/// placeholders that aren't one (most likely typos; they are sent as written)
+pub fn show_prompt_variables(
+    ui: &mut egui::Ui,
+    ui_language: &str,
+    prompt: &mut String,
+    changed: &mut bool,
+) {
+    let header = match ui_language {
+        "vi" => "Biến",
+        "ko" => "변수",
+        _ => "Variables",
+    };
+    egui::CollapsingHeader::new(egui::RichText::new(header).small())
+        .id_salt(ui.id().with("prompt_vars"))
+        .default_open(false)
+        .show(ui, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                ui.set_max_width(152.0);
+                for (name, description) in PROMPT_VARIABLES {
+                    if ui
+                        .small_button(format!("{{{}}}", name))
+                        .on_hover_text(description)
+                        .clicked()
+                    {
+                        prompt.push_str(&format!(" {{{}}}", name));
+                        *changed = true;
+                    }
+                }
+            });
+        });
+
+    let unknown = unknown_variables(prompt);
+    if !unknown.is_empty() {
+        let label = match ui_language {
+            "vi" => "Biến không rõ (gửi nguyên văn):",
+            "ko" => "알 수 없는 변수 (그대로 전송):",
+            _ => "Unknown variables (sent as written):",
+        };
+        let names: Vec<String> = unknown.iter().map(|n| format!("{{{}}}", n)).collect();
+        ui.label(
+            egui::RichText::new(format!("⚠ {} {}", label, names.join(" ")))
+                .small()
+                .color(egui::Color32::from_rgb(220, 150, 60)),
+        );
+    }
+}
+
 pub fn insert_next_language_tag(prompt: &mut String, language_vars: &mut HashMap<String, String>) {
     let mut max_num = 0;
     for k in 1..=10 {
@@ -120,9 +120,17 @@ impl<'a> SnarlViewer<ChainNode> for ChainViewer<'a> {
                     let prefix = self.text.node_input_prefix;
                     ui.label(format!("{} {}", prefix, type_name));
                 }
-                ChainNode::Process { .. } => {
+                ChainNode::Process { block_type, .. } => {
                     draw_icon_static(ui, Icon::Settings, Some(16.0));
-                    let title = self.text.node_process_title;
+                    let title = if block_type == "parallel" {
+                        match self.ui_language.as_str() {
+                            "vi" => "Song song",
+                            "ko" => "병렬",
+                            _ => "Parallel",
+                        }
+                    } else {
+                        self.text.node_process_title
+                    };
                     ui.label(title);
                 }
 
@@ -212,6 +220,16 @@ impl<'a> SnarlViewer<ChainNode> for ChainViewer<'a> {
             self.changed = true;
             ui.close();
         }
+        let add_parallel_label = match self.ui_language.as_str() {
+            "vi" => "➕ Thêm node song song (nhiều mô hình)",
+            "ko" => "➕ 병렬 노드 추가 (여러 모델)",
+            _ => "➕ Add parallel node (several models)",
+        };
+        if ui.button(add_parallel_label).clicked() {
+            snarl.insert_node(pos, ChainNode::new_parallel());
+            self.changed = true;
+            ui.close();
+        }
         if self.preset_type != "text" {
             if ui.button(add_special_label).clicked() {
                 let mut node = ChainNode::default();
@@ -220,6 +238,9 @@ impl<'a> SnarlViewer<ChainNode> for ChainViewer<'a> {
                     id,
                     block_type,
                     model,
+                    provider_override,
+                    model_override,
+                    output_regex,
                     prompt,
                     language_vars,
                     show_overlay,
@@ -227,12 +248,18 @@ impl<'a> SnarlViewer<ChainNode> for ChainViewer<'a> {
                     render_mode,
                     auto_copy,
                     auto_speak,
+                    image_upload,
+                    response_format,
+                    ..
                 } = node
                 {
                     node = ChainNode::Special {
                         id,
                         block_type,
                         model,
+                        provider_override,
+                        model_override,
+                        output_regex,
                         prompt,
                         language_vars,
                         show_overlay,
@@ -240,6 +267,8 @@ impl<'a> SnarlViewer<ChainNode> for ChainViewer<'a> {
                         render_mode,
                         auto_copy,
                         auto_speak,
+                        image_upload,
+                        response_format,
                     };
                 }
                 snarl.insert_node(pos, node);
@@ -1,5 +1,5 @@
 use eframe::egui;
-use crate::config::{Config, ProcessingBlock};
+use crate::config::{AutoCopyMode, AutoCopyTarget, Config, PostCommandMode, ProcessingBlock};
 use crate::gui::locale::LocaleText;
 use super::get_localized_preset_name;
 use egui_snarl::Snarl;
@@ -56,12 +56,12 @@ pub fn render_preset_editor(
         .show(ui, |ui| {
             // Row 1: Preset Name + Controller + Restore
             ui.horizontal(|ui| {
-                ui.label(egui::RichText::new(text.preset_name_label).strong());
+                let name_label = ui.label(egui::RichText::new(text.preset_name_label).strong());
                 
                 if is_default_preset {
                     ui.label(egui::RichText::new(&display_name).strong().size(15.0));
                 } else {
-                    if ui.add(egui::TextEdit::singleline(&mut preset.name).font(egui::TextStyle::Body)).changed() {
+                    if ui.add(egui::TextEdit::singleline(&mut preset.name).font(egui::TextStyle::Body)).labelled_by(name_label.id).changed() {
                         changed = true;
                     }
                 }
@@ -110,7 +110,7 @@ pub fn render_preset_editor(
             
             // Row 2: Type + Mode selectors
             ui.horizontal(|ui| {
-                ui.label(text.preset_type_label);
+                let type_label = ui.label(text.preset_type_label);
                 let selected_text = match preset.preset_type.as_str() {
                     "audio" => text.preset_type_audio,
                     "video" => text.preset_type_video,
@@ -145,29 +145,37 @@ pub fn render_preset_editor(
                         ui.add_enabled_ui(false, |ui| {
                             let _ = ui.selectable_value(&mut preset.preset_type, "video".to_string(), text.preset_type_video);
                         });
-                    });
+                    })
+                    .response
+                    .labelled_by(type_label.id);
 
                 ui.add_space(15.0);
 
                 // Mode selectors based on type
                 if preset.preset_type == "image" {
                     if !preset.show_controller_ui {
-                        ui.label(text.command_mode_label);
+                        let command_label = ui.label(text.command_mode_label);
                         egui::ComboBox::from_id_salt("prompt_mode_combo")
                             .selected_text(if preset.prompt_mode == "dynamic" { text.prompt_mode_dynamic } else { text.prompt_mode_fixed })
                             .show_ui(ui, |ui| {
                                 if ui.selectable_value(&mut preset.prompt_mode, "fixed".to_string(), text.prompt_mode_fixed).clicked() { changed = true; }
                                 if ui.selectable_value(&mut preset.prompt_mode, "dynamic".to_string(), text.prompt_mode_dynamic).clicked() { changed = true; }
-                            });
+                            })
+                            .response
+                            .labelled_by(command_label.id);
                     }
+                    if ui.checkbox(&mut preset.remember_selection, text.remember_selection_label).on_hover_text(text.remember_selection_tooltip).clicked() { changed = true; }
+                    if ui.checkbox(&mut preset.redact_before_send, text.redact_before_send_label).on_hover_text(text.redact_before_send_tooltip).clicked() { changed = true; }
                 } else if preset.preset_type == "text" {
-                    ui.label(text.text_input_mode_label);
+                    let input_mode_label = ui.label(text.text_input_mode_label);
                     egui::ComboBox::from_id_salt("text_input_mode_combo")
                         .selected_text(if preset.text_input_mode == "type" { text.text_mode_type } else { text.text_mode_select })
                         .show_ui(ui, |ui| {
                             if ui.selectable_value(&mut preset.text_input_mode, "select".to_string(), text.text_mode_select).clicked() { changed = true; }
                             if ui.selectable_value(&mut preset.text_input_mode, "type".to_string(), text.text_mode_type).clicked() { changed = true; }
-                        });
+                        })
+                        .response
+                        .labelled_by(input_mode_label.id);
                     
                     if preset.text_input_mode == "type" && !preset.show_controller_ui {
                         if ui.checkbox(&mut preset.continuous_input, text.continuous_input_label).clicked() { changed = true; }
@@ -179,7 +187,7 @@ pub fn render_preset_editor(
                             "ko" => "작동 방식:",
                             _ => "Mode:",
                         };
-                        ui.label(mode_label);
+                        let mode_label_response = ui.label(mode_label);
                         
                         let mode_record = match config.ui_language.as_str() {
                             "vi" => "Thu âm rồi xử lý",
@@ -203,7 +211,9 @@ pub fn render_preset_editor(
                             .show_ui(ui, |ui| {
                                 if ui.selectable_value(&mut preset.audio_processing_mode, "record_then_process".to_string(), mode_record).clicked() { changed = true; }
                                 if ui.selectable_value(&mut preset.audio_processing_mode, "realtime".to_string(), mode_realtime).clicked() { changed = true; }
-                            });
+                            })
+                            .response
+                            .labelled_by(mode_label_response.id);
 
 
                     }
@@ -219,7 +229,7 @@ pub fn render_preset_editor(
                           "ko" => "인터페이스:",
                           _ => "Interface:",
                       };
-                      ui.label(window_mode_label);
+                      let window_mode_label_response = ui.label(window_mode_label);
 
                       let mode_standard = match config.ui_language.as_str() {
                           "vi" => "Tiêu chuẩn",
@@ -243,7 +253,99 @@ pub fn render_preset_editor(
                           .show_ui(ui, |ui| {
                               if ui.selectable_value(&mut preset.realtime_window_mode, "standard".to_string(), mode_standard).clicked() { changed = true; }
                               if ui.selectable_value(&mut preset.realtime_window_mode, "minimal".to_string(), mode_minimal).clicked() { changed = true; }
-                          });
+                          })
+                          .response
+                          .labelled_by(window_mode_label_response.id);
+
+                      ui.add_space(10.0);
+                      let dictation_label = match config.ui_language.as_str() {
+                          "vi" => "Gõ vào ứng dụng",
+                          "ko" => "앱에 입력",
+                          _ => "Type into app",
+                      };
+                      let dictation_hint = match config.ui_language.as_str() {
+                          "vi" => "Gõ lời nói vào ứng dụng đang mở như phần mềm đọc chính tả. Nói \"dấu phẩy\", \"xuống dòng\"... để chèn dấu câu",
+                          "ko" => "받아쓰기 프로그램처럼 말한 내용을 현재 앱에 바로 입력합니다. \"쉼표\", \"줄 바꿈\" 등으로 문장 부호를 넣습니다",
+                          _ => "Type what is said into the focused app, like dictation software. Say \"comma\", \"new line\"... for punctuation",
+                      };
+                      if ui.checkbox(&mut preset.realtime_dictation, dictation_label).on_hover_text(dictation_hint).clicked() { changed = true; }
+                 });
+            }
+
+            // Row 2.6: Silence threshold (global, shared by all realtime presets)
+            if preset.preset_type == "audio" && preset.audio_processing_mode == "realtime" {
+                 ui.add_space(6.0);
+                 ui.horizontal(|ui| {
+                      let vad_label = match config.ui_language.as_str() {
+                          "vi" => "Ngưỡng im lặng:",
+                          "ko" => "무음 기준:",
+                          _ => "Silence threshold:",
+                      };
+                      let vad_hint = match config.ui_language.as_str() {
+                          "vi" => "Dưới mức âm lượng này quá 2 giây thì tạm dừng dịch (0 = tắt)",
+                          "ko" => "이 음량 아래로 2초 이상 지속되면 번역을 일시 중지합니다 (0 = 끄기)",
+                          _ => "Translation pauses after 2 seconds below this level (0 = off)",
+                      };
+                      ui.label(vad_label);
+                      let slider = ui.add(egui::Slider::new(&mut config.realtime_vad_threshold, 0.0..=0.05).fixed_decimals(3))
+                          .on_hover_text(vad_hint);
+                      if slider.changed() {
+                          crate::api::realtime_audio::vad::set_threshold(config.realtime_vad_threshold);
+                          changed = true;
+                      }
+                 });
+
+                 // Translation cadence (also global)
+                 ui.add_space(6.0);
+                 ui.horizontal(|ui| {
+                      let interval_label = match config.ui_language.as_str() {
+                          "vi" => "Chu kỳ dịch:",
+                          "ko" => "번역 주기:",
+                          _ => "Translate every:",
+                      };
+                      let sentence_label = match config.ui_language.as_str() {
+                          "vi" => "Dịch theo câu",
+                          "ko" => "문장 단위로 번역",
+                          _ => "At sentence ends",
+                      };
+                      let sentence_hint = match config.ui_language.as_str() {
+                          "vi" => "Chỉ dịch khi hết câu hoặc người nói ngừng, giảm việc dịch lại giữa câu",
+                          "ko" => "문장이 끝나거나 말이 멈출 때만 번역하여 문장 중간 재번역을 줄입니다",
+                          _ => "Translate only when a sentence ends or the speaker pauses, so half sentences aren't retranslated",
+                      };
+                      ui.label(interval_label);
+                      if ui.add(egui::Slider::new(&mut config.realtime_translation_interval_ms, crate::api::realtime_audio::TRANSLATION_INTERVAL_RANGE_MS).step_by(100.0).suffix(" ms")).changed() {
+                          changed = true;
+                      }
+                      if ui.checkbox(&mut config.realtime_translate_on_sentence, sentence_label).on_hover_text(sentence_hint).changed() {
+                          changed = true;
+                      }
+                 });
+
+                 // Session notes autosave (also global)
+                 ui.add_space(6.0);
+                 ui.horizontal(|ui| {
+                      let autosave_label = match config.ui_language.as_str() {
+                          "vi" => "Tự lưu ghi chú vào:",
+                          "ko" => "노트 자동 저장 위치:",
+                          _ => "Auto-save notes to:",
+                      };
+                      let autosave_hint = match config.ui_language.as_str() {
+                          "vi" => "Ghi từng câu đã chốt vào một tệp markdown theo ngày giờ trong khi phiên đang chạy",
+                          "ko" => "세션 중 확정된 문장을 날짜별 마크다운 파일에 바로 기록합니다",
+                          _ => "Write each finished sentence to a dated markdown file while the session runs",
+                      };
+                      if ui.checkbox(&mut config.realtime_autosave, autosave_label).on_hover_text(autosave_hint).changed() {
+                          changed = true;
+                      }
+                      ui.add_enabled_ui(config.realtime_autosave, |ui| {
+                          let default_dir = crate::api::realtime_audio::notes::default_notes_dir();
+                          if ui.add(egui::TextEdit::singleline(&mut config.realtime_autosave_dir)
+                              .hint_text(default_dir.to_string_lossy())
+                              .desired_width(220.0)).changed() {
+                              changed = true;
+                          }
+                      });
                  });
             }
 
@@ -251,19 +353,23 @@ pub fn render_preset_editor(
             if preset.preset_type == "audio" && preset.audio_processing_mode != "realtime" {
                 ui.add_space(6.0);
                 ui.horizontal(|ui| {
-                    ui.label(text.audio_source_label);
+                    let source_label = ui.label(text.audio_source_label);
                     let selected_text = if preset.audio_source == "mic" { text.audio_src_mic } else { text.audio_src_device };
                     egui::ComboBox::from_id_salt("audio_source_combo")
                         .selected_text(selected_text)
                         .show_ui(ui, |ui| {
                             if ui.selectable_value(&mut preset.audio_source, "mic".to_string(), text.audio_src_mic).clicked() { changed = true; }
                             if ui.selectable_value(&mut preset.audio_source, "device".to_string(), text.audio_src_device).clicked() { changed = true; }
-                        });
+                        })
+                        .response
+                        .labelled_by(source_label.id);
                     if !preset.show_controller_ui {
                         ui.add_space(10.0);
                         if ui.checkbox(&mut preset.hide_recording_ui, text.hide_recording_ui_label).clicked() { changed = true; }
                         ui.add_space(6.0);
                         if ui.checkbox(&mut preset.auto_stop_recording, text.auto_stop_recording_label).clicked() { changed = true; }
+                        ui.add_space(6.0);
+                        if ui.checkbox(&mut preset.pronunciation_practice, text.pronunciation_practice_label).on_hover_text(text.pronunciation_practice_tooltip).clicked() { changed = true; }
                     }
                 });
             }
@@ -272,21 +378,54 @@ pub fn render_preset_editor(
             if preset.preset_type == "text" && preset.text_input_mode == "select" && !preset.show_controller_ui {
                 ui.add_space(6.0);
                 ui.horizontal(|ui| {
-                    ui.label(text.command_mode_label);
+                    let command_label = ui.label(text.command_mode_label);
                     egui::ComboBox::from_id_salt("text_prompt_mode_combo")
                         .selected_text(if preset.prompt_mode == "dynamic" { text.prompt_mode_dynamic } else { text.prompt_mode_fixed })
                         .show_ui(ui, |ui| {
                             if ui.selectable_value(&mut preset.prompt_mode, "fixed".to_string(), text.prompt_mode_fixed).clicked() { changed = true; }
                             if ui.selectable_value(&mut preset.prompt_mode, "dynamic".to_string(), text.prompt_mode_dynamic).clicked() { changed = true; }
-                        });
+                        })
+                        .response
+                        .labelled_by(command_label.id);
                 });
             }
         });
 
     ui.add_space(8.0);
 
+    // Auto-copy: which results get copied, and whether they replace the clipboard
+    if !preset.show_controller_ui {
+        ui.horizontal(|ui| {
+            let target_label = ui.label(text.auto_copy_target_label);
+            let target_name = |target: AutoCopyTarget| match target {
+                AutoCopyTarget::PerBlock => text.auto_copy_per_block,
+                AutoCopyTarget::FinalBlock => text.auto_copy_final_block,
+                AutoCopyTarget::EachBlock => text.auto_copy_each_block,
+                AutoCopyTarget::None => text.auto_copy_off,
+            };
+            egui::ComboBox::from_id_salt("auto_copy_target_combo")
+                .selected_text(target_name(preset.auto_copy_target))
+                .show_ui(ui, |ui| {
+                    for target in [AutoCopyTarget::PerBlock, AutoCopyTarget::FinalBlock, AutoCopyTarget::EachBlock, AutoCopyTarget::None] {
+                        if ui.selectable_value(&mut preset.auto_copy_target, target, target_name(target)).clicked() { changed = true; }
+                    }
+                })
+                .response
+                .labelled_by(target_label.id);
+
+            if preset.has_auto_copy() {
+                let mut append = preset.auto_copy_mode == AutoCopyMode::Append;
+                if ui.checkbox(&mut append, text.auto_copy_append_label).on_hover_text(text.auto_copy_append_tooltip).clicked() {
+                    preset.auto_copy_mode = if append { AutoCopyMode::Append } else { AutoCopyMode::Replace };
+                    changed = true;
+                }
+            }
+        });
+        ui.add_space(4.0);
+    }
+
     // Determine visibility conditions
-    let has_any_auto_copy = preset.blocks.iter().any(|b| b.auto_copy);
+    let has_any_auto_copy = preset.has_auto_copy();
     
     // Show auto-paste control whenever any block has auto_copy enabled AND controller UI is off
     if has_any_auto_copy && !preset.show_controller_ui {
@@ -296,8 +435,9 @@ pub fn render_preset_editor(
             // Auto Newline: visible when any block has auto_copy
             // BUT hide it ONLY if the auto-copy block is an input_adapter (image input node).
             // "image" block type is a processing node that outputs text, so newline is applicable.
+            // (only the per-block target can copy the input node)
             let auto_copy_block = preset.blocks.iter().find(|b| b.auto_copy);
-            let is_input_node_only_copy = if let Some(block) = auto_copy_block {
+            let is_input_node_only_copy = if let (AutoCopyTarget::PerBlock, Some(block)) = (preset.auto_copy_target, auto_copy_block) {
                  // Only hide for input_adapter (pure image copy, no text output)
                  block.block_type == "input_adapter"
             } else {
@@ -307,6 +447,16 @@ pub fn render_preset_editor(
             if !is_input_node_only_copy {
                  if ui.checkbox(&mut preset.auto_paste_newline, text.auto_paste_newline_label).clicked() { changed = true; }
             }
+
+            // Typing only makes sense for text; an image can only be pasted
+            if preset.auto_paste && !is_input_node_only_copy {
+                let (typing_label, typing_hint) = match config.ui_language.as_str() {
+                    "vi" => ("Gõ thay vì dán", "Gõ kết quả từng ký tự thay vì Ctrl+V, cho các ứng dụng dán sai định dạng hoặc chặn dán"),
+                    "ko" => ("붙여넣기 대신 입력", "Ctrl+V 대신 결과를 한 글자씩 입력합니다. 붙여넣기를 망가뜨리거나 막는 앱에 사용하세요"),
+                    _ => ("Type instead of paste", "Type the result character by character instead of Ctrl+V, for apps that mangle or block pasting"),
+                };
+                if ui.checkbox(&mut preset.auto_paste_typing, typing_label).on_hover_text(typing_hint).clicked() { changed = true; }
+            }
         });
     } else if !has_any_auto_copy {
         // No auto_copy means auto_paste must be off
@@ -316,6 +466,11 @@ pub fn render_preset_editor(
         }
     }
 
+    // Arena: the first two text branches share one side-by-side window
+    if preset.preset_type != "audio" && !preset.show_controller_ui {
+        if ui.checkbox(&mut preset.arena_mode, text.arena_mode_label).on_hover_text(text.arena_mode_tooltip).clicked() { changed = true; }
+    }
+
     ui.add_space(10.0);
 
     // Hotkeys - always visible, even when controller UI is enabled
@@ -368,11 +523,18 @@ pub fn render_preset_editor(
         
         let mut hotkey_to_remove = None;
         for (h_idx, hotkey) in preset.hotkeys.iter().enumerate() {
-            if ui.add(egui::Button::new(egui::RichText::new(format!("{} ×", hotkey.name)).color(egui::Color32::WHITE).small())
-                .fill(hotkey_bg)
+            // Taken by another app or Windows: still listed, but flagged
+            let unavailable = crate::is_hotkey_unavailable(hotkey);
+            let label = if unavailable { format!("⚠ {} ×", hotkey.name) } else { format!("{} ×", hotkey.name) };
+            let fill = if unavailable { egui::Color32::from_rgb(170, 90, 40) } else { hotkey_bg };
+            let mut response = ui.add(egui::Button::new(egui::RichText::new(label).color(egui::Color32::WHITE).small())
+                .fill(fill)
                 .corner_radius(10.0))
-                .on_hover_cursor(egui::CursorIcon::PointingHand)
-                .clicked() { 
+                .on_hover_cursor(egui::CursorIcon::PointingHand);
+            if unavailable {
+                response = response.on_hover_text(text.hotkey_unavailable_tooltip);
+            }
+            if response.clicked() { 
                 hotkey_to_remove = Some(h_idx); 
             }
         }
@@ -384,6 +546,97 @@ pub fn render_preset_editor(
         }
     }
 
+    // Secondary language: hotkey + Shift translates into this language instead
+    if !preset.is_master && !preset.hotkeys.is_empty() && !(preset.preset_type == "audio" && preset.audio_processing_mode == "realtime") {
+        ui.horizontal(|ui| {
+            let secondary_label = match config.ui_language.as_str() {
+                "vi" => "Giữ Shift để dịch sang:",
+                "ko" => "Shift를 누르면 번역할 언어:",
+                _ => "Hold Shift to translate to:",
+            };
+            let secondary_hint = match config.ui_language.as_str() {
+                "vi" => "Nhấn phím tắt kèm Shift để dùng ngôn ngữ này cho lần chạy đó, không cần sửa preset",
+                "ko" => "단축키와 Shift를 함께 누르면 프리셋을 수정하지 않고 이번 실행만 이 언어로 번역합니다",
+                _ => "Press the hotkey with Shift to translate into this language for that run, without editing the preset",
+            };
+            let none_label = match config.ui_language.as_str() {
+                "vi" => "Không",
+                "ko" => "없음",
+                _ => "None",
+            };
+            let secondary_label_response = ui.label(secondary_label).on_hover_text(secondary_hint);
+            let selected = if preset.secondary_language.is_empty() { none_label } else { preset.secondary_language.as_str() };
+            egui::ComboBox::from_id_salt("secondary_language_combo")
+                .selected_text(selected)
+                .height(250.0)
+                .show_ui(ui, |ui| {
+                    if ui.selectable_label(preset.secondary_language.is_empty(), none_label).clicked() && !preset.secondary_language.is_empty() {
+                        preset.secondary_language.clear();
+                        changed = true;
+                    }
+                    for lang in crate::config::get_all_languages() {
+                        if ui.selectable_label(preset.secondary_language == *lang, lang).clicked() && preset.secondary_language != *lang {
+                            preset.secondary_language = lang.clone();
+                            changed = true;
+                        }
+                    }
+                })
+                .response
+                .labelled_by(secondary_label_response.id);
+        });
+    }
+
+    // Opt out of the global prompt prefix/suffix (only offered once one is set)
+    let has_global_prompt = !config.global_system_prefix.trim().is_empty() || !config.global_system_suffix.trim().is_empty();
+    if has_global_prompt && !(preset.preset_type == "audio" && preset.audio_processing_mode == "realtime") {
+        let skip_label = match config.ui_language.as_str() {
+            "vi" => "Không dùng lệnh chung cho preset này",
+            "ko" => "이 프리셋에는 공통 지시문 사용 안 함",
+            _ => "Don't add the global instructions to this preset",
+        };
+        if ui.checkbox(&mut preset.skip_global_prompt, skip_label).clicked() { changed = true; }
+    }
+
+    // Close result windows by themselves after a while without the mouse over them
+    if !(preset.preset_type == "audio" && preset.audio_processing_mode == "realtime") && !preset.show_controller_ui {
+        let (dismiss_label, dismiss_hint, secs_suffix) = match config.ui_language.as_str() {
+            "vi" => ("Tự đóng cửa sổ kết quả sau", "Đếm ngược khi chuột không ở trên cửa sổ (viền sáng thu dần), đưa chuột vào để bắt đầu lại", " giây"),
+            "ko" => ("결과 창 자동 닫기:", "마우스가 창 위에 없을 때 카운트다운합니다 (테두리 빛이 줄어듦). 마우스를 올리면 다시 시작합니다", "초 후"),
+            _ => ("Close result windows after", "Counts down while the mouse is off the window (the lit border shrinks); hovering it starts over", " s"),
+        };
+        ui.horizontal(|ui| {
+            let mut enabled = preset.result_auto_dismiss_secs.is_some();
+            if ui.checkbox(&mut enabled, dismiss_label).on_hover_text(dismiss_hint).clicked() {
+                preset.result_auto_dismiss_secs = enabled.then_some(10);
+                changed = true;
+            }
+            if let Some(secs) = preset.result_auto_dismiss_secs.as_mut() {
+                if ui.add(egui::DragValue::new(secs).range(1..=600).suffix(secs_suffix)).changed() { changed = true; }
+            }
+        });
+    }
+
+    // Command that gets the final result on stdin; its output replaces or extends the result
+    if !(preset.preset_type == "audio" && preset.audio_processing_mode == "realtime") {
+        let (command_label, command_hint, replace_label, append_label) = match config.ui_language.as_str() {
+            "vi" => ("Chạy lệnh với kết quả:", "Lệnh (qua cmd) nhận kết quả cuối cùng qua stdin, những gì nó in ra sẽ thay thế hoặc thêm vào kết quả. Không in gì thì kết quả giữ nguyên. Hỏi xác nhận trước lần chạy đầu tiên", "Thay thế", "Thêm vào"),
+            "ko" => ("결과로 명령 실행:", "명령(cmd 사용)이 최종 결과를 stdin으로 받고, 출력이 결과를 대체하거나 뒤에 추가됩니다. 아무것도 출력하지 않으면 결과는 그대로입니다. 처음 실행하기 전에 확인합니다", "대체", "추가"),
+            _ => ("Run command on result:", "The command (run through cmd) gets the final result on stdin; what it prints replaces the result or is added after it. Printing nothing keeps the result. Asks before it first runs", "Replace", "Append"),
+        };
+        ui.horizontal(|ui| {
+            ui.label(command_label).on_hover_text(command_hint);
+            if ui.add(egui::TextEdit::singleline(&mut preset.post_command).desired_width(220.0).hint_text("python C:\\scripts\\notes.py")).changed() { changed = true; }
+            if !preset.post_command.trim().is_empty() {
+                egui::ComboBox::from_id_salt("post_command_mode")
+                    .selected_text(match preset.post_command_mode { PostCommandMode::Replace => replace_label, PostCommandMode::Append => append_label })
+                    .show_ui(ui, |ui| {
+                        if ui.selectable_value(&mut preset.post_command_mode, PostCommandMode::Replace, replace_label).clicked() { changed = true; }
+                        if ui.selectable_value(&mut preset.post_command_mode, PostCommandMode::Append, append_label).clicked() { changed = true; }
+                    });
+            }
+        });
+    }
+
     // --- PROCESSING CHAIN UI ---
     // Hide nodegraph when controller UI is enabled OR when in Realtime mode (no graph needed)
     if !preset.show_controller_ui && !(preset.preset_type == "audio" && preset.audio_processing_mode == "realtime") {
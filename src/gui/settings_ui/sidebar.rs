@@ -3,6 +3,7 @@ use crate::config::{Config, Preset, ThemeMode};
 use crate::gui::icons::{draw_icon_static, icon_button_sized, Icon};
 use crate::gui::locale::LocaleText;
 use eframe::egui;
+use eframe::egui::collapsing_header::CollapsingState;
 
 /// Get localized preset name for default presets (public for reuse in other modules)
 pub fn get_localized_preset_name(preset_id: &str, lang_code: &str) -> String {
@@ -52,6 +53,7 @@ pub fn get_localized_preset_name(preset_id: &str, lang_code: &str) -> String {
         ("preset_hang_text", "vi") => "Treo text".to_string(),
         ("preset_quick_note", "vi") => "Note nhanh".to_string(),
         ("preset_quick_record", "vi") => "Thu âm nhanh".to_string(),
+        ("preset_continuous_writing_online", "vi") => "Viết liên tục (online)".to_string(),
         ("preset_record_device", "vi") => "Thu âm máy".to_string(),
         // MASTER presets - Vietnamese
         ("preset_image_master", "vi") => "Ảnh MASTER".to_string(),
@@ -102,6 +104,7 @@ pub fn get_localized_preset_name(preset_id: &str, lang_code: &str) -> String {
         ("preset_hang_text", "ko") => "텍스트 오버레이".to_string(),
         ("preset_quick_note", "ko") => "빠른 메모".to_string(),
         ("preset_quick_record", "ko") => "빠른 녹음".to_string(),
+        ("preset_continuous_writing_online", "ko") => "연속 받아쓰기 (온라인)".to_string(),
         ("preset_record_device", "ko") => "시스템 녹음".to_string(),
         // MASTER presets - Korean
         ("preset_image_master", "ko") => "이미지 마스터".to_string(),
@@ -152,6 +155,7 @@ pub fn get_localized_preset_name(preset_id: &str, lang_code: &str) -> String {
         ("preset_hang_text", _) => "Text Overlay".to_string(),
         ("preset_quick_note", _) => "Quick Note".to_string(),
         ("preset_quick_record", _) => "Quick Record".to_string(),
+        ("preset_continuous_writing_online", _) => "Continuous writing (online)".to_string(),
         ("preset_record_device", _) => "Device Record".to_string(),
         // MASTER presets - English (default)
         ("preset_image_master", _) => "Image MASTER".to_string(),
@@ -168,10 +172,205 @@ pub fn get_localized_preset_name(preset_id: &str, lang_code: &str) -> String {
     }
 }
 
+/// Split presets into the three sidebar columns: image, text, audio/video.
+/// Indices keep the order of `config.presets` so user reordering is respected.
+fn sidebar_columns(presets: &[Preset]) -> [Vec<usize>; 3] {
+    let mut image_indices = Vec::new();
+    let mut text_indices = Vec::new();
+    let mut audio_video_indices = Vec::new();
+
+    for (i, p) in presets.iter().enumerate() {
+        match p.preset_type.as_str() {
+            "image" => image_indices.push(i),
+            "text" => text_indices.push(i),
+            "audio" | "video" => audio_video_indices.push(i),
+            _ => image_indices.push(i),
+        }
+    }
+
+    [image_indices, text_indices, audio_video_indices]
+}
+
+/// A run of presets in the compact sidebar, headed by the MASTER preset that ends it
+/// in the column layout. Presets after the last MASTER have no header.
+struct CompactSection {
+    master: Option<usize>,
+    items: Vec<usize>,
+}
+
+fn compact_sections(column: &[usize], presets: &[Preset]) -> Vec<CompactSection> {
+    let mut sections = Vec::new();
+    let mut items = Vec::new();
+    for &idx in column {
+        if presets[idx].is_master {
+            sections.push(CompactSection {
+                master: Some(idx),
+                items: std::mem::take(&mut items),
+            });
+        } else {
+            items.push(idx);
+        }
+    }
+    if !items.is_empty() {
+        sections.push(CompactSection {
+            master: None,
+            items,
+        });
+    }
+    sections
+}
+
+/// Display order of the compact layout (each MASTER before the presets it heads)
+fn compact_order(presets: &[Preset]) -> Vec<usize> {
+    sidebar_columns(presets)
+        .iter()
+        .flat_map(|column| compact_sections(column, presets))
+        .flat_map(|section| section.master.into_iter().chain(section.items))
+        .collect()
+}
+
+/// Fill colors of the "+ Image" / "+ Text" / "+ Audio" buttons
+fn add_button_fills(is_dark: bool) -> [egui::Color32; 3] {
+    if is_dark {
+        [
+            egui::Color32::from_rgb(45, 85, 140),
+            egui::Color32::from_rgb(45, 120, 80),
+            egui::Color32::from_rgb(150, 95, 40),
+        ]
+    } else {
+        [
+            egui::Color32::from_rgb(100, 150, 220),
+            egui::Color32::from_rgb(90, 180, 120),
+            egui::Color32::from_rgb(220, 160, 80),
+        ]
+    }
+}
+
+fn add_preset_button(ui: &mut egui::Ui, label: &str, fill: egui::Color32) -> bool {
+    ui.add(
+        egui::Button::new(
+            egui::RichText::new(label)
+                .color(egui::Color32::WHITE)
+                .strong(),
+        )
+        .fill(fill)
+        .corner_radius(12.0),
+    )
+    .clicked()
+}
+
+/// Arrow-key navigation through the preset grid.
+///
+/// Up/Down move within a column, Left/Right jump between columns (same row, clamped),
+/// Enter opens the focused preset and Escape drops the keyboard focus.
+/// The compact layout is treated as a single column.
+/// Callers must only invoke this when no text field or modal owns the keyboard.
+pub fn handle_sidebar_keyboard_nav(
+    ctx: &egui::Context,
+    config: &Config,
+    view_mode: &mut ViewMode,
+    focused_preset: &mut Option<usize>,
+) {
+    if ctx.wants_keyboard_input() || egui::Popup::is_any_open(ctx) {
+        return;
+    }
+
+    let columns = if config.sidebar_layout == "compact" {
+        [compact_order(&config.presets), Vec::new(), Vec::new()]
+    } else {
+        sidebar_columns(&config.presets)
+    };
+    let locate = |idx: usize| -> Option<(usize, usize)> {
+        columns
+            .iter()
+            .enumerate()
+            .find_map(|(col, items)| items.iter().position(|&i| i == idx).map(|row| (col, row)))
+    };
+
+    // Drop stale focus (preset deleted / moved to another column)
+    if focused_preset.is_some_and(|idx| locate(idx).is_none()) {
+        *focused_preset = None;
+    }
+
+    let (up, down, left, right, enter, escape) = ctx.input_mut(|i| {
+        (
+            i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowLeft),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowRight),
+            focused_preset.is_some() && i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+            focused_preset.is_some() && i.consume_key(egui::Modifiers::NONE, egui::Key::Escape),
+        )
+    });
+
+    if escape {
+        *focused_preset = None;
+        return;
+    }
+
+    if enter {
+        if let Some(idx) = *focused_preset {
+            if !config.presets[idx].is_upcoming {
+                *view_mode = ViewMode::Preset(idx);
+            }
+        }
+        return;
+    }
+
+    if !(up || down || left || right) {
+        return;
+    }
+
+    // First key press only reveals the focus ring on the selected (or first) preset
+    let Some(current) = *focused_preset else {
+        *focused_preset = match *view_mode {
+            ViewMode::Preset(idx) if locate(idx).is_some() => Some(idx),
+            _ => columns.iter().find_map(|items| items.first().copied()),
+        };
+        return;
+    };
+
+    let Some((col, row)) = locate(current) else {
+        return;
+    };
+
+    let target = if up {
+        columns[col].get(row.saturating_sub(1)).copied()
+    } else if down {
+        columns[col].get(row + 1).copied()
+    } else {
+        // Walk sideways, skipping empty columns
+        let mut next_col = col;
+        let mut found = None;
+        loop {
+            next_col = if left {
+                match next_col.checked_sub(1) {
+                    Some(c) => c,
+                    None => break,
+                }
+            } else if next_col + 1 < columns.len() {
+                next_col + 1
+            } else {
+                break;
+            };
+            if let Some(last) = columns[next_col].len().checked_sub(1) {
+                found = Some(columns[next_col][row.min(last)]);
+                break;
+            }
+        }
+        found
+    };
+
+    if let Some(idx) = target {
+        *focused_preset = Some(idx);
+    }
+}
+
 pub fn render_sidebar(
     ui: &mut egui::Ui,
     config: &mut Config,
     view_mode: &mut ViewMode,
+    focused_preset: &mut Option<usize>,
     text: &LocaleText,
 ) -> bool {
     let mut changed = false;
@@ -186,18 +385,7 @@ pub fn render_sidebar(
     let dragging_idx_id = egui::Id::new("sidebar_drag_source");
     let dragging_source_idx: Option<usize> = ui.memory(|mem| mem.data.get_temp(dragging_idx_id));
 
-    let mut image_indices = Vec::new();
-    let mut text_indices = Vec::new();
-    let mut audio_video_indices = Vec::new();
-
-    for (i, p) in config.presets.iter().enumerate() {
-        match p.preset_type.as_str() {
-            "image" => image_indices.push(i),
-            "text" => text_indices.push(i),
-            "audio" | "video" => audio_video_indices.push(i),
-            _ => image_indices.push(i),
-        }
-    }
+    let [image_indices, text_indices, audio_video_indices] = sidebar_columns(&config.presets);
 
     // Audio/Video indices are not sorted by type to allow user reordering.
     // They will appear in the order they are defined in config.presets.
@@ -212,8 +400,10 @@ pub fn render_sidebar(
     }
 
     // --- Header Navigation ---
-    // Use horizontal layout that doesn't claim fixed space (avoids influencing grid)
-    ui.horizontal(|ui| {
+    // Use horizontal layout that doesn't claim fixed space (avoids influencing grid).
+    // The compact sidebar is narrow, so its header wraps instead.
+    let is_compact = config.sidebar_layout == "compact";
+    let render_header = |ui: &mut egui::Ui| {
         ui.spacing_mut().item_spacing.x = 8.0;
         let is_dark = ui.visuals().dark_mode;
 
@@ -250,6 +440,11 @@ pub fn render_sidebar(
                 ui.selectable_value(&mut config.ui_language, "en".to_string(), "🇺🇸 English");
                 ui.selectable_value(&mut config.ui_language, "vi".to_string(), "🇻🇳 Tiếng Việt");
                 ui.selectable_value(&mut config.ui_language, "ko".to_string(), "🇰🇷 한국어");
+            })
+            .response
+            .widget_info(|| {
+                // The flag alone is meaningless to a screen reader
+                egui::WidgetInfo::labeled(egui::WidgetType::ComboBox, true, "UI language")
             });
         if original_lang != config.ui_language {
             changed = true;
@@ -283,9 +478,10 @@ pub fn render_sidebar(
         }
 
         // Push remaining items to the right side
-
-        let remaining = (ui.available_width()).max(0.0);
-        ui.add_space(remaining * 0.9);
+        if !is_compact {
+            let remaining = (ui.available_width()).max(0.0);
+            ui.add_space(remaining * 0.9);
+        }
 
         // Help Assistant Button
         let help_bg = if is_dark {
@@ -323,161 +519,216 @@ pub fn render_sidebar(
         {
             should_set_global = true;
         }
-    });
+    };
+    if is_compact {
+        ui.horizontal_wrapped(render_header);
+    } else {
+        ui.horizontal(render_header);
+    }
 
     ui.add_space(8.0);
 
-    // --- Presets Grid ---
-    // Use stable ID based on preset count and IDs (not names - those change during typing)
-    let preset_hash: u64 = config
-        .presets
-        .iter()
-        .fold(config.presets.len() as u64, |acc, p| {
-            acc.wrapping_mul(31).wrapping_add(
-                p.id.bytes()
-                    .fold(0u64, |h, b| h.wrapping_mul(31).wrapping_add(b as u64)),
-            )
-        });
-    let grid_id = egui::Id::new("presets_grid").with(preset_hash);
-
-    let grid_response = egui::Grid::new(grid_id)
-        .num_columns(6)
-        .spacing([8.0, 4.0])
-        .min_col_width(67.0)
-        .show(ui, |ui| {
-            // ROW 1: Add Buttons
-            let is_dark = ui.visuals().dark_mode;
-            let img_bg = if is_dark {
-                egui::Color32::from_rgb(45, 85, 140)
-            } else {
-                egui::Color32::from_rgb(100, 150, 220)
-            };
-            let txt_bg = if is_dark {
-                egui::Color32::from_rgb(45, 120, 80)
-            } else {
-                egui::Color32::from_rgb(90, 180, 120)
-            };
-            let aud_bg = if is_dark {
-                egui::Color32::from_rgb(150, 95, 40)
-            } else {
-                egui::Color32::from_rgb(220, 160, 80)
-            };
-
-            // Image
-            ui.add(
-                egui::Button::new(
-                    egui::RichText::new(text.add_image_preset_btn)
-                        .color(egui::Color32::WHITE)
-                        .strong(),
-                )
-                .fill(img_bg)
-                .corner_radius(12.0),
-            )
-            .clicked()
-            .then(|| preset_to_add_type = Some("image"));
-            ui.label("");
-
-            // Text
-            ui.add(
-                egui::Button::new(
-                    egui::RichText::new(text.add_text_preset_btn)
-                        .color(egui::Color32::WHITE)
-                        .strong(),
-                )
-                .fill(txt_bg)
-                .corner_radius(12.0),
-            )
-            .clicked()
-            .then(|| preset_to_add_type = Some("text"));
-            ui.label("");
+    if is_compact {
+        // --- Compact Preset List ---
+        // One collapsible section per preset type; MASTER presets head their own sub-sections
+        let fills = add_button_fills(ui.visuals().dark_mode);
+        let groups = [
+            (
+                "image",
+                text.preset_type_image,
+                text.add_image_preset_btn,
+                &image_indices,
+            ),
+            (
+                "text",
+                text.preset_type_text,
+                text.add_text_preset_btn,
+                &text_indices,
+            ),
+            (
+                "audio",
+                text.preset_type_audio,
+                text.add_audio_preset_btn,
+                &audio_video_indices,
+            ),
+        ];
+        let mut render_item = |ui: &mut egui::Ui, idx: usize| {
+            render_preset_item_parts(
+                ui,
+                &config.presets,
+                idx,
+                dragging_source_idx,
+                &current_view_mode,
+                *focused_preset,
+                &mut preset_idx_to_select,
+                &mut preset_idx_to_delete,
+                &mut preset_idx_to_clone,
+                &mut preset_idx_to_toggle_favorite,
+                &mut preset_swap_request,
+                &config.ui_language,
+            );
+        };
 
-            // Audio
-            ui.add(
-                egui::Button::new(
-                    egui::RichText::new(text.add_audio_preset_btn)
-                        .color(egui::Color32::WHITE)
-                        .strong(),
+        egui::ScrollArea::vertical()
+            .id_salt("compact_sidebar_scroll")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for ((type_str, title, add_label, indices), fill) in groups.into_iter().zip(fills) {
+                    let group_id = ui.make_persistent_id(("compact_group", type_str));
+                    CollapsingState::load_with_default_open(ui.ctx(), group_id, true)
+                        .show_header(ui, |ui| {
+                            ui.strong(title);
+                            if add_preset_button(ui, add_label, fill) {
+                                preset_to_add_type = Some(type_str);
+                            }
+                        })
+                        .body(|ui| {
+                            for section in compact_sections(indices, &config.presets) {
+                                match section.master {
+                                    Some(master) => {
+                                        let section_id = ui.make_persistent_id((
+                                            "compact_master",
+                                            &config.presets[master].id,
+                                        ));
+                                        CollapsingState::load_with_default_open(
+                                            ui.ctx(),
+                                            section_id,
+                                            true,
+                                        )
+                                        .show_header(ui, |ui| render_item(ui, master))
+                                        .body(|ui| {
+                                            render_compact_rows(
+                                                ui,
+                                                &section.items,
+                                                &mut render_item,
+                                            )
+                                        });
+                                    }
+                                    None => {
+                                        render_compact_rows(ui, &section.items, &mut render_item)
+                                    }
+                                }
+                            }
+                        });
+                }
+            });
+    } else {
+        // --- Presets Grid ---
+        // Use stable ID based on preset count and IDs (not names - those change during typing)
+        let preset_hash: u64 = config
+            .presets
+            .iter()
+            .fold(config.presets.len() as u64, |acc, p| {
+                acc.wrapping_mul(31).wrapping_add(
+                    p.id.bytes()
+                        .fold(0u64, |h, b| h.wrapping_mul(31).wrapping_add(b as u64)),
                 )
-                .fill(aud_bg)
-                .corner_radius(12.0),
-            )
-            .clicked()
-            .then(|| preset_to_add_type = Some("audio"));
-            ui.label("");
-            ui.end_row();
-
-            // ROW 2+: Preset Items
-            let max_len = image_indices
-                .len()
-                .max(text_indices.len())
-                .max(audio_video_indices.len());
-            for i in 0..max_len {
-                // Column 1&2: Image
-                if let Some(&idx) = image_indices.get(i) {
-                    render_preset_item_parts(
-                        ui,
-                        &config.presets,
-                        idx,
-                        dragging_source_idx,
-                        &current_view_mode,
-                        &mut preset_idx_to_select,
-                        &mut preset_idx_to_delete,
-                        &mut preset_idx_to_clone,
-                        &mut preset_idx_to_toggle_favorite,
-                        &mut preset_swap_request,
-                        &config.ui_language,
-                    );
-                } else {
-                    ui.label("");
-                    ui.label("");
+            });
+        let grid_id = egui::Id::new("presets_grid").with(preset_hash);
+
+        let grid_response = egui::Grid::new(grid_id)
+            .num_columns(6)
+            .spacing([8.0, 4.0])
+            .min_col_width(67.0)
+            .show(ui, |ui| {
+                // ROW 1: Add Buttons
+                let [img_bg, txt_bg, aud_bg] = add_button_fills(ui.visuals().dark_mode);
+
+                // Image
+                if add_preset_button(ui, text.add_image_preset_btn, img_bg) {
+                    preset_to_add_type = Some("image");
                 }
+                ui.label("");
 
-                // Column 3&4: Text
-                if let Some(&idx) = text_indices.get(i) {
-                    render_preset_item_parts(
-                        ui,
-                        &config.presets,
-                        idx,
-                        dragging_source_idx,
-                        &current_view_mode,
-                        &mut preset_idx_to_select,
-                        &mut preset_idx_to_delete,
-                        &mut preset_idx_to_clone,
-                        &mut preset_idx_to_toggle_favorite,
-                        &mut preset_swap_request,
-                        &config.ui_language,
-                    );
-                } else {
-                    ui.label("");
-                    ui.label("");
+                // Text
+                if add_preset_button(ui, text.add_text_preset_btn, txt_bg) {
+                    preset_to_add_type = Some("text");
                 }
+                ui.label("");
 
-                // Column 5&6: Audio
-                if let Some(&idx) = audio_video_indices.get(i) {
-                    render_preset_item_parts(
-                        ui,
-                        &config.presets,
-                        idx,
-                        dragging_source_idx,
-                        &current_view_mode,
-                        &mut preset_idx_to_select,
-                        &mut preset_idx_to_delete,
-                        &mut preset_idx_to_clone,
-                        &mut preset_idx_to_toggle_favorite,
-                        &mut preset_swap_request,
-                        &config.ui_language,
-                    );
-                } else {
-                    ui.label("");
-                    ui.label("");
+                // Audio
+                if add_preset_button(ui, text.add_audio_preset_btn, aud_bg) {
+                    preset_to_add_type = Some("audio");
                 }
-
+                ui.label("");
                 ui.end_row();
-            }
-        });
 
-    // Update cached grid width for next frame
-    GRID_WIDTH.with(|w| w.set(grid_response.response.rect.width()));
+                // ROW 2+: Preset Items
+                let max_len = image_indices
+                    .len()
+                    .max(text_indices.len())
+                    .max(audio_video_indices.len());
+                for i in 0..max_len {
+                    // Column 1&2: Image
+                    if let Some(&idx) = image_indices.get(i) {
+                        render_preset_item_parts(
+                            ui,
+                            &config.presets,
+                            idx,
+                            dragging_source_idx,
+                            &current_view_mode,
+                            *focused_preset,
+                            &mut preset_idx_to_select,
+                            &mut preset_idx_to_delete,
+                            &mut preset_idx_to_clone,
+                            &mut preset_idx_to_toggle_favorite,
+                            &mut preset_swap_request,
+                            &config.ui_language,
+                        );
+                    } else {
+                        ui.label("");
+                        ui.label("");
+                    }
+
+                    // Column 3&4: Text
+                    if let Some(&idx) = text_indices.get(i) {
+                        render_preset_item_parts(
+                            ui,
+                            &config.presets,
+                            idx,
+                            dragging_source_idx,
+                            &current_view_mode,
+                            *focused_preset,
+                            &mut preset_idx_to_select,
+                            &mut preset_idx_to_delete,
+                            &mut preset_idx_to_clone,
+                            &mut preset_idx_to_toggle_favorite,
+                            &mut preset_swap_request,
+                            &config.ui_language,
+                        );
+                    } else {
+                        ui.label("");
+                        ui.label("");
+                    }
+
+                    // Column 5&6: Audio
+                    if let Some(&idx) = audio_video_indices.get(i) {
+                        render_preset_item_parts(
+                            ui,
+                            &config.presets,
+                            idx,
+                            dragging_source_idx,
+                            &current_view_mode,
+                            *focused_preset,
+                            &mut preset_idx_to_select,
+                            &mut preset_idx_to_delete,
+                            &mut preset_idx_to_clone,
+                            &mut preset_idx_to_toggle_favorite,
+                            &mut preset_swap_request,
+                            &config.ui_language,
+                        );
+                    } else {
+                        ui.label("");
+                        ui.label("");
+                    }
+
+                    ui.end_row();
+                }
+            });
+
+        // Update cached grid width for next frame
+        GRID_WIDTH.with(|w| w.set(grid_response.response.rect.width()));
+    }
 
     if should_set_global {
         *view_mode = ViewMode::Global;
@@ -487,6 +738,8 @@ pub fn render_sidebar(
     }
     if let Some(idx) = preset_idx_to_select {
         *view_mode = ViewMode::Preset(idx);
+        // Mouse selection hides the keyboard focus ring
+        *focused_preset = None;
     }
 
     if let Some(idx) = preset_idx_to_toggle_favorite {
@@ -583,12 +836,33 @@ pub fn render_sidebar(
     changed
 }
 
+/// Rows of one compact section, in a two-column grid so the action buttons line up
+fn render_compact_rows(
+    ui: &mut egui::Ui,
+    items: &[usize],
+    render_item: &mut impl FnMut(&mut egui::Ui, usize),
+) {
+    let Some(&first) = items.first() else {
+        return;
+    };
+    egui::Grid::new(("compact_rows", first))
+        .num_columns(2)
+        .spacing([8.0, 4.0])
+        .show(ui, |ui| {
+            for &idx in items {
+                render_item(ui, idx);
+                ui.end_row();
+            }
+        });
+}
+
 fn render_preset_item_parts(
     ui: &mut egui::Ui,
     presets: &[Preset],
     idx: usize,
     dragging_source_idx: Option<usize>,
     current_view_mode: &ViewMode,
+    keyboard_focused: Option<usize>,
     preset_idx_to_select: &mut Option<usize>,
     preset_idx_to_delete: &mut Option<usize>,
     preset_idx_to_clone: &mut Option<usize>,
@@ -643,7 +917,15 @@ fn render_preset_item_parts(
         if preset.is_upcoming {
             ui.add_enabled_ui(false, |ui| {
                 draw_icon_static(ui, icon_type, Some(14.0));
-                let _ = ui.selectable_label(is_selected, &display_name);
+                let label_response = ui.selectable_label(is_selected, &display_name);
+                if keyboard_focused == Some(idx) {
+                    ui.painter().rect_stroke(
+                        label_response.rect.expand(1.0),
+                        4.0,
+                        ui.visuals().selection.stroke,
+                        egui::StrokeKind::Outside,
+                    );
+                }
             });
         } else {
             draw_icon_static(ui, icon_type, Some(14.0));
@@ -652,6 +934,16 @@ fn render_preset_item_parts(
             let label_response = ui.selectable_label(is_selected, &display_name);
             let response = ui.interact(label_response.rect, label_response.id, egui::Sense::drag());
 
+            // Keyboard focus ring (arrow-key navigation)
+            if keyboard_focused == Some(idx) {
+                ui.painter().rect_stroke(
+                    label_response.rect.expand(1.0),
+                    4.0,
+                    ui.visuals().selection.stroke,
+                    egui::StrokeKind::Outside,
+                );
+            }
+
             if label_response.clicked() {
                 *preset_idx_to_select = Some(idx);
             }
@@ -358,3 +358,122 @@ pub fn update_window_icon_native(is_dark_mode: bool) {
         set_window_icon(hwnd, is_dark_mode);
     }
 }
+
+// --- NATIVE FILE DIALOGS ---
+
+/// Show a native Save (`save = true`) or Open dialog filtered to `*.{ext}`.
+/// Returns the chosen path, or `None` if the user cancelled.
+pub fn pick_file_path(
+    save: bool,
+    filter_name: &str,
+    ext: &str,
+    default_name: &str,
+) -> Option<std::path::PathBuf> {
+    pick_file_path_of_types(save, &[(filter_name, ext)], default_name)
+}
+
+/// Like `pick_file_path` with a choice of file types, `(filter name, extension)`
+/// each; the first is preselected. The chosen path has the chosen type's extension.
+pub fn pick_file_path_of_types(
+    save: bool,
+    types: &[(&str, &str)],
+    default_name: &str,
+) -> Option<std::path::PathBuf> {
+    use windows::core::{HSTRING, PCWSTR};
+    use windows::Win32::System::Com::{
+        CoCreateInstance, CoInitializeEx, CoTaskMemFree, CoUninitialize, CLSCTX_ALL,
+        COINIT_APARTMENTTHREADED,
+    };
+    use windows::Win32::UI::Shell::Common::COMDLG_FILTERSPEC;
+    use windows::Win32::UI::Shell::{
+        FileOpenDialog, FileSaveDialog, IFileDialog, FOS_FILEMUSTEXIST, FOS_OVERWRITEPROMPT,
+        FOS_STRICTFILETYPES, SIGDN_FILESYSPATH,
+    };
+
+    unsafe {
+        let com = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+
+        let result = (|| -> windows::core::Result<std::path::PathBuf> {
+            let dialog: IFileDialog = if save {
+                CoCreateInstance(&FileSaveDialog, None, CLSCTX_ALL)?
+            } else {
+                CoCreateInstance(&FileOpenDialog, None, CLSCTX_ALL)?
+            };
+
+            // The HSTRINGs must outlive the filter specs pointing into them
+            let names: Vec<(HSTRING, HSTRING)> = types
+                .iter()
+                .map(|(name, ext)| (HSTRING::from(*name), HSTRING::from(format!("*.{}", ext))))
+                .collect();
+            let file_types: Vec<COMDLG_FILTERSPEC> = names
+                .iter()
+                .map(|(name, pattern)| COMDLG_FILTERSPEC {
+                    pszName: PCWSTR(name.as_ptr()),
+                    pszSpec: PCWSTR(pattern.as_ptr()),
+                })
+                .collect();
+            dialog.SetFileTypes(&file_types)?;
+            if let Some((_, ext)) = types.first() {
+                dialog.SetDefaultExtension(&HSTRING::from(*ext))?;
+            }
+
+            let options = dialog.GetOptions()? | FOS_STRICTFILETYPES;
+            if save {
+                dialog.SetFileName(&HSTRING::from(default_name))?;
+                dialog.SetOptions(options | FOS_OVERWRITEPROMPT)?;
+            } else {
+                dialog.SetOptions(options | FOS_FILEMUSTEXIST)?;
+            }
+
+            // Errors here also cover the user cancelling
+            dialog.Show(None)?;
+
+            let path = dialog.GetResult()?.GetDisplayName(SIGDN_FILESYSPATH)?;
+            let path_string = String::from_utf16_lossy(path.as_wide());
+            CoTaskMemFree(Some(path.0 as *const _));
+            Ok(std::path::PathBuf::from(path_string))
+        })();
+
+        if com.is_ok() {
+            CoUninitialize();
+        }
+        result.ok()
+    }
+}
+
+/// Write a file on a worker thread so large exports don't stall the UI. The outcome
+/// ("Exported: path" or the error) is kept under `status_id` for `render_export_status`.
+pub fn export_in_background(
+    ctx: &egui::Context,
+    status_id: egui::Id,
+    path: std::path::PathBuf,
+    text: &crate::gui::locale::LocaleText,
+    write: impl FnOnce(&std::path::Path) -> anyhow::Result<()> + Send + 'static,
+) {
+    let ctx = ctx.clone();
+    let (done, failed) = (text.export_done, text.export_failed);
+    std::thread::spawn(move || {
+        let status = match write(&path) {
+            Ok(()) => (format!("{} {}", done, path.display()), false),
+            Err(e) => {
+                log::warn!("Export to {} failed: {}", path.display(), e);
+                (format!("{} {}", failed, e), true)
+            }
+        };
+        ctx.data_mut(|d| d.insert_temp(status_id, Some(status)));
+        ctx.request_repaint();
+    });
+}
+
+/// The last `export_in_background` outcome stored under `status_id`, if any
+pub fn render_export_status(ui: &mut egui::Ui, status_id: egui::Id) {
+    let status: Option<(String, bool)> = ui.ctx().data(|d| d.get_temp(status_id)).flatten();
+    if let Some((message, is_error)) = status {
+        let color = if is_error {
+            egui::Color32::from_rgb(200, 100, 50)
+        } else {
+            egui::Color32::from_rgb(34, 139, 34)
+        };
+        ui.label(egui::RichText::new(message).size(11.0).color(color));
+    }
+}
@@ -21,24 +21,55 @@ pub struct HistoryItem {
     pub item_type: HistoryType,
     pub text: String,
     pub media_path: String, // Empty for Text type
+    /// What produced the entry; empty for entries saved before this was recorded
+    #[serde(default)]
+    pub preset_id: String,
+    #[serde(default)]
+    pub provider: String,
+    #[serde(default)]
+    pub model: String,
 }
 
+/// The preset and the provider/model that answered, stored with each entry
+#[derive(Clone, Debug, Default)]
+pub struct HistorySource {
+    pub preset_id: String,
+    pub provider: String,
+    pub model: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+/// Input text kept in an export row, in characters
+const EXPORT_INPUT_EXCERPT: usize = 200;
+
 pub enum HistoryAction {
     SaveImage {
         img: ImageBuffer<Rgba<u8>, Vec<u8>>,
         text: String,
+        source: HistorySource,
     },
     SaveAudio {
         wav_data: Vec<u8>,
         text: String,
+        source: HistorySource,
     },
     SaveText {
         result_text: String,
         input_text: String,
+        source: HistorySource,
     }, // NEW: Save text-only entry
     Delete(i64),
     ClearAll,
     Prune(usize),
+    Restore {
+        items: Vec<HistoryItem>,
+        media: Vec<(String, Vec<u8>)>,
+    }, // Replace everything (settings backup import)
 }
 
 pub struct HistoryManager {
@@ -72,19 +103,29 @@ impl HistoryManager {
         Self { tx, items }
     }
 
-    pub fn save_image(&self, img: ImageBuffer<Rgba<u8>, Vec<u8>>, text: String) {
-        let _ = self.tx.send(HistoryAction::SaveImage { img, text });
+    pub fn save_image(
+        &self,
+        img: ImageBuffer<Rgba<u8>, Vec<u8>>,
+        text: String,
+        source: HistorySource,
+    ) {
+        let _ = self.tx.send(HistoryAction::SaveImage { img, text, source });
     }
 
-    pub fn save_audio(&self, wav_data: Vec<u8>, text: String) {
-        let _ = self.tx.send(HistoryAction::SaveAudio { wav_data, text });
+    pub fn save_audio(&self, wav_data: Vec<u8>, text: String, source: HistorySource) {
+        let _ = self.tx.send(HistoryAction::SaveAudio {
+            wav_data,
+            text,
+            source,
+        });
     }
 
-    pub fn save_text(&self, result_text: String, input_text: String) {
+    pub fn save_text(&self, result_text: String, input_text: String, source: HistorySource) {
         if !result_text.trim().is_empty() {
             let _ = self.tx.send(HistoryAction::SaveText {
                 result_text,
                 input_text,
+                source,
             });
         }
     }
@@ -106,6 +147,91 @@ impl HistoryManager {
     pub fn request_prune(&self, limit: usize) {
         let _ = self.tx.send(HistoryAction::Prune(limit));
     }
+
+    /// Replace the whole history (items + media files), e.g. from a settings backup
+    pub fn restore(&self, items: Vec<HistoryItem>, media: Vec<(String, Vec<u8>)>) {
+        *self.items.lock().unwrap() = items.clone();
+        let _ = self.tx.send(HistoryAction::Restore { items, media });
+    }
+}
+
+/// Folder holding the media files referenced by `HistoryItem::media_path`
+pub fn history_media_dir() -> PathBuf {
+    get_paths().2
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Write `items` to `path`, one row/object per entry with the start of its input
+/// (text entries only; image and audio inputs are media files). `preset_names`
+/// maps preset ids to display names; unknown ids are written as they are.
+pub fn export_history(
+    items: &[HistoryItem],
+    preset_names: &std::collections::HashMap<String, String>,
+    path: &std::path::Path,
+    format: ExportFormat,
+) -> anyhow::Result<()> {
+    let media_dir = history_media_dir();
+    let rows: Vec<serde_json::Value> = items
+        .iter()
+        .map(|item| {
+            let input = if item.item_type == HistoryType::Text {
+                fs::read_to_string(media_dir.join(&item.media_path))
+                    .unwrap_or_default()
+                    .chars()
+                    .take(EXPORT_INPUT_EXCERPT)
+                    .collect()
+            } else {
+                String::new()
+            };
+            let preset = preset_names
+                .get(&item.preset_id)
+                .cloned()
+                .unwrap_or_else(|| item.preset_id.clone());
+            serde_json::json!({
+                "timestamp": item.timestamp,
+                "type": format!("{:?}", item.item_type),
+                "preset": preset,
+                "provider": item.provider,
+                "model": item.model,
+                "input": input,
+                "output": item.text,
+            })
+        })
+        .collect();
+
+    let data = match format {
+        ExportFormat::Json => serde_json::to_string_pretty(&rows)?,
+        ExportFormat::Csv => {
+            const COLUMNS: [&str; 7] = [
+                "timestamp",
+                "type",
+                "preset",
+                "provider",
+                "model",
+                "input",
+                "output",
+            ];
+            let mut csv = COLUMNS.join(",") + "\n";
+            for row in &rows {
+                let fields: Vec<String> = COLUMNS
+                    .iter()
+                    .map(|c| csv_field(row[c].as_str().unwrap_or_default()))
+                    .collect();
+                csv.push_str(&fields.join(","));
+                csv.push('\n');
+            }
+            csv
+        }
+    };
+    fs::write(path, data)?;
+    Ok(())
 }
 
 fn get_paths() -> (PathBuf, PathBuf, PathBuf) {
@@ -137,7 +263,7 @@ fn process_queue(
         let mut items = cache.lock().unwrap();
 
         match action {
-            HistoryAction::SaveImage { img, text } => {
+            HistoryAction::SaveImage { img, text, source } => {
                 let now = Local::now();
                 let timestamp = now.format("%Y-%m-%d %H:%M:%S").to_string();
                 let filename = format!("img_{}.png", now.format("%Y%m%d_%H%M%S_%f"));
@@ -153,12 +279,19 @@ fn process_queue(
                             item_type: HistoryType::Image,
                             text,
                             media_path: filename,
+                            preset_id: source.preset_id,
+                            provider: source.provider,
+                            model: source.model,
                         },
                     );
                     should_save = true;
                 }
             }
-            HistoryAction::SaveAudio { wav_data, text } => {
+            HistoryAction::SaveAudio {
+                wav_data,
+                text,
+                source,
+            } => {
                 let now = Local::now();
                 let timestamp = now.format("%Y-%m-%d %H:%M:%S").to_string();
                 let filename = format!("audio_{}.wav", now.format("%Y%m%d_%H%M%S_%f"));
@@ -174,6 +307,9 @@ fn process_queue(
                             item_type: HistoryType::Audio,
                             text,
                             media_path: filename,
+                            preset_id: source.preset_id,
+                            provider: source.provider,
+                            model: source.model,
                         },
                     );
                     should_save = true;
@@ -182,6 +318,7 @@ fn process_queue(
             HistoryAction::SaveText {
                 result_text,
                 input_text,
+                source,
             } => {
                 let now = Local::now();
                 let timestamp = now.format("%Y-%m-%d %H:%M:%S").to_string();
@@ -198,6 +335,9 @@ fn process_queue(
                             item_type: HistoryType::Text,
                             text: result_text,
                             media_path: filename,
+                            preset_id: source.preset_id,
+                            provider: source.provider,
+                            model: source.model,
                         },
                     );
                     should_save = true;
@@ -219,6 +359,21 @@ fn process_queue(
                 items.clear();
                 should_save = true;
             }
+            HistoryAction::Restore {
+                items: new_items,
+                media,
+            } => {
+                if let Ok(entries) = fs::read_dir(&media_dir) {
+                    for entry in entries.flatten() {
+                        let _ = fs::remove_file(entry.path());
+                    }
+                }
+                for (name, data) in media {
+                    let _ = fs::write(media_dir.join(name), data);
+                }
+                *items = new_items;
+                should_save = true;
+            }
             HistoryAction::Prune(new_limit) => {
                 max_items = new_limit;
                 if items.len() > max_items {
@@ -0,0 +1,251 @@
+//! Optional local HTTP control API for automation.
+//!
+//! Opt-in via `Config::http_api_enabled`, bound to 127.0.0.1 only, and every request
+//! must carry the configured token in the `X-SGT-Token` header.
+//!
+//! - `GET  /presets`          → list of presets (`index`, `id`, `name`, `type`)
+//! - `POST /preset/{id}/run`  → run a preset exactly as if its hotkey was pressed
+//! - `POST /translate`        → `{"text", "target_language"?, "model"?}` in, `{"text"}` out
+
+use std::io::Read;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use lazy_static::lazy_static;
+use serde::Deserialize;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::config::Config;
+use crate::gui::settings_ui::get_localized_preset_name;
+use crate::APP;
+use screen_goated_toolbox::DEFAULT_TEXT_MODEL;
+
+/// Header that must contain `Config::http_api_token`
+pub const TOKEN_HEADER: &str = "X-SGT-Token";
+
+struct RunningServer {
+    server: Arc<Server>,
+    thread: JoinHandle<()>,
+    port: u16,
+    token: String,
+}
+
+lazy_static! {
+    static ref RUNNING: Mutex<Option<RunningServer>> = Mutex::new(None);
+}
+
+#[derive(Deserialize)]
+struct TranslateRequest {
+    text: String,
+    #[serde(default)]
+    target_language: Option<String>,
+    #[serde(default)]
+    model: Option<String>,
+}
+
+/// Generate a random access token (hex, 32 chars) from the system RNG
+pub fn generate_token() -> String {
+    use windows::Win32::Security::Cryptography::{
+        BCryptGenRandom, BCRYPT_USE_SYSTEM_PREFERRED_RNG,
+    };
+
+    let mut bytes = [0u8; 16];
+    unsafe { BCryptGenRandom(None, &mut bytes, BCRYPT_USE_SYSTEM_PREFERRED_RNG) }
+        .ok()
+        .expect("system RNG unavailable");
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Compare a presented token without an early exit, so response timing doesn't
+/// reveal how much of it was right
+fn token_matches(presented: &str, token: &str) -> bool {
+    let (a, b) = (presented.as_bytes(), token.as_bytes());
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Start, stop or restart the server so it matches the config.
+/// Cheap to call after every config save.
+pub fn sync_with_config(config: &Config) {
+    let mut running = RUNNING.lock().unwrap();
+
+    let wanted = config.http_api_enabled && !config.http_api_token.is_empty();
+    let up_to_date = running
+        .as_ref()
+        .is_some_and(|r| r.port == config.http_api_port && r.token == config.http_api_token);
+    if wanted && up_to_date {
+        return;
+    }
+
+    if let Some(old) = running.take() {
+        old.server.unblock();
+        let _ = old.thread.join();
+    }
+
+    if !wanted {
+        return;
+    }
+
+    let server = match Server::http(("127.0.0.1", config.http_api_port)) {
+        Ok(s) => Arc::new(s),
+        Err(e) => {
+            log::error!(
+                "HTTP API: failed to bind 127.0.0.1:{}: {}",
+                config.http_api_port,
+                e
+            );
+            return;
+        }
+    };
+
+    let token = config.http_api_token.clone();
+    let server_clone = server.clone();
+    let token_clone = token.clone();
+    let thread = std::thread::spawn(move || {
+        let _worker = crate::overlay::diagnostics::track_worker("HTTP API");
+        for request in server_clone.incoming_requests() {
+            let token = token_clone.clone();
+            // Handle each request on its own thread: /translate can take a while
+            std::thread::spawn(move || handle_request(request, &token));
+        }
+    });
+
+    *running = Some(RunningServer {
+        server,
+        thread,
+        port: config.http_api_port,
+        token,
+    });
+}
+
+fn handle_request(mut request: Request, token: &str) {
+    let authorized = request
+        .headers()
+        .iter()
+        .any(|h| h.field.equiv(TOKEN_HEADER) && token_matches(h.value.as_str(), token));
+    if !authorized {
+        respond_json(
+            request,
+            401,
+            serde_json::json!({ "error": "invalid or missing token" }),
+        );
+        return;
+    }
+
+    let method = request.method().clone();
+    let url = request.url().split('?').next().unwrap_or("").to_string();
+    let segments: Vec<&str> = url.trim_matches('/').split('/').collect();
+
+    match (method, segments.as_slice()) {
+        (Method::Get, ["presets"]) => {
+            let presets = list_presets();
+            respond_json(request, 200, presets);
+        }
+        (Method::Post, ["preset", id, "run"]) => {
+            let id = urlencoding::decode(id)
+                .map(|s| s.into_owned())
+                .unwrap_or_else(|_| id.to_string());
+            let (status, body) = run_preset(&id);
+            respond_json(request, status, body);
+        }
+        (Method::Post, ["translate"]) => {
+            let mut body = String::new();
+            if request.as_reader().read_to_string(&mut body).is_err() {
+                respond_json(
+                    request,
+                    400,
+                    serde_json::json!({ "error": "unreadable body" }),
+                );
+                return;
+            }
+            let (status, body) = translate(&body);
+            respond_json(request, status, body);
+        }
+        _ => respond_json(request, 404, serde_json::json!({ "error": "not found" })),
+    }
+}
+
+fn respond_json(request: Request, status: u16, body: serde_json::Value) {
+    let response = Response::from_string(body.to_string())
+        .with_status_code(status)
+        .with_header(
+            Header::from_bytes(
+                &b"Content-Type"[..],
+                &b"application/json; charset=utf-8"[..],
+            )
+            .unwrap(),
+        );
+    let _ = request.respond(response);
+}
+
+fn list_presets() -> serde_json::Value {
+    let app = APP.lock().unwrap();
+    let lang = &app.config.ui_language;
+    let presets: Vec<serde_json::Value> = app
+        .config
+        .presets
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| !p.is_upcoming)
+        .map(|(idx, p)| {
+            let name = if p.id.starts_with("preset_") {
+                get_localized_preset_name(&p.id, lang)
+            } else {
+                p.name.clone()
+            };
+            serde_json::json!({
+                "index": idx,
+                "id": p.id,
+                "name": name,
+                "type": p.preset_type,
+            })
+        })
+        .collect();
+    serde_json::Value::Array(presets)
+}
+
+fn run_preset(id: &str) -> (u16, serde_json::Value) {
+    let preset_idx = {
+        let app = APP.lock().unwrap();
+        app.config
+            .presets
+            .iter()
+            .position(|p| p.id == id && !p.is_upcoming)
+    };
+
+    let Some(preset_idx) = preset_idx else {
+        return (404, serde_json::json!({ "error": "unknown preset" }));
+    };
+
+    if crate::trigger_preset(preset_idx) {
+        (202, serde_json::json!({ "status": "started" }))
+    } else {
+        (
+            503,
+            serde_json::json!({ "error": "hotkey listener not ready" }),
+        )
+    }
+}
+
+fn translate(body: &str) -> (u16, serde_json::Value) {
+    let req: TranslateRequest = match serde_json::from_str(body) {
+        Ok(r) => r,
+        Err(e) => return (400, serde_json::json!({ "error": e.to_string() })),
+    };
+
+    let config = APP.lock().unwrap().config.clone();
+    let target_language = req
+        .target_language
+        .unwrap_or_else(|| config.realtime_target_language.clone());
+    let model = req.model.unwrap_or_else(|| DEFAULT_TEXT_MODEL.to_string());
+
+    match crate::api::text::translate_text_blocking(
+        &config,
+        &req.text,
+        "auto",
+        &target_language,
+        &model,
+    ) {
+        Ok(text) => (200, serde_json::json!({ "text": text })),
+        Err(e) => (502, serde_json::json!({ "error": e.to_string() })),
+    }
+}
@@ -0,0 +1,17 @@
+//! Screen Goated Toolbox as a library.
+//!
+//! [`ScreenGoatedToolbox`] gives other Rust programs the app's translation, OCR and
+//! text-to-speech backends without its tray icon, hotkeys or overlays. The modules
+//! exported here hold no GUI code; the `screen-goated-toolbox` executable builds the
+//! app on top of them.
+
+pub mod api;
+pub mod config;
+pub mod locale;
+pub mod model_config;
+pub mod privacy;
+pub mod redact;
+mod toolbox;
+
+pub use config::{load_config, Config};
+pub use toolbox::{ScreenGoatedToolbox, DEFAULT_TEXT_MODEL, DEFAULT_VISION_MODEL};
@@ -8,6 +8,7 @@ pub struct LocaleText {
     pub view_image_btn: &'static str,
     pub listen_audio_btn: &'static str,
     pub view_text_btn: &'static str, // NEW
+    pub history_unknown_source: &'static str,
 
     pub prompt_mode_fixed: &'static str,
     pub prompt_mode_dynamic: &'static str,
@@ -22,6 +23,25 @@ pub struct LocaleText {
     pub use_openrouter_checkbox: &'static str,
     pub cerebras_api_key_label: &'static str,
     pub cerebras_get_key_link: &'static str,
+    pub api_key_test_button: &'static str,
+    pub api_key_test_tooltip: &'static str,
+    pub api_key_valid: &'static str,
+    pub api_key_invalid: &'static str,
+    pub encrypt_api_keys_checkbox: &'static str,
+    pub encrypt_api_keys_tooltip: &'static str,
+    pub privacy_scan_label: &'static str,
+    pub privacy_scan_tooltip: &'static str,
+    pub privacy_patterns_label: &'static str,
+    pub privacy_reset_patterns_btn: &'static str,
+    pub privacy_confirm_title: &'static str,
+    pub privacy_confirm_msg: &'static str,
+    pub clear_clipboard_on_exit_label: &'static str,
+    pub clear_clipboard_on_exit_tooltip: &'static str,
+    pub clipboard_clear_after_label: &'static str,
+    pub clipboard_clear_after_tooltip: &'static str,
+    pub request_timeouts_label: &'static str,
+    pub request_timeouts_hint: &'static str,
+    pub clipboard_keep_label: &'static str,
     pub use_cerebras_checkbox: &'static str,
 
     pub global_settings: &'static str,
@@ -31,10 +51,40 @@ pub struct LocaleText {
 
     pub auto_paste_label: &'static str,
     pub auto_paste_newline_label: &'static str,
+    pub auto_paste_elevated_msg: &'static str,
+    pub auto_copy_target_label: &'static str,
+    pub auto_copy_per_block: &'static str,
+    pub auto_copy_final_block: &'static str,
+    pub auto_copy_each_block: &'static str,
+    pub auto_copy_off: &'static str,
+    pub auto_copy_append_label: &'static str,
+    pub auto_copy_append_tooltip: &'static str,
+    pub arena_mode_label: &'static str,
+    pub arena_mode_tooltip: &'static str,
     pub startup_label: &'static str,
     pub add_hotkey_button: &'static str,
     pub press_keys: &'static str,
     pub cancel_label: &'static str,
+    pub app_hotkeys_header: &'static str,
+    pub app_hotkey_open_settings: &'static str,
+    pub app_hotkey_dismiss_overlays: &'static str,
+    pub app_hotkey_toggle_bubble: &'static str,
+    pub app_hotkey_repeat_last: &'static str,
+    pub repeat_last_none: &'static str,
+    pub app_hotkey_translate_clipboard: &'static str,
+    pub app_hotkey_translate_selection: &'static str,
+    pub app_hotkey_undo_replace: &'static str,
+    pub quick_translate_header: &'static str,
+    pub quick_translate_hint: &'static str,
+    pub quick_translate_language_label: &'static str,
+    pub quick_translate_provider_label: &'static str,
+    pub quick_translate_provider_auto: &'static str,
+    pub quick_translate_name: &'static str,
+    pub quick_translate_nothing: &'static str,
+    pub hotkey_unavailable_tooltip: &'static str,
+    pub hotkeys_unavailable_notification: &'static str,
+    pub hotkey_feedback_checkbox: &'static str,
+    pub hotkey_feedback_tooltip: &'static str,
     pub reset_defaults_btn: &'static str,
 
     pub preset_type_label: &'static str,
@@ -48,8 +98,12 @@ pub struct LocaleText {
     pub audio_src_device: &'static str,
     pub hide_recording_ui_label: &'static str,
     pub auto_stop_recording_label: &'static str, // Silence-based auto-stop
+    pub pronunciation_practice_label: &'static str,
+    pub pronunciation_practice_tooltip: &'static str,
     pub hotkeys_section: &'static str,
     pub start_in_tray_label: &'static str,
+    pub disable_warmups_label: &'static str,
+    pub disable_warmups_tooltip: &'static str,
     pub footer_admin_running: &'static str,
     pub admin_startup_on: &'static str,
     pub admin_startup_success: &'static str,
@@ -57,11 +111,66 @@ pub struct LocaleText {
     pub graphics_mode_label: &'static str,
     pub graphics_mode_standard: &'static str,
     pub graphics_mode_minimal: &'static str,
+    pub ui_scale_label: &'static str,
+    pub sidebar_layout_label: &'static str,
+    pub sidebar_layout_columns: &'static str,
+    pub sidebar_layout_compact: &'static str,
+    pub overlay_font_label: &'static str,
+    pub overlay_font_system: &'static str,
+    pub overlay_font_script_warning: &'static str,
+    pub wheel_settings_label: &'static str,
+    pub wheel_item_size_label: &'static str,
+    pub wheel_radius_label: &'static str,
+    pub wheel_release_to_select_label: &'static str,
+    pub wheel_release_to_select_tooltip: &'static str,
+    pub selection_adjust_label: &'static str,
+    pub selection_adjust_tooltip: &'static str,
+    pub log_level_label: &'static str,
+    pub log_level_off: &'static str,
+    pub log_level_error: &'static str,
+    pub log_level_info: &'static str,
+    pub log_level_debug: &'static str,
+    pub open_log_folder_btn: &'static str,
+    pub webview_lifecycle_label: &'static str,
+    pub webview_lifecycle_tooltip: &'static str,
+    pub webview_lifecycle_persistent: &'static str,
+    pub webview_lifecycle_lazy: &'static str,
+    pub webview_memory_btn: &'static str,
+    pub webview_memory_value: &'static str,
+    pub diagnostics_header: &'static str,
+    pub diagnostics_refresh_btn: &'static str,
+    pub diagnostics_copy_btn: &'static str,
+    pub diagnostics_dpi_test_btn: &'static str,
+    pub diagnostics_dpi_test_tooltip: &'static str,
     pub usage_statistics_title: &'static str,
     pub usage_statistics_tooltip: &'static str,
     pub usage_model_column: &'static str,
     pub usage_remaining_column: &'static str,
     pub usage_check_link: &'static str,
+    pub usage_charts_title: &'static str,
+    pub usage_charts_provider_label: &'static str,
+    pub usage_charts_all_providers: &'static str,
+    pub export_csv_btn: &'static str,
+    pub export_json_btn: &'static str,
+    pub export_done: &'static str,
+    pub export_failed: &'static str,
+    pub snapshot_copied: &'static str,
+    pub snapshot_failed: &'static str,
+    pub markdown_toc_label: &'static str,
+    pub selection_dim_label: &'static str,
+    pub selection_border_color_label: &'static str,
+    pub text_replaced_label: &'static str,
+    pub text_replace_undone: &'static str,
+    pub text_replace_nothing_to_undo: &'static str,
+    pub post_command_confirm_title: &'static str,
+    pub post_command_confirm_msg: &'static str,
+    pub post_command_failed: &'static str,
+    pub post_command_timed_out: &'static str,
+    pub table_export_none: &'static str,
+    pub usage_charts_requests_label: &'static str,
+    pub usage_charts_tokens_label: &'static str,
+    pub usage_charts_preset_column: &'static str,
+    pub usage_charts_requests_column: &'static str,
 
     pub footer_admin_text: &'static str,
     pub footer_version: &'static str,
@@ -72,6 +181,8 @@ pub struct LocaleText {
     pub check_again_btn: &'static str,
     pub new_version_available: &'static str,
     pub release_notes_label: &'static str,
+    pub whats_new_title: &'static str,
+    pub whats_new_close: &'static str,
     pub download_update_btn: &'static str,
     pub downloading_update: &'static str,
     pub update_failed: &'static str,
@@ -80,11 +191,30 @@ pub struct LocaleText {
     pub update_success: &'static str,
     pub restart_to_use_new_version: &'static str,
     pub restart_app_btn: &'static str,
+    pub rollback_btn: &'static str,
+    pub update_channel_label: &'static str,
+    pub update_channel_stable: &'static str,
+    pub update_channel_beta: &'static str,
+    pub update_channel_beta_warning: &'static str,
+    pub update_interval_label: &'static str,
+    pub update_interval_startup_only: &'static str,
+    pub update_interval_hours: &'static str,
+    pub update_interval_weekly: &'static str,
+    pub update_quiet_hours_label: &'static str,
+    pub update_quiet_hours_tooltip: &'static str,
+    pub update_last_checked: &'static str,
+    pub rollback_tooltip: &'static str,
+    pub rollback_staged: &'static str,
     // --- NEW TEXT INPUT FIELDS ---
     pub text_input_mode_label: &'static str,
     pub text_mode_select: &'static str,
     pub text_mode_type: &'static str,
     pub continuous_input_label: &'static str, // Checkbox for continuous input mode
+    pub remember_selection_label: &'static str,
+    pub remember_selection_tooltip: &'static str,
+    pub redact_before_send_label: &'static str,
+    pub redact_before_send_tooltip: &'static str,
+    pub redact_hint: &'static str,
     pub command_mode_label: &'static str, // For prompt mode in text/image presets (different from text_input_mode_label)
     pub text_input_title_default: &'static str,
     pub text_input_placeholder: &'static str,
@@ -139,9 +269,21 @@ pub struct LocaleText {
     pub realtime_listening: &'static str,
     pub realtime_device: &'static str,
     pub realtime_waiting: &'static str,
+    pub realtime_speaker_label: &'static str,
+    pub realtime_speaker_labels_tooltip: &'static str,
+    pub realtime_dictation_tooltip: &'static str,
+    pub realtime_caption_tooltip: &'static str,
+    pub realtime_jump_latest: &'static str,
     pub realtime_translation: &'static str,
     pub realtime_mic: &'static str,
     pub ollama_url_guide: &'static str,
+    pub ollama_text_model_label: &'static str,
+    pub ollama_vision_model_label: &'static str,
+    pub ollama_model_default: &'static str,
+    pub ollama_refresh_tooltip: &'static str,
+    pub ollama_status_loading: &'static str,
+    pub ollama_status_ok: &'static str,
+    pub ollama_status_failed: &'static str,
     pub tts_settings_button: &'static str,
     pub tts_settings_title: &'static str,
     pub tts_method_label: &'static str,
@@ -154,6 +296,8 @@ pub struct LocaleText {
     pub tts_edge_desc: &'static str,
     pub tts_pitch_label: &'static str,
     pub tts_rate_label: &'static str,
+    pub tts_playback_speed_label: &'static str,
+    pub tts_playback_speed_tooltip: &'static str,
     pub tts_voice_per_language_label: &'static str,
     pub tts_loading_voices: &'static str,
     pub tts_failed_load_voices: &'static str,
@@ -176,26 +320,40 @@ pub struct LocaleText {
     pub realtime_tts_title: &'static str,
     pub realtime_tts_speed: &'static str,
     pub realtime_tts_auto: &'static str,
+    pub realtime_tts_playback: &'static str,
+    pub tts_pause: &'static str,
+    pub tts_resume: &'static str,
+    pub tts_stop: &'static str,
     // App selection modal
     pub app_select_title: &'static str,
     pub app_select_hint: &'static str,
     // --- TRAY MENU ---
     pub tray_settings: &'static str,
     pub tray_quit: &'static str,
+    pub tray_recent: &'static str,
     pub tray_favorite_bubble: &'static str,
     pub tray_favorite_bubble_disabled: &'static str,
     // --- FAVORITE BUBBLE ---
     pub favorites_empty: &'static str,
     pub favorites_keep_open: &'static str,
+    pub favorite_bubbles_separate_label: &'static str,
+    pub favorite_bubbles_separate_tooltip: &'static str,
     pub recording_subtext: &'static str,
     pub recording_paused: &'static str,
     // --- AUTO COPY BADGE ---
     pub auto_copied_badge: &'static str,
     pub auto_copied_image_badge: &'static str,
     pub live_translate_loading: &'static str,
+    pub webview_recovering: &'static str,
     pub text_input_loading: &'static str,
+    pub secondary_language_notice: &'static str,
+    pub result_all_languages: &'static str,
     pub recording_loading: &'static str,
     pub markdown_view_loading: &'static str,
+    pub dual_pane_no_source: &'static str,
+    pub arena_pick_btn: &'static str,
+    pub arena_picked: &'static str,
+    pub arena_faster: &'static str,
     pub preset_wheel_loading: &'static str,
     pub prompt_dj_loading: &'static str,
     pub tray_popup_loading: &'static str,
@@ -226,11 +384,65 @@ pub struct LocaleText {
     // --- PROMPT DJ ---
     pub prompt_dj_btn: &'static str,
     pub prompt_dj_title: &'static str,
+    pub prompt_dj_midi_off: &'static str,
     // --- PARAKEET DOWNLOAD MODAL ---
     pub parakeet_downloading_title: &'static str,
     pub parakeet_downloading_message: &'static str,
     pub parakeet_downloading_file: &'static str, // "Downloading {}..."
     pub parakeet_supports_english_only: &'static str,
+    // --- CRASH REPORT ---
+    pub crash_report_title: &'static str,
+    pub icon_settings: &'static str,
+    pub icon_hide_value: &'static str,
+    pub icon_show_value: &'static str,
+    pub icon_microphone: &'static str,
+    pub icon_image: &'static str,
+    pub icon_text: &'static str,
+    pub icon_delete: &'static str,
+    pub icon_open_media: &'static str,
+    pub icon_copy: &'static str,
+    pub icon_close: &'static str,
+    pub icon_text_selection: &'static str,
+    pub icon_speak: &'static str,
+    pub icon_speech_disabled: &'static str,
+    pub icon_auto_copy_disabled: &'static str,
+    pub icon_tips: &'static str,
+    pub icon_realtime: &'static str,
+    pub icon_add_favorite: &'static str,
+    pub icon_remove_favorite: &'static str,
+    pub icon_theme_light: &'static str,
+    pub icon_theme_dark: &'static str,
+    pub icon_theme_system: &'static str,
+    pub icon_drag_to_reorder: &'static str,
+    pub icon_history: &'static str,
+    pub instance_not_responding_title: &'static str,
+    pub instance_not_responding_msg: &'static str,
+    pub crash_detected: &'static str,
+    pub crash_error_label: &'static str,
+    pub crash_location_label: &'static str,
+    pub crash_log_saved: &'static str,
+    // --- HTTP CONTROL API ---
+    pub http_api_header: &'static str,
+    pub http_api_enable: &'static str,
+    pub http_api_port_label: &'static str,
+    pub http_api_token_label: &'static str,
+    pub http_api_copy_btn: &'static str,
+    pub http_api_regenerate_btn: &'static str,
+    pub http_api_hint: &'static str,
+    // --- SETTINGS BACKUP ---
+    pub backup_header: &'static str,
+    pub global_prompt_header: &'static str,
+    pub global_prompt_hint: &'static str,
+    pub global_prompt_prefix_label: &'static str,
+    pub global_prompt_suffix_label: &'static str,
+    pub backup_export_btn: &'static str,
+    pub backup_import_btn: &'static str,
+    pub backup_include_api_keys: &'static str,
+    pub backup_file_type: &'static str,
+    pub backup_export_done: &'static str,
+    pub backup_import_done: &'static str,
+    pub backup_failed: &'static str,
+    pub backup_overwrite_keys_msg: &'static str,
 }
 
 impl LocaleText {
@@ -245,6 +457,7 @@ impl LocaleText {
                  view_image_btn: "Xem ảnh",
                  listen_audio_btn: "Nghe audio",
                  view_text_btn: "Xem text",
+                 history_unknown_source: "mô hình không rõ",
 
                  prompt_mode_fixed: "Làm theo lệnh sẵn",
                  prompt_mode_dynamic: "Viết lệnh tại chỗ",
@@ -259,6 +472,25 @@ impl LocaleText {
                  use_openrouter_checkbox: "OpenRouter",
                  cerebras_api_key_label: "Mã API Cerebras:",
                  cerebras_get_key_link: "Lấy mã tại cloud.cerebras.ai",
+                 api_key_test_button: "Kiểm tra",
+                 api_key_test_tooltip: "Gửi một yêu cầu nhỏ để kiểm tra khóa này",
+                 api_key_valid: "Khóa hợp lệ (kiểm tra lúc {})",
+                 api_key_invalid: "Khóa không dùng được (kiểm tra lúc {})",
+                 encrypt_api_keys_checkbox: "Mã hóa khóa API trong tệp cấu hình",
+                 encrypt_api_keys_tooltip: "Khóa đã mã hóa chỉ đọc được bởi tài khoản Windows này. Tắt đi nếu bạn đồng bộ tệp cấu hình giữa các máy.",
+                 privacy_scan_label: "Cảnh báo dữ liệu cá nhân trước khi gửi",
+                 privacy_scan_tooltip: "Sau khi đọc chữ từ ảnh, kiểm tra cục bộ email, số điện thoại và số giống số thẻ, rồi hỏi trước khi gửi tiếp cho các bước sau.",
+                 privacy_patterns_label: "Mẫu (regex, mỗi dòng một mẫu):",
+                 privacy_reset_patterns_btn: "Khôi phục mặc định",
+                 privacy_confirm_title: "Dữ liệu cá nhân",
+                 privacy_confirm_msg: "Ảnh này có vẻ chứa dữ liệu cá nhân. Vẫn gửi?",
+                 clear_clipboard_on_exit_label: "Xóa clipboard khi thoát",
+                 clear_clipboard_on_exit_tooltip: "Khi thoát, xóa clipboard nếu nó vẫn chứa văn bản mà ứng dụng đã sao chép.",
+                 clipboard_clear_after_label: "Xóa văn bản đã sao chép sau:",
+                 clipboard_clear_after_tooltip: "Xóa clipboard sau số giây này kể từ khi ứng dụng sao chép văn bản, trừ khi bạn đã sao chép thứ khác.",
+                 request_timeouts_label: "Thời gian chờ phản hồi",
+                 request_timeouts_hint: "Số giây chờ nhà cung cấp bắt đầu trả lời trước khi báo lỗi hết thời gian.",
+                 clipboard_keep_label: "Giữ",
                  use_cerebras_checkbox: "Cerebras",
 
                 global_settings: "Cài Đặt Chung",
@@ -270,10 +502,40 @@ impl LocaleText {
 
                 auto_paste_label: "Tự động dán",
                 auto_paste_newline_label: "Tự thêm ký tự xuống dòng sau khi copy",
+                auto_paste_elevated_msg: "Không thể tự dán vào cửa sổ đang chạy bằng quyền admin. Kết quả đã được sao chép, hãy nhấn Ctrl+V hoặc chạy SGT bằng quyền admin.",
+                auto_copy_target_label: "Tự sao chép:",
+                auto_copy_per_block: "Theo từng khối",
+                auto_copy_final_block: "Khối cuối",
+                auto_copy_each_block: "Mọi khối",
+                auto_copy_off: "Tắt",
+                auto_copy_append_label: "Nối vào bộ nhớ tạm",
+                auto_copy_append_tooltip: "Thêm kết quả vào sau văn bản đang có trong bộ nhớ tạm thay vì thay thế nó",
+                arena_mode_label: "So sánh (Arena)",
+                arena_mode_tooltip: "Hiển thị hai nhánh văn bản đầu tiên cạnh nhau trong một cửa sổ để chọn kết quả tốt hơn",
                 startup_label: "Khởi động cùng Windows",
                 add_hotkey_button: "+ Thêm Phím",
                 press_keys: "Ấn tổ hợp phím...",
                 cancel_label: "Hủy",
+                app_hotkeys_header: "Phím tắt chung",
+                app_hotkey_open_settings: "Mở cài đặt",
+                app_hotkey_dismiss_overlays: "Đóng mọi cửa sổ nổi",
+                app_hotkey_toggle_bubble: "Bật/tắt bong bóng yêu thích",
+                app_hotkey_repeat_last: "Lặp lại preset vừa dùng",
+                repeat_last_none: "Chưa dùng preset nào",
+                app_hotkey_translate_clipboard: "Dịch clipboard",
+                app_hotkey_translate_selection: "Dịch văn bản đang chọn",
+                app_hotkey_undo_replace: "Hoàn tác lần thay thế cuối",
+                quick_translate_header: "Dịch nhanh",
+                quick_translate_hint: "Dùng cho mục \"Dịch clipboard\" / \"Dịch văn bản đang chọn\" trong khay hệ thống và phím tắt của chúng, không cần tạo preset.",
+                quick_translate_language_label: "Dịch sang:",
+                quick_translate_provider_label: "Nhà cung cấp:",
+                quick_translate_provider_auto: "Tự động (chung)",
+                quick_translate_name: "Dịch nhanh",
+                quick_translate_nothing: "Không có văn bản để dịch",
+                hotkey_unavailable_tooltip: "Phím tắt này đang bị ứng dụng khác hoặc Windows sử dụng nên sẽ không hoạt động. Hãy chọn phím tắt khác (bấm để xóa).",
+                hotkeys_unavailable_notification: "⚠ Phím tắt đã bị chiếm dụng: {}",
+                hotkey_feedback_checkbox: "Hiện thông báo khi nhấn phím tắt preset",
+                hotkey_feedback_tooltip: "Hiện ngay tên preset khi phím tắt được nhận, trước khi cửa sổ hoặc thu âm bắt đầu. Không áp dụng cho preset ảnh (thông báo sẽ lọt vào ảnh chụp).",
                 reset_defaults_btn: "Khôi phục mặc định",
 
 
@@ -291,8 +553,12 @@ impl LocaleText {
                 audio_src_device: "Âm thanh máy tính",
                 hide_recording_ui_label: "Ẩn giao diện ghi âm",
                 auto_stop_recording_label: "Tự động dừng",
+                pronunciation_practice_label: "Chấm phát âm",
+                pronunciation_practice_tooltip: "Chấm điểm từng từ so với câu mẫu. Sao chép câu cần luyện trước khi ghi âm",
                 hotkeys_section: "Phím tắt",
                 start_in_tray_label: "Khởi động trong tray",
+                disable_warmups_label: "Tải cửa sổ khi cần (máy cấu hình thấp)",
+                disable_warmups_tooltip: "Không tạo sẵn các cửa sổ WebView khi khởi động; mỗi cửa sổ được tạo ở lần dùng đầu tiên (mở lần đầu sẽ chậm hơn). Có hiệu lực sau khi khởi động lại.",
                 footer_admin_running: "đang chạy bằng admin",
                 admin_startup_on: "Chạy làm Admin khi khởi động",
                 admin_startup_success: "Đã bật: Sẽ chạy Admin khi khởi động (Task Scheduler).",
@@ -300,11 +566,66 @@ impl LocaleText {
                 graphics_mode_label: "Đồ hoạ:",
                 graphics_mode_standard: "Tiêu chuẩn (Hiệu ứng gradient glow)",
                 graphics_mode_minimal: "Tối giản cho máy yếu (Hiệu ứng quét laser)",
+                ui_scale_label: "Cỡ giao diện:",
+                sidebar_layout_label: "Bố cục danh sách:",
+                sidebar_layout_columns: "Nhiều cột",
+                sidebar_layout_compact: "Gọn (một cột)",
+                overlay_font_label: "Phông chữ cửa sổ nổi:",
+                overlay_font_system: "Phông chữ hệ thống",
+                overlay_font_script_warning: "⚠ Phông này không có chữ {}; các ký tự đó sẽ dùng phông dự phòng",
+                wheel_settings_label: "Vòng preset:",
+                wheel_item_size_label: "Cỡ chữ",
+                wheel_radius_label: "Độ giãn",
+                wheel_release_to_select_label: "Thả phím tắt để chọn preset đang trỏ",
+                wheel_release_to_select_tooltip: "Giữ phím tắt, trỏ chuột vào preset rồi thả phím. Nếu không trỏ vào preset nào, hãy bấm chọn như bình thường.",
+                selection_adjust_label: "Chỉnh vùng chọn bằng phím mũi tên trước khi xác nhận",
+                selection_adjust_tooltip: "Sau khi kéo, vùng chọn được giữ lại: mũi tên di chuyển cạnh phải/dưới, Ctrl+mũi tên di chuyển cả vùng, giữ Shift để bước 10px. Enter để xác nhận, Esc để hủy. Giữ Shift khi kéo để cố định tỉ lệ khung theo lần chọn trước.",
+                log_level_label: "Mức ghi log:",
+                log_level_off: "Tắt",
+                log_level_error: "Chỉ lỗi",
+                log_level_info: "Thông tin",
+                log_level_debug: "Gỡ lỗi (chi tiết)",
+                open_log_folder_btn: "Mở thư mục log",
+                webview_lifecycle_label: "Chế độ WebView:",
+                webview_lifecycle_tooltip: "Giữ sẵn: Prompt DJ và Dịch trực tiếp mở ngay nhưng luôn chiếm bộ nhớ. Khi mở: tạo lại mỗi lần mở và giải phóng khi đóng (mở chậm hơn, tốn ít RAM hơn).",
+                webview_lifecycle_persistent: "Giữ sẵn (nhanh hơn)",
+                webview_lifecycle_lazy: "Tải khi mở (ít RAM hơn)",
+                webview_memory_btn: "Đo bộ nhớ",
+                webview_memory_value: "{} MB trong {} tiến trình",
+                diagnostics_header: "Chẩn đoán",
+                diagnostics_refresh_btn: "Làm mới",
+                diagnostics_copy_btn: "Sao chép",
+                diagnostics_dpi_test_btn: "Kiểm tra DPI",
+                diagnostics_dpi_test_tooltip: "Hiện một ô đúng 100 × 100 pixel trên mỗi màn hình. Nếu ô bị giãn hoặc mờ, tọa độ chụp và lớp phủ có thể bị lệch.",
                 usage_statistics_title: "Thống kê sử dụng",
                 usage_statistics_tooltip: "Dùng mô hình ít nhất một lần để hiện chính xác",
                 usage_model_column: "Mô hình",
                 usage_remaining_column: "Còn lại / Tổng",
                 usage_check_link: "Xem lượng dùng ↗",
+                usage_charts_title: "30 ngày qua",
+                usage_charts_provider_label: "Nhà cung cấp:",
+                usage_charts_all_providers: "Tất cả",
+                export_csv_btn: "Xuất CSV",
+                export_json_btn: "Xuất JSON",
+                export_done: "Đã xuất:",
+                export_failed: "Xuất thất bại:",
+                snapshot_copied: "Đã chép ảnh cửa sổ (Shift+nhấp để lưu thành tệp PNG)",
+                snapshot_failed: "Không chụp được cửa sổ",
+                markdown_toc_label: "Mục lục",
+                selection_dim_label: "Độ tối khi chọn vùng:",
+                selection_border_color_label: "Màu viền vùng chọn:",
+                text_replaced_label: "Đã thay",
+                text_replace_undone: "Đã khôi phục văn bản gốc",
+                text_replace_nothing_to_undo: "Không có gì để hoàn tác",
+                post_command_confirm_title: "Chạy lệnh ngoài?",
+                post_command_confirm_msg: "Cấu hình \"{}\" sẽ chạy lệnh sau trên mỗi kết quả:\n\n{}\n\nChỉ cho phép nếu bạn tin tưởng lệnh này. Cho phép chạy?",
+                post_command_failed: "Lệnh xử lý sau bị lỗi:",
+                post_command_timed_out: "Lệnh xử lý sau chạy quá lâu và đã bị dừng",
+                table_export_none: "Không tìm thấy bảng nào trong kết quả",
+                usage_charts_requests_label: "Số yêu cầu mỗi ngày",
+                usage_charts_tokens_label: "Token ước tính mỗi ngày (số ký tự / 4)",
+                usage_charts_preset_column: "Preset",
+                usage_charts_requests_column: "Yêu cầu",
 
                 footer_admin_text: "chạy bằng admin để dịch game",
                 footer_version: "phiên bản",
@@ -315,6 +636,8 @@ impl LocaleText {
                 check_again_btn: "Kiểm Tra Lại",
                 new_version_available: "Phiên bản mới có sẵn:",
                 release_notes_label: "Ghi Chú Phát Hành",
+                whats_new_title: "Có gì mới trong v{}",
+                whats_new_close: "Đã hiểu",
                 download_update_btn: "Tải Về & Cập Nhật Ngay",
                 downloading_update: "Đang tải về... Ứng dụng sẽ cập nhật tại chỗ.",
                 update_failed: "Cập Nhật Thất Bại:",
@@ -323,11 +646,30 @@ impl LocaleText {
                 update_success: "Cập Nhật Thành Công!",
                 restart_to_use_new_version: "Khởi động lại để sử dụng phiên bản mới.",
                 restart_app_btn: "Khởi Động Lại Ứng Dụng",
+                rollback_btn: "Quay lại phiên bản trước",
+                update_channel_label: "Kênh cập nhật:",
+                update_channel_stable: "Ổn định",
+                update_channel_beta: "Beta",
+                update_channel_beta_warning: "⚠ Bản beta có thể chưa ổn định và có lỗi. Bạn có thể quay lại phiên bản trước bất cứ lúc nào.",
+                update_interval_label: "Tự động kiểm tra:",
+                update_interval_startup_only: "Chỉ khi khởi động",
+                update_interval_hours: "Mỗi {} giờ",
+                update_interval_weekly: "Mỗi tuần",
+                update_quiet_hours_label: "Giờ yên lặng:",
+                update_quiet_hours_tooltip: "Trong khoảng giờ này, ứng dụng không tự kiểm tra cập nhật và không hiện thông báo. Bản cập nhật mới vẫn được đánh dấu ở khay hệ thống và tại đây.",
+                update_last_checked: "Lần kiểm tra gần nhất:",
+                rollback_tooltip: "Khôi phục {} ở lần khởi động tiếp theo",
+                rollback_staged: "Phiên bản trước ({}) sẽ được khôi phục khi khởi động lại.",
                 // --- NEW TEXT INPUT FIELDS VI ---
                 text_input_mode_label: "Phương thức:",
                 text_mode_select: "Hotkey rồi bôi text",
                 text_mode_type: "Hotkey rồi gõ",
                 continuous_input_label: "Nhập liên tục",
+                remember_selection_label: "Nhớ vùng chọn",
+                remember_selection_tooltip: "Vùng đã chọn lần trước được vẽ sẵn: nhấn Enter để dùng lại, dùng phím mũi tên để chỉnh hoặc kéo vùng mới.",
+                redact_before_send_label: "Che vùng nhạy cảm trước khi gửi",
+                redact_before_send_tooltip: "Sau khi chọn vùng, kéo để tô đen thông tin riêng tư trong ảnh. Chỉ ảnh đã tô đen được gửi đi và lưu lại.",
+                redact_hint: "Kéo để tô đen · Enter gửi · Backspace hoàn tác · Esc hủy",
                 command_mode_label: "Lệnh:",
                 text_input_title_default: "Nhập văn bản cần xử lý:",
                 text_input_placeholder: "Nội dung cần xử lý ...",
@@ -411,9 +753,21 @@ impl LocaleText {
                 realtime_listening: "Đang nghe...",
                 realtime_device: "Thiết bị",
                 realtime_waiting: "Đang chờ nói...",
+                realtime_speaker_label: "Người nói {}:",
+                realtime_speaker_labels_tooltip: "Gắn nhãn người nói",
+                realtime_dictation_tooltip: "Gõ vào ứng dụng đang mở (bật/tắt)",
+                realtime_caption_tooltip: "Thanh phụ đề ({})",
+                realtime_jump_latest: "Xem mới nhất",
                 realtime_translation: "Bản dịch",
                 realtime_mic: "Micro",
                 ollama_url_guide: "Xem hướng dẫn tại ollama.com",
+                ollama_text_model_label: "Model văn bản:",
+                ollama_vision_model_label: "Model hình ảnh:",
+                ollama_model_default: "(theo preset)",
+                ollama_refresh_tooltip: "Tải lại danh sách model",
+                ollama_status_loading: "Đang kết nối...",
+                ollama_status_ok: "✓ {} model",
+                ollama_status_failed: "✗ Không kết nối được Ollama",
                 tts_settings_button: "Cài đặt giọng đọc",
                 tts_settings_title: "Thiết lập Giọng Đọc",
                 tts_method_label: "Phương pháp Đọc chữ (TTS):",
@@ -426,6 +780,8 @@ impl LocaleText {
                 tts_edge_desc: "Giọng nói chất lượng cao. Miễn phí, không cần khóa API.",
                 tts_pitch_label: "Cao độ:",
                 tts_rate_label: "Tốc độ:",
+                tts_playback_speed_label: "Tốc độ phát:",
+                tts_playback_speed_tooltip: "Tốc độ phát âm thanh đọc kết quả (giữ nguyên cao độ), cũng là tốc độ ban đầu của TTS dịch trực tiếp",
                 tts_voice_per_language_label: "Giọng theo ngôn ngữ:",
                 tts_loading_voices: "Đang tải danh sách giọng...",
                 tts_failed_load_voices: "Không thể tải giọng: {}",
@@ -459,26 +815,40 @@ impl LocaleText {
                 realtime_tts_title: "Đọc phần Dịch",
                 realtime_tts_speed: "Tốc độ",
                 realtime_tts_auto: "Tự động",
+                realtime_tts_playback: "Phát",
+                tts_pause: "Tạm dừng",
+                tts_resume: "Tiếp tục",
+                tts_stop: "Dừng",
                 // App selection modal
                 app_select_title: "Chọn Ứng Dụng",
                 app_select_hint: "Chọn ứng dụng cần ghi âm (TTS sẽ được tách riêng)",
                 // --- TRAY MENU VI ---
                 tray_settings: "⚙️ Cài đặt",
                 tray_quit: "Thoát",
+                tray_recent: "Gần đây",
                 tray_favorite_bubble: "Hiện bong bóng yêu thích",
                 tray_favorite_bubble_disabled: "Hiện bong bóng yêu thích (Chưa có mục yêu thích)",
                 // --- FAVORITE BUBBLE VI ---
                  favorites_empty: "Vui lòng đưa ít nhất một cấu hình vào ưa thích",
                  favorites_keep_open: "Giữ mở",
+                 favorite_bubbles_separate_label: "Mỗi preset yêu thích một bong bóng riêng",
+                 favorite_bubbles_separate_tooltip: "Bấm vào bong bóng để chạy preset. Nhấn giữ rồi kéo để di chuyển.",
                  recording_subtext: "Nhấn ESC/Hotkey để dừng",
                  recording_paused: "Đã tạm dừng",
                  // --- AUTO COPY BADGE VI ---
                  auto_copied_badge: "Đã tự động copy",
                  auto_copied_image_badge: "🖼️ Ảnh đã được sao chép",
                  live_translate_loading: "⏳ Đang khởi động Live Translate...",
+                 webview_recovering: "⏳ Cửa sổ gặp sự cố, đang khôi phục...",
                  text_input_loading: "⏳ Đang khởi động nhập văn bản...",
+                 secondary_language_notice: "🌐 Dịch sang {}",
+                 result_all_languages: "Tất cả ngôn ngữ",
                  recording_loading: "⏳ Đang khởi động ghi âm...",
                  markdown_view_loading: "⏳ Đang khởi động xem kết quả...",
+                 dual_pane_no_source: "Kết quả này không có văn bản gốc để xem song song",
+                 arena_pick_btn: "Chọn bản này",
+                 arena_picked: "✓ Đã sao chép",
+                 arena_faster: "nhanh hơn",
                  preset_wheel_loading: "⏳ Đang khởi động bảng chọn...",
                  prompt_dj_loading: "⏳ Đang khởi động Chill Corner...",
                  tray_popup_loading: "⏳ Đang khởi động menu...",
@@ -507,11 +877,63 @@ impl LocaleText {
                  help_assistant_hint: "Nhập câu hỏi về cách sử dụng SGT và nhấn Enter hoặc nút Hỏi",
                   prompt_dj_btn: "Góc chill chill",
                   prompt_dj_title: "PromptDJ - Góc chill chill",
+                  prompt_dj_midi_off: "MIDI: Tắt",
                   // --- PARAKEET DOWNLOAD MODAL VI ---
                   parakeet_downloading_title: "Đang tải mô hình Parakeet (0.6 GB)",
                   parakeet_downloading_message: "Vui lòng đợi...",
                   parakeet_downloading_file: "Đang tải {}...",
                   parakeet_supports_english_only: "(Chỉ hỗ trợ tiếng Anh)",
+                  // --- CRASH REPORT ---
+                  crash_report_title: "Báo cáo lỗi SGT",
+                  icon_settings: "Cài đặt",
+                  icon_hide_value: "Ẩn giá trị",
+                  icon_show_value: "Hiện giá trị",
+                  icon_microphone: "Micrô",
+                  icon_image: "Hình ảnh",
+                  icon_text: "Văn bản",
+                  icon_delete: "Xóa",
+                  icon_open_media: "Mở tệp phương tiện",
+                  icon_copy: "Sao chép",
+                  icon_close: "Đóng",
+                  icon_text_selection: "Chọn văn bản",
+                  icon_speak: "Đọc",
+                  icon_speech_disabled: "Đã tắt đọc",
+                  icon_auto_copy_disabled: "Đã tắt tự động sao chép",
+                  icon_tips: "Mẹo",
+                  icon_realtime: "Thời gian thực",
+                  icon_add_favorite: "Thêm vào yêu thích",
+                  icon_remove_favorite: "Bỏ khỏi yêu thích",
+                  icon_theme_light: "Giao diện: Sáng",
+                  icon_theme_dark: "Giao diện: Tối",
+                  icon_theme_system: "Giao diện: Hệ thống",
+                  icon_drag_to_reorder: "Kéo để sắp xếp",
+                  icon_history: "Lịch sử",
+                  instance_not_responding_title: "SGT đang chạy",
+                  instance_not_responding_msg: "Một phiên bản SGT khác đang chạy nhưng không phản hồi. Hãy đóng nó trong Trình quản lý tác vụ rồi mở lại.",
+                  crash_detected: "ỨNG DỤNG BỊ LỖI!",
+                  crash_error_label: "Lỗi:",
+                  crash_location_label: "Vị trí:",
+                  crash_log_saved: "Chi tiết lỗi đã được lưu tại (hãy đính kèm khi báo lỗi):",
+                  http_api_header: "API điều khiển HTTP",
+                  http_api_enable: "Bật API cục bộ (chỉ 127.0.0.1)",
+                  http_api_port_label: "Cổng:",
+                  http_api_token_label: "Mã truy cập:",
+                  http_api_copy_btn: "Sao chép",
+                  http_api_regenerate_btn: "Tạo mã mới",
+                  http_api_hint: "Gửi mã truy cập trong header của mỗi yêu cầu. Các endpoint:",
+                  backup_header: "Sao lưu & khôi phục",
+                  global_prompt_header: "Lệnh chung cho mọi preset",
+                  global_prompt_hint: "Thêm vào trước/sau lệnh của mọi khối dùng AI, ví dụ \"Luôn trả lời ngắn gọn và giữ nguyên định dạng\". Có thể dùng biến như {date}. Từng preset có thể tắt trong cài đặt của nó.",
+                  global_prompt_prefix_label: "Đầu lệnh:",
+                  global_prompt_suffix_label: "Cuối lệnh:",
+                  backup_export_btn: "Xuất cài đặt",
+                  backup_import_btn: "Nhập cài đặt",
+                  backup_include_api_keys: "Kèm API key",
+                  backup_file_type: "Bản sao lưu SGT (*.sgtbackup)",
+                  backup_export_done: "Đã xuất bản sao lưu.",
+                  backup_import_done: "Đã nhập cài đặt. Cài đặt cũ được lưu dưới dạng .pre-import.bak.",
+                  backup_failed: "Lỗi:",
+                  backup_overwrite_keys_msg: "Bản sao lưu chứa API key khác với API key hiện tại. Thay thế API key hiện tại?",
                  },
             "ko" => Self {
                  history_btn: "히스토리",
@@ -522,6 +944,7 @@ impl LocaleText {
                  view_image_btn: "이미지 보기",
                  listen_audio_btn: "오디오 듣기",
                  view_text_btn: "텍스트 보기",
+                 history_unknown_source: "알 수 없는 모델",
 
                  prompt_mode_fixed: "사전 정의된 프롬프트",
                  prompt_mode_dynamic: "즉석에서 작성",
@@ -536,6 +959,25 @@ impl LocaleText {
                 use_openrouter_checkbox: "OpenRouter",
                 cerebras_api_key_label: "Cerebras API 키:",
                 cerebras_get_key_link: "cloud.cerebras.ai에서 API 키 받기",
+                api_key_test_button: "테스트",
+                api_key_test_tooltip: "작은 요청을 보내 이 키를 확인합니다",
+                api_key_valid: "유효한 키 ({} 확인)",
+                api_key_invalid: "사용할 수 없는 키 ({} 확인)",
+                encrypt_api_keys_checkbox: "설정 파일의 API 키 암호화",
+                encrypt_api_keys_tooltip: "암호화된 키는 이 Windows 사용자만 읽을 수 있습니다. 여러 PC 간에 설정 파일을 동기화한다면 끄세요.",
+                privacy_scan_label: "전송 전 개인정보 경고",
+                privacy_scan_tooltip: "이미지에서 읽은 텍스트에 이메일, 전화번호, 카드 번호 형태가 있는지 로컬에서 확인하고 다음 단계로 보내기 전에 묻습니다.",
+                privacy_patterns_label: "패턴 (정규식, 한 줄에 하나):",
+                privacy_reset_patterns_btn: "기본값으로 복원",
+                privacy_confirm_title: "개인정보",
+                privacy_confirm_msg: "이 이미지에 개인정보가 포함된 것 같습니다. 그래도 보낼까요?",
+                clear_clipboard_on_exit_label: "종료 시 클립보드 지우기",
+                clear_clipboard_on_exit_tooltip: "종료할 때 클립보드에 앱이 복사한 텍스트가 남아 있으면 지웁니다.",
+                clipboard_clear_after_label: "복사한 텍스트 지우기:",
+                clipboard_clear_after_tooltip: "앱이 텍스트를 복사한 뒤 이 시간이 지나면 클립보드를 지웁니다. 그 사이 다른 것을 복사했다면 그대로 둡니다.",
+                request_timeouts_label: "요청 시간 제한",
+                request_timeouts_hint: "공급자가 응답을 시작할 때까지 기다리는 시간(초)입니다. 지나면 시간 초과 오류가 표시됩니다.",
+                clipboard_keep_label: "유지",
                 use_cerebras_checkbox: "Cerebras",
 
                 global_settings: "전역 설정",
@@ -547,10 +989,40 @@ impl LocaleText {
 
                 auto_paste_label: "자동 붙여넣기",
                 auto_paste_newline_label: "복사 후 자동 줄바꿈 추가",
+                auto_paste_elevated_msg: "관리자 권한으로 실행 중인 창에는 자동 붙여넣기를 할 수 없습니다. 결과가 복사되었으니 Ctrl+V를 누르거나 SGT를 관리자로 실행하세요.",
+                auto_copy_target_label: "자동 복사:",
+                auto_copy_per_block: "블록별 설정",
+                auto_copy_final_block: "마지막 블록",
+                auto_copy_each_block: "모든 블록",
+                auto_copy_off: "끄기",
+                auto_copy_append_label: "클립보드에 이어 붙이기",
+                auto_copy_append_tooltip: "클립보드 내용을 바꾸지 않고 기존 텍스트 뒤에 결과를 추가합니다",
+                arena_mode_label: "비교 (아레나)",
+                arena_mode_tooltip: "처음 두 텍스트 분기를 한 창에 나란히 표시하고 더 나은 결과를 고릅니다",
                 startup_label: "Windows 시작 시 실행",
                 add_hotkey_button: "+ 키 추가",
                 press_keys: "조합 키 누르기...",
                 cancel_label: "취소",
+                app_hotkeys_header: "앱 단축키",
+                app_hotkey_open_settings: "설정 열기",
+                app_hotkey_dismiss_overlays: "모든 오버레이 닫기",
+                app_hotkey_toggle_bubble: "즐겨찾기 버블 켜기/끄기",
+                app_hotkey_repeat_last: "마지막 프리셋 다시 실행",
+                repeat_last_none: "아직 사용한 프리셋이 없습니다",
+                app_hotkey_translate_clipboard: "클립보드 번역",
+                app_hotkey_translate_selection: "선택한 텍스트 번역",
+                app_hotkey_undo_replace: "마지막 바꾸기 실행 취소",
+                quick_translate_header: "빠른 번역",
+                quick_translate_hint: "트레이의 \"클립보드 번역\" / \"선택한 텍스트 번역\"과 해당 단축키에 사용됩니다. 프리셋이 필요 없습니다.",
+                quick_translate_language_label: "번역할 언어:",
+                quick_translate_provider_label: "제공자:",
+                quick_translate_provider_auto: "자동 (전역)",
+                quick_translate_name: "빠른 번역",
+                quick_translate_nothing: "번역할 텍스트가 없습니다",
+                hotkey_unavailable_tooltip: "이 단축키는 다른 앱이나 Windows에서 이미 사용 중이라 작동하지 않습니다. 다른 단축키를 선택하세요 (클릭하여 삭제).",
+                hotkeys_unavailable_notification: "⚠ 이미 사용 중인 단축키: {}",
+                hotkey_feedback_checkbox: "프리셋 단축키를 누르면 알림 표시",
+                hotkey_feedback_tooltip: "단축키가 인식되면 창이나 녹음이 시작되기 전에 프리셋 이름을 바로 표시합니다. 이미지 프리셋에는 표시되지 않습니다 (스크린샷에 찍히기 때문).",
                 reset_defaults_btn: "기본값으로 재설정",
 
 
@@ -568,8 +1040,12 @@ impl LocaleText {
                 audio_src_device: "컴퓨터 오디오",
                 hide_recording_ui_label: "녹음 UI 숨기기",
                 auto_stop_recording_label: "자동 중지",
+                pronunciation_practice_label: "발음 채점",
+                pronunciation_practice_tooltip: "목표 문장과 단어별로 비교해 점수를 매깁니다. 녹음 전에 연습할 문장을 복사하세요",
                 hotkeys_section: "단축키",
                 start_in_tray_label: "트레이로 시작",
+                disable_warmups_label: "필요할 때 창 불러오기 (저사양 PC)",
+                disable_warmups_tooltip: "시작할 때 WebView 창을 미리 만들지 않고 처음 사용할 때 만듭니다 (처음 열 때 조금 느려집니다). 다시 시작한 후 적용됩니다.",
                 footer_admin_running: "관리자 권한으로 실행 중",
                 admin_startup_on: "시작 시 관리자로 실행",
                 admin_startup_success: "활성화됨: 시작 시 관리자 권한으로 실행됩니다 (작업 스케줄러).",
@@ -577,11 +1053,66 @@ impl LocaleText {
                 graphics_mode_label: "그래픽:",
                 graphics_mode_standard: "표준 (그래디언트 글로우 효과)",
                 graphics_mode_minimal: "최소 (약한 컴퓨터용, 레이저 스캔 효과)",
+                ui_scale_label: "UI 크기:",
+                sidebar_layout_label: "목록 레이아웃:",
+                sidebar_layout_columns: "여러 열",
+                sidebar_layout_compact: "컴팩트 (한 열)",
+                overlay_font_label: "오버레이 글꼴:",
+                overlay_font_system: "시스템 글꼴",
+                overlay_font_script_warning: "⚠ 이 글꼴에는 {} 문자가 없어 대체 글꼴로 표시됩니다",
+                wheel_settings_label: "프리셋 휠:",
+                wheel_item_size_label: "크기",
+                wheel_radius_label: "간격",
+                wheel_release_to_select_label: "단축키를 떼면 가리킨 프리셋 선택",
+                wheel_release_to_select_tooltip: "단축키를 누른 채 프리셋을 가리키고 키를 떼세요. 아무것도 가리키지 않으면 평소처럼 클릭해서 선택합니다.",
+                selection_adjust_label: "확인 전에 방향키로 선택 영역 조정",
+                selection_adjust_tooltip: "드래그 후 선택 영역이 유지됩니다. 방향키는 오른쪽/아래 가장자리를, Ctrl+방향키는 영역 전체를 이동하며 Shift를 누르면 10px씩 움직입니다. Enter로 확인, Esc로 취소합니다. 드래그 중 Shift를 누르면 이전 선택의 비율로 고정됩니다.",
+                log_level_label: "로그 수준:",
+                log_level_off: "끄기",
+                log_level_error: "오류만",
+                log_level_info: "정보",
+                log_level_debug: "디버그 (상세)",
+                open_log_folder_btn: "로그 폴더 열기",
+                webview_lifecycle_label: "WebView 모드:",
+                webview_lifecycle_tooltip: "항상 유지: Prompt DJ와 실시간 번역이 바로 열리지만 메모리를 계속 사용합니다. 열 때 로드: 열 때마다 새로 만들고 닫으면 해제합니다 (열기가 느려지는 대신 메모리를 덜 씁니다).",
+                webview_lifecycle_persistent: "항상 유지 (빠름)",
+                webview_lifecycle_lazy: "열 때 로드 (메모리 절약)",
+                webview_memory_btn: "메모리 측정",
+                webview_memory_value: "{} MB ({}개 프로세스)",
+                diagnostics_header: "진단 정보",
+                diagnostics_refresh_btn: "새로 고침",
+                diagnostics_copy_btn: "복사",
+                diagnostics_dpi_test_btn: "DPI 테스트",
+                diagnostics_dpi_test_tooltip: "모든 모니터에 정확히 100 × 100 픽셀 상자를 표시합니다. 상자가 늘어나거나 흐리면 캡처와 오버레이 좌표가 어긋날 수 있습니다.",
                 usage_statistics_title: "사용 통계",
                 usage_statistics_tooltip: "정확한 데이터를 보려면 모델을 최소 한 번 사용하세요",
                 usage_model_column: "모델",
                 usage_remaining_column: "남은 / 전체",
                 usage_check_link: "사용량 확인 ↗",
+                usage_charts_title: "최근 30일",
+                usage_charts_provider_label: "제공자:",
+                usage_charts_all_providers: "전체",
+                export_csv_btn: "CSV 내보내기",
+                export_json_btn: "JSON 내보내기",
+                export_done: "내보냄:",
+                export_failed: "내보내기 실패:",
+                snapshot_copied: "창 이미지를 복사했습니다 (Shift+클릭하면 PNG로 저장)",
+                snapshot_failed: "창을 캡처하지 못했습니다",
+                markdown_toc_label: "목차",
+                selection_dim_label: "영역 선택 시 어둡게:",
+                selection_border_color_label: "선택 영역 테두리 색:",
+                text_replaced_label: "바꿈:",
+                text_replace_undone: "원래 텍스트를 복원했습니다",
+                text_replace_nothing_to_undo: "실행 취소할 항목이 없습니다",
+                post_command_confirm_title: "외부 명령을 실행할까요?",
+                post_command_confirm_msg: "\"{}\" 프리셋이 모든 결과에 다음 명령을 실행합니다:\n\n{}\n\n신뢰하는 명령인 경우에만 허용하세요. 실행할까요?",
+                post_command_failed: "후처리 명령 실패:",
+                post_command_timed_out: "후처리 명령이 너무 오래 걸려 중지했습니다",
+                table_export_none: "결과에서 표를 찾을 수 없습니다",
+                usage_charts_requests_label: "일별 요청 수",
+                usage_charts_tokens_label: "일별 예상 토큰 (문자 수 / 4)",
+                usage_charts_preset_column: "프리셋",
+                usage_charts_requests_column: "요청",
 
                 footer_admin_text: "게임을 번역하려면 관리자로 실행하세요",
                 footer_version: "버전",
@@ -592,6 +1123,8 @@ impl LocaleText {
                 check_again_btn: "다시 확인",
                 new_version_available: "새 버전을 사용할 수 있습니다:",
                 release_notes_label: "릴리스 노트",
+                whats_new_title: "v{}의 새로운 기능",
+                whats_new_close: "확인",
                 download_update_btn: "지금 다운로드 & 업데이트",
                 downloading_update: "다운로드 중... 앱이 제자리에서 업데이트됩니다.",
                 update_failed: "업데이트 실패:",
@@ -600,11 +1133,30 @@ impl LocaleText {
                 update_success: "업데이트 성공!",
                 restart_to_use_new_version: "새 버전을 사용하려면 다시 시작하세요.",
                 restart_app_btn: "앱 다시 시작",
+                rollback_btn: "이전 버전으로 되돌리기",
+                update_channel_label: "업데이트 채널:",
+                update_channel_stable: "안정",
+                update_channel_beta: "베타",
+                update_channel_beta_warning: "⚠ 베타 빌드는 불안정하거나 버그가 있을 수 있습니다. 언제든지 이전 버전으로 되돌릴 수 있습니다.",
+                update_interval_label: "자동 확인:",
+                update_interval_startup_only: "시작할 때만",
+                update_interval_hours: "{}시간마다",
+                update_interval_weekly: "매주",
+                update_quiet_hours_label: "방해 금지 시간:",
+                update_quiet_hours_tooltip: "이 시간 동안에는 자동으로 업데이트를 확인하거나 알림을 표시하지 않습니다. 새 업데이트는 트레이 아이콘과 이곳에 계속 표시됩니다.",
+                update_last_checked: "마지막 확인:",
+                rollback_tooltip: "다음 시작 시 {}(으)로 복원합니다",
+                rollback_staged: "다시 시작하면 이전 버전({})이 복원됩니다.",
                 // --- NEW TEXT INPUT FIELDS KO ---
                 text_input_mode_label: "작동 방식:",
                 text_mode_select: "단축키 후 텍스트 선택",
                 text_mode_type: "단축키 후 입력",
                 continuous_input_label: "연속 입력",
+                remember_selection_label: "선택 영역 기억",
+                remember_selection_tooltip: "지난번 선택 영역이 미리 그려집니다. Enter로 다시 사용하거나, 방향키로 조정하거나, 새로 드래그하세요.",
+                redact_before_send_label: "보내기 전에 민감한 영역 가리기",
+                redact_before_send_tooltip: "영역을 선택한 후 드래그하여 개인 정보를 검게 칠합니다. 가려진 이미지만 전송되고 저장됩니다.",
+                redact_hint: "드래그하여 가리기 · Enter 전송 · Backspace 되돌리기 · Esc 취소",
                 command_mode_label: "명령:",
                 text_input_title_default: "처리할 텍스트 입력:",
                 text_input_placeholder: "처리할 내용 ...",
@@ -688,9 +1240,21 @@ impl LocaleText {
                 realtime_listening: "듣고 있는 중...",
                 realtime_device: "장치 오디오",
                 realtime_waiting: "말하기 대기 중...",
+                realtime_speaker_label: "화자 {}:",
+                realtime_speaker_labels_tooltip: "화자 구분",
+                realtime_dictation_tooltip: "현재 앱에 입력 (켜기/끄기)",
+                realtime_caption_tooltip: "자막 바 ({})",
+                realtime_jump_latest: "최신으로 이동",
                 realtime_translation: "번역",
                 realtime_mic: "마이크",
                 ollama_url_guide: "올라마 설명서 보기",
+                ollama_text_model_label: "텍스트 모델:",
+                ollama_vision_model_label: "이미지 모델:",
+                ollama_model_default: "(프리셋 설정)",
+                ollama_refresh_tooltip: "모델 목록 새로고침",
+                ollama_status_loading: "연결 중...",
+                ollama_status_ok: "✓ 모델 {}개",
+                ollama_status_failed: "✗ Ollama에 연결할 수 없음",
                 tts_settings_button: "TTS 설정",
                 tts_settings_title: "TTS 설정",
                 tts_method_label: "TTS 방식:",
@@ -703,6 +1267,8 @@ impl LocaleText {
                 tts_edge_desc: "고품질 신경망 음성. 무료이며 API 키가 필요하지 않습니다.",
                 tts_pitch_label: "피치:",
                 tts_rate_label: "속도:",
+                tts_playback_speed_label: "재생 속도:",
+                tts_playback_speed_tooltip: "결과 읽기 재생 속도 (음높이 유지), 실시간 번역 TTS의 시작 속도이기도 합니다",
                 tts_voice_per_language_label: "언어별 음성:",
                 tts_loading_voices: "음성 목록을 불러오는 중...",
                 tts_failed_load_voices: "음성 로드 실패: {}",
@@ -736,26 +1302,40 @@ impl LocaleText {
                 realtime_tts_title: "번역 읽기",
                 realtime_tts_speed: "속도",
                 realtime_tts_auto: "자동",
+                realtime_tts_playback: "재생",
+                tts_pause: "일시정지",
+                tts_resume: "계속",
+                tts_stop: "중지",
                 // App selection modal
                 app_select_title: "앱 선택",
                 app_select_hint: "녹음할 앱을 선택하세요 (TTS는 분리됨)",
                 // --- TRAY MENU KO ---
                 tray_settings: "⚙️ 설정",
                 tray_quit: "종료",
+                tray_recent: "최근 결과",
                 tray_favorite_bubble: "즐겨찾기 버블 표시",
                 tray_favorite_bubble_disabled: "즐겨찾기 버블 표시 (즐겨찾기 없음)",
                 // --- FAVORITE BUBBLE KO ---
                  favorites_empty: "즐겨찾기에 최소한 하나의 프리셋을 추가해주세요",
                  favorites_keep_open: "열린 상태 유지",
+                 favorite_bubbles_separate_label: "즐겨찾기 프리셋마다 별도 버블 표시",
+                 favorite_bubbles_separate_tooltip: "버블을 클릭하면 프리셋이 실행됩니다. 길게 누른 채 끌어서 옮길 수 있습니다.",
                  recording_subtext: "ESC/Hotkey를 눌러 중지",
                  recording_paused: "일시 중지됨",
                  // --- AUTO COPY BADGE KO ---
                  auto_copied_badge: "자동으로 복사됨",
                  auto_copied_image_badge: "🖼️ 이미지가 복사됨",
                  live_translate_loading: "⏳ 실시간 번역 로딩 중...",
+                 webview_recovering: "⏳ 창에 문제가 생겨 복구하는 중...",
                  text_input_loading: "⏳ 텍스트 입력 로딩 중...",
+                 secondary_language_notice: "🌐 {}(으)로 번역",
+                 result_all_languages: "모든 언어",
                  recording_loading: "⏳ 녹음 로딩 중...",
                  markdown_view_loading: "⏳ 결과 보기 로딩 중...",
+                 dual_pane_no_source: "이 결과에는 나란히 볼 원문이 없습니다",
+                 arena_pick_btn: "이것 선택",
+                 arena_picked: "✓ 복사됨",
+                 arena_faster: "더 빠름",
                  preset_wheel_loading: "⏳ 선택 휠 로딩 중...",
                  prompt_dj_loading: "⏳ Chill Corner 로딩 중...",
                  tray_popup_loading: "⏳ 메뉴 로딩 중...",
@@ -784,11 +1364,63 @@ impl LocaleText {
                  help_assistant_hint: "SGT 사용법에 대한 질문을 입력하고 Enter 또는 질문 버튼을 누르세요",
                   prompt_dj_btn: "힐링 공간",
                   prompt_dj_title: "PromptDJ - 힐링 공간",
+                  prompt_dj_midi_off: "MIDI: 끔",
                   // --- PARAKEET DOWNLOAD MODAL KO ---
                   parakeet_downloading_title: "Parakeet 모델 다운로드 중 (0.6 GB)",
                   parakeet_downloading_message: "잠시만 기다려주세요...",
                   parakeet_downloading_file: "{} 다운로드 중...",
                   parakeet_supports_english_only: "(영어만 지원됨)",
+                  // --- CRASH REPORT ---
+                  crash_report_title: "SGT 오류 보고",
+                  icon_settings: "설정",
+                  icon_hide_value: "값 숨기기",
+                  icon_show_value: "값 표시",
+                  icon_microphone: "마이크",
+                  icon_image: "이미지",
+                  icon_text: "텍스트",
+                  icon_delete: "삭제",
+                  icon_open_media: "미디어 열기",
+                  icon_copy: "복사",
+                  icon_close: "닫기",
+                  icon_text_selection: "텍스트 선택",
+                  icon_speak: "읽기",
+                  icon_speech_disabled: "읽기 꺼짐",
+                  icon_auto_copy_disabled: "자동 복사 꺼짐",
+                  icon_tips: "팁",
+                  icon_realtime: "실시간",
+                  icon_add_favorite: "즐겨찾기에 추가",
+                  icon_remove_favorite: "즐겨찾기에서 제거",
+                  icon_theme_light: "테마: 라이트",
+                  icon_theme_dark: "테마: 다크",
+                  icon_theme_system: "테마: 시스템",
+                  icon_drag_to_reorder: "끌어서 순서 변경",
+                  icon_history: "기록",
+                  instance_not_responding_title: "SGT 실행 중",
+                  instance_not_responding_msg: "다른 SGT 인스턴스가 실행 중이지만 응답하지 않습니다. 작업 관리자에서 종료한 뒤 다시 실행하세요.",
+                  crash_detected: "프로그램이 비정상 종료되었습니다!",
+                  crash_error_label: "오류:",
+                  crash_location_label: "위치:",
+                  crash_log_saved: "오류 기록이 저장되었습니다 (버그 신고 시 첨부해 주세요):",
+                  http_api_header: "HTTP 제어 API",
+                  http_api_enable: "로컬 API 사용 (127.0.0.1 전용)",
+                  http_api_port_label: "포트:",
+                  http_api_token_label: "토큰:",
+                  http_api_copy_btn: "복사",
+                  http_api_regenerate_btn: "재생성",
+                  http_api_hint: "모든 요청의 헤더에 토큰을 포함하세요. 엔드포인트:",
+                  backup_header: "백업 및 복원",
+                  global_prompt_header: "모든 프리셋 공통 지시문",
+                  global_prompt_hint: "AI를 쓰는 모든 블록의 프롬프트 앞/뒤에 붙습니다. 예: \"항상 간결하게 답하고 서식을 유지하세요\". {date} 같은 변수도 쓸 수 있습니다. 프리셋마다 설정에서 끌 수 있습니다.",
+                  global_prompt_prefix_label: "앞에 붙일 내용:",
+                  global_prompt_suffix_label: "뒤에 붙일 내용:",
+                  backup_export_btn: "설정 내보내기",
+                  backup_import_btn: "설정 가져오기",
+                  backup_include_api_keys: "API 키 포함",
+                  backup_file_type: "SGT 백업 (*.sgtbackup)",
+                  backup_export_done: "백업을 내보냈습니다.",
+                  backup_import_done: "설정을 가져왔습니다. 이전 설정은 .pre-import.bak으로 저장되었습니다.",
+                  backup_failed: "오류:",
+                  backup_overwrite_keys_msg: "백업에 현재와 다른 API 키가 있습니다. 현재 API 키를 교체할까요?",
                  },
                 _ => Self {
                  history_btn: "History",
@@ -799,6 +1431,7 @@ impl LocaleText {
                  view_image_btn: "View Image",
                  listen_audio_btn: "Listen Audio",
                  view_text_btn: "View Text",
+                 history_unknown_source: "unknown model",
 
                  prompt_mode_fixed: "Predefined Prompt",
                  prompt_mode_dynamic: "Write on the spot",
@@ -813,6 +1446,25 @@ impl LocaleText {
                 use_openrouter_checkbox: "OpenRouter",
                 cerebras_api_key_label: "Cerebras API Key:",
                 cerebras_get_key_link: "Get API Key at cloud.cerebras.ai",
+                api_key_test_button: "Test",
+                api_key_test_tooltip: "Send a minimal request to check this key",
+                api_key_valid: "Key works (checked {})",
+                api_key_invalid: "Key failed (checked {})",
+                encrypt_api_keys_checkbox: "Encrypt API keys in the config file",
+                encrypt_api_keys_tooltip: "Encrypted keys can only be read by this Windows user. Turn off if you sync the config file between machines.",
+                privacy_scan_label: "Warn about personal data before sending",
+                privacy_scan_tooltip: "After text is read from an image, check it locally for emails, phone numbers and card-like numbers, and ask before sending it on to the next blocks.",
+                privacy_patterns_label: "Patterns (regex, one per line):",
+                privacy_reset_patterns_btn: "Reset to defaults",
+                privacy_confirm_title: "Personal data",
+                privacy_confirm_msg: "This image appears to contain personal data — send anyway?",
+                clear_clipboard_on_exit_label: "Clear clipboard on exit",
+                clear_clipboard_on_exit_tooltip: "On exit, empty the clipboard if it still holds text the app copied.",
+                clipboard_clear_after_label: "Clear copied text after:",
+                clipboard_clear_after_tooltip: "Empty the clipboard this long after the app copies text, unless you have copied something else since.",
+                request_timeouts_label: "Request timeouts",
+                request_timeouts_hint: "Seconds to wait for a provider to start answering before the request fails with a timeout.",
+                clipboard_keep_label: "Keep",
                  use_cerebras_checkbox: "Cerebras",
                 global_settings: "Global Settings",
                  preset_name_label: "Preset Name:",
@@ -823,10 +1475,40 @@ impl LocaleText {
 
                 auto_paste_label: "Auto-paste",
                 auto_paste_newline_label: "Auto add newline after copy",
+                auto_paste_elevated_msg: "Can't auto-paste into a window running as admin. The result is copied: press Ctrl+V, or run SGT as admin.",
+                auto_copy_target_label: "Auto-copy:",
+                auto_copy_per_block: "Per block",
+                auto_copy_final_block: "Final block",
+                auto_copy_each_block: "Each block",
+                auto_copy_off: "Off",
+                auto_copy_append_label: "Append to clipboard",
+                auto_copy_append_tooltip: "Add results after the text already on the clipboard instead of replacing it",
+                arena_mode_label: "Compare (Arena)",
+                arena_mode_tooltip: "Show the first two text branches side by side in one window and pick the better answer",
                 startup_label: "Run at Windows Startup",
                 add_hotkey_button: "+ Add Key",
                 press_keys: "Press combination...",
                 cancel_label: "Cancel",
+                app_hotkeys_header: "App hotkeys",
+                app_hotkey_open_settings: "Open settings",
+                app_hotkey_dismiss_overlays: "Dismiss all overlays",
+                app_hotkey_toggle_bubble: "Toggle favorite bubble",
+                app_hotkey_repeat_last: "Repeat last preset",
+                repeat_last_none: "No preset used yet",
+                app_hotkey_translate_clipboard: "Translate clipboard",
+                app_hotkey_translate_selection: "Translate selection",
+                app_hotkey_undo_replace: "Undo last replace",
+                quick_translate_header: "Quick translate",
+                quick_translate_hint: "Used by \"Translate clipboard\" / \"Translate selection\" in the tray and their hotkeys, no preset needed.",
+                quick_translate_language_label: "Translate to:",
+                quick_translate_provider_label: "Provider:",
+                quick_translate_provider_auto: "Auto (global)",
+                quick_translate_name: "Quick translate",
+                quick_translate_nothing: "No text to translate",
+                hotkey_unavailable_tooltip: "Another app or Windows already uses this shortcut, so it won't trigger. Pick a different one (click to remove).",
+                hotkeys_unavailable_notification: "⚠ Shortcut already in use: {}",
+                hotkey_feedback_checkbox: "Show a toast when a preset hotkey is pressed",
+                hotkey_feedback_tooltip: "Shows the preset name the moment its hotkey is recognized, before any window or recording appears. Not shown for image presets, where it would end up in the screenshot.",
                 reset_defaults_btn: "Reset to Defaults",
 
 
@@ -844,8 +1526,12 @@ impl LocaleText {
                 audio_src_device: "Device Audio",
                 hide_recording_ui_label: "Hide Recording UI",
                 auto_stop_recording_label: "Auto-stop",
+                pronunciation_practice_label: "Pronunciation scoring",
+                pronunciation_practice_tooltip: "Score each word against a target phrase. Copy the phrase to practice before recording",
                 hotkeys_section: "Hotkeys",
                 start_in_tray_label: "Start in tray",
+                disable_warmups_label: "Load windows on demand (low-end PCs)",
+                disable_warmups_tooltip: "Don't prepare the WebView windows at startup; each one is created the first time it's used (so it opens slower that time). Takes effect after a restart.",
                 footer_admin_running: "running as admin",
                 admin_startup_on: "Run as Administrator on startup",
                 admin_startup_success: "Enabled: Will run as Admin on startup (Task Scheduler).",
@@ -853,11 +1539,66 @@ impl LocaleText {
                 graphics_mode_label: "Graphics:",
                 graphics_mode_standard: "Standard (Gradient glow effect)",
                 graphics_mode_minimal: "Minimal for weak PC (Laser scan effect)",
+                ui_scale_label: "UI scale:",
+                sidebar_layout_label: "Preset list layout:",
+                sidebar_layout_columns: "Columns",
+                sidebar_layout_compact: "Compact (single column)",
+                overlay_font_label: "Overlay font:",
+                overlay_font_system: "System font",
+                overlay_font_script_warning: "⚠ This font has no {} characters; they'll be drawn with a fallback font",
+                wheel_settings_label: "Preset wheel:",
+                wheel_item_size_label: "Size",
+                wheel_radius_label: "Spread",
+                wheel_release_to_select_label: "Release the hotkey to pick the hovered preset",
+                wheel_release_to_select_tooltip: "Hold the hotkey, point at a preset and let go. If nothing is hovered, click to pick as usual.",
+                selection_adjust_label: "Adjust the selection with the arrow keys before confirming",
+                selection_adjust_tooltip: "After the drag the box stays: arrow keys move its right/bottom edge, Ctrl+arrows move the whole box, hold Shift for 10px steps. Enter confirms, Esc cancels. Holding Shift while dragging keeps the aspect ratio of the previous selection.",
+                log_level_label: "Log level:",
+                log_level_off: "Off",
+                log_level_error: "Errors only",
+                log_level_info: "Info",
+                log_level_debug: "Debug (verbose)",
+                open_log_folder_btn: "Open log folder",
+                webview_lifecycle_label: "WebView mode:",
+                webview_lifecycle_tooltip: "Keep loaded: Prompt DJ and Live Translate open instantly but always use memory. Load on open: they're rebuilt each time they open and freed when closed (slower to open, less memory).",
+                webview_lifecycle_persistent: "Keep loaded (faster)",
+                webview_lifecycle_lazy: "Load on open (less memory)",
+                webview_memory_btn: "Measure memory",
+                webview_memory_value: "{} MB in {} processes",
+                diagnostics_header: "Diagnostics",
+                diagnostics_refresh_btn: "Refresh",
+                diagnostics_copy_btn: "Copy",
+                diagnostics_dpi_test_btn: "DPI test",
+                diagnostics_dpi_test_tooltip: "Show a box of exactly 100 × 100 pixels on every monitor. If a box looks stretched or blurry, capture and overlay coordinates may be misaligned.",
                 usage_statistics_title: "Usage Statistics",
                 usage_statistics_tooltip: "Use a model at least once for accurate data",
                 usage_model_column: "Model",
                 usage_remaining_column: "Remaining / Total",
                 usage_check_link: "Check Usage ↗",
+                usage_charts_title: "Last 30 days",
+                usage_charts_provider_label: "Provider:",
+                usage_charts_all_providers: "All",
+                export_csv_btn: "Export CSV",
+                export_json_btn: "Export JSON",
+                export_done: "Exported:",
+                export_failed: "Export failed:",
+                snapshot_copied: "Window image copied (Shift+click to also save it as PNG)",
+                snapshot_failed: "Couldn't capture the window",
+                markdown_toc_label: "Contents",
+                selection_dim_label: "Dim screen while selecting:",
+                selection_border_color_label: "Selection border color:",
+                text_replaced_label: "Replaced",
+                text_replace_undone: "Restored the original text",
+                text_replace_nothing_to_undo: "Nothing to undo",
+                post_command_confirm_title: "Run an external command?",
+                post_command_confirm_msg: "The preset \"{}\" runs this command on each result:\n\n{}\n\nOnly allow it if you trust the command. Run it?",
+                post_command_failed: "Post-processing command failed:",
+                post_command_timed_out: "Post-processing command took too long and was stopped",
+                table_export_none: "No table found in this result",
+                usage_charts_requests_label: "Requests per day",
+                usage_charts_tokens_label: "Estimated tokens per day (characters / 4)",
+                usage_charts_preset_column: "Preset",
+                usage_charts_requests_column: "Requests",
 
                 footer_admin_text: "Run with admin to translate games",
                 footer_version: "Version",
@@ -868,6 +1609,8 @@ impl LocaleText {
                 check_again_btn: "Check Again",
                 new_version_available: "New version available:",
                 release_notes_label: "Release Notes",
+                whats_new_title: "What's new in v{}",
+                whats_new_close: "Got it",
                 download_update_btn: "Download & Update Now",
                 downloading_update: "Downloading... The app will update in-place.",
                 update_failed: "Update Failed:",
@@ -876,11 +1619,30 @@ impl LocaleText {
                 update_success: "Update Success!",
                 restart_to_use_new_version: "Restart to use the new version.",
                 restart_app_btn: "Restart App",
+                rollback_btn: "Roll back to previous version",
+                update_channel_label: "Update channel:",
+                update_channel_stable: "Stable",
+                update_channel_beta: "Beta",
+                update_channel_beta_warning: "⚠ Beta builds may be unstable and have bugs. You can roll back to the previous version at any time.",
+                update_interval_label: "Check automatically:",
+                update_interval_startup_only: "Only at startup",
+                update_interval_hours: "Every {} hours",
+                update_interval_weekly: "Weekly",
+                update_quiet_hours_label: "Quiet hours:",
+                update_quiet_hours_tooltip: "No automatic update checks or popups during these hours. A new update is still marked on the tray icon and here.",
+                update_last_checked: "Last checked:",
+                rollback_tooltip: "Restore {} on the next start",
+                rollback_staged: "The previous version ({}) will be restored when the app restarts.",
                 // --- NEW TEXT INPUT FIELDS EN ---
                 text_input_mode_label: "Mode:",
                 text_mode_select: "Hotkey then Select Text",
                 text_mode_type: "Hotkey then Type",
                 continuous_input_label: "Continuous Input",
+                remember_selection_label: "Remember selection",
+                remember_selection_tooltip: "The last selected region is pre-drawn: press Enter to reuse it, nudge it with the arrow keys, or drag a new one.",
+                redact_before_send_label: "Black out sensitive areas before sending",
+                redact_before_send_tooltip: "After selecting, drag to paint black boxes over private information. Only the blacked-out image is sent and kept.",
+                redact_hint: "Drag to black out · Enter to send · Backspace to undo · Esc to cancel",
                 command_mode_label: "Command:",
                 text_input_title_default: "Enter text to process:",
                 text_input_placeholder: "Content to process...",
@@ -964,9 +1726,21 @@ impl LocaleText {
                 realtime_listening: "Listening...",
                 realtime_device: "Device",
                 realtime_waiting: "Waiting for speech...",
+                realtime_speaker_label: "Speaker {}:",
+                realtime_speaker_labels_tooltip: "Label speakers",
+                realtime_dictation_tooltip: "Type into the focused app (on/off)",
+                realtime_caption_tooltip: "Caption bar ({})",
+                realtime_jump_latest: "Jump to latest",
                 realtime_translation: "Translation",
                 realtime_mic: "Mic",
                 ollama_url_guide: "View guide at ollama.com",
+                ollama_text_model_label: "Text model:",
+                ollama_vision_model_label: "Vision model:",
+                ollama_model_default: "(preset's model)",
+                ollama_refresh_tooltip: "Reload the model list",
+                ollama_status_loading: "Connecting...",
+                ollama_status_ok: "✓ {} models",
+                ollama_status_failed: "✗ Can't reach Ollama",
                 tts_settings_button: "Voice Settings",
                 tts_settings_title: "TTS Settings",
                 tts_method_label: "TTS Method:",
@@ -979,6 +1753,8 @@ impl LocaleText {
                 tts_edge_desc: "High-quality neural voices. Free, no API key required.",
                 tts_pitch_label: "Pitch:",
                 tts_rate_label: "Rate:",
+                tts_playback_speed_label: "Playback speed:",
+                tts_playback_speed_tooltip: "How fast results are read aloud (the pitch stays the same); also the starting speed of live translation TTS",
                 tts_voice_per_language_label: "Voice per Language:",
                 tts_loading_voices: "Loading voice list...",
                 tts_failed_load_voices: "Failed to load voices: {}",
@@ -1012,26 +1788,40 @@ impl LocaleText {
                 realtime_tts_title: "Read translation",
                 realtime_tts_speed: "Speed",
                 realtime_tts_auto: "AUTO",
+                realtime_tts_playback: "Playback",
+                tts_pause: "Pause",
+                tts_resume: "Resume",
+                tts_stop: "Stop",
                 // App selection modal
                 app_select_title: "Select App to Capture",
                 app_select_hint: "Choose the app whose audio you want to transcribe (TTS isolated)",
                 // --- TRAY MENU EN ---
                 tray_settings: "⚙️ Settings",
                 tray_quit: "Quit",
+                tray_recent: "Recent",
                 tray_favorite_bubble: "Show favorite bubble",
                 tray_favorite_bubble_disabled: "Show favorite bubble (No favorites set yet)",
                 // --- FAVORITE BUBBLE EN ---
                  favorites_empty: "Please add at least one configuration to favorites",
                  favorites_keep_open: "Keep Open",
+                 favorite_bubbles_separate_label: "One bubble per favorite preset",
+                 favorite_bubbles_separate_tooltip: "Click a bubble to run its preset. Press and hold, then drag to move it.",
                  recording_subtext: "Press ESC/Hotkey to stop",
                  recording_paused: "Paused",
                  // --- AUTO COPY BADGE EN ---
                  auto_copied_badge: "Auto-copied",
                  auto_copied_image_badge: "🖼️ Image copied",
                  live_translate_loading: "⏳ Loading Live Translate...",
+                 webview_recovering: "⏳ A window crashed, recovering...",
                  text_input_loading: "⏳ Loading text input...",
+                 secondary_language_notice: "🌐 Translating to {}",
+                 result_all_languages: "All languages",
                  recording_loading: "⏳ Loading recording...",
                  markdown_view_loading: "⏳ Loading result viewer...",
+                 dual_pane_no_source: "No source text to show side by side for this result",
+                 arena_pick_btn: "Pick this one",
+                 arena_picked: "✓ Copied",
+                 arena_faster: "faster",
                  preset_wheel_loading: "⏳ Loading preset selector...",
                  prompt_dj_loading: "⏳ Loading Chill Corner...",
                  tray_popup_loading: "⏳ Loading menu...",
@@ -1060,11 +1850,63 @@ impl LocaleText {
                  help_assistant_hint: "Enter a question about using SGT and press Enter or click Ask",
                   prompt_dj_btn: "Chill Corner",
                   prompt_dj_title: "PromptDJ - Chill Corner",
+                  prompt_dj_midi_off: "MIDI: Off",
                   // --- PARAKEET DOWNLOAD MODAL EN ---
                   parakeet_downloading_title: "Downloading Parakeet (0.6 GB)",
                   parakeet_downloading_message: "Please wait...",
                   parakeet_downloading_file: "Downloading {}...",
                   parakeet_supports_english_only: "(Only supports English)",
+                  // --- CRASH REPORT ---
+                  crash_report_title: "SGT Crash Report",
+                  icon_settings: "Settings",
+                  icon_hide_value: "Hide value",
+                  icon_show_value: "Show value",
+                  icon_microphone: "Microphone",
+                  icon_image: "Image",
+                  icon_text: "Text",
+                  icon_delete: "Delete",
+                  icon_open_media: "Open media",
+                  icon_copy: "Copy",
+                  icon_close: "Close",
+                  icon_text_selection: "Text selection",
+                  icon_speak: "Speak",
+                  icon_speech_disabled: "Speech disabled",
+                  icon_auto_copy_disabled: "Auto-copy disabled",
+                  icon_tips: "Tips",
+                  icon_realtime: "Realtime",
+                  icon_add_favorite: "Add to favorites",
+                  icon_remove_favorite: "Remove from favorites",
+                  icon_theme_light: "Theme: Light",
+                  icon_theme_dark: "Theme: Dark",
+                  icon_theme_system: "Theme: System",
+                  icon_drag_to_reorder: "Drag to reorder",
+                  icon_history: "History",
+                  instance_not_responding_title: "SGT is already running",
+                  instance_not_responding_msg: "Another instance of SGT is running but not responding. End it in Task Manager, then start SGT again.",
+                  crash_detected: "CRASH DETECTED!",
+                  crash_error_label: "Error:",
+                  crash_location_label: "Location:",
+                  crash_log_saved: "Crash details were saved to (please attach it to your bug report):",
+                  http_api_header: "HTTP Control API",
+                  http_api_enable: "Enable local API (127.0.0.1 only)",
+                  http_api_port_label: "Port:",
+                  http_api_token_label: "Token:",
+                  http_api_copy_btn: "Copy",
+                  http_api_regenerate_btn: "Regenerate",
+                  http_api_hint: "Send the token in the header of every request. Endpoints:",
+                  backup_header: "Backup & Restore",
+                  global_prompt_header: "Instructions for every preset",
+                  global_prompt_hint: "Added before/after the prompt of every AI block, e.g. \"Always respond concisely and preserve formatting\". Variables like {date} work here too. Each preset can opt out in its settings.",
+                  global_prompt_prefix_label: "Prefix:",
+                  global_prompt_suffix_label: "Suffix:",
+                  backup_export_btn: "Export settings",
+                  backup_import_btn: "Import settings",
+                  backup_include_api_keys: "Include API keys",
+                  backup_file_type: "SGT backup (*.sgtbackup)",
+                  backup_export_done: "Backup exported.",
+                  backup_import_done: "Settings imported. The previous config was saved as .pre-import.bak.",
+                  backup_failed: "Failed:",
+                  backup_overwrite_keys_msg: "The backup contains API keys that differ from your current ones. Replace your current API keys?",
                  },
                 }
     }
@@ -0,0 +1,123 @@
+//! File logger behind the `log` facade.
+//!
+//! Writes to a rotating `sgt.log` in the app data dir (the windowed build has no console).
+//! Verbosity comes from `Config::log_level` and can be changed at runtime.
+//! Messages are passed through `redact` first, so API keys never reach the file.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Rotate `sgt.log` to `sgt.log.old` once it grows past this size
+const MAX_LOG_BYTES: u64 = 2 * 1024 * 1024;
+
+struct LogFile {
+    file: File,
+    len: u64,
+}
+
+struct FileLogger {
+    file: Mutex<Option<LogFile>>,
+}
+
+static LOGGER: FileLogger = FileLogger {
+    file: Mutex::new(None),
+};
+
+/// Path of the rolling log file in the app data dir
+pub fn log_path() -> PathBuf {
+    let config_dir = dirs::config_dir()
+        .unwrap_or_default()
+        .join("screen-goated-toolbox");
+    let _ = std::fs::create_dir_all(&config_dir);
+    config_dir.join("sgt.log")
+}
+
+/// Map the config value ("off" / "error" / "info" / "debug") to a filter
+pub fn level_filter(log_level: &str) -> LevelFilter {
+    match log_level {
+        "off" => LevelFilter::Off,
+        "info" => LevelFilter::Info,
+        "debug" => LevelFilter::Debug,
+        _ => LevelFilter::Error,
+    }
+}
+
+/// Install the logger. Call once, early in `main()`.
+pub fn init(log_level: &str) {
+    if log::set_logger(&LOGGER).is_ok() {
+        set_level(log_level);
+    }
+}
+
+/// Apply a new verbosity (e.g. after the setting changed)
+pub fn set_level(log_level: &str) {
+    log::set_max_level(level_filter(log_level));
+}
+
+impl LogFile {
+    fn open() -> Option<Self> {
+        let path = log_path();
+        // Start over (keeping one old copy) when the log gets too big
+        if std::fs::metadata(&path).is_ok_and(|m| m.len() > MAX_LOG_BYTES) {
+            let _ = std::fs::rename(&path, path.with_extension("log.old"));
+        }
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .ok()?;
+        let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+        Some(Self { file, len })
+    }
+}
+
+impl Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let message = record.args().to_string();
+        let line = format!(
+            "[{}] {:<5} [{}] {}\n",
+            chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f"),
+            record.level(),
+            record.target(),
+            crate::redact::redact(&message)
+        );
+
+        // Mirror to the console in debug builds
+        #[cfg(debug_assertions)]
+        eprint!("{}", line);
+
+        let Ok(mut file) = self.file.lock() else {
+            return;
+        };
+        if file.as_ref().is_none_or(|f| f.len > MAX_LOG_BYTES) {
+            // Close the old handle before rotating
+            *file = None;
+            *file = LogFile::open();
+        }
+        if let Some(f) = file.as_mut() {
+            if f.file.write_all(line.as_bytes()).is_ok() {
+                f.len += line.len() as u64;
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            if let Some(f) = file.as_mut() {
+                let _ = f.file.flush();
+            }
+        }
+    }
+}
@@ -531,12 +531,13 @@ use crate::config::Config;
 /// Resolve a fallback model for retry logic
 /// Prioritizes:
 /// 1. Same provider, same type (Prioritize based on list order - treating list as priority queue)
-/// 2. Different provider, same type
+/// 2. Different provider, same type (skipped when `restrict_to_provider` is set)
 /// Checks if the provider is actually configured (has API key) before suggesting it.
 pub fn resolve_fallback_model(
     failed_model_id: &str,
     failed_model_ids: &[String],
     current_model_type: &ModelType,
+    restrict_to_provider: Option<&str>,
     config: &Config,
 ) -> Option<ModelConfig> {
     let all_models = get_all_models_with_ollama();
@@ -580,6 +581,11 @@ pub fn resolve_fallback_model(
         }
     }
 
+    // A block locked to one provider never falls back to another
+    if restrict_to_provider.is_some() {
+        return None;
+    }
+
     // 2. Try Different Provider
     let diff_provider_candidates: Vec<&ModelConfig> = all_models
         .iter()
@@ -685,15 +691,9 @@ pub fn is_ollama_scan_in_progress() -> bool {
 
 /// Trigger background scan for Ollama models (non-blocking)
 /// Returns immediately, models will be populated in cache when ready
-pub fn trigger_ollama_model_scan() {
+pub fn trigger_ollama_model_scan(config: &crate::config::Config) {
     // Check if Ollama is enabled
-    let (use_ollama, base_url) = if let Ok(app) = crate::APP.lock() {
-        (app.config.use_ollama, app.config.ollama_base_url.clone())
-    } else {
-        return;
-    };
-
-    if !use_ollama {
+    if !config.use_ollama {
         return;
     }
 
@@ -717,8 +717,10 @@ pub fn trigger_ollama_model_scan() {
     }
 
     // Spawn background thread to scan
+    let config = config.clone();
     std::thread::spawn(move || {
-        let result = crate::api::ollama::fetch_ollama_models_with_caps(&base_url);
+        let result =
+            crate::api::ollama::fetch_ollama_models_with_caps(&config, &config.ollama_base_url);
 
         if let Ok(ollama_models) = result {
             let mut new_models = Vec::new();
@@ -15,7 +15,7 @@ use wry::{Rect, WebContext, WebView, WebViewBuilder};
 static REGISTER_BADGE_CLASS: Once = Once::new();
 
 // Thread-safe handle using atomic (like preset_wheel)
-static BADGE_HWND: AtomicIsize = AtomicIsize::new(0);
+pub(crate) static BADGE_HWND: AtomicIsize = AtomicIsize::new(0);
 static IS_WARMING_UP: AtomicBool = AtomicBool::new(false);
 static IS_WARMED_UP: AtomicBool = AtomicBool::new(false);
 
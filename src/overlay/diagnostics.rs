@@ -0,0 +1,284 @@
+//! Runtime diagnostics for the settings panel: which overlay windows exist, how many
+//! background workers are running, which low-level hooks are installed and how much
+//! memory the app (and its WebView2 processes) uses.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use windows::Win32::Foundation::{CloseHandle, HWND};
+use windows::Win32::System::Diagnostics::ToolHelp::{
+    CreateToolhelp32Snapshot, Thread32First, Thread32Next, TH32CS_SNAPTHREAD, THREADENTRY32,
+};
+use windows::Win32::System::ProcessStatus::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS};
+use windows::Win32::System::Threading::{GetCurrentProcess, GetCurrentProcessId};
+use windows::Win32::UI::WindowsAndMessaging::IsWindow;
+
+use super::webview_lifecycle::WebViewMemory;
+
+lazy_static::lazy_static! {
+    // Running background workers by name
+    static ref WORKERS: Mutex<BTreeMap<&'static str, usize>> = Mutex::new(BTreeMap::new());
+}
+
+/// Counts a running worker until dropped; hold it for the lifetime of the thread
+pub struct WorkerGuard(&'static str);
+
+impl Drop for WorkerGuard {
+    fn drop(&mut self) {
+        if let Ok(mut workers) = WORKERS.lock() {
+            if let Some(count) = workers.get_mut(self.0) {
+                *count = count.saturating_sub(1);
+            }
+        }
+    }
+}
+
+/// Register the calling thread as a running `name` worker
+pub fn track_worker(name: &'static str) -> WorkerGuard {
+    if let Ok(mut workers) = WORKERS.lock() {
+        *workers.entry(name).or_insert(0) += 1;
+    }
+    WorkerGuard(name)
+}
+
+#[derive(Clone, Debug)]
+pub struct WindowInfo {
+    pub name: &'static str,
+    pub hosts_webview: bool,
+    /// 0 when the window doesn't exist (never created, or destroyed)
+    pub hwnd: isize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    pub windows: Vec<WindowInfo>,
+    pub result_windows: usize,
+    pub workers: Vec<(&'static str, usize)>,
+    pub thread_count: Option<usize>,
+    pub hooks: Vec<(&'static str, bool)>,
+    pub process_working_set: Option<u64>,
+    pub webview_memory: Option<WebViewMemory>,
+}
+
+fn live(hwnd: isize) -> isize {
+    let exists = hwnd != 0 && unsafe { IsWindow(Some(HWND(hwnd as *mut _))).as_bool() };
+    if exists {
+        hwnd
+    } else {
+        0
+    }
+}
+
+fn overlay_windows() -> Vec<WindowInfo> {
+    let window = |name, hosts_webview, hwnd: isize| WindowInfo {
+        name,
+        hosts_webview,
+        hwnd: live(hwnd),
+    };
+    unsafe {
+        let text_input_hwnd = std::ptr::addr_of!(super::text_input::INPUT_HWND).read();
+        let prompt_dj_hwnd = std::ptr::addr_of!(super::prompt_dj::PDJ_HWND).read();
+        vec![
+            window(
+                "Hotkey listener",
+                false,
+                crate::LISTENER_HWND
+                    .lock()
+                    .map(|h| h.0 .0 as isize)
+                    .unwrap_or(0),
+            ),
+            window(
+                "Tray popup",
+                true,
+                super::tray_popup::POPUP_HWND.load(Ordering::SeqCst),
+            ),
+            window(
+                "Preset wheel",
+                true,
+                super::preset_wheel::WHEEL_HWND.load(Ordering::SeqCst),
+            ),
+            window("Text input", true, text_input_hwnd.0 .0 as isize),
+            window(
+                "Auto-copy badge",
+                true,
+                super::auto_copy_badge::BADGE_HWND.load(Ordering::SeqCst),
+            ),
+            window(
+                "Markdown warmup",
+                true,
+                std::ptr::addr_of!(super::result::markdown_view::WARMUP_HWND)
+                    .read()
+                    .0 as isize,
+            ),
+            window("Prompt DJ", true, prompt_dj_hwnd.0 .0 as isize),
+            window(
+                "Live Translate",
+                true,
+                std::ptr::addr_of!(super::realtime_webview::state::REALTIME_HWND)
+                    .read()
+                    .0 as isize,
+            ),
+            window(
+                "Live Translate (translation)",
+                true,
+                std::ptr::addr_of!(super::realtime_webview::state::TRANSLATION_HWND)
+                    .read()
+                    .0 as isize,
+            ),
+            window(
+                "Recording",
+                true,
+                super::recording::RECORDING_HWND_VAL.load(Ordering::SeqCst),
+            ),
+            window(
+                "Favorite bubble",
+                false,
+                super::favorite_bubble::state::BUBBLE_HWND.load(Ordering::SeqCst),
+            ),
+            window(
+                "Favorites panel",
+                true,
+                super::favorite_bubble::state::PANEL_HWND.load(Ordering::SeqCst),
+            ),
+            window(
+                "Preset bubbles host",
+                false,
+                super::favorite_bubble::preset_bubbles::HOST_HWND.load(Ordering::SeqCst),
+            ),
+            window(
+                "Volume mixer",
+                true,
+                super::volume_mixer::MIXER_HWND.load(Ordering::SeqCst),
+            ),
+        ]
+    }
+}
+
+fn installed_hooks() -> Vec<(&'static str, bool)> {
+    let mouse = crate::MOUSE_HOOK
+        .lock()
+        .map(|h| !h.0.is_invalid())
+        .unwrap_or(false);
+    let selection = unsafe {
+        !std::ptr::addr_of!(super::selection::SELECTION_HOOK)
+            .read()
+            .is_invalid()
+    };
+    vec![
+        ("Mouse (hotkeys)", mouse),
+        ("Keyboard (screen selection)", selection),
+        (
+            "Keyboard (text selection)",
+            super::text_selection::is_hook_installed(),
+        ),
+        (
+            "Keyboard (recording Esc)",
+            super::recording::ESC_HOOK_INSTALLED.load(Ordering::SeqCst),
+        ),
+    ]
+}
+
+fn thread_count() -> Option<usize> {
+    unsafe {
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0).ok()?;
+        let pid = GetCurrentProcessId();
+        let mut entry = THREADENTRY32 {
+            dwSize: std::mem::size_of::<THREADENTRY32>() as u32,
+            ..Default::default()
+        };
+        let mut count = 0;
+        if Thread32First(snapshot, &mut entry).is_ok() {
+            loop {
+                if entry.th32OwnerProcessID == pid {
+                    count += 1;
+                }
+                if Thread32Next(snapshot, &mut entry).is_err() {
+                    break;
+                }
+            }
+        }
+        let _ = CloseHandle(snapshot);
+        Some(count)
+    }
+}
+
+fn process_working_set() -> Option<u64> {
+    let mut counters = PROCESS_MEMORY_COUNTERS::default();
+    unsafe {
+        GetProcessMemoryInfo(
+            GetCurrentProcess(),
+            &mut counters,
+            std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32,
+        )
+        .ok()?;
+    }
+    Some(counters.WorkingSetSize as u64)
+}
+
+/// Collect the current state (takes a few milliseconds: walks the process list)
+pub fn snapshot() -> Snapshot {
+    let result_windows = super::result::WINDOW_STATES
+        .lock()
+        .map(|states| states.len())
+        .unwrap_or(0);
+    let workers = WORKERS
+        .lock()
+        .map(|w| w.iter().map(|(name, count)| (*name, *count)).collect())
+        .unwrap_or_default();
+    Snapshot {
+        windows: overlay_windows(),
+        result_windows,
+        workers,
+        thread_count: thread_count(),
+        hooks: installed_hooks(),
+        process_working_set: process_working_set(),
+        webview_memory: super::webview_lifecycle::measure_memory(),
+    }
+}
+
+fn megabytes(bytes: u64) -> u64 {
+    bytes / (1024 * 1024)
+}
+
+impl Snapshot {
+    /// Plain-text version for pasting into an issue
+    pub fn to_report(&self) -> String {
+        let mut report = String::from("[Windows]\n");
+        for w in &self.windows {
+            let state = if w.hwnd != 0 {
+                format!("0x{:X}", w.hwnd)
+            } else {
+                "-".to_string()
+            };
+            let kind = if w.hosts_webview { " (WebView)" } else { "" };
+            report.push_str(&format!("{}{}: {}\n", w.name, kind, state));
+        }
+        report.push_str(&format!("Result windows: {}\n", self.result_windows));
+
+        report.push_str("\n[Workers]\n");
+        for (name, count) in &self.workers {
+            report.push_str(&format!("{}: {}\n", name, count));
+        }
+        if let Some(threads) = self.thread_count {
+            report.push_str(&format!("Process threads: {}\n", threads));
+        }
+
+        report.push_str("\n[Hooks]\n");
+        for (name, installed) in &self.hooks {
+            report.push_str(&format!("{}: {}\n", name, installed));
+        }
+
+        report.push_str("\n[Memory]\n");
+        if let Some(bytes) = self.process_working_set {
+            report.push_str(&format!("App: {} MB\n", megabytes(bytes)));
+        }
+        if let Some(memory) = self.webview_memory {
+            report.push_str(&format!(
+                "WebView2: {} MB in {} processes\n",
+                megabytes(memory.working_set_bytes),
+                memory.processes
+            ));
+        }
+        report
+    }
+}
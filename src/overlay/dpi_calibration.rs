@@ -0,0 +1,199 @@
+//! DPI calibration overlay: one small window per monitor with a box that is exactly
+//! 100 × 100 physical pixels, labeled with the monitor's scale. On a correctly
+//! Per-Monitor-V2 aware process the box measures 100 px in a screenshot on every
+//! monitor; a stretched or blurry box means coordinates are being virtualized.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use windows::core::w;
+use windows::Win32::Foundation::*;
+use windows::Win32::Graphics::Gdi::*;
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use windows::Win32::UI::HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI};
+use windows::Win32::UI::WindowsAndMessaging::*;
+use windows_core::BOOL;
+
+use super::utils::to_wstring;
+
+const BOX_SIZE: i32 = 100;
+const MARGIN: i32 = 20;
+const LABEL_HEIGHT: i32 = 40;
+const WINDOW_W: i32 = 320;
+const WINDOW_H: i32 = BOX_SIZE + LABEL_HEIGHT + MARGIN * 3;
+/// Windows close themselves after this long
+const AUTO_CLOSE_MS: u32 = 15_000;
+const CLOSE_TIMER_ID: usize = 1;
+
+static IS_ACTIVE: AtomicBool = AtomicBool::new(false);
+static OPEN_WINDOWS: AtomicUsize = AtomicUsize::new(0);
+
+struct MonitorSpot {
+    work: RECT,
+    dpi: u32,
+}
+
+unsafe extern "system" fn collect_monitor(
+    hmonitor: HMONITOR,
+    _hdc: HDC,
+    _rect: *mut RECT,
+    data: LPARAM,
+) -> BOOL {
+    let spots = &mut *(data.0 as *mut Vec<MonitorSpot>);
+    let mut info = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
+    if GetMonitorInfoW(hmonitor, &mut info).as_bool() {
+        let (mut dpi_x, mut dpi_y) = (96, 96);
+        let _ = GetDpiForMonitor(hmonitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y);
+        spots.push(MonitorSpot {
+            work: info.rcWork,
+            dpi: dpi_x,
+        });
+    }
+    BOOL::from(true)
+}
+
+/// Show the calibration box on every monitor. Click a box or press Esc to close it.
+pub fn show_dpi_calibration() {
+    if IS_ACTIVE.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    std::thread::spawn(|| unsafe {
+        let mut spots: Vec<MonitorSpot> = Vec::new();
+        let _ = EnumDisplayMonitors(
+            None,
+            None,
+            Some(collect_monitor),
+            LPARAM(&mut spots as *mut _ as isize),
+        );
+
+        let instance = GetModuleHandleW(None).unwrap_or_default();
+        let class_name = w!("SGT_DpiCalibration");
+        let wc = WNDCLASSW {
+            lpfnWndProc: Some(calibration_wnd_proc),
+            hInstance: instance.into(),
+            hCursor: LoadCursorW(None, IDC_HAND).unwrap_or_default(),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        let _ = RegisterClassW(&wc);
+
+        for spot in &spots {
+            let Ok(hwnd) = CreateWindowExW(
+                WS_EX_TOPMOST | WS_EX_TOOLWINDOW,
+                class_name,
+                w!("SGT DPI Calibration"),
+                WS_POPUP,
+                spot.work.left + MARGIN * 2,
+                spot.work.top + MARGIN * 2,
+                WINDOW_W,
+                WINDOW_H,
+                None,
+                None,
+                Some(instance.into()),
+                None,
+            ) else {
+                continue;
+            };
+            SetWindowLongPtrW(hwnd, GWLP_USERDATA, spot.dpi as isize);
+            OPEN_WINDOWS.fetch_add(1, Ordering::SeqCst);
+            let _ = ShowWindow(hwnd, SW_SHOW);
+            SetTimer(Some(hwnd), CLOSE_TIMER_ID, AUTO_CLOSE_MS, None);
+        }
+
+        if OPEN_WINDOWS.load(Ordering::SeqCst) > 0 {
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        }
+        IS_ACTIVE.store(false, Ordering::SeqCst);
+    });
+}
+
+unsafe fn paint(hwnd: HWND) {
+    let mut ps = PAINTSTRUCT::default();
+    let hdc = BeginPaint(hwnd, &mut ps);
+    let dpi = GetWindowLongPtrW(hwnd, GWLP_USERDATA) as u32;
+
+    let mut client = RECT::default();
+    let _ = GetClientRect(hwnd, &mut client);
+    let bg = CreateSolidBrush(COLORREF(0x00202020));
+    FillRect(hdc, &client, bg);
+    let _ = DeleteObject(bg.into());
+
+    // The box itself: 100 × 100 device pixels, no scaling applied
+    let box_rect = RECT {
+        left: MARGIN,
+        top: MARGIN,
+        right: MARGIN + BOX_SIZE,
+        bottom: MARGIN + BOX_SIZE,
+    };
+    let fill = CreateSolidBrush(COLORREF(0x00604020));
+    FillRect(hdc, &box_rect, fill);
+    let _ = DeleteObject(fill.into());
+    let edge = CreateSolidBrush(COLORREF(0x00FFC040));
+    FrameRect(hdc, &box_rect, edge);
+    let _ = DeleteObject(edge.into());
+
+    SetBkMode(hdc, TRANSPARENT);
+    SetTextColor(hdc, COLORREF(0x00FFFFFF));
+    let info = format!(
+        "{} × {} px\n{}% ({} DPI)",
+        BOX_SIZE,
+        BOX_SIZE,
+        dpi * 100 / 96,
+        dpi
+    );
+    let mut info_w = to_wstring(&info);
+    let mut info_rect = RECT {
+        left: MARGIN * 2 + BOX_SIZE,
+        top: MARGIN,
+        right: client.right - MARGIN,
+        bottom: MARGIN + BOX_SIZE,
+    };
+    DrawTextW(hdc, &mut info_w, &mut info_rect, DT_LEFT | DT_WORDBREAK);
+
+    let mut hint_w = to_wstring("Click or press Esc to close");
+    let mut hint_rect = RECT {
+        left: MARGIN,
+        top: MARGIN * 2 + BOX_SIZE,
+        right: client.right - MARGIN,
+        bottom: client.bottom - MARGIN,
+    };
+    SetTextColor(hdc, COLORREF(0x00A0A0A0));
+    DrawTextW(hdc, &mut hint_w, &mut hint_rect, DT_LEFT | DT_SINGLELINE);
+
+    let _ = EndPaint(hwnd, &ps);
+}
+
+unsafe extern "system" fn calibration_wnd_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    match msg {
+        WM_PAINT => {
+            paint(hwnd);
+            LRESULT(0)
+        }
+        WM_LBUTTONUP | WM_RBUTTONUP | WM_TIMER => {
+            let _ = DestroyWindow(hwnd);
+            LRESULT(0)
+        }
+        WM_KEYDOWN if wparam.0 == 0x1B => {
+            let _ = DestroyWindow(hwnd);
+            LRESULT(0)
+        }
+        WM_DESTROY => {
+            let _ = KillTimer(Some(hwnd), CLOSE_TIMER_ID);
+            if OPEN_WINDOWS.fetch_sub(1, Ordering::SeqCst) == 1 {
+                PostQuitMessage(0);
+            }
+            LRESULT(0)
+        }
+        _ => DefWindowProcW(hwnd, msg, wparam, lparam),
+    }
+}
@@ -75,6 +75,8 @@ pub fn show_panel(bubble_hwnd: HWND) {
 }
 
 pub fn update_favorites_panel() {
+    super::preset_bubbles::refresh_preset_bubbles();
+
     // Force a refresh of the panel and bubble visual (theme/content)
     // We post a message to the panel window to handle this safely (locking APP in wndproc)
     let bubble_val = BUBBLE_HWND.load(Ordering::SeqCst);
@@ -547,7 +549,7 @@ unsafe extern "system" fn panel_wnd_proc(
     }
 }
 
-fn trigger_preset(preset_idx: usize) {
+pub(super) fn trigger_preset(preset_idx: usize) {
     unsafe {
         // CRITICAL: Restore focus to the original foreground window before triggering.
         // This ensures that text-select presets can send Ctrl+C to the correct window
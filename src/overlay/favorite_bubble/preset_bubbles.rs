@@ -0,0 +1,652 @@
+// Separate bubbles: one small draggable bubble per favorite preset.
+// A click runs the preset right away; a long press picks the bubble up so it can be moved.
+
+use super::panel::trigger_preset;
+use super::state::{BUBBLE_SIZE, LAST_FOREGROUND_HWND, OPACITY_ACTIVE, OPACITY_INACTIVE};
+use crate::gui::settings_ui::get_localized_preset_name;
+use crate::APP;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+use std::sync::Once;
+use windows::core::w;
+use windows::Win32::Foundation::*;
+use windows::Win32::Graphics::Gdi::*;
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    ReleaseCapture, SetCapture, TrackMouseEvent, TME_LEAVE, TRACKMOUSEEVENT,
+};
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+const WM_APP_REBUILD: u32 = WM_APP + 1;
+const LONG_PRESS_TIMER_ID: usize = 1;
+const LONG_PRESS_MS: u32 = 450;
+const BUBBLE_GAP: i32 = 8;
+
+static REGISTER_HOST_CLASS: Once = Once::new();
+static REGISTER_PRESET_BUBBLE_CLASS: Once = Once::new();
+static BUBBLES_ACTIVE: AtomicBool = AtomicBool::new(false);
+// Message-only window owning all bubbles (they live on its thread)
+pub(crate) static HOST_HWND: AtomicIsize = AtomicIsize::new(0);
+
+// Bubble fill colors, cycled by position in the favorites list
+const BUBBLE_COLORS: [(u32, u32, u32); 6] = [
+    (66, 110, 200),
+    (60, 150, 90),
+    (190, 80, 80),
+    (140, 90, 200),
+    (200, 130, 50),
+    (40, 150, 160),
+];
+
+struct PresetBubble {
+    preset_id: String,
+    label: String,
+    color: (u32, u32, u32),
+    hovered: bool,
+    pressed: bool,
+    moving: bool,
+    // Cursor offset from the window's top-left while moving
+    grab_offset: (i32, i32),
+}
+
+thread_local! {
+    static BUBBLES: RefCell<HashMap<isize, PresetBubble>> = RefCell::new(HashMap::new());
+}
+
+pub fn show_preset_bubbles() {
+    if BUBBLES_ACTIVE.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    std::thread::spawn(|| {
+        run_host();
+    });
+}
+
+pub fn hide_preset_bubbles() {
+    post_to_host(WM_CLOSE);
+}
+
+/// Add/remove bubbles after favorites (or their names) changed
+pub fn refresh_preset_bubbles() {
+    post_to_host(WM_APP_REBUILD);
+}
+
+fn post_to_host(msg: u32) {
+    let host_val = HOST_HWND.load(Ordering::SeqCst);
+    if host_val != 0 {
+        unsafe {
+            let _ = PostMessageW(
+                Some(HWND(host_val as *mut std::ffi::c_void)),
+                msg,
+                WPARAM(0),
+                LPARAM(0),
+            );
+        }
+    }
+}
+
+fn run_host() {
+    unsafe {
+        let instance = GetModuleHandleW(None).unwrap_or_default();
+        let host_class = w!("SGTFavoriteBubblesHost");
+        REGISTER_HOST_CLASS.call_once(|| {
+            let wc = WNDCLASSW {
+                lpfnWndProc: Some(host_wnd_proc),
+                hInstance: instance.into(),
+                lpszClassName: host_class,
+                ..Default::default()
+            };
+            RegisterClassW(&wc);
+        });
+
+        let bubble_class = w!("SGTFavoritePresetBubble");
+        REGISTER_PRESET_BUBBLE_CLASS.call_once(|| {
+            let wc = WNDCLASSW {
+                lpfnWndProc: Some(bubble_wnd_proc),
+                hInstance: instance.into(),
+                lpszClassName: bubble_class,
+                hCursor: LoadCursorW(None, IDC_HAND).unwrap_or_default(),
+                ..Default::default()
+            };
+            RegisterClassW(&wc);
+        });
+
+        let host = CreateWindowExW(
+            WINDOW_EX_STYLE::default(),
+            host_class,
+            w!("FavBubblesHost"),
+            WINDOW_STYLE::default(),
+            0,
+            0,
+            0,
+            0,
+            Some(HWND_MESSAGE),
+            None,
+            Some(instance.into()),
+            None,
+        )
+        .unwrap_or_default();
+
+        if host.is_invalid() {
+            BUBBLES_ACTIVE.store(false, Ordering::SeqCst);
+            return;
+        }
+        HOST_HWND.store(host.0 as isize, Ordering::SeqCst);
+
+        rebuild_bubbles();
+
+        let mut msg = MSG::default();
+        while GetMessageW(&mut msg, None, 0, 0).into() {
+            let _ = TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+        }
+
+        HOST_HWND.store(0, Ordering::SeqCst);
+        BUBBLES_ACTIVE.store(false, Ordering::SeqCst);
+    }
+}
+
+unsafe extern "system" fn host_wnd_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    match msg {
+        WM_APP_REBUILD => {
+            rebuild_bubbles();
+            LRESULT(0)
+        }
+        WM_CLOSE => {
+            let bubbles: Vec<isize> = BUBBLES.with(|b| b.borrow().keys().copied().collect());
+            for bubble in bubbles {
+                let _ = DestroyWindow(HWND(bubble as *mut std::ffi::c_void));
+            }
+            let _ = DestroyWindow(hwnd);
+            LRESULT(0)
+        }
+        WM_DESTROY => {
+            PostQuitMessage(0);
+            LRESULT(0)
+        }
+        _ => DefWindowProcW(hwnd, msg, wparam, lparam),
+    }
+}
+
+/// Short label drawn on the bubble: initials of the first two words
+fn bubble_label(name: &str) -> String {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    let label: String = if words.len() >= 2 {
+        words
+            .iter()
+            .take(2)
+            .filter_map(|w| w.chars().next())
+            .collect()
+    } else {
+        name.chars().take(1).collect()
+    };
+    label.to_uppercase()
+}
+
+/// Match the bubble windows to the current favorites
+fn rebuild_bubbles() {
+    let (favorites, positions, fallback_origin) = {
+        let app = APP.lock().unwrap();
+        let lang = app.config.ui_language.clone();
+        let favorites: Vec<(String, String)> = app
+            .config
+            .presets
+            .iter()
+            .filter(|p| p.is_favorite && !p.is_upcoming)
+            .map(|p| {
+                (
+                    p.id.clone(),
+                    bubble_label(&get_localized_preset_name(&p.id, &lang)),
+                )
+            })
+            .collect();
+        (
+            favorites,
+            app.config.favorite_bubble_positions.clone(),
+            app.config.favorite_bubble_position,
+        )
+    };
+
+    // Drop bubbles whose preset is no longer a favorite
+    let stale: Vec<isize> = BUBBLES.with(|b| {
+        b.borrow()
+            .iter()
+            .filter(|(_, bubble)| !favorites.iter().any(|(id, _)| *id == bubble.preset_id))
+            .map(|(hwnd, _)| *hwnd)
+            .collect()
+    });
+    for hwnd in stale {
+        unsafe {
+            let _ = DestroyWindow(HWND(hwnd as *mut std::ffi::c_void));
+        }
+    }
+
+    let mut work_area = RECT::default();
+    unsafe {
+        let _ = SystemParametersInfoW(
+            SPI_GETWORKAREA,
+            0,
+            Some(&mut work_area as *mut _ as *mut std::ffi::c_void),
+            SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+        );
+    }
+
+    for (slot, (preset_id, label)) in favorites.into_iter().enumerate() {
+        let color = BUBBLE_COLORS[slot % BUBBLE_COLORS.len()];
+        let existing = BUBBLES.with(|b| {
+            let mut bubbles = b.borrow_mut();
+            let found = bubbles
+                .iter_mut()
+                .find(|(_, bubble)| bubble.preset_id == preset_id);
+            found.map(|(hwnd, bubble)| {
+                bubble.label = label.clone();
+                bubble.color = color;
+                *hwnd
+            })
+        });
+        if let Some(hwnd) = existing {
+            update_preset_bubble_visual(HWND(hwnd as *mut std::ffi::c_void));
+            continue;
+        }
+
+        // New bubbles stack upwards from the single bubble's spot, in columns
+        let (x, y) = positions.get(&preset_id).copied().unwrap_or_else(|| {
+            let (origin_x, origin_y) = fallback_origin.unwrap_or((
+                work_area.right - BUBBLE_SIZE - 30,
+                work_area.bottom - BUBBLE_SIZE - 150,
+            ));
+            let step = BUBBLE_SIZE + BUBBLE_GAP;
+            let per_column = ((origin_y - work_area.top) / step + 1).max(1) as usize;
+            (
+                origin_x - (slot / per_column) as i32 * step,
+                origin_y - (slot % per_column) as i32 * step,
+            )
+        });
+        let x = x.clamp(work_area.left, work_area.right - BUBBLE_SIZE);
+        let y = y.clamp(work_area.top, work_area.bottom - BUBBLE_SIZE);
+
+        create_preset_bubble(
+            PresetBubble {
+                preset_id,
+                label,
+                color,
+                hovered: false,
+                pressed: false,
+                moving: false,
+                grab_offset: (0, 0),
+            },
+            x,
+            y,
+        );
+    }
+
+    if BUBBLES.with(|b| b.borrow().is_empty()) {
+        hide_preset_bubbles();
+    }
+}
+
+fn create_preset_bubble(bubble: PresetBubble, x: i32, y: i32) {
+    unsafe {
+        let instance = GetModuleHandleW(None).unwrap_or_default();
+        let hwnd = CreateWindowExW(
+            WS_EX_TOPMOST | WS_EX_TOOLWINDOW | WS_EX_LAYERED | WS_EX_NOACTIVATE,
+            w!("SGTFavoritePresetBubble"),
+            w!("FavPresetBubble"),
+            WS_POPUP,
+            x,
+            y,
+            BUBBLE_SIZE,
+            BUBBLE_SIZE,
+            None,
+            None,
+            Some(instance.into()),
+            None,
+        )
+        .unwrap_or_default();
+
+        if hwnd.is_invalid() {
+            return;
+        }
+
+        BUBBLES.with(|b| b.borrow_mut().insert(hwnd.0 as isize, bubble));
+        update_preset_bubble_visual(hwnd);
+        let _ = ShowWindow(hwnd, SW_SHOWNOACTIVATE);
+    }
+}
+
+fn save_preset_bubble_position(hwnd: HWND, preset_id: String) {
+    let mut rect = RECT::default();
+    unsafe {
+        let _ = GetWindowRect(hwnd, &mut rect);
+    }
+    if let Ok(mut app) = APP.lock() {
+        app.config
+            .favorite_bubble_positions
+            .insert(preset_id, (rect.left, rect.top));
+        crate::config::save_config(&app.config);
+    }
+}
+
+fn run_bubble_preset(preset_id: &str) {
+    let preset_idx = APP
+        .lock()
+        .ok()
+        .and_then(|app| app.config.presets.iter().position(|p| p.id == preset_id));
+    let Some(preset_idx) = preset_idx else {
+        return;
+    };
+
+    // Bubbles never take focus, so the foreground window is still the user's target
+    unsafe {
+        let fg = GetForegroundWindow();
+        LAST_FOREGROUND_HWND.store(fg.0 as isize, Ordering::SeqCst);
+    }
+    trigger_preset(preset_idx);
+}
+
+unsafe extern "system" fn bubble_wnd_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    const WM_MOUSELEAVE: u32 = 0x02A3;
+    let key = hwnd.0 as isize;
+
+    match msg {
+        WM_LBUTTONDOWN => {
+            BUBBLES.with(|b| {
+                if let Some(bubble) = b.borrow_mut().get_mut(&key) {
+                    bubble.pressed = true;
+                    bubble.moving = false;
+                }
+            });
+            let _ = SetCapture(hwnd);
+            let _ = SetTimer(Some(hwnd), LONG_PRESS_TIMER_ID, LONG_PRESS_MS, None);
+            LRESULT(0)
+        }
+
+        WM_TIMER if wparam.0 == LONG_PRESS_TIMER_ID => {
+            let _ = KillTimer(Some(hwnd), LONG_PRESS_TIMER_ID);
+            let mut cursor = POINT::default();
+            let mut rect = RECT::default();
+            let _ = GetCursorPos(&mut cursor);
+            let _ = GetWindowRect(hwnd, &mut rect);
+            BUBBLES.with(|b| {
+                if let Some(bubble) = b.borrow_mut().get_mut(&key) {
+                    if bubble.pressed {
+                        bubble.moving = true;
+                        bubble.grab_offset = (cursor.x - rect.left, cursor.y - rect.top);
+                    }
+                }
+            });
+            update_preset_bubble_visual(hwnd);
+            LRESULT(0)
+        }
+
+        WM_MOUSEMOVE => {
+            let (moving, grab_offset, was_hovered) = BUBBLES.with(|b| {
+                let mut bubbles = b.borrow_mut();
+                match bubbles.get_mut(&key) {
+                    Some(bubble) => {
+                        let was_hovered = bubble.hovered;
+                        bubble.hovered = true;
+                        (bubble.moving, bubble.grab_offset, was_hovered)
+                    }
+                    None => (false, (0, 0), true),
+                }
+            });
+
+            if moving {
+                let mut cursor = POINT::default();
+                let _ = GetCursorPos(&mut cursor);
+                let mut work_area = RECT::default();
+                let _ = SystemParametersInfoW(
+                    SPI_GETWORKAREA,
+                    0,
+                    Some(&mut work_area as *mut _ as *mut std::ffi::c_void),
+                    SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+                );
+                let new_x =
+                    (cursor.x - grab_offset.0).clamp(work_area.left, work_area.right - BUBBLE_SIZE);
+                let new_y =
+                    (cursor.y - grab_offset.1).clamp(work_area.top, work_area.bottom - BUBBLE_SIZE);
+                let _ = SetWindowPos(
+                    hwnd,
+                    None,
+                    new_x,
+                    new_y,
+                    0,
+                    0,
+                    SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+                );
+            }
+
+            if !was_hovered {
+                let mut tme = TRACKMOUSEEVENT {
+                    cbSize: std::mem::size_of::<TRACKMOUSEEVENT>() as u32,
+                    dwFlags: TME_LEAVE,
+                    hwndTrack: hwnd,
+                    dwHoverTime: 0,
+                };
+                let _ = TrackMouseEvent(&mut tme);
+                update_preset_bubble_visual(hwnd);
+            }
+            LRESULT(0)
+        }
+
+        WM_LBUTTONUP => {
+            let _ = KillTimer(Some(hwnd), LONG_PRESS_TIMER_ID);
+            let released = BUBBLES.with(|b| {
+                b.borrow_mut().get_mut(&key).map(|bubble| {
+                    let state = (bubble.pressed, bubble.moving, bubble.preset_id.clone());
+                    bubble.pressed = false;
+                    bubble.moving = false;
+                    state
+                })
+            });
+            let _ = ReleaseCapture();
+
+            // A press that ends off the bubble is a cancelled click
+            let x = (lparam.0 as i32 & 0xFFFF) as i16 as i32;
+            let y = ((lparam.0 as i32 >> 16) & 0xFFFF) as i16 as i32;
+            let inside = (0..BUBBLE_SIZE).contains(&x) && (0..BUBBLE_SIZE).contains(&y);
+
+            match released {
+                Some((_, true, preset_id)) => {
+                    save_preset_bubble_position(hwnd, preset_id);
+                    update_preset_bubble_visual(hwnd);
+                }
+                Some((true, false, preset_id)) if inside => run_bubble_preset(&preset_id),
+                _ => {}
+            }
+            LRESULT(0)
+        }
+
+        WM_MOUSELEAVE => {
+            BUBBLES.with(|b| {
+                if let Some(bubble) = b.borrow_mut().get_mut(&key) {
+                    bubble.hovered = false;
+                }
+            });
+            update_preset_bubble_visual(hwnd);
+            LRESULT(0)
+        }
+
+        WM_CLOSE => LRESULT(0),
+
+        WM_DESTROY => {
+            BUBBLES.with(|b| b.borrow_mut().remove(&key));
+            LRESULT(0)
+        }
+
+        _ => DefWindowProcW(hwnd, msg, wparam, lparam),
+    }
+}
+
+fn update_preset_bubble_visual(hwnd: HWND) {
+    let Some((label, color, active, moving)) = BUBBLES.with(|b| {
+        b.borrow().get(&(hwnd.0 as isize)).map(|bubble| {
+            (
+                bubble.label.clone(),
+                bubble.color,
+                bubble.hovered || bubble.pressed,
+                bubble.moving,
+            )
+        })
+    }) else {
+        return;
+    };
+    let opacity = u32::from(if active || moving {
+        OPACITY_ACTIVE
+    } else {
+        OPACITY_INACTIVE
+    });
+
+    unsafe {
+        let hdc_screen = GetDC(None);
+        let hdc_mem = CreateCompatibleDC(Some(hdc_screen));
+
+        let bmi = BITMAPINFO {
+            bmiHeader: BITMAPINFOHEADER {
+                biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+                biWidth: BUBBLE_SIZE,
+                biHeight: -BUBBLE_SIZE, // Top-down
+                biPlanes: 1,
+                biBitCount: 32,
+                biCompression: BI_RGB.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut bits: *mut std::ffi::c_void = std::ptr::null_mut();
+        let Ok(hbm) = CreateDIBSection(Some(hdc_mem), &bmi, DIB_RGB_COLORS, &mut bits, None, 0)
+        else {
+            let _ = DeleteDC(hdc_mem);
+            let _ = ReleaseDC(None, hdc_screen);
+            return;
+        };
+        let old_bm = SelectObject(hdc_mem, hbm.into());
+
+        if !bits.is_null() {
+            let pixels = std::slice::from_raw_parts_mut(
+                bits as *mut u32,
+                (BUBBLE_SIZE * BUBBLE_SIZE) as usize,
+            );
+            let center = BUBBLE_SIZE as f32 / 2.0;
+            let radius = center - 1.0;
+
+            // 1. Opaque disc (plus a light ring while being moved); alpha is set in step 3
+            let (r, g, b) = color;
+            for y in 0..BUBBLE_SIZE {
+                for x in 0..BUBBLE_SIZE {
+                    let dx = x as f32 + 0.5 - center;
+                    let dy = y as f32 + 0.5 - center;
+                    let dist = (dx * dx + dy * dy).sqrt();
+                    let (r, g, b) = if moving && dist > radius - 3.0 {
+                        (235, 235, 235)
+                    } else {
+                        (r, g, b)
+                    };
+                    pixels[(y * BUBBLE_SIZE + x) as usize] = (r << 16) | (g << 8) | b;
+                }
+            }
+
+            // 2. Label (GDI leaves the alpha byte alone, so it's rebuilt below)
+            SetBkMode(hdc_mem, TRANSPARENT);
+            SetTextColor(hdc_mem, COLORREF(0x00FFFFFF));
+            let font = CreateFontW(
+                if label.chars().count() > 1 { 15 } else { 18 },
+                0,
+                0,
+                0,
+                FW_SEMIBOLD.0 as i32,
+                0,
+                0,
+                0,
+                FONT_CHARSET(DEFAULT_CHARSET.0 as u8),
+                FONT_OUTPUT_PRECISION(OUT_DEFAULT_PRECIS.0 as u8),
+                FONT_CLIP_PRECISION(CLIP_DEFAULT_PRECIS.0 as u8),
+                FONT_QUALITY(ANTIALIASED_QUALITY.0 as u8),
+                std::mem::transmute((VARIABLE_PITCH.0 | FF_SWISS.0) as u32),
+                w!("Segoe UI"),
+            );
+            let old_font = SelectObject(hdc_mem, font.into());
+            let mut label_w: Vec<u16> = label.encode_utf16().collect();
+            let mut text_rect = RECT {
+                left: 0,
+                top: 0,
+                right: BUBBLE_SIZE,
+                bottom: BUBBLE_SIZE,
+            };
+            DrawTextW(
+                hdc_mem,
+                &mut label_w,
+                &mut text_rect,
+                DT_CENTER | DT_VCENTER | DT_SINGLELINE,
+            );
+            let _ = SelectObject(hdc_mem, old_font);
+            let _ = DeleteObject(font.into());
+            let _ = GdiFlush();
+
+            // 3. Anti-aliased circular alpha, premultiplied for UpdateLayeredWindow
+            for y in 0..BUBBLE_SIZE {
+                for x in 0..BUBBLE_SIZE {
+                    let idx = (y * BUBBLE_SIZE + x) as usize;
+                    let dx = x as f32 + 0.5 - center;
+                    let dy = y as f32 + 0.5 - center;
+                    let coverage = (radius + 0.5 - (dx * dx + dy * dy).sqrt()).clamp(0.0, 1.0);
+                    let a = (coverage * opacity as f32) as u32;
+                    let px = pixels[idx];
+                    let r = ((px >> 16) & 0xFF) * a / 255;
+                    let g = ((px >> 8) & 0xFF) * a / 255;
+                    let b = (px & 0xFF) * a / 255;
+                    pixels[idx] = (a << 24) | (r << 16) | (g << 8) | b;
+                }
+            }
+        }
+
+        let size = SIZE {
+            cx: BUBBLE_SIZE,
+            cy: BUBBLE_SIZE,
+        };
+        let pt_src = POINT { x: 0, y: 0 };
+        let blend = BLENDFUNCTION {
+            BlendOp: AC_SRC_OVER as u8,
+            BlendFlags: 0,
+            SourceConstantAlpha: 255,
+            AlphaFormat: AC_SRC_ALPHA as u8,
+        };
+
+        let mut rect = RECT::default();
+        let _ = GetWindowRect(hwnd, &mut rect);
+        let pt_dst = POINT {
+            x: rect.left,
+            y: rect.top,
+        };
+
+        let _ = UpdateLayeredWindow(
+            hwnd,
+            Some(hdc_screen),
+            Some(&pt_dst),
+            Some(&size),
+            Some(hdc_mem),
+            Some(&pt_src),
+            COLORREF(0),
+            Some(&blend),
+            ULW_ALPHA,
+        );
+
+        let _ = SelectObject(hdc_mem, old_bm);
+        let _ = DeleteObject(hbm.into());
+        let _ = DeleteDC(hdc_mem);
+        let _ = ReleaseDC(None, hdc_screen);
+    }
+}
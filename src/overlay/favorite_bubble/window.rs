@@ -14,8 +14,17 @@ use windows::Win32::UI::Input::KeyboardAndMouse::{
 };
 use windows::Win32::UI::WindowsAndMessaging::*;
 
-// Show the favorite bubble overlay
+// Show the favorite bubble overlay (or one bubble per favorite, depending on config)
 pub fn show_favorite_bubble() {
+    let separate = APP
+        .lock()
+        .map(|app| app.config.favorite_bubbles_separate)
+        .unwrap_or(false);
+    if separate {
+        super::preset_bubbles::show_preset_bubbles();
+        return;
+    }
+
     // Prevent duplicates
     if BUBBLE_ACTIVE.swap(true, Ordering::SeqCst) {
         return; // Already active
@@ -33,6 +42,8 @@ pub fn show_favorite_bubble() {
 
 // Hide the favorite bubble overlay with fade-out animation
 pub fn hide_favorite_bubble() {
+    super::preset_bubbles::hide_preset_bubbles();
+
     if !BUBBLE_ACTIVE.load(Ordering::SeqCst) {
         return;
     }
@@ -49,6 +60,31 @@ pub fn hide_favorite_bubble() {
     }
 }
 
+/// Flip `show_favorite_bubble`, save it and show or hide the bubble (tray popup and
+/// app hotkey). Returns the new state.
+pub fn toggle_favorite_bubble() -> bool {
+    let new_state = if let Ok(mut app) = APP.lock() {
+        app.config.show_favorite_bubble = !app.config.show_favorite_bubble;
+        crate::config::save_config(&app.config);
+        app.config.show_favorite_bubble
+    } else {
+        false
+    };
+
+    // Outside the lock: showing reads the bubble style from the config
+    if new_state {
+        show_favorite_bubble();
+        // Slight delay so the window is created before blinking
+        std::thread::spawn(|| {
+            std::thread::sleep(std::time::Duration::from_millis(150));
+            trigger_blink_animation();
+        });
+    } else {
+        hide_favorite_bubble();
+    }
+    new_state
+}
+
 pub fn trigger_blink_animation() {
     let hwnd_val = BUBBLE_HWND.load(Ordering::SeqCst);
     if hwnd_val != 0 {
@@ -5,7 +5,7 @@ pub fn get(glow_color: &str, font_size: u32) -> String {
             height: 100%;
             overflow: hidden;
             background: rgba(26, 26, 26, 0.95);
-            font-family: 'Google Sans Flex', sans-serif;
+            font-family: var(--overlay-font);
             color: #fff;
             border-radius: 8px;
             border: 1px solid {glow_color}40;
@@ -99,6 +99,10 @@ pub fn get(glow_color: &str, font_size: u32) -> String {
             backdrop-filter: blur(8px);
             border-radius: 6px;
         }}
+        body:not(.fonts-ready) #header,
+        body:not(.fonts-ready) #header-toggle {{
+            visibility: hidden;
+        }}
         #header.collapsed {{
             max-height: 0;
             margin-bottom: 0;
@@ -161,6 +165,10 @@ pub fn get(glow_color: &str, font_size: u32) -> String {
             height: 24px;
             width: 90px;
             border-radius: 2px;
+            transition: opacity 0.4s;
+        }}
+        #volume-canvas.silent {{
+            opacity: 0.3;
         }}
         #controls {{
             position: relative;
@@ -253,7 +261,7 @@ pub fn get(glow_color: &str, font_size: u32) -> String {
             color: #ff9633;
         }}
         select {{
-            font-family: 'Google Sans Flex', sans-serif;
+            font-family: var(--overlay-font);
             font-variation-settings: 'wght' 600, 'ROND' 100;
             background: rgba(30, 30, 30, 0.9);
             color: #ccc;
@@ -280,7 +288,7 @@ pub fn get(glow_color: &str, font_size: u32) -> String {
             box-shadow: 0 0 6px {glow_color}30;
         }}
         select option {{
-            font-family: 'Google Sans Flex', sans-serif;
+            font-family: var(--overlay-font);
             background: #2a2a2a;
             color: #ccc;
             padding: 4px 8px;
@@ -312,6 +320,17 @@ pub fn get(glow_color: &str, font_size: u32) -> String {
             font-size: {font_size}px;
             line-height: 1.5;
             padding-bottom: 5px;
+            white-space: pre-line;
+            /* A run with no break opportunity (a URL, unsegmented text) wraps anyway */
+            overflow-wrap: anywhere;
+        }}
+        /* Chinese and Japanese break between characters, but not before closing
+           punctuation or small kana; Korean keeps its space-separated words whole */
+        #content:lang(zh), #content:lang(ja) {{
+            line-break: strict;
+        }}
+        #content:lang(ko) {{
+            word-break: keep-all;
         }}
         @keyframes wipe-in {{
             from {{
@@ -332,7 +351,7 @@ pub fn get(glow_color: &str, font_size: u32) -> String {
 
         /* Base styling for all text chunks */
         .text-chunk {{
-            font-family: 'Google Sans Flex', sans-serif !important;
+            font-family: var(--overlay-font) !important;
             font-optical-sizing: auto;
             display: inline;
             transition: 
@@ -378,6 +397,85 @@ pub fn get(glow_color: &str, font_size: u32) -> String {
             opacity: 1;
             filter: blur(0);
         }}
+        /* Right-to-left text aligns right and wipes in from the right */
+        #content[dir="rtl"] {{
+            text-align: right;
+        }}
+        #content[dir="rtl"] .text-chunk.appearing {{
+            -webkit-mask-image: linear-gradient(to left, black 50%, transparent 100%);
+            mask-image: linear-gradient(to left, black 50%, transparent 100%);
+            -webkit-mask-position: 0% 0;
+            mask-position: 0% 0;
+        }}
+        #content[dir="rtl"] .text-chunk.appearing.show {{
+            -webkit-mask-position: 100% 0;
+            mask-position: 100% 0;
+        }}
+        /* Speaker turns (colors per speaker; committed text is dimmed) */
+        .spk-label {{
+            font-weight: 600;
+        }}
+        .spk-0 {{ color: #8ab4f8; }}
+        .spk-1 {{ color: #f28b82; }}
+        .spk-2 {{ color: #81c995; }}
+        .spk-3 {{ color: #fdd663; }}
+        .text-chunk.old .spk {{
+            opacity: 0.7;
+        }}
+        /* Caption bar mode: docked at the bottom of the screen, no header, centered */
+        body.caption-mode {{
+            background: rgba(0, 0, 0, 0.72);
+            border: none;
+            box-shadow: none;
+        }}
+        body.caption-mode #header,
+        body.caption-mode #header-toggle,
+        body.caption-mode #resize-hint {{
+            display: none;
+        }}
+        body.caption-mode #container {{
+            padding: 6px 24px;
+            cursor: default;
+        }}
+        body.caption-mode #content {{
+            text-align: center;
+            text-shadow: 0 1px 3px rgba(0, 0, 0, 0.9);
+        }}
+        body.caption-mode .text-chunk.old {{
+            opacity: 0.55;
+        }}
+        body.caption-mode #viewport {{
+            transition: opacity 0.8s ease-out;
+        }}
+        body.caption-mode.caption-idle #viewport {{
+            opacity: 0;
+        }}
+        /* Shown while auto-scroll is paused by manual scrolling */
+        #jump-latest {{
+            position: absolute;
+            bottom: 6px;
+            left: 50%;
+            transform: translate(-50%, 8px);
+            padding: 3px 12px;
+            border-radius: 12px;
+            background: rgba(60, 60, 60, 0.92);
+            color: #ddd;
+            font-size: 12px;
+            cursor: pointer;
+            opacity: 0;
+            pointer-events: none;
+            transition: opacity 0.2s, transform 0.2s;
+            z-index: 20;
+        }}
+        #jump-latest.show {{
+            opacity: 1;
+            pointer-events: auto;
+            transform: translate(-50%, 0);
+        }}
+        #jump-latest:hover {{
+            background: {glow_color};
+            color: #111;
+        }}
         .placeholder {{
             color: #666;
             font-style: italic;
@@ -511,6 +609,14 @@ pub fn get(glow_color: &str, font_size: u32) -> String {
         .speak-btn.active .material-symbols-rounded {{
             animation: speak-pulse 1.5s ease-in-out infinite;
         }}
+        .dictate-btn.active {{
+            color: #ef5350 !important;
+            border-color: #ef5350;
+            box-shadow: 0 0 8px #ef535060;
+        }}
+        .dictate-btn.active .material-symbols-rounded {{
+            animation: speak-pulse 1.5s ease-in-out infinite;
+        }}
         @keyframes speak-pulse {{
             0%, 100% {{ opacity: 1; }}
             50% {{ opacity: 0.5; }}
@@ -144,6 +144,19 @@ pub fn get() -> String {
             white-space: nowrap;
         }
         
+        .tts-playback {
+            display: flex;
+            align-items: center;
+            gap: 6px;
+        }
+        #tts-pause-btn .tts-icon-play,
+        #tts-pause-btn.paused .tts-icon-pause {
+            display: none;
+        }
+        #tts-pause-btn.paused .tts-icon-play {
+            display: inline-flex;
+        }
+        
         /* App Selection Modal */
         #app-modal {
             display: none;
@@ -2,6 +2,9 @@
 //!
 //! Spins up a tiny ephemeral HTTP server to serve the bundled font.
 //! This bypasses WebView2 file:// restrictions and base64 size limits.
+//!
+//! The realtime, result and PromptDJ windows use `var(--overlay-font)` instead of
+//! naming the font, so `overlay_font_family` can swap it for a Windows font.
 
 use std::io::{Read, Write};
 use std::net::TcpListener;
@@ -13,9 +16,128 @@ use wry::WebViewBuilder;
 static GOOGLE_SANS_FLEX_TTF: &[u8] =
     include_bytes!("../../../assets/GoogleSansFlex-VariableFont_GRAD,ROND,opsz,slnt,wdth,wght.ttf");
 
+/// `overlay_font_family` value for the Windows UI font (`system-ui`)
+pub const SYSTEM_FONT: &str = "system";
+
+pub struct FontChoice {
+    /// Value stored in `overlay_font_family` (the CSS family name)
+    pub family: &'static str,
+    pub label: &'static str,
+    /// Scripts besides Latin/Vietnamese the font has glyphs for
+    pub scripts: &'static [Script],
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Script {
+    Japanese,
+    Korean,
+    Chinese,
+}
+
+/// Selectable overlay fonts. Apart from the bundled one they ship with Windows 10+.
+pub const FONT_CHOICES: &[FontChoice] = &[
+    FontChoice {
+        family: "Google Sans Flex",
+        label: "Google Sans Flex",
+        scripts: &[],
+    },
+    FontChoice {
+        family: "Segoe UI",
+        label: "Segoe UI",
+        scripts: &[],
+    },
+    FontChoice {
+        family: "Yu Gothic UI",
+        label: "Yu Gothic UI (Japanese)",
+        scripts: &[Script::Japanese, Script::Chinese],
+    },
+    FontChoice {
+        family: "Malgun Gothic",
+        label: "Malgun Gothic (Korean)",
+        scripts: &[Script::Korean],
+    },
+    FontChoice {
+        family: "Microsoft YaHei UI",
+        label: "Microsoft YaHei UI (Chinese)",
+        scripts: &[Script::Chinese],
+    },
+    FontChoice {
+        family: SYSTEM_FONT,
+        label: "System font",
+        scripts: &[Script::Japanese, Script::Korean, Script::Chinese],
+    },
+];
+
+/// Tried in order for glyphs the chosen font lacks. Segoe UI has Arabic and Hebrew;
+/// Leelawadee UI has Thai, Lao and Khmer; Nirmala UI the Indic scripts.
+const FALLBACK_STACK: &str = "'Google Sans Flex', 'Segoe UI', 'Yu Gothic UI', 'Malgun Gothic', \
+    'Microsoft YaHei UI', 'Microsoft JhengHei UI', 'Leelawadee UI', 'Nirmala UI', \
+    'Myanmar Text', sans-serif";
+
+/// Script needed to display `language` (an English language name, as in the presets)
+pub fn script_for_language(language: &str) -> Option<Script> {
+    let language = language.to_lowercase();
+    if language.starts_with("japanese") {
+        Some(Script::Japanese)
+    } else if language.starts_with("korean") {
+        Some(Script::Korean)
+    } else if language.starts_with("chinese") || language.starts_with("cantonese") {
+        Some(Script::Chinese)
+    } else {
+        None
+    }
+}
+
+/// Whether `family` has its own glyphs for `language`. Text still renders through
+/// the fallback fonts when it doesn't, just in a mismatched style.
+pub fn font_covers_language(family: &str, language: &str) -> bool {
+    let Some(script) = script_for_language(language) else {
+        return true;
+    };
+    FONT_CHOICES
+        .iter()
+        .find(|c| c.family == family)
+        .is_some_and(|c| c.scripts.contains(&script))
+}
+
+/// Only used when the local font server couldn't start
+const FONT_CDN_CSS: &str =
+    "https://fonts.googleapis.com/css2?family=Google+Sans+Flex:wght@100..1000&display=swap";
+
 static INIT_FONTS: Once = Once::new();
 lazy_static::lazy_static! {
     static ref FONT_SERVER_URL: Mutex<Option<String>> = Mutex::new(None);
+    /// Mirrors `Config::overlay_font_family` so the HTML builders needn't lock `APP`
+    static ref OVERLAY_FONT: Mutex<String> = Mutex::new(String::new());
+}
+
+/// CSS `font-family` value for the chosen overlay font
+fn overlay_font_stack(family: &str) -> String {
+    if family.is_empty() || family == "Google Sans Flex" {
+        FALLBACK_STACK.to_string()
+    } else if family == SYSTEM_FONT {
+        format!("system-ui, {}", FALLBACK_STACK)
+    } else {
+        format!("'{}', {}", family.replace('\'', ""), FALLBACK_STACK)
+    }
+}
+
+/// Pick up a changed `overlay_font_family`; open realtime windows switch right away,
+/// other windows when their page is next built
+pub fn sync_with_config(config: &crate::config::Config) {
+    {
+        let mut current = OVERLAY_FONT.lock().unwrap();
+        if *current == config.overlay_font_family {
+            return;
+        }
+        *current = config.overlay_font_family.clone();
+    }
+
+    let script = format!(
+        "document.documentElement.style.setProperty('--overlay-font', \"{}\");",
+        overlay_font_stack(&config.overlay_font_family)
+    );
+    crate::overlay::realtime_webview::run_script(&script);
 }
 
 pub fn warmup_fonts() {
@@ -36,7 +158,7 @@ fn load_gdi_font() {
         );
 
         if handle.is_invalid() {
-            eprintln!("Failed to load Google Sans Flex into GDI");
+            log::error!("Failed to load Google Sans Flex into GDI");
         }
     }
 }
@@ -53,7 +175,7 @@ fn start_font_server() {
             let listener = match TcpListener::bind("127.0.0.1:0") {
                 Ok(l) => l,
                 Err(e) => {
-                    eprintln!("Failed to bind font server: {}", e);
+                    log::error!("Failed to bind font server: {}", e);
                     return;
                 }
             };
@@ -114,7 +236,7 @@ fn start_font_server() {
                                     // This often happens if the client closes the connection early (e.g. satisfied cache, page reload)
                                     // We filter it out to avoid log spam, as it's usually benign from the server's POV
                                     if e.raw_os_error() != Some(10053) {
-                                        eprintln!("Font server body error: {}", e);
+                                        log::error!("Font server body error: {}", e);
                                     }
                                     return;
                                 }
@@ -122,7 +244,7 @@ fn start_font_server() {
                             let _ = stream.flush();
                         });
                     }
-                    Err(e) => eprintln!("Font server request error: {}", e),
+                    Err(e) => log::error!("Font server request error: {}", e),
                 }
             }
         });
@@ -136,6 +258,11 @@ pub fn get_font_css() -> String {
     // Ensure server is started
     start_font_server();
 
+    let font_stack = OVERLAY_FONT
+        .lock()
+        .map(|family| overlay_font_stack(&family))
+        .unwrap_or_else(|_| FALLBACK_STACK.to_string());
+
     // Get URL with retry logic
     let mut font_url = String::new();
     for _ in 0..10 {
@@ -149,7 +276,17 @@ pub fn get_font_css() -> String {
     }
 
     if font_url.is_empty() {
-        eprintln!("ERROR: Could not get font server URL");
+        // The bundled copy is unreachable; the CDN still works when online
+        log::error!("Could not get font server URL, falling back to the CDN");
+        return format!(
+            r#"
+        @import url('{}');
+        :root {{
+            --overlay-font: {};
+        }}
+    "#,
+            FONT_CDN_CSS, font_stack
+        );
     }
 
     format!(
@@ -162,7 +299,10 @@ pub fn get_font_css() -> String {
             font-display: block;
             src: url('{}') format('truetype');
         }}
+        :root {{
+            --overlay-font: {};
+        }}
     "#,
-        font_url
+        font_url, font_stack
     )
 }
@@ -131,6 +131,23 @@ pub fn get_css() -> &'static str {
     .gridjs-hidden-source {
         display: none !important;
     }
+
+    .sgt-csv-copy {
+        display: block;
+        margin: 0 0 4px auto;
+        padding: 2px 8px;
+        font-size: 11px;
+        color: #aaa;
+        background: #252525;
+        border: 1px solid #444;
+        border-radius: 4px;
+        cursor: pointer;
+    }
+
+    .sgt-csv-copy:hover {
+        color: #81d4fa;
+        border-color: #81d4fa;
+    }
     "#
 }
 
@@ -139,6 +156,25 @@ pub fn get_init_script() -> &'static str {
     (function() {
         var processTimeout;
 
+        // CSV of the source table (Grid.js leaves it in place, hidden), quoting
+        // fields that hold a comma, quote or line break
+        var tableToCsv = function(table) {
+            var lines = [];
+            for (var r = 0; r < table.rows.length; r++) {
+                var cells = table.rows[r].cells;
+                var fields = [];
+                for (var c = 0; c < cells.length; c++) {
+                    var text = cells[c].textContent.trim();
+                    if (/[",\r\n]/.test(text)) {
+                        text = '"' + text.replace(/"/g, '""') + '"';
+                    }
+                    fields.push(text);
+                }
+                lines.push(fields.join(','));
+            }
+            return lines.join('\r\n') + '\r\n';
+        };
+
         var initGridJs = function() {
             if (typeof gridjs === 'undefined') {
                 setTimeout(initGridJs, 50);
@@ -157,6 +193,19 @@ pub fn get_init_script() -> &'static str {
                 var wrapper = document.createElement('div');
                 wrapper.className = 'gridjs-injected-wrapper';
                 table.parentNode.insertBefore(wrapper, table);
+
+                if (window.ipc) {
+                    var copyCsv = document.createElement('button');
+                    copyCsv.className = 'sgt-csv-copy';
+                    copyCsv.textContent = 'CSV';
+                    copyCsv.title = 'Copy as CSV';
+                    copyCsv.onclick = (function(source) {
+                        return function() {
+                            window.ipc.postMessage('copy_csv:' + tableToCsv(source));
+                        };
+                    })(table);
+                    table.parentNode.insertBefore(copyCsv, wrapper);
+                }
                 
                 try {
                     var grid = new gridjs.Grid({
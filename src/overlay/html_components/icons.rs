@@ -76,6 +76,10 @@ pub fn get_icon_svg(name: &str) -> &'static str {
             r#"<svg xmlns="http://www.w3.org/2000/svg" height="40" viewBox="0 -960 960 960" width="40"><path d="M675.48-128q-56.48 0-95.98-39.31Q540-206.63 540-264v-433q0-55.97 39.32-95.99Q618.64-833 676.02-833 732-833 772-792.99q40 40.02 40 95.99v433q0 57.37-40.02 96.69Q731.96-128 675.48-128Zm-391.5 0Q228-128 188-167.31q-40-39.32-40-96.69v-433q0-55.97 40.02-95.99Q228.04-833 284.52-833t95.98 40.01Q420-752.97 420-697v433q0 57.37-39.32 96.69Q341.36-128 283.98-128Z"/></svg>"#
         }
 
+        "stop" => {
+            r#"<svg xmlns="http://www.w3.org/2000/svg" height="24" viewBox="0 -960 960 960" width="24"><path d="M240-320v-320q0-33 23.5-56.5T320-720h320q33 0 56.5 23.5T720-640v320q0 33-23.5 56.5T640-240H320q-33 0-56.5-23.5T240-320Z"/></svg>"#
+        }
+
         "check" => {
             r#"<svg xmlns="http://www.w3.org/2000/svg" height="24" viewBox="0 -960 960 960" width="24"><path d="m382-354 339-339q12-12 28-12t28 12q12 12 12 28.5T777-636L410-268q-12 12-28 12t-28-12L182-440q-12-12-11.5-28.5T183-497q12-12 28.5-12t28.5 12l142 143Z"/></svg>"#
         }
@@ -84,6 +88,18 @@ pub fn get_icon_svg(name: &str) -> &'static str {
             r#"<svg xmlns="http://www.w3.org/2000/svg" height="24" viewBox="0 -960 960 960" width="24"><path d="M480-320 280-520l56-58 104 104v-326h80v326l104-104 56 58-200 200ZM240-160q-33 0-56.5-23.5T160-240v-120h80v120h480v-120h80v120q0 33-23.5 56.5T720-160H240Z"/></svg>"#
         }
 
+        "closed_caption" => {
+            r#"<svg xmlns="http://www.w3.org/2000/svg" height="24" viewBox="0 -960 960 960" width="24"><path d="M200-160q-33 0-56.5-23.5T120-240v-480q0-33 23.5-56.5T200-800h560q33 0 56.5 23.5T840-720v480q0 33-23.5 56.5T760-160H200Zm80-200h120q17 0 28.5-11.5T440-400v-40h-60v20h-80v-120h80v20h60v-40q0-17-11.5-28.5T400-600H280q-17 0-28.5 11.5T240-560v160q0 17 11.5 28.5T280-360Zm280 0h120q17 0 28.5-11.5T720-400v-40h-60v20h-80v-120h80v20h60v-40q0-17-11.5-28.5T680-600H560q-17 0-28.5 11.5T520-560v160q0 17 11.5 28.5T560-360Z"/></svg>"#
+        }
+
+        "keyboard" => {
+            r#"<svg xmlns="http://www.w3.org/2000/svg" height="24" viewBox="0 -960 960 960" width="24"><path d="M160-200q-33 0-56.5-23.5T80-280v-400q0-33 23.5-56.5T160-760h640q33 0 56.5 23.5T880-680v400q0 33-23.5 56.5T800-200H160Zm0-80h640v-400H160v400ZM200-640h80v80h-80ZM320-640h80v80h-80ZM440-640h80v80h-80ZM560-640h80v80h-80ZM680-640h80v80h-80ZM200-520h80v80h-80ZM320-520h80v80h-80ZM440-520h80v80h-80ZM560-520h80v80h-80ZM680-520h80v80h-80ZM320-360h320v-40H320v40Z"/></svg>"#
+        }
+
+        "record_voice_over" => {
+            r#"<svg xmlns="http://www.w3.org/2000/svg" height="24" viewBox="0 -960 960 960" width="24"><path d="M360-440q-66 0-113-47t-47-113q0-66 47-113t113-47q66 0 113 47t47 113q0 66-47 113t-113 47ZM40-200v-32q0-34 17.5-62.5T104-338q62-31 126-46.5T360-400q66 0 130 15.5T616-338q29 15 46.5 43.5T680-232v32q0 33-23.5 56.5T600-120H120q-33 0-56.5-23.5T40-200Zm720-260-60-60q20-20 30-43t10-47q0-24-10-47t-30-43l60-60q32 32 51 71.5t19 78.5q0 39-19 78.5T760-460Zm112 112-56-56q40-40 62-86.5t22-99.5q0-53-22-99.5T816-776l56-56q52 52 80 116t28 136q0 72-28 136T872-348Z"/></svg>"#
+        }
+
         "bolt_en" => {
             r##"<svg xmlns="http://www.w3.org/2000/svg" height="24" viewBox="0 -960 960 960" width="24"><path d="M240-80l80-320H120l280-520h120l-80 320h200L240-80Z" fill="currentColor"/><rect x="440" y="-520" width="520" height="480" rx="80" fill="currentColor" stroke="#1c1c1c" stroke-width="40"/><text x="700" y="-160" text-anchor="middle" font-family="Arial Black, sans-serif" font-size="380" font-weight="1000" fill="black">EN</text></svg>"##
         }
@@ -1,7 +1,108 @@
-pub fn get(placeholder_text: &str) -> String {
+pub fn get(placeholder_text: &str, speaker_label: &str, speaker_labels_on: bool) -> String {
     format!(
-        r###"        function updateText(oldText, newText) {{
+        r###"        // Speaker turns start a line with the label ("Speaker 2:"); the label and the
+        // turn's text get that speaker's color
+        let speakerLabelsOn = {speaker_labels_on};
+        const speakerLabelRe = (function() {{
+            const parts = {speaker_label_json}.split('{{}}').map(p => p.replace(/[.*+?^${{}}()|[\]\\]/g, '\\$&'));
+            return new RegExp('(^|\n)(' + parts.join('(\\d+)') + ')', 'g');
+        }})();
+        
+        // Wrap speaker text in colored spans. textContent stays the same, so the chunk
+        // bookkeeping in updateText is unaffected; unchanged chunks are skipped.
+        function decorateSpeakers() {{
+            if (!speakerLabelsOn) return;
+            let speaker = -1;
+            for (const chunk of content.querySelectorAll('.text-chunk')) {{
+                const text = chunk.textContent;
+                if (chunk.dataset.spkText === text && chunk.dataset.spkStart === String(speaker)) {{
+                    speaker = parseInt(chunk.dataset.spkEnd);
+                    continue;
+                }}
+                const start = speaker;
+                chunk.textContent = '';
+                const pushText = (s) => {{
+                    if (!s) return;
+                    const span = document.createElement('span');
+                    if (speaker >= 0) span.className = 'spk spk-' + (speaker % 4);
+                    span.textContent = s;
+                    chunk.appendChild(span);
+                }};
+                let last = 0;
+                let m;
+                speakerLabelRe.lastIndex = 0;
+                while ((m = speakerLabelRe.exec(text)) !== null) {{
+                    const labelStart = m.index + m[1].length;
+                    pushText(text.substring(last, labelStart));
+                    speaker = Math.max(0, parseInt(m[3] || '1') - 1);
+                    const label = document.createElement('span');
+                    label.className = 'spk-label spk-' + (speaker % 4);
+                    label.textContent = m[2];
+                    chunk.appendChild(label);
+                    last = labelStart + m[2].length;
+                }}
+                pushText(text.substring(last));
+                chunk.dataset.spkText = text;
+                chunk.dataset.spkStart = String(start);
+                chunk.dataset.spkEnd = String(speaker);
+            }}
+        }}
+        
+        const speakerBtn = document.getElementById('speaker-btn');
+        if (speakerBtn) {{
+            speakerBtn.addEventListener('click', function(e) {{
+                e.stopPropagation();
+                speakerLabelsOn = !speakerLabelsOn;
+                this.classList.toggle('active', speakerLabelsOn);
+                window.ipc.postMessage('speakerLabels:' + (speakerLabelsOn ? '1' : '0'));
+            }});
+        }}
+        
+        // Dictation (typing into the focused app): the button glows red while on
+        const dictateBtn = document.getElementById('dictate-btn');
+        window.setDictation = function(on) {{
+            if (dictateBtn) dictateBtn.classList.toggle('active', on);
+        }};
+        if (dictateBtn) {{
+            dictateBtn.addEventListener('click', function(e) {{
+                e.stopPropagation();
+                const on = !this.classList.contains('active');
+                window.setDictation(on);
+                window.ipc.postMessage('dictation:' + (on ? '1' : '0'));
+            }});
+        }}
+        
+        // Caption bar mode (set from Rust): the text fades out after a quiet spell
+        const CAPTION_IDLE_MS = 6000;
+        let captionIdleTimer = null;
+        function bumpCaptionIdle() {{
+            clearTimeout(captionIdleTimer);
+            document.body.classList.remove('caption-idle');
+            if (!document.body.classList.contains('caption-mode')) return;
+            captionIdleTimer = setTimeout(() => document.body.classList.add('caption-idle'), CAPTION_IDLE_MS);
+        }}
+        window.setCaptionMode = function(on) {{
+            document.body.classList.toggle('caption-mode', on);
+            bumpCaptionIdle();
+        }};
+        
+        const captionBtn = document.getElementById('caption-btn');
+        if (captionBtn) {{
+            captionBtn.addEventListener('click', function(e) {{
+                e.stopPropagation();
+                window.ipc.postMessage('captionMode');
+            }});
+        }}
+        // The bar has no header, so a double-click switches back to the window
+        container.addEventListener('dblclick', function(e) {{
+            if (document.body.classList.contains('caption-mode')) {{
+                window.ipc.postMessage('captionMode');
+            }}
+        }});
+        
+        function updateText(oldText, newText) {{
             const hasContent = oldText || newText;
+            bumpCaptionIdle();
             
             if (isFirstText && hasContent) {{
                 content.innerHTML = '';
@@ -19,6 +120,7 @@ pub fn get(placeholder_text: &str) -> String {
                 targetScrollTop = 0;
                 currentScrollTop = 0;
                 viewport.scrollTop = 0;
+                setScrollLocked(false);
                 currentOldTextLength = 0;
                 previousNewText = '';
                 return;
@@ -144,6 +246,8 @@ pub fn get(placeholder_text: &str) -> String {
                 }}
             }}
             
+            decorateSpeakers();
+            
             // Scroll logic
             const naturalHeight = content.offsetHeight;
             if (naturalHeight > minContentHeight) {{
@@ -180,8 +284,9 @@ pub fn get(placeholder_text: &str) -> String {
         let scrollProgress = 0; // 0 to 1, represents progress to next bar shift
         let lastTime = 0;
         
-        function updateVolume(rms) {{
+        function updateVolume(rms, silent) {{
             latestRMS = rms;
+            if (volumeCanvas) volumeCanvas.classList.toggle('silent', !!silent);
         }}
         
         function drawWaveform(timestamp) {{
@@ -276,11 +381,15 @@ pub fn get(placeholder_text: &str) -> String {
             targetScrollTop = 0;
             currentScrollTop = 0;
             viewport.scrollTop = 0;
+            setScrollLocked(false);
             currentOldTextLength = 0;
             previousNewText = '';
         }}
         
         window.clearText = clearText;"###,
-        placeholder_text = placeholder_text
+        placeholder_text = placeholder_text,
+        speaker_labels_on = speaker_labels_on,
+        speaker_label_json =
+            serde_json::to_string(speaker_label).unwrap_or_else(|_| "\"\"".to_string())
     )
 }
@@ -10,6 +10,16 @@ pub fn get(font_size: u32) -> String {
         const fontDecrease = document.getElementById('font-decrease');
         const fontIncrease = document.getElementById('font-increase');
         const resizeHint = document.getElementById('resize-hint');
+        
+        // Header controls stay hidden until the bundled font is in, so labels and the
+        // language list don't show in the fallback font first (capped in case it never loads)
+        const revealControls = () => document.body.classList.add('fonts-ready');
+        if (document.fonts && document.fonts.ready) {{
+            document.fonts.ready.then(revealControls);
+        }} else {{
+            revealControls();
+        }}
+        setTimeout(revealControls, 1500);
         const copyBtn = document.getElementById('copy-btn');
         
         let currentFontSize = {font_size};
@@ -27,8 +37,18 @@ pub fn get(font_size: u32) -> String {
         const ttsToggle = document.getElementById('tts-toggle');
         const speedSlider = document.getElementById('speed-slider');
         const speedValue = document.getElementById('speed-value');
+        const ttsPauseBtn = document.getElementById('tts-pause-btn');
+        const ttsStopBtn = document.getElementById('tts-stop-btn');
         let ttsEnabled = false;
-        let ttsSpeed = 100;
+        let ttsSpeed = speedSlider ? parseInt(speedSlider.value) : 100;
+        let ttsPaused = false;
+        
+        function setTtsPaused(paused) {{
+            ttsPaused = paused;
+            if (!ttsPauseBtn) return;
+            ttsPauseBtn.classList.toggle('paused', paused);
+            ttsPauseBtn.title = paused ? ttsPauseBtn.dataset.resumeTitle : ttsPauseBtn.dataset.pauseTitle;
+        }}
         
         // TTS Modal Logic
         if (speakBtn && ttsModal && ttsModalOverlay) {{
@@ -50,10 +70,26 @@ pub fn get(font_size: u32) -> String {
                 ttsEnabled = !ttsEnabled;
                 this.classList.toggle('on', ttsEnabled);
                 if (speakBtn) speakBtn.classList.toggle('active', ttsEnabled);
+                // Turning TTS off stops it, which also ends a pause
+                if (!ttsEnabled) setTtsPaused(false);
                 window.ipc.postMessage('ttsEnabled:' + (ttsEnabled ? '1' : '0'));
             }});
         }}
         
+        // Playback controls: pause/resume keeps the queue, stop flushes it
+        if (ttsPauseBtn && ttsStopBtn) {{
+            ttsPauseBtn.addEventListener('click', function(e) {{
+                e.stopPropagation();
+                setTtsPaused(!ttsPaused);
+                window.ipc.postMessage('ttsPause:' + (ttsPaused ? '1' : '0'));
+            }});
+            ttsStopBtn.addEventListener('click', function(e) {{
+                e.stopPropagation();
+                setTtsPaused(false);
+                window.ipc.postMessage('ttsStop');
+            }});
+        }}
+        
         if (speedSlider && speedValue) {{
             const autoToggle = document.getElementById('auto-speed-toggle');
             let autoSpeed = true; // Default: auto is on
@@ -61,7 +97,7 @@ pub fn get(font_size: u32) -> String {
             speedSlider.addEventListener('input', function(e) {{
                 e.stopPropagation();
                 ttsSpeed = parseInt(this.value);
-                speedValue.textContent = (ttsSpeed / 100).toFixed(1) + 'x';
+                speedValue.textContent = (ttsSpeed / 100).toFixed(2) + 'x';
                 window.ipc.postMessage('ttsSpeed:' + ttsSpeed);
                 // Auto turns off when user manually adjusts slider
                 if (autoSpeed && autoToggle) {{
@@ -91,22 +127,47 @@ pub fn get(font_size: u32) -> String {
         }}
         
         // Copy button handler
+        // Copy feedback: swap the icon to a check mark for a moment
+        function flashCopied() {{
+            copyBtn.classList.add('copied');
+            const icon = copyBtn.querySelector('.material-symbols-rounded');
+            if (icon) icon.innerHTML = '{check_svg}';
+            setTimeout(() => {{
+                copyBtn.classList.remove('copied');
+                if (icon) icon.innerHTML = '{copy_svg}';
+            }}, 1500);
+        }}
+        
+        // Long-press copies the transcript and translation as bilingual notes
+        let copyHoldTimer = null;
+        let copyHeld = false;
+        if (copyBtn) {{
+            copyBtn.addEventListener('mousedown', function(e) {{
+                if (e.button !== 0) return;
+                copyHeld = false;
+                copyHoldTimer = setTimeout(() => {{
+                    copyHeld = true;
+                    window.ipc.postMessage('copyBilingual');
+                    flashCopied();
+                }}, 500);
+            }});
+            ['mouseup', 'mouseleave'].forEach(evt => copyBtn.addEventListener(evt, () => clearTimeout(copyHoldTimer)));
+        }}
+        
         if (copyBtn) {{
             copyBtn.addEventListener('click', function(e) {{
                 e.stopPropagation();
+                if (copyHeld) {{
+                    copyHeld = false;
+                    return;
+                }}
                 // Get all text content (excluding placeholder)
                 const textContent = content.textContent.trim();
                 if (textContent && !content.querySelector('.placeholder')) {{
                     // Send to Rust via IPC for clipboard (navigator.clipboard not available in WebView2)
                     window.ipc.postMessage('copyText:' + textContent);
                     // Show success feedback
-                    copyBtn.classList.add('copied');
-                    const icon = copyBtn.querySelector('.material-symbols-rounded');
-                    if (icon) icon.innerHTML = '{check_svg}';
-                    setTimeout(() => {{
-                        copyBtn.classList.remove('copied');
-                        if (icon) icon.innerHTML = '{copy_svg}';
-                    }}, 1500);
+                    flashCopied();
                 }}
             }});
         }}
@@ -223,7 +284,7 @@ pub fn get(font_size: u32) -> String {
         window.updateTtsSpeed = function(speed) {{
             ttsSpeed = speed;
             if (speedSlider) speedSlider.value = speed;
-            if (speedValue) speedValue.textContent = (speed / 100).toFixed(1) + 'x';
+            if (speedValue) speedValue.textContent = (speed / 100).toFixed(2) + 'x';
         }};
         
         // Font size controls
@@ -285,6 +346,17 @@ pub fn get(font_size: u32) -> String {
 
         // Language Select Logic - show short code when collapsed, full name when open
         const langSelect = document.getElementById('language-select');
+
+        // The translation takes the target language: right-to-left ones (data-rtl) read
+        // right to left, and lang picks the line breaking rules of its script
+        function applyTargetLanguage() {{
+            const selected = langSelect && langSelect.selectedOptions[0];
+            if (selected) {{
+                content.dir = selected.dataset.rtl ? 'rtl' : 'ltr';
+                content.lang = (selected.dataset.code || '').toLowerCase();
+            }}
+        }}
+
         if (langSelect) {{
             // Store original full names
             const options = langSelect.querySelectorAll('option');
@@ -321,6 +393,7 @@ pub fn get(font_size: u32) -> String {
             langSelect.addEventListener('change', function(e) {{
                 e.stopPropagation();
                 window.ipc.postMessage('language:' + this.value);
+                applyTargetLanguage();
                 // Delay to let the dropdown close animation finish
                 setTimeout(showCodes, 100);
             }});
@@ -414,6 +487,7 @@ pub fn get(font_size: u32) -> String {
             // Update language select
             if (settings.targetLanguage && langSelect) {{
                 langSelect.value = settings.targetLanguage;
+                applyTargetLanguage();
             }}
             
             // Update translation model
@@ -452,7 +526,7 @@ pub fn get(font_size: u32) -> String {
                     content.style.minHeight = '';
                     
                     // Force scroll to bottom immediately to prevent jump
-                    if (content.scrollHeight > viewport.clientHeight) {{
+                    if (!scrollLocked && content.scrollHeight > viewport.clientHeight) {{
                         viewport.scrollTop = content.scrollHeight - viewport.clientHeight;
                     }}
                     targetScrollTop = viewport.scrollTop;
@@ -469,6 +543,10 @@ pub fn get(font_size: u32) -> String {
         let minContentHeight = 0;
         
         function animateScroll() {{
+            if (scrollLocked) {{
+                animationFrame = null;
+                return;
+            }}
             const diff = targetScrollTop - currentScrollTop;
             
             if (Math.abs(diff) > 0.5) {{
@@ -483,6 +561,50 @@ pub fn get(font_size: u32) -> String {
             }}
         }}
         
+        // Manual scrolling: scrolling up pauses auto-scroll (and shows the "jump to
+        // latest" pill) until the user is back at the bottom
+        let scrollLocked = false;
+        const jumpLatest = document.getElementById('jump-latest');
+        
+        function bottomScrollTop() {{
+            return Math.max(0, content.offsetHeight - viewport.clientHeight);
+        }}
+        
+        function setScrollLocked(locked) {{
+            if (scrollLocked === locked) return;
+            scrollLocked = locked;
+            if (jumpLatest) jumpLatest.classList.toggle('show', locked);
+            if (!locked) {{
+                currentScrollTop = viewport.scrollTop;
+                if (!animationFrame) {{
+                    animationFrame = requestAnimationFrame(animateScroll);
+                }}
+            }}
+        }}
+        
+        viewport.addEventListener('wheel', function(e) {{
+            e.preventDefault();
+            if (animationFrame) {{
+                cancelAnimationFrame(animationFrame);
+                animationFrame = null;
+            }}
+            const bottom = bottomScrollTop();
+            viewport.scrollTop = Math.min(bottom, Math.max(0, viewport.scrollTop + e.deltaY));
+            currentScrollTop = viewport.scrollTop;
+            setScrollLocked(viewport.scrollTop < bottom - 4);
+            if (!scrollLocked && !animationFrame) {{
+                animationFrame = requestAnimationFrame(animateScroll);
+            }}
+        }}, {{ passive: false }});
+        
+        if (jumpLatest) {{
+            jumpLatest.addEventListener('mousedown', e => e.stopPropagation());
+            jumpLatest.addEventListener('click', function(e) {{
+                e.stopPropagation();
+                setScrollLocked(false);
+            }});
+        }}
+        
         let currentOldTextLength = 0;
         let previousNewText = '';
 "###,
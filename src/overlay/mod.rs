@@ -1,10 +1,13 @@
 pub mod auto_copy_badge; // Auto-copy notification badge
 pub mod broom_assets;
+pub mod diagnostics; // Windows, workers, hooks and memory for the settings panel
+pub mod dpi_calibration; // 100px test box per monitor for checking DPI alignment
 pub mod input_history; // Persistent input history for arrow up/down navigation
 pub mod paint_utils;
 pub mod preset_wheel;
 pub mod process;
 pub mod prompt_dj;
+pub mod quick_translate; // Translate clipboard/selection without a preset
 pub mod recording;
 pub mod result;
 mod selection;
@@ -18,6 +21,10 @@ pub mod realtime_egui; // Minimal mode (native egui)
 pub mod realtime_html; // HTML generation for realtime overlay
 pub mod realtime_webview; // New WebView2-based with smooth scrolling
 pub mod tray_popup; // Custom non-blocking tray popup menu
+pub mod volume_mixer; // Per-app volume sliders (tray popup)
+pub mod warmup; // Startup WebView warmup scheduling
+pub mod webview_lifecycle; // Persistent vs lazy WebViews, WebView memory usage
+pub mod webview_recovery; // Rebuild WebViews whose WebView2 process crashed
 
 pub use recording::{
     is_recording_overlay_active, show_recording_overlay, stop_recording_and_submit,
@@ -29,6 +36,22 @@ pub use realtime_webview::{
     is_realtime_overlay_active, show_realtime_overlay, stop_realtime_overlay,
 };
 
+/// Close result windows, cancel screen/text selections and the preset wheel, and stop
+/// live translate (the "dismiss all overlays" hotkey)
+pub fn dismiss_all_overlays() {
+    result::close_all_result_windows();
+    selection::is_selection_overlay_active_and_dismiss();
+    if text_selection::is_active() {
+        text_selection::cancel_selection();
+    }
+    if preset_wheel::is_wheel_active() {
+        preset_wheel::dismiss_wheel();
+    }
+    if is_realtime_overlay_active() {
+        stop_realtime_overlay();
+    }
+}
+
 /// Get the shared WebView2 data directory path.
 /// All WebViews using this same path will share browser processes, reducing RAM usage.
 /// Uses %APPDATA%/SGT/webview_data on Windows.
@@ -52,11 +75,11 @@ pub fn clear_webview_permissions() -> bool {
     if path.exists() {
         match std::fs::remove_dir_all(&path) {
             Ok(_) => {
-                println!("WebView data cleared successfully at {:?}", path);
+                log::info!("WebView data cleared successfully at {:?}", path);
                 true
             }
             Err(e) => {
-                eprintln!("Failed to clear WebView data: {:?}", e);
+                log::error!("Failed to clear WebView data: {:?}", e);
                 false
             }
         }
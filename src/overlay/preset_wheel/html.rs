@@ -1,8 +1,26 @@
 // Preset Wheel HTML - Apple Watch fisheye with center-out ripple animation
 
-use crate::config::Preset;
+use crate::config::{Config, Preset};
 use crate::gui::settings_ui::get_localized_preset_name;
 
+/// Size settings of the wheel (see `Config::wheel_radius` / `wheel_item_size`)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WheelGeometry {
+    /// Reach of the hover magnification in px; item spacing grows with it
+    pub radius: u32,
+    /// Font size of the preset pills in px; padding and width scale with it
+    pub item_size: u32,
+}
+
+impl WheelGeometry {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            radius: config.wheel_radius.clamp(40, 200),
+            item_size: config.wheel_item_size.clamp(10, 28),
+        }
+    }
+}
+
 pub fn escape_html(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
@@ -83,11 +101,12 @@ pub fn generate_items_html(presets: &[(usize, Preset)], ui_lang: &str) -> String
     html
 }
 
-/// Returns the static HTML skeleton with CSS and JS (loaded once)
-pub fn get_wheel_template(is_dark: bool) -> String {
+/// Returns the static HTML skeleton with CSS and JS (reloaded when the geometry changes)
+pub fn get_wheel_template(is_dark: bool, geometry: WheelGeometry) -> String {
     let font_css = crate::overlay::html_components::font_manager::get_font_css();
-    let css = generate_css(is_dark);
+    let css = generate_css(is_dark, geometry);
     let js = get_js();
+    let effect_radius = geometry.radius;
 
     format!(
         r#"<!DOCTYPE html>
@@ -108,7 +127,9 @@ pub fn get_wheel_template(is_dark: bool) -> String {
         <!-- Items will be injected here -->
     </div>
 </div>
+<input id="search" class="search-query" type="text" autocomplete="off" spellcheck="false">
 <script>
+const EFFECT_RADIUS = {effect_radius};
 {js}
 </script>
 </body>
@@ -117,7 +138,17 @@ pub fn get_wheel_template(is_dark: bool) -> String {
 }
 
 /// Generate CSS for the wheel with light/dark theme support
-pub fn generate_css(is_dark: bool) -> String {
+pub fn generate_css(is_dark: bool, geometry: WheelGeometry) -> String {
+    // Defaults (radius 80, size 12) give the original 10px gaps and 9x14px padding
+    let gap = geometry.radius / 8;
+    let size = geometry.item_size as f32;
+    let item_font = geometry.item_size;
+    let item_pad_y = (size * 0.75).round();
+    let item_pad_x = (size * 14.0 / 12.0).round();
+    let item_min_width = (size * 85.0 / 12.0).round();
+    let item_radius = (size * 15.0 / 12.0).round();
+    let row_height = (size * 40.0 / 12.0).round();
+
     // Theme-specific colors
     let (
         text_color,
@@ -292,7 +323,7 @@ html, body {{
     flex-direction: column;
     align-items: center;
     justify-content: center;
-    gap: 10px;
+    gap: {gap}px;
     padding: 20px;
 }}
 
@@ -302,21 +333,21 @@ html, body {{
     flex-direction: row;
     justify-content: center;
     align-items: center;
-    gap: 10px;
-    min-height: 40px;
+    gap: {gap}px;
+    min-height: {row_height}px;
 }}
 
 .preset-item {{
     display: inline-flex;
     align-items: center;
     justify-content: center;
-    padding: 9px 14px;
-    min-width: 85px;
+    padding: {item_pad_y}px {item_pad_x}px;
+    min-width: {item_min_width}px;
     backdrop-filter: blur(12px);
     border: 1px solid {item_border};
-    border-radius: 15px;
+    border-radius: {item_radius}px;
     cursor: pointer;
-    font-size: 12px;
+    font-size: {item_font}px;
     white-space: nowrap;
     letter-spacing: 0;
     color: {text_color};
@@ -352,6 +383,43 @@ html, body {{
     transform: scale(0.88) !important;
     transition: transform 0.05s ease !important;
 }}
+
+/* Type-to-search: items that don't match fade out */
+.preset-item.visible.dimmed {{
+    opacity: 0.25;
+}}
+
+/* Typed query - focused but invisible until something is typed */
+.search-query {{
+    position: fixed;
+    bottom: 14px;
+    left: 50%;
+    transform: translateX(-50%);
+    width: 220px;
+    padding: 6px 14px;
+    background: {dismiss_bg};
+    backdrop-filter: blur(16px);
+    border: 1px solid {item_hover_border};
+    border-radius: 14px;
+    outline: none;
+    font: inherit;
+    font-size: 13px;
+    text-align: center;
+    color: {text_color};
+    user-select: text;
+    pointer-events: none;
+    opacity: 0;
+    transition: opacity 0.1s ease;
+}}
+
+.search-query.active {{
+    opacity: 1;
+}}
+
+.search-query.no-match {{
+    border-color: {dismiss_hover_border};
+    color: {dismiss_color};
+}}
 "#,
         text_color = text_color,
         dismiss_bg = dismiss_bg,
@@ -362,7 +430,14 @@ html, body {{
         item_border = item_border,
         item_hover_border = item_hover_border,
         item_shadow = item_shadow,
-        color_palette = color_palette
+        color_palette = color_palette,
+        gap = gap,
+        row_height = row_height,
+        item_pad_y = item_pad_y,
+        item_pad_x = item_pad_x,
+        item_min_width = item_min_width,
+        item_radius = item_radius,
+        item_font = item_font
     )
 }
 
@@ -384,7 +459,6 @@ const dismissBtn = document.querySelector('.dismiss-btn');
 // Tuned constants - NO shrinking, only scale up hovered item
 const MAX_SCALE = 1.10;
 const MIN_SCALE = 1.0;
-const EFFECT_RADIUS = 80;
 const BASE_WEIGHT = 500;     
 const MAX_WEIGHT = 650;      
 const BASE_WIDTH = 100;      
@@ -395,6 +469,18 @@ let mouseX = -1000;
 let mouseY = -1000;
 let isMouseInGrid = false;
 
+// Hovered preset, reported to Rust for release-to-select
+let reportedHover = null;
+
+function reportHover() {
+    const hovered = document.querySelector('.preset-item.hovered');
+    const idx = hovered ? hovered.dataset.idx : '';
+    if (idx !== reportedHover) {
+        reportedHover = idx;
+        window.ipc.postMessage('hover:' + idx);
+    }
+}
+
 // Cache item positions to avoid getBoundingClientRect returning scaled positions
 // This fixes the cursor position vs hover mismatch issue
 let itemCenters = new Map();
@@ -470,6 +556,7 @@ function updateFisheye() {
         
         item.style.transform = `scale(${scale.toFixed(3)})`;
     });
+    reportHover();
 }
 
 function onMouseMove(e) {
@@ -498,6 +585,7 @@ function onMouseLeave() {
         item.style.fontVariationSettings = `'wght' ${BASE_WEIGHT}, 'wdth' ${BASE_WIDTH}, 'ROND' 100`;
         item.classList.remove('hovered');
     });
+    reportHover();
 }
 
 grid.addEventListener('mousemove', onMouseMove);
@@ -549,6 +637,55 @@ function animateIn() {
     setTimeout(() => cacheItemPositions(), totalAnimationTime);
 }
 
+// === Type to search ===
+// Typing narrows the items, arrows/Tab move between matches, Enter selects.
+// A (hidden) input collects the text so IME composition (Korean, Vietnamese) works.
+const searchInput = document.getElementById('search');
+let matchIndex = 0;
+
+function normalizeText(s) {
+    return s.toLowerCase().normalize('NFD').replace(/[\u0300-\u036f]/g, '').replace(/đ/g, 'd');
+}
+
+function getMatches() {
+    return items.filter(item => !item.classList.contains('dimmed'));
+}
+
+function applySearch() {
+    const query = normalizeText(searchInput.value.trim());
+    items.forEach(item => {
+        item.classList.toggle('dimmed', query !== '' && !normalizeText(item.textContent).includes(query));
+        item.classList.remove('hovered');
+    });
+    const matches = getMatches();
+    if (query && matches.length > 0) {
+        matchIndex = ((matchIndex % matches.length) + matches.length) % matches.length;
+        matches[matchIndex].classList.add('hovered');
+    }
+    reportHover();
+    searchInput.classList.toggle('active', query !== '');
+    searchInput.classList.toggle('no-match', query !== '' && matches.length === 0);
+}
+
+function resetSearch() {
+    searchInput.value = '';
+    matchIndex = 0;
+    applySearch();
+}
+
+// Called by Rust once the wheel window has focus
+window.focusSearch = function() {
+    searchInput.focus();
+};
+
+searchInput.addEventListener('input', () => {
+    matchIndex = 0;
+    applySearch();
+});
+
+// Clicks elsewhere (background, items) would take focus away from the query
+document.addEventListener('blur', () => setTimeout(() => searchInput.focus(), 0), true);
+
 // Function called by Rust to update content and trigger animation
 window.updateContent = function(itemsHtml, dismissLabel) {
     grid.innerHTML = itemsHtml;
@@ -556,6 +693,7 @@ window.updateContent = function(itemsHtml, dismissLabel) {
     
     // Re-query items - now nested in .preset-row divs
     items = Array.from(document.querySelectorAll('.preset-item'));
+    resetSearch();
     
     // Clear cached positions
     itemCenters.clear();
@@ -579,7 +717,21 @@ window.updateContent = function(itemsHtml, dismissLabel) {
 };
 
 document.addEventListener('keydown', (e) => {
-    if (e.key === 'Escape') dismiss();
+    if (e.isComposing) return;
+    if (e.key === 'Escape') {
+        dismiss();
+    } else if (e.key === 'Enter') {
+        e.preventDefault();
+        const target = searchInput.value.trim()
+            ? getMatches()[matchIndex]
+            : document.querySelector('.preset-item.hovered');
+        if (target) select(target.dataset.idx);
+    } else if (searchInput.value.trim() && ['ArrowRight', 'ArrowDown', 'ArrowLeft', 'ArrowUp', 'Tab'].includes(e.key)) {
+        e.preventDefault();
+        const back = e.key === 'ArrowLeft' || e.key === 'ArrowUp' || (e.key === 'Tab' && e.shiftKey);
+        matchIndex += back ? -1 : 1;
+        applySearch();
+    }
 });
     "#
 }
@@ -4,4 +4,5 @@
 mod html;
 mod window;
 
-pub use window::{dismiss_wheel, is_wheel_active, show_preset_wheel, warmup};
+pub(crate) use window::WHEEL_HWND;
+pub use window::{dismiss_wheel, is_wheel_active, show_preset_wheel, sync_with_config, warmup};
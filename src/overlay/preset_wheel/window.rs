@@ -1,7 +1,7 @@
 // Preset Wheel Window - Persistent Hidden Window for Instant Appearance
 
-use super::html::{generate_css, generate_items_html, get_wheel_template};
-use crate::config::Preset;
+use super::html::{generate_css, generate_items_html, get_wheel_template, WheelGeometry};
+use crate::config::{Config, Preset};
 use crate::APP;
 use std::cell::RefCell;
 use std::sync::atomic::{AtomicBool, AtomicI32, AtomicIsize, Ordering};
@@ -13,6 +13,7 @@ use windows::Win32::Graphics::Gdi::*;
 use windows::Win32::System::Com::{CoInitialize, CoUninitialize};
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
 use windows::Win32::UI::Controls::MARGINS;
+use windows::Win32::UI::Input::KeyboardAndMouse::GetAsyncKeyState;
 use windows::Win32::UI::WindowsAndMessaging::*;
 use wry::{Rect, WebContext, WebView, WebViewBuilder};
 
@@ -23,6 +24,7 @@ static REGISTER_OVERLAY_CLASS: Once = Once::new();
 const WM_APP_SHOW: u32 = WM_USER + 10;
 const WM_APP_HIDE: u32 = WM_USER + 11;
 const WM_APP_REAL_SHOW: u32 = WM_USER + 12;
+const WM_APP_RELOAD: u32 = WM_USER + 13;
 
 // Large dimensions for wheel window - transparent so no visual impact
 // Must fit on common screens (1366x768 minimum)
@@ -32,9 +34,11 @@ const WHEEL_HEIGHT: i32 = 700;
 // Result communication
 pub static WHEEL_RESULT: AtomicI32 = AtomicI32::new(-1);
 pub static WHEEL_ACTIVE: AtomicBool = AtomicBool::new(false);
+// Preset index under the cursor (or search highlight), -1 if none
+static HOVERED_PRESET: AtomicI32 = AtomicI32::new(-1);
 
 // Thread-safe handles
-static WHEEL_HWND: AtomicIsize = AtomicIsize::new(0);
+pub(crate) static WHEEL_HWND: AtomicIsize = AtomicIsize::new(0);
 static OVERLAY_HWND: AtomicIsize = AtomicIsize::new(0);
 static IS_WARMING_UP: AtomicBool = AtomicBool::new(false);
 static IS_WARMED_UP: AtomicBool = AtomicBool::new(false);
@@ -46,6 +50,8 @@ lazy_static::lazy_static! {
     static ref PENDING_CSS: Mutex<String> = Mutex::new(String::new());
     static ref PENDING_POS: Mutex<(i32, i32)> = Mutex::new((0, 0));
     static ref SELECTED_PRESET: Mutex<Option<usize>> = Mutex::new(None);
+    // Geometry the loaded template was generated with
+    static ref BUILT_GEOMETRY: Mutex<Option<WheelGeometry>> = Mutex::new(None);
 }
 
 thread_local! {
@@ -68,7 +74,18 @@ impl raw_window_handle::HasWindowHandle for HwndWrapper {
     }
 }
 
+/// Create the wheel window, or regenerate its page if it already exists
 pub fn warmup() {
+    if IS_WARMED_UP.load(Ordering::SeqCst) {
+        let wheel_hwnd = HWND(WHEEL_HWND.load(Ordering::SeqCst) as *mut _);
+        if !wheel_hwnd.is_invalid() {
+            unsafe {
+                let _ = PostMessageW(Some(wheel_hwnd), WM_APP_RELOAD, WPARAM(0), LPARAM(0));
+            }
+        }
+        return;
+    }
+
     // Prevent multiple warmup threads from spawning
     if IS_WARMING_UP
         .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
@@ -81,12 +98,39 @@ pub fn warmup() {
     });
 }
 
+/// Regenerate the wheel page when its geometry settings changed
+pub fn sync_with_config(config: &Config) {
+    let built = *BUILT_GEOMETRY.lock().unwrap();
+    if built.is_some_and(|g| g != WheelGeometry::from_config(config)) {
+        warmup();
+    }
+}
+
+/// Current theme and geometry from the config
+fn wheel_style() -> (bool, WheelGeometry) {
+    let app = APP.lock().unwrap();
+    let is_dark = match app.config.theme_mode {
+        crate::config::ThemeMode::Dark => true,
+        crate::config::ThemeMode::Light => false,
+        crate::config::ThemeMode::System => crate::gui::utils::is_system_in_dark_mode(),
+    };
+    (is_dark, WheelGeometry::from_config(&app.config))
+}
+
+fn is_key_down(vk: i32) -> bool {
+    unsafe { (GetAsyncKeyState(vk) as u16 & 0x8000) != 0 }
+}
+
+/// Keyboard keys currently held down (mouse buttons excluded)
+fn held_keys() -> Vec<i32> {
+    (0x08..=0xFE).filter(|&vk| is_key_down(vk)).collect()
+}
+
 pub fn show_preset_wheel(
     filter_type: &str,
     filter_mode: Option<&str>,
     center_pos: POINT,
 ) -> Option<usize> {
-    // Check if warmed up first
     // Check if warmed up first
     if !IS_WARMED_UP.load(Ordering::SeqCst) {
         // Try to trigger warmup for recovery
@@ -115,24 +159,21 @@ pub fn show_preset_wheel(
     unsafe {
         WHEEL_RESULT.store(-1, Ordering::SeqCst);
         WHEEL_ACTIVE.store(true, Ordering::SeqCst);
+        HOVERED_PRESET.store(-1, Ordering::SeqCst);
         *SELECTED_PRESET.lock().unwrap() = None;
 
-        let (presets, ui_lang, is_dark) = {
+        let (presets, ui_lang, release_to_select) = {
             let app = APP.lock().unwrap();
-            let is_dark = match app.config.theme_mode {
-                crate::config::ThemeMode::Dark => true,
-                crate::config::ThemeMode::Light => false,
-                crate::config::ThemeMode::System => crate::gui::utils::is_system_in_dark_mode(),
-            };
             (
                 app.config.presets.clone(),
                 app.config.ui_language.clone(),
-                is_dark,
+                app.config.wheel_release_to_select,
             )
         };
+        let (is_dark, geometry) = wheel_style();
 
         // Generate themed CSS for injection
-        let themed_css = generate_css(is_dark);
+        let themed_css = generate_css(is_dark, geometry);
 
         let filtered: Vec<(usize, Preset)> = presets
             .iter()
@@ -205,12 +246,32 @@ pub fn show_preset_wheel(
             let _ = PostMessageW(Some(wheel_hwnd), WM_APP_SHOW, WPARAM(0), LPARAM(0));
         }
 
+        // Press-and-release: the keys still held from the trigger hotkey. Once they are all
+        // up, the hovered preset is picked; with nothing hovered the wheel waits for a click.
+        let mut trigger_keys = if release_to_select {
+            held_keys()
+        } else {
+            Vec::new()
+        };
+
         let mut msg = MSG::default();
         loop {
             let res = WHEEL_RESULT.load(Ordering::SeqCst);
             if res != -1 {
                 break;
             }
+            if !trigger_keys.is_empty() {
+                trigger_keys.retain(|&vk| is_key_down(vk));
+                let hovered = HOVERED_PRESET.load(Ordering::SeqCst);
+                if trigger_keys.is_empty() && hovered >= 0 {
+                    if !wheel_hwnd.is_invalid() {
+                        let _ = PostMessageW(Some(wheel_hwnd), WM_APP_HIDE, WPARAM(0), LPARAM(0));
+                    }
+                    *SELECTED_PRESET.lock().unwrap() = Some(hovered as usize);
+                    WHEEL_RESULT.store(hovered, Ordering::SeqCst);
+                    break;
+                }
+            }
             if PeekMessageW(&mut msg, None, 0, 0, PM_REMOVE).as_bool() {
                 let _ = TranslateMessage(&msg);
                 DispatchMessageW(&msg);
@@ -342,7 +403,10 @@ fn internal_create_window_loop() {
             };
             let builder = crate::overlay::html_components::font_manager::configure_webview(builder);
 
-            let template_html = get_wheel_template(true); // Default dark for warmup
+            // Theme is re-injected on every show; the geometry is baked into the page
+            let (is_dark, geometry) = wheel_style();
+            *BUILT_GEOMETRY.lock().unwrap() = Some(geometry);
+            let template_html = get_wheel_template(is_dark, geometry);
 
             builder
                 .with_transparent(true)
@@ -377,6 +441,9 @@ fn internal_create_window_loop() {
                         }
                         *SELECTED_PRESET.lock().unwrap() = None;
                         WHEEL_RESULT.store(-2, Ordering::SeqCst);
+                    } else if let Some(idx_str) = body.strip_prefix("hover:") {
+                        let idx = idx_str.parse::<i32>().unwrap_or(-1);
+                        HOVERED_PRESET.store(idx, Ordering::SeqCst);
                     } else if let Some(idx_str) = body.strip_prefix("select:") {
                         if let Ok(idx) = idx_str.parse::<usize>() {
                             let hwnd_val = WHEEL_HWND.load(Ordering::SeqCst);
@@ -567,6 +634,33 @@ unsafe extern "system" fn wheel_wnd_proc(
                 SWP_NOACTIVATE | SWP_NOSIZE,
             );
 
+            // Take keyboard focus so typing filters the presets (Esc still dismisses)
+            let _ = SetForegroundWindow(hwnd);
+            WHEEL_WEBVIEW.with(|wv| {
+                if let Some(webview) = wv.borrow().as_ref() {
+                    let _ = webview.focus();
+                    let _ = webview.evaluate_script("window.focusSearch();");
+                }
+            });
+
+            LRESULT(0)
+        }
+
+        WM_APP_RELOAD => {
+            // Not while open: the next settings change (or restart) picks it up
+            if !WHEEL_ACTIVE.load(Ordering::SeqCst) {
+                let (is_dark, geometry) = wheel_style();
+                WHEEL_WEBVIEW.with(|wv| {
+                    if let Some(webview) = wv.borrow().as_ref() {
+                        if webview
+                            .load_html(&get_wheel_template(is_dark, geometry))
+                            .is_ok()
+                        {
+                            *BUILT_GEOMETRY.lock().unwrap() = Some(geometry);
+                        }
+                    }
+                });
+            }
             LRESULT(0)
         }
 
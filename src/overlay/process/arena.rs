@@ -0,0 +1,258 @@
+//! Arena mode: the same input sent to two models at once, compared in one window.
+
+use crate::api::translate_text_streaming;
+use crate::config::{Config, ProcessingBlock, ResponseFormat};
+use crate::overlay::result::{
+    arena_view, create_result_window, get_chain_color, link_windows, update_window_text,
+    RefineContext, WindowType, WINDOW_STATES,
+};
+use crate::win_types::SendHwnd;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+use std::time::Instant;
+use windows::Win32::Foundation::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+use super::chain::resolve_block_prompt;
+use super::types::get_next_window_position;
+
+/// The finished comparison, for the blocks connected after the sides
+pub struct ArenaResult {
+    /// The comparison window, parent of the windows that follow it
+    pub hwnd: HWND,
+    pub rect: RECT,
+    /// Each side's answer; None when it failed or was stopped
+    pub answers: [Option<String>; 2],
+}
+
+/// Run two text blocks on `input_text` concurrently and stream both answers into
+/// one two-column result window. A failing side shows its error without affecting
+/// the other. Returns None when the chain was cancelled.
+pub fn run_arena(
+    sides: [ProcessingBlock; 2],
+    input_text: String,
+    base_rect: RECT,
+    config: Config,
+    parent_hwnd: Arc<Mutex<Option<SendHwnd>>>,
+    processing_indicator_hwnd: Option<SendHwnd>,
+    cancel_token: Arc<AtomicBool>,
+    preset_id: String,
+) -> Option<ArenaResult> {
+    // Twice the usual width so both columns stay readable
+    let work = crate::overlay::utils::work_area_for_rect(&base_rect);
+    let width = ((base_rect.right - base_rect.left) * 2).min(work.right - work.left);
+    let wide_rect = RECT {
+        right: base_rect.left + width,
+        ..base_rect
+    };
+    let my_rect = get_next_window_position(wide_rect);
+
+    let labels = sides.clone().map(|block| {
+        let model_id = block.effective_model_id();
+        match crate::model_config::get_model_by_id(&model_id) {
+            Some(m) => {
+                let name = match config.ui_language.as_str() {
+                    "vi" => m.name_vi,
+                    "ko" => m.name_ko,
+                    _ => m.name_en,
+                };
+                format!("{} ({})", name, m.provider)
+            }
+            None => model_id,
+        }
+    });
+
+    // Window thread: same setup as a chain step's result window
+    let (tx_hwnd, rx_hwnd) = std::sync::mpsc::channel();
+    let parent_clone = parent_hwnd.clone();
+    let cancel_token_thread = cancel_token.clone();
+    std::thread::spawn(move || {
+        let hwnd = create_result_window(
+            my_rect,
+            WindowType::Primary,
+            RefineContext::None,
+            String::new(),
+            String::new(),
+            true,
+            false,
+            String::new(),
+            get_chain_color(0),
+            "markdown",
+            String::new(),
+        );
+
+        {
+            let mut s = WINDOW_STATES.lock().unwrap();
+            if let Some(st) = s.get_mut(&(hwnd.0 as isize)) {
+                st.cancellation_token = Some(cancel_token_thread);
+            }
+        }
+        if let Ok(p_guard) = parent_clone.lock() {
+            if let Some(ph) = *p_guard {
+                link_windows(ph.0, hwnd);
+            }
+        }
+
+        unsafe {
+            let _ = ShowWindow(hwnd, SW_SHOW);
+        }
+        let _ = tx_hwnd.send(SendHwnd(hwnd));
+
+        unsafe {
+            let mut m = MSG::default();
+            while GetMessageW(&mut m, None, 0, 0).into() {
+                let _ = TranslateMessage(&m);
+                DispatchMessageW(&m);
+                if !IsWindow(Some(hwnd)).as_bool() {
+                    break;
+                }
+            }
+        }
+    });
+
+    let Ok(SendHwnd(hwnd)) = rx_hwnd.recv() else {
+        return None;
+    };
+
+    if let Some(h) = processing_indicator_hwnd {
+        unsafe {
+            let _ = PostMessageW(Some(h.0), WM_CLOSE, WPARAM(0), LPARAM(0));
+        }
+    }
+
+    // One stop signal for both sides: the stop button ends the whole comparison
+    let stop_signal = Arc::new(AtomicBool::new(false));
+    {
+        let mut s = WINDOW_STATES.lock().unwrap();
+        if let Some(st) = s.get_mut(&(hwnd.0 as isize)) {
+            st.stop_signal = Some(stop_signal.clone());
+            st.is_streaming_active = true;
+            st.source_text = input_text.clone();
+        }
+    }
+    arena_view::start_arena(hwnd, labels);
+    update_window_text(hwnd, &arena_view::window_text(hwnd));
+
+    let hwnd_key = hwnd.0 as isize;
+    let workers: Vec<_> = sides
+        .into_iter()
+        .enumerate()
+        .map(|(index, block)| {
+            let input_text = input_text.clone();
+            let config = config.clone();
+            let stop_signal = stop_signal.clone();
+            let preset_id = preset_id.clone();
+            std::thread::spawn(move || {
+                run_arena_side(
+                    index,
+                    &block,
+                    input_text,
+                    &config,
+                    hwnd_key,
+                    &stop_signal,
+                    &preset_id,
+                )
+            })
+        })
+        .collect();
+    let mut answers = [None, None];
+    for (answer, worker) in answers.iter_mut().zip(workers) {
+        *answer = worker.join().ok().flatten();
+    }
+
+    if cancel_token.load(Ordering::Relaxed) {
+        return None;
+    }
+    {
+        let mut s = WINDOW_STATES.lock().unwrap();
+        if let Some(st) = s.get_mut(&hwnd_key) {
+            st.is_streaming_active = false; // Both sides done, show buttons
+            st.font_cache_dirty = true;
+        }
+    }
+    update_window_text(hwnd, &arena_view::window_text(hwnd));
+    Some(ArenaResult {
+        hwnd,
+        rect: my_rect,
+        answers,
+    })
+}
+
+/// Stream one side's answer into its column. Returns the answer if it succeeded.
+fn run_arena_side(
+    index: usize,
+    block: &ProcessingBlock,
+    input_text: String,
+    config: &Config,
+    hwnd_key: isize,
+    stop_signal: &AtomicBool,
+    preset_id: &str,
+) -> Option<String> {
+    let hwnd = HWND(hwnd_key as *mut std::ffi::c_void);
+    let model_id = block.effective_model_id();
+    let model_conf = crate::model_config::get_model_by_id(&model_id);
+    let provider = model_conf
+        .clone()
+        .map(|m| m.provider)
+        .unwrap_or("groq".to_string());
+    let model_full_name = model_conf.map(|m| m.full_name).unwrap_or(model_id);
+
+    let started = Instant::now();
+    let mut accumulated = String::new();
+    let prompt =
+        super::prompt_vars::fill_block_variables(&resolve_block_prompt(block), &input_text);
+    let sent_chars = prompt.len() + input_text.len();
+    let res = translate_text_streaming(
+        config,
+        input_text,
+        prompt,
+        model_full_name.clone(),
+        provider.clone(),
+        block.streaming_enabled,
+        false,
+        &ResponseFormat::Text,
+        None,
+        &config.ui_language,
+        stop_signal,
+        |chunk| {
+            // Handle WIPE_SIGNAL - clear accumulator and use content after signal
+            if let Some(rest) = chunk.strip_prefix(crate::api::WIPE_SIGNAL) {
+                accumulated.clear();
+                accumulated.push_str(rest);
+            } else {
+                accumulated.push_str(chunk);
+            }
+            // The window may have left arena mode (refined) while this side streams
+            if arena_view::is_arena(hwnd) {
+                arena_view::update_column(hwnd, index, &accumulated);
+                update_window_text(hwnd, &arena_view::window_text(hwnd));
+            }
+        },
+    );
+    let elapsed_ms = started.elapsed().as_millis() as u64;
+
+    if let Ok(text) = &res {
+        crate::usage_history::record_request(&provider, preset_id, sent_chars + text.len());
+    }
+    let result = res
+        .map(|text| block.apply_output_regex(&text))
+        .map_err(|e| {
+            crate::overlay::utils::get_error_message(
+                &e.to_string(),
+                &config.ui_language,
+                Some(&model_full_name),
+            )
+        });
+    if let (Ok(text), true) = (&result, block.auto_copy) {
+        crate::overlay::utils::copy_to_clipboard(text, HWND::default());
+        crate::overlay::auto_copy_badge::show_auto_copy_badge_text(text);
+    }
+    let answer = result.as_ref().ok().cloned();
+    if arena_view::is_arena(hwnd) {
+        arena_view::finish_column(hwnd, index, result, elapsed_ms);
+        update_window_text(hwnd, &arena_view::window_text(hwnd));
+    }
+    answer
+}
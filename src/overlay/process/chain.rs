@@ -1,9 +1,9 @@
 use crate::api::{translate_image_streaming, translate_text_streaming};
-use crate::config::{Config, Preset, ProcessingBlock};
+use crate::config::{AutoCopyMode, AutoCopyTarget, Config, Preset, ProcessingBlock};
 use crate::gui::settings_ui::get_localized_preset_name;
 use crate::overlay::result::{
-    create_result_window, get_chain_color, link_windows, update_window_text, RefineContext,
-    WindowType, WINDOW_STATES,
+    create_result_window, get_chain_color, link_windows, update_window_text, ChainStep,
+    LanguageRerun, PronunciationRetry, RefineContext, WindowType, WINDOW_STATES,
 };
 use crate::overlay::text_input;
 use crate::win_types::SendHwnd;
@@ -14,9 +14,20 @@ use std::sync::{
 use windows::Win32::Foundation::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
 
+use super::image_upload::prepare_image_upload;
+use super::prompt_vars::{fill_block_variables, fill_chain_variables};
 use super::types::{get_next_window_position, reset_window_position_queue};
 use super::window::create_processing_window;
 
+/// Put between the existing clipboard text and an appended result
+const AUTO_COPY_APPEND_SEPARATOR: &str = "\n\n";
+/// How long a block waits for the connection to come back before giving up
+const OFFLINE_WAIT: std::time::Duration = std::time::Duration::from_secs(60);
+
+lazy_static::lazy_static! {
+    static ref CLIPBOARD_APPEND_LOCK: Mutex<()> = Mutex::new(());
+}
+
 // --- CORE PIPELINE LOGIC ---
 
 pub fn execute_chain_pipeline(
@@ -112,6 +123,314 @@ pub fn execute_chain_pipeline_with_token(
     );
 }
 
+/// The preset's blocks as this run sends them: each LLM prompt wrapped in the
+/// global prefix/suffix (unless the preset opts out), then the run-wide prompt
+/// variables filled in, so the prefix and suffix can use them too
+fn prepare_chain_blocks(
+    blocks: &[ProcessingBlock],
+    selected_text: &str,
+    config: &Config,
+    preset_id: &str,
+) -> Vec<ProcessingBlock> {
+    let skip_global = config
+        .presets
+        .iter()
+        .any(|p| p.id == preset_id && p.skip_global_prompt);
+    blocks
+        .iter()
+        .map(|block| {
+            let prompt = if skip_global {
+                block.prompt.clone()
+            } else {
+                with_global_prompt(block, config)
+            };
+            ProcessingBlock {
+                prompt: fill_chain_variables(&prompt, selected_text),
+                ..block.clone()
+            }
+        })
+        .collect()
+}
+
+/// `global_system_prefix`, the block's prompt and `global_system_suffix`, one
+/// paragraph each. Blocks without a prompt or that don't call an LLM are left alone.
+fn with_global_prompt(block: &ProcessingBlock, config: &Config) -> String {
+    if block.prompt.trim().is_empty()
+        || block.is_input_adapter()
+        || crate::model_config::model_is_non_llm(&block.model)
+    {
+        return block.prompt.clone();
+    }
+    [
+        config.global_system_prefix.trim(),
+        block.prompt.as_str(),
+        config.global_system_suffix.trim(),
+    ]
+    .into_iter()
+    .filter(|part| !part.is_empty())
+    .collect::<Vec<_>>()
+    .join("\n\n")
+}
+
+/// With the privacy check on, ask whether text that looks like it holds personal
+/// data may be sent on. True when there is nothing to ask about.
+fn confirm_personal_data_send(config: &Config, text: &str) -> bool {
+    use windows::core::HSTRING;
+
+    if !config.privacy_scan_enabled {
+        return true;
+    }
+    let matched = crate::privacy::find_personal_data(text, &config.privacy_patterns);
+    if matched.is_empty() {
+        return true;
+    }
+    log::info!("Personal data check: {} pattern(s) matched", matched.len());
+
+    let locale = crate::gui::locale::LocaleText::get(&config.ui_language);
+    unsafe {
+        MessageBoxW(
+            None,
+            &HSTRING::from(locale.privacy_confirm_msg),
+            &HSTRING::from(locale.privacy_confirm_title),
+            MB_YESNO | MB_ICONWARNING | MB_TOPMOST | MB_SETFOREGROUND,
+        ) == IDYES
+    }
+}
+
+/// Fill the block's prompt template with its language variables
+pub(super) fn resolve_block_prompt(block: &ProcessingBlock) -> String {
+    let mut final_prompt = block.prompt.clone();
+    for (key, value) in &block.language_vars {
+        final_prompt = final_prompt.replace(&format!("{{{}}}", key), value);
+    }
+    // Fallback: if {language1} is still in prompt but not in language_vars, use selected_language
+    if final_prompt.contains("{language1}") && !block.language_vars.contains_key("language1") {
+        final_prompt = final_prompt.replace("{language1}", &block.selected_language);
+    }
+    final_prompt.replace("{language}", &block.selected_language)
+}
+
+/// A copy of `block` to run outside the chain walk (language re-run, arena side).
+/// Output regex only applies to the chain's final block, so a block with blocks
+/// after it loses its rules.
+pub(super) fn block_for_output(block: &ProcessingBlock, is_final_block: bool) -> ProcessingBlock {
+    let mut block = block.clone();
+    if !is_final_block {
+        block.output_regex.clear();
+    }
+    block
+}
+
+/// Progress of `block_idx` for chains with two or more processing blocks (input
+/// adapters don't count), with a localized name for what the block does
+fn chain_step(
+    blocks: &[ProcessingBlock],
+    block_idx: usize,
+    ui_language: &str,
+) -> Option<ChainStep> {
+    let processing: Vec<usize> = (0..blocks.len())
+        .filter(|&i| !blocks[i].is_input_adapter())
+        .collect();
+    let total = processing.len();
+    let step = processing.iter().position(|&i| i == block_idx)? + 1;
+    if total < 2 {
+        return None;
+    }
+
+    let block = &blocks[block_idx];
+    let target = block
+        .language_vars
+        .get("language1")
+        .filter(|l| !l.is_empty())
+        .unwrap_or(&block.selected_language);
+    let translates = block.prompt.contains("{language") && !target.is_empty();
+    let models = block.branches.len();
+    let name = match (ui_language, block.block_type.as_str()) {
+        ("vi", "image") => "Đang trích xuất văn bản".to_string(),
+        ("ko", "image") => "텍스트 추출 중".to_string(),
+        (_, "image") => "Extracting text".to_string(),
+        ("vi", "audio") => "Đang chép lời".to_string(),
+        ("ko", "audio") => "받아쓰는 중".to_string(),
+        (_, "audio") => "Transcribing".to_string(),
+        ("vi", "parallel") => format!("Đang hỏi {} mô hình", models),
+        ("ko", "parallel") => format!("{}개 모델에 질문 중", models),
+        (_, "parallel") => format!("Asking {} models", models),
+        ("vi", _) if translates => format!("Đang dịch sang {}", target),
+        ("ko", _) if translates => format!("{}(으)로 번역 중", target),
+        (_, _) if translates => format!("Translating to {}", target),
+        ("vi", _) => "Đang xử lý".to_string(),
+        ("ko", _) => "처리 중".to_string(),
+        (_, _) => "Processing".to_string(),
+    };
+    let prefix = match ui_language {
+        "vi" => "Bước",
+        "ko" => "단계",
+        _ => "Step",
+    };
+    Some(ChainStep {
+        step,
+        total,
+        status: format!("{} {}/{}: {}…", prefix, step, total, name),
+    })
+}
+
+/// Run a result window's block again with another target language on the same source,
+/// streaming the answer into the window. The old text stays on its undo stack.
+pub fn rerun_with_language(hwnd: HWND, language: &str) {
+    let hwnd_key = hwnd.0 as isize;
+    let stop_signal = Arc::new(AtomicBool::new(false));
+
+    let (block, image_data, source_text) = {
+        let mut states = WINDOW_STATES.lock().unwrap();
+        let Some(st) = states.get_mut(&hwnd_key) else {
+            return;
+        };
+        let Some(rerun) = st.language_rerun.as_mut() else {
+            return;
+        };
+        if st.is_refining || st.is_streaming_active {
+            return;
+        }
+
+        rerun.block.selected_language = language.to_string();
+        if let Some(var) = rerun.block.language_vars.get_mut("language1") {
+            *var = language.to_string();
+        }
+        let (block, image_data) = (rerun.block.clone(), rerun.image.clone());
+
+        let old_text = std::mem::take(&mut st.full_text);
+        if !old_text.is_empty() {
+            st.text_history.push(old_text);
+        }
+        st.redo_history.clear();
+        st.preset_prompt = resolve_block_prompt(&block);
+        st.input_text = st.source_text.clone();
+        st.is_refining = true;
+        st.is_streaming_active = true;
+        st.font_cache_dirty = true;
+        st.pending_text = Some(String::new());
+        st.stop_signal = Some(stop_signal.clone());
+        (block, image_data, st.source_text.clone())
+    };
+
+    std::thread::spawn(move || {
+        let hwnd = HWND(hwnd_key as *mut std::ffi::c_void);
+        let config = crate::APP.lock().unwrap().config.clone();
+        let model_id = block.effective_model_id();
+        let model_conf = crate::model_config::get_model_by_id(&model_id);
+        let provider = model_conf
+            .clone()
+            .map(|m| m.provider)
+            .unwrap_or("groq".to_string());
+        let model_full_name = model_conf.map(|m| m.full_name).unwrap_or(model_id);
+        // Markdown + streaming doesn't work properly, same as in run_chain_step
+        let streaming = block.render_mode != "markdown"
+            && !block.response_format.is_json()
+            && block.streaming_enabled;
+        let mut prompt = fill_block_variables(&resolve_block_prompt(&block), &source_text);
+        if block.response_format.is_json() {
+            prompt = super::structured::json_prompt(&block, &prompt);
+        }
+        let prompt_len = prompt.len() + source_text.len();
+
+        let mut acc = String::new();
+        let on_chunk = |chunk: &str| {
+            if let Some(rest) = chunk.strip_prefix(crate::api::WIPE_SIGNAL) {
+                acc.clear();
+                acc.push_str(rest);
+            } else {
+                acc.push_str(chunk);
+            }
+            if let Some(st) = WINDOW_STATES.lock().unwrap().get_mut(&hwnd_key) {
+                st.is_refining = false;
+            }
+            update_window_text(hwnd, &acc);
+        };
+
+        let res = match image_data.map(|d| prepare_image_upload(d, &block.image_upload)) {
+            Some(img_data) => match image::load_from_memory(&img_data) {
+                Ok(img) => translate_image_streaming(
+                    &config,
+                    prompt,
+                    model_full_name.clone(),
+                    provider,
+                    img.to_rgba8(),
+                    Some(img_data),
+                    streaming,
+                    false,
+                    &block.response_format,
+                    &stop_signal,
+                    on_chunk,
+                ),
+                Err(e) => Err(e.into()),
+            },
+            None => translate_text_streaming(
+                &config,
+                source_text,
+                prompt,
+                model_full_name.clone(),
+                provider,
+                streaming,
+                false,
+                &block.response_format,
+                None,
+                &config.ui_language,
+                &stop_signal,
+                on_chunk,
+            ),
+        };
+
+        if let Some(st) = WINDOW_STATES.lock().unwrap().get_mut(&hwnd_key) {
+            st.is_refining = false;
+            st.is_streaming_active = false;
+            st.font_cache_dirty = true;
+        }
+        let succeeded = res.is_ok();
+        let text = match res {
+            Ok(txt) => {
+                crate::usage_history::record_request(&provider, "", prompt_len + txt.len());
+                let txt = block.apply_output_regex(&txt);
+                if block.response_format.is_json() {
+                    super::structured::finish(&block, &txt, &config.ui_language).1
+                } else {
+                    txt
+                }
+            }
+            Err(e) => crate::overlay::utils::get_error_message(
+                &e.to_string(),
+                &config.ui_language,
+                Some(&model_full_name),
+            ),
+        };
+        update_window_text(hwnd, &text);
+        if succeeded {
+            crate::overlay::result::recent::record(hwnd, &text);
+        }
+    });
+}
+
+/// The blocks that receive `block_idx`'s output: the next block for a legacy linear
+/// chain (no connections), otherwise only the explicit graph connections
+fn next_block_indices(
+    block_idx: usize,
+    block_count: usize,
+    connections: &[(usize, usize)],
+) -> Vec<usize> {
+    if connections.is_empty() {
+        if block_idx + 1 < block_count {
+            vec![block_idx + 1]
+        } else {
+            vec![]
+        }
+    } else {
+        connections
+            .iter()
+            .filter(|(from, _)| *from == block_idx)
+            .map(|(_, to)| *to)
+            .collect()
+    }
+}
+
 /// Recursive step to run a block in the chain (now supports graph with connections)
 pub fn run_chain_step(
     block_idx: usize,
@@ -137,6 +456,20 @@ pub fn run_chain_step(
         return;
     }
 
+    // A new run of the preset cancels one still going; this step counts as busy until it returns
+    if block_idx == 0 {
+        super::running::start(&preset_id, &cancel_token);
+    }
+    let _step = super::running::enter_step(&preset_id, &cancel_token);
+
+    // Prompts get their run-wide parts once, when the chain starts (every entry point
+    // starts at block 0 with the text the preset was run on)
+    let blocks = if block_idx == 0 {
+        prepare_chain_blocks(&blocks, &input_text, &config, &preset_id)
+    } else {
+        blocks
+    };
+
     if block_idx >= blocks.len() {
         // End of chain. If processing overlay is still active (e.g., all blocks were hidden), close it now.
         if let Some(h) = processing_indicator_hwnd {
@@ -148,9 +481,39 @@ pub fn run_chain_step(
     }
 
     let block = &blocks[block_idx];
+    let is_final_block = next_block_indices(block_idx, blocks.len(), &connections).is_empty();
+
+    // A block whose condition isn't met is skipped: its input goes on to the next blocks
+    // as is. Only text blocks get their text from a previous block; the first block and
+    // image/audio blocks work on the capture, so there's no text to judge yet.
+    let receives_text = block_idx > 0 && block.block_type == "text";
+    if receives_text && !skip_execution && !block.condition.is_met(&input_text) {
+        log::info!(
+            "Skipping block {} ({}): condition {:?} not met",
+            block_idx,
+            block.block_type,
+            block.condition
+        );
+        let next_blocks = next_block_indices(block_idx, blocks.len(), &connections);
+        run_next_blocks(
+            next_blocks,
+            input_text,
+            current_rect,
+            blocks,
+            connections,
+            config,
+            parent_hwnd,
+            context,
+            false,
+            processing_indicator_hwnd,
+            cancel_token,
+            preset_id,
+        );
+        return;
+    }
 
-    // 1. Resolve Model & Prompt
-    let model_id = block.model.clone();
+    // 1. Resolve Model & Prompt (per-block provider/model overrides win over the global choice)
+    let model_id = block.effective_model_id();
     let model_conf = crate::model_config::get_model_by_id(&model_id);
     let provider = model_conf
         .clone()
@@ -158,15 +521,10 @@ pub fn run_chain_step(
         .unwrap_or("groq".to_string());
     let model_full_name = model_conf.map(|m| m.full_name).unwrap_or(model_id.clone());
 
-    let mut final_prompt = block.prompt.clone();
-    for (key, value) in &block.language_vars {
-        final_prompt = final_prompt.replace(&format!("{{{}}}", key), value);
-    }
-    // Fallback: if {language1} is still in prompt but not in language_vars, use selected_language
-    if final_prompt.contains("{language1}") && !block.language_vars.contains_key("language1") {
-        final_prompt = final_prompt.replace("{language1}", &block.selected_language);
+    let mut final_prompt = fill_block_variables(&resolve_block_prompt(block), &input_text);
+    if block.response_format.is_json() {
+        final_prompt = super::structured::json_prompt(block, &final_prompt);
     }
-    final_prompt = final_prompt.replace("{language}", &block.selected_language);
 
     // 2. Determine Visibility & Position
     let visible_count_before = blocks
@@ -204,12 +562,17 @@ pub fn run_chain_step(
         let prompt_c = final_prompt.clone();
         // CRITICAL: Override streaming to false if render_mode is markdown
         // Markdown + streaming doesn't work properly (causes missing content)
-        let stream_en = if block.render_mode == "markdown" {
+        // JSON answers are only shown once complete, as a (markdown) table
+        let stream_en = if block.render_mode == "markdown" || block.response_format.is_json() {
             false
         } else {
             block.streaming_enabled
         };
-        let render_md = block.render_mode.clone();
+        let render_md = if block.response_format.is_json() {
+            "markdown".to_string()
+        } else {
+            block.render_mode.clone()
+        };
 
         let parent_clone = parent_hwnd.clone();
         let (tx_hwnd, rx_hwnd) = std::sync::mpsc::channel();
@@ -222,6 +585,8 @@ pub fn run_chain_step(
             block.block_type == "input_adapter" && matches!(context, RefineContext::Image(_));
 
         let locale = crate::gui::locale::LocaleText::get(&config.ui_language);
+        // Only the image and audio input pages below embed the font
+        let font_css = crate::overlay::html_components::font_manager::get_font_css;
 
         // Generate initial content (HTML/Text) for the window immediately
         // This decouples content generation from window display loop
@@ -244,7 +609,7 @@ pub fn run_chain_step(
                         r#"<!DOCTYPE html>
 <html>
 <head>
-<link rel="stylesheet" href="https://fonts.googleapis.com/css2?family=Google+Sans+Flex:wght@400;500&display=swap">
+<style>{}</style>
 <style>
 * {{ margin: 0; padding: 0; box-sizing: border-box; }}
 body {{ 
@@ -347,7 +712,10 @@ slider.oninput = function() {{
 </script>
 </body>
 </html>"#,
-                        mime_type, base64_img, locale.opacity_label
+                        font_css(),
+                        mime_type,
+                        base64_img,
+                        locale.opacity_label
                     )
                 }
                 RefineContext::Audio(wav_data) => {
@@ -357,7 +725,7 @@ slider.oninput = function() {{
                         r#"<!DOCTYPE html>
 <html>
 <head>
-<link rel="stylesheet" href="https://fonts.googleapis.com/css2?family=Google+Sans+Flex:wght@400;500&display=swap">
+<style>{}</style>
 <style>
 * {{ margin: 0; padding: 0; box-sizing: border-box; }}
 body {{ 
@@ -654,6 +1022,7 @@ progressBar.onclick = (e) => {{
 </script>
 </body>
 </html>"#,
+                        font_css(),
                         locale.downloaded_successfully,
                         locale.download_recording_tooltip,
                         base64_audio
@@ -739,11 +1108,38 @@ progressBar.onclick = (e) => {{
             my_hwnd = rx_hwnd.recv().ok().map(|h| h.0);
         }
 
+        // Blocks that translate into a target language get the language switcher
+        let language_rerun = if !block.prompt.contains("{language") {
+            None
+        } else if block.block_type == "text" {
+            Some(LanguageRerun {
+                block: block_for_output(block, is_final_block),
+                image: None,
+            })
+        } else if let (true, RefineContext::Image(img_data)) =
+            (block.block_type == "image", &context)
+        {
+            Some(LanguageRerun {
+                block: block_for_output(block, is_final_block),
+                image: Some(img_data.clone()),
+            })
+        } else {
+            None
+        };
+
+        let auto_dismiss_secs = config
+            .presets
+            .iter()
+            .find(|p| p.id == preset_id)
+            .and_then(|p| p.result_auto_dismiss_secs);
+
         // Associate cancellation token with this window so destruction stops the chain
         if let Some(h) = my_hwnd {
             let mut s = WINDOW_STATES.lock().unwrap();
             if let Some(st) = s.get_mut(&(h.0 as isize)) {
                 st.cancellation_token = Some(cancel_token.clone());
+                st.language_rerun = language_rerun;
+                st.auto_dismiss_secs = auto_dismiss_secs;
             }
         }
 
@@ -765,6 +1161,8 @@ progressBar.onclick = (e) => {{
                 let mut s = WINDOW_STATES.lock().unwrap();
                 if let Some(st) = s.get_mut(&(my_hwnd.unwrap().0 as isize)) {
                     st.input_text = input_text.clone();
+                    st.source_text = input_text.clone();
+                    st.chain_step = chain_step(&blocks, block_idx, &config.ui_language);
                     st.is_refining = true;
                     st.is_streaming_active = true; // Hide buttons during streaming
                     st.font_cache_dirty = true;
@@ -773,6 +1171,7 @@ progressBar.onclick = (e) => {{
                 // Image block: also set streaming active to hide buttons
                 let mut s = WINDOW_STATES.lock().unwrap();
                 if let Some(st) = s.get_mut(&(my_hwnd.unwrap().0 as isize)) {
+                    st.chain_step = chain_step(&blocks, block_idx, &config.ui_language);
                     st.is_streaming_active = true; // Hide buttons during streaming
                 }
             }
@@ -796,30 +1195,64 @@ progressBar.onclick = (e) => {{
         // It will be passed to the next block.
     }
 
+    // Set by the result window's stop button: the request stops reading and keeps the partial text
+    let stop_signal = Arc::new(AtomicBool::new(false));
+    if let Some(h) = my_hwnd {
+        let mut s = WINDOW_STATES.lock().unwrap();
+        if let Some(st) = s.get_mut(&(h.0 as isize)) {
+            st.stop_signal = Some(stop_signal.clone());
+        }
+    }
+
     // 4. Execution (API Call)
     // 4. Execution (API Call)
     let input_text_for_history = input_text.clone();
+    // Provider/model that produced the result, recorded with the history entry
+    let mut answered_by = (provider.clone(), model_full_name.clone());
+    // Phrase a pronunciation practice recording is scored against
+    let pronunciation_target = if skip_execution && block.block_type == "audio" {
+        super::pronunciation::take_target(&preset_id)
+    } else {
+        None
+    };
     let result_text = if block.block_type == "input_adapter" {
         // Pass-through: return input as-is immediately
         input_text.clone()
     } else if skip_execution {
         if let Some(h) = my_hwnd {
-            update_window_text(h, &input_text);
+            match &pronunciation_target {
+                Some(target) => {
+                    let shown =
+                        super::pronunciation::feedback(target, &input_text, &config.ui_language);
+                    update_window_text(h, &shown);
+                    let mut s = WINDOW_STATES.lock().unwrap();
+                    if let Some(st) = s.get_mut(&(h.0 as isize)) {
+                        st.pronunciation_retry = Some(PronunciationRetry {
+                            preset_id: preset_id.clone(),
+                            target: target.clone(),
+                        });
+                    }
+                }
+                None => update_window_text(h, &input_text),
+            }
         }
         input_text
     } else {
-        let groq_key = config.api_key.clone();
-        let gemini_key = config.gemini_api_key.clone();
         // Use JSON format for single-block image extraction (helps with structured output)
-        let use_json = block_idx == 0 && blocks.len() == 1 && blocks[0].block_type == "image";
+        // Blocks with their own JSON format keep the whole answer
+        let use_json = block_idx == 0
+            && blocks.len() == 1
+            && blocks[0].block_type == "image"
+            && !block.response_format.is_json();
 
         // CRITICAL: Override streaming to false if render_mode is markdown
         // Markdown + streaming doesn't work properly (causes missing content)
-        let actual_streaming_enabled = if block.render_mode == "markdown" {
-            false
-        } else {
-            block.streaming_enabled
-        };
+        let actual_streaming_enabled =
+            if block.render_mode == "markdown" || block.response_format.is_json() {
+                false
+            } else {
+                block.streaming_enabled
+            };
 
         let accumulated = Arc::new(Mutex::new(String::new()));
         let acc_clone = accumulated.clone();
@@ -848,17 +1281,69 @@ progressBar.onclick = (e) => {{
         let processing_hwnd_shared = Arc::new(Mutex::new(processing_indicator_hwnd));
         let processing_hwnd_clone = processing_hwnd_shared.clone();
 
+        // For image blocks the window may still be hidden behind the processing indicator;
+        // messages (errors, offline notice) need it shown
+        let reveal_window = |h: HWND| {
+            let mut shown = window_shown.lock().unwrap();
+            if !*shown {
+                *shown = true;
+                unsafe {
+                    let _ = ShowWindow(h, SW_SHOW);
+                }
+                // Also close the processing indicator
+                let mut proc_hwnd = processing_hwnd_shared.lock().unwrap();
+                if let Some(ph) = proc_hwnd.take() {
+                    unsafe {
+                        let _ = PostMessageW(Some(ph.0), WM_CLOSE, WPARAM(0), LPARAM(0));
+                    }
+                }
+            }
+        };
+        let set_refining = |h: HWND, refining: bool| {
+            let mut s = WINDOW_STATES.lock().unwrap();
+            if let Some(st) = s.get_mut(&(h.0 as isize)) {
+                st.is_refining = refining;
+            }
+        };
+
         // RETRY LOOP
         let res = loop {
             // Update model_name_for_error to current attempt
             model_name_for_error = current_model_full_name.clone();
 
+            // Offline: say so instead of spinning, and carry on if the connection returns
+            if !crate::api::client::is_online(&current_provider) {
+                if let Some(h) = my_hwnd {
+                    reveal_window(h);
+                    set_refining(h, false);
+                    update_window_text(
+                        h,
+                        &crate::overlay::utils::get_offline_message(
+                            &config.ui_language,
+                            config.use_ollama,
+                        ),
+                    );
+                }
+                if !crate::api::client::wait_until_online(
+                    &current_provider,
+                    &cancel_token,
+                    OFFLINE_WAIT,
+                ) {
+                    break Err(anyhow::anyhow!(crate::api::client::NO_INTERNET));
+                }
+                if let Some(h) = my_hwnd {
+                    set_refining(h, true);
+                    update_window_text(h, "");
+                }
+            }
+
             let res_inner = if is_first_processing_block
                 && block.block_type == "image"
                 && matches!(context, RefineContext::Image(_))
             {
                 // Image Block (first processing block in chain)
                 if let RefineContext::Image(img_data) = context.clone() {
+                    let img_data = prepare_image_upload(img_data, &block.image_upload);
                     let img = image::load_from_memory(&img_data)
                         .expect("Failed to load png")
                         .to_rgba8();
@@ -876,8 +1361,7 @@ progressBar.onclick = (e) => {{
                     }
 
                     translate_image_streaming(
-                        &groq_key,
-                        &gemini_key,
+                        &config,
                         final_prompt.clone(),
                         current_model_full_name.clone(),
                         current_provider.clone(),
@@ -885,6 +1369,8 @@ progressBar.onclick = (e) => {{
                         Some(img_data),
                         actual_streaming_enabled,
                         use_json,
+                        &block.response_format,
+                        &stop_signal,
                         move |chunk| {
                             let now = std::time::SystemTime::now()
                                 .duration_since(std::time::UNIX_EPOCH)
@@ -946,6 +1432,22 @@ progressBar.onclick = (e) => {{
                 } else {
                     Err(anyhow::anyhow!("Missing image context"))
                 }
+            } else if block.is_parallel() {
+                // Parallel Block: every branch at once, sections shown as they finish
+                super::parallel::run_parallel(
+                    block,
+                    &input_text,
+                    &final_prompt,
+                    &config,
+                    &stop_signal,
+                    &preset_id,
+                    |joined| {
+                        if let Some(h) = my_hwnd {
+                            set_refining(h, false);
+                            update_window_text(h, joined);
+                        }
+                    },
+                )
             } else {
                 // Text Block
                 // Compute search label for compound models
@@ -960,16 +1462,17 @@ progressBar.onclick = (e) => {{
 
                 let acc_clone_inner = acc_clone.clone();
                 translate_text_streaming(
-                    &groq_key,
-                    &gemini_key,
+                    &config,
                     input_text.clone(),
                     final_prompt.clone(),
                     current_model_full_name.clone(),
                     current_provider.clone(),
                     actual_streaming_enabled,
                     false,
+                    &block.response_format,
                     search_label,
                     &config.ui_language,
+                    &stop_signal,
                     move |chunk| {
                         let now = std::time::SystemTime::now()
                             .duration_since(std::time::UNIX_EPOCH)
@@ -1011,7 +1514,11 @@ progressBar.onclick = (e) => {{
                 Ok(val) => break Ok(val),
                 Err(e) => {
                     // Check if retryable
+                    // A pinned model never falls back to another one
+                    // Parallel blocks already report each branch's own error
                     if retry_count < MAX_RETRIES
+                        && !block.is_model_pinned()
+                        && !block.is_parallel()
                         && crate::overlay::utils::is_retryable_error(&e.to_string())
                     {
                         retry_count += 1;
@@ -1029,6 +1536,7 @@ progressBar.onclick = (e) => {{
                             &current_model_id,
                             &failed_model_ids,
                             &current_type,
+                            block.provider_override.as_deref(),
                             &config,
                         ) {
                             current_model_id = next_model.id;
@@ -1070,38 +1578,50 @@ progressBar.onclick = (e) => {{
 
         match res {
             Ok(txt) => {
+                if block.is_parallel() {
+                    // Each branch counted its own request
+                    answered_by = super::parallel::answered_by(block);
+                } else {
+                    crate::usage_history::record_request(
+                        &current_provider,
+                        &preset_id,
+                        final_prompt.len() + input_text.len() + txt.len(),
+                    );
+                    answered_by = (current_provider.clone(), current_model_full_name.clone());
+                }
+                // Strip boilerplate from the chain's final output before it's shown or copied
+                let txt = if is_final_block {
+                    block.apply_output_regex(&txt)
+                } else {
+                    txt
+                };
+                // JSON answers are shown as a table; the JSON is what goes on
+                let (txt, shown) = if block.response_format.is_json() {
+                    super::structured::finish(block, &txt, &config.ui_language)
+                } else {
+                    (txt.clone(), txt)
+                };
                 if let Some(h) = my_hwnd {
-                    update_window_text(h, &txt);
+                    update_window_text(h, &shown);
+                    crate::overlay::result::recent::record(h, &shown);
                 }
                 txt
             }
             Err(e) => {
                 let lang = config.ui_language.clone();
-                let err = crate::overlay::utils::get_error_message(
-                    &e.to_string(),
-                    &lang,
-                    Some(&model_name_for_error),
-                );
+                let err = if e.to_string() == crate::api::client::NO_INTERNET {
+                    crate::overlay::utils::get_offline_message(&lang, config.use_ollama)
+                } else {
+                    crate::overlay::utils::get_error_message(
+                        &e.to_string(),
+                        &lang,
+                        Some(&model_name_for_error),
+                    )
+                };
                 if let Some(h) = my_hwnd {
                     // CRITICAL: For image blocks, the window may still be hidden if on_chunk was never called
                     // We must show it now to display the error message
-                    {
-                        let mut shown = window_shown.lock().unwrap();
-                        if !*shown {
-                            *shown = true;
-                            unsafe {
-                                let _ = ShowWindow(h, SW_SHOW);
-                            }
-                            // Also close the processing indicator
-                            let mut proc_hwnd = processing_hwnd_shared.lock().unwrap();
-                            if let Some(ph) = proc_hwnd.take() {
-                                unsafe {
-                                    let _ =
-                                        PostMessageW(Some(ph.0), WM_CLOSE, WPARAM(0), LPARAM(0));
-                                }
-                            }
-                        }
-                    }
+                    reveal_window(h);
                     update_window_text(h, &err);
                 }
                 String::new()
@@ -1115,9 +1635,53 @@ progressBar.onclick = (e) => {{
     // For input_adapter, we must check if we should copy the SOURCE (Image or Text)
     // result_text is input_text for adapters
     let is_input_adapter = block.block_type == "input_adapter";
-    let has_content = !result_text.trim().is_empty();
 
-    if block.auto_copy {
+    // What to copy is decided by the running preset (target + replace/append)
+    // The preset's post-processing command gets the final result before it's
+    // copied, pasted or saved; a failure is shown under the result, which is kept
+    let result_text = if is_final_block
+        && !is_input_adapter
+        && !skip_execution
+        && !result_text.trim().is_empty()
+        && !cancel_token.load(Ordering::Relaxed)
+    {
+        match super::post_command::run(&config, &preset_id, &result_text) {
+            Ok(Some(processed)) => {
+                if let Some(h) = my_hwnd {
+                    update_window_text(h, &processed);
+                }
+                processed
+            }
+            Ok(None) => result_text,
+            Err(err) => {
+                if let Some(h) = my_hwnd {
+                    update_window_text(h, &format!("{}\n\n{}", result_text.trim_end(), err));
+                }
+                result_text
+            }
+        }
+    } else {
+        result_text
+    };
+    let has_content = !result_text.trim().is_empty();
+    let (should_copy, copy_mode, copy_target) = config
+        .presets
+        .iter()
+        .find(|p| p.id == preset_id)
+        .map(|p| {
+            (
+                p.copies_block(block, is_final_block),
+                p.auto_copy_mode,
+                p.auto_copy_target,
+            )
+        })
+        .unwrap_or((
+            block.auto_copy,
+            AutoCopyMode::Replace,
+            AutoCopyTarget::PerBlock,
+        ));
+
+    if should_copy {
         // CASE 1: Image Input Adapter (Source Copy)
         // If this is an input adapter AND we have image context, copy the image.
         // We do this even if result_text (input_text) is empty, because image source has no text.
@@ -1143,7 +1707,19 @@ progressBar.onclick = (e) => {{
             // by text_selection.rs (the "b?? ??? d?" copy for processing)
             let should_show_badge = !is_input_adapter;
             std::thread::spawn(move || {
-                crate::overlay::utils::copy_to_clipboard(&txt_c, HWND::default());
+                if copy_mode == AutoCopyMode::Append {
+                    // Parallel branches may append at the same time: read-modify-write under a lock
+                    let _guard = CLIPBOARD_APPEND_LOCK.lock().unwrap();
+                    let existing = crate::overlay::utils::get_clipboard_text();
+                    let combined = if existing.trim().is_empty() {
+                        txt_c
+                    } else {
+                        format!("{}{}{}", existing, AUTO_COPY_APPEND_SEPARATOR, txt_c)
+                    };
+                    crate::overlay::utils::copy_to_clipboard(&combined, HWND::default());
+                } else {
+                    crate::overlay::utils::copy_to_clipboard(&txt_c, HWND::default());
+                }
                 // Show auto-copy badge notification with text snippet (skip for input_adapter)
                 if should_show_badge {
                     crate::overlay::auto_copy_badge::show_auto_copy_badge_text(&txt_for_badge);
@@ -1159,7 +1735,9 @@ progressBar.onclick = (e) => {{
         // 1. Non-input_adapter blocks with text content (actual processed results)
         // 2. Image copies from input_adapter (intentional image copy)
         // This prevents double-paste when input_adapter has auto_copy enabled alongside a processing block
-        let should_trigger_paste = (has_content && !is_input_adapter) || image_copied;
+        // With every block copying, only the end of the chain pastes (once per branch)
+        let should_trigger_paste = ((has_content && !is_input_adapter) || image_copied)
+            && (copy_target != AutoCopyTarget::EachBlock || is_final_block);
 
         if should_trigger_paste {
             // Re-clone for the paste thread
@@ -1170,7 +1748,7 @@ progressBar.onclick = (e) => {{
                 std::thread::sleep(std::time::Duration::from_millis(100));
 
                 // Get auto_paste settings from the RUNNING preset (by ID), not active_preset_idx
-                let (should_add_newline, should_paste, target_window) = {
+                let (should_add_newline, should_paste, should_type, target_window) = {
                     let app = crate::APP.lock().unwrap();
                     // Find the preset that's actually running this chain
                     if let Some(preset) =
@@ -1179,6 +1757,7 @@ progressBar.onclick = (e) => {{
                         (
                             preset.auto_paste_newline,
                             preset.auto_paste,
+                            preset.auto_paste_typing,
                             app.last_active_window,
                         )
                     } else {
@@ -1189,10 +1768,11 @@ progressBar.onclick = (e) => {{
                             (
                                 preset.auto_paste_newline,
                                 preset.auto_paste,
+                                preset.auto_paste_typing,
                                 app.last_active_window,
                             )
                         } else {
-                            (false, false, app.last_active_window)
+                            (false, false, false, app.last_active_window)
                         }
                     }
                 };
@@ -1240,7 +1820,20 @@ progressBar.onclick = (e) => {{
                             }
                         } else if let Some(target) = target_window {
                             // Normal paste to last active window
-                            crate::overlay::utils::force_focus_and_paste(target.0);
+                            let sent = if should_type {
+                                crate::overlay::utils::force_focus_and_type(target.0, &final_text)
+                            } else {
+                                crate::overlay::utils::force_focus_and_paste(target.0)
+                            };
+                            // Over a selection, this replaced it: keep what was there
+                            if sent {
+                                crate::overlay::text_selection::record_replacement(
+                                    &preset_id_clone,
+                                    &final_text,
+                                    target.0,
+                                    should_type,
+                                );
+                            }
                         }
                     }
                 }
@@ -1250,22 +1843,32 @@ progressBar.onclick = (e) => {{
 
     // Auto-Speak
     if block.auto_speak && !result_text.trim().is_empty() {
-        let txt_s = result_text.clone();
+        // Practice presets speak the phrase as it should sound
+        let txt_s = pronunciation_target.unwrap_or_else(|| result_text.clone());
+        let config = config.clone();
         std::thread::spawn(move || {
             std::thread::sleep(std::time::Duration::from_millis(200));
-            crate::api::tts::TTS_MANAGER.speak(&txt_s, 0);
+            crate::api::tts::TTS_MANAGER.speak(&txt_s, 0, &config);
         });
     }
 
     // SAVE TO HISTORY: Handle both Text and Image blocks
-    if block.show_overlay && !result_text.trim().is_empty() {
+    // A stopped or cancelled step only has part of its answer, which is not kept as a result
+    let interrupted = cancel_token.load(Ordering::Relaxed) || stop_signal.load(Ordering::Relaxed);
+    if block.show_overlay && !result_text.trim().is_empty() && !interrupted {
         let text_for_history = result_text.clone();
+        let source = crate::history::HistorySource {
+            preset_id: preset_id.clone(),
+            provider: answered_by.0,
+            model: answered_by.1,
+        };
 
-        if block.block_type == "text" {
+        if block.block_type == "text" || block.is_parallel() {
             let input_text_clone = input_text_for_history.clone();
             std::thread::spawn(move || {
                 if let Ok(app) = crate::APP.lock() {
-                    app.history.save_text(text_for_history, input_text_clone);
+                    app.history
+                        .save_text(text_for_history, input_text_clone, source);
                 }
             });
         } else if block.block_type == "image" {
@@ -1278,7 +1881,7 @@ progressBar.onclick = (e) => {{
                     if let Ok(img_dynamic) = image::load_from_memory(&img_bytes) {
                         let img_buffer = img_dynamic.to_rgba8();
                         if let Ok(app) = crate::APP.lock() {
-                            app.history.save_image(img_buffer, text_for_history);
+                            app.history.save_image(img_buffer, text_for_history, source);
                         }
                     }
                 });
@@ -1287,8 +1890,8 @@ progressBar.onclick = (e) => {{
     }
 
     // 6. Chain Next Steps (Graph-based: find all downstream blocks)
-    // Check cancellation before continuing
-    if cancel_token.load(Ordering::Relaxed) {
+    // Check cancellation (window closed or stop pressed) before continuing
+    if cancel_token.load(Ordering::Relaxed) || stop_signal.load(Ordering::Relaxed) {
         if let Some(h) = processing_indicator_hwnd {
             unsafe {
                 let _ = PostMessageW(Some(h.0), WM_CLOSE, WPARAM(0), LPARAM(0));
@@ -1302,27 +1905,7 @@ progressBar.onclick = (e) => {{
     let should_continue = !result_text.trim().is_empty() || block.block_type == "input_adapter";
 
     if should_continue {
-        // Find all downstream blocks from connections
-        let downstream_indices: Vec<usize> = connections
-            .iter()
-            .filter(|(from, _)| *from == block_idx)
-            .map(|(_, to)| *to)
-            .collect();
-
-        // Determine next blocks:
-        // - If connections vec is completely empty (legacy linear chain), use block_idx + 1 fallback
-        // - If connections vec has entries (graph mode), use ONLY explicit connections
-        let next_blocks: Vec<usize> = if connections.is_empty() {
-            // Legacy mode: no graph connections defined, use linear chain
-            if block_idx + 1 < blocks.len() {
-                vec![block_idx + 1]
-            } else {
-                vec![]
-            }
-        } else {
-            // Graph mode: use only explicit connections (no fallback)
-            downstream_indices
-        };
+        let next_blocks = next_block_indices(block_idx, blocks.len(), &connections);
 
         if next_blocks.is_empty() {
             // End of chain
@@ -1334,6 +1917,18 @@ progressBar.onclick = (e) => {{
             return;
         }
 
+        // Personal data in the first OCR result: ask before it reaches the next blocks
+        let is_first_ocr = block.block_type == "image"
+            && !blocks[..block_idx].iter().any(|b| b.block_type == "image");
+        if is_first_ocr && !skip_execution && !confirm_personal_data_send(&config, &result_text) {
+            if let Some(h) = processing_indicator_hwnd {
+                unsafe {
+                    let _ = PostMessageW(Some(h.0), WM_CLOSE, WPARAM(0), LPARAM(0));
+                }
+            }
+            return;
+        }
+
         let next_parent = if my_hwnd.is_some() {
             Arc::new(Mutex::new(my_hwnd.map(|h| SendHwnd(h))))
         } else {
@@ -1346,12 +1941,110 @@ progressBar.onclick = (e) => {{
             current_rect
         };
 
-        // For the first downstream block, pass the processing indicator (if any)
-        // For additional parallel branches, spawn new threads without the indicator
-        let first_next = next_blocks[0];
-        let parallel_branches: Vec<usize> = next_blocks.into_iter().skip(1).collect();
+        // Arena presets: the first two text branches share one side-by-side window;
+        // what's connected after a side runs on its answer once both sides are done
+        let is_arena = !skip_execution
+            && config
+                .presets
+                .iter()
+                .any(|p| p.id == preset_id && p.arena_mode);
+        let arena_sides: Vec<usize> = if is_arena {
+            next_blocks
+                .iter()
+                .copied()
+                .filter(|&i| blocks[i].block_type == "text")
+                .take(2)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let mut next_blocks = next_blocks;
+        if let [left, right] = arena_sides[..] {
+            next_blocks.retain(|i| *i != left && *i != right);
+            let side_block = |i: usize| {
+                let is_final = next_block_indices(i, blocks.len(), &connections).is_empty();
+                block_for_output(&blocks[i], is_final)
+            };
+            let sides = [side_block(left), side_block(right)];
+            let input_clone = result_text.clone();
+            let config_clone = config.clone();
+            let parent_clone = next_parent.clone();
+            let indicator = processing_indicator_hwnd.take();
+            let cancel_clone = cancel_token.clone();
+            let preset_clone = preset_id.clone();
+            let blocks_clone = blocks.clone();
+            let conns_clone = connections.clone();
+            let step = super::running::enter_step(&preset_id, &cancel_token);
+            std::thread::spawn(move || {
+                // CRITICAL: Initialize COM on this thread - required for WebView2
+                unsafe {
+                    use windows::Win32::System::Com::{CoInitializeEx, COINIT_APARTMENTTHREADED};
+                    let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+                }
+                let _step = step;
+                let Some(super::arena::ArenaResult {
+                    hwnd: arena_hwnd,
+                    rect: arena_rect,
+                    answers,
+                }) = super::arena::run_arena(
+                    sides,
+                    input_clone,
+                    base_rect,
+                    config_clone.clone(),
+                    parent_clone,
+                    indicator,
+                    cancel_clone.clone(),
+                    preset_clone.clone(),
+                )
+                else {
+                    return;
+                };
+
+                for (side, answer) in [left, right].into_iter().zip(answers) {
+                    let Some(answer) = answer else {
+                        continue; // A failed side has nothing to pass on
+                    };
+                    let downstream = next_block_indices(side, blocks_clone.len(), &conns_clone);
+                    if downstream.is_empty() {
+                        continue;
+                    }
+                    let blocks_clone = blocks_clone.clone();
+                    let conns_clone = conns_clone.clone();
+                    let config_clone = config_clone.clone();
+                    let cancel_clone = cancel_clone.clone();
+                    let preset_clone = preset_clone.clone();
+                    let arena_parent = Arc::new(Mutex::new(Some(SendHwnd(arena_hwnd))));
+                    let step = super::running::enter_step(&preset_clone, &cancel_clone);
+                    std::thread::spawn(move || {
+                        unsafe {
+                            use windows::Win32::System::Com::{
+                                CoInitializeEx, COINIT_APARTMENTTHREADED,
+                            };
+                            let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+                        }
+                        let _step = step;
+                        run_next_blocks(
+                            downstream,
+                            answer,
+                            arena_rect,
+                            blocks_clone,
+                            conns_clone,
+                            config_clone,
+                            arena_parent,
+                            RefineContext::None,
+                            false,
+                            None,
+                            cancel_clone,
+                            preset_clone,
+                        );
+                    });
+                }
+            });
+            if next_blocks.is_empty() {
+                return;
+            }
+        }
 
-        // Spawn parallel threads for additional branches FIRST
         let next_context = if block.block_type == "input_adapter" {
             context.clone()
         } else {
@@ -1366,70 +2059,18 @@ progressBar.onclick = (e) => {{
             false
         };
 
-        let _s_w = unsafe { GetSystemMetrics(SM_CXSCREEN) };
-        let _s_h = unsafe { GetSystemMetrics(SM_CYSCREEN) };
-
-        for (branch_index, next_idx) in parallel_branches.iter().enumerate() {
-            let result_clone = result_text.clone();
-            let blocks_clone = blocks.clone();
-            let conns_clone = connections.clone();
-            let config_clone = config.clone();
-            let cancel_clone = cancel_token.clone();
-            let parent_clone = next_parent.clone();
-            let preset_id_clone = preset_id.clone();
-            let next_idx_copy = *next_idx;
-
-            // Capture next_context for parallel branches
-            let branch_context = next_context.clone();
-
-            // Position will be determined individually by get_next_window_position inside run_chain_step
-            // We just pass the base_rect as a reference point
-            let branch_rect = base_rect;
-
-            // Incremental delay for each branch (300ms, 600ms, 900ms, ...)
-            // This naturally staggers WebView2 creation without blocking mutexes
-            let delay_ms = (branch_index as u64 + 1) * 300;
-
-            std::thread::spawn(move || {
-                // CRITICAL: Initialize COM on this thread - required for WebView2
-                unsafe {
-                    use windows::Win32::System::Com::{CoInitializeEx, COINIT_APARTMENTTHREADED};
-                    let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
-                }
-
-                // Stagger WebView2 creation across parallel branches
-                std::thread::sleep(std::time::Duration::from_millis(delay_ms));
-
-                run_chain_step(
-                    next_idx_copy,
-                    result_clone,
-                    branch_rect,
-                    blocks_clone,
-                    conns_clone,
-                    config_clone,
-                    parent_clone,
-                    branch_context, // Pass the captured context
-                    next_skip_execution,
-                    None, // No processing indicator for parallel branches
-                    cancel_clone,
-                    preset_id_clone,
-                );
-            });
-        }
-
-        // Continue with the first downstream block on current thread
-        run_chain_step(
-            first_next,
+        run_next_blocks(
+            next_blocks,
             result_text,
             base_rect,
             blocks.clone(),
             connections,
             config,
             next_parent,
-            next_context, // Pass the context
+            next_context,
             next_skip_execution,
-            processing_indicator_hwnd, // Pass it along (might be None or Some)
-            cancel_token,              // Pass the same token through the chain
+            processing_indicator_hwnd,
+            cancel_token,
             preset_id,
         );
     } else {
@@ -1442,3 +2083,93 @@ progressBar.onclick = (e) => {{
         }
     }
 }
+
+/// Hand `text` to the blocks that come next: the first one continues on this thread
+/// (with the processing indicator), any others run as parallel branches
+fn run_next_blocks(
+    next_blocks: Vec<usize>,
+    text: String,
+    base_rect: RECT,
+    blocks: Vec<ProcessingBlock>,
+    connections: Vec<(usize, usize)>,
+    config: Config,
+    parent_hwnd: Arc<Mutex<Option<SendHwnd>>>,
+    context: RefineContext,
+    skip_execution: bool,
+    processing_indicator_hwnd: Option<SendHwnd>,
+    cancel_token: Arc<AtomicBool>,
+    preset_id: String,
+) {
+    let Some((&first_next, parallel_branches)) = next_blocks.split_first() else {
+        // End of chain
+        if let Some(h) = processing_indicator_hwnd {
+            unsafe {
+                let _ = PostMessageW(Some(h.0), WM_CLOSE, WPARAM(0), LPARAM(0));
+            }
+        }
+        return;
+    };
+
+    // Spawn parallel threads for additional branches FIRST
+    for (branch_index, &next_idx) in parallel_branches.iter().enumerate() {
+        let text_clone = text.clone();
+        let blocks_clone = blocks.clone();
+        let conns_clone = connections.clone();
+        let config_clone = config.clone();
+        let cancel_clone = cancel_token.clone();
+        let parent_clone = parent_hwnd.clone();
+        let preset_id_clone = preset_id.clone();
+        let branch_context = context.clone();
+
+        // Incremental delay for each branch (300ms, 600ms, 900ms, ...)
+        // This naturally staggers WebView2 creation without blocking mutexes
+        let delay_ms = (branch_index as u64 + 1) * 300;
+        // Busy from now, so a restart during the delay still cancels this branch
+        let step = super::running::enter_step(&preset_id, &cancel_token);
+
+        std::thread::spawn(move || {
+            // CRITICAL: Initialize COM on this thread - required for WebView2
+            unsafe {
+                use windows::Win32::System::Com::{CoInitializeEx, COINIT_APARTMENTTHREADED};
+                let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+            }
+
+            // Stagger WebView2 creation across parallel branches
+            std::thread::sleep(std::time::Duration::from_millis(delay_ms));
+            let _step = step;
+
+            // Position is determined individually by get_next_window_position inside
+            // run_chain_step; base_rect is only the reference point
+            run_chain_step(
+                next_idx,
+                text_clone,
+                base_rect,
+                blocks_clone,
+                conns_clone,
+                config_clone,
+                parent_clone,
+                branch_context,
+                skip_execution,
+                None, // No processing indicator for parallel branches
+                cancel_clone,
+                preset_id_clone,
+            );
+        });
+    }
+
+    // Continue with the first downstream block on current thread
+    run_chain_step(
+        first_next,
+        text,
+        base_rect,
+        blocks,
+        connections,
+        config,
+        parent_hwnd,
+        context,
+        skip_execution,
+        processing_indicator_hwnd, // Pass it along (might be None or Some)
+        cancel_token,              // Pass the same token through the chain
+        preset_id,
+    );
+}
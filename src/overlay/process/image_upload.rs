@@ -0,0 +1,64 @@
+//! Re-encoding of captured images per an image block's upload settings, so slow
+//! connections and size-limited APIs get a smaller payload.
+
+use std::io::Cursor;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::FilterType;
+
+use crate::config::{ImageUploadFormat, ImageUploadSettings};
+
+/// The capture (PNG bytes) encoded as `settings` ask. The original bytes are
+/// returned untouched for the default settings or if encoding fails.
+pub fn prepare_image_upload(png: Vec<u8>, settings: &ImageUploadSettings) -> Vec<u8> {
+    if settings.is_passthrough() {
+        return png;
+    }
+    match encode(&png, settings) {
+        Ok(encoded) => {
+            log::info!(
+                "Image upload re-encoded as {:?}: {} KB -> {} KB",
+                settings.format,
+                png.len() / 1024,
+                encoded.len() / 1024
+            );
+            encoded
+        }
+        Err(e) => {
+            log::warn!("Image re-encoding failed, sending the original: {}", e);
+            png
+        }
+    }
+}
+
+fn encode(png: &[u8], settings: &ImageUploadSettings) -> anyhow::Result<Vec<u8>> {
+    let mut img = image::load_from_memory(png)?;
+    let max = settings.max_dimension;
+    if max > 0 && (img.width() > max || img.height() > max) {
+        img = img.resize(max, max, FilterType::Lanczos3);
+    }
+
+    let mut out = Vec::new();
+    match settings.format {
+        ImageUploadFormat::Png => {
+            img.write_to(&mut Cursor::new(&mut out), image::ImageFormat::Png)?;
+        }
+        ImageUploadFormat::Jpeg => {
+            // JPEG has no alpha channel
+            let rgb = img.to_rgb8();
+            JpegEncoder::new_with_quality(&mut out, settings.quality.clamp(1, 100))
+                .encode_image(&rgb)?;
+        }
+        ImageUploadFormat::Webp => {
+            let rgba = img.to_rgba8();
+            WebPEncoder::new_lossless(&mut out).encode(
+                rgba.as_raw(),
+                rgba.width(),
+                rgba.height(),
+                image::ExtendedColorType::Rgba8,
+            )?;
+        }
+    }
+    Ok(out)
+}
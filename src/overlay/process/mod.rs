@@ -1,5 +1,13 @@
+pub mod arena;
 pub mod chain;
+pub mod image_upload;
+pub mod parallel;
 pub mod pipeline;
+pub mod post_command;
+pub mod prompt_vars;
+pub mod pronunciation;
+pub mod running;
+pub mod structured;
 pub mod types;
 pub mod window;
 
@@ -0,0 +1,186 @@
+//! Parallel blocks: one input and prompt sent to several models at once. When every
+//! branch has answered, either all outputs (one section per model) or the chosen
+//! branch's output goes on to the next block.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::mpsc;
+
+use crate::api::translate_text_streaming;
+use crate::config::{Config, ParallelBranch, ProcessingBlock, ResponseFormat};
+
+/// A branch as a text block, so per-branch provider overrides resolve like any block's
+fn branch_block(block: &ProcessingBlock, branch: &ParallelBranch) -> ProcessingBlock {
+    ProcessingBlock {
+        block_type: "text".to_string(),
+        model: branch.model.clone(),
+        provider_override: branch.provider_override.clone(),
+        model_override: None,
+        ..block.clone()
+    }
+}
+
+/// "Model name (provider)" heading for a branch's section
+fn branch_label(model_id: &str, ui_language: &str) -> String {
+    match crate::model_config::get_model_by_id(model_id) {
+        Some(m) => {
+            let name = match ui_language {
+                "vi" => m.name_vi,
+                "ko" => m.name_ko,
+                _ => m.name_en,
+            };
+            format!("{} ({})", name, m.provider)
+        }
+        None => model_id.to_string(),
+    }
+}
+
+/// Every branch's section in branch order; unfinished ones show "…"
+fn join_sections(labels: &[String], results: &[Option<Result<String, String>>]) -> String {
+    labels
+        .iter()
+        .zip(results)
+        .map(|(label, result)| {
+            let body = match result {
+                None => "…",
+                Some(Ok(text)) => text.as_str(),
+                Some(Err(err)) => err.as_str(),
+            };
+            format!("### {}\n\n{}", label, body.trim())
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Send `input_text` with `prompt` to every branch of a parallel block and wait for
+/// all of them. `on_progress` gets the joined sections each time a branch finishes.
+/// Fails only if the branch to pass on (or, for all outputs, every branch) failed.
+pub fn run_parallel(
+    block: &ProcessingBlock,
+    input_text: &str,
+    prompt: &str,
+    config: &Config,
+    stop_signal: &AtomicBool,
+    preset_id: &str,
+    mut on_progress: impl FnMut(&str),
+) -> anyhow::Result<String> {
+    if block.branches.is_empty() {
+        return Err(anyhow::anyhow!("Parallel block has no models"));
+    }
+    let branches: Vec<ProcessingBlock> = block
+        .branches
+        .iter()
+        .map(|branch| branch_block(block, branch))
+        .collect();
+    let labels: Vec<String> = branches
+        .iter()
+        .map(|b| branch_label(&b.effective_model_id(), &config.ui_language))
+        .collect();
+    let mut results: Vec<Option<Result<String, String>>> = vec![None; branches.len()];
+    on_progress(&join_sections(&labels, &results));
+
+    let (tx, rx) = mpsc::channel();
+    std::thread::scope(|scope| {
+        for (index, branch) in branches.iter().enumerate() {
+            let tx = tx.clone();
+            scope.spawn(move || {
+                let result = run_branch(branch, input_text, prompt, config, stop_signal, preset_id);
+                let _ = tx.send((index, result));
+            });
+        }
+        drop(tx);
+        for (index, result) in rx {
+            results[index] = Some(result);
+            on_progress(&join_sections(&labels, &results));
+        }
+    });
+
+    let results: Vec<Result<String, String>> = results
+        .into_iter()
+        .map(|r| r.unwrap_or_else(|| Err(String::new())))
+        .collect();
+    match block.parallel_pick {
+        Some(pick) => match results.into_iter().nth(pick) {
+            Some(Ok(text)) => Ok(text),
+            Some(Err(err)) => Err(anyhow::anyhow!(err)),
+            None => Err(anyhow::anyhow!("Parallel block has no model #{}", pick + 1)),
+        },
+        None => {
+            if results.iter().all(|r| r.is_err()) {
+                let err = results
+                    .into_iter()
+                    .find_map(|r| r.err())
+                    .unwrap_or_default();
+                return Err(anyhow::anyhow!(err));
+            }
+            let outputs: Vec<Option<Result<String, String>>> =
+                results.into_iter().map(Some).collect();
+            Ok(join_sections(&labels, &outputs))
+        }
+    }
+}
+
+/// Providers and models of a parallel block's branches, comma separated, for history
+pub fn answered_by(block: &ProcessingBlock) -> (String, String) {
+    let mut providers: Vec<String> = Vec::new();
+    let mut models: Vec<String> = Vec::new();
+    for branch in &block.branches {
+        let model_id = branch_block(block, branch).effective_model_id();
+        match crate::model_config::get_model_by_id(&model_id) {
+            Some(m) => {
+                if !providers.contains(&m.provider) {
+                    providers.push(m.provider);
+                }
+                models.push(m.full_name);
+            }
+            None => models.push(model_id),
+        }
+    }
+    (providers.join(", "), models.join(", "))
+}
+
+fn run_branch(
+    branch: &ProcessingBlock,
+    input_text: &str,
+    prompt: &str,
+    config: &Config,
+    stop_signal: &AtomicBool,
+    preset_id: &str,
+) -> Result<String, String> {
+    let model_id = branch.effective_model_id();
+    let model_conf = crate::model_config::get_model_by_id(&model_id);
+    let provider = model_conf
+        .clone()
+        .map(|m| m.provider)
+        .unwrap_or("groq".to_string());
+    let model_full_name = model_conf.map(|m| m.full_name).unwrap_or(model_id);
+
+    let res = translate_text_streaming(
+        &config,
+        input_text.to_string(),
+        prompt.to_string(),
+        model_full_name.clone(),
+        provider.clone(),
+        false,
+        false,
+        &ResponseFormat::Text,
+        None,
+        &config.ui_language,
+        stop_signal,
+        |_| {},
+    );
+    match res {
+        Ok(text) => {
+            crate::usage_history::record_request(
+                &provider,
+                preset_id,
+                prompt.len() + input_text.len() + text.len(),
+            );
+            Ok(text)
+        }
+        Err(e) => Err(crate::overlay::utils::get_error_message(
+            &e.to_string(),
+            &config.ui_language,
+            Some(&model_full_name),
+        )),
+    }
+}
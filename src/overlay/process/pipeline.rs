@@ -13,16 +13,45 @@ use super::chain::{execute_chain_pipeline, execute_chain_pipeline_with_token, ru
 use super::types::reset_window_position_queue;
 use super::window::create_processing_window;
 
+/// Preset whose next run uses its secondary language (hotkey pressed with Shift)
+static SECONDARY_LANGUAGE_RUN: Mutex<Option<String>> = Mutex::new(None);
+
+/// Set (or clear) the preset id whose next run uses its secondary language
+pub fn set_secondary_language_run(preset_id: Option<String>) {
+    *SECONDARY_LANGUAGE_RUN.lock().unwrap() = preset_id;
+}
+
+/// Switch `preset` to its secondary language if its hotkey was pressed with Shift,
+/// and show which language this run translates to
+pub fn apply_secondary_language_run(preset: &mut Preset) {
+    {
+        let mut pending = SECONDARY_LANGUAGE_RUN.lock().unwrap();
+        if pending.as_deref() != Some(preset.id.as_str()) {
+            return;
+        }
+        *pending = None;
+    }
+    if let Some(language) = preset.use_secondary_language() {
+        let ui_lang = crate::APP.lock().unwrap().config.ui_language.clone();
+        let locale = crate::gui::locale::LocaleText::get(&ui_lang);
+        crate::overlay::auto_copy_badge::show_notification(
+            &locale.secondary_language_notice.replace("{}", &language),
+        );
+    }
+}
+
 // --- ENTRY POINTS ---
 
 pub fn start_text_processing(
     initial_text_content: String,
     screen_rect: RECT,
     config: Config,
-    preset: Preset,
+    mut preset: Preset,
     localized_preset_name: String, // Already localized by caller
     cancel_hotkey_name: String,    // The actual hotkey name like "Ctrl+Shift+D"
 ) {
+    apply_secondary_language_run(&mut preset);
+
     if preset.text_input_mode == "type" {
         // Use blocks[0].prompt instead of legacy preset.prompt
         let first_block_prompt = preset
@@ -260,7 +289,7 @@ pub fn start_text_processing(
 }
 
 pub fn show_audio_result(
-    preset: Preset,
+    mut preset: Preset,
     transcription_text: String,
     wav_data: Vec<u8>, // Audio data for input overlay
     rect: RECT,
@@ -286,6 +315,14 @@ pub fn show_audio_result(
         None
     };
 
+    // A pronunciation score is shown even if the preset normally hides the transcription
+    if super::pronunciation::has_target(&preset.id) {
+        if let Some(first) = preset.blocks.first_mut() {
+            first.show_overlay = true;
+            first.render_mode = "markdown".to_string();
+        }
+    }
+
     // Reset position queue for new chain
     reset_window_position_queue();
 
@@ -309,8 +346,10 @@ pub fn start_processing_pipeline(
     cropped_img: ImageBuffer<Rgba<u8>, Vec<u8>>,
     screen_rect: RECT,
     config: Config,
-    preset: Preset,
+    mut preset: Preset,
 ) {
+    apply_secondary_language_run(&mut preset);
+
     // If dynamic prompt mode, use WebView-based text input
     if preset.prompt_mode == "dynamic" && !preset.blocks.is_empty() {
         // For dynamic mode, encode PNG first (user will type prompt)
@@ -482,8 +521,10 @@ pub fn start_processing_pipeline_parallel(
     rx: std::sync::mpsc::Receiver<Option<(ImageBuffer<Rgba<u8>, Vec<u8>>, Vec<u8>)>>,
     screen_rect: RECT,
     config: Config,
-    preset: Preset,
+    mut preset: Preset,
 ) {
+    apply_secondary_language_run(&mut preset);
+
     // Dynamic prompt mode optimization is complex due to text input dependency
     // Fallback to blocking wait for dynamic mode (usually user input is the bottleneck anyway)
     if preset.prompt_mode == "dynamic" {
@@ -0,0 +1,279 @@
+//! A preset's post-processing command: an external program that gets the final
+//! result on stdin and whose output replaces the result or is appended to it. Lets
+//! results flow into the user's own scripts (a notes file, custom cleanup) without
+//! building each integration in.
+//!
+//! A command only runs after the user allowed it on this PC. Approvals are kept in
+//! their own file, not in the config, so an imported backup or a shared config can't
+//! bring a pre-approved command along.
+
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
+use std::io::{Read, Write};
+use std::os::windows::io::AsRawHandle;
+use std::os::windows::process::CommandExt;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use windows::core::{HSTRING, PCWSTR};
+use windows::Win32::Foundation::{CloseHandle, HANDLE};
+use windows::Win32::System::JobObjects::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+use crate::config::{Config, PostCommandMode};
+use crate::gui::locale::LocaleText;
+
+/// The command is stopped after this long
+const TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Lines of the command's error output shown in the result window
+const MAX_ERROR_LINES: usize = 6;
+
+/// Run the preset's command on `result`. Returns the new result, None when the
+/// preset has no command (or the user didn't allow it), or an error message for the
+/// result window.
+pub fn run(config: &Config, preset_id: &str, result: &str) -> Result<Option<String>, String> {
+    let Some(preset) = config.presets.iter().find(|p| p.id == preset_id) else {
+        return Ok(None);
+    };
+    let command = preset.post_command.trim();
+    if command.is_empty() {
+        return Ok(None);
+    }
+    let locale = LocaleText::get(&config.ui_language);
+    if !is_approved(preset_id, command) && !confirm(&locale, &preset.name, preset_id, command) {
+        return Ok(None);
+    }
+
+    let output = execute(command, preset_id, result).map_err(|e| match e {
+        CommandError::TimedOut => {
+            log::warn!("Post-processing command of {} timed out", preset_id);
+            locale.post_command_timed_out.to_string()
+        }
+        CommandError::Failed(message) => {
+            log::warn!(
+                "Post-processing command of {} failed: {}",
+                preset_id,
+                message
+            );
+            format!("{} {}", locale.post_command_failed, message)
+        }
+    })?;
+
+    // A command that prints nothing only consumed the result (e.g. saved it somewhere)
+    let output = output.trim_end();
+    if output.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(match preset.post_command_mode {
+        PostCommandMode::Replace => output.to_string(),
+        PostCommandMode::Append => format!("{}\n\n{}", result.trim_end(), output),
+    }))
+}
+
+/// Ask before a preset's command runs for the first time (or after it was changed),
+/// and remember the answer when it's yes
+fn confirm(locale: &LocaleText, preset_name: &str, preset_id: &str, command: &str) -> bool {
+    let message = locale
+        .post_command_confirm_msg
+        .replacen("{}", preset_name, 1)
+        .replacen("{}", command, 1);
+    let allowed = unsafe {
+        MessageBoxW(
+            None,
+            &HSTRING::from(message),
+            &HSTRING::from(locale.post_command_confirm_title),
+            MB_YESNO | MB_ICONWARNING | MB_TOPMOST | MB_SETFOREGROUND,
+        ) == IDYES
+    };
+    if allowed {
+        remember_approval(preset_id, command);
+    }
+    allowed
+}
+
+/// Approved commands, loaded on first use
+static APPROVALS: Mutex<Option<BTreeSet<String>>> = Mutex::new(None);
+
+fn approvals_path() -> PathBuf {
+    let config_dir = dirs::config_dir()
+        .unwrap_or_default()
+        .join("screen-goated-toolbox");
+    let _ = std::fs::create_dir_all(&config_dir);
+    config_dir.join("post_command_approvals.json")
+}
+
+fn load_approvals() -> BTreeSet<String> {
+    std::fs::read_to_string(approvals_path())
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+/// What an approval is stored as: a hash of the preset and its exact command, so a
+/// changed command asks again
+fn approval_key(preset_id: &str, command: &str) -> String {
+    Sha256::digest(format!("{}\n{}", preset_id, command))
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn is_approved(preset_id: &str, command: &str) -> bool {
+    let Ok(mut guard) = APPROVALS.lock() else {
+        return false;
+    };
+    guard
+        .get_or_insert_with(load_approvals)
+        .contains(&approval_key(preset_id, command))
+}
+
+fn remember_approval(preset_id: &str, command: &str) {
+    let Ok(mut guard) = APPROVALS.lock() else {
+        return;
+    };
+    let approvals = guard.get_or_insert_with(load_approvals);
+    approvals.insert(approval_key(preset_id, command));
+    match serde_json::to_string(&*approvals) {
+        Ok(data) => {
+            if let Err(e) = std::fs::write(approvals_path(), data) {
+                log::warn!("Failed to save post-processing approvals: {}", e);
+            }
+        }
+        Err(e) => log::warn!("Failed to serialize post-processing approvals: {}", e),
+    }
+}
+
+enum CommandError {
+    TimedOut,
+    Failed(String),
+}
+
+/// A job object that ends every process in it when closed, so a timed-out command
+/// doesn't leave the programs it started running
+struct KillOnClose(HANDLE);
+
+impl KillOnClose {
+    fn new() -> Option<Self> {
+        unsafe {
+            let job = CreateJobObjectW(None, PCWSTR::null()).ok()?;
+            let mut limits = JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+            limits.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+            let set = SetInformationJobObject(
+                job,
+                JobObjectExtendedLimitInformation,
+                &limits as *const _ as *const std::ffi::c_void,
+                std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+            );
+            if set.is_err() {
+                let _ = CloseHandle(job);
+                return None;
+            }
+            Some(Self(job))
+        }
+    }
+
+    fn assign(&self, child: &std::process::Child) {
+        let process = HANDLE(child.as_raw_handle());
+        if let Err(e) = unsafe { AssignProcessToJobObject(self.0, process) } {
+            log::warn!("Could not put the post-processing command in a job: {}", e);
+        }
+    }
+}
+
+impl Drop for KillOnClose {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = CloseHandle(self.0);
+        }
+    }
+}
+
+/// Run `command` through cmd.exe (so pipes, quoting and scripts behave as in a
+/// console) with `input` on stdin, and return its stdout. Anything the command
+/// starts is ended with it.
+fn execute(command: &str, preset_id: &str, input: &str) -> Result<String, CommandError> {
+    let mut cmd = Command::new("cmd");
+    cmd.arg("/C")
+        .raw_arg(command)
+        .env("SGT_PRESET_ID", preset_id)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    // CREATE_NO_WINDOW = 0x08000000 - prevents console window flash
+    cmd.creation_flags(0x08000000);
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| CommandError::Failed(e.to_string()))?;
+    let job = KillOnClose::new();
+    if let Some(job) = &job {
+        job.assign(&child);
+    }
+
+    // Feed stdin and drain the outputs on their own threads, so a command that
+    // writes a lot before reading everything can't block on a full pipe
+    let mut stdin = child.stdin.take();
+    let input = input.as_bytes().to_vec();
+    std::thread::spawn(move || {
+        if let Some(stdin) = stdin.as_mut() {
+            let _ = stdin.write_all(&input);
+        }
+    });
+    let read_all = |pipe: Option<Box<dyn Read + Send>>| {
+        std::thread::spawn(move || {
+            let mut bytes = Vec::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut bytes);
+            }
+            String::from_utf8_lossy(&bytes).into_owned()
+        })
+    };
+    let stdout = read_all(
+        child
+            .stdout
+            .take()
+            .map(|p| Box::new(p) as Box<dyn Read + Send>),
+    );
+    let stderr = read_all(
+        child
+            .stderr
+            .take()
+            .map(|p| Box::new(p) as Box<dyn Read + Send>),
+    );
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if started.elapsed() >= TIMEOUT => {
+                drop(job); // Ends cmd.exe and everything it started
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(CommandError::TimedOut);
+            }
+            Ok(None) => std::thread::sleep(Duration::from_millis(50)),
+            Err(e) => return Err(CommandError::Failed(e.to_string())),
+        }
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    if !status.success() {
+        let details: Vec<&str> = stderr
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .take(MAX_ERROR_LINES)
+            .collect();
+        let code = status
+            .code()
+            .map(|c| format!("exit code {}", c))
+            .unwrap_or_else(|| "stopped".to_string());
+        return Err(CommandError::Failed(if details.is_empty() {
+            code
+        } else {
+            format!("{}\n{}", code, details.join("\n"))
+        }));
+    }
+    Ok(stdout)
+}
@@ -0,0 +1,66 @@
+//! Run-time variables in block prompts.
+//!
+//! `{clipboard}`, `{selected_text}` and `{date}` are filled once when a chain starts,
+//! `{source_lang}` and `{prev_block_output}` for each block from the text it receives.
+//! Any other `{name}` is left as written (the `{languageN}` tags are resolved from
+//! the block's language settings).
+
+use regex::Regex;
+
+/// Every variable with the English description shown in the block editor
+pub const PROMPT_VARIABLES: [(&str, &str); 5] = [
+    ("clipboard", "Clipboard text when the preset starts"),
+    ("selected_text", "The text the preset was started on"),
+    ("date", "Today's date (YYYY-MM-DD)"),
+    ("source_lang", "Detected language of the block's input"),
+    (
+        "prev_block_output",
+        "Output of the previous block (this block's input)",
+    ),
+];
+
+lazy_static::lazy_static! {
+    static ref PLACEHOLDER: Regex = Regex::new(r"\{([A-Za-z_][A-Za-z0-9_]*)\}").unwrap();
+}
+
+/// Fill the variables that stay the same for a whole chain run
+pub fn fill_chain_variables(prompt: &str, selected_text: &str) -> String {
+    let mut filled = prompt.replace("{selected_text}", selected_text);
+    filled = filled.replace(
+        "{date}",
+        &chrono::Local::now().format("%Y-%m-%d").to_string(),
+    );
+    if filled.contains("{clipboard}") {
+        filled = filled.replace("{clipboard}", &crate::overlay::utils::get_clipboard_text());
+    }
+    filled
+}
+
+/// Fill the variables that depend on the block's input
+pub fn fill_block_variables(prompt: &str, input_text: &str) -> String {
+    let mut filled = prompt.replace("{prev_block_output}", input_text);
+    if filled.contains("{source_lang}") {
+        let lang = whatlang::detect_lang(input_text)
+            .map(|l| l.eng_name())
+            .unwrap_or("Unknown");
+        filled = filled.replace("{source_lang}", lang);
+    }
+    filled
+}
+
+/// `{name}` placeholders that are neither a variable nor a language tag, for the
+/// editor's typo warning
+pub fn unknown_variables(prompt: &str) -> Vec<String> {
+    let mut unknown: Vec<String> = Vec::new();
+    for caps in PLACEHOLDER.captures_iter(prompt) {
+        let name = &caps[1];
+        let is_language = name
+            .strip_prefix("language")
+            .is_some_and(|n| n.chars().all(|c| c.is_ascii_digit()));
+        let is_known = is_language || PROMPT_VARIABLES.iter().any(|(v, _)| *v == name);
+        if !is_known && !unknown.iter().any(|u| u == name) {
+            unknown.push(name.to_string());
+        }
+    }
+    unknown
+}
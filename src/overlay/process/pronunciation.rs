@@ -0,0 +1,168 @@
+//! Pronunciation practice presets: the phrase on the clipboard when recording starts
+//! is the target, and the transcription is shown scored against it word by word
+//! (green for words heard as written, through yellow and orange, to red) instead of
+//! as plain text. The result window offers to record the same phrase again.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::api::pronunciation::{overall_score, score_words, WordScore};
+use crate::overlay::result::PronunciationRetry;
+
+/// Longest clipboard text taken as a phrase to practice; more is not a phrase
+const MAX_TARGET_CHARS: usize = 300;
+
+lazy_static::lazy_static! {
+    /// Target phrase of the recording in progress, per preset
+    static ref TARGETS: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
+    /// Phrase for the next recording of a preset, set by "record again"
+    static ref RETRY: Mutex<Option<PronunciationRetry>> = Mutex::new(None);
+}
+
+/// Set (or clear) the phrase the preset's next result is scored against
+pub fn arm(preset_id: &str, target: Option<String>) {
+    let mut targets = TARGETS.lock().unwrap();
+    match target {
+        Some(target) => targets.insert(preset_id.to_string(), target),
+        None => targets.remove(preset_id),
+    };
+}
+
+pub fn has_target(preset_id: &str) -> bool {
+    TARGETS.lock().unwrap().contains_key(preset_id)
+}
+
+pub fn take_target(preset_id: &str) -> Option<String> {
+    TARGETS.lock().unwrap().remove(preset_id)
+}
+
+/// The phrase "record again" asked for, if it was for this preset
+pub fn take_retry(preset_id: &str) -> Option<String> {
+    let mut retry = RETRY.lock().unwrap();
+    if retry.as_ref().is_some_and(|r| r.preset_id == preset_id) {
+        retry.take().map(|r| r.target)
+    } else {
+        None
+    }
+}
+
+/// The clipboard's text as a phrase to practice, if it is short enough to be one
+pub fn target_from_clipboard() -> Option<String> {
+    let text = crate::overlay::utils::get_clipboard_text();
+    let text = text.trim();
+    (!text.is_empty() && text.chars().count() <= MAX_TARGET_CHARS).then(|| text.to_string())
+}
+
+/// Record the same phrase again with the preset that scored it
+pub fn record_again(retry: PronunciationRetry) {
+    let preset_idx = crate::APP.lock().ok().and_then(|app| {
+        app.config
+            .presets
+            .iter()
+            .position(|p| p.id == retry.preset_id)
+    });
+    let Some(preset_idx) = preset_idx else {
+        return;
+    };
+    *RETRY.lock().unwrap() = Some(retry);
+    std::thread::spawn(move || {
+        crate::overlay::recording::show_recording_overlay(preset_idx);
+    });
+}
+
+fn score_color(score: u8) -> &'static str {
+    match score {
+        90.. => "#4caf50",
+        70.. => "#c0ca33",
+        50.. => "#ffa726",
+        _ => "#ef5350",
+    }
+}
+
+/// A word as literal text in markdown (it goes inside an HTML span)
+fn escape(word: &str) -> String {
+    let mut out = String::with_capacity(word.len());
+    for c in word.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '\\' | '*' | '_' | '`' | '[' | ']' | '#' | '~' | '|' => {
+                out.push('\\');
+                out.push(c);
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn colored_word(word: &WordScore) -> String {
+    match (&word.target, &word.heard) {
+        (Some(target), Some(_)) => format!(
+            "<span style=\"color:{}\">{}</span>",
+            score_color(word.score),
+            escape(target)
+        ),
+        (Some(target), None) => format!(
+            "<span style=\"color:#ef5350\"><s>{}</s></span>",
+            escape(target)
+        ),
+        (None, Some(heard)) => format!("<span style=\"color:#9e9e9e\">({})</span>", escape(heard)),
+        (None, None) => String::new(),
+    }
+}
+
+/// Markdown for the result window: the overall score, the target phrase colored word
+/// by word, what was heard, and the words to work on
+pub fn feedback(target: &str, heard: &str, ui_language: &str) -> String {
+    let (heard_label, practice_label, missed_label, perfect) = match ui_language {
+        "vi" => (
+            "Nghe được:",
+            "Cần luyện:",
+            "bị bỏ sót",
+            "Tất cả các từ đều chuẩn!",
+        ),
+        "ko" => (
+            "인식된 문장:",
+            "연습할 단어:",
+            "빠뜨림",
+            "모든 단어를 정확히 발음했습니다!",
+        ),
+        _ => ("Heard:", "Practice:", "left out", "Every word was spot on!"),
+    };
+    let words = score_words(target, heard);
+
+    let phrase: Vec<String> = words.iter().map(colored_word).collect();
+    let mut out = format!(
+        "## {}/100\n\n{}\n\n**{}** {}\n\n",
+        overall_score(&words),
+        phrase.join(" "),
+        heard_label,
+        escape(heard.trim())
+    );
+
+    let to_practice: Vec<String> = words
+        .iter()
+        .filter(|w| w.score < 90)
+        .filter_map(|w| {
+            let target = w.target.as_ref()?;
+            Some(match &w.heard {
+                Some(heard) => {
+                    format!("- **{}** → {} ({})", escape(target), escape(heard), w.score)
+                }
+                None => format!("- **{}** ({})", escape(target), missed_label),
+            })
+        })
+        .collect();
+    if to_practice.is_empty() {
+        out.push_str(perfect);
+    } else {
+        out.push_str(&format!(
+            "**{}**\n\n{}",
+            practice_label,
+            to_practice.join("\n")
+        ));
+    }
+    out
+}
@@ -0,0 +1,90 @@
+//! The chain each preset is running. Starting a preset again while its previous
+//! run is still going cancels that run: blocks that haven't started are dropped,
+//! the step still streaming stops and keeps what it has so far, and windows of
+//! finished steps stay open.
+
+use std::collections::HashMap;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+
+use crate::overlay::result::WINDOW_STATES;
+
+struct RunningChain {
+    token: Arc<AtomicBool>,
+    /// Steps of this run that are executing or about to (spawned branches)
+    active_steps: usize,
+}
+
+lazy_static::lazy_static! {
+    static ref RUNNING: Mutex<HashMap<String, RunningChain>> = Mutex::new(HashMap::new());
+}
+
+/// Register a new run of `preset_id`, cancelling its previous run if that is still busy
+pub fn start(preset_id: &str, token: &Arc<AtomicBool>) {
+    let previous = RUNNING.lock().unwrap().insert(
+        preset_id.to_string(),
+        RunningChain {
+            token: token.clone(),
+            active_steps: 0,
+        },
+    );
+    if let Some(previous) = previous {
+        if previous.active_steps > 0 && !Arc::ptr_eq(&previous.token, token) {
+            log::info!(
+                "Preset {} started again, cancelling its running chain",
+                preset_id
+            );
+            cancel(&previous.token);
+        }
+    }
+}
+
+/// Stop the chain behind `token`: no further blocks run, and windows still
+/// streaming stop reading (the same as their stop button)
+pub fn cancel(token: &Arc<AtomicBool>) {
+    token.store(true, Ordering::SeqCst);
+    let states = WINDOW_STATES.lock().unwrap();
+    for state in states.values() {
+        let same_chain = state
+            .cancellation_token
+            .as_ref()
+            .is_some_and(|t| Arc::ptr_eq(t, token));
+        if same_chain && state.is_streaming_active {
+            if let Some(ref stop) = state.stop_signal {
+                stop.store(true, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+/// Counts as a busy step of the run until dropped
+pub struct StepGuard {
+    preset_id: String,
+    token: Arc<AtomicBool>,
+}
+
+/// Mark a step of the run behind `token` as busy. Steps of a run that was already
+/// replaced by a newer one are not counted.
+pub fn enter_step(preset_id: &str, token: &Arc<AtomicBool>) -> StepGuard {
+    if let Some(chain) = RUNNING.lock().unwrap().get_mut(preset_id) {
+        if Arc::ptr_eq(&chain.token, token) {
+            chain.active_steps += 1;
+        }
+    }
+    StepGuard {
+        preset_id: preset_id.to_string(),
+        token: token.clone(),
+    }
+}
+
+impl Drop for StepGuard {
+    fn drop(&mut self) {
+        if let Some(chain) = RUNNING.lock().unwrap().get_mut(&self.preset_id) {
+            if Arc::ptr_eq(&chain.token, &self.token) {
+                chain.active_steps = chain.active_steps.saturating_sub(1);
+            }
+        }
+    }
+}
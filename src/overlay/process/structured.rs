@@ -0,0 +1,45 @@
+//! Blocks with a JSON response format: the prompt asks for JSON, and the answer is
+//! parsed leniently, checked against the block's schema and shown as a table while
+//! the JSON itself is what gets copied and passed on.
+
+use crate::api::structured::{json_instruction, parse_reply, validate};
+use crate::config::ProcessingBlock;
+use crate::overlay::result::table::Table;
+
+/// `prompt` with the request for JSON (and the block's schema) added
+pub fn json_prompt(block: &ProcessingBlock, prompt: &str) -> String {
+    let instruction = json_instruction(block.response_format.schema().as_ref());
+    format!("{}\n\n{}", prompt, instruction)
+}
+
+/// Split a JSON block's answer into `(output, shown)`: the pretty-printed JSON that is
+/// copied and passed on, and the window text - a table (or the JSON when it isn't
+/// table-shaped), after a warning if the answer is no JSON or misses the schema.
+pub fn finish(block: &ProcessingBlock, answer: &str, ui_language: &str) -> (String, String) {
+    let Some(value) = parse_reply(answer) else {
+        let warning = match ui_language {
+            "vi" => "⚠ Kết quả không phải JSON hợp lệ.",
+            "ko" => "⚠ 결과가 올바른 JSON이 아닙니다.",
+            _ => "⚠ The answer is not valid JSON.",
+        };
+        return (answer.to_string(), format!("> {}\n\n{}", warning, answer));
+    };
+    let json = serde_json::to_string_pretty(&value).unwrap_or_else(|_| answer.to_string());
+
+    let mut shown = String::new();
+    if let Some(schema) = block.response_format.schema() {
+        if let Err(error) = validate(&value, &schema) {
+            let label = match ui_language {
+                "vi" => "⚠ Không khớp schema:",
+                "ko" => "⚠ 스키마와 일치하지 않음:",
+                _ => "⚠ Doesn't match the schema:",
+            };
+            shown.push_str(&format!("> {} `{}`\n\n", label, error));
+        }
+    }
+    match Table::from_json(&value) {
+        Some(table) => shown.push_str(&table.to_markdown()),
+        None => shown.push_str(&format!("```json\n{}\n```", json)),
+    }
+    (json, shown)
+}
@@ -1,8 +1,8 @@
 use windows::Win32::Foundation::*;
 use windows::Win32::Graphics::Gdi::*;
-use windows::Win32::UI::WindowsAndMessaging::*;
 use std::sync::Mutex;
 use crate::overlay::result::layout::calculate_next_window_rect;
+use crate::overlay::utils::work_area_for_rect;
 
 pub const MAX_GLOW_BUFFER_DIM: i32 = 1280;
 
@@ -62,9 +62,6 @@ pub fn reset_window_position_queue() {
 pub fn get_next_window_position(initial_rect: RECT) -> RECT {
     let mut last = LAST_WINDOW_RECT.lock().unwrap();
     
-    let s_w = unsafe { GetSystemMetrics(SM_CXSCREEN) };
-    let s_h = unsafe { GetSystemMetrics(SM_CYSCREEN) };
-    
     let next_rect = match *last {
         None => {
             // First window: use initial rect
@@ -72,7 +69,7 @@ pub fn get_next_window_position(initial_rect: RECT) -> RECT {
         }
         Some(prev) => {
             // Subsequent windows: use snake algorithm from last position
-            calculate_next_window_rect(prev, s_w, s_h)
+            calculate_next_window_rect(prev, work_area_for_rect(&prev))
         }
     };
     
@@ -0,0 +1,128 @@
+//! Native MIDI input for Prompt DJ.
+//!
+//! WebView2's Web MIDI needs a permission prompt and often sees no devices, so the
+//! inputs are opened here with `midir` and note/CC messages are handed to the page as
+//! `pm-dj-midi` window messages. Without a device everything stays silent.
+
+use midir::{Ignore, MidiInput, MidiInputConnection};
+use std::cell::RefCell;
+use std::sync::Mutex;
+use windows::Win32::Foundation::{HWND, LPARAM, WPARAM};
+use windows::Win32::UI::WindowsAndMessaging::PostMessageW;
+
+use super::WM_APP_MIDI;
+
+const CLIENT_NAME: &str = "SGT Prompt DJ";
+
+lazy_static::lazy_static! {
+    // Messages received on midir's thread, waiting for the window thread
+    static ref PENDING_MESSAGES: Mutex<Vec<[u8; 3]>> = Mutex::new(Vec::new());
+}
+
+thread_local! {
+    // (port name, open connection) - lives on the Prompt DJ window thread
+    static CONNECTION: RefCell<Option<(String, MidiInputConnection<()>)>> = RefCell::new(None);
+}
+
+/// Names of the available MIDI input ports
+pub fn list_inputs() -> Vec<String> {
+    let Ok(midi_in) = MidiInput::new(CLIENT_NAME) else {
+        return Vec::new();
+    };
+    midi_in
+        .ports()
+        .iter()
+        .filter_map(|port| midi_in.port_name(port).ok())
+        .collect()
+}
+
+/// Name of the connected input, if any
+pub fn active_input() -> Option<String> {
+    CONNECTION.with(|c| c.borrow().as_ref().map(|(name, _)| name.clone()))
+}
+
+/// Open the input called `name`, replacing the current one. An empty or unknown name
+/// just disconnects.
+pub fn connect(name: &str, hwnd: HWND) {
+    disconnect();
+    if name.is_empty() {
+        return;
+    }
+
+    let Ok(mut midi_in) = MidiInput::new(CLIENT_NAME) else {
+        return;
+    };
+    midi_in.ignore(Ignore::All);
+    let Some(port) = midi_in
+        .ports()
+        .into_iter()
+        .find(|port| midi_in.port_name(port).ok().as_deref() == Some(name))
+    else {
+        return;
+    };
+
+    let hwnd_val = hwnd.0 as isize;
+    let result = midi_in.connect(
+        &port,
+        "sgt-prompt-dj-in",
+        move |_stamp, message, _| {
+            let Some(&status) = message.first() else {
+                return;
+            };
+            // Note off (0x80), note on (0x90), control change (0xB0)
+            if !matches!(status & 0xF0, 0x80 | 0x90 | 0xB0) || message.len() < 3 {
+                return;
+            }
+            PENDING_MESSAGES
+                .lock()
+                .unwrap()
+                .push([status, message[1], message[2]]);
+            unsafe {
+                let _ = PostMessageW(
+                    Some(HWND(hwnd_val as *mut std::ffi::c_void)),
+                    WM_APP_MIDI,
+                    WPARAM(0),
+                    LPARAM(0),
+                );
+            }
+        },
+        (),
+    );
+
+    match result {
+        Ok(connection) => {
+            log::info!("PromptDJ: MIDI input '{}' connected", name);
+            CONNECTION.with(|c| *c.borrow_mut() = Some((name.to_string(), connection)));
+        }
+        Err(e) => log::error!("PromptDJ: failed to open MIDI input '{}': {}", name, e),
+    }
+}
+
+/// Close the open input (window hidden or device deselected)
+pub fn disconnect() {
+    if let Some((_, connection)) = CONNECTION.with(|c| c.borrow_mut().take()) {
+        connection.close();
+    }
+    PENDING_MESSAGES.lock().unwrap().clear();
+}
+
+/// Script posting the queued messages to the page
+pub fn take_pending_script() -> Option<String> {
+    let messages = std::mem::take(&mut *PENDING_MESSAGES.lock().unwrap());
+    if messages.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "window.postMessage({{ type: 'pm-dj-midi', messages: {} }}, '*');",
+        serde_json::to_string(&messages).unwrap_or_else(|_| "[]".to_string())
+    ))
+}
+
+/// Script filling the device picker in the window header
+pub fn inputs_script() -> String {
+    format!(
+        "if (window.djSetMidiInputs) window.djSetMidiInputs({}, {});",
+        serde_json::to_string(&list_inputs()).unwrap_or_else(|_| "[]".to_string()),
+        serde_json::to_string(&active_input()).unwrap_or_else(|_| "null".to_string())
+    )
+}
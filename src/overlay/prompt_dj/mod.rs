@@ -3,6 +3,7 @@ use raw_window_handle::{
 };
 use std::borrow::Cow;
 use std::num::NonZeroIsize;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Once};
 use windows::core::*;
 use windows::Win32::Foundation::*;
@@ -10,26 +11,24 @@ use windows::Win32::Graphics::Dwm::{
     DwmSetWindowAttribute, DWMWA_WINDOW_CORNER_PREFERENCE, DWMWCP_ROUND,
 };
 use windows::Win32::Graphics::Gdi::HBRUSH;
-use windows::Win32::Media::Audio::{
-    eMultimedia, eRender, IAudioSessionControl2, IAudioSessionManager2, IMMDeviceEnumerator,
-    ISimpleAudioVolume, MMDeviceEnumerator,
-};
-use windows::Win32::System::Com::{
-    CoCreateInstance, CoInitializeEx, CLSCTX_ALL, COINIT_APARTMENTTHREADED,
-};
 use windows::Win32::System::LibraryLoader::GetModuleHandleW;
 use windows::Win32::System::Threading::GetCurrentProcessId;
 use windows::Win32::UI::Input::KeyboardAndMouse::{ReleaseCapture, SetFocus};
 use windows::Win32::UI::WindowsAndMessaging::*;
 use wry::{Rect, WebContext, WebViewBuilder};
 
+use crate::overlay::webview_recovery::{show_recovering_notice, WM_APP_WEBVIEW_CRASHED};
 use crate::win_types::SendHwnd;
 
+mod midi;
+
 static REGISTER_PDJ_CLASS: Once = Once::new();
-static mut PDJ_HWND: SendHwnd = SendHwnd(HWND(std::ptr::null_mut()));
+pub(crate) static mut PDJ_HWND: SendHwnd = SendHwnd(HWND(std::ptr::null_mut()));
 static mut IS_WARMED_UP: bool = false;
+static WINDOW_THREAD_RUNNING: AtomicBool = AtomicBool::new(false);
 const WM_APP_SHOW: u32 = WM_USER + 101;
 const WM_APP_UPDATE_SETTINGS: u32 = WM_USER + 102;
+const WM_APP_MIDI: u32 = WM_USER + 103;
 
 // Thread-local storage for WebView
 thread_local! {
@@ -105,44 +104,41 @@ fn update_child_pids() {
                 }
             }
 
+            log::debug!("PromptDJ: found {} child processes", descendants.len());
             if let Ok(mut lock) = CHILD_PIDS.lock() {
                 *lock = descendants;
             }
         }
+    } else if let Err(e) = output {
+        log::error!("PromptDJ: failed to list child processes: {}", e);
     }
 }
 
-unsafe fn set_app_volume(volume: f32) -> Result<()> {
-    // Access cache
-    let current_pid = GetCurrentProcessId();
+fn set_app_volume(volume: f32) -> Result<()> {
+    let current_pid = unsafe { GetCurrentProcessId() };
     let child_pids = CHILD_PIDS.lock().unwrap_or_else(|e| e.into_inner()).clone();
 
-    // We try to initialize COM, but ignore error if already initialized
-    let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
-
-    let device_enumerator: IMMDeviceEnumerator =
-        CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)?;
-
-    let device = device_enumerator.GetDefaultAudioEndpoint(eRender, eMultimedia)?;
-    let session_manager: IAudioSessionManager2 = device.Activate(CLSCTX_ALL, None)?;
-    let session_enumerator = session_manager.GetSessionEnumerator()?;
-    let count = session_enumerator.GetCount()?;
-
-    for i in 0..count {
-        if let Ok(session_control) = session_enumerator.GetSession(i) {
-            if let Ok(session_control2) = session_control.cast::<IAudioSessionControl2>() {
-                if let Ok(pid) = session_control2.GetProcessId() {
-                    // Match Main Process OR known Children
-                    if pid == current_pid || child_pids.contains(&pid) {
-                        if let Ok(simple_volume) = session_control.cast::<ISimpleAudioVolume>() {
-                            let _ = simple_volume.SetMasterVolume(volume, std::ptr::null());
-                        }
-                    }
-                }
-            }
+    // Match Main Process OR known Children
+    crate::audio::sessions::set_volume_where(
+        |pid| pid == current_pid || child_pids.contains(&pid),
+        volume,
+    )
+}
+
+fn save_window_rect() {
+    if let Ok(app) = crate::APP.lock() {
+        if app.config.prompt_dj_window_rect.is_some() {
+            crate::config::save_config(&app.config);
         }
     }
-    Ok(())
+}
+
+fn evaluate_in_pdj(script: &str) {
+    PDJ_WEBVIEW.with(|wv| {
+        if let Some(webview) = wv.borrow().as_ref() {
+            let _ = webview.evaluate_script(script);
+        }
+    });
 }
 
 unsafe extern "system" fn pdj_wnd_proc(
@@ -193,6 +189,7 @@ unsafe extern "system" fn pdj_wnd_proc(
                     let _ = webview.evaluate_script(&script);
                 }
             });
+            evaluate_in_pdj(&midi::inputs_script());
 
             let _ = ShowWindow(hwnd, SW_SHOW);
             let _ = SetForegroundWindow(hwnd);
@@ -241,6 +238,12 @@ unsafe extern "system" fn pdj_wnd_proc(
             });
             LRESULT(0)
         }
+        WM_APP_MIDI => {
+            if let Some(script) = midi::take_pending_script() {
+                evaluate_in_pdj(&script);
+            }
+            LRESULT(0)
+        }
         WM_CLOSE => {
             PDJ_WEBVIEW.with(|wv| {
                 if let Some(webview) = wv.borrow().as_ref() {
@@ -248,7 +251,54 @@ unsafe extern "system" fn pdj_wnd_proc(
                         .evaluate_script("window.postMessage({ type: 'pm-dj-stop-audio' }, '*')");
                 }
             });
+            midi::disconnect();
+            save_window_rect();
             let _ = ShowWindow(hwnd, SW_HIDE);
+            // Lazy mode: drop the WebView; the next show builds a new window
+            if crate::overlay::webview_lifecycle::is_lazy() {
+                let _ = DestroyWindow(hwnd);
+            }
+            LRESULT(0)
+        }
+        WM_APP_WEBVIEW_CRASHED => {
+            // Rebuild the whole window (same path as lazy mode); reopen it if it was showing
+            let was_visible = IsWindowVisible(hwnd).as_bool();
+            midi::disconnect();
+            save_window_rect();
+            let _ = DestroyWindow(hwnd);
+            if was_visible {
+                show_recovering_notice();
+                std::thread::spawn(|| {
+                    for _ in 0..50 {
+                        if !WINDOW_THREAD_RUNNING.load(Ordering::SeqCst) {
+                            show_prompt_dj();
+                            return;
+                        }
+                        std::thread::sleep(std::time::Duration::from_millis(100));
+                    }
+                });
+            }
+            LRESULT(0)
+        }
+        WM_WINDOWPOSCHANGED => {
+            // Track the rect in memory; it's written to disk once the move/resize ends
+            if IsWindowVisible(hwnd).as_bool()
+                && !IsIconic(hwnd).as_bool()
+                && !IsZoomed(hwnd).as_bool()
+            {
+                let mut r = RECT::default();
+                if GetWindowRect(hwnd, &mut r).is_ok() {
+                    if let Ok(mut app) = crate::APP.lock() {
+                        app.config.prompt_dj_window_rect =
+                            Some((r.left, r.top, r.right - r.left, r.bottom - r.top));
+                    }
+                }
+            }
+            // Default handling sends the WM_SIZE that resizes the WebView
+            DefWindowProcW(hwnd, msg, wparam, lparam)
+        }
+        WM_EXITSIZEMOVE => {
+            save_window_rect();
             LRESULT(0)
         }
         WM_DESTROY => {
@@ -326,8 +376,13 @@ fn wnd_http_response(
 }
 
 pub fn warmup() {
+    // One window thread at a time (show_prompt_dj retries warmup while loading)
+    if WINDOW_THREAD_RUNNING.swap(true, Ordering::SeqCst) {
+        return;
+    }
     std::thread::spawn(|| unsafe {
         internal_create_pdj_loop();
+        WINDOW_THREAD_RUNNING.store(false, Ordering::SeqCst);
     });
 }
 
@@ -418,18 +473,28 @@ unsafe fn internal_create_pdj_loop() {
 
     let width = ((screen_w as f64 * 0.70) as i32).clamp(1200, 1600);
     let height = ((screen_h as f64 * height_pct) as i32).clamp(550, 900);
-    let x = (screen_w - width) / 2;
-    let y = (screen_h - height) / 2;
 
-    let (api_key, lang, theme_mode) = {
+    let (api_key, lang, theme_mode, saved_rect) = {
         let app = crate::APP.lock().unwrap();
         (
             app.config.gemini_api_key.clone(),
             app.config.ui_language.clone(),
             app.config.theme_mode.clone(),
+            app.config.prompt_dj_window_rect,
         )
     };
 
+    // Reopen where the user left it, as long as that's still on a monitor
+    let (x, y, width, height) = match saved_rect {
+        Some(rect) => crate::overlay::utils::clamp_rect_to_monitor(rect),
+        None => (
+            (screen_w - width) / 2,
+            (screen_h - height) / 2,
+            width,
+            height,
+        ),
+    };
+
     let title_str = crate::gui::locale::LocaleText::get(&lang).prompt_dj_title;
     let title_wide = windows::core::HSTRING::from(title_str);
 
@@ -515,7 +580,7 @@ unsafe fn internal_create_pdj_loop() {
                 body {{
                     margin: 0;
                     padding: 0;
-                    font-family: 'Google Sans Flex', 'Segoe UI', system-ui, sans-serif !important;
+                    font-family: var(--overlay-font) !important;
                     background-color: transparent !important;
                     overflow: hidden;
                 }}
@@ -543,7 +608,7 @@ unsafe fn internal_create_pdj_loop() {
                     background: transparent;
                     color: rgba(255,255,255,0.5);
                     border: none;
-                    font-family: 'Google Sans Flex', 'Segoe UI', system-ui;
+                    font-family: var(--overlay-font);
                     font-size: 16px;
                     cursor: pointer;
                     display: flex;
@@ -565,7 +630,7 @@ unsafe fn internal_create_pdj_loop() {
                     background: transparent;
                     color: rgba(255,255,255,0.5);
                     border: none;
-                    font-family: 'Google Sans Flex', 'Segoe UI', system-ui;
+                    font-family: var(--overlay-font);
                     font-size: 16px;
                     cursor: pointer;
                     display: flex;
@@ -578,6 +643,27 @@ unsafe fn internal_create_pdj_loop() {
                     background: rgba(255,255,255,0.1);
                     color: white;
                 }}
+                #dj-midi-select {{
+                    position: absolute;
+                    top: 4px;
+                    right: 88px;
+                    height: 24px;
+                    max-width: 200px;
+                    padding: 0 6px;
+                    background: rgba(0,0,0,0.25);
+                    color: rgba(255,255,255,0.7);
+                    border: 1px solid rgba(255,255,255,0.15);
+                    border-radius: 6px;
+                    font-family: var(--overlay-font);
+                    font-size: 12px;
+                    outline: none;
+                    cursor: pointer;
+                    -webkit-app-region: no-drag;
+                }}
+                #dj-midi-select option {{
+                    background: #222;
+                    color: white;
+                }}
                 /* Light theme: keep white text with dark shadow for visibility */
                 [data-theme='light'] #dj-close-btn,
                 [data-theme='light'] #dj-min-btn {{
@@ -609,6 +695,32 @@ unsafe fn internal_create_pdj_loop() {
             }};
             header.appendChild(closeBtn);
 
+            // MIDI input picker - devices are opened natively, hidden when none exist
+            const midiSelect = document.createElement('select');
+            midiSelect.id = 'dj-midi-select';
+            midiSelect.style.display = 'none';
+            midiSelect.onmousedown = (e) => e.stopPropagation();
+            midiSelect.onchange = () => {{
+                if (window.ipc) window.ipc.postMessage('midi_connect:' + midiSelect.value);
+            }};
+            header.appendChild(midiSelect);
+
+            window.djSetMidiInputs = (names, active) => {{
+                midiSelect.innerHTML = '';
+                const off = document.createElement('option');
+                off.value = '';
+                off.textContent = '{midi_off}';
+                midiSelect.appendChild(off);
+                names.forEach((name) => {{
+                    const opt = document.createElement('option');
+                    opt.value = name;
+                    opt.textContent = 'MIDI: ' + name;
+                    midiSelect.appendChild(opt);
+                }});
+                midiSelect.value = active || '';
+                midiSelect.style.display = names.length ? 'block' : 'none';
+            }};
+
             // --- Volume Slider Removed (moved to PromptDjMidi.ts) ---
 
             const updateTheme = (theme) => {{
@@ -628,6 +740,7 @@ unsafe fn internal_create_pdj_loop() {
             // Hover Logic (Removed Vol Container part)
 
             document.body.appendChild(header);
+            if (window.ipc) window.ipc.postMessage('midi_list');
 
             setTimeout(() => {{
                 window.postMessage({{ type: 'pm-dj-set-api-key', apiKey: '{}', lang: '{}' }}, '*');
@@ -637,7 +750,11 @@ unsafe fn internal_create_pdj_loop() {
         }});
 
         "#,
-        font_css, api_key, lang, theme_str
+        font_css,
+        api_key,
+        lang,
+        theme_str,
+        midi_off = crate::gui::locale::LocaleText::get(&lang).prompt_dj_midi_off
     );
 
     let hwnd_ipc = hwnd;
@@ -698,14 +815,18 @@ unsafe fn internal_create_pdj_loop() {
                         let _ = ShowWindow(hwnd_ipc, SW_MINIMIZE);
                     }
                 } else if body == "close_window" {
+                    // Same as the window's WM_CLOSE (posted: the WebView may be dropped)
                     unsafe {
-                        let _ = ShowWindow(hwnd_ipc, SW_HIDE);
+                        let _ = PostMessageW(Some(hwnd_ipc), WM_CLOSE, WPARAM(0), LPARAM(0));
                     }
+                } else if body == "midi_list" {
+                    evaluate_in_pdj(&midi::inputs_script());
+                } else if let Some(name) = body.strip_prefix("midi_connect:") {
+                    midi::connect(name, hwnd_ipc);
+                    evaluate_in_pdj(&midi::inputs_script());
                 } else if body.starts_with("set_volume:") {
                     if let Ok(val) = body.trim_start_matches("set_volume:").parse::<f32>() {
-                        unsafe {
-                            let _ = set_app_volume(val);
-                        }
+                        let _ = set_app_volume(val);
                     }
                 }
             })
@@ -717,13 +838,14 @@ unsafe fn internal_create_pdj_loop() {
     let webview = match webview_result {
         Ok(wv) => wv,
         Err(e) => {
-            eprintln!("Failed to create PromptDJ WebView: {:?}", e);
+            log::error!("Failed to create PromptDJ WebView: {:?}", e);
             // Clean up and exit gracefully
             let _ = DestroyWindow(hwnd);
             PDJ_HWND = SendHwnd::default();
             return;
         }
     };
+    crate::overlay::webview_recovery::watch(&webview, hwnd);
     let webview_arc = Arc::new(webview);
 
     // Initial Resize
@@ -761,4 +883,5 @@ unsafe fn internal_create_pdj_loop() {
         *wv.borrow_mut() = None;
     });
     PDJ_HWND = SendHwnd::default();
+    IS_WARMED_UP = false;
 }
@@ -0,0 +1,73 @@
+//! Quick translate: the clipboard or the selected text, translated without setting up
+//! a preset. Each run builds a one-block text preset from the quick translate settings
+//! (target language, provider) and shows the result in a regular result window.
+
+use windows::Win32::Foundation::RECT;
+
+use crate::config::preset::{BlockBuilder, PresetBuilder};
+use crate::config::{Config, Preset};
+use crate::gui::locale::LocaleText;
+use crate::APP;
+
+/// Id of the preset built for a run. It isn't in the config, so per-preset options
+/// looked up by id (auto-copy, skipping the global prompt) stay off.
+const QUICK_PRESET_ID: &str = "quick_translate";
+
+/// Model of the translation block; a provider set in the settings picks that
+/// provider's model instead
+const QUICK_MODEL: &str = "cerebras_qwen3";
+
+pub fn translate_clipboard() {
+    std::thread::spawn(|| translate(crate::overlay::utils::get_clipboard_text()));
+}
+
+pub fn translate_selection() {
+    std::thread::spawn(|| {
+        let text = super::text_selection::copy_selection().unwrap_or_default();
+        translate(text);
+    });
+}
+
+fn quick_preset(config: &Config, name: &str) -> Preset {
+    let mut block = BlockBuilder::text(QUICK_MODEL)
+        .prompt("Translate the following text to {language1}. Output ONLY the translation.")
+        .language(&config.quick_translate_language)
+        .build();
+    block.provider_override = config.quick_translate_provider.clone();
+    PresetBuilder::new(QUICK_PRESET_ID, name)
+        .text_select()
+        .blocks(vec![block])
+        .build()
+}
+
+fn translate(text: String) {
+    let Ok(config) = APP.lock().map(|app| app.config.clone()) else {
+        return;
+    };
+    let locale = LocaleText::get(&config.ui_language);
+    if text.trim().is_empty() {
+        super::auto_copy_badge::show_notification(locale.quick_translate_nothing);
+        return;
+    }
+    let preset = quick_preset(&config, locale.quick_translate_name);
+
+    // Centered on the monitor under the cursor, like a selection preset's result
+    let (work, scale) = crate::overlay::utils::cursor_monitor_work_area();
+    let (w, h) = ((700.0 * scale) as i32, (300.0 * scale) as i32);
+    let (center_x, center_y) = ((work.left + work.right) / 2, (work.top + work.bottom) / 2);
+    let center_rect = RECT {
+        left: center_x - w / 2,
+        top: center_y - h / 2,
+        right: center_x + w / 2,
+        bottom: center_y + h / 2,
+    };
+
+    super::process::start_text_processing(
+        text,
+        center_rect,
+        config,
+        preset,
+        locale.quick_translate_name.to_string(),
+        String::new(),
+    );
+}
@@ -66,6 +66,9 @@ pub fn show_realtime_egui_overlay(preset_idx: usize) {
     
     LAST_SPOKEN_LENGTH.store(0, Ordering::SeqCst);
     REALTIME_TTS_ENABLED.store(false, Ordering::SeqCst);
+    let tts_speed = APP.lock().unwrap().config.tts_playback_speed;
+    REALTIME_TTS_SPEED.store(tts_speed, Ordering::SeqCst);
+    CURRENT_TTS_SPEED.store(tts_speed, Ordering::SeqCst);
     SELECTED_APP_PID.store(0, Ordering::SeqCst);
     if let Ok(mut name) = SELECTED_APP_NAME.lock() { name.clear(); }
     if let Ok(mut queue) = COMMITTED_TRANSLATION_QUEUE.lock() { queue.clear(); }
@@ -81,7 +84,7 @@ pub fn show_realtime_egui_overlay(preset_idx: usize) {
     let config_audio_source = app.config.realtime_audio_source.clone();
     drop(app);
     
-    let is_device_saved = config_audio_source == "device";
+    let is_device_saved = config_audio_source == "device" && !preset.realtime_dictation;
     
     if let Ok(mut ui_state) = UI_STATE.lock() {
         ui_state.font_size = font_size;
@@ -98,7 +101,9 @@ pub fn show_realtime_egui_overlay(preset_idx: usize) {
         // Don't lazy load apps here to avoid blocking
     }
     
-    let effective_source = if config_audio_source.is_empty() { "device".to_string() } else { config_audio_source };
+    let effective_source = if preset.realtime_dictation {
+        "mic".to_string()
+    } else if config_audio_source.is_empty() { "device".to_string() } else { config_audio_source };
     
     if let Ok(mut new_source) = NEW_AUDIO_SOURCE.lock() {
         *new_source = effective_source.clone();
@@ -111,6 +116,8 @@ pub fn show_realtime_egui_overlay(preset_idx: usize) {
         LANGUAGE_CHANGE.store(true, Ordering::SeqCst);
     }
 
+    crate::overlay::realtime_webview::dictation::start_session(preset.realtime_dictation);
+
     let mut final_preset = preset.clone();
     final_preset.audio_source = effective_source;
 
@@ -328,6 +335,19 @@ fn render_main_ui(ui: &mut egui::Ui, state: &mut RealtimeUiState) {
                     state.apps_list = crate::overlay::realtime_webview::app_selection::enumerate_audio_apps();
                 }
             }
+
+            ui.separator();
+
+            // Dictation toggle: red while typing into the focused app
+            let dictating = DICTATION_ENABLED.load(Ordering::SeqCst);
+            let dictation_icon = if dictating {
+                egui::RichText::new("⌨").color(egui::Color32::from_rgb(239, 83, 80))
+            } else {
+                egui::RichText::new("⌨")
+            };
+            if ui.selectable_label(dictating, dictation_icon).on_hover_text(locale.realtime_dictation_tooltip).clicked() {
+                crate::overlay::realtime_webview::dictation::set_enabled(!dictating);
+            }
         });
     });
     
@@ -361,11 +381,12 @@ fn render_main_ui(ui: &mut egui::Ui, state: &mut RealtimeUiState) {
             let base_speed = REALTIME_TTS_SPEED.load(Ordering::Relaxed);
             let auto_speed = REALTIME_TTS_AUTO_SPEED.load(Ordering::Relaxed);
             
-            ui.label(format!("{:.1}x", current_speed as f32 / 100.0));
+            ui.label(format!("{:.2}x", current_speed as f32 / 100.0));
             
-            let mut speed_val = base_speed as i32;
-            if ui.add(egui::Slider::new(&mut speed_val, 50..=200).show_value(false)).changed() {
-                REALTIME_TTS_SPEED.store(speed_val as u32, Ordering::SeqCst);
+            let mut speed_val = base_speed;
+            let speed_range = crate::api::tts::MIN_PLAYBACK_SPEED..=crate::api::tts::MAX_PLAYBACK_SPEED;
+            if ui.add(egui::Slider::new(&mut speed_val, speed_range).step_by(5.0).show_value(false)).changed() {
+                REALTIME_TTS_SPEED.store(speed_val, Ordering::SeqCst);
                 REALTIME_TTS_AUTO_SPEED.store(false, Ordering::SeqCst);
             }
             
@@ -373,6 +394,21 @@ fn render_main_ui(ui: &mut egui::Ui, state: &mut RealtimeUiState) {
             if ui.checkbox(&mut auto_on, locale.realtime_tts_auto).changed() {
                 REALTIME_TTS_AUTO_SPEED.store(auto_on, Ordering::SeqCst);
             }
+
+            let tts = &crate::api::tts::TTS_MANAGER;
+            let (pause_icon, pause_tip) = if tts.is_paused() {
+                ("▶", locale.tts_resume)
+            } else {
+                ("⏸", locale.tts_pause)
+            };
+            if ui.small_button(pause_icon).on_hover_text(pause_tip).clicked() {
+                tts.toggle_pause();
+            }
+            if ui.small_button("⏹").on_hover_text(locale.tts_stop).clicked() {
+                // Drop everything not yet read; TTS stays on for new translations
+                tts.stop();
+                if let Ok(mut queue) = COMMITTED_TRANSLATION_QUEUE.lock() { queue.clear(); }
+            }
         });
     }
     
@@ -450,12 +486,11 @@ fn render_main_ui(ui: &mut egui::Ui, state: &mut RealtimeUiState) {
             if old_len > state.last_spoken_len {
                 let new_committed = committed[state.last_spoken_len..].to_string();
                 if !new_committed.trim().is_empty() {
-                    if let Ok(mut queue) = COMMITTED_TRANSLATION_QUEUE.lock() {
-                        queue.push_back(new_committed.clone());
-                    }
+                    crate::overlay::realtime_webview::tts_pacing::queue_segment(&new_committed);
                     let text_to_speak = new_committed;
                     std::thread::spawn(move || {
-                        crate::api::tts::TTS_MANAGER.speak_realtime(&text_to_speak, 0);
+                        let config = crate::APP.lock().unwrap().config.clone();
+                        crate::api::tts::TTS_MANAGER.speak_realtime(&text_to_speak, 0, &config);
                     });
                 }
                 state.last_spoken_len = old_len;
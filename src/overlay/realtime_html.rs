@@ -8,6 +8,9 @@ pub fn get_realtime_html(
     translation_model: &str,
     transcription_model: &str,
     font_size: u32,
+    speaker_labels: bool,
+    caption_hotkey: &str,
+    tts_speed: u32,
     text: &LocaleText,
 ) -> String {
     let _title_icon = if is_translation {
@@ -30,6 +33,25 @@ pub fn get_realtime_html(
         r#"<canvas id="volume-canvas" width="90" height="24"></canvas>"#.to_string()
     };
 
+    // The translation follows the target language; a transcript can be in any
+    // language, so the browser picks from its first letters
+    let content_dir = if !is_translation {
+        "auto"
+    } else if crate::overlay::utils::is_rtl_language(current_language) {
+        "rtl"
+    } else {
+        "ltr"
+    };
+    // The language of the translation picks the browser's line breaking rules (and
+    // fonts) for scripts written without spaces
+    let content_lang = if is_translation {
+        isolang::Language::from_name(current_language)
+            .and_then(|l| l.to_639_1())
+            .unwrap_or_default()
+    } else {
+        ""
+    };
+
     let _mic_text = text.realtime_mic;
     let _device_text = text.realtime_device;
     let placeholder_text = text.realtime_waiting;
@@ -49,9 +71,14 @@ pub fn get_realtime_html(
                 .map(|c| c.to_uppercase())
                 .unwrap_or_else(|| lang.chars().take(2).collect::<String>().to_uppercase());
             // Option shows full name, but we store code as data attribute for selected display
+            let rtl = if crate::overlay::utils::is_rtl_language(lang) {
+                "data-rtl=\"1\""
+            } else {
+                ""
+            };
             format!(
-                r#"<option value="{}" data-code="{}" {}>{}</option>"#,
-                lang, lang_code, selected, lang
+                r#"<option value="{}" data-code="{}" {} {}>{}</option>"#,
+                lang, lang_code, rtl, selected, lang
             )
         })
         .collect::<Vec<_>>()
@@ -81,6 +108,8 @@ pub fn get_realtime_html(
                 <span class="material-symbols-rounded trans-model-icon {gemini_active}" data-value="gemini" title="Gemini Live (Cloud)">{auto_awesome_svg}</span>
                 <span class="material-symbols-rounded trans-model-icon {parakeet_active}" data-value="parakeet" title="Parakeet (Local)">{bolt_en_svg}</span>
             </div>
+            <span class="ctrl-btn speaker-btn {speakers_active}" id="speaker-btn" title="{speakers_title}"><span class="material-symbols-rounded">{speakers_svg}</span></span>
+            <span class="ctrl-btn dictate-btn" id="dictate-btn" title="{dictate_title}"><span class="material-symbols-rounded">{keyboard_svg}</span></span>
         "#,
             mic_active = if !is_device { "active" } else { "" },
             device_active = if is_device { "active" } else { "" },
@@ -89,7 +118,13 @@ pub fn get_realtime_html(
             mic_svg = crate::overlay::html_components::icons::get_icon_svg("mic"),
             device_svg = crate::overlay::html_components::icons::get_icon_svg("speaker_group"),
             auto_awesome_svg = crate::overlay::html_components::icons::get_icon_svg("auto_awesome"),
-            bolt_en_svg = crate::overlay::html_components::icons::get_icon_svg("bolt_en")
+            bolt_en_svg = crate::overlay::html_components::icons::get_icon_svg("bolt_en"),
+            speakers_active = if speaker_labels { "active" } else { "" },
+            speakers_title = text.realtime_speaker_labels_tooltip,
+            speakers_svg =
+                crate::overlay::html_components::icons::get_icon_svg("record_voice_over"),
+            dictate_title = text.realtime_dictation_tooltip,
+            keyboard_svg = crate::overlay::html_components::icons::get_icon_svg("keyboard")
         )
     } else {
         // Language selector and model toggle for translation window
@@ -147,7 +182,11 @@ pub fn get_realtime_html(
     let js = format!(
         "{}{}",
         crate::overlay::html_components::js_main::get(font_size),
-        crate::overlay::html_components::js_logic::get(placeholder_text)
+        crate::overlay::html_components::js_logic::get(
+            placeholder_text,
+            text.realtime_speaker_label,
+            speaker_labels
+        )
     );
 
     // Get local font CSS (cached fonts, no network loading)
@@ -170,7 +209,8 @@ pub fn get_realtime_html(
             <div id="title">{title_content}</div>
             <div id="controls">
                 {audio_selector}
-                <span class="ctrl-btn" id="copy-btn" title="Copy text"><span class="material-symbols-rounded">{content_copy_svg}</span></span>
+                <span class="ctrl-btn" id="copy-btn" title="Copy text (hold to copy transcript + translation)"><span class="material-symbols-rounded">{content_copy_svg}</span></span>
+                <span class="ctrl-btn" id="caption-btn" title="{caption_title}"><span class="material-symbols-rounded">{closed_caption_svg}</span></span>
                 <div class="pill-group">
                     <span class="ctrl-btn" id="font-decrease" title="Decrease font size"><span class="material-symbols-rounded">{remove_svg}</span></span>
                     <span class="ctrl-btn" id="font-increase" title="Increase font size"><span class="material-symbols-rounded">{add_svg}</span></span>
@@ -183,9 +223,10 @@ pub fn get_realtime_html(
         </div>
         <div id="header-toggle" title="Toggle header"><span class="material-symbols-rounded">{expand_less_svg}</span></div>
         <div id="viewport">
-            <div id="content">
+            <div id="content" dir="{content_dir}" lang="{content_lang}">
                 <span class="placeholder">{placeholder_text}</span>
             </div>
+            <div id="jump-latest">↓ {jump_latest}</div>
         </div>
         <div id="resize-hint"><span class="material-symbols-rounded" style="font-size: 20px;">{pip_svg}</span></div>
     </div>
@@ -217,11 +258,18 @@ pub fn get_realtime_html(
         <div class="tts-modal-row">
             <span class="tts-modal-label">{tts_speed}</span>
             <div class="speed-slider-container">
-                <input type="range" class="speed-slider" id="speed-slider" min="50" max="200" value="100" step="10">
-                <span class="speed-value" id="speed-value">1.0x</span>
+                <input type="range" class="speed-slider" id="speed-slider" min="{speed_min}" max="{speed_max}" value="{speed_value}" step="5">
+                <span class="speed-value" id="speed-value">{speed_label}</span>
                 <button class="auto-toggle on" id="auto-speed-toggle" title="Auto-adjust speed to catch up">{tts_auto}</button>
             </div>
     </div>
+        <div class="tts-modal-row">
+            <span class="tts-modal-label">{tts_playback}</span>
+            <div class="tts-playback">
+                <span class="ctrl-btn" id="tts-pause-btn" title="{tts_pause}" data-pause-title="{tts_pause}" data-resume-title="{tts_resume}"><span class="material-symbols-rounded tts-icon-pause">{pause_svg}</span><span class="material-symbols-rounded tts-icon-play">{play_svg}</span></span>
+                <span class="ctrl-btn" id="tts-stop-btn" title="{tts_stop}"><span class="material-symbols-rounded">{stop_svg}</span></span>
+            </div>
+        </div>
             </div>
         </div>
     </div>
@@ -248,12 +296,28 @@ pub fn get_realtime_html(
         title_content = title_content,
         audio_selector = audio_selector,
         placeholder_text = placeholder_text,
+        content_dir = content_dir,
+        content_lang = content_lang,
+        jump_latest = text.realtime_jump_latest,
         tts_title = text.realtime_tts_title,
         tts_speed = text.realtime_tts_speed,
+        speed_min = crate::api::tts::MIN_PLAYBACK_SPEED,
+        speed_max = crate::api::tts::MAX_PLAYBACK_SPEED,
+        speed_value = tts_speed,
+        speed_label = format!("{:.2}x", tts_speed as f32 / 100.0),
         tts_auto = text.realtime_tts_auto,
+        tts_playback = text.realtime_tts_playback,
+        tts_pause = text.tts_pause,
+        tts_resume = text.tts_resume,
+        tts_stop = text.tts_stop,
+        pause_svg = crate::overlay::html_components::icons::get_icon_svg("pause"),
+        play_svg = crate::overlay::html_components::icons::get_icon_svg("play_arrow"),
+        stop_svg = crate::overlay::html_components::icons::get_icon_svg("stop"),
         app_select_title = text.app_select_title,
         app_select_hint = text.app_select_hint,
         content_copy_svg = crate::overlay::html_components::icons::get_icon_svg("content_copy"),
+        caption_title = text.realtime_caption_tooltip.replace("{}", caption_hotkey),
+        closed_caption_svg = crate::overlay::html_components::icons::get_icon_svg("closed_caption"),
         remove_svg = crate::overlay::html_components::icons::get_icon_svg("remove"),
         add_svg = crate::overlay::html_components::icons::get_icon_svg("add"),
         subtitles_svg = crate::overlay::html_components::icons::get_icon_svg("subtitles"),
@@ -1,10 +1,13 @@
 pub mod app_selection;
+pub mod caption;
+pub mod dictation;
 pub mod manager;
 pub mod state;
+pub mod tts_pacing;
 pub mod webview;
 pub mod wndproc;
 
 pub use manager::{
-    is_realtime_overlay_active, show_realtime_overlay, stop_realtime_overlay, warmup,
+    is_realtime_overlay_active, run_script, show_realtime_overlay, stop_realtime_overlay, warmup,
 };
 pub use state::*;
@@ -282,7 +282,7 @@ pub fn show_app_selection_popup() {
     // Get apps list
     let apps = enumerate_audio_apps();
     if apps.is_empty() {
-        eprintln!("No audio apps found for selection");
+        log::warn!("No audio apps found for selection");
         return;
     }
 
@@ -610,7 +610,10 @@ pub fn show_app_selection_popup() {
                                     );
                                 }
                             } else {
-                                eprintln!("App Selection: Failed to parse PID from '{}'", pid_str);
+                                log::error!(
+                                    "App Selection: Failed to parse PID from '{}'",
+                                    pid_str
+                                );
                             }
                         }
                     }
@@ -618,7 +621,7 @@ pub fn show_app_selection_popup() {
                 .build_as_child(&HwndWrapper(hwnd));
 
             if result.is_err() {
-                eprintln!("Failed to create WebView for app selection");
+                log::error!("Failed to create WebView for app selection");
                 let _ = DestroyWindow(hwnd);
                 return;
             }
@@ -0,0 +1,187 @@
+//! Caption bar display mode
+//!
+//! Instead of the two floating windows, one wide and short window is docked to the
+//! bottom edge of the work area, like subtitles on a video. It shows the translation
+//! when the preset translates, otherwise the transcript; the other window is hidden.
+//! The text pipeline and IPC are unchanged, only the layout differs. All functions
+//! here must run on the overlay window thread.
+
+use super::state::*;
+use crate::APP;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use windows::Win32::Foundation::*;
+use windows::Win32::Graphics::Gdi::{
+    GetMonitorInfoW, MonitorFromWindow, MONITORINFO, MONITOR_DEFAULTTONEAREST,
+};
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    RegisterHotKey, UnregisterHotKey, HOT_KEY_MODIFIERS,
+};
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+/// `WM_HOTKEY` id registered on the transcription window during a session
+pub const CAPTION_HOTKEY_ID: i32 = 1;
+
+/// Lines of text the bar has room for
+const CAPTION_LINES: i32 = 3;
+const CAPTION_MAX_WIDTH: i32 = 1400;
+/// Gap between the bar and the bottom of the work area
+const CAPTION_MARGIN: i32 = 24;
+
+/// Window placement from before the bar was shown: (hwnd, rect, visible)
+static SAVED_LAYOUT: Mutex<Vec<(isize, RECT, bool)>> = Mutex::new(Vec::new());
+
+fn eval(hwnd: HWND, script: &str) {
+    let hwnd_key = hwnd.0 as isize;
+    REALTIME_WEBVIEWS.with(|wvs| {
+        if let Some(webview) = wvs.borrow().get(&hwnd_key) {
+            let _ = webview.evaluate_script(script);
+        }
+    });
+}
+
+/// Register the session hotkey that toggles the bar
+pub unsafe fn register_hotkey(hwnd: HWND) {
+    let hotkey = APP.lock().unwrap().config.realtime_caption_hotkey.clone();
+    if RegisterHotKey(
+        Some(hwnd),
+        CAPTION_HOTKEY_ID,
+        HOT_KEY_MODIFIERS(hotkey.modifiers),
+        hotkey.code,
+    )
+    .is_err()
+    {
+        log::warn!("Caption hotkey {} is taken by another app", hotkey.name);
+    }
+}
+
+pub unsafe fn unregister_hotkey(hwnd: HWND) {
+    let _ = UnregisterHotKey(Some(hwnd), CAPTION_HOTKEY_ID);
+}
+
+/// Drop any bar layout left from a previous session without moving windows
+pub fn reset() {
+    CAPTION_MODE.store(false, Ordering::SeqCst);
+    if let Ok(mut saved) = SAVED_LAYOUT.lock() {
+        saved.clear();
+    }
+    unsafe {
+        for hwnd in [REALTIME_HWND, TRANSLATION_HWND] {
+            if !hwnd.is_invalid() {
+                eval(
+                    hwnd,
+                    "if(window.setCaptionMode) window.setCaptionMode(false);",
+                );
+            }
+        }
+    }
+}
+
+/// Bounds of the bar on the monitor showing `hwnd`
+unsafe fn caption_rect(hwnd: HWND) -> RECT {
+    let monitor = MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST);
+    let mut info = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
+    let work = if GetMonitorInfoW(monitor, &mut info).as_bool() {
+        info.rcWork
+    } else {
+        RECT {
+            left: 0,
+            top: 0,
+            right: GetSystemMetrics(SM_CXSCREEN),
+            bottom: GetSystemMetrics(SM_CYSCREEN),
+        }
+    };
+
+    let font_size = APP.lock().unwrap().config.realtime_font_size as i32;
+    let work_w = work.right - work.left;
+    let width = (work_w * 7 / 10).min(CAPTION_MAX_WIDTH);
+    // Line height is 1.5em, plus the container padding
+    let height = font_size * 3 / 2 * CAPTION_LINES + 20;
+    let left = work.left + (work_w - width) / 2;
+    let top = work.bottom - CAPTION_MARGIN - height;
+    RECT {
+        left,
+        top,
+        right: left + width,
+        bottom: top + height,
+    }
+}
+
+pub unsafe fn set_caption_mode(enabled: bool) {
+    if CAPTION_MODE.swap(enabled, Ordering::SeqCst) == enabled {
+        return;
+    }
+    let windows = [REALTIME_HWND, TRANSLATION_HWND];
+
+    if enabled {
+        let mut saved = SAVED_LAYOUT.lock().unwrap();
+        saved.clear();
+        for hwnd in windows {
+            let mut rect = RECT::default();
+            let _ = GetWindowRect(hwnd, &mut rect);
+            saved.push((hwnd.0 as isize, rect, IsWindowVisible(hwnd).as_bool()));
+        }
+
+        let caption_hwnd = if IsWindowVisible(TRANSLATION_HWND).as_bool() {
+            TRANSLATION_HWND
+        } else {
+            REALTIME_HWND
+        };
+        for hwnd in windows {
+            if hwnd != caption_hwnd {
+                let _ = ShowWindow(hwnd, SW_HIDE);
+            }
+        }
+        let rect = caption_rect(caption_hwnd);
+        let _ = SetWindowPos(
+            caption_hwnd,
+            Some(HWND_TOPMOST),
+            rect.left,
+            rect.top,
+            rect.right - rect.left,
+            rect.bottom - rect.top,
+            SWP_SHOWWINDOW | SWP_NOACTIVATE,
+        );
+        eval(
+            caption_hwnd,
+            "if(window.setCaptionMode) window.setCaptionMode(true);",
+        );
+    } else {
+        let saved = std::mem::take(&mut *SAVED_LAYOUT.lock().unwrap());
+        for (hwnd_val, rect, visible) in saved {
+            let hwnd = HWND(hwnd_val as *mut std::ffi::c_void);
+            let flags = if visible {
+                SWP_SHOWWINDOW | SWP_NOACTIVATE
+            } else {
+                SWP_HIDEWINDOW | SWP_NOACTIVATE
+            };
+            let _ = SetWindowPos(
+                hwnd,
+                Some(HWND_TOPMOST),
+                rect.left,
+                rect.top,
+                rect.right - rect.left,
+                rect.bottom - rect.top,
+                flags,
+            );
+            eval(
+                hwnd,
+                "if(window.setCaptionMode) window.setCaptionMode(false);",
+            );
+        }
+    }
+
+    if let Ok(mut app) = APP.lock() {
+        if app.config.realtime_caption_mode != enabled {
+            app.config.realtime_caption_mode = enabled;
+            crate::config::save_config(&app.config);
+        }
+    }
+}
+
+pub unsafe fn toggle_caption_mode() {
+    set_caption_mode(!CAPTION_MODE.load(Ordering::SeqCst));
+}
@@ -0,0 +1,185 @@
+//! Dictation: the live transcript is typed into the focused app as it comes in
+//!
+//! What has been typed so far is kept as text. Every tick the transcript since
+//! dictation was turned on is rendered again (spoken punctuation commands applied) and
+//! compared with it; when the model revised earlier words, or a command only became
+//! one with its second word ("new" → "new line"), the typed tail is backspaced and
+//! typed again.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use super::state::{DICTATION_ENABLED, REALTIME_STATE, REALTIME_STOP_SIGNAL};
+
+/// How often the transcript is checked for new text to type
+const TICK: Duration = Duration::from_millis(150);
+
+/// Spoken commands and what they type. Longer phrases come before their prefixes.
+const COMMANDS: &[(&str, &str)] = &[
+    ("new paragraph", "\n\n"),
+    ("new line", "\n"),
+    ("question mark", "?"),
+    ("exclamation mark", "!"),
+    ("exclamation point", "!"),
+    ("full stop", "."),
+    ("period", "."),
+    ("comma", ","),
+    ("semicolon", ";"),
+    ("colon", ":"),
+    ("xuống dòng", "\n"),
+    ("dấu chấm hỏi", "?"),
+    ("dấu chấm than", "!"),
+    ("dấu chấm phẩy", ";"),
+    ("dấu hai chấm", ":"),
+    ("dấu chấm", "."),
+    ("dấu phẩy", ","),
+    ("새 문단", "\n\n"),
+    ("줄 바꿈", "\n"),
+    ("줄바꿈", "\n"),
+    ("물음표", "?"),
+    ("느낌표", "!"),
+    ("마침표", "."),
+    ("쉼표", ","),
+];
+
+struct Typist {
+    /// Byte offset in the transcript where dictation started
+    start: usize,
+    /// Text typed into the target app since then
+    typed: String,
+}
+
+lazy_static::lazy_static! {
+    static ref TYPIST: Mutex<Typist> = Mutex::new(Typist {
+        start: 0,
+        typed: String::new(),
+    });
+}
+
+/// Bumped per realtime session, so the typing thread of an earlier one stops
+static SESSION: AtomicU64 = AtomicU64::new(0);
+
+/// A realtime session started: type along if the preset dictates, restoring focus to
+/// the app that was active when the preset was triggered
+pub fn start_session(enabled: bool) {
+    let session = SESSION.fetch_add(1, Ordering::SeqCst) + 1;
+    DICTATION_ENABLED.store(enabled, Ordering::SeqCst);
+    begin_at_transcript_end();
+
+    if enabled {
+        let target = crate::APP
+            .lock()
+            .ok()
+            .and_then(|app| app.last_active_window);
+        if let Some(target) = target {
+            crate::overlay::utils::force_focus(target.0);
+        }
+    }
+
+    std::thread::spawn(move || {
+        while SESSION.load(Ordering::SeqCst) == session
+            && !REALTIME_STOP_SIGNAL.load(Ordering::SeqCst)
+        {
+            std::thread::sleep(TICK);
+            if DICTATION_ENABLED.load(Ordering::SeqCst) {
+                sync();
+            }
+        }
+    });
+}
+
+/// Turn dictation on or off mid-session; turning it on types only what is said from now
+pub fn set_enabled(enabled: bool) {
+    if enabled && !DICTATION_ENABLED.load(Ordering::SeqCst) {
+        begin_at_transcript_end();
+    }
+    DICTATION_ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+fn begin_at_transcript_end() {
+    let mut typist = TYPIST.lock().unwrap();
+    typist.start = REALTIME_STATE
+        .lock()
+        .map(|state| state.full_transcript.len())
+        .unwrap_or(0);
+    typist.typed.clear();
+}
+
+/// Bring the target app's text in line with the transcript
+fn sync() {
+    // Clicks on the overlay focus it; wait until the user is back in their app
+    if crate::overlay::utils::is_own_window_foreground() {
+        return;
+    }
+
+    let mut typist = TYPIST.lock().unwrap();
+    let Some(spoken) = REALTIME_STATE.lock().ok().and_then(|state| {
+        state
+            .full_transcript
+            .get(typist.start..)
+            .map(str::to_string)
+    }) else {
+        return;
+    };
+    let wanted = apply_commands(&spoken);
+
+    let common = typist
+        .typed
+        .chars()
+        .zip(wanted.chars())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let erase = typist.typed.chars().count() - common;
+    let insert: String = wanted.chars().skip(common).collect();
+    if erase == 0 && insert.is_empty() {
+        return;
+    }
+
+    crate::overlay::utils::type_text(erase, &insert);
+    typist.typed = wanted;
+}
+
+/// The transcript as it should be typed: words separated by single spaces, spoken
+/// commands replaced by their punctuation or line breaks
+fn apply_commands(spoken: &str) -> String {
+    let words: Vec<&str> = spoken.split_whitespace().collect();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < words.len() {
+        if let Some((len, typed)) = match_command(&words[i..]) {
+            // The transcriber may already have punctuated before a punctuation command
+            let mut kept = out.trim_end().len();
+            if !typed.starts_with('\n') {
+                kept = out[..kept]
+                    .trim_end_matches([',', '.', ';', ':', '?', '!'])
+                    .len();
+            }
+            out.truncate(kept);
+            out.push_str(typed);
+            i += len;
+            continue;
+        }
+        if !out.is_empty() && !out.ends_with('\n') {
+            out.push(' ');
+        }
+        out.push_str(words[i]);
+        i += 1;
+    }
+    out
+}
+
+/// Number of words and typed text of the command the words start with
+fn match_command(words: &[&str]) -> Option<(usize, &'static str)> {
+    COMMANDS.iter().find_map(|(phrase, typed)| {
+        let phrase_words: Vec<&str> = phrase.split(' ').collect();
+        if words.len() < phrase_words.len() {
+            return None;
+        }
+        let matches = phrase_words.iter().zip(words).all(|(expected, word)| {
+            let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+            word.to_lowercase() == *expected
+        });
+        matches.then_some((phrase_words.len(), *typed))
+    })
+}
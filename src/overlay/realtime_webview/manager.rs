@@ -3,9 +3,9 @@
 use super::state::*;
 use super::webview::*;
 use super::wndproc::*;
-use crate::api::realtime_audio::{start_realtime_transcription, RealtimeState};
+use crate::api::realtime_audio::{start_realtime_transcription, RealtimeState, WM_EXEC_SCRIPT};
 use crate::APP;
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicBool, Ordering};
 use windows::core::w;
 use windows::Win32::Foundation::*;
 use windows::Win32::Graphics::Dwm::{
@@ -48,9 +48,35 @@ pub fn stop_realtime_overlay() {
     }
 }
 
+/// Run `script` in both realtime WebViews (from any thread)
+pub fn run_script(script: &str) {
+    unsafe {
+        for hwnd in [
+            std::ptr::addr_of!(REALTIME_HWND).read(),
+            std::ptr::addr_of!(TRANSLATION_HWND).read(),
+        ] {
+            if hwnd.is_invalid() {
+                continue;
+            }
+            let ptr = Box::into_raw(Box::new(script.to_string()));
+            if PostMessageW(Some(hwnd), WM_EXEC_SCRIPT, WPARAM(0), LPARAM(ptr as isize)).is_err() {
+                drop(Box::from_raw(ptr));
+            }
+        }
+    }
+}
+
+/// Set from `warmup()` until the window thread exits, so repeated calls don't start a
+/// second pair of windows
+static WINDOW_THREAD_RUNNING: AtomicBool = AtomicBool::new(false);
+
 pub fn warmup() {
+    if WINDOW_THREAD_RUNNING.swap(true, Ordering::SeqCst) {
+        return;
+    }
     std::thread::spawn(|| unsafe {
         internal_create_realtime_loop();
+        WINDOW_THREAD_RUNNING.store(false, Ordering::SeqCst);
     });
 }
 
@@ -58,10 +84,31 @@ pub fn show_realtime_overlay(preset_idx: usize) {
     unsafe {
         // Check if warmed up
         if !IS_WARMED_UP {
+            // Not created yet (or dropped on hide in lazy mode): create it now
+            warmup();
+
             // Show localized message that feature is not ready yet
             let ui_lang = crate::APP.lock().unwrap().config.ui_language.clone();
             let locale = crate::gui::locale::LocaleText::get(&ui_lang);
             crate::overlay::auto_copy_badge::show_notification(locale.live_translate_loading);
+
+            // Start once the windows are ready
+            std::thread::spawn(move || {
+                for _ in 0..50 {
+                    std::thread::sleep(std::time::Duration::from_millis(100));
+                    // SAFETY: same unsynchronized flag reads as the rest of this module
+                    let hwnd = std::ptr::addr_of!(REALTIME_HWND).read();
+                    if std::ptr::addr_of!(IS_WARMED_UP).read() && !hwnd.is_invalid() {
+                        let _ = PostMessageW(
+                            Some(hwnd),
+                            WM_APP_REALTIME_START,
+                            WPARAM(preset_idx),
+                            LPARAM(0),
+                        );
+                        return;
+                    }
+                }
+            });
             return;
         }
 
@@ -192,6 +239,7 @@ unsafe fn internal_create_realtime_loop() {
     destroy_realtime_webview(REALTIME_HWND);
     destroy_realtime_webview(TRANSLATION_HWND);
     IS_ACTIVE = false;
+    IS_WARMED_UP = false;
     REALTIME_HWND = HWND::default();
     TRANSLATION_HWND = HWND::default();
 }
@@ -245,6 +293,7 @@ unsafe fn handle_start_overlay(preset_idx: usize) {
         let mut state = REALTIME_STATE.lock().unwrap();
         *state = RealtimeState::new();
     }
+    super::caption::reset();
 
     // Fetch config
     let (
@@ -255,6 +304,7 @@ unsafe fn handle_start_overlay(preset_idx: usize) {
         config_transcription_model,
         trans_size,
         transcription_size,
+        caption_mode,
     ) = {
         let app = APP.lock().unwrap();
         (
@@ -265,11 +315,14 @@ unsafe fn handle_start_overlay(preset_idx: usize) {
             app.config.realtime_transcription_model.clone(),
             app.config.realtime_translation_size,
             app.config.realtime_transcription_size,
+            app.config.realtime_caption_mode,
         )
     };
 
-    // Default to "device" if no audio source is saved
-    let effective_audio_source = if config_audio_source.is_empty() {
+    // Default to "device" if no audio source is saved; dictation is always the user's voice
+    let effective_audio_source = if preset.realtime_dictation {
+        "mic".to_string()
+    } else if config_audio_source.is_empty() {
         "device".to_string()
     } else {
         config_audio_source.clone()
@@ -378,6 +431,17 @@ unsafe fn handle_start_overlay(preset_idx: usize) {
     // Sync visibility state to webviews (fixes toggled->hidden state on re-show)
     sync_visibility_to_webviews();
 
+    super::caption::register_hotkey(REALTIME_HWND);
+    if caption_mode {
+        super::caption::set_caption_mode(true);
+    }
+
+    super::dictation::start_session(preset.realtime_dictation);
+    run_script(&format!(
+        "if(window.setDictation) window.setDictation({});",
+        preset.realtime_dictation
+    ));
+
     // Start transcription
     let trans_hwnd_opt = if has_translation {
         Some(TRANSLATION_HWND)
@@ -49,9 +49,9 @@ lazy_static::lazy_static! {
     pub static ref REALTIME_TTS_ENABLED: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
     /// TTS playback speed (100 = 1.0x, 50 = 0.5x, 150 = 1.5x, etc.)
     pub static ref REALTIME_TTS_SPEED: Arc<std::sync::atomic::AtomicU32> = Arc::new(std::sync::atomic::AtomicU32::new(100));
-    /// Auto-speed mode: automatically adjust speed based on queue length
+    /// Auto-speed mode: keep pace with the incoming translation (see `tts_pacing`)
     pub static ref REALTIME_TTS_AUTO_SPEED: Arc<AtomicBool> = Arc::new(AtomicBool::new(true));
-    /// Queue of committed translation text segments to speak
+    /// Committed translation segments queued for TTS and not yet played
     pub static ref COMMITTED_TRANSLATION_QUEUE: Mutex<std::collections::VecDeque<String>> = Mutex::new(std::collections::VecDeque::new());
 
     // --- Window Handle for App Selection ---
@@ -62,6 +62,13 @@ lazy_static::lazy_static! {
     pub static ref CURRENT_TTS_SPEED: Arc<std::sync::atomic::AtomicU32> = Arc::new(std::sync::atomic::AtomicU32::new(100));
     /// Signal to close TTS modal (shared between app selection and main window)
     pub static ref CLOSE_TTS_MODAL_REQUEST: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+
+    /// Label speaker turns in the transcription window (mirrors `realtime_speaker_labels`)
+    pub static ref REALTIME_SPEAKER_LABELS: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+    /// Live text is shown in the bottom caption bar instead of the floating windows
+    pub static ref CAPTION_MODE: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+    /// The transcript is typed into the focused app (see `dictation`)
+    pub static ref DICTATION_ENABLED: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
 }
 
 pub static mut REALTIME_HWND: HWND = HWND(std::ptr::null_mut());
@@ -0,0 +1,159 @@
+//! Auto speed for realtime TTS
+//!
+//! Committed translation arrives at the pace of the person speaking. Read at a
+//! fixed speed it either falls behind during fast passages or lags for good. The
+//! pacer tracks how fast text arrives (characters per second over the last half
+//! minute) and how fast the voice reads at 1.0x, and picks the speed that keeps up
+//! with the arrivals while working off what is still queued.
+
+use std::collections::VecDeque;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use windows::Win32::Foundation::{LPARAM, WPARAM};
+use windows::Win32::UI::WindowsAndMessaging::PostMessageW;
+
+use super::state::{
+    COMMITTED_TRANSLATION_QUEUE, CURRENT_TTS_SPEED, REALTIME_HWND, REALTIME_TTS_AUTO_SPEED,
+    REALTIME_TTS_SPEED, TRANSLATION_HWND, WM_UPDATE_TTS_SPEED,
+};
+
+/// How far back the arrival rate looks
+const ARRIVAL_WINDOW: Duration = Duration::from_secs(30);
+/// Shortest span the arrival rate is averaged over, so the first segment of a
+/// session doesn't read as a burst
+const MIN_RATE_SPAN: Duration = Duration::from_secs(5);
+/// Text still queued should be read within this many seconds, on top of new text
+const CATCH_UP_SECS: f64 = 6.0;
+/// Characters a voice reads per second at 1.0x until a segment has been measured
+const DEFAULT_CHARS_PER_SEC: f64 = 14.0;
+/// Fastest auto speed in percent; faster than this speech gets hard to follow
+const AUTO_MAX_SPEED: u32 = 250;
+/// Largest speed change per audio chunk, so the speed glides instead of jumping
+const MAX_STEP: u32 = 5;
+
+struct Pacer {
+    /// When each queued segment arrived and its length in characters
+    arrivals: VecDeque<(Instant, usize)>,
+    /// Measured reading rate of the voice at 1.0x
+    chars_per_sec: f64,
+}
+
+impl Pacer {
+    fn forget_old(&mut self, now: Instant) {
+        while let Some(&(at, _)) = self.arrivals.front() {
+            if now.duration_since(at) <= ARRIVAL_WINDOW {
+                break;
+            }
+            self.arrivals.pop_front();
+        }
+    }
+
+    /// Characters per second arriving, averaged over the arrival window
+    fn arrival_rate(&self, now: Instant) -> f64 {
+        let Some(&(first, _)) = self.arrivals.front() else {
+            return 0.0;
+        };
+        let chars: usize = self.arrivals.iter().map(|(_, n)| n).sum();
+        let span = now.duration_since(first).max(MIN_RATE_SPAN);
+        chars as f64 / span.as_secs_f64()
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref PACER: Mutex<Pacer> = Mutex::new(Pacer {
+        arrivals: VecDeque::new(),
+        chars_per_sec: DEFAULT_CHARS_PER_SEC,
+    });
+}
+
+/// Queue a committed segment for reading and note when it arrived
+pub fn queue_segment(text: &str) {
+    if let Ok(mut queue) = COMMITTED_TRANSLATION_QUEUE.lock() {
+        queue.push_back(text.to_string());
+    }
+    let now = Instant::now();
+    let mut pacer = PACER.lock().unwrap();
+    pacer.forget_old(now);
+    pacer.arrivals.push_back((now, text.trim().chars().count()));
+}
+
+/// The oldest queued segment finished playing after `samples` of unstretched audio
+/// at `sample_rate`: drop it from the queue and refine the voice's reading rate
+pub fn segment_played(samples: usize, sample_rate: u32) {
+    let Some(text) = COMMITTED_TRANSLATION_QUEUE
+        .lock()
+        .ok()
+        .and_then(|mut queue| queue.pop_front())
+    else {
+        return;
+    };
+    let secs = samples as f64 / sample_rate as f64;
+    let chars = text.trim().chars().count();
+    // Very short segments are mostly lead-in silence and would skew the rate
+    if secs >= 1.0 && chars > 0 {
+        let mut pacer = PACER.lock().unwrap();
+        pacer.chars_per_sec = pacer.chars_per_sec * 0.7 + (chars as f64 / secs) * 0.3;
+    }
+}
+
+/// Speed in percent for the next audio chunk with auto speed on: fast enough to
+/// keep up with arriving text and read the queue within a few seconds, never below
+/// `base_speed` (the slider), and at most a few percent away from `current`
+pub fn auto_speed(base_speed: u32, current: u32) -> u32 {
+    let backlog: usize = COMMITTED_TRANSLATION_QUEUE
+        .lock()
+        .map(|queue| queue.iter().map(|s| s.trim().chars().count()).sum())
+        .unwrap_or(0);
+    let now = Instant::now();
+    let (arrival_rate, chars_per_sec) = {
+        let mut pacer = PACER.lock().unwrap();
+        pacer.forget_old(now);
+        (pacer.arrival_rate(now), pacer.chars_per_sec)
+    };
+
+    let needed = (arrival_rate + backlog as f64 / CATCH_UP_SECS) / chars_per_sec;
+    let target =
+        ((needed * 100.0).round() as u32).clamp(base_speed, AUTO_MAX_SPEED.max(base_speed));
+    if target > current {
+        (current + MAX_STEP).min(target)
+    } else {
+        current.saturating_sub(MAX_STEP).max(target)
+    }
+}
+
+/// Speed in percent for the next chunk of realtime speech: the slider, or the auto
+/// speed when that is on. A change is shown in the realtime windows.
+pub fn playback_speed() -> u32 {
+    let base_speed = REALTIME_TTS_SPEED.load(Ordering::Relaxed);
+    let auto_enabled = REALTIME_TTS_AUTO_SPEED.load(Ordering::Relaxed);
+    let old_speed = CURRENT_TTS_SPEED.load(Ordering::Relaxed);
+
+    // Auto speed: keep pace with the incoming translation
+    let speed = if auto_enabled {
+        auto_speed(base_speed, old_speed)
+    } else {
+        base_speed
+    };
+
+    // Update current speed for UI if it changed
+    CURRENT_TTS_SPEED.store(speed, Ordering::Relaxed);
+    if old_speed != speed {
+        unsafe {
+            for hwnd in [
+                std::ptr::addr_of!(REALTIME_HWND).read(),
+                std::ptr::addr_of!(TRANSLATION_HWND).read(),
+            ] {
+                if !hwnd.is_invalid() {
+                    let _ = PostMessageW(
+                        Some(hwnd),
+                        WM_UPDATE_TTS_SPEED,
+                        WPARAM(speed as usize),
+                        LPARAM(0),
+                    );
+                }
+            }
+        }
+    }
+    speed
+}
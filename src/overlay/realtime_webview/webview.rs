@@ -31,11 +31,19 @@ pub fn create_realtime_webview(
     let languages = get_all_languages();
 
     // Fetch locale text
-    let locale_text = {
+    let (locale_text, speaker_labels, caption_hotkey, tts_speed) = {
         let app = APP.lock().unwrap();
         let lang = app.config.ui_language.clone();
-        LocaleText::get(&lang)
+        (
+            LocaleText::get(&lang),
+            app.config.realtime_speaker_labels,
+            app.config.realtime_caption_hotkey.name.clone(),
+            app.config.tts_playback_speed,
+        )
     };
+    REALTIME_SPEAKER_LABELS.store(speaker_labels, Ordering::SeqCst);
+    REALTIME_TTS_SPEED.store(tts_speed, Ordering::SeqCst);
+    CURRENT_TTS_SPEED.store(tts_speed, Ordering::SeqCst);
 
     let html = get_realtime_html(
         is_translation,
@@ -45,6 +53,9 @@ pub fn create_realtime_webview(
         translation_model,
         transcription_model,
         font_size,
+        speaker_labels,
+        &caption_hotkey,
+        tts_speed,
         &locale_text,
     );
     let wrapper = HwndWrapper(hwnd);
@@ -211,6 +222,23 @@ pub fn create_realtime_webview(
                             LPARAM(ptr as isize),
                         );
                     }
+                } else if body == "copyBilingual" {
+                    // Long-press on copy: transcript and translation interleaved
+                    let text = REALTIME_STATE
+                        .lock()
+                        .map(|state| state.bilingual_text())
+                        .unwrap_or_default();
+                    if !text.is_empty() {
+                        let ptr = Box::into_raw(Box::new(text));
+                        unsafe {
+                            let _ = PostMessageW(
+                                Some(hwnd_for_ipc),
+                                WM_COPY_TEXT,
+                                WPARAM(0),
+                                LPARAM(ptr as isize),
+                            );
+                        }
+                    }
                 } else if body == "close" {
                     unsafe {
                         let _ = PostMessageW(Some(hwnd_for_ipc), WM_CLOSE, WPARAM(0), LPARAM(0));
@@ -452,16 +480,57 @@ pub fn create_realtime_webview(
                         }
                     }
                 } else if body.starts_with("ttsSpeed:") {
-                    // TTS playback speed adjustment (50-200, where 100 = 1.0x)
+                    // TTS playback speed adjustment (25-300, where 100 = 1.0x)
                     if let Ok(speed) = body[9..].parse::<u32>() {
+                        let speed = speed.clamp(
+                            crate::api::tts::MIN_PLAYBACK_SPEED,
+                            crate::api::tts::MAX_PLAYBACK_SPEED,
+                        );
                         REALTIME_TTS_SPEED.store(speed, Ordering::SeqCst);
                         // Turn off auto-speed when user manually adjusts slider
                         REALTIME_TTS_AUTO_SPEED.store(false, Ordering::SeqCst);
                     }
+                } else if body.starts_with("ttsPause:") {
+                    // Pause/resume what is being read; new translations keep queueing
+                    if &body[9..] == "1" {
+                        crate::api::tts::TTS_MANAGER.pause();
+                    } else {
+                        crate::api::tts::TTS_MANAGER.resume();
+                    }
+                } else if body == "ttsStop" {
+                    // Drop everything not yet read; TTS stays on for new translations
+                    crate::api::tts::TTS_MANAGER.stop();
+                    if let Ok(mut queue) = COMMITTED_TRANSLATION_QUEUE.lock() {
+                        queue.clear();
+                    }
                 } else if body.starts_with("ttsAutoSpeed:") {
                     // TTS auto-speed toggle
                     let enabled = &body[13..] == "1";
                     REALTIME_TTS_AUTO_SPEED.store(enabled, Ordering::SeqCst);
+                } else if body == "captionMode" {
+                    // Caption bar button / double-click on the bar
+                    unsafe {
+                        super::caption::toggle_caption_mode();
+                    }
+                } else if body.starts_with("dictation:") {
+                    // Dictation toggle in the transcription header
+                    super::dictation::set_enabled(&body[10..] == "1");
+                } else if body.starts_with("speakerLabels:") {
+                    // Speaker labels toggle in the transcription header
+                    let enabled = &body[14..] == "1";
+                    REALTIME_SPEAKER_LABELS.store(enabled, Ordering::SeqCst);
+                    if let Ok(mut app) = APP.lock() {
+                        app.config.realtime_speaker_labels = enabled;
+                        crate::config::save_config(&app.config);
+                    }
+                    unsafe {
+                        let _ = PostMessageW(
+                            Some(hwnd_for_ipc),
+                            WM_REALTIME_UPDATE,
+                            WPARAM(0),
+                            LPARAM(0),
+                        );
+                    }
                 } else if body == "cancelDownload" {
                     // Cancel Parakeet download and revert to Gemini
                     crate::api::realtime_audio::cancel_download_and_revert_to_gemini();
@@ -471,12 +540,69 @@ pub fn create_realtime_webview(
     });
 
     if let Ok(webview) = result {
+        crate::overlay::webview_recovery::watch(&webview, hwnd);
         REALTIME_WEBVIEWS.with(|wvs| {
             wvs.borrow_mut().insert(hwnd_key, webview);
         });
     }
 }
 
+/// Replace a WebView whose process died with a new one showing the current settings,
+/// visibility and text
+pub fn recover_realtime_webview(hwnd: HWND) {
+    let is_translation = unsafe { hwnd == std::ptr::addr_of!(TRANSLATION_HWND).read() };
+    destroy_realtime_webview(hwnd);
+
+    let (font_size, config_source, config_language, translation_model, transcription_model) = {
+        let app = APP.lock().unwrap();
+        (
+            app.config.realtime_font_size,
+            app.config.realtime_audio_source.clone(),
+            app.config.realtime_target_language.clone(),
+            app.config.realtime_translation_model.clone(),
+            app.config.realtime_transcription_model.clone(),
+        )
+    };
+    // Prefer what the running session switched to over the saved config
+    let audio_source = if is_translation {
+        "mic".to_string()
+    } else {
+        NEW_AUDIO_SOURCE
+            .lock()
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.clone())
+            .unwrap_or(config_source)
+    };
+    let language = NEW_TARGET_LANGUAGE
+        .lock()
+        .ok()
+        .filter(|l| !l.is_empty())
+        .map(|l| l.clone())
+        .unwrap_or(config_language);
+
+    create_realtime_webview(
+        hwnd,
+        is_translation,
+        &audio_source,
+        &language,
+        &translation_model,
+        &transcription_model,
+        font_size,
+    );
+    sync_visibility_to_webviews();
+
+    // The update handlers re-send the whole transcript / translation from the state
+    let update_msg = if is_translation {
+        WM_TRANSLATION_UPDATE
+    } else {
+        WM_REALTIME_UPDATE
+    };
+    unsafe {
+        let _ = PostMessageW(Some(hwnd), update_msg, WPARAM(0), LPARAM(0));
+    }
+}
+
 pub fn destroy_realtime_webview(hwnd: HWND) {
     let hwnd_key = hwnd.0 as isize;
     REALTIME_WEBVIEWS.with(|wvs| {
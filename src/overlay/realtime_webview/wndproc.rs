@@ -1,12 +1,15 @@
 //! Window procedures for realtime overlay windows
 
 use super::state::*;
-use super::webview::update_webview_text;
+use super::webview::{recover_realtime_webview, update_webview_text};
 use crate::api::realtime_audio::{
     REALTIME_RMS, WM_COPY_TEXT, WM_DOWNLOAD_PROGRESS, WM_EXEC_SCRIPT, WM_MODEL_SWITCH,
     WM_REALTIME_UPDATE, WM_START_DRAG, WM_TOGGLE_MIC, WM_TOGGLE_TRANS, WM_TRANSLATION_UPDATE,
     WM_UPDATE_TTS_SPEED, WM_VOLUME_UPDATE,
 };
+use crate::gui::locale::LocaleText;
+use crate::overlay::webview_recovery::{show_recovering_notice, WM_APP_WEBVIEW_CRASHED};
+use crate::APP;
 use std::sync::atomic::Ordering;
 use windows::Win32::Foundation::*;
 use windows::Win32::UI::Input::KeyboardAndMouse::ReleaseCapture;
@@ -34,6 +37,10 @@ pub unsafe extern "system" fn realtime_wnd_proc(
             MIC_VISIBLE.store(val, Ordering::SeqCst);
             LRESULT(0)
         }
+        WM_HOTKEY if wparam.0 as i32 == super::caption::CAPTION_HOTKEY_ID => {
+            super::caption::toggle_caption_mode();
+            LRESULT(0)
+        }
         WM_TOGGLE_TRANS => {
             let val = wparam.0 != 0;
             TRANS_VISIBLE.store(val, Ordering::SeqCst);
@@ -76,22 +83,17 @@ pub unsafe extern "system" fn realtime_wnd_proc(
             }
 
             // Get old (committed) and new (current sentence) text from state
+            let speaker_label = if REALTIME_SPEAKER_LABELS.load(Ordering::SeqCst) {
+                let lang = APP.lock().unwrap().config.ui_language.clone();
+                Some(LocaleText::get(&lang).realtime_speaker_label)
+            } else {
+                None
+            };
             let (old_text, new_text) = {
                 if let Ok(state) = REALTIME_STATE.lock() {
                     // Everything before last_committed_pos is "old"
                     // Everything after is "new" (current sentence)
-                    let full = &state.full_transcript;
-                    let pos = state.last_committed_pos.min(full.len());
-                    let old_raw = &full[..pos];
-                    let new_raw = &full[pos..];
-
-                    let old = old_raw.trim_end();
-                    let new = new_raw.trim_start();
-                    if !old.is_empty() && !new.is_empty() {
-                        (old.to_string(), format!(" {}", new))
-                    } else {
-                        (old.to_string(), new.to_string())
-                    }
+                    state.transcript_display_parts(speaker_label)
                 } else {
                     (String::new(), String::new())
                 }
@@ -144,7 +146,11 @@ pub unsafe extern "system" fn realtime_wnd_proc(
             let rms = f32::from_bits(rms_bits);
 
             let hwnd_key = hwnd.0 as isize;
-            let script = format!("if(window.updateVolume) window.updateVolume({});", rms);
+            let silent = crate::api::realtime_audio::vad::is_silent();
+            let script = format!(
+                "if(window.updateVolume) window.updateVolume({}, {});",
+                rms, silent
+            );
 
             REALTIME_WEBVIEWS.with(|wvs| {
                 if let Some(webview) = wvs.borrow().get(&hwnd_key) {
@@ -185,6 +191,11 @@ pub unsafe extern "system" fn realtime_wnd_proc(
             });
             LRESULT(0)
         }
+        WM_APP_WEBVIEW_CRASHED => {
+            show_recovering_notice();
+            recover_realtime_webview(hwnd);
+            LRESULT(0)
+        }
         WM_CLOSE => {
             let _ = PostMessageW(Some(hwnd), WM_APP_REALTIME_HIDE, WPARAM(0), LPARAM(0));
             LRESULT(0)
@@ -207,6 +218,7 @@ pub unsafe extern "system" fn realtime_wnd_proc(
             // Stop transcription and TTS
             REALTIME_STOP_SIGNAL.store(true, Ordering::SeqCst);
             crate::api::tts::TTS_MANAGER.stop();
+            super::caption::unregister_hotkey(hwnd);
 
             // Hide windows
             let _ = ShowWindow(hwnd, SW_HIDE);
@@ -217,6 +229,11 @@ pub unsafe extern "system" fn realtime_wnd_proc(
             // Reset active state so it can be shown again
             IS_ACTIVE = false;
 
+            // Lazy mode: free the WebViews; the next show rebuilds both windows
+            if crate::overlay::webview_lifecycle::is_lazy() {
+                let _ = DestroyWindow(hwnd);
+            }
+
             LRESULT(0)
         }
 
@@ -247,6 +264,20 @@ pub unsafe extern "system" fn translation_wnd_proc(
             }
             LRESULT(0)
         }
+        WM_EXEC_SCRIPT => {
+            let ptr = lparam.0 as *mut String;
+            if !ptr.is_null() {
+                let script_box = Box::from_raw(ptr);
+                let script = *script_box;
+                let hwnd_key = hwnd.0 as isize;
+                REALTIME_WEBVIEWS.with(|wvs| {
+                    if let Some(webview) = wvs.borrow().get(&hwnd_key) {
+                        let _ = webview.evaluate_script(&script);
+                    }
+                });
+            }
+            LRESULT(0)
+        }
         WM_TRANSLATION_UPDATE => {
             // Check if we need to close the modal (flag set by app selection)
             if CLOSE_TTS_MODAL_REQUEST.load(Ordering::SeqCst) {
@@ -315,16 +346,19 @@ pub unsafe extern "system" fn translation_wnd_proc(
 
                     // Only queue non-empty, non-whitespace segments
                     if !new_committed.trim().is_empty() {
-                        // Queue this text for TTS
-                        if let Ok(mut queue) = COMMITTED_TRANSLATION_QUEUE.lock() {
-                            queue.push_back(new_committed.clone());
-                        }
+                        // Queue this text for TTS (auto speed paces by the queue)
+                        super::tts_pacing::queue_segment(&new_committed);
 
                         // Speak using TTS manager (non-blocking)
                         // This uses the existing parallel TTS infrastructure
                         let hwnd_val = hwnd.0 as isize;
                         std::thread::spawn(move || {
-                            crate::api::tts::TTS_MANAGER.speak_realtime(&new_committed, hwnd_val);
+                            let config = crate::APP.lock().unwrap().config.clone();
+                            crate::api::tts::TTS_MANAGER.speak_realtime(
+                                &new_committed,
+                                hwnd_val,
+                                &config,
+                            );
                         });
                     }
 
@@ -389,6 +423,12 @@ pub unsafe extern "system" fn translation_wnd_proc(
             LRESULT(0)
         }
 
+        WM_APP_WEBVIEW_CRASHED => {
+            show_recovering_notice();
+            recover_realtime_webview(hwnd);
+            LRESULT(0)
+        }
+
         WM_CLOSE => {
             let _ = PostMessageW(
                 Some(REALTIME_HWND),
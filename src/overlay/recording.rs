@@ -39,7 +39,9 @@ pub fn update_audio_viz(rms: f32) {
 // --- STATE MANAGEMENT ---
 // 0=Not Created, 1=Hidden/Warmup, 2=Visible/Recording
 static RECORDING_STATE: AtomicI32 = AtomicI32::new(0);
-static RECORDING_HWND_VAL: AtomicIsize = AtomicIsize::new(0);
+pub(crate) static RECORDING_HWND_VAL: AtomicIsize = AtomicIsize::new(0);
+/// The Esc keyboard hook lives as long as the recording window's thread
+pub(crate) static ESC_HOOK_INSTALLED: AtomicBool = AtomicBool::new(false);
 static REGISTER_RECORDING_CLASS: Once = Once::new();
 static LAST_THEME_IS_DARK: AtomicBool = AtomicBool::new(true);
 
@@ -293,7 +295,7 @@ fn internal_create_recording_window() {
             let (ui_width, ui_height) = get_ui_dimensions();
             builder
                 .with_bounds(Rect {
-                    position: wry::dpi::Position::Logical(wry::dpi::LogicalPosition::new(0.0, 0.0)),
+                    position: wry::dpi::Position::Physical(wry::dpi::PhysicalPosition::new(0, 0)),
                     size: wry::dpi::Size::Physical(wry::dpi::PhysicalSize::new(
                         ui_width as u32,
                         ui_height as u32,
@@ -348,6 +350,7 @@ fn internal_create_recording_window() {
                 Some(GetModuleHandleW(None).unwrap().into()),
                 0,
             );
+            ESC_HOOK_INSTALLED.store(hook.is_ok(), Ordering::SeqCst);
 
             // Message Loop
             let mut msg = MSG::default();
@@ -359,6 +362,7 @@ fn internal_create_recording_window() {
             if let Ok(h) = hook {
                 let _ = UnhookWindowsHookEx(h);
             }
+            ESC_HOOK_INSTALLED.store(false, Ordering::SeqCst);
         }
 
         // Cleanup on FULL EXIT
@@ -370,7 +374,14 @@ fn internal_create_recording_window() {
 }
 
 fn start_audio_thread(hwnd: HWND, preset_idx: usize) {
-    let preset = APP.lock().unwrap().config.presets[preset_idx].clone();
+    let mut preset = APP.lock().unwrap().config.presets[preset_idx].clone();
+    crate::overlay::process::apply_secondary_language_run(&mut preset);
+    if preset.pronunciation_practice {
+        use crate::overlay::process::pronunciation;
+        let target =
+            pronunciation::take_retry(&preset.id).or_else(pronunciation::target_from_clipboard);
+        pronunciation::arm(&preset.id, target);
+    }
     let hwnd_val = hwnd.0 as usize;
 
     std::thread::spawn(move || {
@@ -433,7 +444,7 @@ unsafe extern "system" fn recording_wnd_proc(
             } else if wparam.0 == 99 {
                 // FALLBACK TIMER (IPC timed out)
                 let _ = KillTimer(Some(hwnd), 99);
-                println!("Warning: Recording overlay IPC timed out, forcing show");
+                log::warn!("Recording overlay IPC timed out, forcing show");
                 let _ = PostMessageW(Some(hwnd), WM_APP_REAL_SHOW, WPARAM(0), LPARAM(0));
             } else if wparam.0 == 1 {
                 // VIZ UPDATE TIMER
@@ -0,0 +1,241 @@
+//! Arena comparison page: two answers to the same input, side by side.
+//!
+//! The worker threads fill the columns; the page itself is rendered on the window
+//! thread by `markdown_view` whenever the window text changes.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use windows::Win32::Foundation::HWND;
+
+use super::markdown_view::{html_text_direction, render_markdown_fragment, wrap_markdown_document};
+
+lazy_static::lazy_static! {
+    static ref ARENAS: Mutex<HashMap<isize, Arena>> = Mutex::new(HashMap::new());
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ColumnState {
+    Streaming,
+    Done { elapsed_ms: u64 },
+    Failed,
+}
+
+struct ArenaColumn {
+    label: String,
+    text: String,
+    state: ColumnState,
+}
+
+struct Arena {
+    columns: [ArenaColumn; 2],
+    picked: Option<usize>,
+}
+
+const ARENA_CSS: &str = r#"
+    body { padding: 0; overflow: hidden; }
+    .arena { display: flex; height: 100vh; }
+    .column { flex: 1 1 0; min-width: 0; display: flex; flex-direction: column; }
+    .column + .column { border-left: 1px solid #333; }
+    .column.picked { background: #1f2a1f; }
+    .header {
+        display: flex; align-items: center; gap: 6px;
+        padding: 6px 8px; border-bottom: 1px solid #2a2a2a;
+        font-size: 12px; color: #81d4fa; white-space: nowrap;
+    }
+    .label { overflow: hidden; text-overflow: ellipsis; }
+    .badge { font-size: 11px; color: #888; }
+    .badge.faster { color: #ffb74d; }
+    .pick {
+        margin-left: auto; font-size: 11px; padding: 2px 8px;
+        border: 1px solid #4fc3f7; border-radius: 10px;
+        background: transparent; color: #4fc3f7; cursor: pointer;
+    }
+    .pick:hover { background: #4fc3f7; color: #1a1a1a; }
+    .picked .pick { border-color: #66bb6a; color: #66bb6a; }
+    .body { flex: 1; overflow-y: auto; padding: 8px; }
+    .body > *:first-child { margin-top: 0; }
+    .failed .body { color: #ef9a9a; }
+"#;
+
+/// Turn `parent_hwnd` into an arena window with one (empty) column per label
+pub fn start_arena(parent_hwnd: HWND, labels: [String; 2]) {
+    let [left, right] = labels;
+    let column = |label: String| ArenaColumn {
+        label,
+        text: String::new(),
+        state: ColumnState::Streaming,
+    };
+    ARENAS.lock().unwrap().insert(
+        parent_hwnd.0 as isize,
+        Arena {
+            columns: [column(left), column(right)],
+            picked: None,
+        },
+    );
+}
+
+/// Replace the (partial) text of a column that is still streaming
+pub fn update_column(parent_hwnd: HWND, index: usize, text: &str) {
+    if let Some(arena) = ARENAS.lock().unwrap().get_mut(&(parent_hwnd.0 as isize)) {
+        if let Some(column) = arena.columns.get_mut(index) {
+            column.text = text.to_string();
+        }
+    }
+}
+
+/// Mark a column as finished: `Ok` with the final answer, `Err` with the error message
+pub fn finish_column(
+    parent_hwnd: HWND,
+    index: usize,
+    result: Result<String, String>,
+    elapsed_ms: u64,
+) {
+    if let Some(arena) = ARENAS.lock().unwrap().get_mut(&(parent_hwnd.0 as isize)) {
+        if let Some(column) = arena.columns.get_mut(index) {
+            match result {
+                Ok(text) => {
+                    column.text = text;
+                    column.state = ColumnState::Done { elapsed_ms };
+                }
+                Err(message) => {
+                    column.text = message;
+                    column.state = ColumnState::Failed;
+                }
+            }
+        }
+    }
+}
+
+/// Pick a finished column. Returns its text, or None if it can't be picked (yet).
+pub fn pick(parent_hwnd: HWND, index: usize) -> Option<String> {
+    let mut arenas = ARENAS.lock().unwrap();
+    let arena = arenas.get_mut(&(parent_hwnd.0 as isize))?;
+    let column = arena.columns.get(index)?;
+    if !matches!(column.state, ColumnState::Done { .. }) {
+        return None;
+    }
+    let text = column.text.clone();
+    arena.picked = Some(index);
+    Some(text)
+}
+
+/// Text for the window itself (copy button, refine, history): the picked answer,
+/// or both answers while nothing is picked
+pub fn window_text(parent_hwnd: HWND) -> String {
+    let arenas = ARENAS.lock().unwrap();
+    let Some(arena) = arenas.get(&(parent_hwnd.0 as isize)) else {
+        return String::new();
+    };
+    if let Some(picked) = arena.picked {
+        return arena.columns[picked].text.clone();
+    }
+    arena
+        .columns
+        .iter()
+        .map(|c| format!("{}\n{}", c.label, c.text))
+        .collect::<Vec<_>>()
+        .join("\n\n---\n\n")
+}
+
+/// Leave arena mode (window closed or its text refined into a single answer)
+pub fn end_arena(parent_hwnd: HWND) {
+    ARENAS.lock().unwrap().remove(&(parent_hwnd.0 as isize));
+}
+
+pub fn is_arena(parent_hwnd: HWND) -> bool {
+    ARENAS
+        .lock()
+        .unwrap()
+        .contains_key(&(parent_hwnd.0 as isize))
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The full arena page, or None if the window isn't an arena.
+/// Uses inline handlers only (no <script>), so updates can go through document.write.
+pub(super) fn arena_html(hwnd_key: isize) -> Option<String> {
+    let arenas = ARENAS.lock().unwrap();
+    let arena = arenas.get(&hwnd_key)?;
+
+    let locale = {
+        let lang = crate::APP.lock().unwrap().config.ui_language.clone();
+        crate::gui::locale::LocaleText::get(&lang)
+    };
+
+    // The first column to finish successfully is the faster one
+    let fastest = arena
+        .columns
+        .iter()
+        .enumerate()
+        .filter_map(|(i, c)| match c.state {
+            ColumnState::Done { elapsed_ms } => Some((i, elapsed_ms)),
+            _ => None,
+        })
+        .min_by_key(|(_, ms)| *ms)
+        .map(|(i, _)| i);
+
+    let columns: String = arena
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| {
+            let is_picked = arena.picked == Some(i);
+            let class = match column.state {
+                ColumnState::Failed => "column failed",
+                _ if is_picked => "column picked",
+                _ => "column",
+            };
+            let badge = match column.state {
+                ColumnState::Streaming => r#"<span class="badge">…</span>"#.to_string(),
+                ColumnState::Failed => r#"<span class="badge">✕</span>"#.to_string(),
+                ColumnState::Done { elapsed_ms } if fastest == Some(i) => format!(
+                    r#"<span class="badge faster">⚡ {:.1}s · {}</span>"#,
+                    elapsed_ms as f64 / 1000.0,
+                    locale.arena_faster
+                ),
+                ColumnState::Done { elapsed_ms } => format!(
+                    r#"<span class="badge">{:.1}s</span>"#,
+                    elapsed_ms as f64 / 1000.0
+                ),
+            };
+            // Only a finished answer can be picked
+            let pick_btn = if matches!(column.state, ColumnState::Done { .. }) {
+                format!(
+                    r#"<button class="pick" onclick="window.ipc.postMessage('arena_pick:{}')">{}</button>"#,
+                    i,
+                    if is_picked {
+                        locale.arena_picked
+                    } else {
+                        locale.arena_pick_btn
+                    }
+                )
+            } else {
+                String::new()
+            };
+            let body = if column.state == ColumnState::Failed {
+                format!("<p>{}</p>", escape_html(&column.text))
+            } else {
+                render_markdown_fragment(&column.text)
+            };
+            format!(
+                r#"<div class="{}"><div class="header"><span class="label">{}</span>{}{}</div><div class="body" dir="{}">{}</div></div>"#,
+                class,
+                escape_html(&column.label),
+                badge,
+                pick_btn,
+                html_text_direction(&body),
+                body
+            )
+        })
+        .collect();
+
+    Some(wrap_markdown_document(&format!(
+        r#"<style>{}</style><div class="arena" dir="ltr">{}</div>"#,
+        ARENA_CSS, columns
+    )))
+}
@@ -1,6 +1,6 @@
 use windows::Win32::Foundation::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
-use windows::Win32::UI::Input::KeyboardAndMouse::ReleaseCapture;
+use windows::Win32::UI::Input::KeyboardAndMouse::{GetKeyState, ReleaseCapture, VK_SHIFT};
 use std::mem::size_of;
 
 use windows::Win32::Graphics::Gdi::InvalidateRect;
@@ -11,7 +11,7 @@ use crate::overlay::result::state::{WINDOW_STATES, InteractionMode};
 use crate::overlay::result::markdown_view;
 use crate::overlay::result::refine_input;
 use crate::overlay::utils::to_wstring;
-use super::misc::WM_CREATE_WEBVIEW;
+use super::misc::{WM_CANCEL_REQUEST, WM_CREATE_WEBVIEW};
 
 pub unsafe fn handle_lbutton_up(hwnd: HWND) -> LRESULT {
     let _ = ReleaseCapture();
@@ -21,10 +21,19 @@ pub unsafe fn handle_lbutton_up(hwnd: HWND) -> LRESULT {
     let mut is_undo_click = false;
     let mut is_redo_click = false;
     let mut is_markdown_click = false;
+    let mut is_dual_pane_click = false;
     let mut is_back_click = false;
     let mut is_forward_click = false;
     let mut is_download_click = false;
     let mut is_speaker_click = false;
+    let mut is_stop_click = false;
+    let mut is_language_click = false;
+    let mut is_table_export_click = false;
+    let mut is_retry_click = false;
+    let mut is_tts_pause_click = false;
+    let mut is_history_prev_click = false;
+    let mut is_history_next_click = false;
+    let mut is_snapshot_click = false;
     {
         let mut states = WINDOW_STATES.lock().unwrap();
         if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
@@ -36,16 +45,63 @@ pub unsafe fn handle_lbutton_up(hwnd: HWND) -> LRESULT {
                 is_undo_click = state.on_undo_btn;
                 is_redo_click = state.on_redo_btn;
                 is_markdown_click = state.on_markdown_btn;
+                is_dual_pane_click = state.on_dual_pane_btn;
                 is_back_click = state.on_back_btn;
                 is_forward_click = state.on_forward_btn;
                 is_download_click = state.on_download_btn;
                 is_speaker_click = state.on_speaker_btn;
+                is_stop_click = state.on_stop_btn;
+                is_language_click = state.on_language_btn;
+                is_table_export_click = state.on_table_export_btn;
+                is_retry_click = state.on_retry_btn;
+                is_tts_pause_click = state.on_tts_pause_btn;
+                is_history_prev_click = state.on_history_prev_btn;
+                is_history_next_click = state.on_history_next_btn;
+                is_snapshot_click = state.on_snapshot_btn;
             }
         }
     }
     
     if perform_click {
-            if is_back_click {
+            if is_stop_click {
+                let _ = PostMessageW(Some(hwnd), WM_CANCEL_REQUEST, WPARAM(0), LPARAM(0));
+            } else if is_language_click {
+                if let Some(language) = pick_language(hwnd) {
+                    crate::overlay::process::chain::rerun_with_language(hwnd, &language);
+                    let _ = InvalidateRect(Some(hwnd), None, false);
+                }
+            } else if is_retry_click {
+                let retry = {
+                    let states = WINDOW_STATES.lock().unwrap();
+                    states
+                        .get(&(hwnd.0 as isize))
+                        .and_then(|state| state.pronunciation_retry.clone())
+                };
+                if let Some(retry) = retry {
+                    // The new attempt gets its own window
+                    let _ = PostMessageW(Some(hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
+                    crate::overlay::process::pronunciation::record_again(retry);
+                }
+            } else if is_history_prev_click || is_history_next_click {
+                crate::overlay::result::recent::step(hwnd, is_history_prev_click);
+            } else if is_snapshot_click {
+                // Shift+click also saves the image
+                let save = GetKeyState(VK_SHIFT.0 as i32) < 0;
+                crate::overlay::result::snapshot::start(hwnd, save);
+            } else if is_tts_pause_click {
+                crate::api::tts::TTS_MANAGER.toggle_pause();
+                let _ = InvalidateRect(Some(hwnd), None, false);
+            } else if is_table_export_click {
+                let full_text = {
+                    let states = WINDOW_STATES.lock().unwrap();
+                    states
+                        .get(&(hwnd.0 as isize))
+                        .map(|state| state.full_text.clone())
+                        .unwrap_or_default()
+                };
+                let lang = crate::APP.lock().unwrap().config.ui_language.clone();
+                crate::overlay::result::table::export_table(&full_text, &lang);
+            } else if is_back_click {
                 markdown_view::go_back(hwnd);
             } else if is_forward_click {
                 markdown_view::go_forward(hwnd);
@@ -133,86 +189,12 @@ pub unsafe fn handle_lbutton_up(hwnd: HWND) -> LRESULT {
                 let _ = InvalidateRect(Some(hwnd), None, false);
             }
             } else if is_edit_click {
-            // Check if we're in markdown mode to decide which input to use
-            let (is_markdown_mode, _is_currently_editing, _h_edit) = {
-                let states = WINDOW_STATES.lock().unwrap();
-                if let Some(state) = states.get(&(hwnd.0 as isize)) {
-                    (state.is_markdown_mode, state.is_editing, state.edit_hwnd)
-                } else {
-                    (false, false, HWND::default())
-                }
-            };
-            
-            if is_markdown_mode {
-                // Use WebView-based refine input (stays above markdown view)
-                if refine_input::is_refine_input_active(hwnd) {
-                    // Toggle off - hide the refine input
-                    refine_input::hide_refine_input(hwnd);
-                    {
-                        let mut states = WINDOW_STATES.lock().unwrap();
-                        if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
-                            state.is_editing = false;
-                        }
-                    }
-                    // Resize markdown WebView back to full
-                    let is_hovered = {
-                        let states = WINDOW_STATES.lock().unwrap();
-                        states.get(&(hwnd.0 as isize)).map(|s| s.is_hovered).unwrap_or(false)
-                    };
-                    markdown_view::resize_markdown_webview(hwnd, is_hovered);
-                } else {
-                    // Toggle on - show the refine input
-                    let lang = {
-                        let app = crate::APP.lock().unwrap();
-                        app.config.ui_language.clone()
-                    };
-                    let locale = crate::gui::locale::LocaleText::get(&lang);
-                    let placeholder = locale.text_input_placeholder;
-                    
-                    if refine_input::show_refine_input(hwnd, placeholder) {
-                        {
-                            let mut states = WINDOW_STATES.lock().unwrap();
-                            if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
-                                state.is_editing = true;
-                            }
-                        }
-                        // Resize markdown WebView to leave room for refine input
-                        // The refine input is at top, so markdown view shifts down
-                        markdown_view::resize_markdown_webview(hwnd, true);
-                    }
-                }
+            if refine_input::is_refine_input_active(hwnd) {
+                close_refine_input(hwnd);
             } else {
-                // Plain text mode: now also use WebView-based refine input (same as markdown)
-                // This allows the mic button to work in both modes
-                if refine_input::is_refine_input_active(hwnd) {
-                    // Toggle off - hide the refine input
-                    refine_input::hide_refine_input(hwnd);
-                    {
-                        let mut states = WINDOW_STATES.lock().unwrap();
-                        if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
-                            state.is_editing = false;
-                        }
-                    }
-                } else {
-                    // Toggle on - show the refine input
-                    let lang = {
-                        let app = crate::APP.lock().unwrap();
-                        app.config.ui_language.clone()
-                    };
-                    let locale = crate::gui::locale::LocaleText::get(&lang);
-                    let placeholder = locale.text_input_placeholder;
-                    
-                    if refine_input::show_refine_input(hwnd, placeholder) {
-                        {
-                            let mut states = WINDOW_STATES.lock().unwrap();
-                            if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
-                                state.is_editing = true;
-                            }
-                        }
-                    }
-                }
-                let _ = InvalidateRect(Some(hwnd), None, false);
+                open_refine_input(hwnd, "");
             }
+            let _ = InvalidateRect(Some(hwnd), None, false);
             } else if is_copy_click {
             let text_len = GetWindowTextLengthW(hwnd) + 1;
             let mut buf = vec![0u16; text_len as usize];
@@ -250,27 +232,48 @@ pub unsafe fn handle_lbutton_up(hwnd: HWND) -> LRESULT {
                 };
                 
                 if toggle_on {
-                    // DEFER WebView creation to after this handler returns
-                    // Using PostMessage allows the handler to return first.
+                    enter_markdown_mode(hwnd);
+                } else {
+                    leave_markdown_mode(hwnd);
+                }
+                let _ = InvalidateRect(Some(hwnd), None, false);
+            }
+            } else if is_dual_pane_click {
+            // Same rule as the markdown toggle: not while refining or streaming
+            let (can_toggle, source_text) = {
+                let states = WINDOW_STATES.lock().unwrap();
+                if let Some(state) = states.get(&(hwnd.0 as isize)) {
+                    (!state.is_refining && !state.is_streaming_active, state.source_text.clone())
+                } else {
+                    (false, String::new())
+                }
+            };
+
+            if can_toggle && source_text.trim().is_empty() {
+                let lang = crate::APP.lock().unwrap().config.ui_language.clone();
+                let locale = crate::gui::locale::LocaleText::get(&lang);
+                crate::overlay::auto_copy_badge::show_notification(locale.dual_pane_no_source);
+            } else if can_toggle {
+                // The side-by-side view lives in the markdown WebView, so turning it on
+                // also switches to markdown mode
+                let (dual_on, was_markdown, full_text, is_hovered) = {
+                    let mut states = WINDOW_STATES.lock().unwrap();
+                    if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
+                        let was_markdown = state.is_markdown_mode;
+                        state.is_dual_pane = !(state.is_dual_pane && was_markdown);
+                        state.is_markdown_mode |= state.is_dual_pane;
+                        (state.is_dual_pane, was_markdown, state.full_text.clone(), state.is_hovered)
+                    } else {
+                        (false, false, String::new(), false)
+                    }
+                };
+
+                markdown_view::set_dual_pane_source(hwnd, dual_on.then_some(source_text.as_str()));
+                if was_markdown {
+                    markdown_view::create_markdown_webview(hwnd, &full_text, is_hovered);
+                } else {
                     let _ = PostMessageW(Some(hwnd), WM_CREATE_WEBVIEW, WPARAM(0), LPARAM(0));
-                    // Start hover polling timer (ID 2, 30ms interval)
                     SetTimer(Some(hwnd), 2, 30, None);
-                } else {
-                    // Hide markdown webview, show plain text
-                    markdown_view::hide_markdown_webview(hwnd);
-                    // Stop hover polling timer
-                    let _ = KillTimer(Some(hwnd), 2);
-                    
-                    // Re-establish TrackMouseEvent for plain text mode
-                    // This is needed because Timer 2 was handling hover state,
-                    // but now we need WM_MOUSELEAVE to fire again
-                    let mut tme = TRACKMOUSEEVENT { 
-                        cbSize: size_of::<TRACKMOUSEEVENT>() as u32, 
-                        dwFlags: TME_LEAVE, 
-                        hwndTrack: hwnd, 
-                        dwHoverTime: 0 
-                    };
-                    let _ = TrackMouseEvent(&mut tme);
                 }
                 let _ = InvalidateRect(Some(hwnd), None, false);
             }
@@ -323,7 +326,8 @@ pub unsafe fn handle_lbutton_up(hwnd: HWND) -> LRESULT {
                 }
                 let _ = InvalidateRect(Some(hwnd), None, false); // Redraw to show loading
                 
-                let request_id = crate::api::tts::TTS_MANAGER.speak(&full_text, hwnd.0 as isize);
+                let config = crate::APP.lock().unwrap().config.clone();
+                let request_id = crate::api::tts::TTS_MANAGER.speak_document(&full_text, hwnd.0 as isize, &config);
                 {
                     let mut states = WINDOW_STATES.lock().unwrap();
                     if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
@@ -350,6 +354,139 @@ pub unsafe fn handle_lbutton_up(hwnd: HWND) -> LRESULT {
     LRESULT(0)
 }
 
+/// Show the markdown WebView for a window just switched to markdown mode
+pub unsafe fn enter_markdown_mode(hwnd: HWND) {
+    // DEFER WebView creation to after this handler returns
+    // Using PostMessage allows the handler to return first.
+    let _ = PostMessageW(Some(hwnd), WM_CREATE_WEBVIEW, WPARAM(0), LPARAM(0));
+    // Start hover polling timer (ID 2, 30ms interval)
+    SetTimer(Some(hwnd), 2, 30, None);
+}
+
+/// Hide the markdown WebView for a window just switched to plain text
+pub unsafe fn leave_markdown_mode(hwnd: HWND) {
+    markdown_view::hide_markdown_webview(hwnd);
+    // Stop hover polling timer
+    let _ = KillTimer(Some(hwnd), 2);
+
+    // Re-establish TrackMouseEvent for plain text mode
+    // This is needed because Timer 2 was handling hover state,
+    // but now we need WM_MOUSELEAVE to fire again
+    let mut tme = TRACKMOUSEEVENT {
+        cbSize: size_of::<TRACKMOUSEEVENT>() as u32,
+        dwFlags: TME_LEAVE,
+        hwndTrack: hwnd,
+        dwHoverTime: 0,
+    };
+    let _ = TrackMouseEvent(&mut tme);
+}
+
+/// Languages the user actually translates into: preset blocks, secondary languages
+/// and live translate, in that order without duplicates
+fn recent_languages(config: &crate::config::Config) -> Vec<String> {
+    let mut languages: Vec<String> = Vec::new();
+    let candidates = config
+        .presets
+        .iter()
+        .flat_map(|p| {
+            p.blocks
+                .iter()
+                .map(|b| b.selected_language.as_str())
+                .chain(std::iter::once(p.secondary_language.as_str()))
+        })
+        .chain(std::iter::once(config.realtime_target_language.as_str()));
+    for lang in candidates {
+        if !lang.is_empty() && !languages.iter().any(|l| l == lang) {
+            languages.push(lang.to_string());
+        }
+    }
+    languages
+}
+
+/// Pop up the language menu under the cursor, returning the picked language
+unsafe fn pick_language(hwnd: HWND) -> Option<String> {
+    let current = {
+        let states = WINDOW_STATES.lock().unwrap();
+        states
+            .get(&(hwnd.0 as isize))
+            .and_then(|s| s.language_rerun.as_ref())
+            .map(|r| r.block.selected_language.clone())?
+    };
+    let (recent, ui_language) = {
+        let app = crate::APP.lock().unwrap();
+        (
+            recent_languages(&app.config),
+            app.config.ui_language.clone(),
+        )
+    };
+    let all = crate::config::get_all_languages();
+    let locale = crate::gui::locale::LocaleText::get(&ui_language);
+
+    let menu = CreatePopupMenu().ok()?;
+    let all_menu = CreatePopupMenu().ok()?;
+    // Command ids: 1..=recent.len() for the quick list, then the full list
+    let checked = |lang: &str| {
+        if lang == current {
+            MF_CHECKED
+        } else {
+            MF_UNCHECKED
+        }
+    };
+    for (i, lang) in recent.iter().enumerate() {
+        let label = to_wstring(lang);
+        let _ = AppendMenuW(
+            menu,
+            MF_STRING | checked(lang),
+            i + 1,
+            PCWSTR(label.as_ptr()),
+        );
+    }
+    for (i, lang) in all.iter().enumerate() {
+        let label = to_wstring(lang);
+        let _ = AppendMenuW(
+            all_menu,
+            MF_STRING | checked(lang),
+            recent.len() + i + 1,
+            PCWSTR(label.as_ptr()),
+        );
+    }
+    if !recent.is_empty() {
+        let _ = AppendMenuW(menu, MF_SEPARATOR, 0, PCWSTR::null());
+    }
+    let all_label = to_wstring(locale.result_all_languages);
+    let _ = AppendMenuW(
+        menu,
+        MF_POPUP,
+        all_menu.0 as usize,
+        PCWSTR(all_label.as_ptr()),
+    );
+
+    let mut pt = POINT::default();
+    let _ = GetCursorPos(&mut pt);
+    // Without this the menu doesn't close when clicking elsewhere
+    let _ = SetForegroundWindow(hwnd);
+    let cmd = TrackPopupMenu(
+        menu,
+        TPM_RETURNCMD | TPM_NONOTIFY | TPM_BOTTOMALIGN,
+        pt.x,
+        pt.y,
+        None,
+        hwnd,
+        None,
+    )
+    .0 as usize;
+    let _ = PostMessageW(Some(hwnd), WM_NULL, WPARAM(0), LPARAM(0));
+    // Destroys the submenu too
+    let _ = DestroyMenu(menu);
+
+    match cmd {
+        0 => None,
+        i if i <= recent.len() => recent.get(i - 1).cloned(),
+        i => all.get(i - recent.len() - 1).cloned(),
+    }
+    .filter(|lang| *lang != current)
+}
+
 pub unsafe fn handle_rbutton_up(hwnd: HWND) -> LRESULT {
     let _ = ReleaseCapture();
     let mut perform_action = false;
@@ -405,3 +542,47 @@ pub unsafe fn handle_mbutton_up() -> LRESULT {
     }
     LRESULT(0)
 }
+
+/// Show the refine input (WebView-based in both markdown and plain text mode,
+/// so the mic button works everywhere), pre-filled with `initial_text`
+pub unsafe fn open_refine_input(hwnd: HWND, initial_text: &str) {
+    let lang = {
+        let app = crate::APP.lock().unwrap();
+        app.config.ui_language.clone()
+    };
+    let locale = crate::gui::locale::LocaleText::get(&lang);
+
+    if !refine_input::show_refine_input(hwnd, locale.text_input_placeholder, initial_text) {
+        return;
+    }
+    let is_markdown_mode = {
+        let mut states = WINDOW_STATES.lock().unwrap();
+        if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
+            state.is_editing = true;
+            state.is_markdown_mode
+        } else {
+            false
+        }
+    };
+    if is_markdown_mode {
+        // The refine input is at top, so the markdown view shifts down
+        markdown_view::resize_markdown_webview(hwnd, true);
+    }
+}
+
+/// Hide the refine input and give the markdown view its full height back
+pub unsafe fn close_refine_input(hwnd: HWND) {
+    refine_input::hide_refine_input(hwnd);
+    let (is_markdown_mode, is_hovered) = {
+        let mut states = WINDOW_STATES.lock().unwrap();
+        if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
+            state.is_editing = false;
+            (state.is_markdown_mode, state.is_hovered)
+        } else {
+            (false, false)
+        }
+    };
+    if is_markdown_mode {
+        markdown_view::resize_markdown_webview(hwnd, is_hovered);
+    }
+}
@@ -5,10 +5,16 @@ use std::sync::Arc;
 
 use crate::overlay::result::state::WINDOW_STATES;
 use crate::overlay::result::paint;
+use crate::overlay::result::arena_view;
 use crate::overlay::result::markdown_view;
 use crate::overlay::result::refine_input;
+use crate::overlay::result::word_lookup;
 
 pub const WM_CREATE_WEBVIEW: u32 = WM_USER + 200; 
+/// Stop the request streaming into this window (posted by the stop button)
+pub const WM_CANCEL_REQUEST: u32 = WM_USER + 201;
+/// A double-click word lookup has something to show (see `word_lookup`)
+pub const WM_WORD_LOOKUP: u32 = WM_USER + 202;
 
 pub unsafe fn handle_erase_bkgnd(_hwnd: HWND, _wparam: WPARAM) -> LRESULT {
     LRESULT(1)
@@ -36,6 +42,11 @@ pub unsafe fn handle_destroy(hwnd: HWND) -> LRESULT {
                 crate::api::tts::TTS_MANAGER.stop_if_active(state.tts_request_id);
             }
             
+            // Stop reading a response nobody will see
+            if let Some(ref stop) = state.stop_signal {
+                stop.store(true, std::sync::atomic::Ordering::Relaxed);
+            }
+
             // Get the cancellation token from this window
             token_to_signal = state.cancellation_token.clone();
             
@@ -73,6 +84,10 @@ pub unsafe fn handle_destroy(hwnd: HWND) -> LRESULT {
             // Cleanup markdown webview and timer
             let _ = KillTimer(Some(hwnd), 2);
             markdown_view::destroy_markdown_webview(hwnd);
+            markdown_view::set_dual_pane_source(hwnd, None);
+            markdown_view::forget_font_zoom(hwnd);
+            arena_view::end_arena(hwnd);
+            word_lookup::clear_pending(hwnd);
             
             // Cleanup refine input if active
             refine_input::hide_refine_input(hwnd);
@@ -92,15 +107,106 @@ pub unsafe fn handle_destroy(hwnd: HWND) -> LRESULT {
     LRESULT(0)
 }
 
+pub unsafe fn handle_cancel_request(hwnd: HWND) -> LRESULT {
+    let mut states = WINDOW_STATES.lock().unwrap();
+    if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
+        if state.is_streaming_active {
+            if let Some(ref stop) = state.stop_signal {
+                stop.store(true, std::sync::atomic::Ordering::Relaxed);
+            }
+        }
+    }
+    LRESULT(0)
+}
+
+pub unsafe fn handle_word_lookup(hwnd: HWND) -> LRESULT {
+    if let Some((word, definition)) = word_lookup::take_pending(hwnd) {
+        markdown_view::show_word_lookup(hwnd, &word, definition.as_deref());
+    }
+    LRESULT(0)
+}
+
 pub unsafe fn handle_paint(hwnd: HWND) -> LRESULT {
     paint::paint_window(hwnd);
     LRESULT(0)
 }
 
-pub unsafe fn handle_keydown() -> LRESULT {
+/// Keyboard shortcuts while the result window itself has focus
+/// (keys typed into the refine input are handled by its own WebView):
+/// Ctrl+Enter opens the refine input, Up opens it with the previous prompt, Esc closes it.
+/// While the window's text is read aloud, Space pauses/resumes and Left/Right go to
+/// the previous/next sentence.
+pub unsafe fn handle_keydown(hwnd: HWND, wparam: WPARAM) -> LRESULT {
+    use windows::Win32::UI::Input::KeyboardAndMouse::{
+        GetKeyState, VIRTUAL_KEY, VK_CONTROL, VK_ESCAPE, VK_LEFT, VK_RETURN, VK_RIGHT, VK_SPACE,
+        VK_UP,
+    };
+
+    let tts = &crate::api::tts::TTS_MANAGER;
+    let reading = tts.is_reading_document(hwnd.0 as isize);
+
+    let can_refine = {
+        let states = WINDOW_STATES.lock().unwrap();
+        states
+            .get(&(hwnd.0 as isize))
+            .is_some_and(|s| !s.is_refining && !s.is_streaming_active)
+    };
+    let refine_active = refine_input::is_refine_input_active(hwnd);
+    let ctrl_down = GetKeyState(VK_CONTROL.0 as i32) < 0;
+
+    match VIRTUAL_KEY(wparam.0 as u16) {
+        VK_RETURN if ctrl_down && can_refine => {
+            super::click_actions::open_refine_input(hwnd, "");
+        }
+        VK_UP if can_refine && !refine_active => {
+            let previous = crate::overlay::input_history::navigate_history_up("");
+            super::click_actions::open_refine_input(hwnd, previous.as_deref().unwrap_or(""));
+        }
+        VK_ESCAPE if refine_active => {
+            super::click_actions::close_refine_input(hwnd);
+        }
+        VK_SPACE if reading => {
+            tts.toggle_pause();
+        }
+        VK_LEFT if reading => tts.previous_sentence(),
+        VK_RIGHT if reading => tts.next_sentence(),
+        _ => return LRESULT(0),
+    }
+    let _ = InvalidateRect(Some(hwnd), None, false);
+    LRESULT(0)
+}
+
+/// Alt+Left/Right show the older/newer result of the session. Other Alt combinations
+/// (Alt+F4) keep their default handling.
+pub unsafe fn handle_syskeydown(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    use windows::Win32::UI::Input::KeyboardAndMouse::{VIRTUAL_KEY, VK_LEFT, VK_RIGHT};
+
+    let older = match VIRTUAL_KEY(wparam.0 as u16) {
+        VK_LEFT => true,
+        VK_RIGHT => false,
+        _ => return DefWindowProcW(hwnd, msg, wparam, lparam),
+    };
+    crate::overlay::result::recent::step(hwnd, older);
     LRESULT(0)
 }
 
+/// The markdown WebView's process died: drop it and, if the window is showing
+/// markdown, build a new one from the window's text
+pub unsafe fn handle_webview_crashed(hwnd: HWND) -> LRESULT {
+    markdown_view::destroy_markdown_webview(hwnd);
+    let is_markdown_mode = {
+        let states = WINDOW_STATES.lock().unwrap();
+        states
+            .get(&(hwnd.0 as isize))
+            .is_some_and(|s| s.is_markdown_mode)
+    };
+    if !is_markdown_mode {
+        return LRESULT(0);
+    }
+    crate::overlay::webview_recovery::show_recovering_notice();
+    handle_create_webview(hwnd)
+}
+
 pub unsafe fn handle_create_webview(hwnd: HWND) -> LRESULT {
     // Get the text to render
     let (full_text, is_hovered) = {
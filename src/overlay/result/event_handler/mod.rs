@@ -30,6 +30,8 @@ pub unsafe extern "system" fn result_wnd_proc(
 
         WM_MOUSEMOVE => mouse_input::handle_mouse_move(hwnd, lparam),
 
+        WM_MOUSEWHEEL => mouse_input::handle_mouse_wheel(hwnd, msg, wparam, lparam),
+
         0x02A3 => mouse_input::handle_mouse_leave(hwnd), // WM_MOUSELEAVE
 
         WM_LBUTTONUP => click_actions::handle_lbutton_up(hwnd),
@@ -44,7 +46,9 @@ pub unsafe extern "system" fn result_wnd_proc(
 
         WM_PAINT => misc::handle_paint(hwnd),
 
-        WM_KEYDOWN => misc::handle_keydown(),
+        WM_KEYDOWN => misc::handle_keydown(hwnd, wparam),
+
+        WM_SYSKEYDOWN => misc::handle_syskeydown(hwnd, msg, wparam, lparam),
 
         // Enforce minimum window size to prevent rendering issues
         WM_GETMINMAXINFO => {
@@ -59,6 +63,17 @@ pub unsafe extern "system" fn result_wnd_proc(
         // Deferred WebView2 creation - handles the WM_CREATE_WEBVIEW we posted
         msg if msg == misc::WM_CREATE_WEBVIEW => misc::handle_create_webview(hwnd),
 
+        // Stop button: end the streaming request, keep the partial result
+        msg if msg == misc::WM_CANCEL_REQUEST => misc::handle_cancel_request(hwnd),
+
+        // Double-click lookup result (or loading state) ready to show
+        msg if msg == misc::WM_WORD_LOOKUP => misc::handle_word_lookup(hwnd),
+
+        // The markdown WebView's renderer/browser process crashed
+        msg if msg == crate::overlay::webview_recovery::WM_APP_WEBVIEW_CRASHED => {
+            misc::handle_webview_crashed(hwnd)
+        }
+
         _ => DefWindowProcW(hwnd, msg, wparam, lparam),
     }
 }
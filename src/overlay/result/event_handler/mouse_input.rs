@@ -7,13 +7,15 @@ use windows::Win32::UI::Input::KeyboardAndMouse::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
 
 use crate::overlay::result::layout::{
-    get_copy_btn_rect, get_download_btn_rect, get_edit_btn_rect, get_markdown_btn_rect,
-    get_redo_btn_rect, get_resize_edge, get_speaker_btn_rect, get_undo_btn_rect,
-    should_show_buttons,
+    get_copy_btn_rect, get_download_btn_rect, get_dual_pane_btn_rect, get_edit_btn_rect,
+    get_history_btn_rect, get_language_btn_rect, get_markdown_btn_rect, get_redo_btn_rect,
+    get_resize_edge, get_retry_btn_rect, get_snapshot_btn_rect, get_speaker_btn_rect,
+    get_table_export_btn_rect, get_tts_pause_btn_rect, get_undo_btn_rect, should_show_buttons,
 };
 use crate::overlay::result::markdown_view;
 use crate::overlay::result::refine_input;
 use crate::overlay::result::state::{InteractionMode, ResizeEdge, WINDOW_STATES};
+use crate::overlay::result::table::looks_like_table;
 
 unsafe fn set_rounded_edit_region(h_edit: HWND, w: i32, h: i32) {
     let rgn = CreateRoundRectRgn(0, 0, w, h, 12, 12);
@@ -77,11 +79,30 @@ pub unsafe fn handle_set_cursor(hwnd: HWND) -> LRESULT {
 
                 let mut has_history = false;
                 let mut is_browsing = false;
+                let mut has_language = false;
+                let mut has_table = false;
+                let mut retry_slot = None;
+                let mut tts_pause_slot = None;
+                let mut history_slots = (None, None);
+                let mut snapshot_slot = None;
                 {
                     let states = WINDOW_STATES.lock().unwrap();
                     if let Some(state) = states.get(&(hwnd.0 as isize)) {
                         has_history = !state.text_history.is_empty();
                         is_browsing = state.is_browsing;
+                        has_language = state.language_rerun.is_some()
+                            && !state.is_refining
+                            && !state.is_streaming_active;
+                        has_table = !state.is_refining
+                            && !state.is_streaming_active
+                            && looks_like_table(&state.full_text);
+                        retry_slot = (state.pronunciation_retry.is_some()
+                            && !state.is_refining
+                            && !state.is_streaming_active)
+                            .then(|| state.retry_slot());
+                        tts_pause_slot = state.is_tts_playing().then(|| state.tts_pause_slot());
+                        history_slots = state.history_btn_slots();
+                        snapshot_slot = state.snapshot_slot();
                     }
                 }
 
@@ -121,6 +142,12 @@ pub unsafe fn handle_set_cursor(hwnd: HWND) -> LRESULT {
                     && pt.y >= md_rect.top
                     && pt.y <= md_rect.bottom;
 
+                let dual_rect = get_dual_pane_btn_rect(rect.right, rect.bottom);
+                let on_dual = pt.x >= dual_rect.left
+                    && pt.x <= dual_rect.right
+                    && pt.y >= dual_rect.top
+                    && pt.y <= dual_rect.bottom;
+
                 let dl_rect = get_download_btn_rect(rect.right, rect.bottom);
                 let on_dl = pt.x >= dl_rect.left
                     && pt.x <= dl_rect.right
@@ -133,7 +160,76 @@ pub unsafe fn handle_set_cursor(hwnd: HWND) -> LRESULT {
                     && pt.y >= speaker_rect.top
                     && pt.y <= speaker_rect.bottom;
 
-                if on_copy || on_edit || on_undo || on_md || on_back || on_dl || on_speaker {
+                let lang_rect = get_language_btn_rect(rect.right, rect.bottom);
+                let on_language = has_language
+                    && !is_browsing
+                    && pt.x >= lang_rect.left
+                    && pt.x <= lang_rect.right
+                    && pt.y >= lang_rect.top
+                    && pt.y <= lang_rect.bottom;
+
+                let export_rect = get_table_export_btn_rect(rect.right, rect.bottom, has_language);
+                let on_table_export = has_table
+                    && !is_browsing
+                    && pt.x >= export_rect.left
+                    && pt.x <= export_rect.right
+                    && pt.y >= export_rect.top
+                    && pt.y <= export_rect.bottom;
+
+                let on_retry = !is_browsing
+                    && retry_slot.is_some_and(|slot| {
+                        let retry_rect = get_retry_btn_rect(rect.right, rect.bottom, slot);
+                        pt.x >= retry_rect.left
+                            && pt.x <= retry_rect.right
+                            && pt.y >= retry_rect.top
+                            && pt.y <= retry_rect.bottom
+                    });
+
+                let on_tts_pause = !is_browsing
+                    && tts_pause_slot.is_some_and(|slot| {
+                        let pause_rect = get_tts_pause_btn_rect(rect.right, rect.bottom, slot);
+                        pt.x >= pause_rect.left
+                            && pt.x <= pause_rect.right
+                            && pt.y >= pause_rect.top
+                            && pt.y <= pause_rect.bottom
+                    });
+
+                let on_history = !is_browsing
+                    && [history_slots.0, history_slots.1]
+                        .into_iter()
+                        .flatten()
+                        .any(|slot| {
+                            let history_rect = get_history_btn_rect(rect.right, rect.bottom, slot);
+                            pt.x >= history_rect.left
+                                && pt.x <= history_rect.right
+                                && pt.y >= history_rect.top
+                                && pt.y <= history_rect.bottom
+                        });
+
+                let on_snapshot = !is_browsing
+                    && snapshot_slot.is_some_and(|slot| {
+                        let snapshot_rect = get_snapshot_btn_rect(rect.right, rect.bottom, slot);
+                        pt.x >= snapshot_rect.left
+                            && pt.x <= snapshot_rect.right
+                            && pt.y >= snapshot_rect.top
+                            && pt.y <= snapshot_rect.bottom
+                    });
+
+                if on_copy
+                    || on_edit
+                    || on_undo
+                    || on_md
+                    || on_dual
+                    || on_back
+                    || on_dl
+                    || on_speaker
+                    || on_language
+                    || on_table_export
+                    || on_retry
+                    || on_tts_pause
+                    || on_history
+                    || on_snapshot
+                {
                     cursor_id = IDC_HAND;
                 }
             }
@@ -287,6 +383,9 @@ pub unsafe fn handle_mouse_move(hwnd: HWND, lparam: LPARAM) -> LRESULT {
                     && x as i32 <= copy_rect.right + padding
                     && y as i32 >= copy_rect.top - padding
                     && y as i32 <= copy_rect.bottom + padding;
+                // While streaming, the copy slot holds the stop button instead
+                state.on_stop_btn =
+                    state.on_copy_btn && state.is_streaming_active && !state.is_refining;
                 state.on_edit_btn = x as i32 >= edit_rect.left - padding
                     && x as i32 <= edit_rect.right + padding
                     && y as i32 >= edit_rect.top - padding
@@ -347,7 +446,15 @@ pub unsafe fn handle_mouse_move(hwnd: HWND, lparam: LPARAM) -> LRESULT {
                     state.on_copy_btn = false;
                     state.on_edit_btn = false;
                     state.on_markdown_btn = false;
+                    state.on_dual_pane_btn = false;
                     state.on_download_btn = false;
+                    state.on_language_btn = false;
+                    state.on_table_export_btn = false;
+                    state.on_retry_btn = false;
+                    state.on_tts_pause_btn = false;
+                    state.on_history_prev_btn = false;
+                    state.on_history_next_btn = false;
+                    state.on_snapshot_btn = false;
                 } else {
                     state.on_back_btn = false;
                     state.on_forward_btn = false;
@@ -359,6 +466,12 @@ pub unsafe fn handle_mouse_move(hwnd: HWND, lparam: LPARAM) -> LRESULT {
                         && y as i32 >= md_rect.top - padding
                         && y as i32 <= md_rect.bottom + padding;
 
+                    let dual_rect = get_dual_pane_btn_rect(rect.right, rect.bottom);
+                    state.on_dual_pane_btn = x as i32 >= dual_rect.left - padding
+                        && x as i32 <= dual_rect.right + padding
+                        && y as i32 >= dual_rect.top - padding
+                        && y as i32 <= dual_rect.bottom + padding;
+
                     let dl_rect = get_download_btn_rect(rect.right, rect.bottom);
                     state.on_download_btn = x as i32 >= dl_rect.left - padding
                         && x as i32 <= dl_rect.right + padding
@@ -370,18 +483,105 @@ pub unsafe fn handle_mouse_move(hwnd: HWND, lparam: LPARAM) -> LRESULT {
                         && x as i32 <= speaker_rect.right + padding
                         && y as i32 >= speaker_rect.top - padding
                         && y as i32 <= speaker_rect.bottom + padding;
+
+                    if state.language_rerun.is_some()
+                        && !state.is_refining
+                        && !state.is_streaming_active
+                    {
+                        let lang_rect = get_language_btn_rect(rect.right, rect.bottom);
+                        state.on_language_btn = x as i32 >= lang_rect.left - padding
+                            && x as i32 <= lang_rect.right + padding
+                            && y as i32 >= lang_rect.top - padding
+                            && y as i32 <= lang_rect.bottom + padding;
+                    } else {
+                        state.on_language_btn = false;
+                    }
+
+                    if !state.is_refining
+                        && !state.is_streaming_active
+                        && looks_like_table(&state.full_text)
+                    {
+                        let export_rect = get_table_export_btn_rect(
+                            rect.right,
+                            rect.bottom,
+                            state.language_rerun.is_some(),
+                        );
+                        state.on_table_export_btn = x as i32 >= export_rect.left - padding
+                            && x as i32 <= export_rect.right + padding
+                            && y as i32 >= export_rect.top - padding
+                            && y as i32 <= export_rect.bottom + padding;
+                    } else {
+                        state.on_table_export_btn = false;
+                    }
+
+                    if state.pronunciation_retry.is_some()
+                        && !state.is_refining
+                        && !state.is_streaming_active
+                    {
+                        let retry_rect =
+                            get_retry_btn_rect(rect.right, rect.bottom, state.retry_slot());
+                        state.on_retry_btn = x as i32 >= retry_rect.left - padding
+                            && x as i32 <= retry_rect.right + padding
+                            && y as i32 >= retry_rect.top - padding
+                            && y as i32 <= retry_rect.bottom + padding;
+                    } else {
+                        state.on_retry_btn = false;
+                    }
+
+                    if state.is_tts_playing() {
+                        let pause_rect =
+                            get_tts_pause_btn_rect(rect.right, rect.bottom, state.tts_pause_slot());
+                        state.on_tts_pause_btn = x as i32 >= pause_rect.left - padding
+                            && x as i32 <= pause_rect.right + padding
+                            && y as i32 >= pause_rect.top - padding
+                            && y as i32 <= pause_rect.bottom + padding;
+                    } else {
+                        state.on_tts_pause_btn = false;
+                    }
+
+                    let (prev_slot, next_slot) = state.history_btn_slots();
+                    let on_slot = |slot: Option<i32>| {
+                        slot.is_some_and(|slot| {
+                            let history_rect = get_history_btn_rect(rect.right, rect.bottom, slot);
+                            x as i32 >= history_rect.left - padding
+                                && x as i32 <= history_rect.right + padding
+                                && y as i32 >= history_rect.top - padding
+                                && y as i32 <= history_rect.bottom + padding
+                        })
+                    };
+                    state.on_history_prev_btn = on_slot(prev_slot);
+                    state.on_history_next_btn = on_slot(next_slot);
+
+                    if let Some(slot) = state.snapshot_slot() {
+                        let snapshot_rect = get_snapshot_btn_rect(rect.right, rect.bottom, slot);
+                        state.on_snapshot_btn = x as i32 >= snapshot_rect.left - padding
+                            && x as i32 <= snapshot_rect.right + padding
+                            && y as i32 >= snapshot_rect.top - padding
+                            && y as i32 <= snapshot_rect.bottom + padding;
+                    } else {
+                        state.on_snapshot_btn = false;
+                    }
                 }
             } else {
                 // Overlay too small - clear all button hover states
                 state.on_copy_btn = false;
+                state.on_stop_btn = false;
                 state.on_edit_btn = false;
                 state.on_undo_btn = false;
                 state.on_redo_btn = false;
                 state.on_markdown_btn = false;
+                state.on_dual_pane_btn = false;
                 state.on_download_btn = false;
                 state.on_back_btn = false;
                 state.on_forward_btn = false;
                 state.on_speaker_btn = false;
+                state.on_language_btn = false;
+                state.on_table_export_btn = false;
+                state.on_retry_btn = false;
+                state.on_tts_pause_btn = false;
+                state.on_history_prev_btn = false;
+                state.on_history_next_btn = false;
+                state.on_snapshot_btn = false;
             }
 
             // In markdown mode, let the Timer handle is_hovered state to ensure it syncs with WebView resize
@@ -528,11 +728,20 @@ pub unsafe fn handle_mouse_leave(hwnd: HWND) -> LRESULT {
     if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
         // Clear button states
         state.on_copy_btn = false;
+        state.on_stop_btn = false;
         state.on_edit_btn = false;
         state.on_undo_btn = false;
         state.on_redo_btn = false;
         state.on_markdown_btn = false;
+        state.on_dual_pane_btn = false;
         state.on_download_btn = false;
+        state.on_language_btn = false;
+        state.on_table_export_btn = false;
+        state.on_retry_btn = false;
+        state.on_tts_pause_btn = false;
+        state.on_history_prev_btn = false;
+        state.on_history_next_btn = false;
+        state.on_snapshot_btn = false;
         state.on_back_btn = false;
         state.on_forward_btn = false;
         state.on_speaker_btn = false;
@@ -548,3 +757,26 @@ pub unsafe fn handle_mouse_leave(hwnd: HWND) -> LRESULT {
     }
     LRESULT(0)
 }
+
+/// Ctrl+wheel outside the page (the button strip) zooms the text like it does over the
+/// page. Other wheel input goes on to the default handling.
+pub unsafe fn handle_mouse_wheel(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    let ctrl_down = GetKeyState(VK_CONTROL.0 as i32) < 0;
+    let is_markdown = {
+        let states = WINDOW_STATES.lock().unwrap();
+        states
+            .get(&(hwnd.0 as isize))
+            .is_some_and(|state| state.is_markdown_mode)
+    };
+    if !ctrl_down || !is_markdown {
+        return DefWindowProcW(hwnd, msg, wparam, lparam);
+    }
+    // Wheel delta from wparam high word (signed); touchpads send less than a notch
+    let delta = ((wparam.0 >> 16) as i16) as i32;
+    let steps = match delta / WHEEL_DELTA as i32 {
+        0 => delta.signum(),
+        steps => steps,
+    };
+    markdown_view::zoom_font(hwnd, steps);
+    LRESULT(0)
+}
@@ -1,8 +1,11 @@
 use super::super::logic;
+use crate::overlay::result::arena_view;
 use crate::overlay::result::markdown_view;
 use crate::overlay::result::refine_input;
 use crate::overlay::result::state::{RefineContext, WINDOW_STATES};
 use crate::overlay::utils::to_wstring;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 use windows::core::PCWSTR;
 use windows::Win32::Foundation::*;
@@ -25,16 +28,21 @@ pub unsafe fn handle_timer(hwnd: HWND, wparam: WPARAM) -> LRESULT {
             && cursor_pos.y >= window_rect.top
             && cursor_pos.y < window_rect.bottom;
 
-        let (is_markdown_mode, current_hover_state) = {
+        let (is_markdown_mode, current_hover_state, is_taking_snapshot) = {
             let states = WINDOW_STATES.lock().unwrap();
             if let Some(state) = states.get(&(hwnd.0 as isize)) {
-                (state.is_markdown_mode, state.is_hovered)
+                (
+                    state.is_markdown_mode,
+                    state.is_hovered,
+                    state.is_taking_snapshot,
+                )
             } else {
-                (false, false)
+                (false, false, false)
             }
         };
 
-        if is_markdown_mode {
+        // A snapshot in progress keeps the WebView at full size
+        if is_markdown_mode && !is_taking_snapshot {
             // State change detection
             if cursor_inside && !current_hover_state {
                 // Enter: Mark hovered -> Shrink WebView -> Buttons visible
@@ -53,9 +61,18 @@ pub unsafe fn handle_timer(hwnd: HWND, wparam: WPARAM) -> LRESULT {
                     if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
                         state.is_hovered = false;
                         state.on_copy_btn = false;
+                        state.on_stop_btn = false;
                         state.on_undo_btn = false;
                         state.on_markdown_btn = false;
+                        state.on_dual_pane_btn = false;
                         state.on_download_btn = false;
+                        state.on_language_btn = false;
+                        state.on_table_export_btn = false;
+                        state.on_retry_btn = false;
+                        state.on_tts_pause_btn = false;
+                        state.on_history_prev_btn = false;
+                        state.on_history_next_btn = false;
+                        state.on_snapshot_btn = false;
                         state.on_back_btn = false;
                         state.on_forward_btn = false;
                     }
@@ -68,6 +85,28 @@ pub unsafe fn handle_timer(hwnd: HWND, wparam: WPARAM) -> LRESULT {
         return LRESULT(0);
     }
 
+    // Timer ID 3 (60 FPS): auto-dismiss countdown
+    if timer_id == 3 && auto_dismiss_due(hwnd) {
+        let linked_hwnd = WINDOW_STATES
+            .lock()
+            .unwrap()
+            .get(&(hwnd.0 as isize))
+            .and_then(|state| state.linked_window);
+        if let Some(linked) = linked_hwnd {
+            if IsWindow(Some(linked)).as_bool() {
+                let _ = PostMessageW(Some(linked), WM_CLOSE, WPARAM(0), LPARAM(0));
+            }
+        }
+        let _ = PostMessageW(Some(hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
+        return LRESULT(0);
+    }
+
+    // Timer ID 4: snapshot, once the window repainted without its buttons
+    if timer_id == crate::overlay::result::snapshot::SNAPSHOT_TIMER_ID {
+        crate::overlay::result::snapshot::finish(hwnd);
+        return LRESULT(0);
+    }
+
     // Timer ID 1 and other timers: existing logic
     let mut need_repaint = false;
     let mut pending_update: Option<String> = None;
@@ -148,6 +187,9 @@ pub unsafe fn handle_timer(hwnd: HWND, wparam: WPARAM) -> LRESULT {
                 // Hide the refine input
                 refine_input::hide_refine_input(hwnd);
 
+                // A refined arena window holds a single answer from now on
+                arena_view::end_arena(hwnd);
+
                 // Resize markdown WebView back to normal
                 let is_hovered = {
                     let states = WINDOW_STATES.lock().unwrap();
@@ -186,33 +228,48 @@ pub unsafe fn handle_timer(hwnd: HWND, wparam: WPARAM) -> LRESULT {
         let wide_text = to_wstring(&txt);
         let _ = SetWindowTextW(hwnd, PCWSTR(wide_text.as_ptr()));
 
-        let (maybe_markdown_update, is_hovered) = {
+        let (maybe_markdown_update, is_hovered, is_streaming) = {
             let mut states = WINDOW_STATES.lock().unwrap();
             if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
-                // 200ms font recalc throttling during streaming/text updates
+                // 200ms font recalc throttling during streaming/text updates. Text that
+                // arrives once the stream is done is always re-fit, so the final answer
+                // doesn't keep a font size measured on a partial one.
                 let now = SystemTime::now()
                     .duration_since(UNIX_EPOCH)
                     .map(|d| d.as_millis() as u32)
                     .unwrap_or(0);
                 let time_since_last_calc = now.wrapping_sub(state.last_font_calc_time);
-                if time_since_last_calc >= 200 || state.last_font_calc_time == 0 {
+                if !state.is_streaming_active
+                    || time_since_last_calc >= 200
+                    || state.last_font_calc_time == 0
+                {
                     state.font_cache_dirty = true;
                     state.last_font_calc_time = now;
                 }
                 state.full_text = txt.clone();
 
                 if state.is_markdown_mode && !state.is_refining {
-                    (Some(state.full_text.clone()), state.is_hovered)
+                    (
+                        Some(state.full_text.clone()),
+                        state.is_hovered,
+                        state.is_streaming_active,
+                    )
                 } else {
-                    (None, false)
+                    (None, false, false)
                 }
             } else {
-                (None, false)
+                (None, false, false)
             }
         };
 
         if let Some(md_text) = maybe_markdown_update {
-            markdown_view::create_markdown_webview(hwnd, &md_text, is_hovered);
+            if is_streaming {
+                // Only the trailing block is re-parsed while chunks arrive
+                markdown_view::stream_markdown_content(hwnd, &md_text, is_hovered);
+            } else {
+                // Final (or non-streamed) text: full render
+                markdown_view::create_markdown_webview(hwnd, &md_text, is_hovered);
+            }
         }
         need_repaint = true;
     }
@@ -240,6 +297,12 @@ pub unsafe fn handle_timer(hwnd: HWND, wparam: WPARAM) -> LRESULT {
             }
         };
 
+        // The stop button sets this; the refinement keeps whatever arrived so far
+        let stop_signal = Arc::new(AtomicBool::new(false));
+        if let Some(s) = WINDOW_STATES.lock().unwrap().get_mut(&(hwnd.0 as isize)) {
+            s.stop_signal = Some(stop_signal.clone());
+        }
+
         let (final_prev_text, final_user_prompt) =
             if text_to_refine.trim().is_empty() && !preset_prompt.is_empty() {
                 (user_input, preset_prompt)
@@ -256,30 +319,21 @@ pub unsafe fn handle_timer(hwnd: HWND, wparam: WPARAM) -> LRESULT {
 
             let capture_hwnd = HWND(hwnd_val as *mut std::ffi::c_void);
 
-            let (groq_key, gemini_key) = {
-                let app = crate::APP.lock().unwrap();
-                (
-                    app.config.api_key.clone(),
-                    app.config.gemini_api_key.clone(),
-                )
-            };
+            let config = crate::APP.lock().unwrap().config.clone();
 
             let mut acc_text = String::new();
             let mut first_chunk = true;
 
             let result = crate::api::refine_text_streaming(
-                &groq_key,
-                &gemini_key,
+                &config,
                 context_data,
                 final_prev_text,
                 final_user_prompt,
                 &model_id,
                 &provider,
                 streaming,
-                {
-                    let app = crate::APP.lock().unwrap();
-                    &app.config.ui_language.clone()
-                },
+                &config.ui_language,
+                &stop_signal,
                 move |chunk| {
                     let mut states = WINDOW_STATES.lock().unwrap();
                     if let Some(state) = states.get_mut(&(capture_hwnd.0 as isize)) {
@@ -339,3 +393,26 @@ pub unsafe fn handle_timer(hwnd: HWND, wparam: WPARAM) -> LRESULT {
     }
     LRESULT(0)
 }
+
+/// Whether the window has sat idle for its preset's auto-dismiss timeout.
+/// Any activity (hover, editing, streaming, reading aloud, dragging) restarts it.
+fn auto_dismiss_due(hwnd: HWND) -> bool {
+    let refine_active = refine_input::is_refine_input_active(hwnd);
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u32)
+        .unwrap_or(0);
+
+    let mut states = WINDOW_STATES.lock().unwrap();
+    let Some(state) = states.get_mut(&(hwnd.0 as isize)) else {
+        return false;
+    };
+    if state.auto_dismiss_secs.is_none() {
+        return false;
+    }
+    if refine_active || state.is_active() || state.last_activity_time == 0 {
+        state.last_activity_time = now;
+        return false;
+    }
+    state.auto_dismiss_left(now) == Some(0.0)
+}
@@ -67,13 +67,19 @@ fn would_overlap_existing(proposed: &RECT, existing: &[RECT], gap: i32) -> bool
 /// 4. Falls back to cascade positioning if all directions are blocked
 /// 
 /// Similar to the intelligent layout in node_graph.rs blocks_to_snarl()
-pub fn calculate_next_window_rect(prev: RECT, screen_w: i32, screen_h: i32) -> RECT {
+///
+/// `bounds` is the work area of the monitor `prev` is on, in virtual-screen
+/// coordinates (left/top can be negative for monitors left of or above the primary).
+pub fn calculate_next_window_rect(prev: RECT, bounds: RECT) -> RECT {
+    // Get all active window RECTs for collision detection
+    next_rect(prev, bounds, &get_all_active_window_rects())
+}
+
+/// The placement itself, against the given `existing` windows
+fn next_rect(prev: RECT, bounds: RECT, existing_windows: &[RECT]) -> RECT {
     let gap = 15;
     let w = (prev.right - prev.left).abs();
     let h = (prev.bottom - prev.top).abs();
-    
-    // Get all active window RECTs for collision detection
-    let existing_windows = get_all_active_window_rects();
 
     // 1. Try RIGHT
     let right_candidate = RECT {
@@ -82,8 +88,8 @@ pub fn calculate_next_window_rect(prev: RECT, screen_w: i32, screen_h: i32) -> R
         right: prev.right + gap + w,
         bottom: prev.bottom
     };
-    if right_candidate.right <= screen_w 
-        && !would_overlap_existing(&right_candidate, &existing_windows, gap) {
+    if right_candidate.right <= bounds.right 
+        && !would_overlap_existing(&right_candidate, existing_windows, gap) {
         return right_candidate;
     }
     
@@ -94,8 +100,8 @@ pub fn calculate_next_window_rect(prev: RECT, screen_w: i32, screen_h: i32) -> R
         right: prev.right,
         bottom: prev.bottom + gap + h
     };
-    if bottom_candidate.bottom <= screen_h 
-        && !would_overlap_existing(&bottom_candidate, &existing_windows, gap) {
+    if bottom_candidate.bottom <= bounds.bottom 
+        && !would_overlap_existing(&bottom_candidate, existing_windows, gap) {
         return bottom_candidate;
     }
 
@@ -106,8 +112,8 @@ pub fn calculate_next_window_rect(prev: RECT, screen_w: i32, screen_h: i32) -> R
         right: prev.left - gap,
         bottom: prev.bottom
     };
-    if left_candidate.left >= 0 
-        && !would_overlap_existing(&left_candidate, &existing_windows, gap) {
+    if left_candidate.left >= bounds.left 
+        && !would_overlap_existing(&left_candidate, existing_windows, gap) {
         return left_candidate;
     }
 
@@ -118,8 +124,8 @@ pub fn calculate_next_window_rect(prev: RECT, screen_w: i32, screen_h: i32) -> R
         right: prev.right,
         bottom: prev.top - gap
     };
-    if top_candidate.top >= 0 
-        && !would_overlap_existing(&top_candidate, &existing_windows, gap) {
+    if top_candidate.top >= bounds.top 
+        && !would_overlap_existing(&top_candidate, existing_windows, gap) {
         return top_candidate;
     }
     
@@ -136,8 +142,8 @@ pub fn calculate_next_window_rect(prev: RECT, screen_w: i32, screen_h: i32) -> R
     ];
     
     for diag in diagonals {
-        if diag.left >= 0 && diag.right <= screen_w && diag.top >= 0 && diag.bottom <= screen_h
-            && !would_overlap_existing(&diag, &existing_windows, gap) {
+        if diag.left >= bounds.left && diag.right <= bounds.right && diag.top >= bounds.top && diag.bottom <= bounds.bottom
+            && !would_overlap_existing(&diag, existing_windows, gap) {
             return diag;
         }
     }
@@ -154,8 +160,8 @@ pub fn calculate_next_window_rect(prev: RECT, screen_w: i32, screen_h: i32) -> R
         };
         
         // Clamp to screen bounds
-        if cascade.right <= screen_w && cascade.bottom <= screen_h 
-            && !would_overlap_existing(&cascade, &existing_windows, gap) {
+        if cascade.right <= bounds.right && cascade.bottom <= bounds.bottom 
+            && !would_overlap_existing(&cascade, existing_windows, gap) {
             return cascade;
         }
     }
@@ -212,8 +218,8 @@ pub fn get_markdown_btn_rect(window_w: i32, window_h: i32) -> RECT {
     }
 }
 
-// Download HTML button is between Markdown and Undo buttons
-pub fn get_download_btn_rect(window_w: i32, window_h: i32) -> RECT {
+// Dual pane (source | translation) button is between Markdown and Download buttons
+pub fn get_dual_pane_btn_rect(window_w: i32, window_h: i32) -> RECT {
     let md_rect = get_markdown_btn_rect(window_w, window_h);
     let gap = 8;
     let width = md_rect.right - md_rect.left;
@@ -225,6 +231,19 @@ pub fn get_download_btn_rect(window_w: i32, window_h: i32) -> RECT {
     }
 }
 
+// Download HTML button is between Dual pane and Undo buttons
+pub fn get_download_btn_rect(window_w: i32, window_h: i32) -> RECT {
+    let dual_rect = get_dual_pane_btn_rect(window_w, window_h);
+    let gap = 8;
+    let width = dual_rect.right - dual_rect.left;
+    RECT {
+        left: dual_rect.left - width - gap,
+        top: dual_rect.top,
+        right: dual_rect.left - gap,
+        bottom: dual_rect.bottom
+    }
+}
+
 pub fn get_undo_btn_rect(window_w: i32, window_h: i32) -> RECT {
     let dl_rect = get_download_btn_rect(window_w, window_h);
     let gap = 8;
@@ -262,6 +281,57 @@ pub fn get_speaker_btn_rect(window_w: i32, window_h: i32) -> RECT {
     }
 }
 
+/// Language switcher, bottom-left where Back sits while browsing
+pub fn get_language_btn_rect(window_w: i32, window_h: i32) -> RECT {
+    let copy_rect = get_copy_btn_rect(window_w, window_h);
+    let margin = 12;
+    RECT {
+        left: margin,
+        top: copy_rect.top,
+        right: margin + (copy_rect.right - copy_rect.left),
+        bottom: copy_rect.bottom
+    }
+}
+
+/// Bottom-left buttons line up from the language switcher's place; `slot` is the
+/// number of them shown before this one
+fn get_bottom_left_btn_rect(window_w: i32, window_h: i32, slot: i32) -> RECT {
+    let lang_rect = get_language_btn_rect(window_w, window_h);
+    let gap = 8;
+    let width = lang_rect.right - lang_rect.left;
+    let left = lang_rect.left + slot * (width + gap);
+    RECT {
+        left,
+        top: lang_rect.top,
+        right: left + width,
+        bottom: lang_rect.bottom
+    }
+}
+
+/// Table export, bottom-left: right of the language switcher when that is shown
+pub fn get_table_export_btn_rect(window_w: i32, window_h: i32, after_language: bool) -> RECT {
+    get_bottom_left_btn_rect(window_w, window_h, after_language as i32)
+}
+
+/// Record again for a pronunciation result, after the language switcher and table export
+pub fn get_retry_btn_rect(window_w: i32, window_h: i32, slot: i32) -> RECT {
+    get_bottom_left_btn_rect(window_w, window_h, slot)
+}
+
+/// TTS pause/resume while the window is read aloud, after the other bottom-left buttons
+pub fn get_tts_pause_btn_rect(window_w: i32, window_h: i32, slot: i32) -> RECT {
+    get_bottom_left_btn_rect(window_w, window_h, slot)
+}
+
+/// Previous / next recent result, last in the bottom-left row
+pub fn get_history_btn_rect(window_w: i32, window_h: i32, slot: i32) -> RECT {
+    get_bottom_left_btn_rect(window_w, window_h, slot)
+}
+
+/// Copy as image, after the recent result buttons
+pub fn get_snapshot_btn_rect(window_w: i32, window_h: i32, slot: i32) -> RECT {
+    get_bottom_left_btn_rect(window_w, window_h, slot)
+}
 
 pub fn get_resize_edge(width: i32, height: i32, x: i32, y: i32) -> ResizeEdge {
     let margin = 8;
@@ -280,3 +350,84 @@ pub fn get_resize_edge(width: i32, height: i32, x: i32, y: i32) -> ResizeEdge {
     else if bottom { ResizeEdge::Bottom }
     else { ResizeEdge::None }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(left: i32, top: i32, right: i32, bottom: i32) -> RECT {
+        RECT { left, top, right, bottom }
+    }
+
+    /// Primary 1920x1040 work area at the origin
+    const PRIMARY: RECT = RECT { left: 0, top: 0, right: 1920, bottom: 1040 };
+    /// Secondary 1280x984 work area left of and above the primary
+    const SECONDARY: RECT = RECT { left: -1280, top: -200, right: 0, bottom: 784 };
+
+    #[test]
+    fn goes_right_when_there_is_room() {
+        let prev = rect(100, 100, 500, 400);
+        assert_eq!(next_rect(prev, PRIMARY, &[]), rect(515, 100, 915, 400));
+    }
+
+    #[test]
+    fn goes_right_on_a_monitor_with_negative_coordinates() {
+        let prev = rect(-1200, -150, -800, 150);
+        assert_eq!(next_rect(prev, SECONDARY, &[]), rect(-785, -150, -385, 150));
+    }
+
+    #[test]
+    fn overflows_to_the_next_row_at_the_right_edge() {
+        // No room right of a window near the primary's right edge
+        let prev = rect(1500, 100, 1900, 400);
+        assert_eq!(next_rect(prev, PRIMARY, &[]), rect(1500, 415, 1900, 715));
+
+        // Same on the secondary monitor: its right edge is x = 0, not the primary's
+        let prev = rect(-420, -150, -20, 150);
+        assert_eq!(next_rect(prev, SECONDARY, &[]), rect(-420, 165, -20, 465));
+    }
+
+    #[test]
+    fn stays_inside_the_work_area() {
+        // Bottom-right corner of the secondary: right and below would leave it,
+        // left is free
+        let prev = rect(-420, 480, -20, 780);
+        let next = next_rect(prev, SECONDARY, &[]);
+        assert_eq!(next, rect(-835, 480, -435, 780));
+        assert!(next.left >= SECONDARY.left && next.right <= SECONDARY.right);
+        assert!(next.top >= SECONDARY.top && next.bottom <= SECONDARY.bottom);
+
+        // Top-left corner of the secondary: never placed on the primary to its right
+        // when the secondary still has room below
+        let prev = rect(-1280, -200, -20, 200);
+        let next = next_rect(prev, SECONDARY, &[]);
+        assert_eq!(next, rect(-1280, 215, -20, 615));
+    }
+
+    #[test]
+    fn skips_occupied_slots() {
+        let prev = rect(100, 100, 500, 400);
+        // Right slot taken: next row
+        let right = rect(515, 100, 915, 400);
+        assert_eq!(next_rect(prev, PRIMARY, &[right]), rect(100, 415, 500, 715));
+
+        // Right and bottom taken, no room left or above: bottom-right diagonal
+        let prev = rect(0, 0, 400, 300);
+        let below = rect(0, 315, 400, 615);
+        let right = rect(415, 0, 815, 300);
+        assert_eq!(
+            next_rect(prev, PRIMARY, &[right, below]),
+            rect(415, 315, 815, 615)
+        );
+    }
+
+    #[test]
+    fn occupied_windows_on_the_other_monitor_dont_block() {
+        let prev = rect(-1200, -150, -800, 150);
+        let on_primary = rect(100, -150, 500, 150);
+        assert_eq!(
+            next_rect(prev, SECONDARY, &[on_primary]),
+            rect(-785, -150, -385, 150)
+        );
+    }
+}
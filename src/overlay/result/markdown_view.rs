@@ -4,6 +4,7 @@ use raw_window_handle::{
 };
 use std::collections::HashMap;
 use std::num::NonZeroIsize;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Mutex, Once};
 use windows::core::w;
 use windows::Win32::Foundation::*;
@@ -19,10 +20,24 @@ lazy_static::lazy_static! {
     static ref WEBVIEW_READY: Mutex<bool> = Mutex::new(false);
     // Flag to skip next navigation handler call (set before history.back())
     static ref SKIP_NEXT_NAVIGATION: Mutex<HashMap<isize, bool>> = Mutex::new(HashMap::new());
+    // Streaming windows: markdown already rendered into #sgt-stream-stable
+    static ref MARKDOWN_STREAMS: Mutex<HashMap<isize, String>> = Mutex::new(HashMap::new());
+    // Windows in side-by-side mode: the source text shown in the left pane
+    static ref DUAL_PANES: Mutex<HashMap<isize, DualPane>> = Mutex::new(HashMap::new());
+    // Text size picked with Ctrl+wheel, as a factor of the page's 14px
+    static ref FONT_ZOOMS: Mutex<HashMap<isize, f32>> = Mutex::new(HashMap::new());
 }
 
+struct DualPane {
+    source: String,
+    split: f32, // Width of the source pane (0.0 - 1.0), set by dragging the divider
+}
+
+// Contents box of long answers shown open; its title toggles it for all windows
+static TOC_OPEN: AtomicBool = AtomicBool::new(true);
+
 // Global hidden window handle for WebView warmup
-static mut WARMUP_HWND: HWND = HWND(std::ptr::null_mut());
+pub(crate) static mut WARMUP_HWND: HWND = HWND(std::ptr::null_mut());
 static REGISTER_WARMUP_CLASS: Once = Once::new();
 
 // Thread-local storage for WebViews since they're not Send
@@ -208,7 +223,7 @@ fn get_font_style() -> String {
 const MARKDOWN_CSS: &str = r#"
     * { box-sizing: border-box; }
     body { 
-        font-family: 'Google Sans Flex', 'Segoe UI', -apple-system, sans-serif;
+        font-family: var(--overlay-font);
         font-optical-sizing: auto;
         font-variation-settings: 'wght' 400, 'wdth' 100, 'slnt' 0, 'ROND' 100;
         font-size: 14px;
@@ -309,6 +324,51 @@ const MARKDOWN_CSS: &str = r#"
     hr { border: none; border-top: 1px solid #444; margin: 1.5em 0; }
     img { max-width: 100%; border-radius: 8px; }
     
+    /* Outline: foldable headings and the contents box */
+    .sgt-foldable { cursor: pointer; user-select: none; }
+    .sgt-foldable::before {
+        content: '▾';
+        display: inline-block;
+        width: 1em;
+        font-size: 0.7em;
+        color: #666;
+        transition: transform 0.15s;
+    }
+    .sgt-foldable:hover::before { color: #4fc3f7; }
+    .sgt-collapsed::before { transform: rotate(-90deg); }
+    #sgt-toc {
+        background: #202020;
+        border: 1px solid #333;
+        border-radius: 8px;
+        padding: 6px 8px;
+        margin-bottom: 10px;
+        font-size: 0.9em;
+    }
+    .sgt-toc-title {
+        cursor: pointer;
+        user-select: none;
+        color: #4fc3f7;
+        font-variation-settings: 'wght' 550, 'wdth' 100, 'slnt' 0, 'ROND' 100;
+    }
+    .sgt-toc-title::before { content: '▾ '; }
+    #sgt-toc.sgt-toc-closed .sgt-toc-title::before { content: '▸ '; }
+    #sgt-toc.sgt-toc-closed a { display: none; }
+    #sgt-toc a {
+        display: block;
+        color: #aaa;
+        cursor: pointer;
+        padding: 1px 4px;
+        border-radius: 4px;
+        white-space: nowrap;
+        overflow: hidden;
+        text-overflow: ellipsis;
+    }
+    #sgt-toc a:hover { background: #2a2a2a; color: #81d4fa; text-decoration: none; }
+
+    /* Right-to-left answers; code in them stays left to right */
+    [dir="rtl"] { text-align: right; }
+    [dir="rtl"] pre, [dir="rtl"] code { direction: ltr; unicode-bidi: isolate; text-align: left; }
+
     /* Scrollbar styling - Hidden but scrollable */
     ::-webkit-scrollbar { display: none; }
 "#;
@@ -519,7 +579,7 @@ pub fn markdown_to_html(
         } else {
             format!("{}\n\n{}", preset_prompt, input_text)
         };
-        let quote = crate::overlay::utils::get_context_quote(&combined);
+        let quote = crate::api::text::get_context_quote(&combined);
         return format!(
             r#"<!DOCTYPE html>
 <html>
@@ -558,6 +618,11 @@ pub fn markdown_to_html(
         return inject_scrollbar_css(&with_grid);
     }
 
+    wrap_markdown_document(&render_markdown_fragment(markdown))
+}
+
+/// Parse markdown into an HTML fragment (no document wrapper)
+pub(super) fn render_markdown_fragment(markdown: &str) -> String {
     let mut options = Options::empty();
     options.insert(Options::ENABLE_TABLES);
     options.insert(Options::ENABLE_STRIKETHROUGH);
@@ -566,7 +631,202 @@ pub fn markdown_to_html(
     let parser = Parser::new_ext(markdown, options);
     let mut html_output = String::new();
     html::push_html(&mut html_output, parser);
+    html_output
+}
+
+/// Inline `ondblclick` for the page body: post a double-clicked word for lookup and
+/// remember where it was, so the popup can be placed next to it.
+/// An attribute rather than a <script>, so it survives document.write updates.
+const LOOKUP_ON_DBLCLICK: &str = "(function(w, e) { \
+    if (!w || w.length > 48 || /\\s/.test(w)) return; \
+    document.body.dataset.lookupWord = w; \
+    document.body.dataset.lookupX = e.clientX; \
+    document.body.dataset.lookupY = e.clientY; \
+    window.ipc.postMessage('lookup:' + w); \
+})(String(window.getSelection()).trim(), event)";
+
+/// Ctrl+wheel over the page zooms the window's text instead of WebView2's page zoom.
+/// document.open() drops window listeners, so this runs again after every write.
+const CTRL_WHEEL_ZOOM: &str = "window.addEventListener('wheel', function (e) { \
+    if (!e.ctrlKey) return; \
+    e.preventDefault(); \
+    window.ipc.postMessage('font_zoom:' + (e.deltaY < 0 ? 1 : -1)); \
+}, { passive: false });";
+
+/// Body font size of the page at 100%, from MARKDOWN_CSS
+const BASE_FONT_PX: f32 = 14.0;
+const FONT_ZOOM_STEP: f32 = 1.1;
+const FONT_ZOOM_MIN: f32 = 0.5;
+const FONT_ZOOM_MAX: f32 = 3.0;
+
+/// Script giving the page body the window's zoomed font size (the stylesheet's at 100%)
+fn font_zoom_script(hwnd_key: isize) -> String {
+    let zoom = FONT_ZOOMS
+        .lock()
+        .unwrap()
+        .get(&hwnd_key)
+        .copied()
+        .unwrap_or(1.0);
+    let size = if (zoom - 1.0).abs() < 0.01 {
+        String::new()
+    } else {
+        format!("{:.1}px", BASE_FONT_PX * zoom)
+    };
+    format!(
+        "if (document.body) document.body.style.fontSize = '{}';",
+        size
+    )
+}
+
+/// Outline of a long markdown answer: headings fold the section under them on click,
+/// and with three or more a contents box at the top jumps to them. Run on the page
+/// instead of shipped in it as a <script>, which would send every answer containing
+/// "let " down the recreate path of `content_needs_recreation`.
+/// Only pages marked `data-outline` (rendered answers, not web pages or raw HTML), and
+/// not while streaming, where the headings are still coming in.
+fn outline_script() -> String {
+    let ui_language = crate::APP
+        .lock()
+        .map(|app| app.config.ui_language.clone())
+        .unwrap_or_default();
+    let locale = crate::gui::locale::LocaleText::get(&ui_language);
+    let label_json = serde_json::to_string(locale.markdown_toc_label).unwrap_or_default();
+    format!(
+        r#"(function(label, open) {{
+    var body = document.body;
+    if (!body || !body.hasAttribute('data-outline')) return;
+    if (document.getElementById('sgt-stream-stable') || document.getElementById('sgt-toc')) return;
+    var heads = Array.prototype.slice.call(
+        body.querySelectorAll(':scope > h1, :scope > h2, :scope > h3, :scope > h4'));
+    if (heads.length < 2) return;
+
+    function level(el) {{
+        var m = /^H([1-4])$/.exec(el.tagName);
+        return m ? +m[1] : 0;
+    }}
+    // Hide what follows a folded heading, up to the next heading at its level or above
+    function apply() {{
+        var hideBelow = 0;
+        Array.prototype.forEach.call(body.children, function(el) {{
+            if (el.id && el.id.indexOf('sgt-') === 0) return;
+            var l = level(el);
+            if (l && (!hideBelow || l <= hideBelow)) {{
+                hideBelow = el.classList.contains('sgt-collapsed') ? l : 0;
+                el.style.display = '';
+            }} else {{
+                el.style.display = hideBelow ? 'none' : '';
+            }}
+        }});
+    }}
+    // Unfold the headings a heading is folded under
+    function reveal(h) {{
+        var l = level(h);
+        h.classList.remove('sgt-collapsed');
+        for (var el = h.previousElementSibling; el && l > 1; el = el.previousElementSibling) {{
+            var parentLevel = level(el);
+            if (parentLevel && parentLevel < l) {{
+                el.classList.remove('sgt-collapsed');
+                l = parentLevel;
+            }}
+        }}
+        apply();
+    }}
+    heads.forEach(function(h) {{
+        h.classList.add('sgt-foldable');
+        h.addEventListener('click', function() {{
+            h.classList.toggle('sgt-collapsed');
+            apply();
+        }});
+    }});
+    if (heads.length < 3) return;
+
+    var toc = document.createElement('nav');
+    toc.id = 'sgt-toc';
+    if (!open) toc.classList.add('sgt-toc-closed');
+    var title = document.createElement('div');
+    title.className = 'sgt-toc-title';
+    title.textContent = label;
+    title.addEventListener('click', function() {{
+        toc.classList.toggle('sgt-toc-closed');
+        window.ipc.postMessage('toc_open:' + (toc.classList.contains('sgt-toc-closed') ? 0 : 1));
+    }});
+    toc.appendChild(title);
+    var top = Math.min.apply(null, heads.map(level));
+    heads.forEach(function(h) {{
+        var a = document.createElement('a');
+        a.textContent = h.textContent;
+        a.style.paddingLeft = ((level(h) - top) * 12 + 4) + 'px';
+        a.addEventListener('click', function() {{
+            reveal(h);
+            h.scrollIntoView({{ behavior: 'smooth', block: 'start' }});
+        }});
+        toc.appendChild(a);
+    }});
+    body.insertBefore(toc, body.firstChild);
+}})({}, {});"#,
+        label_json,
+        TOC_OPEN.load(Ordering::Relaxed)
+    )
+}
+
+/// What runs after a page is written with document.write
+fn after_document_write(hwnd_key: isize) -> String {
+    format!(
+        " {} {} {}",
+        CTRL_WHEEL_ZOOM,
+        font_zoom_script(hwnd_key),
+        outline_script()
+    )
+}
+
+/// Make the window's text larger (positive steps) or smaller. Only the markdown page
+/// zooms: plain text is already sized to fill the window, and this zoom is a font size
+/// inside the page, so resizing the window keeps it.
+pub fn zoom_font(parent_hwnd: HWND, steps: i32) {
+    let hwnd_key = parent_hwnd.0 as isize;
+    {
+        let mut zooms = FONT_ZOOMS.lock().unwrap();
+        let zoom = zooms.entry(hwnd_key).or_insert(1.0);
+        *zoom = (*zoom * FONT_ZOOM_STEP.powi(steps)).clamp(FONT_ZOOM_MIN, FONT_ZOOM_MAX);
+    }
+    let is_browsing = super::state::WINDOW_STATES
+        .lock()
+        .unwrap()
+        .get(&hwnd_key)
+        .is_some_and(|s| s.is_browsing);
+    // Web pages opened from a link keep their own sizes
+    if !is_browsing {
+        evaluate_in_webview(hwnd_key, &font_zoom_script(hwnd_key));
+    }
+}
+
+/// The window closed: drop its text zoom
+pub fn forget_font_zoom(parent_hwnd: HWND) {
+    FONT_ZOOMS.lock().unwrap().remove(&(parent_hwnd.0 as isize));
+}
+
+/// `dir` for rendered HTML, from the letters outside its tags. Mixed runs inside are
+/// left to the browser's bidi algorithm.
+pub(super) fn html_text_direction(html: &str) -> &'static str {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    if crate::overlay::utils::is_rtl_text(&text) {
+        "rtl"
+    } else {
+        "ltr"
+    }
+}
 
+/// Wrap rendered markdown into the styled document (fonts, CSS, Grid.js for tables)
+pub(super) fn wrap_markdown_document(html_output: &str) -> String {
     // Grid.js Integration
     let has_table = html_output.contains("<table");
     let gridjs_head = if has_table {
@@ -602,7 +862,7 @@ pub fn markdown_to_html(
     <style>{}</style>
     {}
 </head>
-<body>
+<body data-outline dir="{}" ondblclick="{}">
     {}
     {}
 </body>
@@ -610,11 +870,128 @@ pub fn markdown_to_html(
         get_font_style(),
         MARKDOWN_CSS,
         gridjs_head,
+        html_text_direction(html_output),
+        LOOKUP_ON_DBLCLICK,
         html_output,
         gridjs_body
     )
 }
 
+/// Pick the page for a window: the arena comparison, the side-by-side view when it's
+/// enabled and the answer is markdown, or the regular markdown/HTML page.
+/// Streaming answers stay single-column until the final render.
+fn content_to_html(
+    hwnd_key: isize,
+    markdown: &str,
+    is_refining: bool,
+    preset_prompt: &str,
+    input_text: &str,
+) -> String {
+    if !is_refining {
+        if let Some(html) = super::arena_view::arena_html(hwnd_key) {
+            return html;
+        }
+    }
+    if !is_refining && !is_html_content(markdown) {
+        if let Some(pane) = DUAL_PANES.lock().unwrap().get(&hwnd_key) {
+            return dual_pane_to_html(&pane.source, markdown, pane.split);
+        }
+    }
+    markdown_to_html(markdown, is_refining, preset_prompt, input_text)
+}
+
+/// Two scroll-synced columns: source on the left, translation on the right,
+/// split by a draggable divider. Each pane has its own text direction.
+fn dual_pane_to_html(source: &str, translation: &str, split: f32) -> String {
+    let (source_html, translation_html) = (
+        render_markdown_fragment(source),
+        render_markdown_fragment(translation),
+    );
+    let body = format!(
+        r#"<div id="sgt-dual" dir="ltr">
+    <div class="sgt-pane" id="sgt-source" dir="{}">{}</div>
+    <div id="sgt-divider"></div>
+    <div class="sgt-pane" id="sgt-target" dir="{}">{}</div>
+</div>
+<style>
+    body {{ padding: 0; overflow: hidden; }}
+    #sgt-dual {{ display: flex; height: 100vh; }}
+    .sgt-pane {{ overflow-y: auto; padding: 8px; min-width: 0; }}
+    .sgt-pane > *:first-child {{ margin-top: 0; }}
+    #sgt-source {{ flex: 0 0 {:.1}%; color: #aaa; }}
+    #sgt-target {{ flex: 1 1 0; }}
+    #sgt-divider {{ flex: 0 0 5px; cursor: col-resize; background: #2a2a2a; }}
+    #sgt-divider:hover, #sgt-divider.dragging {{ background: #4fc3f7; }}
+</style>
+<script>
+(function() {{
+    const dual = document.getElementById('sgt-dual');
+    const source = document.getElementById('sgt-source');
+    const target = document.getElementById('sgt-target');
+    const divider = document.getElementById('sgt-divider');
+
+    // Scroll sync by relative position (the panes rarely have the same height)
+    let syncing = null;
+    function sync(from, to) {{
+        if (syncing && syncing !== from) return;
+        syncing = from;
+        const range = from.scrollHeight - from.clientHeight;
+        const ratio = range > 0 ? from.scrollTop / range : 0;
+        to.scrollTop = ratio * (to.scrollHeight - to.clientHeight);
+        requestAnimationFrame(() => {{ syncing = null; }});
+    }}
+    source.addEventListener('scroll', () => sync(source, target));
+    target.addEventListener('scroll', () => sync(target, source));
+
+    let split = null;
+    divider.addEventListener('pointerdown', (e) => {{
+        divider.setPointerCapture(e.pointerId);
+        divider.classList.add('dragging');
+    }});
+    divider.addEventListener('pointermove', (e) => {{
+        if (!divider.hasPointerCapture(e.pointerId)) return;
+        const rect = dual.getBoundingClientRect();
+        split = Math.min(0.85, Math.max(0.15, (e.clientX - rect.left) / rect.width));
+        source.style.flexBasis = (split * 100).toFixed(1) + '%';
+    }});
+    divider.addEventListener('pointerup', (e) => {{
+        divider.releasePointerCapture(e.pointerId);
+        divider.classList.remove('dragging');
+        if (split !== null) window.ipc.postMessage('dual_split:' + split);
+    }});
+}})();
+</script>"#,
+        html_text_direction(&source_html),
+        source_html,
+        html_text_direction(&translation_html),
+        translation_html,
+        split * 100.0
+    );
+    wrap_markdown_document(&body)
+}
+
+/// Turn the side-by-side view on (with the text for the source pane) or off.
+/// Takes effect on the next render.
+pub fn set_dual_pane_source(parent_hwnd: HWND, source: Option<&str>) {
+    let hwnd_key = parent_hwnd.0 as isize;
+    let mut panes = DUAL_PANES.lock().unwrap();
+    match source {
+        Some(source) => {
+            let split = panes.get(&hwnd_key).map(|p| p.split).unwrap_or(0.5);
+            panes.insert(
+                hwnd_key,
+                DualPane {
+                    source: source.to_string(),
+                    split,
+                },
+            );
+        }
+        None => {
+            panes.remove(&hwnd_key);
+        }
+    }
+}
+
 /// Create a WebView child window for markdown rendering
 /// Must be called from the main thread!
 pub fn create_markdown_webview(parent_hwnd: HWND, markdown_text: &str, is_hovered: bool) -> bool {
@@ -695,7 +1072,13 @@ pub fn create_markdown_webview_ex(
         let _ = GetClientRect(parent_hwnd, &mut rect);
     }
 
-    let html_content = markdown_to_html(markdown_text, is_refining, preset_prompt, input_text);
+    let html_content = content_to_html(
+        hwnd_key,
+        markdown_text,
+        is_refining,
+        preset_prompt,
+        input_text,
+    );
 
     let wrapper = HwndWrapper(parent_hwnd);
 
@@ -726,6 +1109,12 @@ pub fn create_markdown_webview_ex(
             )),
         })
         .with_html(&html_content)
+        .with_initialization_script(&format!(
+            "{} document.addEventListener('DOMContentLoaded', function () {{ {} {} }});",
+            CTRL_WHEEL_ZOOM,
+            font_zoom_script(hwnd_key),
+            outline_script()
+        ))
         .with_transparent(false)
         .with_navigation_handler(move |url: String| {
             // Check if we should skip this navigation (triggered by history.back())
@@ -814,12 +1203,39 @@ pub fn create_markdown_webview_ex(
                             SetLayeredWindowAttributes(parent_hwnd, COLORREF(0), alpha, LWA_ALPHA);
                     }
                 }
+            } else if let Some(index) = body.strip_prefix("arena_pick:") {
+                // Copy the chosen answer; it also becomes the window's text
+                let picked = index
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|i| super::arena_view::pick(parent_hwnd, i));
+                if let Some(text) = picked {
+                    crate::overlay::utils::copy_to_clipboard(&text, parent_hwnd);
+                    super::update_window_text(parent_hwnd, &text);
+                }
+            } else if let Some(csv) = body.strip_prefix("copy_csv:") {
+                crate::overlay::utils::copy_to_clipboard(csv, parent_hwnd);
+            } else if let Some(word) = body.strip_prefix("lookup:") {
+                super::word_lookup::request_lookup(parent_hwnd, word);
+            } else if let Some(steps) = body.strip_prefix("font_zoom:") {
+                if let Ok(steps) = steps.parse::<i32>() {
+                    zoom_font(parent_hwnd, steps);
+                }
+            } else if let Some(open) = body.strip_prefix("toc_open:") {
+                TOC_OPEN.store(open == "1", Ordering::Relaxed);
+            } else if let Some(split) = body.strip_prefix("dual_split:") {
+                if let Ok(split) = split.parse::<f32>() {
+                    if let Some(pane) = DUAL_PANES.lock().unwrap().get_mut(&hwnd_key) {
+                        pane.split = split.clamp(0.15, 0.85);
+                    }
+                }
             }
         })
         .build_as_child(&wrapper);
 
     match result {
         Ok(webview) => {
+            crate::overlay::webview_recovery::watch(&webview, parent_hwnd);
             WEBVIEWS.with(|webviews| {
                 webviews.borrow_mut().insert(hwnd_key, webview);
             });
@@ -967,7 +1383,16 @@ pub fn update_markdown_content_ex(
     input_text: &str,
 ) -> bool {
     let hwnd_key = parent_hwnd.0 as isize;
-    let html = markdown_to_html(markdown_text, is_refining, preset_prompt, input_text);
+    let html = content_to_html(
+        hwnd_key,
+        markdown_text,
+        is_refining,
+        preset_prompt,
+        input_text,
+    );
+
+    // A full render replaces the streaming page
+    MARKDOWN_STREAMS.lock().unwrap().remove(&hwnd_key);
 
     // Check if this content has scripts that need full browser capabilities
     // If so, we must recreate the WebView to get proper origin access
@@ -996,25 +1421,151 @@ pub fn update_markdown_content_ex(
     }
 
     // Fast path for simple content without scripts
+    let script = format!(
+        "document.open(); document.write(`{}`); document.close();{}",
+        escape_template_literal(&html),
+        after_document_write(hwnd_key)
+    );
+    evaluate_in_webview(hwnd_key, &script)
+}
+
+/// Escape text for embedding in a JS template literal
+fn escape_template_literal(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace("${", "\\${")
+}
+
+fn evaluate_in_webview(hwnd_key: isize, script: &str) -> bool {
     WEBVIEWS.with(|webviews| {
         if let Some(webview) = webviews.borrow().get(&hwnd_key) {
-            // For simple markdown, update body content via DOM manipulation
-            // This is safe because we verified there are no conflicting scripts
-            let escaped_html = html
-                .replace('\\', "\\\\")
-                .replace('`', "\\`")
-                .replace("${", "\\${");
-            let script = format!(
-                "document.open(); document.write(`{}`); document.close();",
-                escaped_html
-            );
-            let _ = webview.evaluate_script(&script);
+            let _ = webview.evaluate_script(script);
             return true;
         }
         false
     })
 }
 
+/// Show a double-click lookup in a popup next to the word (`None` = still loading).
+/// Ignored if the user has since double-clicked another word.
+pub fn show_word_lookup(parent_hwnd: HWND, word: &str, definition: Option<&str>) {
+    let hwnd_key = parent_hwnd.0 as isize;
+    let word_json = serde_json::to_string(word).unwrap_or_default();
+    let text_json = serde_json::to_string(definition.unwrap_or("…")).unwrap_or_default();
+    let script = format!(
+        r#"(function(word, text) {{
+    var body = document.body;
+    if (!body || body.dataset.lookupWord !== word) return;
+    var popup = document.getElementById('sgt-lookup');
+    if (!popup) {{
+        popup = document.createElement('div');
+        popup.id = 'sgt-lookup';
+        popup.style.cssText = 'position:fixed; z-index:9999; max-width:280px; padding:8px 10px; ' +
+            'background:#262626; color:#ddd; border:1px solid #444; border-radius:8px; ' +
+            'box-shadow:0 4px 14px rgba(0,0,0,0.5); font-size:13px; line-height:1.4;';
+        body.appendChild(popup);
+        document.addEventListener('mousedown', function close(e) {{
+            if (popup.contains(e.target)) return;
+            popup.remove();
+            document.removeEventListener('mousedown', close);
+        }});
+    }}
+    var title = document.createElement('div');
+    title.style.cssText = 'font-weight:bold; color:#81d4fa; margin-bottom:4px;';
+    title.textContent = word;
+    var entry = document.createElement('div');
+    entry.style.whiteSpace = 'pre-wrap';
+    entry.textContent = text;
+    popup.replaceChildren(title, entry);
+
+    var x = Number(body.dataset.lookupX) || 0;
+    var y = Number(body.dataset.lookupY) || 0;
+    popup.style.left = Math.max(4, Math.min(x, window.innerWidth - popup.offsetWidth - 4)) + 'px';
+    popup.style.top = (y + 16 + popup.offsetHeight > window.innerHeight
+        ? Math.max(4, y - popup.offsetHeight - 8)
+        : y + 16) + 'px';
+}})({}, {});"#,
+        word_json, text_json
+    );
+    evaluate_in_webview(hwnd_key, &script);
+}
+
+/// Byte offset up to which `markdown` is made of complete blocks: just past the last
+/// blank line outside a fenced code block. Anything after it may still change.
+fn stable_block_boundary(markdown: &str) -> usize {
+    let mut boundary = 0;
+    let mut offset = 0;
+    let mut in_fence = false;
+    for line in markdown.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        } else if !in_fence && line.ends_with('\n') && line.trim().is_empty() {
+            boundary = offset + line.len();
+        }
+        offset += line.len();
+    }
+    boundary
+}
+
+/// Render a markdown answer while it is still streaming in.
+///
+/// Complete blocks are parsed once and appended to the page; only the trailing block,
+/// which may still change, is re-parsed on every chunk. This avoids rewriting the whole
+/// document (and the flicker that comes with it) for each chunk. Once the stream ends,
+/// render the final text with `create_markdown_webview` so it gets a full, correct parse.
+pub fn stream_markdown_content(parent_hwnd: HWND, markdown_text: &str, is_hovered: bool) -> bool {
+    let hwnd_key = parent_hwnd.0 as isize;
+
+    // Raw HTML answers and arena pages can't be rendered piecewise
+    if !has_markdown_webview(parent_hwnd)
+        || is_html_content(markdown_text)
+        || super::arena_view::is_arena(parent_hwnd)
+    {
+        return create_markdown_webview(parent_hwnd, markdown_text, is_hovered);
+    }
+
+    let (stable, tail) = markdown_text.split_at(stable_block_boundary(markdown_text));
+    let committed = MARKDOWN_STREAMS.lock().unwrap().get(&hwnd_key).cloned();
+
+    let script = match committed {
+        // Same stream: append the newly completed blocks and replace the tail
+        Some(committed) if stable.starts_with(&committed) => format!(
+            "(function() {{ \
+                var s = document.getElementById('sgt-stream-stable'); \
+                var t = document.getElementById('sgt-stream-tail'); \
+                if (!s || !t) return; \
+                s.insertAdjacentHTML('beforeend', `{}`); \
+                t.innerHTML = `{}`; \
+            }})();",
+            escape_template_literal(&render_markdown_fragment(&stable[committed.len()..])),
+            escape_template_literal(&render_markdown_fragment(tail))
+        ),
+        // First chunk, or the text was rewritten (retry / WIPE_SIGNAL): write a fresh page
+        _ => {
+            let body = format!(
+                r#"<div id="sgt-stream-stable">{}</div><div id="sgt-stream-tail">{}</div>"#,
+                render_markdown_fragment(stable),
+                render_markdown_fragment(tail)
+            );
+            format!(
+                "document.open(); document.write(`{}`); document.close();{}",
+                escape_template_literal(&wrap_markdown_document(&body)),
+                after_document_write(hwnd_key)
+            )
+        }
+    };
+
+    let ok = evaluate_in_webview(hwnd_key, &script);
+    if ok {
+        MARKDOWN_STREAMS
+            .lock()
+            .unwrap()
+            .insert(hwnd_key, stable.to_string());
+    }
+    ok
+}
+
 /// Resize the WebView to match parent window
 /// When hovered: leaves 52px at bottom for buttons
 /// When not hovered: expands to full height for clean view
@@ -1087,6 +1638,8 @@ pub fn destroy_markdown_webview(parent_hwnd: HWND) {
         webviews.borrow_mut().remove(&hwnd_key);
     });
 
+    MARKDOWN_STREAMS.lock().unwrap().remove(&hwnd_key);
+
     let mut states = WEBVIEW_STATES.lock().unwrap();
     states.remove(&hwnd_key);
 }
@@ -1192,7 +1745,7 @@ fn generate_filename(content: &str) -> String {
             default_name
         }
         Err(e) => {
-            eprintln!("Failed to generate filename: {}", e);
+            log::error!("Failed to generate filename: {}", e);
             default_name
         }
     }
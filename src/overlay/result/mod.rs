@@ -5,7 +5,12 @@ pub mod layout;
 mod window;
 mod event_handler;
 pub mod markdown_view;
+pub mod arena_view;
 pub mod refine_input;
+pub mod word_lookup;
+pub mod table;
+pub mod recent;
+pub mod snapshot;
 
-pub use state::{WindowType, link_windows, RefineContext, WINDOW_STATES, close_windows_with_token};
+pub use state::{WindowType, link_windows, RefineContext, LanguageRerun, PronunciationRetry, ChainStep, WINDOW_STATES, close_windows_with_token, close_all_result_windows};
 pub use window::{create_result_window, update_window_text, get_chain_color};
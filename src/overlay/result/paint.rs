@@ -1,5 +1,9 @@
-use super::layout::should_show_buttons;
+use super::layout::{
+    get_history_btn_rect, get_retry_btn_rect, get_snapshot_btn_rect, get_table_export_btn_rect,
+    get_tts_pause_btn_rect, should_show_buttons,
+};
 use super::state::{ResizeEdge, WINDOW_STATES};
+use super::table::looks_like_table;
 use crate::overlay::broom_assets::{render_procedural_broom, BroomRenderParams, BROOM_H, BROOM_W};
 use crate::overlay::paint_utils::{hsv_to_rgb, sd_rounded_box};
 use std::mem::size_of;
@@ -121,6 +125,8 @@ pub fn paint_window(hwnd: HWND) {
             on_redo_btn,
             on_markdown_btn,
             is_markdown_mode,
+            on_dual_pane_btn,
+            is_dual_pane,
             is_browsing,
             on_back_btn,
             on_forward_btn,
@@ -136,6 +142,20 @@ pub fn paint_window(hwnd: HWND) {
             cached_bg_bm,
             is_refining,
             is_streaming_active,
+            on_stop_btn,
+            has_language,
+            on_language_btn,
+            has_table,
+            on_table_export_btn,
+            retry_slot,
+            on_retry_btn,
+            tts_pause_slot,
+            on_tts_pause_btn,
+            history_slots,
+            on_history_prev_btn,
+            on_history_next_btn,
+            snapshot_slot,
+            on_snapshot_btn,
             anim_offset,
             history_count,
             redo_count,
@@ -144,6 +164,8 @@ pub fn paint_window(hwnd: HWND) {
             graphics_mode,
             preset_prompt,
             input_text,
+            chain_step,
+            auto_dismiss_left,
         ) = {
             let mut states = WINDOW_STATES.lock().unwrap();
             if let Some(state) = states.get_mut(&(hwnd.0 as isize)) {
@@ -232,16 +254,26 @@ pub fn paint_window(hwnd: HWND) {
                 let is_closing = false;
 
                 let show_broom = !is_closing
+                    && !state.is_taking_snapshot
                     && (state.is_hovered
                         && !state.on_copy_btn
                         && !state.on_edit_btn
                         && !state.on_undo_btn
                         && !state.on_redo_btn
                         && !state.on_markdown_btn
+                        && !state.on_dual_pane_btn
                         && !state.on_back_btn
                         && !state.on_forward_btn
                         && !state.on_download_btn
                         && !state.on_speaker_btn
+                        && !state.on_stop_btn
+                        && !state.on_language_btn
+                        && !state.on_table_export_btn
+                        && !state.on_retry_btn
+                        && !state.on_tts_pause_btn
+                        && !state.on_history_prev_btn
+                        && !state.on_history_next_btn
+                        && !state.on_snapshot_btn
                         && state.current_resize_edge == ResizeEdge::None);
 
                 let broom_info = if show_broom {
@@ -265,7 +297,8 @@ pub fn paint_window(hwnd: HWND) {
 
                 (
                     state.bg_color,
-                    state.is_hovered,
+                    // The snapshot shows the result, not the buttons over it
+                    state.is_hovered && !state.is_taking_snapshot,
                     state.on_copy_btn,
                     state.copy_success,
                     state.on_edit_btn,
@@ -273,6 +306,8 @@ pub fn paint_window(hwnd: HWND) {
                     state.on_redo_btn,
                     state.on_markdown_btn,
                     state.is_markdown_mode,
+                    state.on_dual_pane_btn,
+                    state.is_dual_pane,
                     state.is_browsing,
                     state.on_back_btn,
                     state.on_forward_btn,
@@ -288,6 +323,23 @@ pub fn paint_window(hwnd: HWND) {
                     state.bg_bitmap,
                     state.is_refining,
                     state.is_streaming_active,
+                    state.on_stop_btn,
+                    state.language_rerun.is_some(),
+                    state.on_language_btn,
+                    looks_like_table(&state.full_text),
+                    state.on_table_export_btn,
+                    state
+                        .pronunciation_retry
+                        .is_some()
+                        .then(|| state.retry_slot()),
+                    state.on_retry_btn,
+                    state.is_tts_playing().then(|| state.tts_pause_slot()),
+                    state.on_tts_pause_btn,
+                    state.history_btn_slots(),
+                    state.on_history_prev_btn,
+                    state.on_history_next_btn,
+                    state.snapshot_slot(),
+                    state.on_snapshot_btn,
                     state.animation_offset,
                     state.text_history.len(),
                     state.redo_history.len(),
@@ -296,6 +348,13 @@ pub fn paint_window(hwnd: HWND) {
                     state.graphics_mode.clone(),
                     state.preset_prompt.clone(),
                     state.input_text.clone(),
+                    state.chain_step.clone(),
+                    state.auto_dismiss_left(
+                        std::time::SystemTime::now()
+                            .duration_since(std::time::UNIX_EPOCH)
+                            .map(|d| d.as_millis() as u32)
+                            .unwrap_or(0),
+                    ),
                 )
             } else {
                 (
@@ -315,6 +374,8 @@ pub fn paint_window(hwnd: HWND) {
                     false,
                     false,
                     false,
+                    false,
+                    false,
                     None,
                     Vec::new(),
                     HBITMAP::default(),
@@ -323,6 +384,20 @@ pub fn paint_window(hwnd: HWND) {
                     HBITMAP::default(),
                     false,
                     false,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                    None,
+                    false,
+                    None,
+                    false,
+                    (None, None),
+                    false,
+                    false,
+                    None,
+                    false,
                     0.0,
                     0,
                     0,
@@ -331,6 +406,8 @@ pub fn paint_window(hwnd: HWND) {
                     "standard".to_string(),
                     String::new(),
                     String::new(),
+                    None,
+                    None,
                 )
             }
         };
@@ -395,7 +472,13 @@ pub fn paint_window(hwnd: HWND) {
                 SetTextColor(cache_dc, COLORREF(0x00FFFFFF));
 
                 let mut buf = if is_refining {
-                    if !crate::overlay::utils::SHOW_REFINING_CONTEXT_QUOTE {
+                    if let Some(step) = &chain_step {
+                        // Which step of a long chain is being waited on
+                        step.status
+                            .encode_utf16()
+                            .chain(std::iter::once(0))
+                            .collect::<Vec<u16>>()
+                    } else if !crate::overlay::utils::SHOW_REFINING_CONTEXT_QUOTE {
                         vec![0u16; 1] // Return empty buffer
                     } else {
                         let combined = if input_text.is_empty() {
@@ -403,7 +486,7 @@ pub fn paint_window(hwnd: HWND) {
                         } else {
                             format!("{}\n\n{}", preset_prompt, input_text)
                         };
-                        let quote = crate::overlay::utils::get_context_quote(&combined);
+                        let quote = crate::api::text::get_context_quote(&combined);
                         quote
                             .encode_utf16()
                             .chain(std::iter::once(0))
@@ -495,6 +578,8 @@ pub fn paint_window(hwnd: HWND) {
 
                 let draw_flags = if is_refining {
                     DT_CENTER | DT_WORDBREAK
+                } else if crate::overlay::utils::is_rtl_text(&String::from_utf16_lossy(&buf)) {
+                    DT_RIGHT | DT_RTLREADING | DT_WORDBREAK
                 } else {
                     DT_LEFT | DT_WORDBREAK
                 };
@@ -633,6 +718,71 @@ pub fn paint_window(hwnd: HWND) {
                 }
             }
 
+            // 4.0b CHAIN PROGRESS: thin bar along the top edge, filled up to this step
+            if let Some(step) = chain_step
+                .as_ref()
+                .filter(|_| is_refining || is_streaming_active)
+            {
+                let filled = (width as usize * step.step / step.total.max(1)) as i32;
+                for y in 0..3.min(height) {
+                    for x in 0..filled.min(width) {
+                        let idx = (y * width + x) as usize;
+                        let bg_px = raw_pixels[idx];
+                        let bg_b = (bg_px & 0xFF) as f32;
+                        let bg_g = ((bg_px >> 8) & 0xFF) as f32;
+                        let bg_r = ((bg_px >> 16) & 0xFF) as f32;
+                        let intensity = 0.8;
+                        let out_r = (255.0 * intensity + bg_r * (1.0 - intensity)) as u32;
+                        let out_g = (255.0 * intensity + bg_g * (1.0 - intensity)) as u32;
+                        let out_b = (255.0 * intensity + bg_b * (1.0 - intensity)) as u32;
+                        raw_pixels[idx] = (255 << 24) | (out_r << 16) | (out_g << 8) | out_b;
+                    }
+                }
+            }
+
+            // 4.0c AUTO-DISMISS RING: the time left runs clockwise along the border, from
+            // the top-left corner. Drawn on the edge margin so markdown results show it too.
+            if let Some(left) = auto_dismiss_left.filter(|_| width > 4 && height > 4) {
+                let perimeter = 2.0 * (width + height) as f32;
+                let lit_until = perimeter * left;
+                let thickness = 2;
+                for y in 0..height {
+                    // Whole rows along the top and bottom, only the side columns between
+                    let on_row_edge = y < thickness || y >= height - thickness;
+                    let (left_end, right_start) = if on_row_edge {
+                        (width, width)
+                    } else {
+                        (thickness, width - thickness)
+                    };
+                    for x in (0..left_end).chain(right_start..width) {
+                        let (fx, fy) = (x as f32, y as f32);
+                        let (w, h) = (width as f32, height as f32);
+                        let along = if y < thickness {
+                            fx
+                        } else if x >= width - thickness {
+                            w + fy
+                        } else if y >= height - thickness {
+                            w + h + (w - fx)
+                        } else {
+                            2.0 * w + h + (h - fy)
+                        };
+                        if along > lit_until {
+                            continue;
+                        }
+                        let idx = (y * width + x) as usize;
+                        let bg_px = raw_pixels[idx];
+                        let bg_b = (bg_px & 0xFF) as f32;
+                        let bg_g = ((bg_px >> 8) & 0xFF) as f32;
+                        let bg_r = ((bg_px >> 16) & 0xFF) as f32;
+                        let intensity = 0.35;
+                        let out_r = (255.0 * intensity + bg_r * (1.0 - intensity)) as u32;
+                        let out_g = (255.0 * intensity + bg_g * (1.0 - intensity)) as u32;
+                        let out_b = (255.0 * intensity + bg_b * (1.0 - intensity)) as u32;
+                        raw_pixels[idx] = (255 << 24) | (out_r << 16) | (out_g << 8) | out_b;
+                    }
+                }
+            }
+
             // 4.1 Particles
             for (d_x, d_y, life, size, col) in particles {
                 if life <= 0.0 {
@@ -705,12 +855,13 @@ pub fn paint_window(hwnd: HWND) {
                 let cx_forward = (width - margin - btn_size / 2) as f32; // Forward on right when browsing
 
                 // Result UI button positions (only used when not browsing)
-                // Order from right to left: Copy -> Speaker -> Edit -> Markdown -> Download -> Undo -> Redo
+                // Order from right to left: Copy -> Speaker -> Edit -> Markdown -> Dual pane -> Download -> Undo -> Redo
                 let cx_copy = (width - margin - btn_size / 2) as f32;
                 let cx_speaker = cx_copy - (btn_size as f32) - 8.0;
                 let cx_edit = cx_speaker - (btn_size as f32) - 8.0;
                 let cx_md = cx_edit - (btn_size as f32) - 8.0;
-                let cx_dl = cx_md - (btn_size as f32) - 8.0;
+                let cx_dual = cx_md - (btn_size as f32) - 8.0;
+                let cx_dl = cx_dual - (btn_size as f32) - 8.0;
                 let cx_undo = cx_dl - (btn_size as f32) - 8.0;
                 let cx_redo = cx_undo - (btn_size as f32) - 8.0;
 
@@ -746,6 +897,13 @@ pub fn paint_window(hwnd: HWND) {
                 } else {
                     (80.0, 80.0, 80.0)
                 };
+                let (tr_dp, tg_dp, tb_dp) = if is_dual_pane && is_markdown_mode {
+                    (60.0, 180.0, 200.0)
+                } else if on_dual_pane_btn {
+                    (100.0, 140.0, 180.0)
+                } else {
+                    (80.0, 80.0, 80.0)
+                };
                 let (tr_b, tg_b, tb_b) = if on_back_btn {
                     (128.0, 128.0, 128.0)
                 } else {
@@ -977,6 +1135,30 @@ pub fn paint_window(hwnd: HWND) {
                                 }
                             }
 
+                            // DUAL PANE
+                            if !hit {
+                                let dx_dp = (fx - cx_dual).abs();
+                                let dist_dp = (dx_dp * dx_dp + dy * dy).sqrt();
+                                let aa_dp = (radius + 0.5 - dist_dp).clamp(0.0, 1.0);
+                                if aa_dp > 0.0 {
+                                    hit = true;
+                                    alpha = aa_dp;
+                                    t_r = tr_dp;
+                                    t_g = tg_dp;
+                                    t_b = tb_dp;
+                                    border_alpha = ((radius + 0.5 - dist_dp).clamp(0.0, 1.0)
+                                        * ((dist_dp - (border_inner_radius - 0.5))
+                                            .clamp(0.0, 1.0)))
+                                        * 0.6;
+                                    // Two columns: page outline split down the middle
+                                    let frame_d = sd_box(fx, fy, cx_dual, cy, 5.0, 4.5);
+                                    let d_frame = frame_d.abs();
+                                    let d_split =
+                                        dist_segment(fx, fy, cx_dual, cy - 4.5, cx_dual, cy + 4.5);
+                                    icon_alpha = (1.25 - d_frame.min(d_split)).clamp(0.0, 1.0);
+                                }
+                            }
+
                             // DOWNLOAD
                             if !hit {
                                 let dx_dl = (fx - cx_dl).abs();
@@ -1192,6 +1374,466 @@ pub fn paint_window(hwnd: HWND) {
                     }
                 }
             }
+
+            // 4.3 Stop button - takes the copy slot while a response is streaming in
+            if is_hovered
+                && is_streaming_active
+                && !is_refining
+                && should_show_buttons(width, height)
+            {
+                let btn_size = 28;
+                let margin = 12;
+                let threshold_h = btn_size + (margin * 2);
+                let cy = if height < threshold_h {
+                    (height as f32) / 2.0
+                } else {
+                    (height - margin - btn_size / 2) as f32
+                };
+                let cx = (width - margin - btn_size / 2) as f32;
+                let radius = 13.0;
+                let border_inner_radius = radius - 1.5;
+                let (t_r, t_g, t_b) = if on_stop_btn {
+                    (220.0, 70.0, 70.0)
+                } else {
+                    (170.0, 50.0, 50.0)
+                };
+
+                let y_range =
+                    ((cy - radius - 2.0) as i32).max(0)..((cy + radius + 2.0) as i32).min(height);
+                let x_range =
+                    ((cx - radius - 2.0) as i32).max(0)..((cx + radius + 2.0) as i32).min(width);
+                for y in y_range {
+                    for x in x_range.clone() {
+                        let fx = x as f32;
+                        let fy = y as f32;
+                        let dist = ((fx - cx).powi(2) + (fy - cy).powi(2)).sqrt();
+                        let alpha = (radius + 0.5 - dist).clamp(0.0, 1.0);
+                        if alpha <= 0.0 {
+                            continue;
+                        }
+                        let border_alpha =
+                            alpha * (dist - (border_inner_radius - 0.5)).clamp(0.0, 1.0) * 0.6;
+                        // Square "stop" glyph
+                        let icon_alpha = (0.8 - sd_box(fx, fy, cx, cy, 4.0, 4.0)).clamp(0.0, 1.0);
+
+                        let idx = (y * width + x) as usize;
+                        let bg = raw_pixels[idx];
+                        let a = 0.9 * alpha;
+                        let mut final_r = t_r * a + ((bg >> 16) & 0xFF) as f32 * (1.0 - a);
+                        let mut final_g = t_g * a + ((bg >> 8) & 0xFF) as f32 * (1.0 - a);
+                        let mut final_b = t_b * a + (bg & 0xFF) as f32 * (1.0 - a);
+                        final_r += 255.0 * border_alpha;
+                        final_g += 255.0 * border_alpha;
+                        final_b += 255.0 * border_alpha;
+                        final_r = 255.0 * icon_alpha + final_r * (1.0 - icon_alpha);
+                        final_g = 255.0 * icon_alpha + final_g * (1.0 - icon_alpha);
+                        final_b = 255.0 * icon_alpha + final_b * (1.0 - icon_alpha);
+
+                        raw_pixels[idx] = (255 << 24)
+                            | ((final_r.min(255.0) as u32) << 16)
+                            | ((final_g.min(255.0) as u32) << 8)
+                            | (final_b.min(255.0) as u32);
+                    }
+                }
+            }
+
+            // 4.4 Language button - bottom-left, re-runs the translation into another language
+            if is_hovered
+                && has_language
+                && !is_refining
+                && !is_streaming_active
+                && !is_browsing
+                && should_show_buttons(width, height)
+            {
+                let btn_size = 28;
+                let margin = 12;
+                let threshold_h = btn_size + (margin * 2);
+                let cy = if height < threshold_h {
+                    (height as f32) / 2.0
+                } else {
+                    (height - margin - btn_size / 2) as f32
+                };
+                let cx = (margin + btn_size / 2) as f32;
+                let radius = 13.0;
+                let border_inner_radius = radius - 1.5;
+                let (t_r, t_g, t_b) = if on_language_btn {
+                    (128.0, 128.0, 128.0)
+                } else {
+                    (80.0, 80.0, 80.0)
+                };
+
+                let y_range =
+                    ((cy - radius - 2.0) as i32).max(0)..((cy + radius + 2.0) as i32).min(height);
+                let x_range =
+                    ((cx - radius - 2.0) as i32).max(0)..((cx + radius + 2.0) as i32).min(width);
+                for y in y_range {
+                    for x in x_range.clone() {
+                        let fx = x as f32;
+                        let fy = y as f32;
+                        let dist = ((fx - cx).powi(2) + (fy - cy).powi(2)).sqrt();
+                        let alpha = (radius + 0.5 - dist).clamp(0.0, 1.0);
+                        if alpha <= 0.0 {
+                            continue;
+                        }
+                        let border_alpha =
+                            alpha * (dist - (border_inner_radius - 0.5)).clamp(0.0, 1.0) * 0.6;
+
+                        // Globe glyph: outline, equator and a meridian ellipse
+                        let (dx, dy) = (fx - cx, fy - cy);
+                        let ring = ((dist - 7.0).abs() - 0.7).max(0.0);
+                        let equator = if dx.abs() <= 7.0 {
+                            (dy.abs() - 0.6).max(0.0)
+                        } else {
+                            f32::MAX
+                        };
+                        let ellipse = ((dx / 3.0).powi(2) + (dy / 7.0).powi(2)).sqrt();
+                        let meridian = ((ellipse - 1.0).abs() * 3.0 - 0.6).max(0.0);
+                        let icon_alpha = (1.0 - ring.min(equator).min(meridian)).clamp(0.0, 1.0);
+
+                        let idx = (y * width + x) as usize;
+                        let bg = raw_pixels[idx];
+                        let a = 0.9 * alpha;
+                        let mut final_r = t_r * a + ((bg >> 16) & 0xFF) as f32 * (1.0 - a);
+                        let mut final_g = t_g * a + ((bg >> 8) & 0xFF) as f32 * (1.0 - a);
+                        let mut final_b = t_b * a + (bg & 0xFF) as f32 * (1.0 - a);
+                        final_r += 255.0 * border_alpha;
+                        final_g += 255.0 * border_alpha;
+                        final_b += 255.0 * border_alpha;
+                        final_r = 255.0 * icon_alpha + final_r * (1.0 - icon_alpha);
+                        final_g = 255.0 * icon_alpha + final_g * (1.0 - icon_alpha);
+                        final_b = 255.0 * icon_alpha + final_b * (1.0 - icon_alpha);
+
+                        raw_pixels[idx] = (255 << 24)
+                            | ((final_r.min(255.0) as u32) << 16)
+                            | ((final_g.min(255.0) as u32) << 8)
+                            | (final_b.min(255.0) as u32);
+                    }
+                }
+            }
+
+            // 4.5 Table export button - bottom-left, saves the result's table as CSV/Excel
+            if is_hovered
+                && has_table
+                && !is_refining
+                && !is_streaming_active
+                && !is_browsing
+                && should_show_buttons(width, height)
+            {
+                let btn_rect = get_table_export_btn_rect(width, height, has_language);
+                let cx = (btn_rect.left + btn_rect.right) as f32 / 2.0;
+                let cy = (btn_rect.top + btn_rect.bottom) as f32 / 2.0;
+                let radius = 13.0;
+                let border_inner_radius = radius - 1.5;
+                let (t_r, t_g, t_b) = if on_table_export_btn {
+                    (128.0, 128.0, 128.0)
+                } else {
+                    (80.0, 80.0, 80.0)
+                };
+
+                let y_range =
+                    ((cy - radius - 2.0) as i32).max(0)..((cy + radius + 2.0) as i32).min(height);
+                let x_range =
+                    ((cx - radius - 2.0) as i32).max(0)..((cx + radius + 2.0) as i32).min(width);
+                for y in y_range {
+                    for x in x_range.clone() {
+                        let fx = x as f32;
+                        let fy = y as f32;
+                        let dist = ((fx - cx).powi(2) + (fy - cy).powi(2)).sqrt();
+                        let alpha = (radius + 0.5 - dist).clamp(0.0, 1.0);
+                        if alpha <= 0.0 {
+                            continue;
+                        }
+                        let border_alpha =
+                            alpha * (dist - (border_inner_radius - 0.5)).clamp(0.0, 1.0) * 0.6;
+
+                        // Grid glyph: frame, header rule and a column rule
+                        let (dx, dy) = (fx - cx, fy - cy);
+                        let frame = (sd_rounded_box(dx, dy, 6.5, 5.5, 1.0).abs() - 0.7).max(0.0);
+                        let inside = dx.abs() <= 6.5 && dy.abs() <= 5.5;
+                        let header = if inside {
+                            ((dy + 1.5).abs() - 0.7).max(0.0)
+                        } else {
+                            f32::MAX
+                        };
+                        let column = if inside {
+                            (dx.abs() - 0.6).max(0.0)
+                        } else {
+                            f32::MAX
+                        };
+                        let icon_alpha = (1.0 - frame.min(header).min(column)).clamp(0.0, 1.0);
+
+                        let idx = (y * width + x) as usize;
+                        let bg = raw_pixels[idx];
+                        let a = 0.9 * alpha;
+                        let mut final_r = t_r * a + ((bg >> 16) & 0xFF) as f32 * (1.0 - a);
+                        let mut final_g = t_g * a + ((bg >> 8) & 0xFF) as f32 * (1.0 - a);
+                        let mut final_b = t_b * a + (bg & 0xFF) as f32 * (1.0 - a);
+                        final_r += 255.0 * border_alpha;
+                        final_g += 255.0 * border_alpha;
+                        final_b += 255.0 * border_alpha;
+                        final_r = 255.0 * icon_alpha + final_r * (1.0 - icon_alpha);
+                        final_g = 255.0 * icon_alpha + final_g * (1.0 - icon_alpha);
+                        final_b = 255.0 * icon_alpha + final_b * (1.0 - icon_alpha);
+
+                        raw_pixels[idx] = (255 << 24)
+                            | ((final_r.min(255.0) as u32) << 16)
+                            | ((final_g.min(255.0) as u32) << 8)
+                            | (final_b.min(255.0) as u32);
+                    }
+                }
+            }
+
+            // 4.55 Record again button - bottom-left on a pronunciation result
+            if let Some(slot) = retry_slot.filter(|_| {
+                is_hovered
+                    && !is_refining
+                    && !is_streaming_active
+                    && !is_browsing
+                    && should_show_buttons(width, height)
+            }) {
+                let btn_rect = get_retry_btn_rect(width, height, slot);
+                let cx = (btn_rect.left + btn_rect.right) as f32 / 2.0;
+                let cy = (btn_rect.top + btn_rect.bottom) as f32 / 2.0;
+                let radius = 13.0;
+                let border_inner_radius = radius - 1.5;
+                let (t_r, t_g, t_b) = if on_retry_btn {
+                    (128.0, 128.0, 128.0)
+                } else {
+                    (80.0, 80.0, 80.0)
+                };
+
+                let y_range =
+                    ((cy - radius - 2.0) as i32).max(0)..((cy + radius + 2.0) as i32).min(height);
+                let x_range =
+                    ((cx - radius - 2.0) as i32).max(0)..((cx + radius + 2.0) as i32).min(width);
+                for y in y_range {
+                    for x in x_range.clone() {
+                        let fx = x as f32;
+                        let fy = y as f32;
+                        let dist = ((fx - cx).powi(2) + (fy - cy).powi(2)).sqrt();
+                        let alpha = (radius + 0.5 - dist).clamp(0.0, 1.0);
+                        if alpha <= 0.0 {
+                            continue;
+                        }
+                        let border_alpha =
+                            alpha * (dist - (border_inner_radius - 0.5)).clamp(0.0, 1.0) * 0.6;
+
+                        // Red record dot
+                        let icon_alpha = (5.0 - dist).clamp(0.0, 1.0);
+
+                        let idx = (y * width + x) as usize;
+                        let bg = raw_pixels[idx];
+                        let a = 0.9 * alpha;
+                        let mut final_r = t_r * a + ((bg >> 16) & 0xFF) as f32 * (1.0 - a);
+                        let mut final_g = t_g * a + ((bg >> 8) & 0xFF) as f32 * (1.0 - a);
+                        let mut final_b = t_b * a + (bg & 0xFF) as f32 * (1.0 - a);
+                        final_r += 255.0 * border_alpha;
+                        final_g += 255.0 * border_alpha;
+                        final_b += 255.0 * border_alpha;
+                        final_r = 239.0 * icon_alpha + final_r * (1.0 - icon_alpha);
+                        final_g = 83.0 * icon_alpha + final_g * (1.0 - icon_alpha);
+                        final_b = 80.0 * icon_alpha + final_b * (1.0 - icon_alpha);
+
+                        raw_pixels[idx] = (255 << 24)
+                            | ((final_r.min(255.0) as u32) << 16)
+                            | ((final_g.min(255.0) as u32) << 8)
+                            | (final_b.min(255.0) as u32);
+                    }
+                }
+            }
+
+            // 4.6 TTS pause button - bottom-left while the result is read aloud
+            if let Some(slot) = tts_pause_slot
+                .filter(|_| is_hovered && !is_browsing && should_show_buttons(width, height))
+            {
+                let btn_rect = get_tts_pause_btn_rect(width, height, slot);
+                let tts_paused = crate::api::tts::TTS_MANAGER.is_paused();
+                let cx = (btn_rect.left + btn_rect.right) as f32 / 2.0;
+                let cy = (btn_rect.top + btn_rect.bottom) as f32 / 2.0;
+                let radius = 13.0;
+                let border_inner_radius = radius - 1.5;
+                let (t_r, t_g, t_b) = if on_tts_pause_btn {
+                    (128.0, 128.0, 128.0)
+                } else {
+                    (80.0, 80.0, 80.0)
+                };
+
+                let y_range =
+                    ((cy - radius - 2.0) as i32).max(0)..((cy + radius + 2.0) as i32).min(height);
+                let x_range =
+                    ((cx - radius - 2.0) as i32).max(0)..((cx + radius + 2.0) as i32).min(width);
+                for y in y_range {
+                    for x in x_range.clone() {
+                        let fx = x as f32;
+                        let fy = y as f32;
+                        let dist = ((fx - cx).powi(2) + (fy - cy).powi(2)).sqrt();
+                        let alpha = (radius + 0.5 - dist).clamp(0.0, 1.0);
+                        if alpha <= 0.0 {
+                            continue;
+                        }
+                        let border_alpha =
+                            alpha * (dist - (border_inner_radius - 0.5)).clamp(0.0, 1.0) * 0.6;
+
+                        // Play triangle while paused, else two pause bars
+                        let (dx, dy) = (fx - cx, fy - cy);
+                        let glyph = if tts_paused {
+                            // Triangle pointing right: -4 <= dx, |dy| <= (5 - dx) * 0.6
+                            let edge = (dy.abs() - (5.0 - dx) * 0.6) / 1.17;
+                            edge.max(-4.0 - dx)
+                        } else {
+                            sd_rounded_box(dx.abs() - 2.5, dy, 1.3, 5.5, 0.5)
+                        };
+                        let icon_alpha = (0.5 - glyph).clamp(0.0, 1.0);
+
+                        let idx = (y * width + x) as usize;
+                        let bg = raw_pixels[idx];
+                        let a = 0.9 * alpha;
+                        let mut final_r = t_r * a + ((bg >> 16) & 0xFF) as f32 * (1.0 - a);
+                        let mut final_g = t_g * a + ((bg >> 8) & 0xFF) as f32 * (1.0 - a);
+                        let mut final_b = t_b * a + (bg & 0xFF) as f32 * (1.0 - a);
+                        final_r += 255.0 * border_alpha;
+                        final_g += 255.0 * border_alpha;
+                        final_b += 255.0 * border_alpha;
+                        final_r = 255.0 * icon_alpha + final_r * (1.0 - icon_alpha);
+                        final_g = 255.0 * icon_alpha + final_g * (1.0 - icon_alpha);
+                        final_b = 255.0 * icon_alpha + final_b * (1.0 - icon_alpha);
+
+                        raw_pixels[idx] = (255 << 24)
+                            | ((final_r.min(255.0) as u32) << 16)
+                            | ((final_g.min(255.0) as u32) << 8)
+                            | (final_b.min(255.0) as u32);
+                    }
+                }
+            }
+
+            // 4.7 Recent result buttons - older / newer result of the session
+            let history_buttons = [
+                (history_slots.0, on_history_prev_btn, -1.0f32),
+                (history_slots.1, on_history_next_btn, 1.0f32),
+            ];
+            for (slot, is_hot, dir) in history_buttons {
+                let Some(slot) = slot
+                    .filter(|_| is_hovered && !is_browsing && should_show_buttons(width, height))
+                else {
+                    continue;
+                };
+                let btn_rect = get_history_btn_rect(width, height, slot);
+                let cx = (btn_rect.left + btn_rect.right) as f32 / 2.0;
+                let cy = (btn_rect.top + btn_rect.bottom) as f32 / 2.0;
+                let radius = 13.0;
+                let border_inner_radius = radius - 1.5;
+                let (t_r, t_g, t_b) = if is_hot {
+                    (128.0, 128.0, 128.0)
+                } else {
+                    (80.0, 80.0, 80.0)
+                };
+
+                let y_range =
+                    ((cy - radius - 2.0) as i32).max(0)..((cy + radius + 2.0) as i32).min(height);
+                let x_range =
+                    ((cx - radius - 2.0) as i32).max(0)..((cx + radius + 2.0) as i32).min(width);
+                for y in y_range {
+                    for x in x_range.clone() {
+                        let fx = x as f32;
+                        let fy = y as f32;
+                        let dist = ((fx - cx).powi(2) + (fy - cy).powi(2)).sqrt();
+                        let alpha = (radius + 0.5 - dist).clamp(0.0, 1.0);
+                        if alpha <= 0.0 {
+                            continue;
+                        }
+                        let border_alpha =
+                            alpha * (dist - (border_inner_radius - 0.5)).clamp(0.0, 1.0) * 0.6;
+
+                        // Chevron: two strokes meeting at the tip, pointing along dir
+                        let dx = (fx - cx) * dir;
+                        let dy = (fy - cy).abs();
+                        let (ux, uy) = (0.6f32, 0.8f32);
+                        let (px, py) = (dx - 2.5, dy);
+                        let t = (-px * ux + py * uy).clamp(0.0, 6.0);
+                        let stroke = ((px + t * ux).powi(2) + (py - t * uy).powi(2)).sqrt() - 1.1;
+                        let icon_alpha = (0.5 - stroke).clamp(0.0, 1.0);
+
+                        let idx = (y * width + x) as usize;
+                        let bg = raw_pixels[idx];
+                        let a = 0.9 * alpha;
+                        let mut final_r = t_r * a + ((bg >> 16) & 0xFF) as f32 * (1.0 - a);
+                        let mut final_g = t_g * a + ((bg >> 8) & 0xFF) as f32 * (1.0 - a);
+                        let mut final_b = t_b * a + (bg & 0xFF) as f32 * (1.0 - a);
+                        final_r += 255.0 * border_alpha;
+                        final_g += 255.0 * border_alpha;
+                        final_b += 255.0 * border_alpha;
+                        final_r = 255.0 * icon_alpha + final_r * (1.0 - icon_alpha);
+                        final_g = 255.0 * icon_alpha + final_g * (1.0 - icon_alpha);
+                        final_b = 255.0 * icon_alpha + final_b * (1.0 - icon_alpha);
+
+                        raw_pixels[idx] = (255 << 24)
+                            | ((final_r.min(255.0) as u32) << 16)
+                            | ((final_g.min(255.0) as u32) << 8)
+                            | (final_b.min(255.0) as u32);
+                    }
+                }
+            }
+
+            // 4.8 Snapshot button - copy the window as an image
+            if let Some(slot) = snapshot_slot
+                .filter(|_| is_hovered && !is_browsing && should_show_buttons(width, height))
+            {
+                let btn_rect = get_snapshot_btn_rect(width, height, slot);
+                let cx = (btn_rect.left + btn_rect.right) as f32 / 2.0;
+                let cy = (btn_rect.top + btn_rect.bottom) as f32 / 2.0;
+                let radius = 13.0;
+                let border_inner_radius = radius - 1.5;
+                let (t_r, t_g, t_b) = if on_snapshot_btn {
+                    (128.0, 128.0, 128.0)
+                } else {
+                    (80.0, 80.0, 80.0)
+                };
+
+                let y_range =
+                    ((cy - radius - 2.0) as i32).max(0)..((cy + radius + 2.0) as i32).min(height);
+                let x_range =
+                    ((cx - radius - 2.0) as i32).max(0)..((cx + radius + 2.0) as i32).min(width);
+                for y in y_range {
+                    for x in x_range.clone() {
+                        let fx = x as f32;
+                        let fy = y as f32;
+                        let dist = ((fx - cx).powi(2) + (fy - cy).powi(2)).sqrt();
+                        let alpha = (radius + 0.5 - dist).clamp(0.0, 1.0);
+                        if alpha <= 0.0 {
+                            continue;
+                        }
+                        let border_alpha =
+                            alpha * (dist - (border_inner_radius - 0.5)).clamp(0.0, 1.0) * 0.6;
+
+                        // Camera: outlined body with a bump on top, and a lens
+                        let (dx, dy) = (fx - cx, fy - cy);
+                        let body = sd_rounded_box(dx, dy - 1.0, 6.5, 4.5, 1.5);
+                        let bump = sd_rounded_box(dx, dy + 4.5, 2.5, 1.5, 0.5);
+                        let outline = body.min(bump).abs() - 0.6;
+                        let lens = ((dx * dx + (dy - 1.0).powi(2)).sqrt() - 2.2).abs() - 0.6;
+                        let glyph = outline.min(lens);
+                        let icon_alpha = (0.5 - glyph).clamp(0.0, 1.0);
+
+                        let idx = (y * width + x) as usize;
+                        let bg = raw_pixels[idx];
+                        let a = 0.9 * alpha;
+                        let mut final_r = t_r * a + ((bg >> 16) & 0xFF) as f32 * (1.0 - a);
+                        let mut final_g = t_g * a + ((bg >> 8) & 0xFF) as f32 * (1.0 - a);
+                        let mut final_b = t_b * a + (bg & 0xFF) as f32 * (1.0 - a);
+                        final_r += 255.0 * border_alpha;
+                        final_g += 255.0 * border_alpha;
+                        final_b += 255.0 * border_alpha;
+                        final_r = 255.0 * icon_alpha + final_r * (1.0 - icon_alpha);
+                        final_g = 255.0 * icon_alpha + final_g * (1.0 - icon_alpha);
+                        final_b = 255.0 * icon_alpha + final_b * (1.0 - icon_alpha);
+
+                        raw_pixels[idx] = (255 << 24)
+                            | ((final_r.min(255.0) as u32) << 16)
+                            | ((final_g.min(255.0) as u32) << 8)
+                            | (final_b.min(255.0) as u32);
+                    }
+                }
+            }
         }
 
         // --- PHASE 5: DYNAMIC BROOM ---
@@ -0,0 +1,137 @@
+//! Recent results of the session. A result window flips back and forth through them
+//! (bottom-left ‹ › buttons, Alt+Left/Right) instead of the user reopening them.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use windows::Win32::Foundation::HWND;
+use windows::Win32::Graphics::Gdi::InvalidateRect;
+
+use super::markdown_view;
+use super::state::{LanguageRerun, WINDOW_STATES};
+
+/// How many results the session keeps
+const MAX_RECENT: usize = 12;
+
+#[derive(Clone)]
+struct RecentResult {
+    id: u64,
+    text: String,
+    is_markdown: bool,
+    /// What the result was made from, for the side-by-side view
+    source_text: String,
+    /// Lets the language switcher re-run the block that made it
+    language_rerun: Option<LanguageRerun>,
+}
+
+struct Recent {
+    results: VecDeque<RecentResult>,
+    next_id: u64,
+}
+
+lazy_static::lazy_static! {
+    static ref RECENT: Mutex<Recent> = Mutex::new(Recent {
+        results: VecDeque::new(),
+        next_id: 1,
+    });
+}
+
+/// Remember the result a window just finished with; the window is then at that entry
+pub fn record(hwnd: HWND, text: &str) {
+    if text.trim().is_empty() {
+        return;
+    }
+    let mut states = WINDOW_STATES.lock().unwrap();
+    let Some(state) = states.get_mut(&(hwnd.0 as isize)) else {
+        return;
+    };
+    let mut recent = RECENT.lock().unwrap();
+    let id = recent.next_id;
+    recent.next_id += 1;
+    recent.results.push_back(RecentResult {
+        id,
+        text: text.to_string(),
+        is_markdown: state.is_markdown_mode,
+        source_text: state.source_text.clone(),
+        language_rerun: state.language_rerun.clone(),
+    });
+    while recent.results.len() > MAX_RECENT {
+        recent.results.pop_front();
+    }
+    state.recent_id = Some(id);
+}
+
+/// Whether there are older / newer results than the entry a window is at. A window
+/// whose result was never recorded, or has dropped out, has neither.
+pub fn neighbours(current: Option<u64>) -> (bool, bool) {
+    let recent = RECENT.lock().unwrap();
+    match current.and_then(|id| recent.results.iter().position(|r| r.id == id)) {
+        Some(pos) => (pos > 0, pos + 1 < recent.results.len()),
+        None => (false, false),
+    }
+}
+
+/// Show the older (or newer) result in the window. Returns false if there is none.
+pub fn step(hwnd: HWND, older: bool) -> bool {
+    let entry = {
+        let states = WINDOW_STATES.lock().unwrap();
+        let Some(state) = states.get(&(hwnd.0 as isize)) else {
+            return false;
+        };
+        if state.is_refining || state.is_streaming_active {
+            return false;
+        }
+        let recent = RECENT.lock().unwrap();
+        let Some(pos) = state
+            .recent_id
+            .and_then(|id| recent.results.iter().position(|r| r.id == id))
+        else {
+            return false;
+        };
+        let target = if older {
+            pos.checked_sub(1)
+        } else {
+            Some(pos + 1)
+        };
+        match target.and_then(|t| recent.results.get(t)) {
+            Some(entry) => entry.clone(),
+            None => return false,
+        }
+    };
+    show(hwnd, entry);
+    true
+}
+
+fn show(hwnd: HWND, entry: RecentResult) {
+    let (was_markdown, is_dual_pane) = {
+        let mut states = WINDOW_STATES.lock().unwrap();
+        let Some(state) = states.get_mut(&(hwnd.0 as isize)) else {
+            return;
+        };
+        let was_markdown = state.is_markdown_mode;
+        state.recent_id = Some(entry.id);
+        state.full_text = entry.text.clone();
+        state.pending_text = Some(entry.text.clone());
+        state.source_text = entry.source_text.clone();
+        state.language_rerun = entry.language_rerun.clone();
+        state.is_markdown_mode = entry.is_markdown;
+        state.is_dual_pane &= entry.is_markdown;
+        // Undo steps belonged to the result shown before
+        state.text_history.clear();
+        state.redo_history.clear();
+        state.font_cache_dirty = true;
+        (was_markdown, state.is_dual_pane)
+    };
+
+    if is_dual_pane {
+        markdown_view::set_dual_pane_source(hwnd, Some(entry.source_text.as_str()));
+    }
+    // The pending text renders into the WebView on the next tick when in markdown mode
+    unsafe {
+        if entry.is_markdown && !was_markdown {
+            super::event_handler::click_actions::enter_markdown_mode(hwnd);
+        } else if !entry.is_markdown && was_markdown {
+            super::event_handler::click_actions::leave_markdown_mode(hwnd);
+        }
+        let _ = InvalidateRect(Some(hwnd), None, false);
+    }
+}
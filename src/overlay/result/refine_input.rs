@@ -267,10 +267,14 @@ const REFINE_CSS: &str = r#"
     }
 "#;
 
-/// Generate HTML for the refine input
-fn get_refine_html(placeholder: &str) -> String {
+/// Generate HTML for the refine input, optionally pre-filled with `initial_text`
+fn get_refine_html(placeholder: &str, initial_text: &str) -> String {
     let font_css = crate::overlay::html_components::font_manager::get_font_css();
     let escaped = placeholder.replace('\'', "\\'");
+    let initial_value = initial_text
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;");
     format!(
         r#"<!DOCTYPE html>
 <html>
@@ -280,7 +284,7 @@ fn get_refine_html(placeholder: &str) -> String {
 </head>
 <body>
     <div class="container">
-        <input type="text" id="editor" placeholder="{}" autofocus>
+        <input type="text" id="editor" placeholder="{}" value="{}" autofocus>
         <button class="mic-btn" id="micBtn" title="Speech to text">
             <svg viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg">
                 <path d="M12 14c1.66 0 3-1.34 3-3V5c0-1.66-1.34-3-3-3S9 3.34 9 5v6c0 1.66 1.34 3 3 3z"/>
@@ -292,7 +296,7 @@ fn get_refine_html(placeholder: &str) -> String {
                 <path d="M2.01 21L23 12 2.01 3 2 10l15 2-15 2z"/>
             </svg>
         </button>
-        <span class="hint">Esc ✕</span>
+        <span class="hint">Ctrl+Enter ➤ · Esc ✕</span>
     </div>
     <script>
         const editor = document.getElementById('editor');
@@ -300,10 +304,14 @@ fn get_refine_html(placeholder: &str) -> String {
         const sendBtn = document.getElementById('sendBtn');
         
         window.onload = () => {{
-            setTimeout(() => editor.focus(), 50);
+            setTimeout(() => {{
+                editor.focus();
+                editor.setSelectionRange(editor.value.length, editor.value.length);
+            }}, 50);
         }};
         
         editor.addEventListener('keydown', (e) => {{
+            // Enter or Ctrl+Enter = Submit the refinement
             if (e.key === 'Enter') {{
                 e.preventDefault();
                 const text = editor.value.trim();
@@ -348,13 +356,13 @@ fn get_refine_html(placeholder: &str) -> String {
     </script>
 </body>
 </html>"#,
-        font_css, REFINE_CSS, escaped
+        font_css, REFINE_CSS, escaped, initial_value
     )
 }
 
-/// Show the refine input above the markdown view
+/// Show the refine input above the markdown view, pre-filled with `initial_text`
 /// Returns the child window handle for positioning
-pub fn show_refine_input(parent_hwnd: HWND, placeholder: &str) -> bool {
+pub fn show_refine_input(parent_hwnd: HWND, placeholder: &str, initial_text: &str) -> bool {
     let parent_key = parent_hwnd.0 as isize;
 
     // Check if already exists
@@ -408,7 +416,7 @@ pub fn show_refine_input(parent_hwnd: HWND, placeholder: &str) -> bool {
         }
 
         // Create WebView inside the child window
-        let html = get_refine_html(placeholder);
+        let html = get_refine_html(placeholder, initial_text);
         let child_hwnd = child_hwnd.unwrap();
         let wrapper = HwndWrapper(child_hwnd);
 
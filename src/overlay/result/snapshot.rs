@@ -0,0 +1,168 @@
+//! Copy a result window as an image, for sharing a translation where the formatting
+//! matters (tables, math). The window is rendered with its buttons hidden and the
+//! markdown WebView at full size, then captured at its physical pixel size, so the
+//! image is as sharp as the window on a high-DPI monitor.
+
+use std::collections::HashSet;
+use std::mem::size_of;
+use std::sync::Mutex;
+use windows::Win32::Foundation::*;
+use windows::Win32::Graphics::Gdi::*;
+use windows::Win32::Storage::Xps::{PrintWindow, PRINT_WINDOW_FLAGS, PW_CLIENTONLY};
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+use super::markdown_view;
+use super::state::WINDOW_STATES;
+use crate::gui::locale::LocaleText;
+
+/// Timer that takes the snapshot once the window has repainted without its buttons
+pub const SNAPSHOT_TIMER_ID: usize = 4;
+
+/// Time for the WebView to lay out again at full size
+const SETTLE_MS: u32 = 150;
+
+/// PW_RENDERFULLCONTENT: include the WebView's DirectComposition content
+const PW_RENDERFULLCONTENT: u32 = 2;
+
+lazy_static::lazy_static! {
+    /// Windows whose snapshot is also saved to a file
+    static ref SAVE_AFTER: Mutex<HashSet<isize>> = Mutex::new(HashSet::new());
+}
+
+/// Hide the buttons and take the snapshot on the next timer tick. With `save` the
+/// image is also saved as a PNG.
+pub unsafe fn start(hwnd: HWND, save: bool) {
+    let is_markdown = {
+        let mut states = WINDOW_STATES.lock().unwrap();
+        let Some(state) = states.get_mut(&(hwnd.0 as isize)) else {
+            return;
+        };
+        if state.is_taking_snapshot {
+            return;
+        }
+        state.is_taking_snapshot = true;
+        state.is_markdown_mode
+    };
+    if save {
+        SAVE_AFTER.lock().unwrap().insert(hwnd.0 as isize);
+    }
+    if is_markdown {
+        markdown_view::resize_markdown_webview(hwnd, false);
+    }
+    let _ = InvalidateRect(Some(hwnd), None, false);
+    SetTimer(Some(hwnd), SNAPSHOT_TIMER_ID, SETTLE_MS, None);
+}
+
+/// Capture the window, bring the buttons back and hand the image out
+pub unsafe fn finish(hwnd: HWND) {
+    let _ = KillTimer(Some(hwnd), SNAPSHOT_TIMER_ID);
+    let image = capture(hwnd);
+
+    let restore_hover = {
+        let mut states = WINDOW_STATES.lock().unwrap();
+        states.get_mut(&(hwnd.0 as isize)).map(|state| {
+            state.is_taking_snapshot = false;
+            (state.is_markdown_mode, state.is_hovered)
+        })
+    };
+    if let Some((true, is_hovered)) = restore_hover {
+        markdown_view::resize_markdown_webview(hwnd, is_hovered);
+    }
+    let _ = InvalidateRect(Some(hwnd), None, false);
+
+    let save = SAVE_AFTER.lock().unwrap().remove(&(hwnd.0 as isize));
+    let ui_language = crate::APP.lock().unwrap().config.ui_language.clone();
+    let locale = LocaleText::get(&ui_language);
+
+    let mut png = Vec::new();
+    let encoded = image.is_some_and(|image| {
+        image
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .is_ok()
+    });
+    if !encoded {
+        crate::overlay::auto_copy_badge::show_notification(locale.snapshot_failed);
+        return;
+    }
+    crate::overlay::utils::copy_image_to_clipboard(&png);
+
+    if !save {
+        crate::overlay::auto_copy_badge::show_notification(locale.snapshot_copied);
+        return;
+    }
+    let default_name = format!(
+        "sgt-result-{}.png",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    );
+    let Some(path) =
+        crate::gui::utils::pick_file_path_of_types(true, &[("PNG (*.png)", "png")], &default_name)
+    else {
+        return;
+    };
+    let message = match std::fs::write(&path, &png) {
+        Ok(()) => format!("{} {}", locale.export_done, path.display()),
+        Err(e) => {
+            log::warn!("Saving snapshot to {} failed: {}", path.display(), e);
+            format!("{} {}", locale.export_failed, e)
+        }
+    };
+    crate::overlay::auto_copy_badge::show_notification(&message);
+}
+
+/// The window's client area as it is drawn, WebView included
+unsafe fn capture(hwnd: HWND) -> Option<image::DynamicImage> {
+    let mut rect = RECT::default();
+    let _ = GetClientRect(hwnd, &mut rect);
+    let (width, height) = (rect.right - rect.left, rect.bottom - rect.top);
+    if width <= 0 || height <= 0 {
+        return None;
+    }
+
+    let hdc = GetDC(Some(hwnd));
+    let mem_dc = CreateCompatibleDC(Some(hdc));
+    let bmi = BITMAPINFO {
+        bmiHeader: BITMAPINFOHEADER {
+            biSize: size_of::<BITMAPINFOHEADER>() as u32,
+            biWidth: width,
+            biHeight: -height,
+            biPlanes: 1,
+            biBitCount: 32,
+            biCompression: BI_RGB.0 as u32,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut bits: *mut core::ffi::c_void = std::ptr::null_mut();
+    let Ok(hbm) = CreateDIBSection(Some(hdc), &bmi, DIB_RGB_COLORS, &mut bits, None, 0) else {
+        let _ = DeleteDC(mem_dc);
+        ReleaseDC(Some(hwnd), hdc);
+        return None;
+    };
+    let old_hbm = SelectObject(mem_dc, hbm.into());
+
+    let printed = PrintWindow(
+        hwnd,
+        mem_dc,
+        PRINT_WINDOW_FLAGS(PW_CLIENTONLY.0 | PW_RENDERFULLCONTENT),
+    )
+    .as_bool();
+
+    let mut rgba = Vec::with_capacity((width * height * 4) as usize);
+    if printed && !bits.is_null() {
+        let pixels = std::slice::from_raw_parts(bits as *const u32, (width * height) as usize);
+        for &p in pixels {
+            // BGRA; the window is drawn opaque, its translucency is a layered attribute
+            rgba.extend_from_slice(&[(p >> 16) as u8, (p >> 8) as u8, p as u8, 255]);
+        }
+    }
+
+    SelectObject(mem_dc, old_hbm);
+    let _ = DeleteObject(hbm.into());
+    let _ = DeleteDC(mem_dc);
+    ReleaseDC(Some(hwnd), hdc);
+
+    if !printed {
+        return None;
+    }
+    image::RgbaImage::from_raw(width as u32, height as u32, rgba).map(image::DynamicImage::from)
+}
@@ -4,7 +4,7 @@ use std::sync::{
     Arc, Mutex,
 };
 use windows::Win32::Foundation::*;
-use windows::Win32::Graphics::Gdi::{HBITMAP, HFONT};
+use windows::Win32::Graphics::Gdi::{InvalidateRect, HBITMAP, HFONT};
 
 // --- DYNAMIC PARTICLES ---
 pub struct DustParticle {
@@ -84,11 +84,33 @@ impl Default for CursorPhysics {
 }
 
 // Context for Refinement
+pub use crate::api::text::RefineContext;
+
+/// What the language switcher needs to run a window's block again
 #[derive(Clone)]
-pub enum RefineContext {
-    None,
-    Image(Vec<u8>), // PNG Bytes
-    Audio(Vec<u8>), // WAV Bytes
+pub struct LanguageRerun {
+    /// The block that produced the text (its prompt takes a target language)
+    pub block: crate::config::ProcessingBlock,
+    /// Captured image for image blocks; text blocks reuse `source_text`
+    pub image: Option<Vec<u8>>,
+}
+
+/// What the "record again" button of a pronunciation result needs
+#[derive(Clone)]
+pub struct PronunciationRetry {
+    pub preset_id: String,
+    /// Phrase the recording was scored against
+    pub target: String,
+}
+
+/// Where a window's block sits in a chain of several processing steps
+#[derive(Clone)]
+pub struct ChainStep {
+    /// 1-based position among the chain's processing blocks
+    pub step: usize,
+    pub total: usize,
+    /// Localized "Step 2/3: Translating to Vietnamese…" line shown while waiting
+    pub status: String,
 }
 
 pub struct WindowState {
@@ -115,6 +137,10 @@ pub struct WindowState {
 
     // Streaming state - true when actively receiving chunks (buttons hidden during streaming)
     pub is_streaming_active: bool,
+    // Stop button (shown instead of the other buttons while streaming)
+    pub on_stop_btn: bool,
+    // Set by WM_CANCEL_REQUEST: the running request stops reading and keeps the partial result
+    pub stop_signal: Option<Arc<AtomicBool>>,
 
     // Metadata for Refinement/Processing
     pub model_id: String,
@@ -174,6 +200,11 @@ pub struct WindowState {
     pub is_markdown_mode: bool, // True when showing markdown view
     pub on_markdown_btn: bool,  // Hover state for markdown button
 
+    // Side-by-side view: source text | translation (markdown view only)
+    pub is_dual_pane: bool,
+    pub on_dual_pane_btn: bool, // Hover state for dual pane button
+    pub source_text: String,    // Text this window's block received (OCR / previous block output)
+
     // Web Browsing State
     pub is_browsing: bool, // True when user has navigated away from initial content
     pub navigation_depth: usize, // How many pages deep from initial content (0 = at result)
@@ -184,12 +215,112 @@ pub struct WindowState {
     // Download HTML button state
     pub on_download_btn: bool, // Hover state for download HTML button
 
+    // Language switcher (bottom-left), only for blocks that translate
+    pub language_rerun: Option<LanguageRerun>,
+    pub on_language_btn: bool,
+
+    // Export of a markdown/JSON table in the result (bottom-left)
+    pub on_table_export_btn: bool,
+
+    // Record again after pronunciation scoring (bottom-left)
+    pub pronunciation_retry: Option<PronunciationRetry>,
+    pub on_retry_btn: bool,
+
+    // Pause/resume while the result is read aloud (bottom-left)
+    pub on_tts_pause_btn: bool,
+
+    // Flipping through the session's recent results (bottom-left)
+    pub recent_id: Option<u64>,
+    pub on_history_prev_btn: bool,
+    pub on_history_next_btn: bool,
+
+    // Copy the window as an image (bottom-left); buttons stay hidden while it is taken
+    pub on_snapshot_btn: bool,
+    pub is_taking_snapshot: bool,
+
+    // Multi-step chains: status line while waiting, progress bar while streaming
+    pub chain_step: Option<ChainStep>,
+
+    // Auto-close after the preset's timeout without activity (ring along the border)
+    pub auto_dismiss_secs: Option<u32>,
+    pub last_activity_time: u32, // ms timestamp, like last_resize_time
+
     // Speaker/TTS button state
     pub on_speaker_btn: bool, // Hover state for speaker button
     pub tts_request_id: u64,  // Active TTS request ID (0 = not speaking)
     pub tts_loading: bool,    // True when TTS is loading/connecting (shows spinner)
 }
 
+impl WindowState {
+    /// The window's text is being played (or paused) by TTS
+    pub fn is_tts_playing(&self) -> bool {
+        !self.tts_loading && crate::api::tts::TTS_MANAGER.is_speaking(self.tts_request_id)
+    }
+
+    /// Slots of the previous / next result buttons, after the TTS pause button;
+    /// None for a direction with no result to go to
+    pub fn history_btn_slots(&self) -> (Option<i32>, Option<i32>) {
+        if self.is_refining || self.is_streaming_active {
+            return (None, None);
+        }
+        let (older, newer) = super::recent::neighbours(self.recent_id);
+        let first = self.tts_pause_slot() + self.is_tts_playing() as i32;
+        (
+            older.then_some(first),
+            newer.then_some(first + older as i32),
+        )
+    }
+
+    /// Slot of the snapshot button, last in the bottom-left row
+    pub fn snapshot_slot(&self) -> Option<i32> {
+        if self.is_refining || self.is_streaming_active || self.full_text.trim().is_empty() {
+            return None;
+        }
+        let (older, newer) = super::recent::neighbours(self.recent_id);
+        Some(self.tts_pause_slot() + self.is_tts_playing() as i32 + older as i32 + newer as i32)
+    }
+
+    /// Something is going on in the window that keeps it from auto-closing
+    pub fn is_active(&self) -> bool {
+        self.is_hovered
+            || self.is_editing
+            || self.is_refining
+            || self.is_streaming_active
+            || self.tts_loading
+            || self.is_tts_playing()
+            || !matches!(self.interaction_mode, InteractionMode::None)
+    }
+
+    /// Share of the auto-dismiss timeout still left (1.0 down to 0.0) while it runs
+    pub fn auto_dismiss_left(&self, now: u32) -> Option<f32> {
+        let secs = self.auto_dismiss_secs.filter(|s| *s > 0)?;
+        if self.is_active() || self.last_activity_time == 0 {
+            return None;
+        }
+        let idle = now.wrapping_sub(self.last_activity_time) as f32;
+        Some((1.0 - idle / (secs as f32 * 1000.0)).max(0.0))
+    }
+
+    /// Slot of the TTS pause button in the bottom-left row: after the language
+    /// switcher, the table export and the record-again button, when those are shown
+    pub fn tts_pause_slot(&self) -> i32 {
+        if self.is_refining || self.is_streaming_active {
+            return 0;
+        }
+        self.retry_slot() + self.pronunciation_retry.is_some() as i32
+    }
+
+    /// Slot of the record-again button in the bottom-left row: after the language
+    /// switcher and table export when those are shown
+    pub fn retry_slot(&self) -> i32 {
+        if self.is_refining || self.is_streaming_active {
+            return 0;
+        }
+        let has_table = crate::overlay::result::table::looks_like_table(&self.full_text);
+        self.language_rerun.is_some() as i32 + has_table as i32
+    }
+}
+
 // SAFETY: Raw pointers are not Send/Sync, but we only use them within the main thread
 // This is safe because all access is synchronized via WINDOW_STATES mutex
 unsafe impl Send for WindowState {}
@@ -249,3 +380,60 @@ pub fn close_windows_with_token(token: &Arc<AtomicBool>) {
         }
     }
 }
+
+/// Close every result window, cancelling whatever is still streaming into them
+pub fn close_all_result_windows() {
+    let (tokens, untracked) = {
+        let states = WINDOW_STATES.lock().unwrap();
+        let mut tokens: Vec<Arc<AtomicBool>> = Vec::new();
+        let mut untracked = Vec::new();
+        for (&h_val, state) in states.iter() {
+            match &state.cancellation_token {
+                Some(t) if !tokens.iter().any(|known| Arc::ptr_eq(known, t)) => {
+                    tokens.push(t.clone())
+                }
+                Some(_) => {}
+                None => untracked.push(HWND(h_val as *mut std::ffi::c_void)),
+            }
+        }
+        (tokens, untracked)
+    };
+
+    for token in &tokens {
+        close_windows_with_token(token);
+    }
+    for hwnd in untracked {
+        unsafe {
+            if IsWindow(Some(hwnd)).as_bool() {
+                let _ = PostMessageW(
+                    Some(hwnd),
+                    WM_CLOSE,
+                    windows::Win32::Foundation::WPARAM(0),
+                    windows::Win32::Foundation::LPARAM(0),
+                );
+            }
+        }
+    }
+}
+
+/// TTS hook: the window's speech started playing, so its speaker button stops
+/// showing the loading state
+pub fn tts_loading_done(hwnd: isize) {
+    if let Some(state) = WINDOW_STATES.lock().unwrap().get_mut(&hwnd) {
+        state.tts_loading = false;
+    }
+    unsafe {
+        let _ = InvalidateRect(Some(HWND(hwnd as *mut std::ffi::c_void)), None, false);
+    }
+}
+
+/// TTS hook: the window's speech ended
+pub fn tts_speech_ended(hwnd: isize) {
+    if let Some(state) = WINDOW_STATES.lock().unwrap().get_mut(&hwnd) {
+        state.tts_loading = false;
+        state.tts_request_id = 0;
+    }
+    unsafe {
+        let _ = InvalidateRect(Some(HWND(hwnd as *mut std::ffi::c_void)), None, false);
+    }
+}
@@ -0,0 +1,378 @@
+//! Tables in result windows: structured (JSON) answers turned into rows and
+//! columns and shown as a markdown table (which gets the CSV copy button), and the
+//! table of a result exported to a `.csv` or `.xlsx` file.
+
+use std::io::Write;
+use std::path::Path;
+
+use serde_json::Value;
+
+pub struct Table {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Rows from a JSON answer: `{"columns": [...], "rows": [[...], ...]}`, an array
+    /// of objects (one column per key, in the order they first appear), an array of
+    /// arrays (the first one is the header), an array of plain values, an object
+    /// holding one such array (`{"items": [...]}`) or a single object as a one-row
+    /// table. `None` for anything else.
+    pub fn from_json(value: &Value) -> Option<Table> {
+        if let (Some(columns), Some(rows)) = (
+            value.get("columns").and_then(|c| c.as_array()),
+            value.get("rows").and_then(|r| r.as_array()),
+        ) {
+            let mut items = vec![Value::Array(columns.clone())];
+            items.extend(rows.iter().cloned());
+            return Self::from_items(&items);
+        }
+        match value {
+            Value::Array(items) => Self::from_items(items),
+            Value::Object(fields) => {
+                let mut arrays = fields.values().filter_map(|v| v.as_array());
+                match (arrays.next(), arrays.next()) {
+                    (Some(items), None) => Self::from_items(items),
+                    _ => Self::from_items(std::slice::from_ref(value)),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn from_items(items: &[Value]) -> Option<Table> {
+        let first = items.first()?;
+        let table = match first {
+            Value::Object(_) => {
+                let mut headers: Vec<String> = Vec::new();
+                for item in items {
+                    for key in item.as_object().into_iter().flat_map(|o| o.keys()) {
+                        if !headers.contains(key) {
+                            headers.push(key.clone());
+                        }
+                    }
+                }
+                let rows = items
+                    .iter()
+                    .map(|item| {
+                        headers
+                            .iter()
+                            .map(|h| item.get(h).map(cell_text).unwrap_or_default())
+                            .collect()
+                    })
+                    .collect();
+                Table { headers, rows }
+            }
+            Value::Array(header) => Table {
+                headers: header.iter().map(cell_text).collect(),
+                rows: items[1..]
+                    .iter()
+                    .map(|row| match row {
+                        Value::Array(cells) => cells.iter().map(cell_text).collect(),
+                        other => vec![cell_text(other)],
+                    })
+                    .collect(),
+            },
+            _ => Table {
+                headers: vec!["value".to_string()],
+                rows: items.iter().map(|item| vec![cell_text(item)]).collect(),
+            },
+        };
+        (!table.headers.is_empty()).then_some(table)
+    }
+
+    /// The table in a result's text: the rows of a JSON answer, else the first
+    /// markdown table
+    pub fn from_text(text: &str) -> Option<Table> {
+        let trimmed = text.trim_start();
+        if trimmed.starts_with(['{', '[']) || trimmed.starts_with("```json") {
+            let from_json = crate::api::structured::parse_reply(text)
+                .as_ref()
+                .and_then(Table::from_json);
+            if from_json.is_some() {
+                return from_json;
+            }
+        }
+        Table::from_markdown(text)
+    }
+
+    /// The first markdown table in `text`: the row above the `---` separator is the
+    /// header and the table runs until the first line without a `|`. Rows keep
+    /// their own number of cells; the exports pad them to the widest row.
+    pub fn from_markdown(text: &str) -> Option<Table> {
+        let lines: Vec<&str> = text.lines().map(str::trim).collect();
+        let separator = lines
+            .iter()
+            .enumerate()
+            .skip(1)
+            .find(|(i, line)| is_separator_row(line) && lines[i - 1].contains('|'))
+            .map(|(i, _)| i)?;
+        let rows = lines[separator + 1..]
+            .iter()
+            .take_while(|line| line.contains('|'))
+            .map(|line| split_row(line))
+            .collect();
+        Some(Table {
+            headers: split_row(lines[separator - 1]),
+            rows,
+        })
+    }
+
+    /// Number of columns: the header or the longest row, whichever is wider
+    fn width(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|r| r.len())
+            .chain(std::iter::once(self.headers.len()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Cells of `row` padded to `width`
+    fn padded(row: &[String], width: usize) -> impl Iterator<Item = &str> {
+        row.iter()
+            .map(|c| c.as_str())
+            .chain(std::iter::repeat(""))
+            .take(width)
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let width = self.width();
+        let line = |cells: &[String]| {
+            let cells: Vec<String> = Self::padded(cells, width)
+                .map(|c| c.replace('|', "\\|").replace(['\r', '\n'], " "))
+                .collect();
+            format!("| {} |\n", cells.join(" | "))
+        };
+        let mut md = line(&self.headers);
+        md.push_str(&format!("|{}\n", " --- |".repeat(width)));
+        for row in &self.rows {
+            md.push_str(&line(row));
+        }
+        md
+    }
+
+    /// RFC 4180 CSV, every row padded to the same number of fields
+    pub fn to_csv(&self) -> String {
+        let width = self.width();
+        let line = |cells: &[String]| {
+            let cells: Vec<String> = Self::padded(cells, width).map(csv_field).collect();
+            format!("{}\r\n", cells.join(","))
+        };
+        let mut csv = line(&self.headers);
+        for row in &self.rows {
+            csv.push_str(&line(row));
+        }
+        csv
+    }
+
+    /// A one-sheet Excel workbook with the header row frozen. Cells that read as
+    /// numbers are stored as numbers, everything else as text.
+    pub fn write_xlsx(&self, path: &Path) -> anyhow::Result<()> {
+        let file = std::fs::File::create(path)?;
+        let mut zip = zip::ZipWriter::new(file);
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+        let sheet = self.sheet_xml();
+        let parts = [
+            ("[Content_Types].xml", XLSX_CONTENT_TYPES),
+            ("_rels/.rels", XLSX_ROOT_RELS),
+            ("xl/workbook.xml", XLSX_WORKBOOK),
+            ("xl/_rels/workbook.xml.rels", XLSX_WORKBOOK_RELS),
+            ("xl/worksheets/sheet1.xml", sheet.as_str()),
+        ];
+        for (name, content) in parts {
+            zip.start_file(name, options)?;
+            zip.write_all(content.as_bytes())?;
+        }
+        zip.finish()?;
+        Ok(())
+    }
+
+    fn sheet_xml(&self) -> String {
+        let width = self.width();
+        let mut rows = String::new();
+        for (r, row) in std::iter::once(&self.headers).chain(&self.rows).enumerate() {
+            rows.push_str(&format!("<row r=\"{}\">", r + 1));
+            for (c, cell) in Self::padded(row, width).enumerate() {
+                let reference = format!("{}{}", column_name(c), r + 1);
+                if cell.is_empty() {
+                    continue;
+                }
+                if r > 0 && is_number(cell) {
+                    rows.push_str(&format!("<c r=\"{}\"><v>{}</v></c>", reference, cell));
+                } else {
+                    rows.push_str(&format!(
+                        "<c r=\"{}\" t=\"inlineStr\"><is><t xml:space=\"preserve\">{}</t></is></c>",
+                        reference,
+                        xml_escape(cell)
+                    ));
+                }
+            }
+            rows.push_str("</row>");
+        }
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetViews><sheetView workbookViewId="0"><pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/></sheetView></sheetViews><sheetData>{}</sheetData></worksheet>"#,
+            rows
+        )
+    }
+}
+
+/// Ask where to save the table in `text` and write it as CSV or Excel, whichever
+/// type was picked. A notification tells how it went.
+pub fn export_table(text: &str, ui_language: &str) {
+    let locale = crate::gui::locale::LocaleText::get(ui_language);
+    let Some(table) = Table::from_text(text) else {
+        crate::overlay::auto_copy_badge::show_notification(locale.table_export_none);
+        return;
+    };
+    let default_name = format!(
+        "sgt-table-{}.csv",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    );
+    let Some(path) = crate::gui::utils::pick_file_path_of_types(
+        true,
+        &[("CSV (*.csv)", "csv"), ("Excel (*.xlsx)", "xlsx")],
+        &default_name,
+    ) else {
+        return;
+    };
+
+    let is_xlsx = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("xlsx"));
+    let result = if is_xlsx {
+        table.write_xlsx(&path)
+    } else {
+        // The BOM makes Excel read the file as UTF-8
+        std::fs::write(&path, format!("\u{feff}{}", table.to_csv())).map_err(Into::into)
+    };
+    let message = match result {
+        Ok(()) => format!("{} {}", locale.export_done, path.display()),
+        Err(e) => {
+            log::warn!("Table export to {} failed: {}", path.display(), e);
+            format!("{} {}", locale.export_failed, e)
+        }
+    };
+    crate::overlay::auto_copy_badge::show_notification(&message);
+}
+
+/// Cheap check for showing the export button: a markdown separator row, or text
+/// shaped like a JSON answer. `Table::from_text` makes the real decision.
+pub fn looks_like_table(text: &str) -> bool {
+    let trimmed = text.trim();
+    let json_like = (trimmed.starts_with('{') && trimmed.ends_with('}'))
+        || (trimmed.starts_with('[') && trimmed.ends_with(']'));
+    json_like || text.lines().any(|line| is_separator_row(line.trim()))
+}
+
+/// `| --- | :---: |`, with or without the outer pipes
+fn is_separator_row(line: &str) -> bool {
+    line.contains('-')
+        && line.contains('|')
+        && line
+            .chars()
+            .all(|c| matches!(c, '|' | '-' | ':' | ' ' | '\t'))
+}
+
+/// Cells of a markdown table row: outer pipes dropped, `\|` kept as a literal pipe,
+/// `<br>` as a line break and bold markers removed
+fn split_row(line: &str) -> Vec<String> {
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = match line.strip_suffix('|') {
+        Some(rest) if !rest.ends_with('\\') => rest,
+        _ => line,
+    };
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                cell.push('|');
+                chars.next();
+            }
+            '|' => cells.push(std::mem::take(&mut cell)),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(cell);
+    cells
+        .into_iter()
+        .map(|c| {
+            c.trim()
+                .replace("<br>", "\n")
+                .replace("<br/>", "\n")
+                .replace("**", "")
+        })
+        .collect()
+}
+
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Quote a CSV field when it holds a comma, quote or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Excel column letters: A..Z, AA..
+fn column_name(mut index: usize) -> String {
+    let mut name = String::new();
+    loop {
+        name.insert(0, (b'A' + (index % 26) as u8) as char);
+        if index < 26 {
+            return name;
+        }
+        index = index / 26 - 1;
+    }
+}
+
+/// Plain decimal numbers only: "007" or "1e5" stay text, as typed
+fn is_number(cell: &str) -> bool {
+    let digits = cell.strip_prefix('-').unwrap_or(cell);
+    let leading_zero = digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.");
+    !leading_zero
+        && digits.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && digits.chars().filter(|c| *c == '.').count() <= 1
+        && cell.parse::<f64>().is_ok_and(f64::is_finite)
+}
+
+/// Escape text for XML, dropping control characters XML can't hold
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if (c as u32) < 0x20 => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+const XLSX_CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/></Types>"#;
+
+const XLSX_ROOT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#;
+
+const XLSX_WORKBOOK: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="Table" sheetId="1" r:id="rId1"/></sheets></workbook>"#;
+
+const XLSX_WORKBOOK_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/></Relationships>"#;
@@ -195,6 +195,8 @@ pub fn create_result_window(
                     is_refining: false,
                     animation_offset: 0.0,
                     is_streaming_active: false,
+                    on_stop_btn: false,
+                    stop_signal: None,
                     model_id,
                     provider,
                     streaming_enabled,
@@ -226,12 +228,29 @@ pub fn create_result_window(
                     // Markdown mode state
                     is_markdown_mode: render_mode == "markdown",
                     on_markdown_btn: false,
+                    is_dual_pane: false,
+                    on_dual_pane_btn: false,
+                    source_text: String::new(),
                     is_browsing: false,
                     navigation_depth: 0,
                     max_navigation_depth: 0,
                     on_back_btn: false,
                     on_forward_btn: false,
                     on_download_btn: false,
+                    language_rerun: None,
+                    on_language_btn: false,
+                    on_table_export_btn: false,
+                    pronunciation_retry: None,
+                    on_retry_btn: false,
+                    on_tts_pause_btn: false,
+                    recent_id: None,
+                    on_history_prev_btn: false,
+                    on_history_next_btn: false,
+                    on_snapshot_btn: false,
+                    is_taking_snapshot: false,
+                    chain_step: None,
+                    auto_dismiss_secs: None,
+                    last_activity_time: 0,
                     on_speaker_btn: false,
                     tts_request_id: 0,
                     tts_loading: false,
@@ -0,0 +1,128 @@
+//! Double-click dictionary lookup in the markdown view.
+//!
+//! The page posts `lookup:<word>`; the definition is fetched on a worker thread and
+//! handed back to the window thread with `WM_WORD_LOOKUP`, which shows it in a popup
+//! next to the word.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::Mutex;
+use windows::Win32::Foundation::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+use super::event_handler::misc::WM_WORD_LOOKUP;
+use crate::model_config::{get_model_by_id, ModelType};
+
+/// Used when the window's own model can't answer free-form prompts (GTX, vision)
+const FALLBACK_MODEL: &str = "text_fast_120b";
+
+/// Longest selection treated as a single word
+const MAX_WORD_CHARS: usize = 48;
+
+lazy_static::lazy_static! {
+    // Definitions fetched this session, keyed by (ui language, lowercased word)
+    static ref LOOKUP_CACHE: Mutex<HashMap<(String, String), String>> = Mutex::new(HashMap::new());
+    // Latest lookup per window: (word as selected, definition or None while loading)
+    static ref PENDING: Mutex<HashMap<isize, (String, Option<String>)>> = Mutex::new(HashMap::new());
+}
+
+/// Look up `selected` for the window: shows a loading popup right away, then the
+/// definition (from the cache or a quick request to the window's model)
+pub fn request_lookup(parent_hwnd: HWND, selected: &str) {
+    let word = selected
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_string();
+    if word.is_empty()
+        || word.chars().count() > MAX_WORD_CHARS
+        || word.contains(char::is_whitespace)
+    {
+        return;
+    }
+
+    let config = crate::APP.lock().unwrap().config.clone();
+    let cache_key = (config.ui_language.clone(), word.to_lowercase());
+    let cached = LOOKUP_CACHE.lock().unwrap().get(&cache_key).cloned();
+    let done = cached.is_some();
+    deliver(parent_hwnd, selected, cached);
+    if done {
+        return;
+    }
+
+    let model_id = {
+        let states = super::WINDOW_STATES.lock().unwrap();
+        states
+            .get(&(parent_hwnd.0 as isize))
+            .map(|s| s.model_id.clone())
+            .unwrap_or_default()
+    };
+    let model = get_model_by_id(&model_id)
+        .filter(|m| m.model_type == ModelType::Text && m.provider != "gtx")
+        .or_else(|| get_model_by_id(FALLBACK_MODEL));
+    let Some(model) = model else {
+        return;
+    };
+
+    let selected = selected.to_string();
+    let hwnd_key = parent_hwnd.0 as isize;
+    std::thread::spawn(move || {
+        let language = isolang::Language::from_639_1(&config.ui_language)
+            .map(|l| l.to_name())
+            .unwrap_or("English");
+        let instruction = format!(
+            "Give a short dictionary entry for the given word: its part of speech and a one-sentence definition, written in {}. Output ONLY the entry.",
+            language
+        );
+        let result = crate::api::translate_text_streaming(
+            &config,
+            word,
+            instruction,
+            model.full_name.clone(),
+            model.provider,
+            false,
+            false,
+            &crate::config::ResponseFormat::Text,
+            None,
+            &config.ui_language,
+            &AtomicBool::new(false),
+            |_| {},
+        );
+
+        let definition = match result {
+            Ok(text) => {
+                let text = text.trim().to_string();
+                LOOKUP_CACHE.lock().unwrap().insert(cache_key, text.clone());
+                text
+            }
+            Err(e) => crate::overlay::utils::get_error_message(
+                &e.to_string(),
+                &config.ui_language,
+                Some(&model.full_name),
+            ),
+        };
+        deliver(
+            HWND(hwnd_key as *mut std::ffi::c_void),
+            &selected,
+            Some(definition),
+        );
+    });
+}
+
+/// Hand a lookup state to the window thread
+fn deliver(parent_hwnd: HWND, selected: &str, definition: Option<String>) {
+    PENDING
+        .lock()
+        .unwrap()
+        .insert(parent_hwnd.0 as isize, (selected.to_string(), definition));
+    unsafe {
+        let _ = PostMessageW(Some(parent_hwnd), WM_WORD_LOOKUP, WPARAM(0), LPARAM(0));
+    }
+}
+
+/// Latest lookup state for the window, if it hasn't been shown yet
+pub fn take_pending(parent_hwnd: HWND) -> Option<(String, Option<String>)> {
+    PENDING.lock().unwrap().remove(&(parent_hwnd.0 as isize))
+}
+
+pub fn clear_pending(parent_hwnd: HWND) {
+    PENDING.lock().unwrap().remove(&(parent_hwnd.0 as isize));
+}
@@ -2,7 +2,10 @@ use windows::core::*;
 use windows::Win32::Foundation::*;
 use windows::Win32::Graphics::Gdi::*;
 use windows::Win32::System::LibraryLoader::*;
-use windows::Win32::UI::Input::KeyboardAndMouse::{ReleaseCapture, SetCapture, VK_ESCAPE};
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    GetKeyState, ReleaseCapture, SetCapture, VK_BACK, VK_CONTROL, VK_DOWN, VK_ESCAPE, VK_LEFT,
+    VK_RETURN, VK_RIGHT, VK_SHIFT, VK_UP,
+};
 use windows::Win32::UI::WindowsAndMessaging::*;
 
 use super::process::start_processing_pipeline;
@@ -27,19 +30,44 @@ static mut MAG_SET_FULLSCREEN_TRANSFORM: Option<MagSetFullscreenTransformFn> = N
 
 // --- CONFIGURATION ---
 const FADE_TIMER_ID: usize = 2;
-const TARGET_OPACITY: u8 = 120;
 const FADE_STEP: u8 = 40;
+/// Posted by the keyboard hook while adjusting or redacting: wparam = virtual key
+const WM_APP_SELECTION_KEY: u32 = WM_APP + 1;
+const NUDGE_STEP: i32 = 1;
+const NUDGE_STEP_FAST: i32 = 10;
 
 // --- STATE ---
 static mut START_POS: POINT = POINT { x: 0, y: 0 };
 static mut CURR_POS: POINT = POINT { x: 0, y: 0 };
 static mut IS_DRAGGING: bool = false;
+// Box kept after the drag for arrow-key adjustment (selection_adjust_before_confirm)
+static mut IS_ADJUSTING: bool = false;
+static mut ADJUST_BEFORE_CONFIRM: bool = false;
+// Width / height of the last confirmed selection, kept by Shift-dragging
+static mut LAST_ASPECT: f32 = 1.0;
+// Redaction step (Preset::redact_before_send): black boxes drawn inside the confirmed
+// selection, burned into the crop before it leaves this module
+static mut IS_REDACTING: bool = false;
+static mut REDACT_SELECTION: RECT = RECT {
+    left: 0,
+    top: 0,
+    right: 0,
+    bottom: 0,
+};
+static mut REDACT_BOXES: Vec<RECT> = Vec::new();
+static mut IS_REDACT_DRAGGING: bool = false;
+static mut REDACT_START: POINT = POINT { x: 0, y: 0 };
+static mut REDACT_CURR: POINT = POINT { x: 0, y: 0 };
+static mut REDACT_HINT: &str = "";
 static mut IS_FADING_OUT: bool = false;
 static mut CURRENT_ALPHA: u8 = 0;
 static mut SELECTION_OVERLAY_ACTIVE: bool = false;
 static mut SELECTION_OVERLAY_HWND: SendHwnd = SendHwnd(HWND(std::ptr::null_mut()));
 static mut CURRENT_PRESET_IDX: usize = 0;
-static mut SELECTION_HOOK: HHOOK = HHOOK(std::ptr::null_mut());
+// Look of the overlay from the settings, read when it opens
+static mut TARGET_OPACITY: u8 = 120;
+static mut BORDER_COLOR: u32 = 0x00FFFFFF; // 0x00RRGGBB, as the DIB stores it
+pub(crate) static mut SELECTION_HOOK: HHOOK = HHOOK(std::ptr::null_mut());
 
 // Cached back buffer to avoid per-frame allocations
 // Use a 32-bit DIB section for per-pixel alpha support (opaque box on semi-transparent dim)
@@ -204,9 +232,45 @@ pub fn show_selection_overlay(preset_idx: usize) {
     unsafe {
         CURRENT_PRESET_IDX = preset_idx;
         SELECTION_OVERLAY_ACTIVE = true;
+        if let Ok(app) = APP.lock() {
+            let [r, g, b] = app.config.selection_border_color;
+            // Fully transparent pixels of a layered window let clicks through
+            TARGET_OPACITY = app.config.selection_dim_opacity.max(1);
+            BORDER_COLOR = ((r as u32) << 16) | ((g as u32) << 8) | b as u32;
+        }
         CURRENT_ALPHA = 0;
         IS_FADING_OUT = false;
         IS_DRAGGING = false;
+        IS_ADJUSTING = false;
+        IS_REDACTING = false;
+        IS_REDACT_DRAGGING = false;
+        REDACT_BOXES.clear();
+        let remembered = {
+            let app = APP.lock().unwrap();
+            ADJUST_BEFORE_CONFIRM = app.config.selection_adjust_before_confirm;
+            app.config
+                .presets
+                .get(preset_idx)
+                .filter(|p| p.remember_selection)
+                .and_then(|p| p.last_selection)
+        };
+        // Pre-draw the remembered region, ready for Enter or arrow-key adjustment.
+        // Skipped if the monitor layout changed and it's no longer fully visible.
+        if let Some((left, top, right, bottom)) = remembered {
+            let on_screen = [(left, top), (right - 1, bottom - 1)]
+                .iter()
+                .all(|&(x, y)| {
+                    !MonitorFromPoint(POINT { x, y }, MONITOR_DEFAULTTONULL).is_invalid()
+                });
+            if on_screen && right - left > 10 && bottom - top > 10 {
+                START_POS = POINT { x: left, y: top };
+                CURR_POS = POINT {
+                    x: right,
+                    y: bottom,
+                };
+                IS_ADJUSTING = true;
+            }
+        }
 
         // Reset zoom state
         ZOOM_LEVEL = 1.0;
@@ -323,11 +387,247 @@ unsafe extern "system" fn selection_hook_proc(
                 }
                 return LRESULT(1);
             }
+            let is_adjust_key = [VK_LEFT, VK_RIGHT, VK_UP, VK_DOWN, VK_RETURN]
+                .iter()
+                .any(|vk| kbd.vkCode == vk.0 as u32);
+            let is_redact_key = [VK_RETURN, VK_BACK]
+                .iter()
+                .any(|vk| kbd.vkCode == vk.0 as u32);
+            if (IS_ADJUSTING && is_adjust_key) || (IS_REDACTING && is_redact_key) {
+                let hwnd = std::ptr::addr_of!(SELECTION_OVERLAY_HWND).read().0;
+                if !hwnd.is_invalid() {
+                    let _ = PostMessageW(
+                        Some(hwnd),
+                        WM_APP_SELECTION_KEY,
+                        WPARAM(kbd.vkCode as usize),
+                        LPARAM(0),
+                    );
+                }
+                return LRESULT(1);
+            }
         }
     }
     CallNextHookEx(None, code, wparam, lparam)
 }
 
+/// Accept the selection: go on to the redaction step if the preset asks for it,
+/// otherwise send it
+#[allow(static_mut_refs)]
+unsafe fn confirm_selection(hwnd: HWND, rect: RECT) {
+    IS_ADJUSTING = false;
+    let (redact, ui_language) = {
+        let app = APP.lock().unwrap();
+        let redact = app
+            .config
+            .presets
+            .get(CURRENT_PRESET_IDX)
+            .is_some_and(|p| p.redact_before_send);
+        (redact, app.config.ui_language.clone())
+    };
+    if redact {
+        IS_REDACTING = true;
+        REDACT_SELECTION = rect;
+        REDACT_BOXES.clear();
+        REDACT_HINT = crate::gui::locale::LocaleText::get(&ui_language).redact_hint;
+        sync_layered_window_contents(hwnd);
+    } else {
+        send_selection(hwnd, rect);
+    }
+}
+
+/// Send the selected area to the preset (via the wheel for MASTER presets) and fade out
+#[allow(static_mut_refs)]
+unsafe fn send_selection(hwnd: HWND, rect: RECT) {
+    IS_REDACTING = false;
+    LAST_ASPECT = (rect.right - rect.left) as f32 / (rect.bottom - rect.top).max(1) as f32;
+
+    // Remember the region on the preset that opened the overlay
+    {
+        let mut guard = APP.lock().unwrap();
+        if let Some(preset) = guard.config.presets.get_mut(CURRENT_PRESET_IDX) {
+            let region = Some((rect.left, rect.top, rect.right, rect.bottom));
+            if preset.remember_selection && preset.last_selection != region {
+                preset.last_selection = region;
+                crate::config::save_config(&guard.config);
+            }
+        }
+    }
+
+    // Check if this is a MASTER preset
+    let is_master = {
+        let guard = APP.lock().unwrap();
+        guard
+            .config
+            .presets
+            .get(CURRENT_PRESET_IDX)
+            .map(|p| p.is_master)
+            .unwrap_or(false)
+    };
+
+    // For MASTER presets, show the preset wheel first
+    let final_preset_idx = if is_master {
+        // Get cursor position for wheel center
+        let mut cursor_pos = POINT::default();
+        let _ = GetCursorPos(&mut cursor_pos);
+
+        // Hide selection overlay temporarily while showing wheel
+        // Hide selection overlay temporarily while showing wheel
+        ZOOM_ALPHA_OVERRIDE = Some(60);
+        sync_layered_window_contents(hwnd);
+
+        // Show preset wheel - this blocks until user makes selection
+        let selected = super::preset_wheel::show_preset_wheel("image", None, cursor_pos);
+
+        if let Some(idx) = selected {
+            Some(idx)
+        } else {
+            // User dismissed wheel - cancel operation
+            IS_FADING_OUT = true;
+            SetTimer(Some(hwnd), FADE_TIMER_ID, 16, None);
+            return;
+        }
+    } else {
+        Some(CURRENT_PRESET_IDX)
+    };
+
+    if let Some(preset_idx) = final_preset_idx {
+        // 1. EXTRACT CROP (New Logic)
+        let (cropped_img, config, preset) = {
+            let mut guard = APP.lock().unwrap();
+
+            // CRITICAL: Update active_preset_idx so auto_paste logic works!
+            guard.config.active_preset_idx = preset_idx;
+
+            // Access the handle
+            let capture = guard
+                .screenshot_handle
+                .as_ref()
+                .expect("Screenshot handle missing");
+            let config_clone = guard.config.clone();
+            let preset_clone = guard.config.presets[preset_idx].clone();
+
+            // Extract pixels NOW (The slow part happens here, AFTER user finishes drawing)
+            let mut img = extract_crop_from_hbitmap(capture, rect);
+            black_out(&mut img, rect, &REDACT_BOXES);
+            REDACT_BOXES.clear();
+
+            (img, config_clone, preset_clone)
+        };
+
+        // 2. TRIGGER PROCESSING
+        std::thread::spawn(move || {
+            // Pass the rect for result window positioning
+            start_processing_pipeline(cropped_img, rect, config, preset);
+        });
+    }
+
+    // 3. START FADE OUT
+    IS_FADING_OUT = true;
+    // Reset magnification instantly
+    unsafe {
+        if MAG_INITIALIZED {
+            if let Some(transform_fn) = MAG_SET_FULLSCREEN_TRANSFORM {
+                let _ = transform_fn(1.0, 0, 0);
+            }
+        }
+    }
+    let _ = SetTimer(Some(hwnd), FADE_TIMER_ID, 16, None);
+}
+
+/// Move `curr` so the box from `start` has width / height == `aspect`, keeping the
+/// drag direction and the larger of the two extents
+fn constrain_to_aspect(start: POINT, curr: POINT, aspect: f32) -> POINT {
+    let dx = curr.x - start.x;
+    let dy = curr.y - start.y;
+    let (w, h) = (dx.abs() as f32, dy.abs() as f32);
+    let (w, h) = if w / aspect > h {
+        (w, w / aspect)
+    } else {
+        (h * aspect, h)
+    };
+    POINT {
+        x: start.x + (w as i32) * dx.signum(),
+        y: start.y + (h as i32) * dy.signum(),
+    }
+}
+
+/// Arrow keys grow/shrink the box from its right/bottom edge, Ctrl+arrows move it,
+/// Shift makes the step 10px. Enter confirms.
+#[allow(static_mut_refs)]
+unsafe fn handle_adjust_key(hwnd: HWND, vk: u32) {
+    if vk == VK_RETURN.0 as u32 {
+        let rect = selection_rect();
+        if rect.right - rect.left > 10 && rect.bottom - rect.top > 10 {
+            confirm_selection(hwnd, rect);
+        }
+        return;
+    }
+
+    let step = if GetKeyState(VK_SHIFT.0 as i32) < 0 {
+        NUDGE_STEP_FAST
+    } else {
+        NUDGE_STEP
+    };
+    let (dx, dy) = match vk {
+        v if v == VK_LEFT.0 as u32 => (-step, 0),
+        v if v == VK_RIGHT.0 as u32 => (step, 0),
+        v if v == VK_UP.0 as u32 => (0, -step),
+        v if v == VK_DOWN.0 as u32 => (0, step),
+        _ => return,
+    };
+    if GetKeyState(VK_CONTROL.0 as i32) < 0 {
+        START_POS.x += dx;
+        START_POS.y += dy;
+    }
+    // Never shrink past the left/top edge
+    CURR_POS.x = (CURR_POS.x + dx).max(START_POS.x + 1);
+    CURR_POS.y = (CURR_POS.y + dy).max(START_POS.y + 1);
+    sync_layered_window_contents(hwnd);
+}
+
+/// The box being dragged in the redaction step, clipped to the selection
+#[allow(static_mut_refs)]
+unsafe fn redact_drag_rect() -> Option<RECT> {
+    let sel = REDACT_SELECTION;
+    let area = RECT {
+        left: REDACT_START.x.min(REDACT_CURR.x).max(sel.left),
+        top: REDACT_START.y.min(REDACT_CURR.y).max(sel.top),
+        right: REDACT_START.x.max(REDACT_CURR.x).min(sel.right),
+        bottom: REDACT_START.y.max(REDACT_CURR.y).min(sel.bottom),
+    };
+    (area.right - area.left > 2 && area.bottom - area.top > 2).then_some(area)
+}
+
+/// Paint `boxes` (virtual-screen coordinates) black in the crop taken at `crop_rect`
+fn black_out(
+    img: &mut image::ImageBuffer<image::Rgba<u8>, Vec<u8>>,
+    crop_rect: RECT,
+    boxes: &[RECT],
+) {
+    for area in boxes {
+        let left = (area.left - crop_rect.left).max(0) as u32;
+        let top = (area.top - crop_rect.top).max(0) as u32;
+        let right = ((area.right - crop_rect.left).max(0) as u32).min(img.width());
+        let bottom = ((area.bottom - crop_rect.top).max(0) as u32).min(img.height());
+        for y in top..bottom {
+            for x in left..right {
+                img.put_pixel(x, y, image::Rgba([0, 0, 0, 255]));
+            }
+        }
+    }
+}
+
+/// Current selection in virtual-screen coordinates
+#[allow(static_mut_refs)]
+unsafe fn selection_rect() -> RECT {
+    RECT {
+        left: START_POS.x.min(CURR_POS.x),
+        top: START_POS.y.min(CURR_POS.y),
+        right: START_POS.x.max(CURR_POS.x),
+        bottom: START_POS.y.max(CURR_POS.y),
+    }
+}
+
 #[allow(static_mut_refs)]
 unsafe extern "system" fn selection_wnd_proc(
     hwnd: HWND,
@@ -337,7 +637,16 @@ unsafe extern "system" fn selection_wnd_proc(
 ) -> LRESULT {
     match msg {
         WM_LBUTTONDOWN => {
-            if !IS_FADING_OUT {
+            if IS_REDACTING {
+                if !IS_FADING_OUT {
+                    IS_REDACT_DRAGGING = true;
+                    let _ = GetCursorPos(std::ptr::addr_of_mut!(REDACT_START));
+                    REDACT_CURR = REDACT_START;
+                    SetCapture(hwnd);
+                }
+            } else if !IS_FADING_OUT {
+                // A new drag while adjusting starts over
+                IS_ADJUSTING = false;
                 IS_DRAGGING = true;
                 let _ = GetCursorPos(std::ptr::addr_of_mut!(START_POS));
                 CURR_POS = START_POS;
@@ -364,8 +673,14 @@ unsafe extern "system" fn selection_wnd_proc(
             LRESULT(0)
         }
         WM_MOUSEMOVE => {
-            if IS_DRAGGING {
+            if IS_REDACT_DRAGGING {
+                let _ = GetCursorPos(std::ptr::addr_of_mut!(REDACT_CURR));
+                sync_layered_window_contents(hwnd);
+            } else if IS_DRAGGING {
                 let _ = GetCursorPos(std::ptr::addr_of_mut!(CURR_POS));
+                if GetKeyState(VK_SHIFT.0 as i32) < 0 {
+                    CURR_POS = constrain_to_aspect(START_POS, CURR_POS, LAST_ASPECT);
+                }
                 // Force immediate repaint for smoothness
                 sync_layered_window_contents(hwnd);
             } else if IS_RIGHT_DRAGGING {
@@ -435,16 +750,20 @@ unsafe extern "system" fn selection_wnd_proc(
             LRESULT(0)
         }
         WM_LBUTTONUP => {
+            if IS_REDACT_DRAGGING {
+                IS_REDACT_DRAGGING = false;
+                let _ = ReleaseCapture();
+                if let Some(area) = redact_drag_rect() {
+                    REDACT_BOXES.push(area);
+                }
+                sync_layered_window_contents(hwnd);
+                return LRESULT(0);
+            }
             if IS_DRAGGING {
                 IS_DRAGGING = false;
                 let _ = ReleaseCapture();
 
-                let rect = RECT {
-                    left: START_POS.x.min(CURR_POS.x),
-                    top: START_POS.y.min(CURR_POS.y),
-                    right: START_POS.x.max(CURR_POS.x),
-                    bottom: START_POS.y.max(CURR_POS.y),
-                };
+                let rect = selection_rect();
 
                 let width = (rect.right - rect.left).abs();
                 let height = (rect.bottom - rect.top).abs();
@@ -498,85 +817,21 @@ unsafe extern "system" fn selection_wnd_proc(
                 }
 
                 if width > 10 && height > 10 {
-                    // Check if this is a MASTER preset
-                    let is_master = {
-                        let guard = APP.lock().unwrap();
-                        guard
-                            .config
-                            .presets
-                            .get(CURRENT_PRESET_IDX)
-                            .map(|p| p.is_master)
-                            .unwrap_or(false)
-                    };
-
-                    // For MASTER presets, show the preset wheel first
-                    let final_preset_idx = if is_master {
-                        // Get cursor position for wheel center
-                        let mut cursor_pos = POINT::default();
-                        let _ = GetCursorPos(&mut cursor_pos);
-
-                        // Hide selection overlay temporarily while showing wheel
-                        // Hide selection overlay temporarily while showing wheel
-                        ZOOM_ALPHA_OVERRIDE = Some(60);
+                    if ADJUST_BEFORE_CONFIRM {
+                        // Keep the box for arrow-key nudging; Enter confirms
+                        IS_ADJUSTING = true;
+                        START_POS = POINT {
+                            x: rect.left,
+                            y: rect.top,
+                        };
+                        CURR_POS = POINT {
+                            x: rect.right,
+                            y: rect.bottom,
+                        };
                         sync_layered_window_contents(hwnd);
-
-                        // Show preset wheel - this blocks until user makes selection
-                        let selected =
-                            super::preset_wheel::show_preset_wheel("image", None, cursor_pos);
-
-                        if let Some(idx) = selected {
-                            Some(idx)
-                        } else {
-                            // User dismissed wheel - cancel operation
-                            IS_FADING_OUT = true;
-                            SetTimer(Some(hwnd), FADE_TIMER_ID, 16, None);
-                            return LRESULT(0);
-                        }
                     } else {
-                        Some(CURRENT_PRESET_IDX)
-                    };
-
-                    if let Some(preset_idx) = final_preset_idx {
-                        // 1. EXTRACT CROP (New Logic)
-                        let (cropped_img, config, preset) = {
-                            let mut guard = APP.lock().unwrap();
-
-                            // CRITICAL: Update active_preset_idx so auto_paste logic works!
-                            guard.config.active_preset_idx = preset_idx;
-
-                            // Access the handle
-                            let capture = guard
-                                .screenshot_handle
-                                .as_ref()
-                                .expect("Screenshot handle missing");
-                            let config_clone = guard.config.clone();
-                            let preset_clone = guard.config.presets[preset_idx].clone();
-
-                            // Extract pixels NOW (The slow part happens here, AFTER user finishes drawing)
-                            let img = extract_crop_from_hbitmap(capture, rect);
-
-                            (img, config_clone, preset_clone)
-                        };
-
-                        // 2. TRIGGER PROCESSING
-                        std::thread::spawn(move || {
-                            // Pass the rect for result window positioning
-                            start_processing_pipeline(cropped_img, rect, config, preset);
-                        });
-                    }
-
-                    // 3. START FADE OUT
-                    IS_FADING_OUT = true;
-                    // Reset magnification instantly
-                    unsafe {
-                        if MAG_INITIALIZED {
-                            if let Some(transform_fn) = MAG_SET_FULLSCREEN_TRANSFORM {
-                                let _ = transform_fn(1.0, 0, 0);
-                            }
-                        }
+                        confirm_selection(hwnd, rect);
                     }
-                    let _ = SetTimer(Some(hwnd), FADE_TIMER_ID, 16, None);
-
                     return LRESULT(0);
                 } else {
                     let _ = SendMessageW(hwnd, WM_CLOSE, Some(WPARAM(0)), Some(LPARAM(0)));
@@ -691,6 +946,23 @@ unsafe extern "system" fn selection_wnd_proc(
             LRESULT(0)
         }
         WM_ERASEBKGND => LRESULT(1), // Handle erasing to prevent flicker
+        WM_APP_SELECTION_KEY => {
+            let vk = wparam.0 as u32;
+            if IS_FADING_OUT {
+                return LRESULT(0);
+            }
+            if IS_REDACTING {
+                if vk == VK_RETURN.0 as u32 {
+                    send_selection(hwnd, REDACT_SELECTION);
+                } else if vk == VK_BACK.0 as u32 {
+                    REDACT_BOXES.pop();
+                    sync_layered_window_contents(hwnd);
+                }
+            } else if IS_ADJUSTING {
+                handle_adjust_key(hwnd, vk);
+            }
+            LRESULT(0)
+        }
         WM_CLOSE => {
             if !IS_FADING_OUT {
                 IS_FADING_OUT = true;
@@ -738,6 +1010,59 @@ unsafe extern "system" fn selection_wnd_proc(
     }
 }
 
+/// Draw a small opaque text tag just below-right of `anchor` (bitmap coordinates)
+unsafe fn draw_tag(
+    mem_dc: HDC,
+    pixels: &mut [u32],
+    bmp_w: i32,
+    bmp_h: i32,
+    anchor: POINT,
+    text: &str,
+) {
+    const TAG_H: i32 = 24;
+    const PADDING: i32 = 10;
+    let mut label: Vec<u16> = text.encode_utf16().collect();
+    let mut measure = RECT::default();
+    DrawTextW(
+        mem_dc,
+        &mut label,
+        &mut measure,
+        DT_CALCRECT | DT_SINGLELINE,
+    );
+    let tag_w = (measure.right - measure.left + PADDING * 2).min(bmp_w);
+
+    let left = (anchor.x + 14).min(bmp_w - tag_w).max(0);
+    let top = (anchor.y + 14).min(bmp_h - TAG_H).max(0);
+    let tag = RECT {
+        left,
+        top,
+        right: (left + tag_w).min(bmp_w),
+        bottom: (top + TAG_H).min(bmp_h),
+    };
+
+    let bg = CreateSolidBrush(COLORREF(0x00202020));
+    FillRect(mem_dc, &tag, bg);
+    let _ = DeleteObject(bg.into());
+
+    let mut text_rect = tag;
+    SetBkMode(mem_dc, TRANSPARENT);
+    SetTextColor(mem_dc, COLORREF(0x00FFFFFF));
+    DrawTextW(
+        mem_dc,
+        &mut label,
+        &mut text_rect,
+        DT_CENTER | DT_VCENTER | DT_SINGLELINE,
+    );
+
+    // GDI leaves alpha at 0, make the whole tag opaque
+    for y in tag.top..tag.bottom {
+        let row = (y * bmp_w) as usize;
+        for p in &mut pixels[row + tag.left as usize..row + tag.right as usize] {
+            *p |= 0xFF000000;
+        }
+    }
+}
+
 /// New high-performance renderer using UpdateLayeredWindow
 /// This allows us to have an OPAQUE white box even when the dim background is TRANSPARENT
 #[allow(static_mut_refs)]
@@ -807,13 +1132,8 @@ unsafe fn sync_layered_window_contents(hwnd: HWND) {
     pixels_u32.fill(bg_val);
 
     // Draw the selection rectangle
-    if IS_DRAGGING {
-        let rect_abs = RECT {
-            left: START_POS.x.min(CURR_POS.x),
-            top: START_POS.y.min(CURR_POS.y),
-            right: START_POS.x.max(CURR_POS.x),
-            bottom: START_POS.y.max(CURR_POS.y),
-        };
+    if IS_DRAGGING || IS_ADJUSTING || IS_REDACTING {
+        let rect_abs = selection_rect();
 
         let screen_x = GetSystemMetrics(SM_XVIRTUALSCREEN);
         let screen_y = GetSystemMetrics(SM_YVIRTUALSCREEN);
@@ -829,7 +1149,7 @@ unsafe fn sync_layered_window_contents(hwnd: HWND) {
         let h = (r.bottom - r.top).abs();
 
         if w > 0 && h > 0 {
-            // Draw pure white box (GDI will set color but likely alpha 0)
+            // Draw the box in white as a mask (GDI will set color but likely alpha 0)
             let pen = CreatePen(PS_SOLID, 2, COLORREF(0x00FFFFFF));
             let old_pen = SelectObject(mem_dc, pen.into());
             let null_brush = GetStockObject(NULL_BRUSH);
@@ -854,11 +1174,48 @@ unsafe fn sync_layered_window_contents(hwnd: HWND) {
                 if row_start < pixels_u32.len() && row_end <= pixels_u32.len() {
                     for p in &mut pixels_u32[row_start..row_end] {
                         if (*p & 0x00FFFFFF) > 0x0A0A0A {
-                            *p = 0xFFFFFFFF; // Make the white box opaque
+                            *p = 0xFF000000 | BORDER_COLOR; // Opaque, in the border color
                         }
                     }
                 }
             }
+
+            if IS_REDACTING {
+                // Boxes so far plus the one being dragged, solid black as they'll be sent
+                let dragging = if IS_REDACT_DRAGGING {
+                    redact_drag_rect()
+                } else {
+                    None
+                };
+                for area in REDACT_BOXES.iter().chain(dragging.iter()) {
+                    let left = (area.left - screen_x).clamp(0, width);
+                    let right = (area.right - screen_x).clamp(0, width);
+                    for y in (area.top - screen_y).max(0)..(area.bottom - screen_y).min(height) {
+                        let row = (y * width) as usize;
+                        pixels_u32[row + left as usize..row + right as usize].fill(0xFF000000);
+                    }
+                }
+
+                let anchor = POINT {
+                    x: r.left - 14,
+                    y: r.bottom,
+                };
+                draw_tag(mem_dc, pixels_u32, width, height, anchor, REDACT_HINT);
+            } else {
+                // Selection size next to the moving corner
+                let anchor = POINT {
+                    x: CURR_POS.x - screen_x,
+                    y: CURR_POS.y - screen_y,
+                };
+                draw_tag(
+                    mem_dc,
+                    pixels_u32,
+                    width,
+                    height,
+                    anchor,
+                    &format!("{} × {}", w, h),
+                );
+            }
         }
     }
 
@@ -16,7 +16,7 @@ use wry::{Rect, WebContext, WebViewBuilder};
 use crate::win_types::SendHwnd;
 
 static REGISTER_INPUT_CLASS: Once = Once::new();
-static mut INPUT_HWND: SendHwnd = SendHwnd(HWND(std::ptr::null_mut()));
+pub(crate) static mut INPUT_HWND: SendHwnd = SendHwnd(HWND(std::ptr::null_mut()));
 static mut IS_WARMED_UP: bool = false;
 // Colors
 const COL_DARK_BG: u32 = 0x202020; // RGB(32, 32, 32)
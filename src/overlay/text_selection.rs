@@ -45,6 +45,22 @@ static SELECTION_STATE: Mutex<TextSelectionState> = Mutex::new(TextSelectionStat
 
 static REGISTER_TAG_CLASS: Once = Once::new();
 
+/// A selection that an auto-pasted result was typed or pasted over
+struct Replacement {
+    original: String,
+    replacement: String,
+    target: HWND,
+    typed: bool,
+}
+unsafe impl Send for Replacement {}
+
+/// Preset id and selected text of the last selection sent to an auto-paste preset,
+/// until its result lands
+static PENDING_ORIGINAL: Mutex<Option<(String, String)>> = Mutex::new(None);
+
+/// The last replacement, for the "Undo last replace" hotkey
+static LAST_REPLACEMENT: Mutex<Option<Replacement>> = Mutex::new(None);
+
 lazy_static::lazy_static! {
     pub static ref TAG_ABORT_SIGNAL: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
 }
@@ -53,10 +69,27 @@ pub fn is_active() -> bool {
     !SELECTION_STATE.lock().unwrap().hwnd.is_invalid()
 }
 
+/// Whether the Esc keyboard hook of the selection tag is currently installed
+pub fn is_hook_installed() -> bool {
+    !SELECTION_STATE.lock().unwrap().hook_handle.is_invalid()
+}
+
 /// Try to process already-selected text instantly.
 /// Returns true if text was found and processing started (caller should NOT show selection tag).
 /// Returns false if no text was selected (caller should show selection tag for manual selection).
 pub fn try_instant_process(preset_idx: usize) -> bool {
+    match copy_selection() {
+        Some(clipboard_text) => {
+            process_selected_text(preset_idx, clipboard_text, true);
+            true // Signal caller that we handled it
+        }
+        None => false, // Signal caller to show selection tag
+    }
+}
+
+/// Copy the text selected in the foreground app via Ctrl+C. Returns None (with the
+/// clipboard put back as it was) when nothing is selected.
+pub fn copy_selection() -> Option<String> {
     unsafe {
         // Step 1: Save current clipboard content (we'll restore if empty selection)
         let original_clipboard = get_clipboard_text();
@@ -112,13 +145,25 @@ pub fn try_instant_process(preset_idx: usize) -> bool {
             if !original_clipboard.is_empty() {
                 crate::overlay::utils::copy_to_clipboard(&original_clipboard, HWND::default());
             }
-            return false; // Signal caller to show selection tag
+            return None;
         }
 
-        // Step 5: Text found! Process it immediately
-        process_selected_text(preset_idx, clipboard_text);
-        true // Signal caller that we handled it
+        Some(clipboard_text)
+    }
+}
+
+/// Process the selected text, or the clipboard's text when nothing is selected.
+/// Returns false if there was neither.
+pub fn process_selection_or_clipboard(preset_idx: usize) -> bool {
+    if try_instant_process(preset_idx) {
+        return true;
     }
+    let clipboard_text = unsafe { get_clipboard_text() };
+    if clipboard_text.trim().is_empty() {
+        return false;
+    }
+    process_selected_text(preset_idx, clipboard_text, false);
+    true
 }
 
 /// Get text from clipboard (returns empty string if no text available)
@@ -143,8 +188,9 @@ unsafe fn get_clipboard_text() -> String {
     result
 }
 
-/// Process selected text with the given preset (shared logic for both instant and manual selection)
-fn process_selected_text(preset_idx: usize, clipboard_text: String) {
+/// Process selected text with the given preset (shared logic for both instant and manual selection).
+/// `from_selection` is false for the clipboard fallback, where nothing gets replaced.
+fn process_selected_text(preset_idx: usize, clipboard_text: String, from_selection: bool) {
     unsafe {
         // Check if this is a MASTER preset
         let (is_master, _original_mode) = {
@@ -173,15 +219,13 @@ fn process_selected_text(preset_idx: usize, clipboard_text: String) {
         };
 
         // Process with the selected preset
-        let (config, mut preset, screen_w, screen_h) = {
+        let (config, mut preset) = {
             let mut app = APP.lock().unwrap();
             // CRITICAL: Update active_preset_idx so auto_paste logic works!
             app.config.active_preset_idx = final_preset_idx;
             (
                 app.config.clone(),
                 app.config.presets[final_preset_idx].clone(),
-                GetSystemMetrics(SM_CXSCREEN),
-                GetSystemMetrics(SM_CYSCREEN),
             )
         };
 
@@ -189,11 +233,15 @@ fn process_selected_text(preset_idx: usize, clipboard_text: String) {
         // directly, not re-opened in a text input modal
         preset.text_input_mode = "select".to_string();
 
+        // Centered on the monitor the text was selected on, sized for its DPI
+        let (work, scale) = crate::overlay::utils::cursor_monitor_work_area();
+        let (w, h) = ((700.0 * scale) as i32, (300.0 * scale) as i32);
+        let (center_x, center_y) = ((work.left + work.right) / 2, (work.top + work.bottom) / 2);
         let center_rect = RECT {
-            left: (screen_w - 700) / 2,
-            top: (screen_h - 300) / 2,
-            right: (screen_w + 700) / 2,
-            bottom: (screen_h + 300) / 2,
+            left: center_x - w / 2,
+            top: center_y - h / 2,
+            right: center_x + w / 2,
+            bottom: center_y + h / 2,
         };
         // Get localized preset name and hotkey for the text input header
         let localized_name =
@@ -204,6 +252,11 @@ fn process_selected_text(preset_idx: usize, clipboard_text: String) {
             .map(|h| h.name.clone())
             .unwrap_or_default();
 
+        // The result will be pasted over the selection; keep it for undo
+        if preset.auto_paste && from_selection {
+            *PENDING_ORIGINAL.lock().unwrap() = Some((preset.id.clone(), clipboard_text.clone()));
+        }
+
         super::process::start_text_processing(
             clipboard_text,
             center_rect,
@@ -215,6 +268,118 @@ fn process_selected_text(preset_idx: usize, clipboard_text: String) {
     }
 }
 
+/// Note that `replacement` was just typed or pasted into `target` by `preset_id`. If
+/// that preset was started on a selection, the selection was replaced: show what was
+/// replaced and remember it for `undo_last_replacement`.
+pub fn record_replacement(preset_id: &str, replacement: &str, target: HWND, typed: bool) {
+    let original = {
+        let mut pending = PENDING_ORIGINAL.lock().unwrap();
+        match pending.take() {
+            Some((id, text)) if id == preset_id => text,
+            other => {
+                *pending = other;
+                return;
+            }
+        }
+    };
+
+    let (lang, undo_hotkey) = {
+        let app = APP.lock().unwrap();
+        (
+            app.config.ui_language.clone(),
+            app.config
+                .undo_replace_hotkey
+                .as_ref()
+                .map(|h| h.name.clone()),
+        )
+    };
+    let locale = crate::gui::locale::LocaleText::get(&lang);
+    let mut message = format!(
+        "{} {} → {}",
+        locale.text_replaced_label,
+        short_quote(&original),
+        short_quote(replacement)
+    );
+    if let Some(hotkey) = undo_hotkey {
+        message.push_str(&format!(
+            " ({}: {})",
+            locale.app_hotkey_undo_replace, hotkey
+        ));
+    }
+    super::auto_copy_badge::show_notification(&message);
+
+    *LAST_REPLACEMENT.lock().unwrap() = Some(Replacement {
+        original,
+        replacement: replacement.to_string(),
+        target,
+        typed,
+    });
+}
+
+/// Put back the text the last replacement overwrote. The replacement is selected
+/// from the caret backwards, so this expects the caret to still be right after it.
+pub fn undo_last_replacement() {
+    let last = LAST_REPLACEMENT.lock().unwrap().take();
+    let lang = APP.lock().unwrap().config.ui_language.clone();
+    let locale = crate::gui::locale::LocaleText::get(&lang);
+    let Some(last) = last else {
+        super::auto_copy_badge::show_notification(locale.text_replace_nothing_to_undo);
+        return;
+    };
+    if !crate::overlay::utils::focus_for_input(last.target) {
+        return;
+    }
+
+    // Editors step over a line break in one go, whether it arrived as \r\n or \n
+    let steps = last.replacement.chars().filter(|&c| c != '\r').count();
+    select_before_caret(steps);
+    std::thread::sleep(std::time::Duration::from_millis(50));
+
+    if last.typed {
+        crate::overlay::utils::type_text(0, &last.original);
+    } else {
+        crate::overlay::utils::copy_to_clipboard(&last.original, HWND::default());
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        crate::overlay::utils::send_paste();
+    }
+    super::auto_copy_badge::show_notification(locale.text_replace_undone);
+}
+
+/// Shift+Left `steps` times
+fn select_before_caret(steps: usize) {
+    let key = |vk: VIRTUAL_KEY, flags: KEYBD_EVENT_FLAGS| INPUT {
+        r#type: INPUT_KEYBOARD,
+        Anonymous: INPUT_0 {
+            ki: KEYBDINPUT {
+                wVk: vk,
+                dwFlags: flags,
+                ..Default::default()
+            },
+        },
+    };
+
+    let mut inputs = vec![key(VK_SHIFT, KEYBD_EVENT_FLAGS(0))];
+    for _ in 0..steps {
+        inputs.push(key(VK_LEFT, KEYEVENTF_EXTENDEDKEY));
+        inputs.push(key(VK_LEFT, KEYEVENTF_EXTENDEDKEY | KEYEVENTF_KEYUP));
+    }
+    inputs.push(key(VK_SHIFT, KEYEVENTF_KEYUP));
+    unsafe {
+        SendInput(&inputs, std::mem::size_of::<INPUT>() as i32);
+    }
+}
+
+/// The start of `text` on one line, quoted, for a notification
+fn short_quote(text: &str) -> String {
+    const MAX_CHARS: usize = 24;
+    let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() > MAX_CHARS {
+        format!("\"{}…\"", line.chars().take(MAX_CHARS).collect::<String>())
+    } else {
+        format!("\"{}\"", line)
+    }
+}
+
 pub fn cancel_selection() {
     TAG_ABORT_SIGNAL.store(true, Ordering::SeqCst);
     let hwnd = SELECTION_STATE.lock().unwrap().hwnd;
@@ -447,7 +612,7 @@ pub fn show_text_selection_tag(preset_idx: usize) {
                         if !clipboard_text.trim().is_empty()
                             && !TAG_ABORT_SIGNAL.load(Ordering::Relaxed)
                         {
-                            process_selected_text(preset_idx_for_thread, clipboard_text);
+                            process_selected_text(preset_idx_for_thread, clipboard_text, true);
                             let _ = PostMessageW(Some(hwnd_copy), WM_CLOSE, WPARAM(0), LPARAM(0));
                         } else {
                             let mut state = SELECTION_STATE.lock().unwrap();
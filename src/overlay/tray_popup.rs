@@ -20,7 +20,7 @@ use wry::{Rect, WebContext, WebView, WebViewBuilder};
 static REGISTER_POPUP_CLASS: Once = Once::new();
 // 0=Closed, 1=Warmup, 2=Open, 3=PendingCancel
 static POPUP_STATE: std::sync::atomic::AtomicI32 = std::sync::atomic::AtomicI32::new(0);
-static POPUP_HWND: AtomicIsize = AtomicIsize::new(0);
+pub(crate) static POPUP_HWND: AtomicIsize = AtomicIsize::new(0);
 static IGNORE_FOCUS_LOSS_UNTIL: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
 
 
@@ -31,7 +31,103 @@ thread_local! {
 }
 
 const BASE_POPUP_WIDTH: i32 = 220;
-const BASE_POPUP_HEIGHT: i32 = 152; // Base height at 100% scaling (96 DPI) - includes stop TTS row
+const BASE_POPUP_HEIGHT: i32 = 254; // Base height at 100% scaling (96 DPI) - includes quick translate, stop TTS and mixer rows
+const PRESET_ROW_HEIGHT: i32 = 34; // menu-item height + margin
+const SEPARATOR_HEIGHT: i32 = 9;
+const MAX_PRESET_ROWS: usize = 8; // Keep the popup a menu, not a preset list
+const SECTION_TITLE_HEIGHT: i32 = 24;
+const RECENT_ROWS: usize = 5;
+const RECENT_LABEL_CHARS: usize = 30;
+const PAUSE_ICON: &str =
+    r#"<svg viewBox="0 0 24 24" fill="currentColor"><path d="M6 5h4v14H6zM14 5h4v14h-4z"/></svg>"#;
+const PLAY_ICON: &str =
+    r#"<svg viewBox="0 0 24 24" fill="currentColor"><path d="M8 5v14l11-7z"/></svg>"#;
+
+/// Favorited presets as (preset index, display name), listed above the fixed items
+fn favorite_presets() -> Vec<(usize, String)> {
+    let Ok(app) = APP.lock() else {
+        return Vec::new();
+    };
+    let lang = &app.config.ui_language;
+    app.config
+        .presets
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| p.is_favorite && !p.is_upcoming)
+        .take(MAX_PRESET_ROWS)
+        .map(|(idx, p)| {
+            let name = if p.id.starts_with("preset_") {
+                crate::gui::settings_ui::get_localized_preset_name(&p.id, lang)
+            } else {
+                p.name.clone()
+            };
+            (idx, name)
+        })
+        .collect()
+}
+
+/// Newest history results as (history id, one-line preview)
+fn recent_history() -> Vec<(i64, String)> {
+    let Some(history) = APP.lock().ok().map(|app| app.history.clone()) else {
+        return Vec::new();
+    };
+    let Ok(items) = history.items.lock() else {
+        return Vec::new();
+    };
+    items
+        .iter()
+        .filter(|item| !item.text.trim().is_empty())
+        .take(RECENT_ROWS)
+        .map(|item| {
+            let line = item.text.split_whitespace().collect::<Vec<_>>().join(" ");
+            let mut preview: String = line.chars().take(RECENT_LABEL_CHARS).collect();
+            if line.chars().count() > RECENT_LABEL_CHARS {
+                preview.push('…');
+            }
+            (item.id, preview)
+        })
+        .collect()
+}
+
+/// Copy a history entry's result to the clipboard
+fn copy_history_item(id: i64) {
+    let Some(history) = APP.lock().ok().map(|app| app.history.clone()) else {
+        return;
+    };
+    let text = history.items.lock().ok().and_then(|items| {
+        items
+            .iter()
+            .find(|item| item.id == id)
+            .map(|item| item.text.clone())
+    });
+    if let Some(text) = text {
+        crate::gui::utils::copy_to_clipboard_text(&text);
+        crate::overlay::auto_copy_badge::show_auto_copy_badge_text(&text);
+    }
+}
+
+/// Unscaled popup height for the given number of preset and recent-result rows,
+/// plus the reading controls while a document is read aloud
+fn popup_base_height(preset_rows: usize, recent_rows: usize, reading: bool) -> i32 {
+    let mut height = BASE_POPUP_HEIGHT;
+    if reading {
+        height += PRESET_ROW_HEIGHT;
+    }
+    if preset_rows > 0 {
+        height += preset_rows as i32 * PRESET_ROW_HEIGHT + SEPARATOR_HEIGHT;
+    }
+    if recent_rows > 0 {
+        height += SECTION_TITLE_HEIGHT + recent_rows as i32 * PRESET_ROW_HEIGHT + SEPARATOR_HEIGHT;
+    }
+    height
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
 
 /// Get DPI-scaled dimension
 fn get_scaled_dimension(base: i32) -> i32 {
@@ -190,10 +286,14 @@ pub fn is_popup_open() -> bool {
     POPUP_STATE.load(Ordering::SeqCst) == 2
 }
 
-fn generate_popup_html() -> String {
+fn generate_popup_html(
+    favorites: &[(usize, String)],
+    recent: &[(i64, String)],
+    reading: bool,
+) -> String {
     use crate::config::ThemeMode;
     
-    let (settings_text, bubble_text, stop_tts_text, quit_text, bubble_checked, is_dark_mode) = if let Ok(app) = APP.lock() {
+    let (settings_text, bubble_text, stop_tts_text, mixer_text, quit_text, bubble_checked, is_dark_mode) = if let Ok(app) = APP.lock() {
         let lang = &app.config.ui_language;
         let settings = match lang.as_str() {
             "vi" => "Cài đặt",
@@ -210,6 +310,11 @@ fn generate_popup_html() -> String {
             "ko" => "재생 중인 모든 음성 중지",
             _ => "Stop All Playing TTS",
         };
+        let mixer = match lang.as_str() {
+            "vi" => "Âm lượng ứng dụng",
+            "ko" => "앱별 볼륨",
+            _ => "Volume Mixer",
+        };
         let quit = match lang.as_str() {
             "vi" => "Thoát",
             "ko" => "종료",
@@ -224,9 +329,9 @@ fn generate_popup_html() -> String {
             ThemeMode::System => crate::gui::utils::is_system_in_dark_mode(),
         };
         
-        (settings, bubble, stop_tts, quit, checked, is_dark)
+        (settings, bubble, stop_tts, mixer, quit, checked, is_dark)
     } else {
-        ("Settings", "Favorite Bubble", "Stop All TTS", "Quit", false, true)
+        ("Settings", "Favorite Bubble", "Stop All TTS", "Volume Mixer", "Quit", false, true)
     };
 
     // Check if TTS has pending audio
@@ -253,6 +358,123 @@ fn generate_popup_html() -> String {
 
     let stop_tts_disabled_class = if has_tts_pending { "" } else { "disabled" };
 
+    // Previous sentence / pause / next sentence while a result is read aloud
+    let reading_row = if reading {
+        let lang = APP
+            .lock()
+            .map(|app| app.config.ui_language.clone())
+            .unwrap_or_default();
+        let label = match lang.as_str() {
+            "vi" => "Đang đọc",
+            "ko" => "읽는 중",
+            _ => "Reading aloud",
+        };
+        let pause_icon = if crate::api::tts::TTS_MANAGER.is_paused() {
+            PLAY_ICON
+        } else {
+            PAUSE_ICON
+        };
+        format!(
+            r#"<div class="menu-item reading-item">
+        <div class="label">{label}</div>
+        <div class="reading-btn" onclick="action('tts_prev')">
+            <svg viewBox="0 0 24 24" fill="currentColor"><path d="M6 5h2v14H6zM20 5v14L9 12z"/></svg>
+        </div>
+        <div class="reading-btn" id="tts-pause" onclick="togglePause()">{pause_icon}</div>
+        <div class="reading-btn" onclick="action('tts_next')">
+            <svg viewBox="0 0 24 24" fill="currentColor"><path d="M16 5h2v14h-2zM4 5v14l11-7z"/></svg>
+        </div>
+    </div>
+    "#,
+            label = escape_html(label),
+            pause_icon = pause_icon
+        )
+    } else {
+        String::new()
+    };
+
+    // Quick-run rows for favorited presets; the popup is rebuilt on every open
+    let mut preset_rows: String = favorites
+        .iter()
+        .map(|(idx, name)| {
+            format!(
+                r#"<div class="menu-item" onclick="action('preset:{idx}')">
+        <div class="icon">
+            <svg viewBox="0 0 24 24" fill="currentColor"><path d="M8 5v14l11-7z"/></svg>
+        </div>
+        <div class="label">{name}</div>
+        <div class="check"></div>
+    </div>
+    "#,
+                idx = idx,
+                name = escape_html(name)
+            )
+        })
+        .collect();
+    if !preset_rows.is_empty() {
+        preset_rows.push_str("<div class=\"separator\"></div>\n    ");
+    }
+
+    // Recent results, newest first; clicking one copies it
+    let mut recent_rows = String::new();
+    if !recent.is_empty() {
+        let title = APP
+            .lock()
+            .map(|app| crate::gui::locale::LocaleText::get(&app.config.ui_language).tray_recent)
+            .unwrap_or("Recent");
+        recent_rows.push_str(&format!(
+            "<div class=\"section-title\">{}</div>\n    ",
+            escape_html(title)
+        ));
+        for (id, preview) in recent {
+            recent_rows.push_str(&format!(
+                r#"<div class="menu-item recent-item" onclick="action('recent:{id}')">
+        <div class="icon">
+            <svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><rect x="9" y="9" width="13" height="13" rx="2"/><path d="M5 15H4a2 2 0 0 1-2-2V4a2 2 0 0 1 2-2h9a2 2 0 0 1 2 2v1"/></svg>
+        </div>
+        <div class="label">{preview}</div>
+        <div class="check"></div>
+    </div>
+    "#,
+                id = id,
+                preview = escape_html(preview)
+            ));
+        }
+        recent_rows.push_str("<div class=\"separator\"></div>\n    ");
+    }
+
+    // Translate clipboard / selection with the quick translate settings, no preset needed
+    let (quick_clipboard_text, quick_selection_text) = APP
+        .lock()
+        .map(|app| {
+            let locale = crate::gui::locale::LocaleText::get(&app.config.ui_language);
+            (
+                locale.app_hotkey_translate_clipboard,
+                locale.app_hotkey_translate_selection,
+            )
+        })
+        .unwrap_or(("Translate clipboard", "Translate selection"));
+    let quick_rows = format!(
+        r#"<div class="menu-item" onclick="action('quick_clipboard')">
+        <div class="icon">
+            <svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M16 4h2a2 2 0 0 1 2 2v14a2 2 0 0 1-2 2H6a2 2 0 0 1-2-2V6a2 2 0 0 1 2-2h2"/><rect x="8" y="2" width="8" height="4" rx="1"/></svg>
+        </div>
+        <div class="label">{clipboard}</div>
+        <div class="check"></div>
+    </div>
+    <div class="menu-item" onclick="action('quick_selection')">
+        <div class="icon">
+            <svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M5 8l6 6"/><path d="M4 14l6-6 2-3"/><path d="M2 5h12"/><path d="M7 2h1"/><path d="M22 22l-5-10-5 10"/><path d="M14 18h6"/></svg>
+        </div>
+        <div class="label">{selection}</div>
+        <div class="check"></div>
+    </div>
+    <div class="separator"></div>
+    "#,
+        clipboard = escape_html(quick_clipboard_text),
+        selection = escape_html(quick_selection_text)
+    );
+
     // Get font CSS to preload fonts into WebView2 cache (tray popup warms up first)
     let font_css = crate::overlay::html_components::font_manager::get_font_css();
 
@@ -354,6 +576,37 @@ svg {{
     color: var(--text-color);
 }}
 
+.section-title {{
+    font-size: 11px;
+    opacity: 0.6;
+    padding: 4px 10px;
+    height: 24px;
+}}
+
+.recent-item .label {{
+    font-size: 12px;
+    opacity: 0.9;
+}}
+
+.reading-btn {{
+    display: flex;
+    align-items: center;
+    justify-content: center;
+    width: 26px;
+    height: 24px;
+    border-radius: 4px;
+    opacity: 0.8;
+}}
+
+.reading-btn:hover {{
+    background: var(--hover-bg);
+    opacity: 1;
+}}
+
+.reading-item:hover {{
+    background: transparent;
+}}
+
 .menu-item.disabled {{
     opacity: 0.4;
     pointer-events: none;
@@ -362,7 +615,7 @@ svg {{
 </head>
 <body>
 <div class="container">
-    <div class="menu-item" onclick="action('settings')">
+    {preset_rows}{quick_rows}{recent_rows}<div class="menu-item" onclick="action('settings')">
         <div class="icon">
             <svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round">
                 <path d="M12.22 2h-.44a2 2 0 0 0-2 2v.18a2 2 0 0 1-1 1.73l-.43.25a2 2 0 0 1-2 0l-.15-.08a2 2 0 0 0-2.73.73l-.22.38a2 2 0 0 0 .73 2.73l.15.1a2 2 0 0 1 1 1.72v.51a2 2 0 0 1-1 1.74l-.15.09a2 2 0 0 0-.73 2.73l.22.38a2 2 0 0 0 2.73.73l.15-.08a2 2 0 0 1 2 0l.43.25a2 2 0 0 1 1 1.73V20a2 2 0 0 0 2 2h.44a2 2 0 0 0 2-2v-.18a2 2 0 0 1 1-1.73l.43-.25a2 2 0 0 1 2 0l.15.08a2 2 0 0 0 2.73-.73l.22-.39a2 2 0 0 0-.73-2.73l-.15-.09a2 2 0 0 1-1-1.74v-.47a2 2 0 0 1 1-1.74l.15-.09a2 2 0 0 0 .73-2.73l-.22-.39a2 2 0 0 0-2.73-.73l-.15.08a2 2 0 0 1-2 0l-.43-.25a2 2 0 0 1-1-1.73V4a2 2 0 0 0-2-2z"></path>
@@ -381,7 +634,7 @@ svg {{
         <div class="check" id="bubble-check-container">{check}</div>
     </div>
     
-    <div class="menu-item {stop_tts_disabled}" onclick="action('stop_tts')">
+    {reading_row}<div class="menu-item {stop_tts_disabled}" onclick="action('stop_tts')">
         <div class="icon">
             <svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M11 5L6 9H2v6h4l5 4V5z"/><line x1="23" y1="9" x2="17" y2="15"/><line x1="17" y1="9" x2="23" y2="15"/></svg>
         </div>
@@ -389,6 +642,14 @@ svg {{
         <div class="check"></div>
     </div>
     
+    <div class="menu-item" onclick="action('mixer')">
+        <div class="icon">
+            <svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><line x1="4" y1="21" x2="4" y2="14"/><line x1="4" y1="10" x2="4" y2="3"/><line x1="12" y1="21" x2="12" y2="12"/><line x1="12" y1="8" x2="12" y2="3"/><line x1="20" y1="21" x2="20" y2="16"/><line x1="20" y1="12" x2="20" y2="3"/><line x1="1" y1="14" x2="7" y2="14"/><line x1="9" y1="8" x2="15" y2="8"/><line x1="17" y1="16" x2="23" y2="16"/></svg>
+        </div>
+        <div class="label">{mixer}</div>
+        <div class="check"></div>
+    </div>
+    
     <div class="separator"></div>
     
     <div class="menu-item" onclick="action('quit')">
@@ -417,6 +678,11 @@ function action(cmd) {{
     }}
     window.ipc.postMessage(cmd);
 }}
+function togglePause() {{
+    const btn = document.getElementById('tts-pause');
+    btn.innerHTML = btn.innerHTML.indexOf('M8 5') >= 0 ? '{pause_svg}' : '{play_svg}';
+    window.ipc.postMessage('tts_pause');
+}}
 // Close on click outside (detect blur)
 window.addEventListener('blur', function() {{
     if (window.ignoreBlur) return;
@@ -430,10 +696,17 @@ window.addEventListener('blur', function() {{
         hover = hover_color,
         border = border_color,
         separator = separator_color,
+        preset_rows = preset_rows,
+        quick_rows = quick_rows,
+        recent_rows = recent_rows,
         settings = settings_text,
         bubble = bubble_text,
         stop_tts = stop_tts_text,
         stop_tts_disabled = stop_tts_disabled_class,
+        reading_row = reading_row,
+        pause_svg = PAUSE_ICON,
+        play_svg = PLAY_ICON,
+        mixer = mixer_text,
         quit = quit_text,
         check = check_mark
     )
@@ -487,7 +760,11 @@ fn create_popup_window(is_warmup: bool) {
         });
 
         // Get DPI-scaled dimensions
-        let popup_height = get_scaled_dimension(BASE_POPUP_HEIGHT);
+        let favorites = favorite_presets();
+        let recent = recent_history();
+        let reading = crate::api::tts::TTS_MANAGER.is_reading_document(0);
+        let popup_height =
+            get_scaled_dimension(popup_base_height(favorites.len(), recent.len(), reading));
         let popup_width = get_scaled_dimension(BASE_POPUP_WIDTH);
 
         // Get cursor position for placement (calculated later if warming up)
@@ -544,7 +821,7 @@ fn create_popup_window(is_warmup: bool) {
 
         // Create WebView using shared context for RAM efficiency
         let wrapper = HwndWrapper(hwnd);
-        let html = generate_popup_html();
+        let html = generate_popup_html(&favorites, &recent, reading);
 
         // Initialize shared WebContext if needed (uses same data dir as other modules)
         POPUP_WEB_CONTEXT.with(|ctx| {
@@ -564,7 +841,7 @@ fn create_popup_window(is_warmup: bool) {
             let builder = crate::overlay::html_components::font_manager::configure_webview(builder);
             builder
                 .with_bounds(Rect {
-                    position: wry::dpi::Position::Logical(wry::dpi::LogicalPosition::new(0.0, 0.0)),
+                    position: wry::dpi::Position::Physical(wry::dpi::PhysicalPosition::new(0, 0)),
                     size: wry::dpi::Size::Physical(wry::dpi::PhysicalSize::new(
                         popup_width as u32,
                         popup_height as u32,
@@ -591,25 +868,7 @@ fn create_popup_window(is_warmup: bool) {
                         }
                         "bubble" => {
                             // Toggle bubble state
-                            let new_state = if let Ok(mut app) = APP.lock() {
-                                app.config.show_favorite_bubble = !app.config.show_favorite_bubble;
-                                let enabled = app.config.show_favorite_bubble;
-                                crate::config::save_config(&app.config);
-
-                                if enabled {
-                                    crate::overlay::favorite_bubble::show_favorite_bubble();
-                                    // Slight delay so the window is created before blinking
-                                    std::thread::spawn(|| {
-                                        std::thread::sleep(std::time::Duration::from_millis(150));
-                                        crate::overlay::favorite_bubble::trigger_blink_animation();
-                                    });
-                                } else {
-                                    crate::overlay::favorite_bubble::hide_favorite_bubble();
-                                }
-                                enabled
-                            } else {
-                                false
-                            };
+                            let new_state = crate::overlay::favorite_bubble::toggle_favorite_bubble();
 
                             // Update checkmark in popup via JavaScript (keep popup open)
                             POPUP_WEBVIEW.with(|cell| {
@@ -624,6 +883,12 @@ fn create_popup_window(is_warmup: bool) {
                                 }
                             });
                         }
+                        // Reading controls keep the popup open
+                        "tts_prev" => crate::api::tts::TTS_MANAGER.previous_sentence(),
+                        "tts_next" => crate::api::tts::TTS_MANAGER.next_sentence(),
+                        "tts_pause" => {
+                            crate::api::tts::TTS_MANAGER.toggle_pause();
+                        }
                         "stop_tts" => {
                             // Stop all TTS playback and clear queues
                             crate::api::tts::TTS_MANAGER.stop();
@@ -638,6 +903,18 @@ fn create_popup_window(is_warmup: bool) {
                                 );
                             }
                         }
+                        "mixer" => {
+                            let h = POPUP_HWND.load(Ordering::SeqCst);
+                            if h != 0 {
+                                let _ = PostMessageW(
+                                    Some(HWND(h as *mut _)),
+                                    WM_CLOSE,
+                                    WPARAM(0),
+                                    LPARAM(0),
+                                );
+                            }
+                            crate::overlay::volume_mixer::show_volume_mixer();
+                        }
                         "quit" => {
                             // Close popup first
                             let h = POPUP_HWND.load(Ordering::SeqCst);
@@ -652,9 +929,66 @@ fn create_popup_window(is_warmup: bool) {
                             // Small delay to let window close, then exit
                             std::thread::spawn(|| {
                                 std::thread::sleep(std::time::Duration::from_millis(50));
+                                crate::gui::flush_pending_config_save();
+                                crate::overlay::utils::clear_clipboard_on_exit();
                                 std::process::exit(0);
                             });
                         }
+                        cmd if cmd.starts_with("preset:") => {
+                            let h = POPUP_HWND.load(Ordering::SeqCst);
+                            if h != 0 {
+                                let _ = PostMessageW(
+                                    Some(HWND(h as *mut _)),
+                                    WM_CLOSE,
+                                    WPARAM(0),
+                                    LPARAM(0),
+                                );
+                            }
+                            // Same path as the preset's hotkey. Wait for the popup to go so
+                            // the window it captures as paste target is the user's app.
+                            if let Ok(preset_idx) = cmd["preset:".len()..].parse::<usize>() {
+                                std::thread::spawn(move || {
+                                    std::thread::sleep(std::time::Duration::from_millis(100));
+                                    crate::trigger_preset(preset_idx);
+                                });
+                            }
+                        }
+                        "quick_clipboard" | "quick_selection" => {
+                            let h = POPUP_HWND.load(Ordering::SeqCst);
+                            if h != 0 {
+                                let _ = PostMessageW(
+                                    Some(HWND(h as *mut _)),
+                                    WM_CLOSE,
+                                    WPARAM(0),
+                                    LPARAM(0),
+                                );
+                            }
+                            // The selection is copied from the foreground app, so let the
+                            // popup go first
+                            let from_selection = body == "quick_selection";
+                            std::thread::spawn(move || {
+                                std::thread::sleep(std::time::Duration::from_millis(100));
+                                if from_selection {
+                                    crate::overlay::quick_translate::translate_selection();
+                                } else {
+                                    crate::overlay::quick_translate::translate_clipboard();
+                                }
+                            });
+                        }
+                        cmd if cmd.starts_with("recent:") => {
+                            let h = POPUP_HWND.load(Ordering::SeqCst);
+                            if h != 0 {
+                                let _ = PostMessageW(
+                                    Some(HWND(h as *mut _)),
+                                    WM_CLOSE,
+                                    WPARAM(0),
+                                    LPARAM(0),
+                                );
+                            }
+                            if let Ok(id) = cmd["recent:".len()..].parse::<i64>() {
+                                copy_history_item(id);
+                            }
+                        }
                         "close" => {
                             let h = POPUP_HWND.load(Ordering::SeqCst);
                             if h != 0 {
@@ -1,13 +1,18 @@
 use windows::Win32::Foundation::*;
 use windows::Win32::Graphics::Gdi::{
-    CreateDIBitmap, GetDC, ReleaseDC, BITMAPINFO, BITMAPINFOHEADER, CBM_INIT, DIB_RGB_COLORS,
+    CreateDIBitmap, GetDC, GetMonitorInfoW, MonitorFromPoint, MonitorFromRect, ReleaseDC,
+    BITMAPINFO, BITMAPINFOHEADER, CBM_INIT, DIB_RGB_COLORS, HMONITOR, MONITORINFO,
+    MONITOR_DEFAULTTONEAREST,
 };
 use windows::Win32::System::DataExchange::*;
 use windows::Win32::System::Memory::*;
 use windows::Win32::System::Threading::*;
+use windows::Win32::UI::HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI};
 use windows::Win32::UI::Input::KeyboardAndMouse::*;
 use windows::Win32::UI::WindowsAndMessaging::*;
 
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
 pub fn to_wstring(s: &str) -> Vec<u16> {
     s.encode_utf16().chain(std::iter::once(0)).collect()
 }
@@ -16,13 +21,110 @@ pub fn to_wstring(s: &str) -> Vec<u16> {
 /// Set to false to hide the quote and only show the glow animation.
 pub const SHOW_REFINING_CONTEXT_QUOTE: bool = false;
 
-pub fn get_context_quote(text: &str) -> String {
-    let words: Vec<&str> = text.split_whitespace().collect();
-    let len = words.len();
-    if len > 50 {
-        format!("\"... {}\"", words[len - 50..].join(" "))
-    } else {
-        format!("\"... {}\"", words.join(" "))
+/// Fit a saved window rect (x, y, width, height) into the work area of the monitor
+/// it's mostly on (or the nearest one, if that monitor is gone), shrinking it if needed
+pub fn clamp_rect_to_monitor(rect: (i32, i32, i32, i32)) -> (i32, i32, i32, i32) {
+    let (x, y, width, height) = rect;
+    unsafe {
+        let monitor = MonitorFromRect(
+            &RECT {
+                left: x,
+                top: y,
+                right: x + width,
+                bottom: y + height,
+            },
+            MONITOR_DEFAULTTONEAREST,
+        );
+        let mut info = MONITORINFO {
+            cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+            ..Default::default()
+        };
+        if !GetMonitorInfoW(monitor, &mut info).as_bool() {
+            return rect;
+        }
+        let work = info.rcWork;
+        let width = width.min(work.right - work.left);
+        let height = height.min(work.bottom - work.top);
+        let x = x.clamp(work.left, work.right - width);
+        let y = y.clamp(work.top, work.bottom - height);
+        (x, y, width, height)
+    }
+}
+
+fn monitor_work_area(monitor: HMONITOR) -> Option<RECT> {
+    let mut info = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
+    unsafe { GetMonitorInfoW(monitor, &mut info).as_bool() }.then_some(info.rcWork)
+}
+
+fn primary_screen_rect() -> RECT {
+    unsafe {
+        RECT {
+            left: 0,
+            top: 0,
+            right: GetSystemMetrics(SM_CXSCREEN),
+            bottom: GetSystemMetrics(SM_CYSCREEN),
+        }
+    }
+}
+
+/// Work area (virtual-screen coordinates) of the monitor a rect is mostly on
+pub fn work_area_for_rect(rect: &RECT) -> RECT {
+    let monitor = unsafe { MonitorFromRect(rect, MONITOR_DEFAULTTONEAREST) };
+    monitor_work_area(monitor).unwrap_or_else(primary_screen_rect)
+}
+
+/// Work area of the monitor under the cursor, plus that monitor's scale
+/// (1.0 at 96 DPI), for placing windows that have no anchor of their own
+pub fn cursor_monitor_work_area() -> (RECT, f32) {
+    unsafe {
+        let mut cursor = POINT::default();
+        let _ = GetCursorPos(&mut cursor);
+        let monitor = MonitorFromPoint(cursor, MONITOR_DEFAULTTONEAREST);
+        let (mut dpi_x, mut dpi_y) = (96, 96);
+        let _ = GetDpiForMonitor(monitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y);
+        let work = monitor_work_area(monitor).unwrap_or_else(primary_screen_rect);
+        (work, dpi_x as f32 / 96.0)
+    }
+}
+
+/// Clipboard sequence number right after our last text copy (0 = none yet). While
+/// the clipboard still has this number, it holds exactly what we put there.
+static OWNED_CLIPBOARD_SEQ: AtomicU32 = AtomicU32::new(0);
+/// Mirrors of the config's clipboard retention settings, see `set_clipboard_retention`
+static CLIPBOARD_CLEAR_AFTER_SECS: AtomicU32 = AtomicU32::new(0);
+static CLEAR_CLIPBOARD_ON_EXIT: AtomicBool = AtomicBool::new(false);
+
+/// Take over the clipboard retention settings; called whenever the config is loaded or saved
+pub fn set_clipboard_retention(config: &crate::config::Config) {
+    CLIPBOARD_CLEAR_AFTER_SECS.store(config.clipboard_clear_after_secs, Ordering::SeqCst);
+    CLEAR_CLIPBOARD_ON_EXIT.store(config.clear_clipboard_on_exit, Ordering::SeqCst);
+}
+
+/// Empty the clipboard if it still holds the text of our copy number `seq`;
+/// anything the user copied since is left alone
+fn clear_clipboard_if_owned(seq: u32) {
+    unsafe {
+        if seq == 0 || GetClipboardSequenceNumber() != seq {
+            return;
+        }
+        if OpenClipboard(None).is_ok() {
+            // Re-check under the open clipboard, something may have been copied meanwhile
+            if GetClipboardSequenceNumber() == seq {
+                let _ = EmptyClipboard();
+                log::debug!("Cleared our text from the clipboard");
+            }
+            let _ = CloseClipboard();
+        }
+    }
+}
+
+/// With "clear clipboard on exit" on, remove our last copy before the process ends
+pub fn clear_clipboard_on_exit() {
+    if CLEAR_CLIPBOARD_ON_EXIT.load(Ordering::SeqCst) {
+        clear_clipboard_if_owned(OWNED_CLIPBOARD_SEQ.load(Ordering::SeqCst));
     }
 }
 
@@ -50,6 +152,16 @@ pub fn copy_to_clipboard(text: &str, hwnd: HWND) {
                 }
 
                 let _ = CloseClipboard();
+
+                let seq = GetClipboardSequenceNumber();
+                OWNED_CLIPBOARD_SEQ.store(seq, Ordering::SeqCst);
+                let clear_after = CLIPBOARD_CLEAR_AFTER_SECS.load(Ordering::SeqCst);
+                if clear_after > 0 {
+                    std::thread::spawn(move || {
+                        std::thread::sleep(std::time::Duration::from_secs(clear_after as u64));
+                        clear_clipboard_if_owned(seq);
+                    });
+                }
                 return; // Success
             }
 
@@ -57,12 +169,41 @@ pub fn copy_to_clipboard(text: &str, hwnd: HWND) {
             if attempt < 4 {
                 std::thread::sleep(std::time::Duration::from_millis(10));
             } else {
-                eprintln!("Failed to copy to clipboard after 5 attempts");
+                log::error!("Failed to copy to clipboard after 5 attempts");
             }
         }
     }
 }
 
+/// Read the clipboard's text (empty if it holds no text)
+pub fn get_clipboard_text() -> String {
+    unsafe {
+        for attempt in 0..5 {
+            if OpenClipboard(None).is_ok() {
+                let mut text = String::new();
+                // CF_UNICODETEXT = 13
+                if let Ok(h_data) = GetClipboardData(13u32) {
+                    let ptr = GlobalLock(HGLOBAL(h_data.0)) as *const u16;
+                    if !ptr.is_null() {
+                        let mut len = 0;
+                        while *ptr.add(len) != 0 {
+                            len += 1;
+                        }
+                        text = String::from_utf16_lossy(std::slice::from_raw_parts(ptr, len));
+                        let _ = GlobalUnlock(HGLOBAL(h_data.0));
+                    }
+                }
+                let _ = CloseClipboard();
+                return text;
+            }
+            if attempt < 4 {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+        }
+    }
+    String::new()
+}
+
 pub fn copy_image_to_clipboard(image_bytes: &[u8]) {
     // Convert PNG/etc bytes to BMP format using image crate
     // Clipboard expects CF_DIB which is BMP without the File Header (first 14 bytes)
@@ -307,31 +448,112 @@ pub fn get_target_window_for_paste() -> Option<HWND> {
     }
 }
 
-pub fn force_focus_and_paste(hwnd_target: HWND) {
+/// True if `hwnd` belongs to an elevated (admin) process while SGT is not elevated.
+/// Windows silently drops simulated input sent to such windows (UIPI).
+pub fn is_paste_blocked_by_elevation(hwnd: HWND) -> bool {
+    if crate::gui::utils::is_running_as_admin() {
+        return false;
+    }
     unsafe {
-        // 1. Force focus back to the target window
-        if IsWindow(Some(hwnd_target)).as_bool() {
-            let cur_thread = GetCurrentThreadId();
-            let target_thread = GetWindowThreadProcessId(hwnd_target, None);
-
-            if cur_thread != target_thread {
-                let _ = AttachThreadInput(cur_thread, target_thread, true);
-                let _ = SetForegroundWindow(hwnd_target);
-                // Important: Bring window to top so it receives input
-                let _ = BringWindowToTop(hwnd_target);
-                let _ = SetFocus(Some(hwnd_target));
-                let _ = AttachThreadInput(cur_thread, target_thread, false);
-            } else {
-                let _ = SetForegroundWindow(hwnd_target);
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+        if pid == 0 {
+            return false;
+        }
+        let Ok(process) = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) else {
+            return false;
+        };
+        let elevated = is_process_elevated(process);
+        let _ = CloseHandle(process);
+        elevated
+    }
+}
+
+unsafe fn is_process_elevated(process: HANDLE) -> bool {
+    use windows::Win32::Security::{
+        GetTokenInformation, TokenElevation, TOKEN_ELEVATION, TOKEN_QUERY,
+    };
+
+    let mut token = HANDLE::default();
+    if OpenProcessToken(process, TOKEN_QUERY, &mut token).is_err() {
+        return false;
+    }
+    let mut elevation = TOKEN_ELEVATION::default();
+    let mut return_length = 0u32;
+    let ok = GetTokenInformation(
+        token,
+        TokenElevation,
+        Some(&mut elevation as *mut _ as *mut std::ffi::c_void),
+        std::mem::size_of::<TOKEN_ELEVATION>() as u32,
+        &mut return_length,
+    )
+    .is_ok();
+    let _ = CloseHandle(token);
+    ok && elevation.TokenIsElevated != 0
+}
+
+/// Bring `hwnd_target` to the foreground with keyboard focus.
+/// Windows only lets the foreground thread hand focus over, so join its input queue
+/// (and the target's) while switching.
+pub fn force_focus(hwnd_target: HWND) {
+    unsafe {
+        let cur_thread = GetCurrentThreadId();
+        let target_thread = GetWindowThreadProcessId(hwnd_target, None);
+        let fg_thread = GetWindowThreadProcessId(GetForegroundWindow(), None);
+
+        let mut attached = Vec::new();
+        for thread in [fg_thread, target_thread] {
+            if thread != 0
+                && thread != cur_thread
+                && !attached.contains(&thread)
+                && AttachThreadInput(cur_thread, thread, true).as_bool()
+            {
+                attached.push(thread);
             }
-        } else {
-            return;
         }
 
-        // 2. Wait for focus to settle
+        let _ = SetForegroundWindow(hwnd_target);
+        // Important: Bring window to top so it receives input
+        let _ = BringWindowToTop(hwnd_target);
+        let _ = SetFocus(Some(hwnd_target));
+
+        for thread in attached {
+            let _ = AttachThreadInput(cur_thread, thread, false);
+        }
+    }
+}
+
+/// Focus `hwnd_target` and get it ready for synthesized keys. Returns false (after
+/// telling the user why) if the keys would not reach it.
+pub fn focus_for_input(hwnd_target: HWND) -> bool {
+    unsafe {
+        if !IsWindow(Some(hwnd_target)).as_bool() {
+            return false;
+        }
+
+        // An elevated target would swallow the keys: say why instead of doing nothing.
+        // The text is already on the clipboard, so the user can still paste by hand.
+        if is_paste_blocked_by_elevation(hwnd_target) {
+            let lang = crate::APP.lock().unwrap().config.ui_language.clone();
+            let locale = crate::gui::locale::LocaleText::get(&lang);
+            crate::overlay::auto_copy_badge::show_notification(locale.auto_paste_elevated_msg);
+            return false;
+        }
+
+        force_focus(hwnd_target);
+
+        // Wait for focus to settle
         std::thread::sleep(std::time::Duration::from_millis(350));
 
-        // 3. CLEANUP MODIFIERS SMARTLY
+        release_modifiers();
+        true
+    }
+}
+
+/// Release the modifiers still held from the hotkey, so they don't combine with
+/// the keys sent next
+pub fn release_modifiers() {
+    unsafe {
         // Only send KeyUp if the key is actually physically pressed to avoid side effects
         let release_if_pressed = |vk: u16| {
             let state = GetAsyncKeyState(vk as i32);
@@ -357,8 +579,30 @@ pub fn force_focus_and_paste(hwnd_target: HWND) {
         release_if_pressed(VK_CONTROL.0); // Ctrl
 
         std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+}
+
+/// Focus `hwnd_target` and Ctrl+V into it. Returns false if it couldn't take the keys.
+pub fn force_focus_and_paste(hwnd_target: HWND) -> bool {
+    let focused = focus_for_input(hwnd_target);
+    if focused {
+        send_paste();
+    }
+    focused
+}
+
+/// Like `force_focus_and_paste`, but types `text` instead of pasting the clipboard
+pub fn force_focus_and_type(hwnd_target: HWND, text: &str) -> bool {
+    let focused = focus_for_input(hwnd_target);
+    if focused {
+        type_text(0, text);
+    }
+    focused
+}
 
-        // 4. Send Ctrl+V Sequence
+/// Ctrl+V into the focused window
+pub fn send_paste() {
+    unsafe {
         let send_input_event = |vk: u16, flags: KEYBD_EVENT_FLAGS| {
             let input = INPUT {
                 r#type: INPUT_KEYBOARD,
@@ -392,7 +636,131 @@ pub fn force_focus_and_paste(hwnd_target: HWND) {
     }
 }
 
+/// True if the foreground window is one of SGT's own (an overlay or the settings)
+pub fn is_own_window_foreground() -> bool {
+    unsafe {
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(GetForegroundWindow(), Some(&mut pid));
+        pid == GetCurrentProcessId()
+    }
+}
+
+/// Type into the focused window: Backspace `backspaces` times, then `text` (newlines
+/// as Enter, everything else as Unicode characters, so no keyboard layout is involved)
+pub fn type_text(backspaces: usize, text: &str) {
+    let key = |vk: VIRTUAL_KEY, scan: u16, flags: KEYBD_EVENT_FLAGS| INPUT {
+        r#type: INPUT_KEYBOARD,
+        Anonymous: INPUT_0 {
+            ki: KEYBDINPUT {
+                wVk: vk,
+                wScan: scan,
+                dwFlags: flags,
+                time: 0,
+                dwExtraInfo: 0,
+            },
+        },
+    };
+
+    let mut inputs = Vec::new();
+    for _ in 0..backspaces {
+        inputs.push(key(VK_BACK, 0, KEYBD_EVENT_FLAGS(0)));
+        inputs.push(key(VK_BACK, 0, KEYEVENTF_KEYUP));
+    }
+    for c in text.chars() {
+        if c == '\n' {
+            inputs.push(key(VK_RETURN, 0, KEYBD_EVENT_FLAGS(0)));
+            inputs.push(key(VK_RETURN, 0, KEYEVENTF_KEYUP));
+            continue;
+        }
+        let mut units = [0u16; 2];
+        for unit in c.encode_utf16(&mut units) {
+            inputs.push(key(VIRTUAL_KEY(0), *unit, KEYEVENTF_UNICODE));
+            inputs.push(key(
+                VIRTUAL_KEY(0),
+                *unit,
+                KEYEVENTF_UNICODE | KEYEVENTF_KEYUP,
+            ));
+        }
+    }
+    if !inputs.is_empty() {
+        unsafe {
+            SendInput(&inputs, std::mem::size_of::<INPUT>() as i32);
+        }
+    }
+}
+
+/// Languages written right to left, by ISO 639-3 code
+const RTL_LANGUAGES: &[&str] = &[
+    "ara", "heb", "fas", "urd", "pus", "yid", "snd", "uig", "div", "ckb", "syr",
+];
+
+/// Whether `language` (an English language name, as in the config) is written right
+/// to left
+pub fn is_rtl_language(language: &str) -> bool {
+    isolang::Language::from_name(language).is_some_and(|l| RTL_LANGUAGES.contains(&l.to_639_3()))
+}
+
+/// Whether most letters of `text` are in a right-to-left script (Hebrew, Arabic,
+/// Syriac, Thaana...), for text whose language isn't known
+pub fn is_rtl_text(text: &str) -> bool {
+    let (mut rtl, mut ltr) = (0usize, 0usize);
+    for c in text.chars().filter(|c| c.is_alphabetic()) {
+        match c as u32 {
+            0x0590..=0x08FF
+            | 0xFB1D..=0xFDFF
+            | 0xFE70..=0xFEFF
+            | 0x10800..=0x10FFF
+            | 0x1E800..=0x1EFFF => rtl += 1,
+            _ => ltr += 1,
+        }
+    }
+    rtl > ltr
+}
+
+/// "No internet connection", optionally suggesting the local (Ollama) models
+pub fn get_offline_message(lang: &str, suggest_local: bool) -> String {
+    let (message, hint) = match lang {
+        "vi" => (
+            "Không có kết nối Internet!",
+            "Bạn có thể chuyển sang model cục bộ (Ollama).",
+        ),
+        "ko" => (
+            "인터넷 연결이 없습니다!",
+            "로컬 모델(Ollama)로 전환할 수 있습니다.",
+        ),
+        "ja" => (
+            "インターネットに接続されていません!",
+            "ローカルモデル(Ollama)に切り替えることができます。",
+        ),
+        "zh" => ("没有网络连接!", "您可以切换到本地模型(Ollama)。"),
+        _ => (
+            "No internet connection!",
+            "You can switch to a local (Ollama) model.",
+        ),
+    };
+    if suggest_local {
+        format!("{}\n{}", message, hint)
+    } else {
+        message.to_string()
+    }
+}
+
 pub fn get_error_message(error: &str, lang: &str, model_name: Option<&str>) -> String {
+    if error.starts_with(crate::api::client::NO_INTERNET) {
+        return get_offline_message(lang, false);
+    }
+
+    if is_timeout_error(error) {
+        let model = model_name.unwrap_or("API");
+        return match lang {
+            "vi" => format!("{} không phản hồi kịp (hết thời gian chờ)!", model),
+            "ko" => format!("{} 요청 시간이 초과되었습니다!", model),
+            "ja" => format!("{} のリクエストがタイムアウトしました!", model),
+            "zh" => format!("{} 请求超时!", model),
+            _ => format!("{} request timed out!", model),
+        };
+    }
+
     // Parse NO_API_KEY:provider format
     if error.starts_with("NO_API_KEY") {
         let provider = if error.contains(':') {
@@ -606,12 +974,24 @@ fn format_http_error(
     }
 }
 
+/// Whether the request failed because the provider didn't answer within its
+/// configured timeout (ureq reports "timeout: ...", socket reads "timed out")
+pub fn is_timeout_error(error: &str) -> bool {
+    let lower_err = error.to_lowercase();
+    lower_err.contains("timeout:") || lower_err.contains("timed out")
+}
+
 pub fn is_retryable_error(error: &str) -> bool {
     // 1. Check for explicit Auth errors (Never retry)
     if error.contains("NO_API_KEY") || error.contains("INVALID_API_KEY") {
         return false;
     }
 
+    // A slow provider: fall back to another model rather than wait again
+    if is_timeout_error(error) {
+        return true;
+    }
+
     // 2. Check HTTP status if present
     if let Some(code) = extract_http_status_code(error) {
         // 429: Rate Limit (Retry!)
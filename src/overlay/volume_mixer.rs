@@ -0,0 +1,498 @@
+// Volume Mixer - Small popup with one volume slider per app playing audio
+// Opened from the tray popup; built on crate::audio::sessions (same as Prompt DJ's volume)
+
+use crate::audio::sessions::{self, AudioSession};
+use crate::APP;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicIsize, Ordering};
+use std::sync::Once;
+use windows::core::w;
+use windows::Win32::Foundation::*;
+use windows::Win32::Graphics::Dwm::{
+    DwmSetWindowAttribute, DWMWA_WINDOW_CORNER_PREFERENCE, DWMWCP_ROUND,
+};
+use windows::Win32::Graphics::Gdi::HBRUSH;
+use windows::Win32::System::Com::{CoInitializeEx, COINIT_APARTMENTTHREADED};
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use windows::Win32::UI::WindowsAndMessaging::*;
+use wry::{Rect, WebContext, WebView, WebViewBuilder};
+
+static REGISTER_MIXER_CLASS: Once = Once::new();
+pub(crate) static MIXER_HWND: AtomicIsize = AtomicIsize::new(0);
+
+const FOCUS_TIMER_ID: usize = 1;
+const REFRESH_TIMER_ID: usize = 2;
+const REFRESH_INTERVAL_MS: u32 = 2000;
+
+const BASE_WIDTH: i32 = 300;
+const BASE_HEADER_HEIGHT: i32 = 44;
+const BASE_ROW_HEIGHT: i32 = 44;
+/// Rows shown before the list scrolls
+const MAX_VISIBLE_ROWS: i32 = 8;
+
+thread_local! {
+    static MIXER_WEBVIEW: RefCell<Option<WebView>> = RefCell::new(None);
+    static MIXER_WEB_CONTEXT: RefCell<Option<WebContext>> = RefCell::new(None);
+    // Set once the mixer has been foreground; only losing focus after that closes it
+    static HAD_FOCUS: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+// HWND wrapper for wry
+struct HwndWrapper(HWND);
+impl raw_window_handle::HasWindowHandle for HwndWrapper {
+    fn window_handle(
+        &self,
+    ) -> Result<raw_window_handle::WindowHandle<'_>, raw_window_handle::HandleError> {
+        let raw = raw_window_handle::Win32WindowHandle::new(
+            std::num::NonZeroIsize::new(self.0 .0 as isize).expect("HWND cannot be null"),
+        );
+        let handle = raw_window_handle::RawWindowHandle::Win32(raw);
+        unsafe { Ok(raw_window_handle::WindowHandle::borrow_raw(handle)) }
+    }
+}
+
+/// Open the mixer near the cursor (or close it if it's already open)
+pub fn show_volume_mixer() {
+    let existing = MIXER_HWND.load(Ordering::SeqCst);
+    if existing != 0 {
+        unsafe {
+            let _ = PostMessageW(
+                Some(HWND(existing as *mut _)),
+                WM_CLOSE,
+                WPARAM(0),
+                LPARAM(0),
+            );
+        }
+        return;
+    }
+    std::thread::spawn(create_mixer_window);
+}
+
+fn scale(base: i32) -> i32 {
+    let dpi = unsafe { windows::Win32::UI::HiDpi::GetDpiForSystem() };
+    (base * dpi as i32) / 96
+}
+
+/// Physical window size for `rows` sessions
+fn window_size(rows: usize) -> (i32, i32) {
+    let rows = (rows as i32).clamp(1, MAX_VISIBLE_ROWS);
+    (
+        scale(BASE_WIDTH),
+        scale(BASE_HEADER_HEIGHT + rows * BASE_ROW_HEIGHT + 12),
+    )
+}
+
+fn sessions_json(sessions: &[AudioSession]) -> String {
+    serde_json::to_string(sessions).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Resize the window to fit `rows`, keeping its bottom edge where it was
+unsafe fn fit_to_rows(hwnd: HWND, rows: usize) {
+    let (width, height) = window_size(rows);
+    let mut rect = RECT::default();
+    if GetWindowRect(hwnd, &mut rect).is_err() || rect.bottom - rect.top == height {
+        return;
+    }
+    let _ = SetWindowPos(
+        hwnd,
+        None,
+        rect.left,
+        rect.bottom - height,
+        width,
+        height,
+        SWP_NOZORDER | SWP_NOACTIVATE,
+    );
+    MIXER_WEBVIEW.with(|cell| {
+        if let Some(webview) = cell.borrow().as_ref() {
+            let _ = webview.set_bounds(Rect {
+                position: wry::dpi::Position::Physical(wry::dpi::PhysicalPosition::new(0, 0)),
+                size: wry::dpi::Size::Physical(wry::dpi::PhysicalSize::new(
+                    width as u32,
+                    height as u32,
+                )),
+            });
+        }
+    });
+}
+
+unsafe fn refresh(hwnd: HWND) {
+    let sessions = sessions::list_sessions().unwrap_or_default();
+    fit_to_rows(hwnd, sessions.len());
+    let script = format!("window.setSessions({});", sessions_json(&sessions));
+    MIXER_WEBVIEW.with(|cell| {
+        if let Some(webview) = cell.borrow().as_ref() {
+            let _ = webview.evaluate_script(&script);
+        }
+    });
+}
+
+fn generate_mixer_html(sessions: &[AudioSession]) -> String {
+    use crate::config::ThemeMode;
+
+    let (title, system_sounds, empty, is_dark) = {
+        let app = APP.lock().unwrap();
+        let lang = app.config.ui_language.as_str();
+        let title = match lang {
+            "vi" => "Âm lượng ứng dụng",
+            "ko" => "앱별 볼륨",
+            _ => "Volume Mixer",
+        };
+        let system_sounds = match lang {
+            "vi" => "Âm thanh hệ thống",
+            "ko" => "시스템 소리",
+            _ => "System sounds",
+        };
+        let empty = match lang {
+            "vi" => "Không có ứng dụng nào đang phát âm thanh",
+            "ko" => "소리를 재생 중인 앱이 없습니다",
+            _ => "No apps are playing audio",
+        };
+        let is_dark = match app.config.theme_mode {
+            ThemeMode::Dark => true,
+            ThemeMode::Light => false,
+            ThemeMode::System => crate::gui::utils::is_system_in_dark_mode(),
+        };
+        (title, system_sounds, empty, is_dark)
+    };
+
+    let (bg_color, text_color, muted_color, border_color, accent_color) = if is_dark {
+        ("#2c2c2c", "#ffffff", "#9a9a9a", "#454545", "#60cdff")
+    } else {
+        ("#f9f9f9", "#1a1a1a", "#6b6b6b", "#dcdcdc", "#005fb8")
+    };
+
+    let font_css = crate::overlay::html_components::font_manager::get_font_css();
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="UTF-8">
+<style>
+{font_css}
+* {{ margin: 0; padding: 0; box-sizing: border-box; }}
+html, body {{
+    width: 100%;
+    height: 100%;
+    overflow: hidden;
+    background: {bg};
+    color: {text};
+    font-family: 'Google Sans Flex', 'Segoe UI', sans-serif;
+    font-size: 13px;
+    user-select: none;
+}}
+.container {{
+    height: 100%;
+    display: flex;
+    flex-direction: column;
+    border: 1px solid {border};
+    border-radius: 8px;
+    padding: 6px 12px;
+}}
+.title {{
+    height: 32px;
+    line-height: 32px;
+    font-weight: 600;
+    flex-shrink: 0;
+}}
+.list {{
+    flex: 1;
+    overflow-y: auto;
+}}
+.row {{
+    height: 44px;
+    display: flex;
+    flex-direction: column;
+    justify-content: center;
+    gap: 4px;
+}}
+.row-top {{
+    display: flex;
+    justify-content: space-between;
+}}
+.name {{
+    overflow: hidden;
+    text-overflow: ellipsis;
+    white-space: nowrap;
+}}
+.value {{
+    color: {muted};
+    min-width: 36px;
+    text-align: right;
+}}
+input[type=range] {{
+    width: 100%;
+    accent-color: {accent};
+}}
+.empty {{
+    color: {muted};
+    padding-top: 8px;
+}}
+</style>
+</head>
+<body>
+<div class="container">
+    <div class="title">{title}</div>
+    <div class="list" id="list"></div>
+</div>
+<script>
+const SYSTEM_SOUNDS = {system_sounds_json};
+const EMPTY_TEXT = {empty_json};
+let dragging = null;
+
+function pct(v) {{ return Math.round(v * 100); }}
+
+function buildRow(s) {{
+    const row = document.createElement('div');
+    row.className = 'row';
+    row.dataset.pid = s.pid;
+    const top = document.createElement('div');
+    top.className = 'row-top';
+    const name = document.createElement('span');
+    name.className = 'name';
+    name.textContent = s.pid === 0 ? SYSTEM_SOUNDS : s.name;
+    const value = document.createElement('span');
+    value.className = 'value';
+    top.appendChild(name);
+    top.appendChild(value);
+    const slider = document.createElement('input');
+    slider.type = 'range';
+    slider.min = 0;
+    slider.max = 100;
+    slider.addEventListener('pointerdown', () => {{ dragging = s.pid; }});
+    slider.addEventListener('pointerup', () => {{ dragging = null; }});
+    slider.addEventListener('input', () => {{
+        value.textContent = slider.value + '%';
+        window.ipc.postMessage('set:' + s.pid + ':' + (slider.value / 100));
+    }});
+    row.appendChild(top);
+    row.appendChild(slider);
+    return row;
+}}
+
+window.setSessions = function(sessions) {{
+    const list = document.getElementById('list');
+    if (sessions.length === 0) {{
+        list.innerHTML = '';
+        const empty = document.createElement('div');
+        empty.className = 'empty';
+        empty.textContent = EMPTY_TEXT;
+        list.appendChild(empty);
+        return;
+    }}
+    const rows = new Map();
+    list.querySelectorAll('.row').forEach(r => rows.set(Number(r.dataset.pid), r));
+    list.querySelectorAll('.empty').forEach(e => e.remove());
+    sessions.forEach(s => {{
+        let row = rows.get(s.pid);
+        if (!row) row = buildRow(s);
+        rows.delete(s.pid);
+        list.appendChild(row);
+        if (dragging !== s.pid) {{
+            row.querySelector('input').value = pct(s.volume);
+            row.querySelector('.value').textContent = pct(s.volume) + '%';
+        }}
+    }});
+    rows.forEach(r => r.remove());
+}};
+
+window.setSessions({sessions});
+
+document.addEventListener('keydown', e => {{
+    if (e.key === 'Escape') window.ipc.postMessage('close');
+}});
+</script>
+</body>
+</html>"#,
+        font_css = font_css,
+        bg = bg_color,
+        text = text_color,
+        muted = muted_color,
+        border = border_color,
+        accent = accent_color,
+        title = title,
+        system_sounds_json = serde_json::to_string(system_sounds).unwrap_or_default(),
+        empty_json = serde_json::to_string(empty).unwrap_or_default(),
+        sessions = sessions_json(sessions),
+    )
+}
+
+fn create_mixer_window() {
+    unsafe {
+        // Session enumeration runs on this thread (initial list, refresh timer, IPC)
+        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+
+        let instance = GetModuleHandleW(None).unwrap_or_default();
+        let class_name = w!("SGTVolumeMixer");
+
+        REGISTER_MIXER_CLASS.call_once(|| {
+            let wc = WNDCLASSW {
+                lpfnWndProc: Some(mixer_wnd_proc),
+                hInstance: instance.into(),
+                lpszClassName: class_name,
+                hCursor: LoadCursorW(None, IDC_ARROW).unwrap_or_default(),
+                hbrBackground: HBRUSH(std::ptr::null_mut()),
+                ..Default::default()
+            };
+            RegisterClassW(&wc);
+        });
+
+        let sessions = sessions::list_sessions().unwrap_or_default();
+        let (width, height) = window_size(sessions.len());
+
+        // Above the cursor, like the tray popup it was opened from
+        let mut pt = POINT::default();
+        let _ = GetCursorPos(&mut pt);
+        let screen_w = GetSystemMetrics(SM_CXSCREEN);
+        let screen_h = GetSystemMetrics(SM_CYSCREEN);
+        let x = (pt.x - width / 2).max(0).min(screen_w - width);
+        let y = (pt.y - height - 10).max(0).min(screen_h - height);
+
+        let hwnd = CreateWindowExW(
+            WS_EX_TOPMOST | WS_EX_TOOLWINDOW,
+            class_name,
+            w!("Volume Mixer"),
+            WS_POPUP,
+            x,
+            y,
+            width,
+            height,
+            None,
+            None,
+            Some(instance.into()),
+            None,
+        )
+        .unwrap_or_default();
+
+        if hwnd.is_invalid() {
+            return;
+        }
+        MIXER_HWND.store(hwnd.0 as isize, Ordering::SeqCst);
+
+        let corner_pref = DWMWCP_ROUND;
+        let _ = DwmSetWindowAttribute(
+            hwnd,
+            DWMWA_WINDOW_CORNER_PREFERENCE,
+            std::ptr::addr_of!(corner_pref) as *const _,
+            std::mem::size_of_val(&corner_pref) as u32,
+        );
+
+        let wrapper = HwndWrapper(hwnd);
+        let html = generate_mixer_html(&sessions);
+
+        MIXER_WEB_CONTEXT.with(|ctx| {
+            if ctx.borrow().is_none() {
+                let shared_data_dir = crate::overlay::get_shared_webview_data_dir();
+                *ctx.borrow_mut() = Some(WebContext::new(Some(shared_data_dir)));
+            }
+        });
+
+        let webview = MIXER_WEB_CONTEXT.with(|ctx| {
+            let mut ctx_ref = ctx.borrow_mut();
+            let builder = if let Some(web_ctx) = ctx_ref.as_mut() {
+                WebViewBuilder::new_with_web_context(web_ctx)
+            } else {
+                WebViewBuilder::new()
+            };
+            let builder = crate::overlay::html_components::font_manager::configure_webview(builder);
+            builder
+                .with_bounds(Rect {
+                    position: wry::dpi::Position::Physical(wry::dpi::PhysicalPosition::new(0, 0)),
+                    size: wry::dpi::Size::Physical(wry::dpi::PhysicalSize::new(
+                        width as u32,
+                        height as u32,
+                    )),
+                })
+                .with_html(&html)
+                .with_ipc_handler(move |msg: wry::http::Request<String>| {
+                    let body = msg.body();
+                    if let Some(rest) = body.strip_prefix("set:") {
+                        if let Some((pid, volume)) = rest.split_once(':') {
+                            if let (Ok(pid), Ok(volume)) =
+                                (pid.parse::<u32>(), volume.parse::<f32>())
+                            {
+                                let _ = sessions::set_volume(pid, volume);
+                            }
+                        }
+                    } else if body == "close" {
+                        let h = MIXER_HWND.load(Ordering::SeqCst);
+                        if h != 0 {
+                            let _ = PostMessageW(
+                                Some(HWND(h as *mut _)),
+                                WM_CLOSE,
+                                WPARAM(0),
+                                LPARAM(0),
+                            );
+                        }
+                    }
+                })
+                .build(&wrapper)
+        });
+
+        match webview {
+            Ok(wv) => {
+                MIXER_WEBVIEW.with(|cell| {
+                    *cell.borrow_mut() = Some(wv);
+                });
+                let _ = ShowWindow(hwnd, SW_SHOW);
+                let _ = SetForegroundWindow(hwnd);
+                // Focus polling (blur events are unreliable with WebView2) and live levels
+                let _ = SetTimer(Some(hwnd), FOCUS_TIMER_ID, 100, None);
+                let _ = SetTimer(Some(hwnd), REFRESH_TIMER_ID, REFRESH_INTERVAL_MS, None);
+            }
+            Err(_) => {
+                let _ = PostMessageW(Some(hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
+            }
+        }
+
+        let mut msg = MSG::default();
+        while GetMessageW(&mut msg, None, 0, 0).into() {
+            let _ = TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+        }
+
+        MIXER_WEBVIEW.with(|cell| {
+            *cell.borrow_mut() = None;
+        });
+        MIXER_HWND.store(0, Ordering::SeqCst);
+    }
+}
+
+unsafe extern "system" fn mixer_wnd_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    match msg {
+        WM_TIMER => {
+            match wparam.0 {
+                FOCUS_TIMER_ID => {
+                    let fg = GetForegroundWindow();
+                    if fg == hwnd || GetAncestor(fg, GA_ROOT) == hwnd {
+                        HAD_FOCUS.with(|f| f.set(true));
+                    } else if HAD_FOCUS.with(|f| f.get()) {
+                        let _ = PostMessageW(Some(hwnd), WM_CLOSE, WPARAM(0), LPARAM(0));
+                    }
+                }
+                REFRESH_TIMER_ID => refresh(hwnd),
+                _ => {}
+            }
+            LRESULT(0)
+        }
+
+        WM_CLOSE => {
+            let _ = KillTimer(Some(hwnd), FOCUS_TIMER_ID);
+            let _ = KillTimer(Some(hwnd), REFRESH_TIMER_ID);
+            let _ = DestroyWindow(hwnd);
+            LRESULT(0)
+        }
+
+        WM_DESTROY => {
+            PostQuitMessage(0);
+            LRESULT(0)
+        }
+
+        _ => DefWindowProcW(hwnd, msg, wparam, lparam),
+    }
+}
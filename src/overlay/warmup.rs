@@ -0,0 +1,153 @@
+//! Background creation of the hidden WebView windows at startup.
+//!
+//! Tasks are registered with a priority and the names of tasks that must run first,
+//! then executed one at a time on a single worker thread. Before each task the worker
+//! waits for the tray popup to close (a new WebView would steal its focus) and for the
+//! CPU to settle, backing off longer while the machine stays busy.
+
+use std::time::{Duration, Instant};
+use windows::Win32::Foundation::FILETIME;
+use windows::Win32::System::Threading::GetSystemTimes;
+
+/// Above this system CPU usage (%) the next warmup is postponed
+const BUSY_CPU_PERCENT: f64 = 60.0;
+/// Length of one CPU usage sample
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(250);
+const MIN_BACKOFF: Duration = Duration::from_millis(250);
+const MAX_BACKOFF: Duration = Duration::from_secs(2);
+/// A task runs after this long even if the CPU never calms down
+const MAX_WAIT: Duration = Duration::from_secs(10);
+
+struct WarmupTask {
+    name: &'static str,
+    /// Lower runs first among tasks whose dependencies are done
+    priority: u8,
+    after: &'static [&'static str],
+    run: fn(),
+}
+
+#[derive(Default)]
+pub struct WarmupManager {
+    tasks: Vec<WarmupTask>,
+    start_delay: Duration,
+}
+
+impl WarmupManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wait this long before the first task (lets the main window come up undisturbed)
+    pub fn with_start_delay(mut self, delay: Duration) -> Self {
+        self.start_delay = delay;
+        self
+    }
+
+    /// Add a task that runs once every task named in `after` has run
+    pub fn register(
+        mut self,
+        name: &'static str,
+        priority: u8,
+        after: &'static [&'static str],
+        run: fn(),
+    ) -> Self {
+        self.tasks.push(WarmupTask {
+            name,
+            priority,
+            after,
+            run,
+        });
+        self
+    }
+
+    /// Run the tasks on a background worker
+    pub fn spawn(self) {
+        std::thread::spawn(move || {
+            let _worker = super::diagnostics::track_worker("Startup warmup");
+            self.run();
+        });
+    }
+
+    fn run(mut self) {
+        std::thread::sleep(self.start_delay);
+
+        let mut done: Vec<&'static str> = Vec::new();
+        while !self.tasks.is_empty() {
+            let next = self
+                .tasks
+                .iter()
+                .enumerate()
+                .filter(|(_, t)| t.after.iter().all(|dep| done.contains(dep)))
+                .min_by_key(|(_, t)| t.priority)
+                .map(|(i, _)| i);
+
+            let Some(index) = next else {
+                // Remaining tasks depend on names that were never registered
+                for task in &self.tasks {
+                    log::error!("Warmup '{}' skipped: unmet dependencies", task.name);
+                }
+                return;
+            };
+
+            let task = self.tasks.remove(index);
+            wait_until_idle();
+            log::debug!("Warmup: {}", task.name);
+            (task.run)();
+            done.push(task.name);
+        }
+    }
+}
+
+fn wait_until_idle() {
+    let started = Instant::now();
+    let mut backoff = MIN_BACKOFF;
+    loop {
+        if crate::overlay::tray_popup::is_popup_open() {
+            // Not bounded by MAX_WAIT: the popup would close on the focus change
+            std::thread::sleep(Duration::from_millis(100));
+            continue;
+        }
+        if started.elapsed() >= MAX_WAIT {
+            return;
+        }
+        match cpu_usage_percent(SAMPLE_INTERVAL) {
+            Some(usage) if usage > BUSY_CPU_PERCENT => {
+                std::thread::sleep(backoff);
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+            _ => return,
+        }
+    }
+}
+
+fn filetime_ticks(ft: &FILETIME) -> u64 {
+    ((ft.dwHighDateTime as u64) << 32) | ft.dwLowDateTime as u64
+}
+
+/// System-wide CPU usage over `interval`
+fn cpu_usage_percent(interval: Duration) -> Option<f64> {
+    let sample = || unsafe {
+        let (mut idle, mut kernel, mut user) = (
+            FILETIME::default(),
+            FILETIME::default(),
+            FILETIME::default(),
+        );
+        GetSystemTimes(Some(&mut idle), Some(&mut kernel), Some(&mut user)).ok()?;
+        // Kernel time includes idle time
+        Some((
+            filetime_ticks(&idle),
+            filetime_ticks(&kernel) + filetime_ticks(&user),
+        ))
+    };
+
+    let (idle_before, total_before) = sample()?;
+    std::thread::sleep(interval);
+    let (idle_after, total_after) = sample()?;
+
+    let total = total_after.saturating_sub(total_before);
+    if total == 0 {
+        return None;
+    }
+    let idle = idle_after.saturating_sub(idle_before);
+    Some(100.0 * (1.0 - idle as f64 / total as f64))
+}
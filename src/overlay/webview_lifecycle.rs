@@ -0,0 +1,103 @@
+//! Whether overlay WebViews stay alive while hidden, and how much memory they use.
+//!
+//! In "lazy" mode the Prompt DJ and Live Translate windows tear down their WebView
+//! (and window thread) when hidden and are rebuilt on the next show, trading open
+//! latency for idle memory. Result windows stay out of it: their WebViews already
+//! live only as long as the window.
+
+use crate::config::WebViewLifecycle;
+use windows::Win32::Foundation::CloseHandle;
+use windows::Win32::System::Diagnostics::ToolHelp::{
+    CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W, TH32CS_SNAPPROCESS,
+};
+use windows::Win32::System::ProcessStatus::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS};
+use windows::Win32::System::Threading::{
+    GetCurrentProcessId, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_VM_READ,
+};
+
+const WEBVIEW_EXE: &str = "msedgewebview2.exe";
+
+/// True when hidden overlays should drop their WebView
+pub fn is_lazy() -> bool {
+    crate::APP
+        .lock()
+        .map(|app| app.config.webview_lifecycle == WebViewLifecycle::Lazy)
+        .unwrap_or(false)
+}
+
+/// WebView2 processes started by this app
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WebViewMemory {
+    pub processes: usize,
+    pub working_set_bytes: u64,
+}
+
+/// Sum the working sets of the WebView2 processes descended from this process
+pub fn measure_memory() -> Option<WebViewMemory> {
+    unsafe {
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0).ok()?;
+        let mut entries: Vec<(u32, u32, String)> = Vec::new();
+        let mut entry = PROCESSENTRY32W {
+            dwSize: std::mem::size_of::<PROCESSENTRY32W>() as u32,
+            ..Default::default()
+        };
+        if Process32FirstW(snapshot, &mut entry).is_ok() {
+            loop {
+                let len = entry
+                    .szExeFile
+                    .iter()
+                    .position(|&c| c == 0)
+                    .unwrap_or(entry.szExeFile.len());
+                entries.push((
+                    entry.th32ProcessID,
+                    entry.th32ParentProcessID,
+                    String::from_utf16_lossy(&entry.szExeFile[..len]),
+                ));
+                if Process32NextW(snapshot, &mut entry).is_err() {
+                    break;
+                }
+            }
+        }
+        let _ = CloseHandle(snapshot);
+
+        // Walk the process tree down from us (browser process -> renderers, GPU, ...)
+        let mut descendants = vec![GetCurrentProcessId()];
+        let mut i = 0;
+        while i < descendants.len() {
+            let parent = descendants[i];
+            for (pid, ppid, _) in &entries {
+                if *ppid == parent && *pid != parent && !descendants.contains(pid) {
+                    descendants.push(*pid);
+                }
+            }
+            i += 1;
+        }
+
+        let mut memory = WebViewMemory::default();
+        for (pid, _, exe) in &entries {
+            if !descendants.contains(pid) || !exe.eq_ignore_ascii_case(WEBVIEW_EXE) {
+                continue;
+            }
+            let Ok(handle) = OpenProcess(
+                PROCESS_QUERY_LIMITED_INFORMATION | PROCESS_VM_READ,
+                false,
+                *pid,
+            ) else {
+                continue;
+            };
+            let mut counters = PROCESS_MEMORY_COUNTERS::default();
+            if GetProcessMemoryInfo(
+                handle,
+                &mut counters,
+                std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32,
+            )
+            .is_ok()
+            {
+                memory.processes += 1;
+                memory.working_set_bytes += counters.WorkingSetSize as u64;
+            }
+            let _ = CloseHandle(handle);
+        }
+        Some(memory)
+    }
+}
@@ -0,0 +1,63 @@
+//! Detect a WebView whose WebView2 browser or renderer process died.
+//!
+//! wry doesn't surface WebView2's `ProcessFailed` event, so it's registered on the
+//! underlying controller. The handler only posts `WM_APP_WEBVIEW_CRASHED` to the host
+//! window: the dead WebView can't be dropped from inside its own event, so each overlay
+//! rebuilds it from its window procedure.
+
+use webview2_com::Microsoft::Web::WebView2::Win32::{
+    COREWEBVIEW2_PROCESS_FAILED_KIND, COREWEBVIEW2_PROCESS_FAILED_KIND_BROWSER_PROCESS_EXITED,
+    COREWEBVIEW2_PROCESS_FAILED_KIND_RENDER_PROCESS_EXITED,
+    COREWEBVIEW2_PROCESS_FAILED_KIND_RENDER_PROCESS_UNRESPONSIVE,
+};
+use webview2_com::ProcessFailedEventHandler;
+use windows::Win32::Foundation::{HWND, LPARAM, WPARAM};
+use windows::Win32::UI::WindowsAndMessaging::{PostMessageW, WM_APP};
+use wry::WebViewExtWindows;
+
+/// Posted to a window whose WebView lost its process
+pub const WM_APP_WEBVIEW_CRASHED: u32 = WM_APP + 300;
+
+/// Post `WM_APP_WEBVIEW_CRASHED` to `hwnd` when `webview`'s browser process exits or its
+/// renderer exits / hangs (GPU and utility process failures recover on their own)
+pub fn watch(webview: &wry::WebView, hwnd: HWND) {
+    let hwnd_key = hwnd.0 as isize;
+    let handler = ProcessFailedEventHandler::create(Box::new(move |_, args| {
+        if let Some(args) = args {
+            let mut kind = COREWEBVIEW2_PROCESS_FAILED_KIND::default();
+            unsafe { args.ProcessFailedKind(&mut kind)? };
+            if kind == COREWEBVIEW2_PROCESS_FAILED_KIND_BROWSER_PROCESS_EXITED
+                || kind == COREWEBVIEW2_PROCESS_FAILED_KIND_RENDER_PROCESS_EXITED
+                || kind == COREWEBVIEW2_PROCESS_FAILED_KIND_RENDER_PROCESS_UNRESPONSIVE
+            {
+                log::error!("WebView2 process failed (kind {}), recovering", kind.0);
+                unsafe {
+                    let _ = PostMessageW(
+                        Some(HWND(hwnd_key as *mut std::ffi::c_void)),
+                        WM_APP_WEBVIEW_CRASHED,
+                        WPARAM(0),
+                        LPARAM(0),
+                    );
+                }
+            }
+        }
+        Ok(())
+    }));
+
+    unsafe {
+        let registered = webview.controller().CoreWebView2().and_then(|core| {
+            let mut token = 0;
+            core.add_ProcessFailed(&handler, &mut token)
+        });
+        if let Err(e) = registered {
+            log::warn!("Could not watch WebView2 process failures: {:?}", e);
+        }
+    }
+}
+
+/// Brief "recovering" notice while a crashed WebView is rebuilt
+pub fn show_recovering_notice() {
+    let ui_lang = crate::APP.lock().unwrap().config.ui_language.clone();
+    let locale = crate::gui::locale::LocaleText::get(&ui_lang);
+    crate::overlay::auto_copy_badge::show_notification(locale.webview_recovering);
+}
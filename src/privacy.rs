@@ -0,0 +1,37 @@
+//! Local personal-data check on OCR output.
+//!
+//! With `Config::privacy_scan_enabled` on, the text read by a chain's first image
+//! block is matched against `Config::privacy_patterns` before it goes on to the next
+//! blocks, and the user confirms the send if anything matches. Nothing leaves the
+//! machine for the check itself.
+
+use regex::Regex;
+
+/// Email addresses, phone numbers and card-like digit runs
+pub fn default_patterns() -> Vec<String> {
+    [
+        r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}",
+        r"(?:\+\d{1,3}[ .-]?)?\(?\d{2,4}\)?[ .-]?\d{3,4}[ .-]?\d{3,4}\b",
+        r"\b\d(?:[ -]?\d){12,18}\b",
+    ]
+    .into_iter()
+    .map(str::to_string)
+    .collect()
+}
+
+/// The patterns (as written in the config) that match somewhere in `text`.
+/// Blank lines are skipped and invalid patterns logged and ignored.
+pub fn find_personal_data<'a>(text: &str, patterns: &'a [String]) -> Vec<&'a str> {
+    patterns
+        .iter()
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty())
+        .filter(|p| match Regex::new(p) {
+            Ok(re) => re.is_match(text),
+            Err(e) => {
+                log::warn!("Ignoring invalid privacy pattern {:?}: {}", p, e);
+                false
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,98 @@
+//! Secret scrubbing for `sgt.log` and crash reports.
+//!
+//! Every line the logger writes and every crash payload goes through `redact`, which
+//! masks the API keys from the config and anything shaped like a provider key, a
+//! bearer token or a `key=` query parameter, so a key that ends up in an error or a
+//! panic message is never written out.
+
+use std::borrow::Cow;
+use std::sync::RwLock;
+
+use regex::Regex;
+
+use crate::config::Config;
+
+/// Shorter configured values are not masked; they'd match ordinary words
+const MIN_SECRET_LEN: usize = 8;
+/// Characters of a secret left visible, enough to tell which key it was
+const VISIBLE_PREFIX: usize = 4;
+
+/// Configured secret values, refreshed whenever the config is loaded or saved
+static SECRETS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+lazy_static::lazy_static! {
+    static ref KEY_PATTERNS: Regex = Regex::new(concat!(
+        r"gsk_[A-Za-z0-9]{20,}",              // Groq
+        r"|AIza[0-9A-Za-z_\-]{30,}",          // Google / Gemini
+        r"|sk-or-v1-[0-9A-Za-z]{20,}",        // OpenRouter
+        r"|csk-[0-9A-Za-z]{20,}",             // Cerebras
+        r"|sk-[0-9A-Za-z_\-]{20,}",           // Other OpenAI-style keys
+    ))
+    .unwrap();
+    // Credentials whose value follows a marker: the marker is kept, the value masked
+    static ref KEY_PARAMS: Regex =
+        Regex::new(r"(?i)([?&](?:key|api_key|token)=|bearer\s+|x-goog-api-key:\s*)([^\s&'\x22]+)")
+            .unwrap();
+}
+
+/// Remember the config's secrets so they are masked even without a known key shape
+pub fn set_secrets(config: &Config) {
+    let secrets = [
+        &config.api_key,
+        &config.gemini_api_key,
+        &config.openrouter_api_key,
+        &config.cerebras_api_key,
+        &config.http_api_token,
+    ]
+    .into_iter()
+    .map(|s| s.trim())
+    .filter(|s| s.len() >= MIN_SECRET_LEN)
+    .map(str::to_string)
+    .collect();
+
+    if let Ok(mut stored) = SECRETS.write() {
+        *stored = secrets;
+    }
+}
+
+fn mask(secret: &str) -> String {
+    let prefix: String = secret.chars().take(VISIBLE_PREFIX).collect();
+    format!("{}****", prefix)
+}
+
+/// `text` with every known or key-shaped secret masked
+pub fn redact(text: &str) -> Cow<'_, str> {
+    let mut out = Cow::Borrowed(text);
+
+    // try_read: this also runs in the panic hook, which must never block
+    if let Ok(secrets) = SECRETS.try_read() {
+        for secret in secrets.iter() {
+            if out.contains(secret.as_str()) {
+                out = Cow::Owned(out.replace(secret.as_str(), &mask(secret)));
+            }
+        }
+    }
+
+    if KEY_PATTERNS.is_match(&out) {
+        out = Cow::Owned(
+            KEY_PATTERNS
+                .replace_all(&out, |caps: &regex::Captures| mask(&caps[0]))
+                .into_owned(),
+        );
+    }
+    if KEY_PARAMS.is_match(&out) {
+        out = Cow::Owned(
+            KEY_PARAMS
+                .replace_all(&out, |caps: &regex::Captures| {
+                    // Already masked above
+                    if caps[2].ends_with("****") {
+                        caps[0].to_string()
+                    } else {
+                        format!("{}{}", &caps[1], mask(&caps[2]))
+                    }
+                })
+                .into_owned(),
+        );
+    }
+    out
+}
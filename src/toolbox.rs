@@ -0,0 +1,97 @@
+//! The library facade: the app's translation, OCR and text-to-speech backends for
+//! programs that embed the crate, with no windows involved.
+
+use std::sync::Once;
+
+use crate::config::Config;
+
+/// Text model used when none is given, here and by the app's CLI and HTTP API
+pub const DEFAULT_TEXT_MODEL: &str = "text_accurate_kimi";
+
+/// Vision model used when none is given
+pub const DEFAULT_VISION_MODEL: &str = "maverick";
+
+/// The TTS threads start with the first `speak`, not with the toolbox
+static START_TTS: Once = Once::new();
+
+/// Entry point of the library. Each toolbox holds its own config (API keys,
+/// providers, TTS voice) and hands it to every request it makes.
+pub struct ScreenGoatedToolbox {
+    config: Config,
+}
+
+impl ScreenGoatedToolbox {
+    /// Use the settings saved by the app (API keys, enabled providers, TTS voice)
+    pub fn new() -> Self {
+        Self::with_config(crate::config::load_config())
+    }
+
+    /// Use `config` instead of the saved settings
+    pub fn with_config(config: Config) -> Self {
+        Self { config }
+    }
+
+    /// The settings this toolbox uses
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Translate `text` into `target_language` (an English language name, e.g.
+    /// "Vietnamese"). `model_id` picks the model and its provider, see
+    /// [`DEFAULT_TEXT_MODEL`].
+    pub fn translate_text(
+        &self,
+        text: &str,
+        target_language: &str,
+        model_id: Option<&str>,
+    ) -> anyhow::Result<String> {
+        crate::api::text::translate_text_blocking(
+            &self.config,
+            text,
+            "auto",
+            target_language,
+            model_id.unwrap_or(DEFAULT_TEXT_MODEL),
+        )
+    }
+
+    /// Read the text in an encoded image (PNG, JPEG...) and translate it into
+    /// `target_language`. `model_id` must be a vision model, see
+    /// [`DEFAULT_VISION_MODEL`].
+    pub fn translate_image(
+        &self,
+        image_bytes: &[u8],
+        target_language: &str,
+        model_id: Option<&str>,
+    ) -> anyhow::Result<String> {
+        crate::api::vision::translate_image_blocking(
+            &self.config,
+            image_bytes,
+            target_language,
+            model_id.unwrap_or(DEFAULT_VISION_MODEL),
+        )
+    }
+
+    /// Read `text` aloud with the configured voice. Returns at once; the returned id
+    /// goes to [`Self::is_speaking`]. Speech plays on the output device of the first
+    /// toolbox that speaks.
+    pub fn speak(&self, text: &str) -> u64 {
+        START_TTS.call_once(|| crate::api::tts::init_tts(&self.config.tts_output_device));
+        crate::api::tts::TTS_MANAGER.speak(text, 0, &self.config)
+    }
+
+    /// Whether the speech started by [`Self::speak`] is still playing
+    pub fn is_speaking(&self, request_id: u64) -> bool {
+        crate::api::tts::TTS_MANAGER.is_speaking(request_id)
+    }
+
+    /// Stop all speech
+    pub fn stop_speaking(&self) {
+        crate::api::tts::TTS_MANAGER.stop();
+    }
+}
+
+impl Default for ScreenGoatedToolbox {
+    fn default() -> Self {
+        Self::new()
+    }
+}
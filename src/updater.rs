@@ -1,15 +1,33 @@
+use std::path::{Path, PathBuf};
 use std::sync::mpsc::Sender;
 use std::thread;
 
+use crate::config::UpdateChannel;
+
+/// Newest releases first; enough to reach the latest stable past a run of betas
+const RELEASES_URL: &str =
+    "https://api.github.com/repos/nganlinh4/screen-goated-toolbox/releases?per_page=20";
+
 #[derive(Debug, Clone)]
 pub enum UpdateStatus {
     Idle,
     Checking,
     UpToDate(String), // Current version
-    UpdateAvailable { version: String, body: String },
+    UpdateAvailable {
+        version: String,
+        body: String,
+    },
     Downloading,
     Error(String),
     UpdatedAndRestartRequired,
+    /// Notes of the version now running, for the "What's new" panel after an update.
+    /// Taken by the settings app as it arrives, never kept as the update status.
+    ReleaseNotes {
+        version: String,
+        body: String,
+    },
+    /// An earlier build (file name) is staged and replaces this one on restart
+    RollbackStaged(String),
 }
 
 pub struct Updater {
@@ -21,14 +39,14 @@ impl Updater {
         Self { tx }
     }
 
-    pub fn check_for_updates(&self) {
+    pub fn check_for_updates(&self, channel: UpdateChannel) {
         let tx = self.tx.clone();
         thread::spawn(move || {
             let _ = tx.send(UpdateStatus::Checking);
 
             // Use a custom manual request with a specific User-Agent to avoid 403 Forbidden
             // GitHub API requires a User-Agent, and self_update's default might be blocked or rate-limited.
-            let url = "https://api.github.com/repos/nganlinh4/screen-goated-toolbox/releases?per_page=1&prerelease=false";
+            let url = RELEASES_URL;
 
             // Use ureq 3.x API - create agent with config
             let config = ureq::Agent::config_builder()
@@ -56,9 +74,8 @@ impl Updater {
 
                     let data: Result<Vec<serde_json::Value>, _> =
                         serde_json::from_str(&release_json);
-                    match data {
-                        Ok(mut releases) if !releases.is_empty() => {
-                            let rel = releases.remove(0);
+                    match data.map(|releases| pick_release(releases, channel)) {
+                        Ok(Some(rel)) => {
                             let tag_name =
                                 rel.get("tag_name").and_then(|v| v.as_str()).unwrap_or("");
                             let version = tag_name.trim_start_matches('v').to_string();
@@ -107,7 +124,58 @@ impl Updater {
         });
     }
 
-    pub fn perform_update(&self) {
+    /// Fetch the GitHub release notes of `version`; sends `ReleaseNotes` on success
+    pub fn fetch_release_notes(&self, version: &str) {
+        let tx = self.tx.clone();
+        let version = version.to_string();
+        thread::spawn(move || {
+            let url = format!(
+                "https://api.github.com/repos/nganlinh4/screen-goated-toolbox/releases/tags/v{}",
+                version
+            );
+            let config = ureq::Agent::config_builder()
+                .timeout_global(Some(std::time::Duration::from_secs(10)))
+                .build();
+            let agent: ureq::Agent = config.into();
+
+            let release: Result<serde_json::Value, String> = agent
+                .get(&url)
+                .header("User-Agent", "screen-goated-toolbox-checker")
+                .call()
+                .map_err(|e| e.to_string())
+                .and_then(|mut resp| resp.body_mut().read_json().map_err(|e| e.to_string()));
+
+            match release {
+                Ok(rel) => {
+                    let body = rel
+                        .get("body")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("")
+                        .to_string();
+                    if !body.trim().is_empty() {
+                        let _ = tx.send(UpdateStatus::ReleaseNotes { version, body });
+                    }
+                }
+                Err(e) => log::warn!("Failed to fetch release notes for v{}: {}", version, e),
+            }
+        });
+    }
+
+    /// Stage the previous build to replace this one on restart (see `stage_rollback`)
+    pub fn roll_back(&self) {
+        let status = match stage_rollback() {
+            Ok(previous) => UpdateStatus::RollbackStaged(
+                previous
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+            ),
+            Err(e) => UpdateStatus::Error(e),
+        };
+        let _ = self.tx.send(status);
+    }
+
+    pub fn perform_update(&self, channel: UpdateChannel) {
         let tx = self.tx.clone();
         thread::spawn(move || {
             let _ = tx.send(UpdateStatus::Downloading);
@@ -134,19 +202,20 @@ impl Updater {
             let mut staging_path = exe_dir.join("update_pending.exe");
 
             // Use a custom HTTP request to get the latest release (the one marked as "Latest" on GitHub)
-            let release_json = match ureq::get("https://api.github.com/repos/nganlinh4/screen-goated-toolbox/releases?per_page=1&prerelease=false")
+            let release_json = match ureq::get(RELEASES_URL)
                 .header("User-Agent", "screen-goated-toolbox-updater")
                 .call()
             {
-                Ok(mut response) => {
-                    match response.body_mut().read_to_string() {
-                        Ok(s) => s,
-                        Err(e) => {
-                            let _ = tx.send(UpdateStatus::Error(format!("Failed to parse response: {}", e)));
-                            return;
-                        }
+                Ok(mut response) => match response.body_mut().read_to_string() {
+                    Ok(s) => s,
+                    Err(e) => {
+                        let _ = tx.send(UpdateStatus::Error(format!(
+                            "Failed to parse response: {}",
+                            e
+                        )));
+                        return;
                     }
-                }
+                },
                 Err(e) => {
                     let error_msg = {
                         let err_str = e.to_string();
@@ -161,47 +230,44 @@ impl Updater {
                 }
             };
 
-            // Parse the JSON to get the first release
+            // Parse the JSON to get the newest release on the channel
             let release_data: Result<Vec<serde_json::Value>, _> =
                 serde_json::from_str(&release_json);
-            let release = match release_data {
-                Ok(mut releases) if !releases.is_empty() => {
-                    let rel = releases.remove(0);
-                    self_update::update::Release {
-                        name: rel
-                            .get("name")
-                            .and_then(|v| v.as_str())
-                            .unwrap_or("")
-                            .to_string(),
-                        version: rel
-                            .get("tag_name")
-                            .and_then(|v| v.as_str())
-                            .unwrap_or("")
-                            .trim_start_matches('v')
-                            .to_string(),
-                        date: rel
-                            .get("published_at")
-                            .and_then(|v| v.as_str())
-                            .unwrap_or("")
-                            .to_string(),
-                        body: rel
-                            .get("body")
-                            .and_then(|v| v.as_str())
-                            .map(|s| s.to_string()),
-                        assets: rel
-                            .get("assets")
-                            .and_then(|a| a.as_array())
-                            .unwrap_or(&vec![])
-                            .iter()
-                            .filter_map(|asset| {
-                                let name = asset.get("name")?.as_str()?.to_string();
-                                let download_url =
-                                    asset.get("browser_download_url")?.as_str()?.to_string();
-                                Some(self_update::update::ReleaseAsset { name, download_url })
-                            })
-                            .collect(),
-                    }
-                }
+            let release = match release_data.map(|releases| pick_release(releases, channel)) {
+                Ok(Some(rel)) => self_update::update::Release {
+                    name: rel
+                        .get("name")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("")
+                        .to_string(),
+                    version: rel
+                        .get("tag_name")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("")
+                        .trim_start_matches('v')
+                        .to_string(),
+                    date: rel
+                        .get("published_at")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("")
+                        .to_string(),
+                    body: rel
+                        .get("body")
+                        .and_then(|v| v.as_str())
+                        .map(|s| s.to_string()),
+                    assets: rel
+                        .get("assets")
+                        .and_then(|a| a.as_array())
+                        .unwrap_or(&vec![])
+                        .iter()
+                        .filter_map(|asset| {
+                            let name = asset.get("name")?.as_str()?.to_string();
+                            let download_url =
+                                asset.get("browser_download_url")?.as_str()?.to_string();
+                            Some(self_update::update::ReleaseAsset { name, download_url })
+                        })
+                        .collect(),
+                },
                 _ => {
                     let _ = tx.send(UpdateStatus::Error("No releases found".to_string()));
                     return;
@@ -280,6 +346,13 @@ impl Updater {
                     }
                     drop(file); // Close file before processing
 
+                    if let Err(e) = verify_download(&release, asset, &temp_path) {
+                        log::error!("Update rejected: {}", e);
+                        let _ = tx.send(UpdateStatus::Error(e));
+                        let _ = std::fs::remove_file(&temp_path);
+                        return;
+                    }
+
                     // Process the downloaded file
                     if asset.name.ends_with(".zip") {
                         // Extract zip
@@ -354,3 +427,271 @@ impl Updater {
         });
     }
 }
+
+/// Whether `hour` (0-23) falls in the quiet window `start..end`, which may wrap past
+/// midnight (22 to 8). An empty window (start == end) is never quiet.
+pub fn is_quiet_hour(start: u8, end: u8, hour: u32) -> bool {
+    let (start, end) = (start as u32, end as u32);
+    if start <= end {
+        (start..end).contains(&hour)
+    } else {
+        hour >= start || hour < end
+    }
+}
+
+/// Newest release `channel` accepts from a GitHub release list (newest first)
+fn pick_release(
+    releases: Vec<serde_json::Value>,
+    channel: UpdateChannel,
+) -> Option<serde_json::Value> {
+    releases.into_iter().find(|rel| {
+        let flag = |key: &str| rel.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+        !flag("draft") && (channel == UpdateChannel::Beta || !flag("prerelease"))
+    })
+}
+
+/// Checksum files a release may carry: `<asset>.sha256` is looked up first
+const CHECKSUM_ASSETS: [&str; 3] = ["SHA256SUMS", "SHA256SUMS.txt", "checksums.txt"];
+
+/// SHA-256 the release publishes for `asset_name`, if it has a checksum file.
+/// Accepts a bare hash (`<asset>.sha256`) or `sha256sum` output (`<hash>  <name>`).
+fn published_checksum(
+    release: &self_update::update::Release,
+    asset_name: &str,
+) -> Result<Option<String>, String> {
+    let per_asset = format!("{}.sha256", asset_name);
+    let Some(checksum_asset) = release
+        .assets
+        .iter()
+        .find(|a| a.name == per_asset)
+        .or_else(|| {
+            release
+                .assets
+                .iter()
+                .find(|a| CHECKSUM_ASSETS.contains(&a.name.as_str()))
+        })
+    else {
+        return Ok(None);
+    };
+
+    let text = ureq::get(&checksum_asset.download_url)
+        .header("User-Agent", "screen-goated-toolbox-updater")
+        .call()
+        .and_then(|mut r| r.body_mut().read_to_string())
+        .map_err(|e| format!("Failed to download {}: {}", checksum_asset.name, e))?;
+
+    let hash = text.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        let hash = parts.next()?;
+        let matches_name = match parts.next() {
+            Some(name) => name.trim_start_matches('*') == asset_name,
+            None => checksum_asset.name == per_asset,
+        };
+        (matches_name && hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit()))
+            .then(|| hash.to_ascii_lowercase())
+    });
+    match hash {
+        Some(hash) => Ok(Some(hash)),
+        None => Err(format!(
+            "{} has no checksum for {}",
+            checksum_asset.name, asset_name
+        )),
+    }
+}
+
+fn sha256_file(path: &Path) -> std::io::Result<String> {
+    use sha2::{Digest, Sha256};
+
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+/// Check a downloaded asset against the checksum published with its release.
+/// A mismatch is an error, and so is a release without a checksum: an unverified
+/// download is never installed.
+fn verify_download(
+    release: &self_update::update::Release,
+    asset: &self_update::update::ReleaseAsset,
+    downloaded: &Path,
+) -> Result<(), String> {
+    let Some(expected) = published_checksum(release, &asset.name)? else {
+        return Err(format!(
+            "Release {} publishes no SHA-256 for {}, so the download can't be verified. Download it from the releases page instead",
+            release.version, asset.name
+        ));
+    };
+    let actual =
+        sha256_file(downloaded).map_err(|e| format!("Failed to hash the download: {}", e))?;
+    if actual != expected {
+        return Err(format!(
+            "Checksum mismatch for {} (expected {}, got {}), the download is corrupted or was tampered with",
+            asset.name, expected, actual
+        ));
+    }
+    log::info!("Verified SHA-256 of {}", asset.name);
+    Ok(())
+}
+
+/// Earlier builds kept next to the exe, so an update can be rolled back
+const KEEP_PREVIOUS_BUILDS: usize = 2;
+
+/// File name prefix of downloaded builds and of the backups kept when one is applied
+const BUILD_PREFIX: &str = "ScreenGoatedToolbox_v";
+
+/// Name the running build is kept under when an update replaces it
+pub fn backup_name() -> String {
+    format!("{}{}.exe.old", BUILD_PREFIX, env!("CARGO_PKG_VERSION"))
+}
+
+/// "1.2.3" as comparable numbers
+fn parse_version(version: &str) -> Option<Vec<u64>> {
+    version.split('.').map(|part| part.parse().ok()).collect()
+}
+
+/// Version in a build's file name: `ScreenGoatedToolbox_v1.2.3.exe`, a suffixed
+/// asset like `..._v1.2.3_nopack.exe`, or the `.exe.old` backup of either
+fn build_version(file_name: &str) -> Option<Vec<u64>> {
+    let rest = file_name.strip_prefix(BUILD_PREFIX)?;
+    if !rest.ends_with(".exe") && !rest.ends_with(".exe.old") {
+        return None;
+    }
+    let version: String = rest
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    parse_version(version.trim_end_matches('.'))
+}
+
+/// The builds in `files` older than `current`, newest version first. Newer ones are
+/// downloaded updates that haven't been applied, never a rollback target.
+fn older_builds(files: Vec<PathBuf>, current: &[u64]) -> Vec<(PathBuf, Vec<u64>)> {
+    let mut builds: Vec<(PathBuf, Vec<u64>)> = files
+        .into_iter()
+        .filter_map(|path| {
+            let version = build_version(&path.file_name()?.to_string_lossy())?;
+            (version.as_slice() < current).then_some((path, version))
+        })
+        .collect();
+    builds.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    builds
+}
+
+/// Files next to `exe_path` that may be earlier builds, `exe_path` itself excluded
+fn build_files(exe_path: &Path) -> Vec<PathBuf> {
+    let Some(exe_dir) = exe_path.parent() else {
+        return Vec::new();
+    };
+    let Ok(entries) = std::fs::read_dir(exe_dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|path| path != exe_path && path.is_file())
+        .collect()
+}
+
+/// Earlier builds next to `exe_path`, newest version first, one file per version:
+/// downloaded `ScreenGoatedToolbox_v*.exe` files and the `.exe.old` backups kept
+/// when an update was applied
+pub fn previous_builds(exe_path: &Path) -> Vec<PathBuf> {
+    let Some(current) = parse_version(env!("CARGO_PKG_VERSION")) else {
+        return Vec::new();
+    };
+    let mut builds = older_builds(build_files(exe_path), &current);
+    builds.dedup_by(|a, b| a.1 == b.1);
+    builds.into_iter().map(|(path, _)| path).collect()
+}
+
+/// Delete earlier builds beyond the newest `KEEP_PREVIOUS_BUILDS` versions, and the
+/// unversioned `.exe.old` backup older builds kept
+pub fn prune_previous_builds(exe_path: &Path) {
+    let keep: Vec<PathBuf> = previous_builds(exe_path)
+        .into_iter()
+        .take(KEEP_PREVIOUS_BUILDS)
+        .collect();
+    if let Some(current) = parse_version(env!("CARGO_PKG_VERSION")) {
+        for (path, _) in older_builds(build_files(exe_path), &current) {
+            if !keep.contains(&path) {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+    let _ = std::fs::remove_file(exe_path.with_extension("exe.old"));
+}
+
+/// Stage the newest earlier build as `update_pending.exe`, which `main()` swaps in on
+/// the next start like a downloaded update. Returns the build that was staged.
+pub fn stage_rollback() -> Result<PathBuf, String> {
+    let exe_path = std::env::current_exe().map_err(|e| e.to_string())?;
+    let exe_dir = exe_path
+        .parent()
+        .ok_or_else(|| "Could not find exe directory".to_string())?;
+    let previous = previous_builds(&exe_path)
+        .into_iter()
+        .next()
+        .ok_or_else(|| "No previous version is kept".to_string())?;
+
+    std::fs::copy(&previous, exe_dir.join("update_pending.exe"))
+        .map_err(|e| format!("Failed to stage previous version: {}", e))?;
+    log::info!("Staged rollback to {}", previous.display());
+    Ok(previous)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(builds: &[(PathBuf, Vec<u64>)]) -> Vec<String> {
+        builds
+            .iter()
+            .map(|(p, _)| p.to_string_lossy().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn build_version_reads_downloads_and_backups() {
+        assert_eq!(
+            build_version("ScreenGoatedToolbox_v4.2.0.exe"),
+            Some(vec![4, 2, 0])
+        );
+        assert_eq!(
+            build_version("ScreenGoatedToolbox_v4.10.1_nopack.exe"),
+            Some(vec![4, 10, 1])
+        );
+        assert_eq!(
+            build_version("ScreenGoatedToolbox_v4.2.0.exe.old"),
+            Some(vec![4, 2, 0])
+        );
+        assert_eq!(build_version("ScreenGoatedToolbox.exe.old"), None);
+        assert_eq!(build_version("update_pending.exe"), None);
+        assert_eq!(build_version("ScreenGoatedToolbox_v4.2.0.zip"), None);
+    }
+
+    #[test]
+    fn older_builds_skip_pending_downloads_and_sort_by_version() {
+        let files = [
+            "ScreenGoatedToolbox_v5.0.0.exe", // downloaded, not applied yet
+            "ScreenGoatedToolbox_v4.9.0.exe.old",
+            "ScreenGoatedToolbox_v4.10.0.exe.old",
+            "ScreenGoatedToolbox_v4.12.0.exe", // the running version
+            "notes.txt",
+        ]
+        .map(PathBuf::from)
+        .to_vec();
+        let builds = older_builds(files, &[4, 12, 0]);
+        assert_eq!(
+            names(&builds),
+            [
+                "ScreenGoatedToolbox_v4.10.0.exe.old",
+                "ScreenGoatedToolbox_v4.9.0.exe.old"
+            ]
+        );
+    }
+}
@@ -0,0 +1,127 @@
+//! Daily request counts per provider and per preset, kept in `usage_history.json`
+//! next to the config for the charts in the usage statistics window.
+//!
+//! Tokens are an estimate: the characters of the prompt, the input text and the
+//! answer divided by four. Image input is counted as a request only.
+
+use chrono::{Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Days older than this are dropped on the next write
+const KEEP_DAYS: i64 = 90;
+/// Rough characters per token for the estimate
+const CHARS_PER_TOKEN: usize = 4;
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProviderUsage {
+    pub requests: u64,
+    pub est_tokens: u64,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DayUsage {
+    /// Keyed by provider id ("groq", "google", ...)
+    #[serde(default)]
+    pub providers: BTreeMap<String, ProviderUsage>,
+    /// Requests keyed by preset id
+    #[serde(default)]
+    pub presets: BTreeMap<String, u64>,
+}
+
+/// Loaded on first use
+static DAYS: Mutex<Option<BTreeMap<NaiveDate, DayUsage>>> = Mutex::new(None);
+
+fn history_path() -> PathBuf {
+    let config_dir = dirs::config_dir()
+        .unwrap_or_default()
+        .join("screen-goated-toolbox");
+    let _ = std::fs::create_dir_all(&config_dir);
+    config_dir.join("usage_history.json")
+}
+
+fn load() -> BTreeMap<NaiveDate, DayUsage> {
+    std::fs::read_to_string(history_path())
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+/// Count one API request made by `preset_id` (empty if not run from a preset).
+/// `chars` is the length of everything sent and received as text.
+pub fn record_request(provider: &str, preset_id: &str, chars: usize) {
+    let Ok(mut guard) = DAYS.lock() else {
+        return;
+    };
+    let days = guard.get_or_insert_with(load);
+    let today = Local::now().date_naive();
+
+    let day = days.entry(today).or_default();
+    let usage = day.providers.entry(provider.to_string()).or_default();
+    usage.requests += 1;
+    usage.est_tokens += (chars / CHARS_PER_TOKEN) as u64;
+    if !preset_id.is_empty() {
+        *day.presets.entry(preset_id.to_string()).or_default() += 1;
+    }
+
+    let cutoff = today - Duration::days(KEEP_DAYS);
+    days.retain(|date, _| *date > cutoff);
+    match serde_json::to_string(&*days) {
+        Ok(data) => {
+            if let Err(e) = std::fs::write(history_path(), data) {
+                log::warn!("Failed to save usage history: {}", e);
+            }
+        }
+        Err(e) => log::warn!("Failed to serialize usage history: {}", e),
+    }
+}
+
+/// The last `count` days up to today, oldest first, with empty days filled in
+pub fn last_days(count: i64) -> Vec<(NaiveDate, DayUsage)> {
+    let Ok(mut guard) = DAYS.lock() else {
+        return Vec::new();
+    };
+    let days = guard.get_or_insert_with(load);
+    let today = Local::now().date_naive();
+    (0..count)
+        .rev()
+        .map(|back| {
+            let date = today - Duration::days(back);
+            (date, days.get(&date).cloned().unwrap_or_default())
+        })
+        .collect()
+}
+
+/// One row per day and provider: `date,provider,requests,est_tokens`
+pub fn to_csv(days: &[(NaiveDate, DayUsage)]) -> String {
+    let mut csv = String::from("date,provider,requests,est_tokens\n");
+    for (date, day) in days {
+        for (provider, usage) in &day.providers {
+            csv.push_str(&format!(
+                "{},{},{},{}\n",
+                date.format("%Y-%m-%d"),
+                provider,
+                usage.requests,
+                usage.est_tokens
+            ));
+        }
+    }
+    csv
+}
+
+/// The same days as JSON: `[{"date": ..., "providers": {...}, "presets": {...}}]`
+pub fn to_json(days: &[(NaiveDate, DayUsage)]) -> serde_json::Result<String> {
+    let rows: Vec<serde_json::Value> = days
+        .iter()
+        .map(|(date, day)| {
+            serde_json::json!({
+                "date": date.format("%Y-%m-%d").to_string(),
+                "providers": day.providers,
+                "presets": day.presets,
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&rows)
+}